@@ -0,0 +1,605 @@
+use crate::{db::DynDB, github};
+use anyhow::{format_err, Context, Result};
+use async_trait::async_trait;
+use clomonitor_core::net;
+use config::{Config, ConfigError};
+use jsonwebtoken::{
+    decode, decode_header,
+    jwk::{Jwk, JwkSet},
+    Algorithm, DecodingKey, Validation,
+};
+#[cfg(test)]
+use mockall::automock;
+use openssl::memcmp;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{sync::RwLock, time::Instant};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Minimum time between refreshes of the OIDC provider's keys set.
+const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// How long the GitHub users the tokens belong to are cached. Tokens revoked
+/// may still be accepted during this period.
+const GITHUB_USERS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Number of GitHub users cached above which the expired ones are removed.
+const MAX_GITHUB_USERS: usize = 1_000;
+
+/// Claim used by default to get the groups an OIDC user belongs to.
+const DEFAULT_GROUPS_CLAIM: &str = "groups";
+
+/// Type alias to represent an IdentityProvider trait object.
+pub(crate) type DynIdentityProvider = Arc<dyn IdentityProvider + Send + Sync>;
+
+/// Roles that can be granted to an authenticated identity. Roles can be
+/// granted by the identity providers or assigned to a subject in the database.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "role", rename_all = "snake_case")]
+pub(crate) enum Role {
+    /// Allowed to perform any operation.
+    PlatformAdmin,
+    /// Allowed to manage the foundation and its projects.
+    FoundationStaff { foundation: String },
+    /// Allowed to manage the project.
+    ProjectMaintainer { foundation: String, project: String },
+}
+
+impl Role {
+    /// Check if this role includes the one provided (i.e. foundation staff
+    /// can perform the operations allowed to the foundation's projects
+    /// maintainers).
+    pub(crate) fn includes(&self, other: &Role) -> bool {
+        match (self, other) {
+            (Role::PlatformAdmin, _) => true,
+            (
+                Role::FoundationStaff { foundation: f1 },
+                Role::FoundationStaff { foundation: f2 },
+            ) => f1 == f2,
+            (
+                Role::FoundationStaff { foundation: f1 },
+                Role::ProjectMaintainer { foundation: f2, .. },
+            ) => f1 == f2,
+            (Role::ProjectMaintainer { .. }, Role::ProjectMaintainer { .. }) => self == other,
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = anyhow::Error;
+
+    /// Parse a role that is not scoped to a foundation or project (only those
+    /// can be granted based on the groups an OIDC user belongs to).
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "platform_admin" => Ok(Role::PlatformAdmin),
+            _ => Err(format_err!("invalid role: {s}")),
+        }
+    }
+}
+
+/// Role assigned to a subject.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct RoleAssignment {
+    pub subject: String,
+    #[serde(flatten)]
+    pub role: Role,
+}
+
+/// Identity authenticated by an identity provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Identity {
+    pub subject: String,
+    pub roles: HashSet<Role>,
+}
+
+impl Identity {
+    /// Check if the identity has been granted the role provided, either
+    /// directly or through a role that includes it.
+    pub(crate) fn has_role(&self, role: &Role) -> bool {
+        self.roles.iter().any(|granted| granted.includes(role))
+    }
+}
+
+/// Trait that defines some operations an IdentityProvider implementation must
+/// support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub(crate) trait IdentityProvider {
+    /// Authenticate the bearer token provided. None is returned when the token
+    /// was not issued by this provider.
+    async fn authenticate(&self, token: &str) -> Result<Option<Identity>>;
+}
+
+/// Authenticator that validates bearer tokens using the identity providers
+/// configured, in order. The roles assigned in the database to the identities
+/// authenticated are added to the ones granted by the providers.
+pub(crate) struct Authenticator {
+    db: DynDB,
+    providers: Vec<DynIdentityProvider>,
+}
+
+impl Authenticator {
+    /// Create a new Authenticator instance using the providers provided.
+    pub(crate) fn new(db: DynDB, providers: Vec<DynIdentityProvider>) -> Self {
+        Self { db, providers }
+    }
+
+    /// Setup an authenticator using the identity providers enabled in the
+    /// configuration (static admin token, OIDC provider and GitHub tokens).
+    pub(crate) fn from_config(cfg: &Config, db: DynDB) -> Result<Self> {
+        let mut providers: Vec<DynIdentityProvider> = vec![];
+        let admin_token = cfg.get_string("apiserver.admin.token").unwrap_or_default();
+        if !admin_token.is_empty() {
+            providers.push(Arc::new(StaticTokenProvider::new(admin_token)));
+        }
+        if cfg.get_string("apiserver.oidc.issuer").is_ok() {
+            providers.push(Arc::new(OidcProvider::new(cfg)?));
+        }
+        if cfg
+            .get_bool("apiserver.githubAuth.enabled")
+            .unwrap_or(false)
+        {
+            providers.push(Arc::new(GitHubProvider::new(github::api_url(cfg))));
+        }
+        Ok(Self::new(db, providers))
+    }
+
+    /// Check if any identity provider has been configured.
+    pub(crate) fn is_enabled(&self) -> bool {
+        !self.providers.is_empty()
+    }
+
+    /// Authenticate the bearer token provided using the first identity
+    /// provider that recognizes it.
+    pub(crate) async fn authenticate(&self, token: &str) -> Result<Option<Identity>> {
+        for provider in &self.providers {
+            match provider.authenticate(token).await {
+                Ok(Some(mut identity)) => {
+                    // Platform admins have already been granted all roles
+                    if !identity.has_role(&Role::PlatformAdmin) {
+                        let roles = self.db.subject_roles(&identity.subject).await?;
+                        identity.roles.extend(roles);
+                    }
+                    return Ok(Some(identity));
+                }
+                Ok(None) => continue,
+                Err(err) => {
+                    debug!("error authenticating token: {:#}", err);
+                    continue;
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// IdentityProvider implementation that grants the platform admin role to the
+/// holders of a static token.
+pub(crate) struct StaticTokenProvider {
+    token: String,
+}
+
+impl StaticTokenProvider {
+    /// Create a new StaticTokenProvider instance.
+    pub(crate) fn new(token: String) -> Self {
+        Self { token }
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for StaticTokenProvider {
+    async fn authenticate(&self, token: &str) -> Result<Option<Identity>> {
+        // Tokens are compared in constant time to prevent timing attacks
+        if token.len() != self.token.len() || !memcmp::eq(token.as_bytes(), self.token.as_bytes()) {
+            return Ok(None);
+        }
+        Ok(Some(Identity {
+            subject: "admin".to_string(),
+            roles: HashSet::from([Role::PlatformAdmin]),
+        }))
+    }
+}
+
+/// IdentityProvider implementation that authenticates GitHub users using
+/// their GitHub tokens. No roles are granted by this provider: GitHub users
+/// become projects maintainers by claiming them (or when a role is assigned
+/// to their subject).
+pub(crate) struct GitHubProvider {
+    api_url: String,
+    logins: RwLock<HashMap<String, (String, Instant)>>,
+}
+
+impl GitHubProvider {
+    /// Create a new GitHubProvider instance.
+    pub(crate) fn new(api_url: String) -> Self {
+        Self {
+            api_url,
+            logins: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get the login of the user the token provided belongs to. Logins are
+    /// cached for a while (indexed by the token's hash) to avoid hitting the
+    /// GitHub API on each request. Invalid tokens are not cached.
+    async fn user_login(&self, token: &str) -> Result<Option<String>> {
+        let token_hash = hex::encode(Sha256::digest(token.as_bytes()));
+        if let Some((login, cached_at)) = self.logins.read().await.get(&token_hash) {
+            if cached_at.elapsed() < GITHUB_USERS_CACHE_TTL {
+                return Ok(Some(login.clone()));
+            }
+        }
+        let login = github::user_login(&self.api_url, token).await?;
+        let mut logins = self.logins.write().await;
+        match &login {
+            Some(login) => {
+                if logins.len() >= MAX_GITHUB_USERS {
+                    logins.retain(|_, (_, cached_at)| cached_at.elapsed() < GITHUB_USERS_CACHE_TTL);
+                }
+                logins.insert(token_hash, (login.clone(), Instant::now()));
+            }
+            None => {
+                logins.remove(&token_hash);
+            }
+        }
+        Ok(login)
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for GitHubProvider {
+    async fn authenticate(&self, token: &str) -> Result<Option<Identity>> {
+        if !github::is_user_token(token) {
+            return Ok(None);
+        }
+        let Some(login) = self.user_login(token).await? else {
+            return Ok(None);
+        };
+        Ok(Some(Identity {
+            subject: github::subject(&login),
+            roles: HashSet::new(),
+        }))
+    }
+}
+
+/// OIDC provider's metadata document (only the fields used are included).
+#[derive(Debug, Deserialize)]
+struct ProviderMetadata {
+    jwks_uri: String,
+}
+
+/// Keys set used to verify the tokens signatures, along with the time it was
+/// fetched.
+struct CachedJwks {
+    jwks: JwkSet,
+    fetched_at: Instant,
+}
+
+/// IdentityProvider implementation that validates the ID tokens issued by a
+/// generic OIDC provider, mapping the groups the user belongs to to roles.
+pub(crate) struct OidcProvider {
+    http_client: reqwest::Client,
+    issuer: String,
+    audience: String,
+    groups_claim: String,
+    roles_mapping: HashMap<String, Vec<Role>>,
+    jwks: RwLock<Option<CachedJwks>>,
+}
+
+impl OidcProvider {
+    /// Create a new OidcProvider instance.
+    pub(crate) fn new(cfg: &Config) -> Result<Self> {
+        let groups_claim = match cfg.get_string("apiserver.oidc.groupsClaim") {
+            Ok(claim) => claim,
+            Err(ConfigError::NotFound(_)) => DEFAULT_GROUPS_CLAIM.to_string(),
+            Err(err) => return Err(err.into()),
+        };
+        let roles_groups = match cfg.get::<HashMap<String, Vec<String>>>("apiserver.oidc.roles") {
+            Ok(roles_groups) => roles_groups,
+            Err(ConfigError::NotFound(_)) => HashMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let mut roles_mapping: HashMap<String, Vec<Role>> = HashMap::new();
+        for (role, groups) in roles_groups {
+            let role = Role::from_str(&role).context("invalid oidc roles mapping")?;
+            for group in groups {
+                roles_mapping.entry(group).or_default().push(role.clone());
+            }
+        }
+
+        Ok(Self {
+            http_client: net::client_builder().build()?,
+            issuer: cfg.get_string("apiserver.oidc.issuer")?,
+            audience: cfg.get_string("apiserver.oidc.audience")?,
+            groups_claim,
+            roles_mapping,
+            jwks: RwLock::new(None),
+        })
+    }
+
+    /// Get the key with the id provided from the provider's keys set. The
+    /// keys set is refreshed when the key is not found (i.e. after a key
+    /// rotation), at most once every JWKS_MIN_REFRESH_INTERVAL.
+    async fn key(&self, kid: &str) -> Result<Option<Jwk>> {
+        // Try with the cached keys set first
+        if let Some(cached) = &*self.jwks.read().await {
+            if let Some(jwk) = cached.jwks.find(kid) {
+                return Ok(Some(jwk.clone()));
+            }
+            if cached.fetched_at.elapsed() < JWKS_MIN_REFRESH_INTERVAL {
+                return Ok(None);
+            }
+        }
+
+        // Refresh keys set
+        let jwks = self.fetch_jwks().await?;
+        let jwk = jwks.find(kid).cloned();
+        *self.jwks.write().await = Some(CachedJwks {
+            jwks,
+            fetched_at: Instant::now(),
+        });
+        Ok(jwk)
+    }
+
+    /// Fetch the provider's keys set, discovering its location from the
+    /// provider's metadata.
+    async fn fetch_jwks(&self) -> Result<JwkSet> {
+        let metadata_url = format!(
+            "{}/.well-known/openid-configuration",
+            self.issuer.trim_end_matches('/')
+        );
+        let metadata: ProviderMetadata = serde_json::from_str(
+            &self
+                .http_client
+                .get(metadata_url)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?,
+        )
+        .context("invalid oidc provider metadata")?;
+        let jwks = serde_json::from_str(
+            &self
+                .http_client
+                .get(metadata.jwks_uri)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?,
+        )
+        .context("invalid oidc provider keys set")?;
+        Ok(jwks)
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for OidcProvider {
+    async fn authenticate(&self, token: &str) -> Result<Option<Identity>> {
+        // Tokens that are not JWTs cannot have been issued by this provider
+        let Ok(header) = decode_header(token) else {
+            return Ok(None);
+        };
+
+        // Only asymmetric signatures can be verified using the provider's keys
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(format_err!("unsupported token algorithm"));
+        }
+        let kid = header
+            .kid
+            .ok_or_else(|| format_err!("token key id missing"))?;
+        let Some(jwk) = self.key(&kid).await? else {
+            return Ok(None);
+        };
+
+        // Verify token and extract identity from its claims
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        let claims =
+            decode::<HashMap<String, Value>>(token, &DecodingKey::from_jwk(&jwk)?, &validation)?
+                .claims;
+        let subject = claims
+            .get("sub")
+            .and_then(Value::as_str)
+            .ok_or_else(|| format_err!("token subject missing"))?
+            .to_string();
+        Ok(Some(Identity {
+            subject,
+            roles: roles_from_claims(&claims, &self.groups_claim, &self.roles_mapping),
+        }))
+    }
+}
+
+/// Get the roles granted to the groups listed in the claims provided.
+fn roles_from_claims(
+    claims: &HashMap<String, Value>,
+    groups_claim: &str,
+    roles_mapping: &HashMap<String, Vec<Role>>,
+) -> HashSet<Role> {
+    let groups: Vec<&str> = match claims.get(groups_claim) {
+        Some(Value::Array(groups)) => groups.iter().filter_map(Value::as_str).collect(),
+        Some(Value::String(group)) => vec![group.as_str()],
+        _ => vec![],
+    };
+    groups
+        .iter()
+        .filter_map(|group| roles_mapping.get(*group))
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::MockDB;
+    use futures::future;
+    use mockall::predicate::eq;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn authenticator_uses_first_provider_recognizing_token() {
+        let mut provider1 = MockIdentityProvider::new();
+        provider1
+            .expect_authenticate()
+            .with(eq("token"))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok(None))));
+        let mut provider2 = MockIdentityProvider::new();
+        provider2
+            .expect_authenticate()
+            .with(eq("token"))
+            .times(1)
+            .returning(|_: &str| {
+                Box::pin(future::ready(Ok(Some(Identity {
+                    subject: "user".to_string(),
+                    roles: HashSet::from([Role::PlatformAdmin]),
+                }))))
+            });
+
+        let auth = Authenticator::new(
+            Arc::new(MockDB::new()),
+            vec![Arc::new(provider1), Arc::new(provider2)],
+        );
+        let identity = auth.authenticate("token").await.unwrap().unwrap();
+        assert_eq!(identity.subject, "user");
+        assert!(identity.has_role(&Role::PlatformAdmin));
+    }
+
+    #[tokio::test]
+    async fn authenticator_adds_roles_assigned_in_db() {
+        let mut db = MockDB::new();
+        db.expect_subject_roles()
+            .with(eq("user"))
+            .times(1)
+            .returning(|_: &str| {
+                Box::pin(future::ready(Ok(vec![Role::FoundationStaff {
+                    foundation: "cncf".to_string(),
+                }])))
+            });
+        let mut provider = MockIdentityProvider::new();
+        provider
+            .expect_authenticate()
+            .with(eq("token"))
+            .times(1)
+            .returning(|_: &str| {
+                Box::pin(future::ready(Ok(Some(Identity {
+                    subject: "user".to_string(),
+                    roles: HashSet::new(),
+                }))))
+            });
+
+        let auth = Authenticator::new(Arc::new(db), vec![Arc::new(provider)]);
+        let identity = auth.authenticate("token").await.unwrap().unwrap();
+        assert!(identity.has_role(&Role::ProjectMaintainer {
+            foundation: "cncf".to_string(),
+            project: "artifact-hub".to_string(),
+        }));
+        assert!(!identity.has_role(&Role::PlatformAdmin));
+    }
+
+    #[tokio::test]
+    async fn authenticator_provider_error() {
+        let mut provider = MockIdentityProvider::new();
+        provider
+            .expect_authenticate()
+            .with(eq("token"))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Err(format_err!("fake error")))));
+
+        let auth = Authenticator::new(Arc::new(MockDB::new()), vec![Arc::new(provider)]);
+        assert!(auth.authenticate("token").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn static_token_provider() {
+        let provider = StaticTokenProvider::new("token".to_string());
+        assert!(provider
+            .authenticate("token")
+            .await
+            .unwrap()
+            .unwrap()
+            .has_role(&Role::PlatformAdmin));
+        assert!(provider.authenticate("tokem").await.unwrap().is_none());
+        assert!(provider.authenticate("other").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn github_provider() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"login": "Tegioz"})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = GitHubProvider::new(server.uri());
+        for _ in 0..2 {
+            assert_eq!(
+                provider.authenticate("gho_token").await.unwrap(),
+                Some(Identity {
+                    subject: "github:tegioz".to_string(),
+                    roles: HashSet::new(),
+                })
+            );
+        }
+        assert!(provider.authenticate("token").await.unwrap().is_none());
+    }
+
+    #[test]
+    fn roles_from_claims_groups_mapped() {
+        let claims = HashMap::from([("groups".to_string(), json!(["staff", "other"]))]);
+        let roles_mapping = HashMap::from([("staff".to_string(), vec![Role::PlatformAdmin])]);
+        assert_eq!(
+            roles_from_claims(&claims, "groups", &roles_mapping),
+            HashSet::from([Role::PlatformAdmin])
+        );
+    }
+
+    #[test]
+    fn roles_from_claims_no_groups() {
+        let claims = HashMap::from([("sub".to_string(), json!("user"))]);
+        let roles_mapping = HashMap::from([("staff".to_string(), vec![Role::PlatformAdmin])]);
+        assert!(roles_from_claims(&claims, "groups", &roles_mapping).is_empty());
+    }
+
+    #[test]
+    fn role_includes() {
+        let staff = Role::FoundationStaff {
+            foundation: "cncf".to_string(),
+        };
+        let maintainer = Role::ProjectMaintainer {
+            foundation: "cncf".to_string(),
+            project: "artifact-hub".to_string(),
+        };
+        let other_maintainer = Role::ProjectMaintainer {
+            foundation: "lfaidata".to_string(),
+            project: "artifact-hub".to_string(),
+        };
+        assert!(Role::PlatformAdmin.includes(&staff));
+        assert!(staff.includes(&maintainer));
+        assert!(!staff.includes(&other_maintainer));
+        assert!(!staff.includes(&Role::PlatformAdmin));
+        assert!(maintainer.includes(&maintainer));
+        assert!(!maintainer.includes(&staff));
+    }
+}
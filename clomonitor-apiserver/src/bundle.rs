@@ -0,0 +1,259 @@
+use anyhow::Result;
+use clomonitor_core::{
+    linter::CheckOverrides,
+    score::{Score, Weights},
+};
+use flate2::{write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tar::{Builder, Header};
+
+/// Version of the apiserver building the bundles.
+const APISERVER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Version of the bundle format.
+const BUNDLE_VERSION: usize = 1;
+
+/// Repository information needed to build a reproducibility bundle.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RepositoryBundle {
+    pub foundation: String,
+    pub project: String,
+    pub name: String,
+    pub url: String,
+    pub digest: Option<String>,
+    #[serde(default)]
+    pub check_sets: Vec<String>,
+    pub check_overrides: Option<CheckOverrides>,
+    pub provider: Option<String>,
+    pub container_images: Option<Vec<String>>,
+    pub gerrit_url: Option<String>,
+    pub score: Option<Score>,
+    pub report: Option<Value>,
+    pub errors: Option<String>,
+    pub linter_version: Option<String>,
+    pub environment: Option<Value>,
+    pub updated_at: i64,
+}
+
+impl RepositoryBundle {
+    /// Name of the bundle tarball.
+    pub(crate) fn filename(&self) -> String {
+        format!("{}-bundle.tar.gz", self.base_dir())
+    }
+
+    /// Name of the directory all the bundle files are placed in.
+    fn base_dir(&self) -> String {
+        format!("{}-{}", self.project, self.name)
+    }
+}
+
+/// Build a reproducibility bundle (gzipped tarball) for the repository
+/// provided. It includes the report and score stored, the configuration and
+/// environment used to produce them and a script to replay the linter locally
+/// on the same revision.
+pub(crate) fn build(bundle: &RepositoryBundle, weights: &Weights) -> Result<Vec<u8>> {
+    let manifest = json!({
+        "bundle_version": BUNDLE_VERSION,
+        "apiserver_version": APISERVER_VERSION,
+        "foundation": bundle.foundation,
+        "project": bundle.project,
+        "repository": {
+            "name": bundle.name,
+            "url": bundle.url,
+            "digest": bundle.digest,
+        },
+        "linter_version": bundle.linter_version,
+        "environment": bundle.environment,
+        "errors": bundle.errors,
+        "updated_at": bundle.updated_at,
+    });
+    let config = json!({
+        "check_sets": bundle.check_sets,
+        "check_overrides": bundle.check_overrides,
+        "provider": bundle.provider,
+        "container_images": bundle.container_images,
+        "gerrit_url": bundle.gerrit_url,
+        "weights": weights,
+    });
+
+    let mut files = vec![
+        (
+            "manifest.json",
+            serde_json::to_vec_pretty(&manifest)?,
+            0o644,
+        ),
+        ("config.json", serde_json::to_vec_pretty(&config)?, 0o644),
+        ("replay.sh", replay_script(bundle).into_bytes(), 0o755),
+    ];
+    if let Some(report) = &bundle.report {
+        files.push(("report.json", serde_json::to_vec_pretty(report)?, 0o644));
+    }
+    if let Some(score) = &bundle.score {
+        files.push(("score.json", serde_json::to_vec_pretty(score)?, 0o644));
+    }
+
+    let mut tar = Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    let base_dir = bundle.base_dir();
+    for (name, data, mode) in files {
+        let mut header = Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(mode);
+        header.set_mtime(bundle.updated_at.max(0) as u64);
+        header.set_cksum();
+        tar.append_data(&mut header, format!("{base_dir}/{name}"), data.as_slice())?;
+    }
+    Ok(tar.into_inner()?.finish()?)
+}
+
+/// Prepare a script that replays the linter on the repository's revision the
+/// report was produced from.
+fn replay_script(bundle: &RepositoryBundle) -> String {
+    let checkout = match &bundle.digest {
+        Some(digest) => format!("git -C repository checkout --quiet {digest}\n"),
+        None => "# Revision not available, the latest one will be used\n".to_string(),
+    };
+    let check_sets: String = bundle
+        .check_sets
+        .iter()
+        .map(|check_set| format!(" --check-set {check_set}"))
+        .collect();
+    let check_overrides: String = bundle
+        .check_overrides
+        .iter()
+        .flat_map(|overrides| {
+            let enabled = overrides
+                .enable
+                .iter()
+                .map(|check_id| format!(" --enable-check {check_id}"));
+            let disabled = overrides
+                .disable
+                .iter()
+                .map(|check_id| format!(" --disable-check {check_id}"));
+            enabled.chain(disabled)
+        })
+        .collect();
+    let provider = match &bundle.provider {
+        Some(provider) => format!(" --provider {provider}"),
+        None => String::new(),
+    };
+    let container_images: String = bundle
+        .container_images
+        .iter()
+        .flatten()
+        .map(|image| format!(" --container-image {image}"))
+        .collect();
+    let gerrit_url = match &bundle.gerrit_url {
+        Some(gerrit_url) => format!(" --gerrit-url '{}'", gerrit_url.replace('\'', "")),
+        None => String::new(),
+    };
+    let url = bundle.url.replace('\'', "");
+    format!(
+        r#"#!/bin/sh
+#
+# Replay the CLOMonitor linter on the same revision of the repository the
+# report in this bundle was produced from. The resulting report can be compared
+# with report.json. Please note that some checks rely on remote APIs (i.e.
+# GitHub, OpenSSF Scorecard), so their results may differ if the remote data
+# has changed since then.
+#
+# The linter requires a GITHUB_TOKEN environment variable to be set.
+
+set -e
+
+git clone --quiet '{url}' repository
+{checkout}clomonitor-linter --path repository --url '{url}'{check_sets}{check_overrides}{provider}{container_images}{gerrit_url} --format json > replayed-report.json
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::{collections::HashMap, io::Read};
+    use tar::Archive;
+
+    fn repository_bundle() -> RepositoryBundle {
+        RepositoryBundle {
+            foundation: "cncf".to_string(),
+            project: "artifact-hub".to_string(),
+            name: "hub".to_string(),
+            url: "https://github.com/artifacthub/hub".to_string(),
+            digest: Some("653b5219d16a2e5be274a7fb765916789ae68fbb".to_string()),
+            check_sets: vec!["code".to_string(), "community".to_string()],
+            check_overrides: Some(CheckOverrides {
+                disable: vec!["recent_release".to_string()],
+                ..CheckOverrides::default()
+            }),
+            provider: Some("github".to_string()),
+            container_images: Some(vec!["ghcr.io/artifacthub/hub".to_string()]),
+            gerrit_url: None,
+            score: Some(Score::default()),
+            report: Some(json!({"k": "v"})),
+            errors: None,
+            linter_version: Some("1.0.0".to_string()),
+            environment: Some(json!({"os": "linux", "arch": "x86_64"})),
+            updated_at: 1645692042,
+        }
+    }
+
+    fn unpack(tarball: &[u8]) -> HashMap<String, String> {
+        let mut archive = Archive::new(GzDecoder::new(tarball));
+        archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let mut entry = entry.unwrap();
+                let path = entry.path().unwrap().to_string_lossy().to_string();
+                let mut content = String::new();
+                entry.read_to_string(&mut content).unwrap();
+                (path, content)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn build_bundle_includes_all_files() {
+        let bundle = repository_bundle();
+        let files = unpack(&build(&bundle, &Weights::default()).unwrap());
+
+        let mut paths: Vec<&String> = files.keys().collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "artifact-hub-hub/config.json",
+                "artifact-hub-hub/manifest.json",
+                "artifact-hub-hub/replay.sh",
+                "artifact-hub-hub/report.json",
+                "artifact-hub-hub/score.json",
+            ]
+        );
+        let manifest: Value =
+            serde_json::from_str(&files["artifact-hub-hub/manifest.json"]).unwrap();
+        assert_eq!(manifest["linter_version"], "1.0.0");
+        assert_eq!(
+            manifest["repository"]["digest"],
+            "653b5219d16a2e5be274a7fb765916789ae68fbb"
+        );
+        assert!(files["artifact-hub-hub/replay.sh"].contains(
+            "clomonitor-linter --path repository --url 'https://github.com/artifacthub/hub' --check-set code --check-set community --disable-check recent_release --provider github --container-image ghcr.io/artifacthub/hub --format json"
+        ));
+    }
+
+    #[test]
+    fn build_bundle_without_report() {
+        let bundle = RepositoryBundle {
+            digest: None,
+            score: None,
+            report: None,
+            errors: Some("error linting repository".to_string()),
+            ..repository_bundle()
+        };
+        let files = unpack(&build(&bundle, &Weights::default()).unwrap());
+
+        assert_eq!(files.len(), 3);
+        assert!(files["artifact-hub-hub/replay.sh"].contains("Revision not available"));
+    }
+}
@@ -0,0 +1,192 @@
+use anyhow::{format_err, Context, Result};
+use clomonitor_core::net::RestrictedClient;
+use reqwest::{StatusCode, Url};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Placeholder in the data url replaced with the foundation's data ref.
+const DATA_REF_PLACEHOLDER: &str = "{ref}";
+
+/// Maximum time that can take fetching a foundation's data file.
+const DATA_FILE_FETCH_TIMEOUT: u64 = 30;
+
+/// Manifest listing the data files a foundation's data has been split across
+/// (same format supported by the registrar).
+#[derive(Debug, Clone, Deserialize)]
+struct DataManifest {
+    files: Vec<String>,
+}
+
+/// Check that the foundation's data file (or all the data files listed in the
+/// manifest it points to) can be fetched and parsed, returning the number of
+/// projects found. The ref placeholder in the url, if any, is replaced with
+/// the data ref provided. Only the urls allowed by the host policy of the
+/// restricted client given are fetched.
+pub(crate) async fn validate(
+    data_url: &str,
+    data_ref: Option<&str>,
+    restricted_client: &RestrictedClient,
+) -> Result<usize> {
+    let data_url = resolve_data_url(data_url, data_ref)?;
+    let data = fetch(restricted_client, &data_url).await?;
+    let Ok(manifest) = serde_yaml::from_str::<DataManifest>(&data) else {
+        return parse(&data);
+    };
+    if manifest.files.is_empty() {
+        return Err(format_err!("data manifest does not list any data files"));
+    }
+
+    let base_url = Url::parse(&data_url).context("invalid data url")?;
+    let mut projects = 0;
+    for file in &manifest.files {
+        let file_url = base_url
+            .join(file)
+            .context(format!("invalid data file url {file}"))?;
+        let data = fetch(restricted_client, file_url.as_str())
+            .await
+            .context(format!("error fetching data file {file_url}"))?;
+        projects += parse(&data).context(format!("error parsing data file {file_url}"))?;
+    }
+    Ok(projects)
+}
+
+/// Return the data url provided, replacing the ref placeholder with the data
+/// ref when present.
+fn resolve_data_url(data_url: &str, data_ref: Option<&str>) -> Result<String> {
+    if !data_url.contains(DATA_REF_PLACEHOLDER) {
+        return Ok(data_url.to_string());
+    }
+    match data_ref {
+        Some(data_ref) if !data_ref.is_empty() => {
+            Ok(data_url.replace(DATA_REF_PLACEHOLDER, data_ref))
+        }
+        _ => Err(format_err!(
+            "data url contains a ref placeholder but no data ref has been provided"
+        )),
+    }
+}
+
+/// Fetch the data file from the url provided.
+async fn fetch(restricted_client: &RestrictedClient, url: &str) -> Result<String> {
+    let resp = restricted_client
+        .get(url)?
+        .timeout(Duration::from_secs(DATA_FILE_FETCH_TIMEOUT))
+        .send()
+        .await?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code getting data file: {}",
+            resp.status()
+        ));
+    }
+    Ok(resp.text().await?)
+}
+
+/// Parse the data file provided, returning the number of projects in it. The
+/// projects' details are validated by the registrar when processing the file,
+/// so only their names are required here.
+fn parse(data: &str) -> Result<usize> {
+    let entries: Vec<serde_yaml::Value> =
+        serde_yaml::from_str(data).context("invalid data file")?;
+    for (i, entry) in entries.iter().enumerate() {
+        match entry.get("name").and_then(serde_yaml::Value::as_str) {
+            Some(name) if !name.trim().is_empty() => {}
+            _ => return Err(format_err!("project {} in data file has no name", i + 1)),
+        }
+    }
+    Ok(entries.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::net::HostPolicy;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn resolve_data_url_with_placeholder() {
+        assert_eq!(
+            resolve_data_url("https://example.com/{ref}/data.yaml", Some("v1.0.0")).unwrap(),
+            "https://example.com/v1.0.0/data.yaml"
+        );
+        assert!(resolve_data_url("https://example.com/{ref}/data.yaml", None).is_err());
+        assert_eq!(
+            resolve_data_url("https://example.com/data.yaml", None).unwrap(),
+            "https://example.com/data.yaml"
+        );
+    }
+
+    #[test]
+    fn parse_data_file() {
+        assert_eq!(parse("- name: project1\n- name: project2\n").unwrap(), 2);
+        assert!(parse("- name: project1\n- display_name: Project 2\n").is_err());
+        assert!(parse("not a list").is_err());
+    }
+
+    #[tokio::test]
+    async fn validate_manifest() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/data/manifest.yaml"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("files:\n  - a.yaml\n  - b.yaml\n"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/data/a.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("- name: project1\n"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/data/b.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("- name: project2\n"))
+            .mount(&server)
+            .await;
+
+        let data_url = format!("{}/data/manifest.yaml", server.uri());
+        assert_eq!(
+            validate(&data_url, None, &RestrictedClient::default())
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_unreachable_data_file() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/data.yaml"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let data_url = format!("{}/data.yaml", server.uri());
+        assert!(validate(&data_url, None, &RestrictedClient::default())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn validate_data_file_host_denied() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/data.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("- name: project1\n"))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let data_url = format!("{}/data.yaml", server.uri());
+        let restricted_client = RestrictedClient::new(HostPolicy {
+            deny_private_networks: true,
+            ..HostPolicy::default()
+        })
+        .unwrap();
+        assert!(validate(&data_url, None, &restricted_client).await.is_err());
+    }
+}
@@ -0,0 +1,1123 @@
+use crate::{
+    auth::{Role, RoleAssignment},
+    bundle::RepositoryBundle,
+    handlers::{
+        FoundationFeed, FoundationInput, FoundationReport, MaturityGatesReport, NewFoundationInput,
+        ProjectRatingChange, ProjectWebhookInput, RepositoryReportMDTemplate,
+    },
+    ratelimit::ApiKey,
+    views::{Day, ProjectId, Total},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use clomonitor_core::{
+    linter::Report,
+    score::{Score, Weights},
+};
+use deadpool_postgres::Pool;
+#[cfg(test)]
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use time::Date;
+use tokio_postgres::types::Json;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+mod sqlite;
+pub(crate) use sqlite::{SqliteDB, UnsupportedError};
+
+// Lock key used when updating the projects views in the database.
+const LOCK_KEY_UPDATE_PROJECTS_VIEWS: i64 = 1;
+
+/// Sections whose scores can be used to sort the projects search results.
+pub(crate) const SEARCH_SORT_SECTIONS: [&str; 7] = [
+    "documentation",
+    "license",
+    "best_practices",
+    "security",
+    "legal",
+    "artifacts",
+    "organization",
+];
+
+/// Type alias to represent a DB trait object.
+pub(crate) type DynDB = Arc<dyn DB + Send + Sync>;
+
+/// Type alias to represent a json string.
+type JsonString = String;
+
+/// Type alias to represent a counter value.
+type Count = i64;
+
+/// Trait that defines some operations a DB implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub(crate) trait DB {
+    /// Acknowledge the exemption of the check provided on behalf of one of
+    /// the project's maintainers. Returns false if the project was not found.
+    async fn acknowledge_exemption(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        check_id: &str,
+        subject: &str,
+    ) -> Result<bool>;
+
+    /// Register a new API key, returning its id.
+    async fn add_api_key(
+        &self,
+        name: &str,
+        key_hash: &str,
+        rate_limit: Option<u32>,
+    ) -> Result<Uuid>;
+
+    /// Add the role assignment provided, returning its id. None is returned
+    /// when the foundation or project the role is scoped to was not found.
+    async fn add_role_assignment(&self, assignment: &RoleAssignment) -> Result<Option<Uuid>>;
+
+    /// Register a webhook for the project provided, returning its id. None is
+    /// returned when the project was not found.
+    async fn add_project_webhook(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        created_by: &str,
+        input: &ProjectWebhookInput,
+    ) -> Result<Option<Uuid>>;
+
+    /// Get the API key matching the hash provided, provided it has not been
+    /// revoked.
+    async fn api_key(&self, key_hash: &str) -> Result<Option<ApiKey>>;
+
+    /// Get all API keys in json format.
+    async fn api_keys(&self) -> Result<JsonString>;
+
+    /// Apply the delta provided (obtained from the primary instance) to the
+    /// mirror's database.
+    async fn apply_sync_delta(&self, delta: &str) -> Result<()>;
+
+    /// Get the discrepancies found by the latest consistency check in json
+    /// format.
+    async fn consistency_issues(&self) -> Result<JsonString>;
+
+    /// Get the dates of the public datasets published by the archiver.
+    async fn datasets(&self) -> Result<Vec<Date>>;
+
+    /// Delete the foundation provided. Returns false if the foundation was not
+    /// found, or None if it cannot be deleted as it still has projects
+    /// registered.
+    async fn delete_foundation(&self, foundation: &str) -> Result<Option<bool>>;
+
+    /// Delete the acknowledgement of the exemption of the check provided.
+    /// Returns false if the acknowledgement was not found.
+    async fn delete_exemption_acknowledgement(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        check_id: &str,
+    ) -> Result<bool>;
+
+    /// Delete the project's webhook provided. Returns false if the webhook was
+    /// not found in the project.
+    async fn delete_project_webhook(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        project_webhook_id: &Uuid,
+    ) -> Result<bool>;
+
+    /// Delete the role assignment provided. Returns false if the role
+    /// assignment was not found.
+    async fn delete_role_assignment(&self, role_assignment_id: &Uuid) -> Result<bool>;
+
+    /// Get the checks exemptions acknowledged by the project's maintainers, in
+    /// json format.
+    async fn exemption_acknowledgements(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>>;
+
+    /// Get foundation's recent rating changes and new projects.
+    async fn foundation_feed(&self, foundation: &str) -> Result<Option<FoundationFeed>>;
+
+    /// Get the report evaluating the foundation's projects against its
+    /// maturity gates.
+    async fn foundation_maturity_gates(
+        &self,
+        foundation: &str,
+    ) -> Result<Option<MaturityGatesReport>>;
+
+    /// Get foundation's report for the period provided.
+    async fn foundation_report(
+        &self,
+        foundation: &str,
+        from: &Date,
+        to: &Date,
+    ) -> Result<Option<FoundationReport>>;
+
+    /// Get the score weights overrides defined for the foundation provided.
+    async fn foundation_weights(&self, foundation: &str) -> Result<Option<Weights>>;
+
+    /// Get the point in time (as a unix timestamp) the mirror's database has
+    /// been synced until.
+    async fn mirror_synced_until(&self) -> Result<Option<i64>>;
+
+    /// Get the history of the check provided in the project's repositories
+    /// (when it started or stopped passing), in json format.
+    async fn project_check_history(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        check_id: &str,
+    ) -> Result<Option<JsonString>>;
+
+    /// Get project's data in json format.
+    async fn project_data(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>>;
+
+    /// Get project's rating.
+    async fn project_rating(&self, foundation: &str, project_name: &str) -> Result<Option<String>>;
+
+    /// Get project's current rating along with the previous rating it had.
+    async fn project_rating_change(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<ProjectRatingChange>>;
+
+    /// Get the reports of the project's repositories, along with the
+    /// repositories names and weights.
+    async fn project_reports(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Vec<(String, f64, Report)>>;
+
+    /// Get project's score.
+    async fn project_score(&self, foundation: &str, project_name: &str) -> Result<Option<Score>>;
+
+    /// Get project's snapshot data.
+    async fn project_snapshot(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        date: &Date,
+    ) -> Result<Option<JsonString>>;
+
+    /// Get the errors found while tracking the project's repositories, in
+    /// json format.
+    async fn project_tracking_errors(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>>;
+
+    /// Get the webhooks registered for the project, in json format.
+    async fn project_webhooks(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>>;
+
+    /// Get the information needed to build a repository's reproducibility
+    /// bundle.
+    async fn repository_bundle(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        repository_name: &str,
+    ) -> Result<Option<RepositoryBundle>>;
+
+    /// Get all repositories including checks details.
+    async fn repositories_with_checks(&self) -> Result<String>;
+
+    /// Get repository's data, including its report, in json format.
+    async fn repository_data(&self, repository_id: &Uuid) -> Result<Option<JsonString>>;
+
+    /// Get some repository info to prepare report in markdown format.
+    async fn repository_report_md(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        repository_name: &str,
+    ) -> Result<Option<RepositoryReportMDTemplate>>;
+
+    /// Register the foundation provided. Returns false if a foundation with
+    /// the same id already exists.
+    async fn register_foundation(&self, foundation: &NewFoundationInput) -> Result<bool>;
+
+    /// Request processing the foundation's data file on demand. Returns false
+    /// if the foundation was not found.
+    async fn request_foundation_registration(&self, foundation: &str) -> Result<bool>;
+
+    /// Request tracking the repositories of the foundation's projects provided
+    /// on demand (all the foundation's projects when none are provided).
+    /// Returns the names of the projects whose tracking has been requested.
+    async fn request_projects_tracking(
+        &self,
+        foundation: &str,
+        project_names: Option<&[String]>,
+    ) -> Result<Vec<String>>;
+
+    /// Revoke the API key provided. Returns false if the API key was not found
+    /// or it had already been revoked.
+    async fn revoke_api_key(&self, api_key_id: &Uuid) -> Result<bool>;
+
+    /// Get all role assignments in json format.
+    async fn role_assignments(&self) -> Result<JsonString>;
+
+    /// Search projects that match the criteria provided.
+    async fn search_projects(&self, input: &SearchProjectsInput) -> Result<SearchProjectsOutput>;
+
+    /// Search the repositories matching the url provided, returning their
+    /// data in json format.
+    async fn search_repositories(&self, url: &str) -> Result<JsonString>;
+
+    /// Get some general stats.
+    async fn stats(&self, foundation: Option<&str>) -> Result<JsonString>;
+
+    /// Get checks adoption stats.
+    async fn stats_checks(&self, foundation: Option<&str>) -> Result<JsonString>;
+
+    /// Get the top rated projects of each maturity level.
+    async fn stats_leaderboard(&self, foundation: Option<&str>, limit: i32) -> Result<JsonString>;
+
+    /// Get the projects whose score increased the most during the period
+    /// provided (in days).
+    async fn stats_most_improved(
+        &self,
+        foundation: Option<&str>,
+        period: i32,
+        limit: i32,
+    ) -> Result<JsonString>;
+
+    /// Get stats' snapshot data.
+    async fn stats_snapshot(
+        &self,
+        foundation: Option<&str>,
+        date: &Date,
+    ) -> Result<Option<JsonString>>;
+
+    /// Get the roles assigned to the subject provided.
+    async fn subject_roles(&self, subject: &str) -> Result<Vec<Role>>;
+
+    /// Get the projects similar to the text provided (up to the limit given)
+    /// in json format, ranked by similarity.
+    async fn suggest_projects(&self, text: &str, limit: i32) -> Result<JsonString>;
+
+    /// Get the changes since the unix timestamp provided in json format, so
+    /// that they can be applied to a mirror's database.
+    async fn sync_delta(&self, since: i64) -> Result<JsonString>;
+
+    /// Get the progress of the latest tracker run.
+    async fn tracker_run_progress(&self) -> Result<Option<JsonString>>;
+
+    /// Update the details of the foundation provided. Returns false if the
+    /// foundation was not found.
+    async fn update_foundation(&self, foundation: &str, input: &FoundationInput) -> Result<bool>;
+
+    /// Update the ref used to build the foundation's data file url. Returns
+    /// false if the foundation was not found.
+    async fn update_foundation_data_ref(
+        &self,
+        foundation: &str,
+        data_ref: Option<&str>,
+    ) -> Result<bool>;
+
+    /// Update the number of views of the projects provided.
+    async fn update_projects_views(&self, data: Vec<(ProjectId, Day, Total)>) -> Result<()>;
+}
+
+/// DB implementation backed by PostgreSQL.
+pub(crate) struct PgDB {
+    pool: Pool,
+}
+
+impl PgDB {
+    /// Create a new PgDB instance.
+    pub(crate) fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DB for PgDB {
+    async fn acknowledge_exemption(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        check_id: &str,
+        subject: &str,
+    ) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one(
+                "select acknowledge_exemption($1::text, $2::text, $3::text, $4::text)",
+                &[&foundation, &project_name, &check_id, &subject],
+            )
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn add_api_key(
+        &self,
+        name: &str,
+        key_hash: &str,
+        rate_limit: Option<u32>,
+    ) -> Result<Uuid> {
+        let db = self.pool.get().await?;
+        let input = json!({
+            "name": name,
+            "key_hash": key_hash,
+            "rate_limit": rate_limit,
+        });
+        let api_key_id = db
+            .query_one("select add_api_key($1::jsonb)", &[&Json(input)])
+            .await?
+            .get(0);
+        Ok(api_key_id)
+    }
+
+    async fn add_role_assignment(&self, assignment: &RoleAssignment) -> Result<Option<Uuid>> {
+        let db = self.pool.get().await?;
+        let role_assignment_id: Option<Uuid> = db
+            .query_one(
+                "select add_role_assignment($1::jsonb)",
+                &[&Json(assignment)],
+            )
+            .await?
+            .get(0);
+        Ok(role_assignment_id)
+    }
+
+    async fn add_project_webhook(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        created_by: &str,
+        input: &ProjectWebhookInput,
+    ) -> Result<Option<Uuid>> {
+        let db = self.pool.get().await?;
+        let project_webhook_id: Option<Uuid> = db
+            .query_one(
+                "select add_project_webhook($1::text, $2::text, $3::text, $4::jsonb)",
+                &[&foundation, &project_name, &created_by, &Json(input)],
+            )
+            .await?
+            .get(0);
+        Ok(project_webhook_id)
+    }
+
+    async fn api_key(&self, key_hash: &str) -> Result<Option<ApiKey>> {
+        let db = self.pool.get().await?;
+        let api_key = db
+            .query_one("select get_api_key($1::text)::jsonb", &[&key_hash])
+            .await?
+            .get::<_, Option<Json<ApiKey>>>(0)
+            .map(|Json(api_key)| api_key);
+        Ok(api_key)
+    }
+
+    async fn api_keys(&self) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let api_keys = db
+            .query_one("select get_api_keys()::text", &[])
+            .await?
+            .get(0);
+        Ok(api_keys)
+    }
+
+    async fn apply_sync_delta(&self, delta: &str) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute("select apply_sync_delta($1::text::jsonb)", &[&delta])
+            .await?;
+        Ok(())
+    }
+
+    async fn consistency_issues(&self) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let consistency_issues = db
+            .query_one("select get_consistency_issues()::text", &[])
+            .await?
+            .get(0);
+        Ok(consistency_issues)
+    }
+
+    async fn datasets(&self) -> Result<Vec<Date>> {
+        let db = self.pool.get().await?;
+        let datasets = db
+            .query("select date from dataset order by date desc", &[])
+            .await?
+            .iter()
+            .map(|row| row.get("date"))
+            .collect();
+        Ok(datasets)
+    }
+
+    async fn delete_foundation(&self, foundation: &str) -> Result<Option<bool>> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one("select delete_foundation($1::text)", &[&foundation])
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn delete_exemption_acknowledgement(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        check_id: &str,
+    ) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one(
+                "select delete_exemption_acknowledgement($1::text, $2::text, $3::text)",
+                &[&foundation, &project_name, &check_id],
+            )
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn delete_project_webhook(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        project_webhook_id: &Uuid,
+    ) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one(
+                "select delete_project_webhook($1::text, $2::text, $3::uuid)",
+                &[&foundation, &project_name, &project_webhook_id],
+            )
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn delete_role_assignment(&self, role_assignment_id: &Uuid) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one(
+                "select delete_role_assignment($1::uuid)",
+                &[&role_assignment_id],
+            )
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn exemption_acknowledgements(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let acknowledgements: Option<JsonString> = db
+            .query_one(
+                "select get_exemption_acknowledgements($1::text, $2::text)::text",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .get(0);
+        Ok(acknowledgements)
+    }
+
+    async fn foundation_feed(&self, foundation: &str) -> Result<Option<FoundationFeed>> {
+        let db = self.pool.get().await?;
+        let feed = db
+            .query_one("select get_foundation_feed($1::text)", &[&foundation])
+            .await?
+            .get::<_, Option<Json<FoundationFeed>>>(0)
+            .map(|Json(feed)| feed);
+        Ok(feed)
+    }
+
+    async fn foundation_maturity_gates(
+        &self,
+        foundation: &str,
+    ) -> Result<Option<MaturityGatesReport>> {
+        let db = self.pool.get().await?;
+        let report = db
+            .query_one(
+                "select get_foundation_maturity_gates($1::text)",
+                &[&foundation],
+            )
+            .await?
+            .get::<_, Option<Json<MaturityGatesReport>>>(0)
+            .map(|Json(report)| report);
+        Ok(report)
+    }
+
+    async fn foundation_report(
+        &self,
+        foundation: &str,
+        from: &Date,
+        to: &Date,
+    ) -> Result<Option<FoundationReport>> {
+        let db = self.pool.get().await?;
+        let report = db
+            .query_one(
+                "select get_foundation_report($1::text, $2::date, $3::date)",
+                &[&foundation, &from, &to],
+            )
+            .await?
+            .get::<_, Option<Json<FoundationReport>>>(0)
+            .map(|Json(report)| report);
+        Ok(report)
+    }
+
+    async fn foundation_weights(&self, foundation: &str) -> Result<Option<Weights>> {
+        let db = self.pool.get().await?;
+        let weights = db
+            .query_one(
+                "select get_foundation_weights($1::text)::jsonb",
+                &[&foundation],
+            )
+            .await?
+            .get::<_, Option<Json<Weights>>>(0)
+            .map(|Json(weights)| weights);
+        Ok(weights)
+    }
+
+    async fn mirror_synced_until(&self) -> Result<Option<i64>> {
+        let db = self.pool.get().await?;
+        let synced_until = db
+            .query_opt(
+                "select floor(extract(epoch from synced_until))::bigint from mirror_sync",
+                &[],
+            )
+            .await?
+            .map(|row| row.get(0));
+        Ok(synced_until)
+    }
+
+    async fn project_check_history(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        check_id: &str,
+    ) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let history: Option<JsonString> = db
+            .query_one(
+                "select get_project_check_history($1::text, $2::text, $3::text)::text",
+                &[&foundation, &project_name, &check_id],
+            )
+            .await?
+            .get(0);
+        Ok(history)
+    }
+
+    async fn project_data(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let project: Option<JsonString> = db
+            .query_one(
+                "select get_project_by_name($1::text, $2::text)::text",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .get(0);
+        Ok(project)
+    }
+
+    async fn project_rating(&self, foundation: &str, project_name: &str) -> Result<Option<String>> {
+        let db = self.pool.get().await?;
+        let rating = db
+            .query_opt(
+                "
+                select rating
+                from project p
+                where p.foundation_id = $1::text
+                and p.name = $2::text
+                ",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .and_then(|row| row.get("rating"));
+        Ok(rating)
+    }
+
+    async fn project_rating_change(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<ProjectRatingChange>> {
+        let db = self.pool.get().await?;
+        let rating_change = db
+            .query_opt(
+                "
+                select
+                    coalesce(p.display_name, p.name) as name,
+                    p.logo_url,
+                    p.rating,
+                    (
+                        select s.data->>'rating'
+                        from project_snapshot s
+                        where s.project_id = p.project_id
+                        and s.data->>'rating' is not null
+                        and s.data->>'rating' <> p.rating
+                        order by s.date desc
+                        limit 1
+                    ) as previous_rating
+                from project p
+                where p.foundation_id = $1::text
+                and p.name = $2::text
+                ",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .map(|row| ProjectRatingChange {
+                name: row.get("name"),
+                logo_url: row.get("logo_url"),
+                rating: row.get("rating"),
+                previous_rating: row.get("previous_rating"),
+            });
+        Ok(rating_change)
+    }
+
+    async fn project_reports(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Vec<(String, f64, Report)>> {
+        let db = self.pool.get().await?;
+        let reports = db
+            .query(
+                "
+                select r.name, r.weight, rp.data
+                from project p
+                join repository r using (project_id)
+                join report rp using (repository_id)
+                where p.foundation_id = $1::text
+                and p.name = $2::text
+                and rp.data is not null
+                order by r.name asc
+                ",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .iter()
+            .map(|row| {
+                let weight: f32 = row.get("weight");
+                let Json(report): Json<Report> = row.get("data");
+                (row.get("name"), weight as f64, report)
+            })
+            .collect();
+        Ok(reports)
+    }
+
+    async fn project_score(&self, foundation: &str, project_name: &str) -> Result<Option<Score>> {
+        let db = self.pool.get().await?;
+        let score = db
+            .query_opt(
+                "
+                select score
+                from project p
+                where p.foundation_id = $1::text
+                and p.name = $2::text
+                ",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .and_then(|row| {
+                let score: Option<Json<Score>> = row.get("score");
+                score.map(|Json(score)| score)
+            });
+        Ok(score)
+    }
+
+    async fn project_snapshot(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        date: &Date,
+    ) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let snapshot = db
+            .query_opt(
+                "
+                select data::text
+                from project_snapshot s
+                join project p using (project_id)
+                where p.foundation_id = $1
+                and p.name = $2
+                and s.date = $3
+                ",
+                &[&foundation, &project_name, &date],
+            )
+            .await?
+            .and_then(|row| row.get("data"));
+        Ok(snapshot)
+    }
+
+    async fn project_tracking_errors(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let errors: Option<JsonString> = db
+            .query_one(
+                "select get_project_tracking_errors($1::text, $2::text)::text",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .get(0);
+        Ok(errors)
+    }
+
+    async fn project_webhooks(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let webhooks: Option<JsonString> = db
+            .query_one(
+                "select get_project_webhooks($1::text, $2::text)::text",
+                &[&foundation, &project_name],
+            )
+            .await?
+            .get(0);
+        Ok(webhooks)
+    }
+
+    async fn repository_bundle(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        repository_name: &str,
+    ) -> Result<Option<RepositoryBundle>> {
+        let db = self.pool.get().await?;
+        let bundle = db
+            .query_one(
+                "select get_repository_bundle($1::text, $2::text, $3::text)",
+                &[&foundation, &project_name, &repository_name],
+            )
+            .await?
+            .get::<_, Option<Json<RepositoryBundle>>>(0)
+            .map(|Json(bundle)| bundle);
+        Ok(bundle)
+    }
+
+    async fn repositories_with_checks(&self) -> Result<String> {
+        let db = self.pool.get().await?;
+        let repos = db
+            .query("select get_repositories_with_checks()", &[])
+            .await?
+            .iter()
+            .map(|row| format!("{}\n", row.get::<_, String>(0)))
+            .collect();
+        Ok(repos)
+    }
+
+    async fn repository_data(&self, repository_id: &Uuid) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let repository: Option<JsonString> = db
+            .query_one(
+                "select get_repository_by_id($1::uuid)::text",
+                &[&repository_id],
+            )
+            .await?
+            .get(0);
+        Ok(repository)
+    }
+
+    async fn repository_report_md(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        repository_name: &str,
+    ) -> Result<Option<RepositoryReportMDTemplate>> {
+        let db = self.pool.get().await?;
+        let report_md = db
+            .query_one(
+                "select get_repository_report($1::text, $2::text, $3::text)",
+                &[&foundation, &project_name, &repository_name],
+            )
+            .await?
+            .get::<_, Option<Json<RepositoryReportMDTemplate>>>(0)
+            .map(|Json(report_md)| report_md);
+        Ok(report_md)
+    }
+
+    async fn request_foundation_registration(&self, foundation: &str) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one(
+                "select request_foundation_registration($1::text)",
+                &[&foundation],
+            )
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn register_foundation(&self, foundation: &NewFoundationInput) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let registered = db
+            .query_one(
+                "select register_foundation($1::jsonb)",
+                &[&Json(foundation)],
+            )
+            .await?
+            .get(0);
+        Ok(registered)
+    }
+
+    async fn request_projects_tracking(
+        &self,
+        foundation: &str,
+        project_names: Option<&[String]>,
+    ) -> Result<Vec<String>> {
+        let db = self.pool.get().await?;
+        let projects = db
+            .query_one(
+                "select request_projects_tracking($1::text, $2::text[])",
+                &[&foundation, &project_names],
+            )
+            .await?
+            .get(0);
+        Ok(projects)
+    }
+
+    async fn revoke_api_key(&self, api_key_id: &Uuid) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one("select revoke_api_key($1::uuid)", &[&api_key_id])
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn role_assignments(&self) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let role_assignments = db
+            .query_one("select get_role_assignments()::text", &[])
+            .await?
+            .get(0);
+        Ok(role_assignments)
+    }
+
+    async fn search_projects(&self, input: &SearchProjectsInput) -> Result<SearchProjectsOutput> {
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "
+                select total_count, next_cursor, projects::text, facets::text
+                from search_projects($1::jsonb)
+                ",
+                &[&Json(input)],
+            )
+            .await?;
+        Ok(SearchProjectsOutput {
+            count: row.get("total_count"),
+            next_cursor: row.get("next_cursor"),
+            projects: row.get("projects"),
+            facets: row.get("facets"),
+        })
+    }
+
+    async fn search_repositories(&self, url: &str) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let repositories = db
+            .query_one("select search_repositories_by_url($1::text)::text", &[&url])
+            .await?
+            .get(0);
+        Ok(repositories)
+    }
+
+    async fn stats(&self, foundation: Option<&str>) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let stats = db
+            .query_one("select get_stats($1::text)::text", &[&foundation])
+            .await?
+            .get(0);
+        Ok(stats)
+    }
+
+    async fn stats_checks(&self, foundation: Option<&str>) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let stats = db
+            .query_one("select get_checks_stats($1::text)::text", &[&foundation])
+            .await?
+            .get(0);
+        Ok(stats)
+    }
+
+    async fn stats_leaderboard(&self, foundation: Option<&str>, limit: i32) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let stats = db
+            .query_one(
+                "select get_projects_leaderboard($1::text, $2::int)::text",
+                &[&foundation, &limit],
+            )
+            .await?
+            .get(0);
+        Ok(stats)
+    }
+
+    async fn stats_most_improved(
+        &self,
+        foundation: Option<&str>,
+        period: i32,
+        limit: i32,
+    ) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let stats = db
+            .query_one(
+                "select get_most_improved_projects($1::text, $2::int, $3::int)::text",
+                &[&foundation, &period, &limit],
+            )
+            .await?
+            .get(0);
+        Ok(stats)
+    }
+
+    async fn stats_snapshot(
+        &self,
+        foundation: Option<&str>,
+        date: &Date,
+    ) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let row = match foundation {
+            Some(foundation) => {
+                db.query_opt(
+                    "select data::text from stats_snapshot where foundation_id = $1 and date = $2",
+                    &[&foundation, &date],
+                )
+                .await?
+            }
+            None => db.query_opt(
+                "select data::text from stats_snapshot where foundation_id is null and date = $1",
+                &[&date],
+            ).await?,
+        };
+        let snapshot = row.and_then(|row| row.get("data"));
+        Ok(snapshot)
+    }
+
+    async fn subject_roles(&self, subject: &str) -> Result<Vec<Role>> {
+        let db = self.pool.get().await?;
+        let Json(roles) = db
+            .query_one("select get_subject_roles($1::text)::jsonb", &[&subject])
+            .await?
+            .get::<_, Json<Vec<Role>>>(0);
+        Ok(roles)
+    }
+
+    async fn suggest_projects(&self, text: &str, limit: i32) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let projects = db
+            .query_one(
+                "select suggest_projects($1::text, $2::int)::text",
+                &[&text, &limit],
+            )
+            .await?
+            .get(0);
+        Ok(projects)
+    }
+
+    async fn sync_delta(&self, since: i64) -> Result<JsonString> {
+        let db = self.pool.get().await?;
+        let delta = db
+            .query_one(
+                "select get_sync_delta(to_timestamp($1::bigint))::text",
+                &[&since],
+            )
+            .await?
+            .get(0);
+        Ok(delta)
+    }
+
+    async fn tracker_run_progress(&self) -> Result<Option<JsonString>> {
+        let db = self.pool.get().await?;
+        let progress: Option<JsonString> = db
+            .query_one("select get_tracker_run_progress()::text", &[])
+            .await?
+            .get(0);
+        Ok(progress)
+    }
+
+    async fn update_foundation(&self, foundation: &str, input: &FoundationInput) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one(
+                "select update_foundation($1::text, $2::jsonb)",
+                &[&foundation, &Json(input)],
+            )
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn update_foundation_data_ref(
+        &self,
+        foundation: &str,
+        data_ref: Option<&str>,
+    ) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let found = db
+            .query_one(
+                "select update_foundation_data_ref($1::text, $2::text)",
+                &[&foundation, &data_ref],
+            )
+            .await?
+            .get(0);
+        Ok(found)
+    }
+
+    async fn update_projects_views(&self, data: Vec<(ProjectId, Day, Total)>) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_projects_views($1::bigint, $2::jsonb)",
+            &[&LOCK_KEY_UPDATE_PROJECTS_VIEWS, &Json(&data)],
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Query input used when searching for projects.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct SearchProjectsInput {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub cursor: Option<String>,
+    pub sort_by: Option<String>,
+    pub sort_direction: Option<String>,
+    pub text: Option<String>,
+    pub foundation: Option<Vec<String>>,
+    pub maturity: Option<Vec<String>>,
+    pub rating: Option<Vec<char>>,
+    pub accepted_from: Option<String>,
+    pub accepted_to: Option<String>,
+    pub passing_check: Option<Vec<String>>,
+    pub not_passing_check: Option<Vec<String>>,
+    pub failing_check: Option<Vec<String>>,
+    pub facets: Option<bool>,
+}
+
+/// Results of a projects search.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct SearchProjectsOutput {
+    pub count: Count,
+    pub next_cursor: Option<String>,
+    pub projects: JsonString,
+    pub facets: JsonString,
+}
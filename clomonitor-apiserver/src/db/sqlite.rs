@@ -0,0 +1,1675 @@
+use super::{
+    Count, JsonString, SearchProjectsInput, SearchProjectsOutput, DB, SEARCH_SORT_SECTIONS,
+};
+use crate::{
+    auth::{Role, RoleAssignment},
+    bundle::RepositoryBundle,
+    handlers::{
+        FoundationFeed, FoundationInput, FoundationReport, MaturityGatesReport, NewFoundationInput,
+        ProjectRatingChange, ProjectWebhookInput, RepositoryReportMDTemplate,
+    },
+    ratelimit::ApiKey,
+    views::{Day, ProjectId, Total},
+};
+use anyhow::{format_err, Context, Error, Result};
+use async_trait::async_trait;
+use clomonitor_core::{
+    linter::Report,
+    score::{Score, Weights},
+};
+use regex::{Regex, RegexBuilder};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+use time::{format_description::well_known::Rfc3339, macros::format_description, Date};
+use uuid::Uuid;
+
+/// Schema of the SQLite database. Foundations and projects are stored in the
+/// same format they are received in the sync deltas.
+const SCHEMA: &str = r#"
+create table if not exists foundation (
+    foundation_id text primary key,
+    data text not null
+);
+
+create table if not exists project (
+    project_id text primary key,
+    foundation_id text not null,
+    name text not null,
+    data text not null,
+    unique (foundation_id, name)
+);
+
+create table if not exists mirror_sync (
+    mirror_sync_id integer primary key check (mirror_sync_id = 1),
+    synced_until integer not null
+);
+"#;
+
+/// Default number of projects returned by a search.
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+/// Minimum similarity with the text provided projects must have to be
+/// suggested (same as the default pg_trgm similarity threshold).
+const SUGGESTIONS_MIN_SIMILARITY: f64 = 0.3;
+
+/// Maturity levels the stats are broken down by.
+const STATS_MATURITY_LEVELS: [&str; 3] = ["graduated", "incubating", "sandbox"];
+
+/// Sections whose average score is included in the stats.
+const STATS_SECTIONS: [&str; 5] = [
+    "documentation",
+    "license",
+    "best_practices",
+    "security",
+    "legal",
+];
+
+/// DB implementation backed by SQLite, meant to be used by mirror instances in
+/// small deployments. The data synced from the primary instance is stored as
+/// received and the responses are built from it, so the operations depending
+/// on data not included in the sync deltas (i.e. snapshots or API keys) are
+/// not supported. Stats are calculated from the projects synced, leaving out
+/// the ones that depend on views or snapshots.
+pub(crate) struct SqliteDB {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteDB {
+    /// Create a new SqliteDB instance using the database file provided,
+    /// setting up its schema if needed.
+    pub(crate) fn new(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)
+            .context(format!("error opening sqlite database {}", path.display()))?;
+        Self::setup(conn)
+    }
+
+    /// Create a new SqliteDB instance backed by an in-memory database.
+    #[cfg(test)]
+    fn new_in_memory() -> Result<Self> {
+        Self::setup(Connection::open_in_memory()?)
+    }
+
+    /// Set up the database schema and create the instance.
+    fn setup(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Run the function provided on a blocking thread with exclusive access to
+    /// the database connection.
+    async fn run<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T> + Send + 'static,
+    {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut conn = conn
+                .lock()
+                .map_err(|_| format_err!("sqlite connection lock poisoned"))?;
+            f(&mut conn)
+        })
+        .await?
+    }
+
+    /// Get the data of the project provided, as received from the primary.
+    async fn project(&self, foundation: &str, project_name: &str) -> Result<Option<Value>> {
+        let foundation = foundation.to_string();
+        let project_name = project_name.to_string();
+        self.run(move |conn| {
+            let data: Option<String> = conn
+                .query_row(
+                    "select data from project where foundation_id = ?1 and name = ?2",
+                    params![foundation, project_name],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            data.map(|data| serde_json::from_str(&data).map_err(Error::from))
+                .transpose()
+        })
+        .await
+    }
+
+    /// Get the data of all the projects, as received from the primary.
+    async fn projects(&self) -> Result<Vec<Value>> {
+        self.run(|conn| {
+            let mut stmt = conn.prepare("select data from project")?;
+            let projects = stmt
+                .query_map([], |row| row.get::<_, String>(0))?
+                .map(|data| Ok(serde_json::from_str(&data?)?))
+                .collect::<Result<Vec<Value>>>()?;
+            Ok(projects)
+        })
+        .await
+    }
+
+    /// Get a repository from the data of the project provided.
+    async fn repository(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        repository_name: &str,
+    ) -> Result<Option<Value>> {
+        let repository = self
+            .project(foundation, project_name)
+            .await?
+            .and_then(|project| {
+                array(&project["repositories"])
+                    .iter()
+                    .find(|r| r["name"].as_str() == Some(repository_name))
+                    .cloned()
+            });
+        Ok(repository)
+    }
+}
+
+#[async_trait]
+impl DB for SqliteDB {
+    async fn acknowledge_exemption(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+        _check_id: &str,
+        _subject: &str,
+    ) -> Result<bool> {
+        Err(unsupported("acknowledging exemptions"))
+    }
+
+    async fn add_api_key(
+        &self,
+        _name: &str,
+        _key_hash: &str,
+        _rate_limit: Option<u32>,
+    ) -> Result<Uuid> {
+        Err(unsupported("adding api keys"))
+    }
+
+    async fn add_role_assignment(&self, _assignment: &RoleAssignment) -> Result<Option<Uuid>> {
+        Err(unsupported("adding role assignments"))
+    }
+
+    async fn add_project_webhook(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+        _created_by: &str,
+        _input: &ProjectWebhookInput,
+    ) -> Result<Option<Uuid>> {
+        Err(unsupported("adding project webhooks"))
+    }
+
+    async fn api_key(&self, _key_hash: &str) -> Result<Option<ApiKey>> {
+        // API keys are not synced from the primary instance
+        Ok(None)
+    }
+
+    async fn api_keys(&self) -> Result<JsonString> {
+        Ok("[]".to_string())
+    }
+
+    async fn apply_sync_delta(&self, delta: &str) -> Result<()> {
+        let delta: Value = serde_json::from_str(delta)?;
+        self.run(move |conn| {
+            let tx = conn.transaction()?;
+
+            // Foundations
+            for foundation in array(&delta["foundations"]) {
+                tx.execute(
+                    "
+                    insert into foundation (foundation_id, data) values (?1, ?2)
+                    on conflict (foundation_id) do update set data = excluded.data
+                    ",
+                    params![
+                        str_field(foundation, "foundation_id")?,
+                        foundation.to_string()
+                    ],
+                )?;
+            }
+
+            // Projects removed
+            for project in array(&delta["removed_projects"]) {
+                tx.execute(
+                    "delete from project where foundation_id = ?1 and name = ?2",
+                    params![
+                        str_field(project, "foundation_id")?,
+                        str_field(project, "name")?
+                    ],
+                )?;
+            }
+
+            // Projects added or updated (a project registered again gets a new
+            // id, so any previous project using its name is removed first)
+            for project in array(&delta["projects"]) {
+                let project_id = str_field(project, "project_id")?;
+                let foundation_id = str_field(project, "foundation_id")?;
+                let name = str_field(project, "name")?;
+                tx.execute(
+                    "
+                    delete from project
+                    where foundation_id = ?1 and name = ?2 and project_id <> ?3
+                    ",
+                    params![foundation_id, name, project_id],
+                )?;
+                tx.execute(
+                    "
+                    insert into project (project_id, foundation_id, name, data)
+                    values (?1, ?2, ?3, ?4)
+                    on conflict (project_id) do update set
+                        foundation_id = excluded.foundation_id,
+                        name = excluded.name,
+                        data = excluded.data
+                    ",
+                    params![project_id, foundation_id, name, project.to_string()],
+                )?;
+            }
+
+            // Point in time the database has been synced until
+            let until = delta["until"]
+                .as_f64()
+                .ok_or_else(|| format_err!("until field not found in delta"))?;
+            tx.execute(
+                "
+                insert into mirror_sync (mirror_sync_id, synced_until) values (1, ?1)
+                on conflict (mirror_sync_id) do update set synced_until = excluded.synced_until
+                ",
+                params![until as i64],
+            )?;
+
+            tx.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn consistency_issues(&self) -> Result<JsonString> {
+        Err(unsupported("consistency checks"))
+    }
+
+    async fn datasets(&self) -> Result<Vec<Date>> {
+        Err(unsupported("datasets"))
+    }
+
+    async fn delete_foundation(&self, _foundation: &str) -> Result<Option<bool>> {
+        Err(unsupported("deleting foundations"))
+    }
+
+    async fn delete_exemption_acknowledgement(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+        _check_id: &str,
+    ) -> Result<bool> {
+        Err(unsupported("deleting exemptions acknowledgements"))
+    }
+
+    async fn delete_project_webhook(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+        _project_webhook_id: &Uuid,
+    ) -> Result<bool> {
+        Err(unsupported("deleting project webhooks"))
+    }
+
+    async fn delete_role_assignment(&self, _role_assignment_id: &Uuid) -> Result<bool> {
+        Err(unsupported("deleting role assignments"))
+    }
+
+    async fn exemption_acknowledgements(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        Err(unsupported("exemptions acknowledgements"))
+    }
+
+    async fn foundation_feed(&self, _foundation: &str) -> Result<Option<FoundationFeed>> {
+        Err(unsupported("foundation feeds"))
+    }
+
+    async fn foundation_maturity_gates(
+        &self,
+        _foundation: &str,
+    ) -> Result<Option<MaturityGatesReport>> {
+        Err(unsupported("maturity gates reports"))
+    }
+
+    async fn foundation_report(
+        &self,
+        _foundation: &str,
+        _from: &Date,
+        _to: &Date,
+    ) -> Result<Option<FoundationReport>> {
+        Err(unsupported("foundation reports"))
+    }
+
+    async fn foundation_weights(&self, foundation: &str) -> Result<Option<Weights>> {
+        let foundation = foundation.to_string();
+        let data: Option<String> = self
+            .run(move |conn| {
+                Ok(conn
+                    .query_row(
+                        "select data from foundation where foundation_id = ?1",
+                        params![foundation],
+                        |row| row.get(0),
+                    )
+                    .optional()?)
+            })
+            .await?;
+        let Some(data) = data else {
+            return Ok(None);
+        };
+        let foundation: Value = serde_json::from_str(&data)?;
+        let (mut checks, mut sections) = (Map::new(), Map::new());
+        for weight in array(&foundation["weights"]) {
+            let name = str_field(weight, "name")?.to_string();
+            match weight["kind"].as_str() {
+                Some("check") => checks.insert(name, weight["weight"].clone()),
+                Some("section") => sections.insert(name, weight["weight"].clone()),
+                _ => None,
+            };
+        }
+        let weights = json!({"checks": checks, "sections": sections});
+        Ok(Some(serde_json::from_value(weights)?))
+    }
+
+    async fn mirror_synced_until(&self) -> Result<Option<i64>> {
+        self.run(|conn| {
+            Ok(conn
+                .query_row("select synced_until from mirror_sync", [], |row| row.get(0))
+                .optional()?)
+        })
+        .await
+    }
+
+    async fn project_check_history(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+        _check_id: &str,
+    ) -> Result<Option<JsonString>> {
+        Err(unsupported("checks history"))
+    }
+
+    async fn project_data(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        let data = self
+            .project(foundation, project_name)
+            .await?
+            .filter(|project| !project["score"].is_null())
+            .map(|project| project_json(&project).to_string());
+        Ok(data)
+    }
+
+    async fn project_rating(&self, foundation: &str, project_name: &str) -> Result<Option<String>> {
+        let rating = self
+            .project(foundation, project_name)
+            .await?
+            .and_then(|project| project["rating"].as_str().map(ToString::to_string));
+        Ok(rating)
+    }
+
+    async fn project_rating_change(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Option<ProjectRatingChange>> {
+        // Projects snapshots are not synced, so the previous rating is unknown
+        let rating_change = self
+            .project(foundation, project_name)
+            .await?
+            .map(|project| ProjectRatingChange {
+                name: project["display_name"]
+                    .as_str()
+                    .or_else(|| project["name"].as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                logo_url: project["logo_url"].as_str().map(ToString::to_string),
+                rating: project["rating"].as_str().map(ToString::to_string),
+                previous_rating: None,
+            });
+        Ok(rating_change)
+    }
+
+    async fn project_reports(
+        &self,
+        foundation: &str,
+        project_name: &str,
+    ) -> Result<Vec<(String, f64, Report)>> {
+        let Some(project) = self.project(foundation, project_name).await? else {
+            return Ok(vec![]);
+        };
+        let mut reports = vec![];
+        for repository in array(&project["repositories"]) {
+            let data = &repository["report"]["data"];
+            if data.is_null() {
+                continue;
+            }
+            let report: Report = serde_json::from_value(data.clone())?;
+            reports.push((
+                str_field(repository, "name")?.to_string(),
+                repository["weight"].as_f64().unwrap_or(1.0),
+                report,
+            ));
+        }
+        reports.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(reports)
+    }
+
+    async fn project_score(&self, foundation: &str, project_name: &str) -> Result<Option<Score>> {
+        let score = match self.project(foundation, project_name).await? {
+            Some(project) if !project["score"].is_null() => {
+                Some(serde_json::from_value(project["score"].clone())?)
+            }
+            _ => None,
+        };
+        Ok(score)
+    }
+
+    async fn project_snapshot(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+        _date: &Date,
+    ) -> Result<Option<JsonString>> {
+        Err(unsupported("projects snapshots"))
+    }
+
+    async fn project_tracking_errors(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        Err(unsupported("tracking errors"))
+    }
+
+    async fn project_webhooks(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+    ) -> Result<Option<JsonString>> {
+        Err(unsupported("project webhooks"))
+    }
+
+    async fn repository_bundle(
+        &self,
+        _foundation: &str,
+        _project_name: &str,
+        _repository_name: &str,
+    ) -> Result<Option<RepositoryBundle>> {
+        Err(unsupported("reproducibility bundles"))
+    }
+
+    async fn repositories_with_checks(&self) -> Result<String> {
+        Err(unsupported("repositories checks exports"))
+    }
+
+    async fn repository_data(&self, repository_id: &Uuid) -> Result<Option<JsonString>> {
+        let repository_id = repository_id.to_string();
+        for project in self.projects().await? {
+            for repository in array(&project["repositories"]) {
+                if repository["repository_id"].as_str() == Some(repository_id.as_str()) {
+                    return Ok(Some(repository_json(repository, &project).to_string()));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    async fn repository_report_md(
+        &self,
+        foundation: &str,
+        project_name: &str,
+        repository_name: &str,
+    ) -> Result<Option<RepositoryReportMDTemplate>> {
+        let Some(repository) = self
+            .repository(foundation, project_name, repository_name)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let report = &repository["report"];
+        if report.is_null() {
+            return Ok(None);
+        }
+        let report_md = strip_nulls(json!({
+            "name": repository["name"],
+            "url": repository["url"],
+            "check_sets": report["check_sets"],
+            "score": repository["score"],
+            "report": report["data"],
+        }));
+        Ok(Some(serde_json::from_value(report_md)?))
+    }
+
+    async fn register_foundation(&self, _foundation: &NewFoundationInput) -> Result<bool> {
+        Err(unsupported("registering foundations"))
+    }
+
+    async fn request_foundation_registration(&self, _foundation: &str) -> Result<bool> {
+        Err(unsupported("foundations registration requests"))
+    }
+
+    async fn request_projects_tracking(
+        &self,
+        _foundation: &str,
+        _project_names: Option<&[String]>,
+    ) -> Result<Vec<String>> {
+        Err(unsupported("projects tracking requests"))
+    }
+
+    async fn revoke_api_key(&self, _api_key_id: &Uuid) -> Result<bool> {
+        Err(unsupported("revoking api keys"))
+    }
+
+    async fn role_assignments(&self) -> Result<JsonString> {
+        Ok("[]".to_string())
+    }
+
+    async fn search_projects(&self, input: &SearchProjectsInput) -> Result<SearchProjectsOutput> {
+        let projects = self.projects().await?;
+        search_projects(&projects, input)
+    }
+
+    async fn search_repositories(&self, url: &str) -> Result<JsonString> {
+        let url = normalize_url(url);
+        let mut matches = vec![];
+        for project in self.projects().await? {
+            for repository in array(&project["repositories"]) {
+                if normalize_url(repository["url"].as_str().unwrap_or_default()) == url {
+                    let key = (
+                        field(&project, "foundation_id").to_string(),
+                        field(&project, "name").to_string(),
+                        field(repository, "name").to_string(),
+                    );
+                    matches.push((key, repository_json(repository, &project)));
+                }
+            }
+        }
+        matches.sort_by(|a, b| a.0.cmp(&b.0));
+        let repositories: Vec<Value> = matches.into_iter().map(|(_, r)| r).collect();
+        Ok(Value::Array(repositories).to_string())
+    }
+
+    async fn stats(&self, foundation: Option<&str>) -> Result<JsonString> {
+        let projects = self.projects().await?;
+        Ok(stats(&projects, foundation).to_string())
+    }
+
+    async fn stats_checks(&self, foundation: Option<&str>) -> Result<JsonString> {
+        let projects = self.projects().await?;
+        Ok(checks_stats(&projects, foundation).to_string())
+    }
+
+    async fn stats_leaderboard(&self, foundation: Option<&str>, limit: i32) -> Result<JsonString> {
+        let projects = self.projects().await?;
+        Ok(leaderboard(&projects, foundation, limit).to_string())
+    }
+
+    async fn stats_most_improved(
+        &self,
+        _foundation: Option<&str>,
+        _period: i32,
+        _limit: i32,
+    ) -> Result<JsonString> {
+        // Projects' snapshots are not synced, so there is no baseline to
+        // compare the current scores with
+        Ok("[]".to_string())
+    }
+
+    async fn stats_snapshot(
+        &self,
+        _foundation: Option<&str>,
+        _date: &Date,
+    ) -> Result<Option<JsonString>> {
+        // Stats snapshots are not synced from the primary instance
+        Ok(None)
+    }
+
+    async fn subject_roles(&self, _subject: &str) -> Result<Vec<Role>> {
+        // Role assignments are not synced from the primary instance
+        Ok(vec![])
+    }
+
+    async fn suggest_projects(&self, text: &str, limit: i32) -> Result<JsonString> {
+        let projects = self.projects().await?;
+        Ok(suggest_projects(&projects, text, limit).to_string())
+    }
+
+    async fn sync_delta(&self, _since: i64) -> Result<JsonString> {
+        Err(unsupported("sync deltas"))
+    }
+
+    async fn tracker_run_progress(&self) -> Result<Option<JsonString>> {
+        Err(unsupported("tracker runs progress"))
+    }
+
+    async fn update_foundation(&self, _foundation: &str, _input: &FoundationInput) -> Result<bool> {
+        Err(unsupported("updating foundations"))
+    }
+
+    async fn update_foundation_data_ref(
+        &self,
+        _foundation: &str,
+        _data_ref: Option<&str>,
+    ) -> Result<bool> {
+        Err(unsupported("updating foundations"))
+    }
+
+    async fn update_projects_views(&self, _data: Vec<(ProjectId, Day, Total)>) -> Result<()> {
+        // Projects views are only tracked by the primary instance
+        Ok(())
+    }
+}
+
+/// Cursor used to paginate the projects search results. It holds the sort key
+/// values of the last project returned (same format used by PostgreSQL).
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    sort_key: f64,
+    name: String,
+    id: String,
+}
+
+/// Search the projects provided using the criteria given, mimicking the
+/// search_projects function in the PostgreSQL database.
+fn search_projects(
+    projects: &[Value],
+    input: &SearchProjectsInput,
+) -> Result<SearchProjectsOutput> {
+    // Prepare sorting and pagination
+    let limit = input.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    let mut offset = input.offset.unwrap_or(0);
+    let desc = input.sort_direction.as_deref() == Some("desc");
+    let sort_score = match input.sort_by.as_deref().unwrap_or("name") {
+        "score" => Some("global"),
+        s if SEARCH_SORT_SECTIONS.contains(&s) => Some(s),
+        _ => None,
+    };
+    let cursor = match &input.cursor {
+        Some(cursor) => {
+            offset = 0;
+            let cursor: Cursor = hex::decode(cursor)
+                .map_err(Error::from)
+                .and_then(|data| Ok(serde_json::from_slice(&data)?))
+                .context("invalid cursor")?;
+            Some(cursor)
+        }
+        None => None,
+    };
+    let text = input
+        .text
+        .as_deref()
+        .map(|text| RegexBuilder::new(text).case_insensitive(true).build())
+        .transpose()?;
+
+    // Filter and sort projects
+    let mut filtered: Vec<(f64, &Value)> = projects
+        .iter()
+        .filter(|p| matches_filters(p, input, text.as_ref()))
+        .map(|p| {
+            let sort_key = match sort_score {
+                Some(section) => p["score"][section].as_f64().unwrap_or(-1.0),
+                None => 0.0,
+            };
+            (sort_key, p)
+        })
+        .collect();
+    filtered.sort_by(|a, b| {
+        let ordering = key_cmp(sort_values(a), sort_values(b));
+        if desc {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    // Facets
+    let mut facets: BTreeMap<&str, BTreeMap<String, Count>> = BTreeMap::new();
+    for kind in ["foundation", "maturity", "rating"] {
+        facets.insert(kind, BTreeMap::new());
+    }
+    for (_, p) in &filtered {
+        for (kind, value) in [
+            ("foundation", &p["foundation_id"]),
+            ("maturity", &p["maturity"]),
+            ("rating", &p["rating"]),
+        ] {
+            if let Some(value) = value.as_str() {
+                *facets
+                    .get_mut(kind)
+                    .expect("facet to be present")
+                    .entry(value.to_string())
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    // Page
+    let count = filtered.len() as Count;
+    let page: Vec<&(f64, &Value)> = filtered
+        .iter()
+        .filter(|entry| match &cursor {
+            None => true,
+            Some(c) => {
+                let ordering = key_cmp(sort_values(entry), (c.sort_key, &c.name, &c.id));
+                if desc {
+                    ordering == Ordering::Less
+                } else {
+                    ordering == Ordering::Greater
+                }
+            }
+        })
+        .skip(offset)
+        .take(limit + 1)
+        .collect();
+    let next_cursor = if limit > 0 && page.len() > limit {
+        let (sort_key, p) = page[limit - 1];
+        let cursor = Cursor {
+            sort_key: *sort_key,
+            name: field(p, "name").to_string(),
+            id: field(p, "project_id").to_string(),
+        };
+        Some(hex::encode(serde_json::to_vec(&cursor)?))
+    } else {
+        None
+    };
+    let projects: Vec<Value> = page
+        .iter()
+        .take(limit)
+        .map(|(_, p)| search_project_json(p))
+        .collect();
+
+    Ok(SearchProjectsOutput {
+        count,
+        next_cursor,
+        projects: Value::Array(projects).to_string(),
+        facets: serde_json::to_string(&facets)?,
+    })
+}
+
+/// Return the values projects are sorted by in the search results (sort key,
+/// name and id).
+fn sort_values<'a>(entry: &(f64, &'a Value)) -> (f64, &'a str, &'a str) {
+    let (sort_key, p) = *entry;
+    (sort_key, field(p, "name"), field(p, "project_id"))
+}
+
+/// Compare the sort values of two projects.
+fn key_cmp((k1, n1, id1): (f64, &str, &str), (k2, n2, id2): (f64, &str, &str)) -> Ordering {
+    k1.partial_cmp(&k2)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| n1.cmp(n2))
+        .then_with(|| id1.cmp(id2))
+}
+
+/// Check if the project provided matches the search filters.
+fn matches_filters(p: &Value, input: &SearchProjectsInput, text: Option<&Regex>) -> bool {
+    if p["score"].is_null() {
+        return false;
+    }
+    if let Some(text) = text {
+        let matches = |name: &str| p[name].as_str().map_or(false, |v| text.is_match(v));
+        if !matches("name") && !matches("display_name") {
+            return false;
+        }
+    }
+    let one_of = |values: &Option<Vec<String>>, name: &str| match values {
+        Some(values) if !values.is_empty() => {
+            values.iter().any(|v| p[name].as_str() == Some(v.as_str()))
+        }
+        _ => true,
+    };
+    if !one_of(&input.foundation, "foundation_id") || !one_of(&input.maturity, "maturity") {
+        return false;
+    }
+    if let Some(rating) = input.rating.as_ref().filter(|r| !r.is_empty()) {
+        if !rating
+            .iter()
+            .any(|r| p["rating"].as_str() == Some(r.to_string().as_str()))
+        {
+            return false;
+        }
+    }
+
+    // Accepted date
+    let accepted_at = p["accepted_at"].as_str();
+    if let Some(from) = &input.accepted_from {
+        if !accepted_at.map_or(false, |accepted_at| accepted_at >= from.as_str()) {
+            return false;
+        }
+    }
+    if let Some(to) = &input.accepted_to {
+        if !accepted_at.map_or(false, |accepted_at| accepted_at <= to.as_str()) {
+            return false;
+        }
+    }
+
+    // Checks
+    let passed: BTreeSet<&str> = array(&p["passed_checks"])
+        .iter()
+        .filter_map(Value::as_str)
+        .collect();
+    if let Some(checks) = input.passing_check.as_ref().filter(|c| !c.is_empty()) {
+        if !checks.iter().all(|c| passed.contains(c.as_str())) {
+            return false;
+        }
+    }
+    if let Some(checks) = input.not_passing_check.as_ref().filter(|c| !c.is_empty()) {
+        if p["passed_checks"].is_null() || checks.iter().any(|c| passed.contains(c.as_str())) {
+            return false;
+        }
+    }
+    if let Some(checks) = input.failing_check.as_ref().filter(|c| !c.is_empty()) {
+        if checks.iter().any(|c| passed.contains(c.as_str())) {
+            return false;
+        }
+        let project_checks: BTreeSet<&str> = array(&p["repositories"])
+            .iter()
+            .filter_map(|r| r["report"]["data"].as_object())
+            .flat_map(|data| data.values())
+            .filter_map(Value::as_object)
+            .flat_map(|section| section.iter())
+            .filter(|(_, output)| !output.is_null())
+            .map(|(check_id, _)| check_id.as_str())
+            .collect();
+        if !checks.iter().all(|c| project_checks.contains(c.as_str())) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Suggest the projects similar to the text provided, mimicking the
+/// suggest_projects function in the PostgreSQL database. Projects whose name
+/// or display name start with the text are ranked first, followed by the ones
+/// whose name, display name or repositories are the most similar to it.
+fn suggest_projects(projects: &[Value], text: &str, limit: i32) -> Value {
+    let text = normalize_url(text);
+    let text = text
+        .strip_prefix("https://")
+        .or_else(|| text.strip_prefix("http://"))
+        .unwrap_or(&text);
+    if text.is_empty() {
+        return Value::Array(vec![]);
+    }
+
+    let mut suggestions: Vec<(bool, f64, &Value)> = projects
+        .iter()
+        .filter_map(|p| {
+            let name = field(p, "name").to_lowercase();
+            let display_name = field(p, "display_name").to_lowercase();
+            let prefix_match = name.starts_with(text)
+                || (!display_name.is_empty() && display_name.starts_with(text));
+            let mut rank = similarity(&name, text).max(similarity(&display_name, text));
+            for r in array(&p["repositories"]) {
+                let url = normalize_url(field(r, "url"));
+                let url = url.split_once("://").map_or(url.as_str(), |(_, url)| url);
+                rank = rank
+                    .max(similarity(&field(r, "name").to_lowercase(), text))
+                    .max(word_similarity(text, url));
+            }
+            (prefix_match || rank >= SUGGESTIONS_MIN_SIMILARITY).then_some((prefix_match, rank, p))
+        })
+        .collect();
+    suggestions.sort_by(|(prefix1, rank1, p1), (prefix2, rank2, p2)| {
+        prefix2
+            .cmp(prefix1)
+            .then(rank2.total_cmp(rank1))
+            .then_with(|| field(p1, "name").cmp(field(p2, "name")))
+            .then_with(|| field(p1, "foundation_id").cmp(field(p2, "foundation_id")))
+    });
+    Value::Array(
+        suggestions
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+            .map(|(_, _, p)| {
+                strip_nulls(json!({
+                    "id": p["project_id"],
+                    "name": p["name"],
+                    "display_name": p["display_name"],
+                    "foundation": p["foundation_id"],
+                    "logo_url": p["logo_url"],
+                    "rating": p["rating"],
+                }))
+            })
+            .collect(),
+    )
+}
+
+/// Return the trigrams of the words provided, built like pg_trgm does (each
+/// word is padded with two spaces at the beginning and one at the end).
+fn trigrams(words: &[&str]) -> BTreeSet<String> {
+    let mut trigrams = BTreeSet::new();
+    for word in words {
+        let padded: Vec<char> = format!("  {} ", word.to_lowercase()).chars().collect();
+        for trigram in padded.windows(3) {
+            trigrams.insert(trigram.iter().collect());
+        }
+    }
+    trigrams
+}
+
+/// Return the words (alphanumeric sequences) of the text provided.
+fn words(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Return the similarity between the sets of trigrams provided.
+fn trigrams_similarity(t1: &BTreeSet<String>, t2: &BTreeSet<String>) -> f64 {
+    let union = t1.union(t2).count();
+    if union == 0 {
+        return 0.0;
+    }
+    t1.intersection(t2).count() as f64 / union as f64
+}
+
+/// Return the trigram similarity between the texts provided (like pg_trgm's
+/// similarity function).
+fn similarity(text1: &str, text2: &str) -> f64 {
+    trigrams_similarity(&trigrams(&words(text1)), &trigrams(&words(text2)))
+}
+
+/// Return the share of the trigrams of the first text provided that can be
+/// found in the second one, so that partial texts match (like pg_trgm's
+/// word_similarity function does).
+fn word_similarity(text1: &str, text2: &str) -> f64 {
+    let t1 = trigrams(&words(text1));
+    if t1.is_empty() {
+        return 0.0;
+    }
+    let t2 = trigrams(&words(text2));
+    t1.intersection(&t2).count() as f64 / t1.len() as f64
+}
+
+/// Build the stats of the projects provided (only those of the foundation
+/// given, if any), like the get_stats function in the PostgreSQL database.
+/// The projects views and the snapshots available are not included, as they
+/// are not synced from the primary instance.
+fn stats(projects: &[Value], foundation: Option<&str>) -> Value {
+    let projects: Vec<&Value> = foundation_projects(projects, foundation).collect();
+
+    // Projects accepted per month
+    let mut accepted: BTreeMap<Date, u64> = BTreeMap::new();
+    for p in &projects {
+        let month = p["accepted_at"]
+            .as_str()
+            .and_then(|date| Date::parse(date, format_description!("[year]-[month]-[day]")).ok())
+            .and_then(|date| Date::from_calendar_date(date.year(), date.month(), 1).ok());
+        if let Some(month) = month {
+            *accepted.entry(month).or_default() += 1;
+        }
+    }
+    let mut total = 0;
+    let running_total: Vec<Value> = accepted
+        .iter()
+        .map(|(month, count)| {
+            total += count;
+            json!([month.midnight().assume_utc().unix_timestamp() * 1000, total])
+        })
+        .collect();
+    let accepted_distribution: Vec<Value> = accepted
+        .iter()
+        .rev()
+        .map(|(month, count)| {
+            json!({"year": month.year(), "month": u8::from(month.month()), "total": count})
+        })
+        .collect();
+
+    // Ratings distribution and sections average (overall and per maturity)
+    let has_maturity =
+        |p: &Value, maturity: Option<&str>| maturity.map_or(true, |m| field(p, "maturity") == m);
+    let rating_distribution = |maturity: Option<&str>| {
+        let mut ratings: BTreeMap<&str, u64> = BTreeMap::new();
+        for p in projects.iter().filter(|p| has_maturity(p, maturity)) {
+            if let Some(rating) = p["rating"].as_str() {
+                *ratings.entry(rating).or_default() += 1;
+            }
+        }
+        let ratings: Vec<Value> = ratings
+            .into_iter()
+            .map(|(rating, total)| json!({ rating: total }))
+            .collect();
+        (!ratings.is_empty()).then(|| Value::from(ratings))
+    };
+    let sections_average = |maturity: Option<&str>| {
+        let averages: Map<String, Value> = STATS_SECTIONS
+            .iter()
+            .map(|section| {
+                let scores: Vec<f64> = projects
+                    .iter()
+                    .filter(|p| has_maturity(p, maturity))
+                    .filter_map(|p| p["score"][section].as_f64())
+                    .collect();
+                let average = (!scores.is_empty())
+                    .then(|| (scores.iter().sum::<f64>() / scores.len() as f64).round());
+                (section.to_string(), json!(average))
+            })
+            .collect();
+        Value::Object(averages)
+    };
+    let mut ratings = Map::new();
+    let mut averages = Map::new();
+    ratings.insert("all".to_string(), json!(rating_distribution(None)));
+    averages.insert("all".to_string(), sections_average(None));
+    for maturity in STATS_MATURITY_LEVELS {
+        ratings.insert(
+            maturity.to_string(),
+            json!(rating_distribution(Some(maturity))),
+        );
+        averages.insert(maturity.to_string(), sections_average(Some(maturity)));
+    }
+
+    // Percentage of repositories passing each check (among those where it
+    // was run)
+    let mut passing_check: BTreeMap<&str, Map<String, Value>> = BTreeMap::new();
+    for ((section, check_id), (passed, total)) in checks_passed(&projects) {
+        passing_check
+            .entry(section)
+            .or_default()
+            .insert(check_id.to_string(), json!(percentage(passed, total)));
+    }
+
+    strip_nulls(json!({
+        "generated_at": generated_at(),
+        "projects": {
+            "running_total": (!running_total.is_empty()).then_some(running_total),
+            "accepted_distribution": (!accepted_distribution.is_empty())
+                .then_some(accepted_distribution),
+            "rating_distribution": ratings,
+            "sections_average": averages,
+        },
+        "repositories": {
+            "passing_check": passing_check,
+        },
+    }))
+}
+
+/// Build the checks adoption stats of the projects provided (only those of
+/// the foundation given, if any). The monthly evolution is not available, as
+/// it is calculated from the stats snapshots.
+fn checks_stats(projects: &[Value], foundation: Option<&str>) -> Value {
+    let projects: Vec<&Value> = foundation_projects(projects, foundation).collect();
+    let mut checks: BTreeMap<&str, (u64, u64)> = BTreeMap::new();
+    for ((_, check_id), (passed, total)) in checks_passed(&projects) {
+        let entry = checks.entry(check_id).or_default();
+        entry.0 += passed;
+        entry.1 += total;
+    }
+    let checks: Map<String, Value> = checks
+        .into_iter()
+        .map(|(check_id, (passed, total))| {
+            let stats = json!({"current": percentage(passed, total), "monthly": []});
+            (check_id.to_string(), stats)
+        })
+        .collect();
+    json!({
+        "generated_at": generated_at(),
+        "checks": checks,
+    })
+}
+
+/// Build the leaderboard of the projects provided (only those of the
+/// foundation given, if any), including the top rated ones of each maturity
+/// level sorted by their global score.
+fn leaderboard(projects: &[Value], foundation: Option<&str>, limit: i32) -> Value {
+    let mut ranked: BTreeMap<&str, Vec<(f64, &Value)>> = BTreeMap::new();
+    for p in foundation_projects(projects, foundation) {
+        let (Some(maturity), Some(score)) = (p["maturity"].as_str(), p["score"]["global"].as_f64())
+        else {
+            continue;
+        };
+        ranked.entry(maturity).or_default().push((score, p));
+    }
+    let leaderboard: Map<String, Value> = ranked
+        .into_iter()
+        .map(|(maturity, mut projects)| {
+            projects.sort_by(|(s1, p1), (s2, p2)| {
+                s2.total_cmp(s1)
+                    .then_with(|| field(p1, "name").cmp(field(p2, "name")))
+            });
+            let projects: Vec<Value> = projects
+                .into_iter()
+                .take(limit.max(0) as usize)
+                .enumerate()
+                .map(|(i, (score, p))| {
+                    strip_nulls(json!({
+                        "rank": i + 1,
+                        "foundation": p["foundation_id"],
+                        "name": p["name"],
+                        "display_name": p["display_name"],
+                        "logo_url": p["logo_url"],
+                        "rating": p["rating"],
+                        "score": score,
+                    }))
+                })
+                .collect();
+            (maturity.to_string(), Value::from(projects))
+        })
+        .collect();
+    Value::Object(leaderboard)
+}
+
+/// Return the projects provided that belong to the foundation given (all of
+/// them when no foundation is provided).
+fn foundation_projects<'a>(
+    projects: &'a [Value],
+    foundation: Option<&'a str>,
+) -> impl Iterator<Item = &'a Value> {
+    projects
+        .iter()
+        .filter(move |p| foundation.map_or(true, |f| field(p, "foundation_id") == f))
+}
+
+/// Return the number of repositories passing each check (identified by its
+/// section and id) of the projects provided, along with the number of
+/// repositories where the check was run.
+fn checks_passed<'a>(projects: &[&'a Value]) -> BTreeMap<(&'a str, &'a str), (u64, u64)> {
+    let mut checks: BTreeMap<(&str, &str), (u64, u64)> = BTreeMap::new();
+    for r in projects
+        .iter()
+        .copied()
+        .flat_map(|p| array(&p["repositories"]))
+    {
+        let Some(sections) = r["report"]["data"].as_object() else {
+            continue;
+        };
+        for (section, section_checks) in sections {
+            let Some(section_checks) = section_checks.as_object() else {
+                continue;
+            };
+            for (check_id, output) in section_checks.iter().filter(|(_, o)| !o.is_null()) {
+                let entry = checks
+                    .entry((section.as_str(), check_id.as_str()))
+                    .or_default();
+                if output["passed"].as_bool() == Some(true) {
+                    entry.0 += 1;
+                }
+                entry.1 += 1;
+            }
+        }
+    }
+    checks
+}
+
+/// Return the percentage provided, rounded (0 when the total is 0).
+fn percentage(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (count as f64 / total as f64 * 100.0).round()
+}
+
+/// Return the time the stats are generated at (unix timestamp in ms).
+fn generated_at() -> i64 {
+    time::OffsetDateTime::now_utc().unix_timestamp() * 1000
+}
+
+/// Build the project's json representation returned by the API.
+fn project_json(p: &Value) -> Value {
+    let repositories: Vec<Value> = array(&p["repositories"])
+        .iter()
+        .map(|r| {
+            json!({
+                "repository_id": r["repository_id"],
+                "name": r["name"],
+                "url": r["url"],
+                "check_sets": r["check_sets"],
+                "digest": r["digest"],
+                "score": r["score"],
+                "weight": r["weight"],
+                "report": report_json(&r["report"]),
+            })
+        })
+        .collect();
+    let repositories = (!repositories.is_empty()).then(|| Value::from(repositories));
+    strip_nulls(json!({
+        "id": p["project_id"],
+        "name": p["name"],
+        "display_name": p["display_name"],
+        "description": p["description"],
+        "category": p["category"],
+        "home_url": p["home_url"],
+        "logo_url": p["logo_url"],
+        "logo_dark_url": p["logo_dark_url"],
+        "devstats_url": p["devstats_url"],
+        "score": p["score"],
+        "rating": p["rating"],
+        "accepted_at": date_epoch(&p["accepted_at"]),
+        "updated_at": timestamp_epoch(&p["updated_at"]),
+        "maturity": p["maturity"],
+        "repositories": repositories,
+        "foundation": p["foundation_id"],
+    }))
+}
+
+/// Build the project's json representation returned in the search results.
+fn search_project_json(p: &Value) -> Value {
+    let repositories: Vec<Value> = array(&p["repositories"])
+        .iter()
+        .map(|r| {
+            json!({
+                "name": r["name"],
+                "url": r["url"],
+                "check_sets": r["check_sets"],
+                "website_url": r["report"]["data"]["documentation"]["website"]["url"],
+            })
+        })
+        .collect();
+    let repositories = (!repositories.is_empty()).then(|| Value::from(repositories));
+    strip_nulls(json!({
+        "id": p["project_id"],
+        "name": p["name"],
+        "display_name": p["display_name"],
+        "description": p["description"],
+        "category": p["category"],
+        "home_url": p["home_url"],
+        "logo_url": p["logo_url"],
+        "logo_dark_url": p["logo_dark_url"],
+        "devstats_url": p["devstats_url"],
+        "score": p["score"],
+        "rating": p["rating"],
+        "accepted_at": date_epoch(&p["accepted_at"]),
+        "updated_at": timestamp_epoch(&p["updated_at"]),
+        "maturity": p["maturity"],
+        "repositories": repositories,
+        "foundation": p["foundation_id"],
+    }))
+}
+
+/// Build the repository's json representation returned by the API.
+fn repository_json(r: &Value, p: &Value) -> Value {
+    strip_nulls(json!({
+        "repository_id": r["repository_id"],
+        "name": r["name"],
+        "url": r["url"],
+        "check_sets": r["check_sets"],
+        "digest": r["digest"],
+        "score": r["score"],
+        "weight": r["weight"],
+        "report": report_json(&r["report"]),
+        "project": {
+            "id": p["project_id"],
+            "name": p["name"],
+            "display_name": p["display_name"],
+            "foundation": p["foundation_id"],
+        },
+    }))
+}
+
+/// Build the report's json representation returned by the API.
+fn report_json(report: &Value) -> Value {
+    if report.is_null() {
+        return Value::Null;
+    }
+    json!({
+        "report_id": report["report_id"],
+        "check_sets": report["check_sets"],
+        "data": report["data"],
+        "errors": report["errors"],
+        "updated_at": timestamp_epoch(&report["updated_at"]),
+    })
+}
+
+/// Remove recursively all object fields with null values (like PostgreSQL's
+/// json_strip_nulls does).
+fn strip_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, strip_nulls(v)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(strip_nulls).collect()),
+        value => value,
+    }
+}
+
+/// Convert the timestamp provided (in RFC 3339 format) to a unix timestamp.
+fn timestamp_epoch(value: &Value) -> Value {
+    value
+        .as_str()
+        .and_then(|ts| time::OffsetDateTime::parse(ts, &Rfc3339).ok())
+        .map_or(Value::Null, |ts| ts.unix_timestamp().into())
+}
+
+/// Convert the date provided (in YYYY-MM-DD format) to a unix timestamp.
+fn date_epoch(value: &Value) -> Value {
+    value
+        .as_str()
+        .and_then(|date| Date::parse(date, format_description!("[year]-[month]-[day]")).ok())
+        .map_or(Value::Null, |date| {
+            date.midnight().assume_utc().unix_timestamp().into()
+        })
+}
+
+/// Normalize the url provided so that it can be compared with others,
+/// ignoring the case, trailing slashes and the .git suffix.
+fn normalize_url(url: &str) -> String {
+    let url = url.trim().to_lowercase();
+    let url = url.trim_end_matches('/');
+    url.strip_suffix(".git").unwrap_or(url).to_string()
+}
+
+/// Return the elements of the json array provided (none if it isn't one).
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Return the string field provided (empty if it isn't present).
+fn field<'a>(value: &'a Value, name: &str) -> &'a str {
+    value[name].as_str().unwrap_or_default()
+}
+
+/// Return the required string field provided.
+fn str_field<'a>(value: &'a Value, name: &str) -> Result<&'a str> {
+    value[name]
+        .as_str()
+        .ok_or_else(|| format_err!("{name} field not found in delta"))
+}
+
+/// Error returned by the operations the SQLite backend does not support.
+#[derive(Debug)]
+pub(crate) struct UnsupportedError {
+    pub operation: String,
+}
+
+impl fmt::Display for UnsupportedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} not supported by the sqlite backend", self.operation)
+    }
+}
+
+impl std::error::Error for UnsupportedError {}
+
+/// Build the error returned by the operations the SQLite backend does not
+/// support.
+fn unsupported(operation: &str) -> Error {
+    Error::new(UnsupportedError {
+        operation: operation.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FOUNDATION: &str = "foundation";
+    const PROJECT: &str = "project";
+    const PROJECT_ID: &str = "00000000-0001-0000-0000-000000000000";
+    const REPOSITORY_ID: &str = "00000000-0000-0001-0000-000000000000";
+
+    fn delta(until: i64, project_id: &str, score: f64) -> String {
+        json!({
+            "until": until,
+            "foundations": [{
+                "foundation_id": FOUNDATION,
+                "display_name": "Foundation",
+                "data_url": "https://example.com/data.yaml",
+                "weights": [
+                    {"kind": "check", "name": "readme", "weight": 5},
+                    {"kind": "section", "name": "security", "weight": 2},
+                ],
+                "maturity_gates": [],
+            }],
+            "projects": [{
+                "project_id": project_id,
+                "name": PROJECT,
+                "display_name": null,
+                "score": {"global": score, "documentation": score},
+                "rating": "b",
+                "passed_checks": ["readme"],
+                "accepted_at": "2021-01-01",
+                "updated_at": "2022-10-28T14:02:32.409798+02:00",
+                "maturity": "graduated",
+                "foundation_id": FOUNDATION,
+                "repositories": [{
+                    "repository_id": REPOSITORY_ID,
+                    "name": "repo",
+                    "url": "https://github.com/org/repo",
+                    "score": {"global": score},
+                    "check_sets": ["code"],
+                    "weight": 1.0,
+                    "report": {
+                        "report_id": "00000000-0000-0000-0001-000000000000",
+                        "check_sets": ["code"],
+                        "data": {"documentation": {"readme": {"passed": true}}},
+                        "errors": null,
+                        "updated_at": "2022-10-28T14:02:32.409798+02:00",
+                    },
+                }],
+            }],
+            "removed_projects": [],
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn apply_sync_delta_and_get_project() {
+        let db = SqliteDB::new_in_memory().unwrap();
+        db.apply_sync_delta(&delta(1_700_000_000, PROJECT_ID, 80.0))
+            .await
+            .unwrap();
+
+        assert_eq!(db.mirror_synced_until().await.unwrap(), Some(1_700_000_000));
+        assert_eq!(
+            db.project_rating(FOUNDATION, PROJECT).await.unwrap(),
+            Some("b".to_string())
+        );
+        let data: Value =
+            serde_json::from_str(&db.project_data(FOUNDATION, PROJECT).await.unwrap().unwrap())
+                .unwrap();
+        assert_eq!(data["id"], PROJECT_ID);
+        assert_eq!(data["foundation"], FOUNDATION);
+        assert_eq!(data["accepted_at"], 1_609_459_200);
+        assert_eq!(data["updated_at"], 1_666_958_552);
+        assert!(data.get("display_name").is_none());
+        assert_eq!(
+            data["repositories"][0]["report"]["updated_at"],
+            1_666_958_552
+        );
+        assert_eq!(
+            db.project_reports(FOUNDATION, PROJECT).await.unwrap().len(),
+            1
+        );
+        let weights = db.foundation_weights(FOUNDATION).await.unwrap().unwrap();
+        assert_eq!(weights.checks.get("readme"), Some(&5));
+    }
+
+    #[tokio::test]
+    async fn apply_sync_delta_replaces_project_registered_again() {
+        let db = SqliteDB::new_in_memory().unwrap();
+        db.apply_sync_delta(&delta(1, PROJECT_ID, 80.0))
+            .await
+            .unwrap();
+        let new_project_id = "00000000-0002-0000-0000-000000000000";
+        db.apply_sync_delta(&delta(2, new_project_id, 90.0))
+            .await
+            .unwrap();
+
+        let output = db
+            .search_projects(&SearchProjectsInput::default())
+            .await
+            .unwrap();
+        assert_eq!(output.count, 1);
+        let projects: Value = serde_json::from_str(&output.projects).unwrap();
+        assert_eq!(projects[0]["id"], new_project_id);
+        assert_eq!(db.mirror_synced_until().await.unwrap(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn search_projects_filters_and_facets() {
+        let db = SqliteDB::new_in_memory().unwrap();
+        db.apply_sync_delta(&delta(1, PROJECT_ID, 80.0))
+            .await
+            .unwrap();
+
+        let output = db
+            .search_projects(&SearchProjectsInput {
+                text: Some("PROJ".to_string()),
+                rating: Some(vec!['b']),
+                passing_check: Some(vec!["readme".to_string()]),
+                ..SearchProjectsInput::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(output.count, 1);
+        assert_eq!(output.next_cursor, None);
+        let facets: Value = serde_json::from_str(&output.facets).unwrap();
+        assert_eq!(facets["maturity"]["graduated"], 1);
+        let projects: Value = serde_json::from_str(&output.projects).unwrap();
+        assert_eq!(
+            projects[0]["repositories"][0]["url"],
+            "https://github.com/org/repo"
+        );
+
+        let output = db
+            .search_projects(&SearchProjectsInput {
+                not_passing_check: Some(vec!["readme".to_string()]),
+                ..SearchProjectsInput::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(output.count, 0);
+    }
+
+    #[tokio::test]
+    async fn search_repositories_normalizing_url() {
+        let db = SqliteDB::new_in_memory().unwrap();
+        db.apply_sync_delta(&delta(1, PROJECT_ID, 80.0))
+            .await
+            .unwrap();
+
+        let repositories: Value = serde_json::from_str(
+            &db.search_repositories(" https://GitHub.com/org/repo.git/ ")
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(repositories[0]["repository_id"], REPOSITORY_ID);
+        assert_eq!(repositories[0]["project"]["name"], PROJECT);
+        assert!(db
+            .repository_data(&Uuid::parse_str(REPOSITORY_ID).unwrap())
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn stats_calculated_from_projects_synced() {
+        let db = SqliteDB::new_in_memory().unwrap();
+        db.apply_sync_delta(&delta(1, PROJECT_ID, 80.0))
+            .await
+            .unwrap();
+
+        let stats: Value =
+            serde_json::from_str(&db.stats(Some(FOUNDATION)).await.unwrap()).unwrap();
+        assert_eq!(
+            stats["projects"]["running_total"],
+            json!([[1_609_459_200_000_i64, 1]])
+        );
+        assert_eq!(
+            stats["projects"]["accepted_distribution"],
+            json!([{"year": 2021, "month": 1, "total": 1}])
+        );
+        assert_eq!(
+            stats["projects"]["rating_distribution"]["graduated"],
+            json!([{"b": 1}])
+        );
+        assert!(stats["projects"]["rating_distribution"]
+            .get("sandbox")
+            .is_none());
+        assert_eq!(
+            stats["projects"]["sections_average"]["all"],
+            json!({"documentation": 80.0})
+        );
+        assert_eq!(
+            stats["repositories"]["passing_check"]["documentation"]["readme"],
+            100.0
+        );
+
+        let checks: Value = serde_json::from_str(&db.stats_checks(None).await.unwrap()).unwrap();
+        assert_eq!(
+            checks["checks"]["readme"],
+            json!({"current": 100.0, "monthly": []})
+        );
+        let leaderboard: Value =
+            serde_json::from_str(&db.stats_leaderboard(None, 5).await.unwrap()).unwrap();
+        assert_eq!(leaderboard["graduated"][0]["name"], PROJECT);
+        assert_eq!(leaderboard["graduated"][0]["rank"], 1);
+        assert!(db
+            .stats_snapshot(
+                None,
+                &Date::from_calendar_date(2023, time::Month::January, 1).unwrap()
+            )
+            .await
+            .unwrap()
+            .is_none());
+
+        let stats: Value = serde_json::from_str(&db.stats(Some("other")).await.unwrap()).unwrap();
+        assert!(stats["projects"].get("running_total").is_none());
+    }
+
+    #[tokio::test]
+    async fn suggest_projects_tolerating_typos_and_partial_urls() {
+        let db = SqliteDB::new_in_memory().unwrap();
+        db.apply_sync_delta(&delta(1, PROJECT_ID, 80.0))
+            .await
+            .unwrap();
+
+        for text in ["proj", "projetc", "https://github.com/org/rep"] {
+            let projects: Value =
+                serde_json::from_str(&db.suggest_projects(text, 10).await.unwrap()).unwrap();
+            assert_eq!(
+                projects,
+                json!([{
+                    "id": PROJECT_ID,
+                    "name": PROJECT,
+                    "foundation": FOUNDATION,
+                    "rating": "b",
+                }])
+            );
+        }
+        let projects: Value =
+            serde_json::from_str(&db.suggest_projects("kubernetes", 10).await.unwrap()).unwrap();
+        assert_eq!(projects, json!([]));
+    }
+
+    #[test]
+    fn trigram_similarity() {
+        assert!((similarity("artifact hub", "artifact hub") - 1.0).abs() < f64::EPSILON);
+        assert!(similarity("Artifact Hub", "artifct hub") > SUGGESTIONS_MIN_SIMILARITY);
+        assert!(similarity("backstage", "artifct hub") < SUGGESTIONS_MIN_SIMILARITY);
+        assert!(word_similarity("artifacthub/hu", "github.com/artifacthub/hub") > 0.9);
+        assert!((word_similarity("", "github.com/artifacthub/hub")).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn normalize_url_ignores_case_suffix_and_slashes() {
+        assert_eq!(
+            normalize_url("https://GitHub.com/org/repo.git/"),
+            "https://github.com/org/repo"
+        );
+        assert_eq!(
+            normalize_url("https://github.com/org/repo//"),
+            "https://github.com/org/repo"
+        );
+    }
+}
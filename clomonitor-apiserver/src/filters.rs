@@ -1,3 +1,19 @@
+/// Template filter that returns the color (black or white) that contrasts the
+/// most with the color provided (in #rrggbb format), so that the content drawn
+/// on top of it is readable.
+pub(crate) fn contrast_color(color: &str) -> askama::Result<&'static str> {
+    let channel = |i: usize| {
+        let value = color.trim_start_matches('#').get(i..i + 2).unwrap_or("ff");
+        f64::from(u8::from_str_radix(value, 16).unwrap_or(u8::MAX)) / 255.0
+    };
+    let luminance = 0.2126 * channel(0) + 0.7152 * channel(2) + 0.0722 * channel(4);
+    Ok(if luminance > 0.6 {
+        "#000000"
+    } else {
+        "#ffffff"
+    })
+}
+
 /// Template filter that returns the rating letter corresponding to the score
 /// value provided.
 pub(crate) fn rating(score: &f64) -> askama::Result<char> {
@@ -39,6 +55,15 @@ pub(crate) fn stroke(v: &f64) -> askama::Result<f64> {
     Ok(251.42 + (251.42 * v / 100.0))
 }
 
+/// Template filter that return the stroke-dasharray for the optional score
+/// provided. An empty gauge is drawn when the value is none.
+pub(crate) fn stroke_opt(v: &Option<f64>) -> askama::Result<f64> {
+    match v {
+        Some(v) => stroke(v),
+        None => stroke(&0.0),
+    }
+}
+
 /// Template filter that returns the integer part of the rounded score value
 /// provided as a string. "n/a" is returned when the value is none.
 pub(crate) fn to_string(score: &Option<f64>) -> askama::Result<String> {
@@ -52,6 +77,13 @@ pub(crate) fn to_string(score: &Option<f64>) -> askama::Result<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn contrast_color_works() {
+        assert_eq!(contrast_color("#2a0552").unwrap(), "#ffffff");
+        assert_eq!(contrast_color("#f9c74f").unwrap(), "#000000");
+        assert_eq!(contrast_color("#ffffff").unwrap(), "#000000");
+    }
+
     #[test]
     fn rating_works() {
         assert_eq!(rating(&80.0).unwrap(), 'a');
@@ -85,6 +117,16 @@ mod tests {
         assert_eq!(rs_section_score_width(&None).unwrap(), 0.0);
     }
 
+    #[test]
+    fn stroke_opt_some() {
+        assert_eq!(stroke_opt(&Some(80.0)).unwrap(), stroke(&80.0).unwrap());
+    }
+
+    #[test]
+    fn stroke_opt_none() {
+        assert_eq!(stroke_opt(&None).unwrap(), 251.42);
+    }
+
     #[test]
     fn to_string_some() {
         assert_eq!(to_string(&Some(79.9)).unwrap(), "80".to_string());
@@ -0,0 +1,211 @@
+use anyhow::{format_err, Result};
+use clomonitor_core::net;
+use config::Config;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::{
+    header::{ACCEPT, AUTHORIZATION, USER_AGENT},
+    StatusCode,
+};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// GitHub API url used when none has been configured.
+const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// Maximum time that can take a request to the GitHub API.
+const API_REQUEST_TIMEOUT: u64 = 10;
+
+/// Prefix added to the GitHub users' logins to build their subjects.
+pub(crate) const SUBJECT_PREFIX: &str = "github:";
+
+/// Prefixes of the GitHub tokens that can be used to authenticate a user
+/// (personal access tokens, OAuth and GitHub App user-to-server tokens).
+const USER_TOKEN_PREFIXES: [&str; 4] = ["ghp_", "gho_", "ghu_", "github_pat_"];
+
+lazy_static! {
+    /// HTTP client used to interact with the GitHub API.
+    static ref HTTP_CLIENT: reqwest::Client = net::client_builder()
+        .timeout(Duration::from_secs(API_REQUEST_TIMEOUT))
+        .build()
+        .expect("http client to be built");
+
+    /// Regular expression used to extract the owner and name from a GitHub
+    /// repository url.
+    static ref GITHUB_REPO_URL: Regex =
+        Regex::new("^https://github.com/(?P<owner>[^/]+)/(?P<repo>[^/]+)/?$")
+            .expect("exprs in GITHUB_REPO_URL to be valid");
+}
+
+/// GitHub user (only the fields used are included).
+#[derive(Debug, Deserialize)]
+struct User {
+    login: String,
+}
+
+/// GitHub repository (only the fields used are included).
+#[derive(Debug, Deserialize)]
+struct Repository {
+    #[serde(default)]
+    permissions: Permissions,
+}
+
+/// Permissions the authenticated user has on a repository.
+#[derive(Debug, Default, Deserialize)]
+struct Permissions {
+    #[serde(default)]
+    admin: bool,
+    #[serde(default)]
+    maintain: bool,
+    #[serde(default)]
+    push: bool,
+}
+
+/// Return the GitHub API url set in the configuration, or the default one.
+pub(crate) fn api_url(cfg: &Config) -> String {
+    cfg.get_string("apiserver.githubAuth.apiUrl")
+        .unwrap_or_else(|_| DEFAULT_API_URL.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Check if the token provided looks like one issued by GitHub to a user.
+pub(crate) fn is_user_token(token: &str) -> bool {
+    USER_TOKEN_PREFIXES
+        .iter()
+        .any(|prefix| token.starts_with(prefix))
+}
+
+/// Build the subject of the GitHub user provided. Logins are case
+/// insensitive, so they are normalized to lowercase.
+pub(crate) fn subject(login: &str) -> String {
+    format!("{SUBJECT_PREFIX}{}", login.to_lowercase())
+}
+
+/// Get the login of the user the token provided belongs to. None is returned
+/// when the token is not valid.
+pub(crate) async fn user_login(api_url: &str, token: &str) -> Result<Option<String>> {
+    let resp = request(&format!("{api_url}/user"), token).await?;
+    match resp.status() {
+        StatusCode::OK => Ok(Some(resp.json::<User>().await?.login)),
+        StatusCode::UNAUTHORIZED => Ok(None),
+        status => Err(format_err!(
+            "unexpected status code getting github user: {status}"
+        )),
+    }
+}
+
+/// Check if the user the token provided belongs to has write access to the
+/// GitHub repository provided. Repositories not hosted on GitHub, or not
+/// visible to the user, are reported as not writable.
+pub(crate) async fn has_write_access(api_url: &str, token: &str, repo_url: &str) -> Result<bool> {
+    let Some((owner, repo)) = parse_repo_url(repo_url) else {
+        return Ok(false);
+    };
+    let resp = request(&format!("{api_url}/repos/{owner}/{repo}"), token).await?;
+    match resp.status() {
+        StatusCode::OK => {
+            let p = resp.json::<Repository>().await?.permissions;
+            Ok(p.admin || p.maintain || p.push)
+        }
+        StatusCode::NOT_FOUND | StatusCode::FORBIDDEN => Ok(false),
+        status => Err(format_err!(
+            "unexpected status code getting github repository: {status}"
+        )),
+    }
+}
+
+/// Send a request to the GitHub API url provided, authenticated with the
+/// token provided.
+async fn request(url: &str, token: &str) -> Result<reqwest::Response> {
+    Ok(HTTP_CLIENT
+        .get(url)
+        .header(ACCEPT, "application/vnd.github+json")
+        .header(AUTHORIZATION, format!("Bearer {token}"))
+        .header(USER_AGENT, "clomonitor")
+        .send()
+        .await?)
+}
+
+/// Extract the owner and name from the GitHub repository url provided.
+fn parse_repo_url(url: &str) -> Option<(String, String)> {
+    let c = GITHUB_REPO_URL.captures(url)?;
+    let repo = c["repo"].trim_end_matches(".git");
+    Some((c["owner"].to_string(), repo.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TOKEN: &str = "gho_token";
+
+    #[test]
+    fn parse_repo_url_works() {
+        assert_eq!(
+            parse_repo_url("https://github.com/artifacthub/hub"),
+            Some(("artifacthub".to_string(), "hub".to_string()))
+        );
+        assert_eq!(
+            parse_repo_url("https://github.com/artifacthub/hub.git"),
+            Some(("artifacthub".to_string(), "hub".to_string()))
+        );
+        assert_eq!(parse_repo_url("https://gitlab.com/org/repo"), None);
+    }
+
+    #[test]
+    fn is_user_token_works() {
+        assert!(is_user_token("ghp_1234"));
+        assert!(is_user_token("github_pat_1234"));
+        assert!(!is_user_token("ghs_1234"));
+        assert!(!is_user_token("admin-token"));
+    }
+
+    #[tokio::test]
+    async fn user_login_invalid_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        assert_eq!(user_login(&server.uri(), TOKEN).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn has_write_access_using_repository_permissions() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/artifacthub/hub"))
+            .and(header("authorization", format!("Bearer {TOKEN}").as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "permissions": {"admin": false, "maintain": false, "push": true, "pull": true}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/artifacthub/other"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "permissions": {"admin": false, "maintain": false, "push": false, "pull": true}
+            })))
+            .mount(&server)
+            .await;
+
+        let api_url = server.uri();
+        assert!(
+            has_write_access(&api_url, TOKEN, "https://github.com/artifacthub/hub")
+                .await
+                .unwrap()
+        );
+        assert!(
+            !has_write_access(&api_url, TOKEN, "https://github.com/artifacthub/other")
+                .await
+                .unwrap()
+        );
+    }
+}
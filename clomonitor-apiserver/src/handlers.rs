@@ -1,35 +1,54 @@
 use super::filters;
 use crate::{
-    db::{DynDB, SearchProjectsInput},
+    auth::{Identity, Role, RoleAssignment},
+    bundle, datafile,
+    db::{DynDB, SearchProjectsInput, UnsupportedError, SEARCH_SORT_SECTIONS},
+    github,
+    i18n::{Lang, Messages},
+    openapi::OPENAPI_JSON,
+    pdf,
+    ratelimit::{generate_api_key, hash_api_key, ApiKeyInput},
+    templates::Overrides,
     views::DynVT,
 };
-use anyhow::Error;
+use anyhow::{format_err, Error};
 use askama_axum::Template;
 use axum::{
     body::Full,
-    extract::{Path, Query, RawQuery, State},
+    extract::{Extension, Path, Query, RawQuery, State},
     http::{
-        header::{CACHE_CONTROL, CONTENT_TYPE},
-        Response, StatusCode,
+        header::{AUTHORIZATION, CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderMap, Response, StatusCode,
     },
     response::{self, IntoResponse},
 };
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use clomonitor_core::{
-    linter::{CheckSet, Report},
-    score::Score,
+    cache::{self, DynCache},
+    linter::{remediation, CheckSet, Report, ReportSection, Severity},
+    net::RestrictedClient,
+    score::{self, CheckWeight, Score, ScoreSimulation, ScoreTrace},
 };
 use config::Config;
 use lazy_static::lazy_static;
-use mime::{APPLICATION_JSON, CSV, HTML, PNG};
+use mime::{APPLICATION_JSON, APPLICATION_PDF, CSV, HTML, IMAGE_SVG, PNG};
 use serde::{Deserialize, Serialize};
-use serde_json::json;
-use std::{collections::HashMap, fmt::Display, sync::Arc};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    sync::Arc,
+    time::Duration,
+};
 use tera::{Context, Tera};
 use time::{
-    format_description::{self, FormatItem},
-    Date,
+    ext::NumericalDuration,
+    format_description::{self, well_known::Rfc3339, FormatItem},
+    Date, OffsetDateTime,
 };
-use tracing::error;
+use tracing::{debug, error};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Index HTML document cache duration.
@@ -41,6 +60,36 @@ pub const DEFAULT_API_MAX_AGE: usize = 300;
 /// Header that indicates the number of items available for pagination purposes.
 pub const PAGINATION_TOTAL_COUNT: &str = "pagination-total-count";
 
+/// Header that contains the cursor to fetch the next page of results.
+pub const PAGINATION_NEXT_CURSOR: &str = "pagination-next-cursor";
+
+/// Media type used for documents in markdown format.
+pub const MARKDOWN: &str = "text/markdown";
+
+/// Media type used for Atom feeds.
+pub const ATOM: &str = "application/atom+xml";
+
+/// Version of the public datasets published by the archiver (it must match
+/// the one used by the archiver to build the datasets files keys).
+pub const DATASET_VERSION: &str = "v1";
+
+/// Periods (in days) supported by the most improved projects stats.
+pub const MOST_IMPROVED_PERIODS: [i32; 2] = [30, 90];
+
+/// Default and maximum number of projects returned by the stats rankings.
+pub const STATS_RANKING_DEFAULT_LIMIT: i32 = 10;
+pub const STATS_RANKING_MAX_LIMIT: i32 = 50;
+
+/// Default and maximum number of projects suggested.
+pub const SUGGESTIONS_DEFAULT_LIMIT: i32 = 10;
+pub const SUGGESTIONS_MAX_LIMIT: i32 = 20;
+
+/// Maximum length of the text projects suggestions are requested for.
+pub const SUGGESTIONS_TEXT_MAX_LEN: usize = 100;
+
+/// Default period covered by the foundation report (in days).
+pub const FOUNDATION_REPORT_DEFAULT_PERIOD: i64 = 365;
+
 /// Metadata used when rendering the index HTML document.
 pub const INDEX_META_TITLE: &str = "CLOMonitor";
 pub const INDEX_META_DESCRIPTION: &str = "CLOMonitor is a tool that periodically checks open source projects repositories to verify they meet certain project health best practices.";
@@ -50,6 +99,32 @@ pub const INDEX_META_DESCRIPTION_PROJECT: &str = "CLOMonitor report summary";
 pub const REPORT_SUMMARY_WIDTH: u32 = 900;
 pub const REPORT_SUMMARY_HEIGHT: u32 = 470;
 
+/// Themes the report summary image can be rendered with.
+pub const REPORT_SUMMARY_THEMES: [&str; 2] = ["light", "dark"];
+
+/// Range of widths the report summary PNG image can be rendered at (the
+/// height is adjusted to keep the image's aspect ratio).
+pub const REPORT_SUMMARY_PNG_MIN_WIDTH: u32 = 300;
+pub const REPORT_SUMMARY_PNG_MAX_WIDTH: u32 = 2700;
+
+/// Scale applied to the report summary image when embedding it in the PDF
+/// report, so that it looks sharp when printed.
+pub const REPORT_PDF_SCALE: u32 = 3;
+
+/// How long rendered images and documents will be kept in the cache. Cache
+/// keys include a digest of the source SVG image, so entries are not reused
+/// once the project's score changes.
+pub const RENDERED_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Rating change image width.
+const RATING_CHANGE_WIDTH: u32 = 1200;
+
+/// Rating change image height.
+const RATING_CHANGE_HEIGHT: u32 = 630;
+
+/// Maximum time that can take fetching a project's logo.
+const LOGO_FETCH_TIMEOUT: u64 = 10;
+
 lazy_static! {
     /// Format used in snapshots dates.
     pub static ref SNAPSHOT_DATE_FORMAT: Vec<FormatItem<'static>> =
@@ -58,9 +133,24 @@ lazy_static! {
 }
 
 /// Handler that returns the information needed to render the project's badge.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/badge",
+    tag = "projects",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("project" = String, Path, description = "Project name"),
+        ("lang" = Option<String>, Query, description = "Language: en (default), es, ja or zh"),
+    ),
+    responses(
+        (status = 200, description = "Shields.io endpoint badge configuration", body = Object),
+        (status = 404, description = "Not found"),
+    )
+)]
 pub(crate) async fn badge(
     State(db): State<DynDB>,
     Path((foundation, project)): Path<(String, String)>,
+    Query(params): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
     // Get project rating from database
     let rating = db
@@ -72,21 +162,16 @@ pub(crate) async fn badge(
     }
 
     // Prepare badge configuration
+    let msg = Lang::from_code(params.get("lang").map(String::as_str)).messages();
     let message: String;
     let color: &str;
     match rating {
         Some(rating) => {
             message = rating.to_uppercase();
-            color = match rating.as_ref() {
-                "a" => "green",
-                "b" => "yellow",
-                "c" => "orange",
-                "d" => "red",
-                _ => "grey",
-            };
+            color = rating_color(&rating);
         }
         None => {
-            message = "not processed yet".to_owned();
+            message = msg.not_processed.to_owned();
             color = "grey";
         }
     }
@@ -100,7 +185,7 @@ pub(crate) async fn badge(
             "namedLogo": "cncf",
             "logoColor": "BEB5C8",
             "logoWidth": 10,
-            "label": "CLOMonitor Report",
+            "label": msg.report,
             "message": message,
             "color": color,
             "schemaVersion": 1,
@@ -109,6 +194,396 @@ pub(crate) async fn badge(
     ))
 }
 
+/// Handler that returns the project's rating in the shields.io endpoint badge
+/// schema. Unlike the badge endpoint, no styling is included, so that users
+/// can customize the badge using the shields.io query parameters.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/shields",
+    tag = "projects",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("project" = String, Path, description = "Project name"),
+        ("lang" = Option<String>, Query, description = "Language: en (default), es, ja or zh"),
+    ),
+    responses(
+        (status = 200, description = "Shields.io endpoint badge schema", body = Object),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn shields(
+    State(db): State<DynDB>,
+    Path((foundation, project)): Path<(String, String)>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Get project rating from database
+    let rating = db
+        .project_rating(&foundation, &project)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Return shields.io endpoint badge schema as json
+    let msg = Lang::from_code(params.get("lang").map(String::as_str)).messages();
+    let headers = [(CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE))];
+    Ok((
+        headers,
+        response::Json(json!({
+            "schemaVersion": 1,
+            "label": msg.report,
+            "message": rating.to_uppercase(),
+            "color": rating_color(&rating),
+            "cacheSeconds": DEFAULT_API_MAX_AGE,
+        })),
+    ))
+}
+
+/// Return the badge color corresponding to the rating provided.
+fn rating_color(rating: &str) -> &'static str {
+    match rating {
+        "a" => "green",
+        "b" => "yellow",
+        "c" => "orange",
+        "d" => "red",
+        _ => "grey",
+    }
+}
+
+/// Foundation feed data, including the recent rating changes and new projects
+/// of a foundation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FoundationFeed {
+    pub foundation: String,
+    pub foundation_display_name: String,
+    pub entries: Vec<FoundationFeedEntry>,
+}
+
+/// Foundation feed entry (a project's rating change or a new project).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FoundationFeedEntry {
+    pub kind: String,
+    pub name: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub rating_from: String,
+    #[serde(default)]
+    pub rating_to: String,
+    pub updated_at: String,
+}
+
+/// Template for the foundation feed in Atom format.
+#[derive(Debug, Clone, Template)]
+#[template(path = "foundation-feed.xml")]
+pub(crate) struct FoundationFeedTemplate {
+    pub base_url: String,
+    pub updated: String,
+    pub feed: FoundationFeed,
+}
+
+/// Handler that returns an Atom feed with the recent rating changes and new
+/// projects of the foundation provided.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/feed.xml",
+    tag = "foundations",
+    params(("foundation" = String, Path, description = "Foundation identifier")),
+    responses(
+        (status = 200, description = "Atom feed with the recent rating changes and new projects", body = String, content_type = "application/atom+xml"),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn foundation_feed(
+    State(cfg): State<Arc<Config>>,
+    State(db): State<DynDB>,
+    Path(foundation): Path<String>,
+) -> impl IntoResponse {
+    // Get foundation feed from database
+    let feed = db
+        .foundation_feed(&foundation)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Render feed and return it
+    let updated = match feed.entries.first() {
+        Some(entry) => entry.updated_at.clone(),
+        None => OffsetDateTime::now_utc()
+            .replace_nanosecond(0)
+            .map_err(internal_error)?
+            .format(&Rfc3339)
+            .map_err(internal_error)?,
+    };
+    let template = FoundationFeedTemplate {
+        base_url: cfg
+            .get_string("apiserver.baseURL")
+            .expect("base url to be set"),
+        updated,
+        feed,
+    };
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, ATOM.to_string()),
+    ];
+    Ok((headers, template.render().map_err(internal_error)?))
+}
+
+/// Foundation report data, summarizing how the foundation's projects evolved
+/// during a given period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FoundationReport {
+    pub foundation: String,
+    pub foundation_display_name: String,
+    pub from: String,
+    pub to: String,
+    pub score_evolution: Vec<FoundationReportScore>,
+    pub checks_improvement: Vec<FoundationReportCheck>,
+    pub rating_changes: Vec<FoundationReportRatingChange>,
+    pub new_projects: Vec<FoundationReportProject>,
+    pub removed_projects: Vec<FoundationReportProject>,
+}
+
+/// Foundation's projects average score in a given month.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FoundationReportScore {
+    pub month: String,
+    pub average_score: f64,
+}
+
+/// Evolution of the percentage of repositories passing a check.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FoundationReportCheck {
+    pub check: String,
+    pub passing_from: f64,
+    pub passing_to: f64,
+}
+
+/// Project whose rating changed during the report period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FoundationReportRatingChange {
+    pub name: String,
+    pub display_name: String,
+    pub rating_from: String,
+    pub rating_to: String,
+}
+
+/// Project added to or removed from the foundation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FoundationReportProject {
+    pub name: String,
+    pub display_name: String,
+}
+
+/// Template for the foundation report in markdown format.
+#[derive(Debug, Clone, Template, Serialize)]
+#[template(path = "foundation-report.md")]
+pub(crate) struct FoundationReportMDTemplate {
+    pub report: FoundationReport,
+}
+
+/// Template for the foundation report in HTML format.
+#[derive(Debug, Clone, Template, Serialize)]
+#[template(path = "foundation-report.html")]
+pub(crate) struct FoundationReportHTMLTemplate {
+    pub report: FoundationReport,
+}
+
+/// Handler that returns the foundation's report for the period provided, in
+/// markdown (default) or HTML format.
+#[utoipa::path(
+    get,
+    path = "/api/foundations/{foundation}/report",
+    tag = "foundations",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("from" = Option<String>, Query, description = "Period start date (YYYY-MM-DD), defaults to one year before the end date"),
+        ("to" = Option<String>, Query, description = "Period end date (YYYY-MM-DD), defaults to today"),
+        ("format" = Option<String>, Query, description = "Report format: md (default) or html"),
+    ),
+    responses(
+        (status = 200, description = "Foundation report", body = String, content_type = "text/markdown"),
+        (status = 400, description = "Invalid input provided"),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn foundation_report(
+    State(db): State<DynDB>,
+    State(overrides): State<Arc<Overrides>>,
+    Path(foundation): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Parse period provided (defaults to the last year)
+    let parse_date = |name: &str| -> Result<Option<Date>, StatusCode> {
+        params
+            .get(name)
+            .map(|date| Date::parse(date, &SNAPSHOT_DATE_FORMAT))
+            .transpose()
+            .map_err(|_| StatusCode::BAD_REQUEST)
+    };
+    let to = parse_date("to")?.unwrap_or_else(|| OffsetDateTime::now_utc().date());
+    let from = parse_date("from")?.unwrap_or(to - FOUNDATION_REPORT_DEFAULT_PERIOD.days());
+    if from > to {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // Get foundation report from database
+    let report = db
+        .foundation_report(&foundation, &from, &to)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Render report in the format requested and return it
+    let (content_type, body) = match params.get("format").map(|f| f.as_str()) {
+        None | Some("md") => (
+            MARKDOWN.to_string(),
+            overrides
+                .render(&FoundationReportMDTemplate { report })
+                .map_err(internal_error)?,
+        ),
+        Some("html") => (
+            HTML.to_string(),
+            overrides
+                .render(&FoundationReportHTMLTemplate { report })
+                .map_err(internal_error)?,
+        ),
+        Some(_) => return Err(StatusCode::BAD_REQUEST),
+    };
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, content_type),
+    ];
+    Ok((headers, body))
+}
+
+/// Report evaluating the foundation's projects against the maturity gates
+/// defined for it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct MaturityGatesReport {
+    pub foundation: String,
+    pub foundation_display_name: String,
+    pub projects: Vec<MaturityGatesReportProject>,
+}
+
+/// Project evaluated against the foundation's maturity gates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct MaturityGatesReportProject {
+    pub name: String,
+    pub display_name: String,
+    pub maturity: String,
+    pub gates: Vec<MaturityGateResult>,
+}
+
+/// Result of the evaluation of a maturity gate for a project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct MaturityGateResult {
+    pub maturity: String,
+    pub name: String,
+    pub checks: Vec<String>,
+    pub passed: bool,
+    pub checks_not_passed: Vec<String>,
+}
+
+/// Template for the maturity gates report in markdown format.
+#[derive(Debug, Clone, Template, Serialize)]
+#[template(path = "maturity-gates-report.md")]
+pub(crate) struct MaturityGatesReportMDTemplate {
+    pub report: MaturityGatesReport,
+}
+
+/// Handler that returns the report evaluating the foundation's projects
+/// against its maturity gates, in markdown (default) or json format.
+#[utoipa::path(
+    get,
+    path = "/api/foundations/{foundation}/maturity-gates",
+    tag = "foundations",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("format" = Option<String>, Query, description = "Report format: md (default) or json"),
+    ),
+    responses(
+        (status = 200, description = "Maturity gates report", body = String, content_type = "text/markdown"),
+        (status = 400, description = "Invalid input provided"),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn foundation_maturity_gates(
+    State(db): State<DynDB>,
+    State(overrides): State<Arc<Overrides>>,
+    Path(foundation): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Get maturity gates report from database
+    let report = db
+        .foundation_maturity_gates(&foundation)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Render report in the format requested and return it
+    let (content_type, body) = match params.get("format").map(|f| f.as_str()) {
+        None | Some("md") => (
+            MARKDOWN.to_string(),
+            overrides
+                .render(&MaturityGatesReportMDTemplate { report })
+                .map_err(internal_error)?,
+        ),
+        Some("json") => (
+            APPLICATION_JSON.to_string(),
+            serde_json::to_string(&report).map_err(internal_error)?,
+        ),
+        Some(_) => return Err(StatusCode::BAD_REQUEST),
+    };
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, content_type),
+    ];
+    Ok((headers, body))
+}
+
+/// Checks (default and effective) and sections weights used to calculate the
+/// scores of a foundation's projects.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub(crate) struct FoundationWeights {
+    pub checks: BTreeMap<String, CheckWeight>,
+    pub sections: BTreeMap<ReportSection, usize>,
+    pub severities: BTreeMap<String, Severity>,
+    pub severity_multipliers: BTreeMap<Severity, usize>,
+}
+
+/// Handler that returns the checks and sections weights used to calculate the
+/// scores of the foundation's projects.
+#[utoipa::path(
+    get,
+    path = "/api/foundations/{foundation}/weights",
+    tag = "foundations",
+    params(("foundation" = String, Path, description = "Foundation identifier")),
+    responses(
+        (status = 200, description = "Checks and sections weights", body = FoundationWeights),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn foundation_weights(
+    State(db): State<DynDB>,
+    Path(foundation): Path<String>,
+) -> impl IntoResponse {
+    // Get foundation weights overrides from database
+    let weights = db
+        .foundation_weights(&foundation)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Return default and effective weights as json
+    let headers = [(CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE))];
+    let body = FoundationWeights {
+        checks: weights.checks_weights(),
+        severities: weights.checks_severities(),
+        sections: weights.sections,
+        severity_multipliers: weights.severity_multipliers,
+    };
+    Ok((headers, response::Json(body)))
+}
+
 /// Handler that returns the index HTML document with some metadata embedded.
 pub(crate) async fn index(
     State(cfg): State<Arc<Config>>,
@@ -167,7 +642,26 @@ pub(crate) async fn index_project(
     )
 }
 
+/// Handler that returns the OpenAPI specification of the API.
+pub(crate) async fn openapi_json() -> impl IntoResponse {
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+    ];
+    (headers, OPENAPI_JSON.as_str())
+}
+
 /// Handler that returns some information about the requested project.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 200, description = "Project information", body = Object),
+        (status = 404, description = "Not found"),
+    )
+)]
 pub(crate) async fn project(
     State(db): State<DynDB>,
     Path((foundation, project)): Path<(String, String)>,
@@ -181,6 +675,7 @@ pub(crate) async fn project(
     // Return project information as json if found
     match project {
         Some(project) => {
+            let project = add_remediations(project).map_err(internal_error)?;
             let headers = [
                 (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
                 (CONTENT_TYPE, APPLICATION_JSON.to_string()),
@@ -191,7 +686,160 @@ pub(crate) async fn project(
     }
 }
 
+/// Add the remediation guidance of the checks not passed to the reports of
+/// the project's repositories. Remediations are defined alongside the checks
+/// in clomonitor_core, so they are not stored in the reports but injected
+/// when they are served. The project json is returned untouched when there
+/// is nothing to add.
+fn add_remediations(project: String) -> Result<String, Error> {
+    let mut value: Value = serde_json::from_str(&project)?;
+    let mut added = false;
+    let reports = value
+        .get_mut("repositories")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter_map(|repository| repository.pointer_mut("/report/data"));
+    for report in reports {
+        added |= add_report_remediations(report);
+    }
+    if !added {
+        return Ok(project);
+    }
+    Ok(serde_json::to_string(&value)?)
+}
+
+/// Add the remediation guidance of the checks not passed to the reports of
+/// the repositories provided (a single repository or a list of them). The
+/// json is returned untouched when there is nothing to add.
+fn add_repositories_remediations(repositories: String) -> Result<String, Error> {
+    let mut value: Value = serde_json::from_str(&repositories)?;
+    let mut added = false;
+    let reports: Vec<&mut Value> = match &mut value {
+        Value::Array(repositories) => repositories
+            .iter_mut()
+            .filter_map(|repository| repository.pointer_mut("/report/data"))
+            .collect(),
+        repository => repository.pointer_mut("/report/data").into_iter().collect(),
+    };
+    for report in reports {
+        added |= add_report_remediations(report);
+    }
+    if !added {
+        return Ok(repositories);
+    }
+    Ok(serde_json::to_string(&value)?)
+}
+
+/// Add the remediation guidance of the checks not passed to the report data
+/// provided. Returns true when some remediation was added.
+fn add_report_remediations(report: &mut Value) -> bool {
+    let mut added = false;
+    let checks = report
+        .as_object_mut()
+        .into_iter()
+        .flat_map(|sections| sections.values_mut())
+        .filter_map(Value::as_object_mut)
+        .flat_map(|section| section.iter_mut());
+    for (check_id, output) in checks {
+        let Some(output) = output.as_object_mut() else {
+            continue;
+        };
+        let is_true = |field: &str| output.get(field) == Some(&Value::Bool(true));
+        if is_true("passed") || is_true("exempt") {
+            continue;
+        }
+        if let Some(remediation) = remediation(check_id) {
+            output.insert("remediation".to_string(), json!(remediation));
+            added = true;
+        }
+    }
+    added
+}
+
+/// Handler that returns when the check provided started or stopped passing on
+/// each of the project's repositories, based on the project's snapshots.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/checks/{check_id}/history",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name"), ("check_id" = String, Path, description = "Check identifier")),
+    responses(
+        (status = 200, description = "Check history", body = Object),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn project_check_history(
+    State(db): State<DynDB>,
+    Path((foundation, project, check_id)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    // Get project's check history from database
+    let history = db
+        .project_check_history(&foundation, &project, &check_id)
+        .await
+        .map_err(internal_error)?;
+
+    // Return check history if found
+    match history {
+        Some(history) => {
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+                (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+            ];
+            Ok((headers, history))
+        }
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+/// Handler that returns the errors found while tracking the project's
+/// repositories, so that the checks that could not be run (i.e. because of a
+/// rate limit or a timeout) can be told apart from those that did not pass.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/tracking-errors",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 200, description = "Tracking errors", body = Object),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn project_tracking_errors(
+    State(db): State<DynDB>,
+    Path((foundation, project)): Path<(String, String)>,
+) -> impl IntoResponse {
+    // Get project's tracking errors from database
+    let errors = db
+        .project_tracking_errors(&foundation, &project)
+        .await
+        .map_err(internal_error)?;
+
+    // Return tracking errors if the project was found
+    match errors {
+        Some(errors) => {
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+                (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+            ];
+            Ok((headers, errors))
+        }
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
 /// Handler that returns the requested project snapshot.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/snapshots/{date}",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name"), ("date" = String, Path, description = "Snapshot date (YYYY-MM-DD)")),
+    responses(
+        (status = 200, description = "Project snapshot", body = Object),
+        (status = 400, description = "Invalid input provided"),
+        (status = 404, description = "Not found"),
+    )
+)]
 pub(crate) async fn project_snapshot(
     State(db): State<DynDB>,
     Path((foundation, project, date)): Path<(String, String, String)>,
@@ -219,26 +867,234 @@ pub(crate) async fn project_snapshot(
     }
 }
 
+/// Project's current rating along with the previous rating it had.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ProjectRatingChange {
+    pub name: String,
+    pub logo_url: Option<String>,
+    pub rating: Option<String>,
+    pub previous_rating: Option<String>,
+}
+
+impl ProjectRatingChange {
+    /// Check if the project's rating has improved (ratings go from d to a).
+    fn improved(&self) -> bool {
+        match (&self.previous_rating, &self.rating) {
+            (Some(previous_rating), Some(rating)) => rating < previous_rating,
+            _ => false,
+        }
+    }
+}
+
+/// Template for the rating change SVG image.
+#[derive(Debug, Clone, Template, Serialize)]
+#[template(path = "rating-change.svg")]
+pub(crate) struct RatingChangeTemplate {
+    pub name: String,
+    pub logo: Option<String>,
+    pub previous_rating: String,
+    pub rating: String,
+}
+
+/// Handler that returns a PNG image celebrating the project's rating
+/// improvement, ready to be shared. When the project's rating hasn't improved
+/// since the last change, a not found status code is returned.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/rating-change.png",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 200, description = "Image celebrating the project's rating improvement", content_type = "image/png"),
+        (status = 404, description = "Not found or the rating hasn't improved"),
+    )
+)]
+pub(crate) async fn rating_change_png(
+    State(db): State<DynDB>,
+    State(cache): State<DynCache>,
+    State(overrides): State<Arc<Overrides>>,
+    State(restricted_client): State<RestrictedClient>,
+    Path((foundation, project)): Path<(String, String)>,
+) -> impl IntoResponse {
+    // Get project rating change from database
+    let rating_change = db
+        .project_rating_change(&foundation, &project)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    if !rating_change.improved() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let ProjectRatingChange {
+        name,
+        logo_url,
+        rating,
+        previous_rating,
+    } = rating_change;
+    let (rating, previous_rating) = (
+        rating.expect("checked if is some above"),
+        previous_rating.expect("checked if is some above"),
+    );
+
+    // Render rating change image (or get it from the cache when it was
+    // rendered previously)
+    let input = format!("{name}:{logo_url:?}:{previous_rating}:{rating}");
+    let key = rendered_cache_key("rating-change-png", &foundation, &project, &input);
+    let png = cache::get_or_insert_with(Some(&cache), &key, RENDERED_CACHE_TTL, async {
+        let logo = match logo_url {
+            Some(logo_url) => match fetch_image(&logo_url, &restricted_client).await {
+                Ok(logo) => Some(logo),
+                Err(err) => {
+                    error!("error fetching project logo ({logo_url}): {err:#}");
+                    None
+                }
+            },
+            None => None,
+        };
+        let svg = overrides.render(&RatingChangeTemplate {
+            name,
+            logo,
+            previous_rating,
+            rating,
+        })?;
+        let pixmap = render_svg(&svg, RATING_CHANGE_WIDTH, RATING_CHANGE_HEIGHT)?;
+        Ok(pixmap.encode_png()?)
+    })
+    .await
+    .map_err(internal_error)?;
+
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, PNG.to_string()),
+    ];
+    Ok((headers, png))
+}
+
 /// Template for the report summary SVG image.
-#[derive(Debug, Clone, Template)]
+#[derive(Debug, Clone, Template, Serialize)]
 #[template(path = "report-summary.svg")]
 pub(crate) struct ReportSummaryTemplate {
     pub score: Score,
     pub theme: String,
+
+    /// Color used in the header instead of the theme's one (#rrggbb).
+    pub accent: Option<String>,
+
+    /// Messages in the language the report summary is rendered in.
+    pub msg: Messages,
 }
 
 impl ReportSummaryTemplate {
-    fn new(score: Score, theme: Option<String>) -> Self {
-        let theme = theme.unwrap_or_else(|| "light".to_string());
-        Self { score, theme }
+    /// Create a new ReportSummaryTemplate instance. Unknown themes fall back
+    /// to the light one, and invalid accent colors are ignored.
+    fn new(score: Score, theme: Option<String>, accent: Option<String>, lang: Lang) -> Self {
+        let theme = theme
+            .filter(|theme| REPORT_SUMMARY_THEMES.contains(&theme.as_str()))
+            .unwrap_or_else(|| "light".to_string());
+        let accent = accent.as_deref().and_then(normalize_hex_color);
+        Self {
+            score,
+            theme,
+            accent,
+            msg: lang.messages(),
+        }
+    }
+}
+
+/// Normalize the hex color provided (#rgb or #rrggbb, the leading # is
+/// optional) to the #rrggbb format. None is returned if it is not valid.
+fn normalize_hex_color(color: &str) -> Option<String> {
+    let color = color.trim().trim_start_matches('#');
+    if !color.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match color.len() {
+        3 => Some(format!(
+            "#{}",
+            color
+                .chars()
+                .flat_map(|c| [c, c])
+                .collect::<String>()
+                .to_lowercase()
+        )),
+        6 => Some(format!("#{}", color.to_lowercase())),
+        _ => None,
+    }
+}
+
+/// Handler that returns a PDF document with the project's report summary.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/report.pdf",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 200, description = "Project's report summary", content_type = "application/pdf"),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn report_pdf(
+    State(db): State<DynDB>,
+    State(cache): State<DynCache>,
+    State(overrides): State<Arc<Overrides>>,
+    Path((foundation, project)): Path<(String, String)>,
+) -> impl IntoResponse {
+    // Get project score from database
+    let score = db
+        .project_score(&foundation, &project)
+        .await
+        .map_err(internal_error)?;
+    if score.is_none() {
+        return Err(StatusCode::NOT_FOUND);
     }
+
+    // Render report summary SVG
+    let svg = overrides
+        .render(&ReportSummaryTemplate::new(
+            score.expect("checked if is some above"),
+            None,
+            None,
+            Lang::default(),
+        ))
+        .map_err(internal_error)?;
+
+    // Convert report summary SVG to PDF (or get it from the cache when it was
+    // rendered previously)
+    let key = rendered_cache_key("report-pdf", &foundation, &project, &svg);
+    let pdf = cache::get_or_insert_with(Some(&cache), &key, RENDERED_CACHE_TTL, async {
+        let pixmap = render_svg(
+            &svg,
+            REPORT_SUMMARY_WIDTH * REPORT_PDF_SCALE,
+            REPORT_SUMMARY_HEIGHT * REPORT_PDF_SCALE,
+        )?;
+        pdf::from_pixmap(&pixmap, &format!("CLOMonitor report: {project}"))
+    })
+    .await
+    .map_err(internal_error)?;
+
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, APPLICATION_PDF.to_string()),
+        (
+            CONTENT_DISPOSITION,
+            format!(r#"inline; filename="{project}-clomonitor-report.pdf""#),
+        ),
+    ];
+    Ok((headers, pdf))
 }
 
-/// Handler that returns a PNG image with the project's report summary.
+/// Handler that returns a PNG image with the project's report summary. The
+/// image's width, theme, accent color and language can be provided in the
+/// query string.
 pub(crate) async fn report_summary_png(
     State(db): State<DynDB>,
+    State(cache): State<DynCache>,
+    State(overrides): State<Arc<Overrides>>,
     Path((foundation, project)): Path<(String, String)>,
+    Query(params): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
+    let (width, height) = report_summary_png_size(params.get("width"))?;
+
     // Get project score from database
     let score = db
         .project_score(&foundation, &project)
@@ -249,25 +1105,25 @@ pub(crate) async fn report_summary_png(
     }
 
     // Render report summary SVG
-    let svg = ReportSummaryTemplate::new(score.expect("checked if is some above"), None)
-        .render()
+    let svg = overrides
+        .render(&ReportSummaryTemplate::new(
+            score.expect("checked if is some above"),
+            params.get("theme").cloned(),
+            params.get("accent").cloned(),
+            Lang::from_code(params.get("lang").map(String::as_str)),
+        ))
         .map_err(internal_error)?;
 
-    // Convert report summary SVG to PNG
-    let mut opt = usvg::Options::default();
-    opt.fontdb.load_system_fonts();
-    opt.font_family = "Open Sans SemiBold".to_string();
-    let rtree = usvg::Tree::from_data(svg.as_bytes(), &opt.to_ref()).map_err(internal_error)?;
-    let mut pixmap = tiny_skia::Pixmap::new(REPORT_SUMMARY_WIDTH, REPORT_SUMMARY_HEIGHT)
-        .expect("width or height defined in consts are not zero");
-    resvg::render(
-        &rtree,
-        usvg::FitTo::Size(REPORT_SUMMARY_WIDTH, REPORT_SUMMARY_HEIGHT),
-        tiny_skia::Transform::default(),
-        pixmap.as_mut(),
-    )
-    .expect("width or height defined in consts are not zero");
-    let png = pixmap.encode_png().map_err(internal_error)?;
+    // Convert report summary SVG to PNG (or get it from the cache when it was
+    // rendered previously)
+    let kind = format!("report-summary-png-{width}");
+    let key = rendered_cache_key(&kind, &foundation, &project, &svg);
+    let png = cache::get_or_insert_with(Some(&cache), &key, RENDERED_CACHE_TTL, async {
+        let pixmap = render_svg(&svg, width, height)?;
+        Ok(pixmap.encode_png()?)
+    })
+    .await
+    .map_err(internal_error)?;
 
     let headers = [
         (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
@@ -276,9 +1132,41 @@ pub(crate) async fn report_summary_png(
     Ok((headers, png))
 }
 
+/// Return the dimensions the report summary PNG image will be rendered at,
+/// based on the width requested (if any). Widths out of the range supported
+/// are adjusted to its limits.
+fn report_summary_png_size(width: Option<&String>) -> Result<(u32, u32), StatusCode> {
+    let width = match width {
+        Some(width) => width
+            .parse::<u32>()
+            .map_err(|_| StatusCode::BAD_REQUEST)?
+            .clamp(REPORT_SUMMARY_PNG_MIN_WIDTH, REPORT_SUMMARY_PNG_MAX_WIDTH),
+        None => REPORT_SUMMARY_WIDTH,
+    };
+    let height = width * REPORT_SUMMARY_HEIGHT / REPORT_SUMMARY_WIDTH;
+    Ok((width, height))
+}
+
 /// Handler that returns an SVG image with the project's report summary.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/report-summary",
+    tag = "projects",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("project" = String, Path, description = "Project name"),
+        ("theme" = Option<String>, Query, description = "Theme: light (default) or dark"),
+        ("accent" = Option<String>, Query, description = "Accent color used in the header (hex format, i.e. 2a0552)"),
+        ("lang" = Option<String>, Query, description = "Language: en (default), es, ja or zh"),
+    ),
+    responses(
+        (status = 200, description = "Project's report summary", body = String, content_type = "image/svg+xml"),
+        (status = 404, description = "Not found"),
+    )
+)]
 pub(crate) async fn report_summary_svg(
     State(db): State<DynDB>,
+    State(overrides): State<Arc<Overrides>>,
     Path((foundation, project)): Path<(String, String)>,
     Query(params): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
@@ -291,9 +1179,17 @@ pub(crate) async fn report_summary_svg(
     // Render report summary SVG and return it if the score was found
     match score {
         Some(score) => {
-            let headers = [(CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE))];
             let theme = params.get("theme").cloned();
-            Ok((headers, ReportSummaryTemplate::new(score, theme)))
+            let accent = params.get("accent").cloned();
+            let lang = Lang::from_code(params.get("lang").map(String::as_str));
+            let svg = overrides
+                .render(&ReportSummaryTemplate::new(score, theme, accent, lang))
+                .map_err(internal_error)?;
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+                (CONTENT_TYPE, IMAGE_SVG.to_string()),
+            ];
+            Ok((headers, svg))
         }
         None => Err(StatusCode::NOT_FOUND),
     }
@@ -323,12 +1219,71 @@ pub(crate) struct RepositoryReportMDTemplate {
     pub check_sets: Vec<CheckSet>,
     pub score: Option<Score>,
     pub report: Option<Report>,
+
+    /// Checks that did not pass, grouped by their severity in the
+    /// repository's foundation (most severe first).
+    #[serde(default)]
+    pub failed_checks: Vec<SeverityChecks>,
 }
 
-/// Handler that returns the repository's report in markdown format.
-pub(crate) async fn repository_report_md(
-    State(db): State<DynDB>,
-    Path((foundation, project, repository)): Path<(String, String, String)>,
+/// Checks of a given severity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SeverityChecks {
+    pub severity: Severity,
+    pub checks: Vec<String>,
+}
+
+/// Handler that returns some information about the requested repository,
+/// including its report.
+#[utoipa::path(
+    get,
+    path = "/api/repositories/{repository_id}/report",
+    tag = "repositories",
+    params(("repository_id" = Uuid, Path, description = "Repository identifier")),
+    responses(
+        (status = 200, description = "Repository information and report", body = Object),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn repository(
+    State(db): State<DynDB>,
+    Path(repository_id): Path<Uuid>,
+) -> impl IntoResponse {
+    // Get repository from database
+    let repository = db
+        .repository_data(&repository_id)
+        .await
+        .map_err(internal_error)?;
+
+    // Return repository information as json if found
+    match repository {
+        Some(repository) => {
+            let repository = add_repositories_remediations(repository).map_err(internal_error)?;
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+                (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+            ];
+            Ok((headers, repository))
+        }
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+/// Handler that returns the repository's report in markdown format.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/{repository}/report.md",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name"), ("repository" = String, Path, description = "Repository name")),
+    responses(
+        (status = 200, description = "Repository's report", body = String, content_type = "text/markdown"),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn repository_report_md(
+    State(db): State<DynDB>,
+    State(overrides): State<Arc<Overrides>>,
+    Path((foundation, project, repository)): Path<(String, String, String)>,
 ) -> impl IntoResponse {
     // Get repository report info from database
     let report_md = db
@@ -338,15 +1293,144 @@ pub(crate) async fn repository_report_md(
 
     // Render repository report in markdown format and return it
     match report_md {
-        Some(report_md) => {
-            let headers = [(CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE))];
+        Some(mut report_md) => {
+            // Group the checks that did not pass by their severity, so that
+            // the most important ones are listed first
+            if let Some(report) = &report_md.report {
+                let weights = db
+                    .foundation_weights(&foundation)
+                    .await
+                    .map_err(internal_error)?
+                    .unwrap_or_default();
+                report_md.failed_checks = weights
+                    .group_by_severity(&report.not_passed())
+                    .into_iter()
+                    .map(|(severity, checks)| SeverityChecks {
+                        severity,
+                        checks: checks.into_iter().map(ToString::to_string).collect(),
+                    })
+                    .collect();
+            }
+            let report_md = overrides.render(&report_md).map_err(internal_error)?;
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+                (CONTENT_TYPE, MARKDOWN.to_string()),
+            ];
             Ok((headers, report_md))
         }
         None => Err(StatusCode::NOT_FOUND),
     }
 }
 
-/// Handler that allows searching for projects.
+/// Handler that returns the full trace of the calculation of a project's
+/// score, from the points of each check to the aggregation across the
+/// project's repositories, using the foundation's weights in effect.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/score-trace",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 200, description = "Full trace of the calculation of the project's score", body = ScoreTrace),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn score_trace(
+    State(db): State<DynDB>,
+    Path((foundation, project)): Path<(String, String)>,
+) -> impl IntoResponse {
+    // Get project's reports and foundation's weights from database
+    let reports = db
+        .project_reports(&foundation, &project)
+        .await
+        .map_err(internal_error)?;
+    if reports.is_empty() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let weights = db
+        .foundation_weights(&foundation)
+        .await
+        .map_err(internal_error)?
+        .unwrap_or_default();
+
+    // Return score trace as json
+    let trace: ScoreTrace = score::trace(&reports, &weights);
+    let headers = [(CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE))];
+    Ok((headers, response::Json(trace)))
+}
+
+/// Score simulation input.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct ScoreSimulationInput {
+    pub checks: Vec<String>,
+}
+
+/// Handler that simulates the score a project would get if the checks
+/// provided passed in all its repositories, using the foundation's weights in
+/// effect. It helps prioritizing the fixes with the biggest impact.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{foundation}/{project}/score/simulate",
+    tag = "projects",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    request_body = ScoreSimulationInput,
+    responses(
+        (status = 200, description = "Current and simulated project's score", body = ScoreSimulation),
+        (status = 400, description = "Invalid input provided"),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn simulate_score(
+    State(db): State<DynDB>,
+    Path((foundation, project)): Path<(String, String)>,
+    response::Json(input): response::Json<ScoreSimulationInput>,
+) -> impl IntoResponse {
+    if input.checks.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // Get project's reports and foundation's weights from database
+    let reports = db
+        .project_reports(&foundation, &project)
+        .await
+        .map_err(internal_error)?;
+    if reports.is_empty() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let weights = db
+        .foundation_weights(&foundation)
+        .await
+        .map_err(internal_error)?
+        .unwrap_or_default();
+
+    // Simulate score and return the result as json
+    let check_ids: Vec<&str> = input.checks.iter().map(String::as_str).collect();
+    let simulation: ScoreSimulation =
+        score::simulate(&reports, &weights, &check_ids).map_err(|_| StatusCode::BAD_REQUEST)?;
+    Ok(response::Json(simulation))
+}
+
+/// Handler that allows searching for projects. When facets are requested,
+/// the projects found are returned along with the number of projects matching
+/// per foundation, maturity level and rating.
+#[utoipa::path(
+    get,
+    path = "/api/projects/search",
+    tag = "projects",
+    params(SearchProjectsInput),
+    responses(
+        (
+            status = 200,
+            description = "Projects found",
+            body = Object,
+            headers(
+                ("pagination-total-count" = usize, description = "Number of projects available"),
+                ("pagination-next-cursor" = String, description = "Cursor to fetch the next page of results"),
+            )
+        ),
+        (status = 400, description = "Invalid input provided"),
+    )
+)]
 pub(crate) async fn search_projects(
     State(db): State<DynDB>,
     RawQuery(query): RawQuery,
@@ -355,18 +1439,263 @@ pub(crate) async fn search_projects(
     let query = query.unwrap_or_default();
     let input: SearchProjectsInput =
         serde_qs::from_str(&query).map_err(|_| StatusCode::BAD_REQUEST)?;
-    let (count, projects) = db.search_projects(&input).await.map_err(internal_error)?;
+    if let Some(sort_by) = input.sort_by.as_deref() {
+        if !["name", "score"].contains(&sort_by) && !SEARCH_SORT_SECTIONS.contains(&sort_by) {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+    }
+    let output = db.search_projects(&input).await.map_err(internal_error)?;
 
     // Return search results as json
-    Response::builder()
+    let body = if input.facets.unwrap_or(false) {
+        format!(
+            r#"{{"projects":{},"facets":{}}}"#,
+            output.projects, output.facets
+        )
+    } else {
+        output.projects
+    };
+    let mut builder = Response::builder()
         .header(CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE))
         .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
-        .header(PAGINATION_TOTAL_COUNT, count.to_string())
-        .body(Full::from(projects))
-        .map_err(internal_error)
+        .header(PAGINATION_TOTAL_COUNT, output.count.to_string());
+    if let Some(next_cursor) = output.next_cursor {
+        builder = builder.header(PAGINATION_NEXT_CURSOR, next_cursor);
+    }
+    builder.body(Full::from(body)).map_err(internal_error)
+}
+
+/// Handler that returns the projects whose name, display name or repositories
+/// are similar to the text provided, ranked by similarity. It is meant to be
+/// used to suggest projects while the user types a search, so typos and
+/// partial repositories urls are tolerated.
+#[utoipa::path(
+    get,
+    path = "/api/projects/suggest",
+    tag = "projects",
+    params(
+        ("q" = String, Query, description = "Text to get suggestions for"),
+        ("limit" = Option<i32>, Query, description = "Maximum number of projects returned (1-20)"),
+    ),
+    responses(
+        (status = 200, description = "Projects suggested", body = Object),
+        (status = 400, description = "Invalid input provided"),
+    )
+)]
+pub(crate) async fn suggest_projects(
+    State(db): State<DynDB>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Get suggestions from database
+    let text = match params.get("q").map(|q| q.trim()) {
+        Some(text) if !text.is_empty() && text.chars().count() <= SUGGESTIONS_TEXT_MAX_LEN => text,
+        _ => return Err(StatusCode::BAD_REQUEST),
+    };
+    let limit = match params.get("limit") {
+        Some(limit) => limit
+            .parse::<i32>()
+            .ok()
+            .filter(|limit| (1..=SUGGESTIONS_MAX_LIMIT).contains(limit))
+            .ok_or(StatusCode::BAD_REQUEST)?,
+        None => SUGGESTIONS_DEFAULT_LIMIT,
+    };
+    let projects = db
+        .suggest_projects(text, limit)
+        .await
+        .map_err(internal_error)?;
+
+    // Return suggestions as json
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+    ];
+    Ok((headers, projects))
+}
+
+/// Handler that returns the repositories matching the url provided, including
+/// their reports.
+#[utoipa::path(
+    get,
+    path = "/api/repositories/search",
+    tag = "repositories",
+    params(("url" = String, Query, description = "Repository url (case, trailing slashes and .git suffix are ignored)")),
+    responses(
+        (status = 200, description = "Repositories found", body = Object),
+        (status = 400, description = "Invalid input provided"),
+    )
+)]
+pub(crate) async fn search_repositories(
+    State(db): State<DynDB>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Search repositories in database
+    let url = match params.get("url") {
+        Some(url) if !url.trim().is_empty() => url,
+        _ => return Err(StatusCode::BAD_REQUEST),
+    };
+    let repositories = db.search_repositories(url).await.map_err(internal_error)?;
+    let repositories = add_repositories_remediations(repositories).map_err(internal_error)?;
+
+    // Return search results as json
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+        (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+    ];
+    Ok((headers, repositories))
+}
+
+/// Template for the section badge SVG image.
+#[derive(Debug, Clone, Template, Serialize)]
+#[template(path = "section-badge.svg")]
+pub(crate) struct SectionBadgeTemplate {
+    pub section: String,
+    pub score: Option<f64>,
+    pub theme: String,
+}
+
+impl SectionBadgeTemplate {
+    /// Create a new SectionBadgeTemplate instance for the section provided,
+    /// using its name in the language given. None is returned when the
+    /// section is not valid.
+    fn new(score: &Score, section: &str, theme: Option<String>, lang: Lang) -> Option<Self> {
+        let section = match section {
+            "best-practices" => ReportSection::BestPractices,
+            section => section.parse().ok()?,
+        };
+        let theme = theme.unwrap_or_else(|| "light".to_string());
+        Some(Self {
+            section: lang.messages().section(section).to_string(),
+            score: score.section(section),
+            theme,
+        })
+    }
+}
+
+/// Handler that returns an SVG badge with the score of the project's report
+/// section provided.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{foundation}/{project}/badge/{section}",
+    tag = "projects",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("project" = String, Path, description = "Project name"),
+        ("section" = String, Path, description = "Report section: documentation, license, best_practices, security, legal, artifacts or organization"),
+        ("theme" = Option<String>, Query, description = "Theme: light (default) or dark"),
+        ("lang" = Option<String>, Query, description = "Language: en (default), es, ja or zh"),
+    ),
+    responses(
+        (status = 200, description = "Badge with the score of the section", body = String, content_type = "image/svg+xml"),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn section_badge(
+    State(db): State<DynDB>,
+    State(overrides): State<Arc<Overrides>>,
+    Path((foundation, project, section)): Path<(String, String, String)>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Get project score from database
+    let score = db
+        .project_score(&foundation, &project)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Render section badge SVG and return it if the section is valid
+    let theme = params.get("theme").cloned();
+    let lang = Lang::from_code(params.get("lang").map(String::as_str));
+    match SectionBadgeTemplate::new(&score, &section, theme, lang) {
+        Some(badge) => {
+            let svg = overrides.render(&badge).map_err(internal_error)?;
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE)),
+                (CONTENT_TYPE, IMAGE_SVG.to_string()),
+            ];
+            Ok((headers, svg))
+        }
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+/// Urls of the files of a public dataset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub(crate) struct DatasetFiles {
+    pub json: String,
+    pub parquet: String,
+}
+
+/// Public dataset published by the archiver.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub(crate) struct Dataset {
+    pub date: String,
+    pub files: DatasetFiles,
+}
+
+/// Manifest listing the public datasets available.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub(crate) struct DatasetsManifest {
+    pub version: String,
+    pub datasets: Vec<Dataset>,
+}
+
+/// Handler that returns the manifest of the public datasets published daily
+/// by the archiver, including the urls of each date's JSON and Parquet files.
+#[utoipa::path(
+    get,
+    path = "/api/datasets",
+    tag = "datasets",
+    responses(
+        (status = 200, description = "Datasets manifest", body = DatasetsManifest),
+        (status = 404, description = "Datasets not available"),
+    )
+)]
+pub(crate) async fn datasets(
+    State(cfg): State<Arc<Config>>,
+    State(db): State<DynDB>,
+) -> impl IntoResponse {
+    // Datasets are only available when their public url has been configured
+    let Ok(base_url) = cfg.get_string("apiserver.datasets.baseURL") else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+    let base_url = base_url.trim_end_matches('/');
+
+    // Get datasets published from database and build manifest
+    let datasets = db
+        .datasets()
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|date| {
+            let prefix = format!("{base_url}/{DATASET_VERSION}/{date}");
+            Dataset {
+                date: date.to_string(),
+                files: DatasetFiles {
+                    json: format!("{prefix}/projects.json"),
+                    parquet: format!("{prefix}/checks.parquet"),
+                },
+            }
+        })
+        .collect();
+    let manifest = DatasetsManifest {
+        version: DATASET_VERSION.to_string(),
+        datasets,
+    };
+
+    let headers = [(CACHE_CONTROL, format!("max-age={}", DEFAULT_API_MAX_AGE))];
+    Ok((headers, response::Json(manifest)))
 }
 
 /// Handler that returns some general stats.
+#[utoipa::path(
+    get,
+    path = "/api/stats",
+    tag = "stats",
+    params(("foundation" = Option<String>, Query, description = "Foundation identifier")),
+    responses(
+        (status = 200, description = "General stats", body = Object),
+    )
+)]
 pub(crate) async fn stats(
     State(db): State<DynDB>,
     Query(params): Query<HashMap<String, String>>,
@@ -385,7 +1714,138 @@ pub(crate) async fn stats(
         .map_err(internal_error)
 }
 
+/// Handler that returns the percentage of repositories passing each check, now
+/// and monthly in the past.
+#[utoipa::path(
+    get,
+    path = "/api/stats/checks",
+    tag = "stats",
+    params(("foundation" = Option<String>, Query, description = "Foundation identifier")),
+    responses(
+        (status = 200, description = "Percentage of repositories passing each check", body = Object),
+    )
+)]
+pub(crate) async fn stats_checks(
+    State(db): State<DynDB>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Get checks stats from database
+    let stats = db
+        .stats_checks(params.get("foundation").map(|p| p.as_str()))
+        .await
+        .map_err(internal_error)?;
+
+    // Return checks stats as json
+    Response::builder()
+        .header(CACHE_CONTROL, "max-age=3600")
+        .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+        .body(Full::from(stats))
+        .map_err(internal_error)
+}
+
+/// Handler that returns the top rated projects of each maturity level.
+#[utoipa::path(
+    get,
+    path = "/api/stats/leaderboard",
+    tag = "stats",
+    params(
+        ("foundation" = Option<String>, Query, description = "Foundation identifier"),
+        ("limit" = Option<i32>, Query, description = "Number of projects per maturity level (10 by default, 50 max)"),
+    ),
+    responses(
+        (status = 200, description = "Top rated projects indexed by maturity level", body = Object),
+        (status = 400, description = "Invalid limit provided"),
+    )
+)]
+pub(crate) async fn stats_leaderboard(
+    State(db): State<DynDB>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let limit = stats_ranking_limit(&params)?;
+
+    // Get leaderboard from database
+    let stats = db
+        .stats_leaderboard(params.get("foundation").map(|p| p.as_str()), limit)
+        .await
+        .map_err(internal_error)?;
+
+    // Return leaderboard as json
+    Response::builder()
+        .header(CACHE_CONTROL, "max-age=3600")
+        .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+        .body(Full::from(stats))
+        .map_err(internal_error)
+}
+
+/// Handler that returns the projects whose score increased the most during
+/// the last 30 or 90 days.
+#[utoipa::path(
+    get,
+    path = "/api/stats/most-improved",
+    tag = "stats",
+    params(
+        ("foundation" = Option<String>, Query, description = "Foundation identifier"),
+        ("period" = Option<i32>, Query, description = "Period in days (30 or 90, 30 by default)"),
+        ("limit" = Option<i32>, Query, description = "Number of projects (10 by default, 50 max)"),
+    ),
+    responses(
+        (status = 200, description = "Projects sorted by score gain", body = Object),
+        (status = 400, description = "Invalid period or limit provided"),
+    )
+)]
+pub(crate) async fn stats_most_improved(
+    State(db): State<DynDB>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let period = match params.get("period") {
+        Some(period) => period
+            .parse::<i32>()
+            .ok()
+            .filter(|period| MOST_IMPROVED_PERIODS.contains(period))
+            .ok_or(StatusCode::BAD_REQUEST)?,
+        None => MOST_IMPROVED_PERIODS[0],
+    };
+    let limit = stats_ranking_limit(&params)?;
+
+    // Get most improved projects from database
+    let stats = db
+        .stats_most_improved(params.get("foundation").map(|p| p.as_str()), period, limit)
+        .await
+        .map_err(internal_error)?;
+
+    // Return most improved projects as json
+    Response::builder()
+        .header(CACHE_CONTROL, "max-age=3600")
+        .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+        .body(Full::from(stats))
+        .map_err(internal_error)
+}
+
+/// Get the number of projects to return in the stats rankings from the query
+/// parameters provided.
+fn stats_ranking_limit(params: &HashMap<String, String>) -> Result<i32, StatusCode> {
+    match params.get("limit") {
+        Some(limit) => limit
+            .parse::<i32>()
+            .ok()
+            .filter(|limit| (1..=STATS_RANKING_MAX_LIMIT).contains(limit))
+            .ok_or(StatusCode::BAD_REQUEST),
+        None => Ok(STATS_RANKING_DEFAULT_LIMIT),
+    }
+}
+
 /// Handler that returns the requested stats snapshot.
+#[utoipa::path(
+    get,
+    path = "/api/stats/snapshots/{date}",
+    tag = "stats",
+    params(("date" = String, Path, description = "Snapshot date (YYYY-MM-DD)"), ("foundation" = Option<String>, Query, description = "Foundation identifier")),
+    responses(
+        (status = 200, description = "Stats snapshot", body = Object),
+        (status = 400, description = "Invalid input provided"),
+        (status = 404, description = "Not found"),
+    )
+)]
 pub(crate) async fn stats_snapshot(
     State(db): State<DynDB>,
     Path(date): Path<String>,
@@ -413,7 +1873,74 @@ pub(crate) async fn stats_snapshot(
     }
 }
 
+/// Handler that returns the changes since the unix timestamp provided, used by
+/// mirror instances to keep their databases in sync with the primary one.
+#[utoipa::path(
+    get,
+    path = "/api/sync/delta",
+    tag = "stats",
+    params(("since" = Option<i64>, Query, description = "Unix timestamp (all data is returned when not provided)")),
+    responses(
+        (status = 200, description = "Foundations, projects updated and projects removed since the timestamp provided", body = Object),
+        (status = 400, description = "Invalid input provided"),
+    )
+)]
+pub(crate) async fn sync_delta(
+    State(db): State<DynDB>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    // Get changes since the timestamp provided from database
+    let since = match params.get("since") {
+        Some(since) => since.parse::<i64>().map_err(|_| StatusCode::BAD_REQUEST)?,
+        None => 0,
+    };
+    let delta = db.sync_delta(since).await.map_err(internal_error)?;
+
+    // Return changes as json
+    let headers = [
+        (CACHE_CONTROL, "max-age=0".to_string()),
+        (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+    ];
+    Ok::<_, StatusCode>((headers, delta))
+}
+
+/// Handler that returns the progress of the latest tracker run.
+#[utoipa::path(
+    get,
+    path = "/api/tracker/progress",
+    tag = "stats",
+    responses(
+        (status = 200, description = "Progress of the latest tracker run", body = Object),
+        (status = 404, description = "Not found"),
+    )
+)]
+pub(crate) async fn tracker_run_progress(State(db): State<DynDB>) -> impl IntoResponse {
+    // Get tracker run progress from database
+    let progress = db.tracker_run_progress().await.map_err(internal_error)?;
+
+    // Return progress as json if there is any run
+    match progress {
+        Some(progress) => {
+            let headers = [
+                (CACHE_CONTROL, "max-age=60".to_string()),
+                (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+            ];
+            Ok((headers, progress))
+        }
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
 /// Handler used to track a project view.
+#[utoipa::path(
+    post,
+    path = "/api/projects/views/{project_id}",
+    tag = "projects",
+    params(("project_id" = Uuid, Path, description = "Project identifier")),
+    responses(
+        (status = 204, description = "View tracked"),
+    )
+)]
 pub(crate) async fn track_view(
     State(vt): State<DynVT>,
     Path(project_id): Path<Uuid>,
@@ -424,11 +1951,918 @@ pub(crate) async fn track_view(
     }
 }
 
+/// Input used to request tracking some of the foundation's projects on
+/// demand. All the foundation's projects are requested when no projects are
+/// provided.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct TrackProjectsInput {
+    pub projects: Option<Vec<String>>,
+}
+
+/// Projects whose tracking has been requested on demand.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct TrackProjectsOutput {
+    pub projects: Vec<String>,
+}
+
+/// Handler used to request processing the foundation's data file on demand
+/// (i.e. from the data file repository's CI after merging some changes). It
+/// will be processed by the next on demand registrar run, instead of waiting
+/// for the next scheduled one.
+#[utoipa::path(
+    post,
+    path = "/api/admin/foundations/{foundation}/register",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier")),
+    responses(
+        (status = 202, description = "Registration requested"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn request_foundation_registration(
+    State(db): State<DynDB>,
+    Path(foundation): Path<String>,
+) -> impl IntoResponse {
+    match db.request_foundation_registration(&foundation).await {
+        Ok(true) => StatusCode::ACCEPTED,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Handler used by GitHub users to claim the maintainership of a project. The
+/// claim is granted when the user has write access to any of the project's
+/// repositories, assigning the project maintainer role to the user's subject.
+#[utoipa::path(
+    post,
+    path = "/api/admin/projects/{foundation}/{project}/claim",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 201, description = "Project maintainer role assigned", body = Object),
+        (status = 400, description = "Not authenticated using a GitHub token"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "No write access to any of the project's repositories"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn claim_project(
+    State(cfg): State<Arc<Config>>,
+    State(db): State<DynDB>,
+    Extension(identity): Extension<Identity>,
+    Path((foundation, project)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    // Only GitHub users can claim projects, as their token is needed to check
+    // their permissions on the project's repositories
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+    if !identity.subject.starts_with(github::SUBJECT_PREFIX) || !github::is_user_token(token) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // Get the urls of the project's repositories
+    let project_data = db
+        .project_data(&foundation, &project)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let project_data: Value = serde_json::from_str(&project_data).map_err(internal_error)?;
+    let repositories_urls: Vec<&str> = project_data
+        .get("repositories")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|repository| repository.get("url").and_then(Value::as_str))
+        .collect();
+
+    // Check the user has write access to any of them
+    let api_url = github::api_url(&cfg);
+    let mut has_write_access = false;
+    for url in repositories_urls {
+        if github::has_write_access(&api_url, token, url)
+            .await
+            .map_err(internal_error)?
+        {
+            has_write_access = true;
+            break;
+        }
+    }
+    if !has_write_access {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    // Assign the project maintainer role to the user
+    let assignment = RoleAssignment {
+        subject: identity.subject,
+        role: Role::ProjectMaintainer {
+            foundation,
+            project,
+        },
+    };
+    let role_assignment_id = db
+        .add_role_assignment(&assignment)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok((
+        StatusCode::CREATED,
+        response::Json(json!({ "role_assignment_id": role_assignment_id })),
+    ))
+}
+
+/// Handler used to request tracking the project's repositories on demand.
+/// They'll be tracked by the next on demand tracker run, even if they haven't
+/// changed since the last time they were tracked.
+#[utoipa::path(
+    post,
+    path = "/api/admin/projects/{foundation}/{project}/track",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 202, description = "Tracking requested"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn track_project(
+    State(db): State<DynDB>,
+    Path((foundation, project)): Path<(String, String)>,
+) -> impl IntoResponse {
+    match db
+        .request_projects_tracking(&foundation, Some(&[project][..]))
+        .await
+    {
+        Ok(projects) if projects.is_empty() => StatusCode::NOT_FOUND,
+        Ok(_) => StatusCode::ACCEPTED,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Events project webhooks can be subscribed to (same ones supported by the
+/// tracker's notifications subscriptions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum WebhookEvent {
+    RatingDrop,
+    ScoreDrop,
+    SecurityCheckFailed,
+}
+
+/// Project webhook registration input. Webhooks receive the same payload
+/// posted to Slack incoming webhooks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct ProjectWebhookInput {
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+/// Handler that returns the webhooks registered for the project.
+#[utoipa::path(
+    get,
+    path = "/api/admin/projects/{foundation}/{project}/webhooks",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 200, description = "Project webhooks", body = Object),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn project_webhooks(
+    State(db): State<DynDB>,
+    Path((foundation, project)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let webhooks = db
+        .project_webhooks(&foundation, &project)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok::<_, StatusCode>(([(CONTENT_TYPE, APPLICATION_JSON.as_ref())], webhooks))
+}
+
+/// Handler used to register a webhook for the project. Webhooks are notified
+/// when the events they are subscribed to happen in the project.
+#[utoipa::path(
+    post,
+    path = "/api/admin/projects/{foundation}/{project}/webhooks",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    request_body = ProjectWebhookInput,
+    responses(
+        (status = 201, description = "Webhook registered", body = Object),
+        (status = 400, description = "Invalid input provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn add_project_webhook(
+    State(db): State<DynDB>,
+    Extension(identity): Extension<Identity>,
+    Path((foundation, project)): Path<(String, String)>,
+    response::Json(input): response::Json<ProjectWebhookInput>,
+) -> impl IntoResponse {
+    if !input.url.starts_with("https://") || input.events.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let project_webhook_id = db
+        .add_project_webhook(&foundation, &project, &identity.subject, &input)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok((
+        StatusCode::CREATED,
+        response::Json(json!({ "project_webhook_id": project_webhook_id })),
+    ))
+}
+
+/// Handler used to delete one of the project's webhooks.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/projects/{foundation}/{project}/webhooks/{project_webhook_id}",
+    tag = "admin",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("project" = String, Path, description = "Project name"),
+        ("project_webhook_id" = Uuid, Path, description = "Project webhook identifier"),
+    ),
+    responses(
+        (status = 204, description = "Webhook deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn delete_project_webhook(
+    State(db): State<DynDB>,
+    Path((foundation, project, project_webhook_id)): Path<(String, String, Uuid)>,
+) -> impl IntoResponse {
+    match db
+        .delete_project_webhook(&foundation, &project, &project_webhook_id)
+        .await
+    {
+        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Handler that returns the checks exemptions acknowledged by the project's
+/// maintainers.
+#[utoipa::path(
+    get,
+    path = "/api/admin/projects/{foundation}/{project}/exemptions",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name")),
+    responses(
+        (status = 200, description = "Exemptions acknowledged", body = Object),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn exemption_acknowledgements(
+    State(db): State<DynDB>,
+    Path((foundation, project)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let acknowledgements = db
+        .exemption_acknowledgements(&foundation, &project)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok::<_, StatusCode>((
+        [(CONTENT_TYPE, APPLICATION_JSON.as_ref())],
+        acknowledgements,
+    ))
+}
+
+/// Handler used to acknowledge the exemption of a check declared in the
+/// project's repositories metadata, recording who acknowledged it.
+#[utoipa::path(
+    put,
+    path = "/api/admin/projects/{foundation}/{project}/exemptions/{check_id}",
+    tag = "admin",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("project" = String, Path, description = "Project name"),
+        ("check_id" = String, Path, description = "Check identifier"),
+    ),
+    responses(
+        (status = 204, description = "Exemption acknowledged"),
+        (status = 400, description = "Unknown check"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn acknowledge_exemption(
+    State(db): State<DynDB>,
+    Extension(identity): Extension<Identity>,
+    Path((foundation, project, check_id)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    if remediation(&check_id).is_none() {
+        return StatusCode::BAD_REQUEST;
+    }
+    match db
+        .acknowledge_exemption(&foundation, &project, &check_id, &identity.subject)
+        .await
+    {
+        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Handler used to withdraw the acknowledgement of a check exemption.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/projects/{foundation}/{project}/exemptions/{check_id}",
+    tag = "admin",
+    params(
+        ("foundation" = String, Path, description = "Foundation identifier"),
+        ("project" = String, Path, description = "Project name"),
+        ("check_id" = String, Path, description = "Check identifier"),
+    ),
+    responses(
+        (status = 204, description = "Exemption acknowledgement deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn delete_exemption_acknowledgement(
+    State(db): State<DynDB>,
+    Path((foundation, project, check_id)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    match db
+        .delete_exemption_acknowledgement(&foundation, &project, &check_id)
+        .await
+    {
+        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Handler used to request tracking the repositories of some of the
+/// foundation's projects (or all of them) on demand.
+#[utoipa::path(
+    post,
+    path = "/api/admin/foundations/{foundation}/track",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier")),
+    request_body = TrackProjectsInput,
+    responses(
+        (status = 202, description = "Tracking requested", body = TrackProjectsOutput),
+        (status = 400, description = "Invalid input provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn track_projects(
+    State(db): State<DynDB>,
+    Path(foundation): Path<String>,
+    response::Json(input): response::Json<TrackProjectsInput>,
+) -> impl IntoResponse {
+    if let Some(projects) = &input.projects {
+        if projects.is_empty() || projects.iter().any(|p| p.trim().is_empty()) {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+    }
+    let projects = db
+        .request_projects_tracking(&foundation, input.projects.as_deref())
+        .await
+        .map_err(internal_error)?;
+    if projects.is_empty() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok((
+        StatusCode::ACCEPTED,
+        response::Json(TrackProjectsOutput { projects }),
+    ))
+}
+
+/// Foundation's data ref update input.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct FoundationDataRefInput {
+    #[serde(rename = "ref")]
+    pub data_ref: Option<String>,
+}
+
+/// Handler used to update the ref (i.e. a git tag) used to build the
+/// foundation's data file url. A null ref clears the current one.
+#[utoipa::path(
+    put,
+    path = "/api/admin/foundations/{foundation}/data-ref",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier")),
+    request_body = FoundationDataRefInput,
+    responses(
+        (status = 204, description = "Data ref updated"),
+        (status = 400, description = "Invalid input provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn update_foundation_data_ref(
+    State(db): State<DynDB>,
+    Path(foundation): Path<String>,
+    response::Json(input): response::Json<FoundationDataRefInput>,
+) -> impl IntoResponse {
+    if let Some(data_ref) = &input.data_ref {
+        if data_ref.trim().is_empty() {
+            return StatusCode::BAD_REQUEST;
+        }
+    }
+    match db
+        .update_foundation_data_ref(&foundation, input.data_ref.as_deref())
+        .await
+    {
+        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Foundation's details provided when registering or updating it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct FoundationInput {
+    pub display_name: String,
+    pub data_url: String,
+    #[serde(rename = "ref")]
+    pub data_ref: Option<String>,
+    pub logo_url: Option<String>,
+}
+
+impl FoundationInput {
+    /// Check if the foundation's details are valid. The data file is not
+    /// checked here, as it needs to be fetched.
+    fn is_valid(&self) -> bool {
+        let is_http_url = |url: &str| url.starts_with("https://") || url.starts_with("http://");
+        !self.display_name.trim().is_empty()
+            && is_http_url(&self.data_url)
+            && self
+                .data_ref
+                .as_ref()
+                .map_or(true, |r| !r.trim().is_empty())
+            && self.logo_url.as_deref().map_or(true, is_http_url)
+    }
+}
+
+/// Input used to register a new foundation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct NewFoundationInput {
+    pub foundation_id: String,
+    #[serde(flatten)]
+    pub foundation: FoundationInput,
+}
+
+impl NewFoundationInput {
+    /// Check if the foundation's identifier and details are valid.
+    /// Identifiers can only contain lowercase alphanumeric characters and
+    /// dashes, as they are used in urls.
+    fn is_valid(&self) -> bool {
+        !self.foundation_id.is_empty()
+            && self
+                .foundation_id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && self.foundation.is_valid()
+    }
+}
+
+/// Handler used to register a new foundation. The foundation's data file must
+/// be reachable and parseable, otherwise the foundation is not registered.
+#[utoipa::path(
+    post,
+    path = "/api/admin/foundations",
+    tag = "admin",
+    request_body = NewFoundationInput,
+    responses(
+        (status = 201, description = "Foundation registered"),
+        (status = 400, description = "Invalid input provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 409, description = "Foundation already registered"),
+        (status = 422, description = "Data file cannot be fetched or parsed", body = String),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn register_foundation(
+    State(db): State<DynDB>,
+    State(restricted_client): State<RestrictedClient>,
+    response::Json(input): response::Json<NewFoundationInput>,
+) -> impl IntoResponse {
+    if !input.is_valid() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let foundation = &input.foundation;
+    if let Err(err) = datafile::validate(
+        &foundation.data_url,
+        foundation.data_ref.as_deref(),
+        &restricted_client,
+    )
+    .await
+    {
+        return (StatusCode::UNPROCESSABLE_ENTITY, format!("{err:#}")).into_response();
+    }
+    match db.register_foundation(&input).await {
+        Ok(true) => StatusCode::CREATED.into_response(),
+        Ok(false) => StatusCode::CONFLICT.into_response(),
+        Err(err) => internal_error(err).into_response(),
+    }
+}
+
+/// Handler used to update the details of a foundation. The foundation's data
+/// file must be reachable and parseable, otherwise no changes are applied.
+#[utoipa::path(
+    put,
+    path = "/api/admin/foundations/{foundation}",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier")),
+    request_body = FoundationInput,
+    responses(
+        (status = 204, description = "Foundation updated"),
+        (status = 400, description = "Invalid input provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+        (status = 422, description = "Data file cannot be fetched or parsed", body = String),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn update_foundation(
+    State(db): State<DynDB>,
+    State(restricted_client): State<RestrictedClient>,
+    Path(foundation): Path<String>,
+    response::Json(input): response::Json<FoundationInput>,
+) -> impl IntoResponse {
+    if !input.is_valid() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    if let Err(err) = datafile::validate(
+        &input.data_url,
+        input.data_ref.as_deref(),
+        &restricted_client,
+    )
+    .await
+    {
+        return (StatusCode::UNPROCESSABLE_ENTITY, format!("{err:#}")).into_response();
+    }
+    match db.update_foundation(&foundation, &input).await {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => StatusCode::NOT_FOUND.into_response(),
+        Err(err) => internal_error(err).into_response(),
+    }
+}
+
+/// Handler used to delete a foundation. Foundations can only be deleted once
+/// all their projects have been unregistered.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/foundations/{foundation}",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier")),
+    responses(
+        (status = 204, description = "Foundation deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Foundation still has projects registered"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn delete_foundation(
+    State(db): State<DynDB>,
+    Path(foundation): Path<String>,
+) -> impl IntoResponse {
+    match db.delete_foundation(&foundation).await {
+        Ok(Some(true)) => StatusCode::NO_CONTENT,
+        Ok(Some(false)) => StatusCode::NOT_FOUND,
+        Ok(None) => StatusCode::CONFLICT,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Handler used to assign a role to a subject.
+#[utoipa::path(
+    post,
+    path = "/api/admin/roles",
+    tag = "admin",
+    request_body = RoleAssignment,
+    responses(
+        (status = 201, description = "Role assigned", body = Object),
+        (status = 400, description = "Invalid input provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Foundation or project not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn add_role_assignment(
+    State(db): State<DynDB>,
+    response::Json(assignment): response::Json<RoleAssignment>,
+) -> impl IntoResponse {
+    if assignment.subject.trim().is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let role_assignment_id = db
+        .add_role_assignment(&assignment)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok((
+        StatusCode::CREATED,
+        response::Json(json!({ "role_assignment_id": role_assignment_id })),
+    ))
+}
+
+/// Handler used to delete a role assignment.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/roles/{role_assignment_id}",
+    tag = "admin",
+    params(("role_assignment_id" = Uuid, Path, description = "Role assignment identifier")),
+    responses(
+        (status = 204, description = "Role assignment deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn delete_role_assignment(
+    State(db): State<DynDB>,
+    Path(role_assignment_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match db.delete_role_assignment(&role_assignment_id).await {
+        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Handler used to issue a new API key. The key is only returned once, as
+/// only its hash is stored.
+#[utoipa::path(
+    post,
+    path = "/api/admin/api-keys",
+    tag = "admin",
+    request_body = ApiKeyInput,
+    responses(
+        (status = 201, description = "API key issued", body = Object),
+        (status = 400, description = "Invalid input provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn add_api_key(
+    State(db): State<DynDB>,
+    response::Json(input): response::Json<ApiKeyInput>,
+) -> impl IntoResponse {
+    if input.name.trim().is_empty() || input.rate_limit == Some(0) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let key = generate_api_key().map_err(internal_error)?;
+    let api_key_id = db
+        .add_api_key(&input.name, &hash_api_key(&key), input.rate_limit)
+        .await
+        .map_err(internal_error)?;
+    Ok((
+        StatusCode::CREATED,
+        response::Json(json!({ "api_key_id": api_key_id, "key": key })),
+    ))
+}
+
+/// Handler that returns all the API keys (without the keys themselves).
+#[utoipa::path(
+    get,
+    path = "/api/admin/api-keys",
+    tag = "admin",
+    responses(
+        (status = 200, description = "API keys", body = Object),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn api_keys(State(db): State<DynDB>) -> impl IntoResponse {
+    let api_keys = db.api_keys().await.map_err(internal_error)?;
+    Ok::<_, StatusCode>(([(CONTENT_TYPE, APPLICATION_JSON.as_ref())], api_keys))
+}
+
+/// Handler used to revoke an API key.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/api-keys/{api_key_id}",
+    tag = "admin",
+    params(("api_key_id" = Uuid, Path, description = "API key identifier")),
+    responses(
+        (status = 204, description = "API key revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn revoke_api_key(
+    State(db): State<DynDB>,
+    Path(api_key_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match db.revoke_api_key(&api_key_id).await {
+        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Handler that returns all the role assignments.
+#[utoipa::path(
+    get,
+    path = "/api/admin/roles",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Role assignments", body = [RoleAssignment]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn role_assignments(State(db): State<DynDB>) -> impl IntoResponse {
+    let role_assignments = db.role_assignments().await.map_err(internal_error)?;
+    Ok::<_, StatusCode>((
+        [(CONTENT_TYPE, APPLICATION_JSON.as_ref())],
+        role_assignments,
+    ))
+}
+
+/// Handler that returns the discrepancies found by the latest consistency
+/// check.
+#[utoipa::path(
+    get,
+    path = "/api/admin/consistency-issues",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Discrepancies found by the latest consistency check", body = Object),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn consistency_issues(State(db): State<DynDB>) -> impl IntoResponse {
+    let consistency_issues = db.consistency_issues().await.map_err(internal_error)?;
+    Ok::<_, StatusCode>((
+        [(CONTENT_TYPE, APPLICATION_JSON.as_ref())],
+        consistency_issues,
+    ))
+}
+
+/// Handler that returns a reproducibility bundle (gzipped tarball) of the
+/// repository's report, so that it can be replayed locally with the linter.
+#[utoipa::path(
+    get,
+    path = "/api/admin/projects/{foundation}/{project}/{repository}/bundle.tar.gz",
+    tag = "admin",
+    params(("foundation" = String, Path, description = "Foundation identifier"), ("project" = String, Path, description = "Project name"), ("repository" = String, Path, description = "Repository name")),
+    responses(
+        (status = 200, description = "Repository's reproducibility bundle", content_type = "application/gzip"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer_token" = []))
+)]
+pub(crate) async fn repository_bundle(
+    State(db): State<DynDB>,
+    Path((foundation, project, repository)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    // Get repository bundle information from database
+    let repository_bundle = db
+        .repository_bundle(&foundation, &project, &repository)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let weights = db
+        .foundation_weights(&foundation)
+        .await
+        .map_err(internal_error)?
+        .unwrap_or_default();
+
+    // Build bundle and return it
+    let tarball = bundle::build(&repository_bundle, &weights).map_err(internal_error)?;
+    let headers = [
+        (CACHE_CONTROL, "no-store".to_string()),
+        (CONTENT_TYPE, "application/gzip".to_string()),
+        (
+            CONTENT_DISPOSITION,
+            format!(r#"attachment; filename="{}""#, repository_bundle.filename()),
+        ),
+    ];
+    Ok((headers, tarball))
+}
+
+/// Build the key used to cache a document of the kind provided rendered from
+/// the given project's SVG image.
+fn rendered_cache_key(kind: &str, foundation: &str, project: &str, svg: &str) -> String {
+    let digest = hex::encode(Sha256::digest(svg.as_bytes()));
+    format!("{kind}:{foundation}:{project}:{digest}")
+}
+
+/// Fetch the image at the url provided, returning it as a data url so that it
+/// can be embedded in an SVG image. Only the urls allowed by the host policy
+/// of the restricted client given are fetched.
+async fn fetch_image(url: &str, restricted_client: &RestrictedClient) -> Result<String, Error> {
+    let resp = restricted_client
+        .get(url)?
+        .timeout(Duration::from_secs(LOGO_FETCH_TIMEOUT))
+        .send()
+        .await?
+        .error_for_status()?;
+
+    // Detect the image type from the url extension, falling back to the
+    // content type returned by the server
+    let path = url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let content_type = if path.ends_with(".svg") {
+        "image/svg+xml".to_string()
+    } else if path.ends_with(".png") {
+        "image/png".to_string()
+    } else if path.ends_with(".jpg") || path.ends_with(".jpeg") {
+        "image/jpeg".to_string()
+    } else {
+        resp.headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(';').next())
+            .map(|v| v.trim().to_string())
+            .unwrap_or_default()
+    };
+    if !["image/svg+xml", "image/png", "image/jpeg"].contains(&content_type.as_str()) {
+        return Err(format_err!("unsupported image type: {content_type}"));
+    }
+
+    let data = resp.bytes().await?;
+    Ok(format!(
+        "data:{content_type};base64,{}",
+        BASE64.encode(&data)
+    ))
+}
+
+/// Render the SVG image provided into a pixmap of the given dimensions.
+fn render_svg(svg: &str, width: u32, height: u32) -> Result<tiny_skia::Pixmap, Error> {
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_system_fonts();
+    opt.font_family = "Open Sans SemiBold".to_string();
+    let rtree = usvg::Tree::from_data(svg.as_bytes(), &opt.to_ref())?;
+    let mut pixmap =
+        tiny_skia::Pixmap::new(width, height).expect("width or height provided are not zero");
+    resvg::render(
+        &rtree,
+        usvg::FitTo::Size(width, height),
+        tiny_skia::Transform::default(),
+        pixmap.as_mut(),
+    )
+    .expect("width or height provided are not zero");
+    Ok(pixmap)
+}
+
 /// Helper for mapping any error into a `500 Internal Server Error` response.
+/// Operations not supported by the database backend in use (i.e. the SQLite
+/// database used by mirrors) are mapped into a `501 Not Implemented` one.
 fn internal_error<E>(err: E) -> StatusCode
 where
     E: Into<Error> + Display,
 {
+    let err: Error = err.into();
+    if err.is::<UnsupportedError>() {
+        debug!("{err}");
+        return StatusCode::NOT_IMPLEMENTED;
+    }
     error!("{err}");
     StatusCode::INTERNAL_SERVER_ERROR
 }
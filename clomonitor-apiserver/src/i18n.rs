@@ -0,0 +1,141 @@
+use clomonitor_core::linter::ReportSection;
+use serde::Serialize;
+
+/// Languages the report summaries and badges can be rendered in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Lang {
+    #[default]
+    En,
+    Es,
+    Ja,
+    Zh,
+}
+
+impl Lang {
+    /// Get the language corresponding to the code provided (i.e. `es` or
+    /// `zh-CN`). English is used when no code is provided or the language is
+    /// not supported.
+    pub(crate) fn from_code(code: Option<&str>) -> Self {
+        let code = code.unwrap_or_default().trim().to_lowercase();
+        match code.split(['-', '_']).next().unwrap_or_default() {
+            "es" => Self::Es,
+            "ja" => Self::Ja,
+            "zh" => Self::Zh,
+            _ => Self::En,
+        }
+    }
+
+    /// Return the catalog of messages of the language.
+    pub(crate) fn messages(&self) -> Messages {
+        match self {
+            Self::En => EN,
+            Self::Es => ES,
+            Self::Ja => JA,
+            Self::Zh => ZH,
+        }
+    }
+}
+
+/// Catalog of the messages used in the report summaries and badges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub(crate) struct Messages {
+    pub documentation: &'static str,
+    pub license: &'static str,
+    pub best_practices: &'static str,
+    pub security: &'static str,
+    pub legal: &'static str,
+    pub artifacts: &'static str,
+    pub organization: &'static str,
+    pub report: &'static str,
+    pub not_processed: &'static str,
+}
+
+impl Messages {
+    /// Return the name of the report section provided.
+    pub(crate) fn section(&self, section: ReportSection) -> &'static str {
+        match section {
+            ReportSection::Documentation => self.documentation,
+            ReportSection::License => self.license,
+            ReportSection::BestPractices => self.best_practices,
+            ReportSection::Security => self.security,
+            ReportSection::Legal => self.legal,
+            ReportSection::Artifacts => self.artifacts,
+            ReportSection::Organization => self.organization,
+        }
+    }
+}
+
+const EN: Messages = Messages {
+    documentation: "Documentation",
+    license: "License",
+    best_practices: "Best Practices",
+    security: "Security",
+    legal: "Legal",
+    artifacts: "Artifacts",
+    organization: "Organization",
+    report: "CLOMonitor Report",
+    not_processed: "not processed yet",
+};
+
+const ES: Messages = Messages {
+    documentation: "Documentación",
+    license: "Licencia",
+    best_practices: "Buenas prácticas",
+    security: "Seguridad",
+    legal: "Legal",
+    artifacts: "Artefactos",
+    organization: "Organización",
+    report: "Informe CLOMonitor",
+    not_processed: "aún no procesado",
+};
+
+const JA: Messages = Messages {
+    documentation: "ドキュメント",
+    license: "ライセンス",
+    best_practices: "ベストプラクティス",
+    security: "セキュリティ",
+    legal: "法務",
+    artifacts: "成果物",
+    organization: "組織",
+    report: "CLOMonitor レポート",
+    not_processed: "未処理",
+};
+
+const ZH: Messages = Messages {
+    documentation: "文档",
+    license: "许可证",
+    best_practices: "最佳实践",
+    security: "安全",
+    legal: "法律",
+    artifacts: "制品",
+    organization: "组织",
+    report: "CLOMonitor 报告",
+    not_processed: "尚未处理",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lang_from_code() {
+        assert_eq!(Lang::from_code(None), Lang::En);
+        assert_eq!(Lang::from_code(Some("es")), Lang::Es);
+        assert_eq!(Lang::from_code(Some("ja-JP")), Lang::Ja);
+        assert_eq!(Lang::from_code(Some("zh_CN")), Lang::Zh);
+        assert_eq!(Lang::from_code(Some("ZH")), Lang::Zh);
+        assert_eq!(Lang::from_code(Some("fr")), Lang::En);
+    }
+
+    #[test]
+    fn messages_section() {
+        assert_eq!(
+            Lang::Es.messages().section(ReportSection::BestPractices),
+            "Buenas prácticas"
+        );
+        assert_eq!(
+            Lang::En.messages().section(ReportSection::Security),
+            "Security"
+        );
+    }
+}
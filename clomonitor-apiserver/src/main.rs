@@ -1,23 +1,39 @@
-use crate::{db::PgDB, views::ViewsTrackerDB};
-use anyhow::{Context, Result};
+use crate::{
+    db::{DynDB, PgDB, SqliteDB},
+    mirror::{Syncer, DEFAULT_SYNC_INTERVAL},
+    views::ViewsTrackerDB,
+};
+use anyhow::{format_err, Context, Result};
 use clap::Parser;
+use clomonitor_core::{cache, net};
 use config::{Config, File};
 use deadpool_postgres::{Config as DbConfig, Runtime};
 use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
 use std::net::SocketAddr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::{signal, sync::RwLock};
-use tracing::{debug, info};
+use tracing::{debug, info, info_span, Instrument};
 use tracing_subscriber::EnvFilter;
 
+mod auth;
+mod bundle;
+mod datafile;
 mod db;
 mod filters;
+mod github;
 mod handlers;
+mod i18n;
 mod middleware;
+mod mirror;
+mod openapi;
+mod pdf;
+mod ratelimit;
 mod router;
+mod templates;
 mod views;
 
 #[derive(Debug, Parser)]
@@ -41,6 +57,13 @@ async fn main() -> Result<()> {
         .context("error setting up configuration")?;
     let cfg = Arc::new(cfg);
 
+    // Setup outbound HTTP proxy (if any)
+    net::setup_proxy(
+        cfg.get_string("http.proxy").ok().as_deref(),
+        cfg.get_string("http.noProxy").ok().as_deref(),
+    )
+    .context("error setting up outbound http proxy")?;
+
     // Setup logging
     if std::env::var_os("RUST_LOG").is_none() {
         std::env::set_var("RUST_LOG", "clomonitor_apiserver=debug,tower_http=debug")
@@ -48,21 +71,63 @@ async fn main() -> Result<()> {
     let s = tracing_subscriber::fmt().with_env_filter(EnvFilter::from_default_env());
     match cfg.get_string("log.format").as_deref() {
         Ok("json") => s.json().init(),
-        _ => s.init(),
+        Ok("pretty") | Err(_) => s.init(),
+        Ok(format) => return Err(format_err!("invalid log format: {format} (log.format)")),
     };
 
+    // All the events logged by this run are recorded within a span identified
+    // by its id, so that they can be correlated (requests spans are linked to
+    // the run as well)
+    let run_span = info_span!("run", run_id = %*router::RUN_ID);
+
     // Setup database
     debug!("setting up database");
-    let mut builder = SslConnector::builder(SslMethod::tls())?;
-    builder.set_verify(SslVerifyMode::NONE);
-    let connector = MakeTlsConnector::new(builder.build());
-    let db_cfg: DbConfig = cfg.get("db")?;
-    let pool = db_cfg.create_pool(Some(Runtime::Tokio1), connector)?;
-    let db = Arc::new(PgDB::new(pool));
+    let mirror_enabled = cfg.get_bool("apiserver.mirror.enabled").unwrap_or(false);
+    let db: DynDB = match cfg.get_string("db.sqlite.path") {
+        Ok(path) => {
+            // The SQLite database only holds the data synced from a primary
+            if !mirror_enabled {
+                return Err(format_err!(
+                    "the sqlite database can only be used in mirror mode"
+                ));
+            }
+            info!("using sqlite database ({path})");
+            Arc::new(SqliteDB::new(Path::new(&path))?)
+        }
+        Err(_) => {
+            let mut builder = SslConnector::builder(SslMethod::tls())?;
+            builder.set_verify(SslVerifyMode::NONE);
+            let connector = MakeTlsConnector::new(builder.build());
+            let db_cfg: DbConfig = cfg.get("db")?;
+            let pool = db_cfg.create_pool(Some(Runtime::Tokio1), connector)?;
+            Arc::new(PgDB::new(pool))
+        }
+    };
 
     // Setup views tracker
     let vt = Arc::new(RwLock::new(ViewsTrackerDB::new(db.clone())));
 
+    // Setup mirror syncer when running in read-only mirror mode
+    let mut syncer = if mirror_enabled {
+        let primary_url = cfg.get_string("apiserver.mirror.primaryURL")?;
+        let interval = cfg
+            .get::<u64>("apiserver.mirror.syncInterval")
+            .unwrap_or(DEFAULT_SYNC_INTERVAL);
+        info!("running in read-only mirror mode (primary: {primary_url})");
+        Some(Syncer::new(
+            db.clone(),
+            primary_url,
+            Duration::from_secs(interval),
+        )?)
+    } else {
+        None
+    };
+
+    // Setup cache
+    debug!("setting up cache");
+    let redis_url = cfg.get_string("cache.redis.url").ok();
+    let cache = cache::setup(redis_url.as_deref()).await?;
+
     // Setup and launch Prometheus exporter
     debug!("setting up prometheus exporter");
     PrometheusBuilder::new()
@@ -76,17 +141,21 @@ async fn main() -> Result<()> {
 
     // Setup and launch API HTTP server
     debug!("setting up apiserver");
-    let router = router::setup(cfg.clone(), db, vt.clone())?;
+    let router = router::setup(cfg.clone(), db, vt.clone(), cache)?;
     let addr: SocketAddr = cfg.get_string("apiserver.addr")?.parse()?;
     info!("apiserver started");
     info!("listening on {}", addr);
     axum::Server::bind(&addr)
-        .serve(router.into_make_service())
+        .serve(router.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal())
+        .instrument(run_span)
         .await?;
 
-    // Ask views tracker to stop and wait for it to finish
+    // Ask views tracker and mirror syncer to stop and wait for them to finish
     vt.write().await.stop().await;
+    if let Some(syncer) = syncer.as_mut() {
+        syncer.stop().await;
+    }
 
     info!("apiserver stopped");
     Ok(())
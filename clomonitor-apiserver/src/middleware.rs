@@ -1,7 +1,31 @@
-use axum::{extract::MatchedPath, http::Request, middleware::Next, response::IntoResponse};
+use crate::{
+    auth::{Authenticator, Identity, Role},
+    ratelimit::{Client, RateLimitStatus, RateLimiter, API_KEY_HEADER},
+};
+use axum::{
+    body::{boxed, Empty, Full},
+    extract::{ConnectInfo, MatchedPath, Path, State},
+    http::{
+        header::{AUTHORIZATION, CACHE_CONTROL, CONTENT_LENGTH, ETAG, IF_NONE_MATCH, RETRY_AFTER},
+        HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Extension,
+};
 use lazy_static::lazy_static;
 use regex::RegexSet;
-use std::time::Instant;
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Instant};
+use tracing::{debug, error};
+
+/// Header used by proxies to provide the address of the client.
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Headers used to inform clients about their rate limit status.
+const X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+const X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const X_RATELIMIT_RESET: &str = "x-ratelimit-reset";
 
 /// Middleware that collects some metrics about requests processed.
 pub(crate) async fn metrics_collector<B>(req: Request<B>, next: Next<B>) -> impl IntoResponse {
@@ -45,3 +69,258 @@ pub(crate) async fn metrics_collector<B>(req: Request<B>, next: Next<B>) -> impl
 
     response
 }
+
+/// Middleware that authenticates requests using the bearer token included in
+/// the authorization header. The identity authenticated is added to the
+/// request's extensions, so that it can be used by the next handlers.
+pub(crate) async fn authenticate<B>(
+    State(auth): State<Arc<Authenticator>>,
+    mut req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let token = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(ToString::to_string);
+    let Some(token) = token else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    match auth.authenticate(&token).await {
+        Ok(Some(identity)) => {
+            req.extensions_mut().insert(identity);
+            next.run(req).await
+        }
+        Ok(None) => StatusCode::UNAUTHORIZED.into_response(),
+        Err(err) => {
+            error!("error authenticating request: {:#}", err);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Middleware that only lets through requests from identities that have been
+/// granted the platform admin role.
+pub(crate) async fn require_platform_admin<B>(
+    Extension(identity): Extension<Identity>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    authorize(&identity, Some(Role::PlatformAdmin), req, next).await
+}
+
+/// Middleware that only lets through requests from identities that have been
+/// granted the staff role on the foundation in the request's path.
+pub(crate) async fn require_foundation_staff<B>(
+    Extension(identity): Extension<Identity>,
+    Path(params): Path<HashMap<String, String>>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let role = params
+        .get("foundation")
+        .map(|foundation| Role::FoundationStaff {
+            foundation: foundation.clone(),
+        });
+    authorize(&identity, role, req, next).await
+}
+
+/// Middleware that only lets through requests from identities that have been
+/// granted the maintainer role on the project in the request's path.
+pub(crate) async fn require_project_maintainer<B>(
+    Extension(identity): Extension<Identity>,
+    Path(params): Path<HashMap<String, String>>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let role = match (params.get("foundation"), params.get("project")) {
+        (Some(foundation), Some(project)) => Some(Role::ProjectMaintainer {
+            foundation: foundation.clone(),
+            project: project.clone(),
+        }),
+        _ => None,
+    };
+    authorize(&identity, role, req, next).await
+}
+
+/// Run the next handler if the identity provided has been granted the role
+/// required (requests are forbidden when the role cannot be determined).
+async fn authorize<B>(
+    identity: &Identity,
+    required_role: Option<Role>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    match required_role {
+        Some(role) if identity.has_role(&role) => next.run(req).await,
+        _ => StatusCode::FORBIDDEN.into_response(),
+    }
+}
+
+/// Middleware that enforces the API rate limits. Requests that include an API
+/// key are limited based on the key, whereas anonymous ones are limited based
+/// on the client's address. Invalid or revoked keys are rejected, but they are
+/// charged to the client's address first so that they cannot be used to skip
+/// the anonymous rate limit. The API key is added to the request's extensions,
+/// so that it can be used by the next handlers.
+pub(crate) async fn rate_limit<B>(
+    State(rl): State<Arc<RateLimiter>>,
+    mut req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    // Identify the client the request was received from
+    let key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string);
+    let client = match key {
+        Some(key) => match rl.api_key(&key).await {
+            Ok(Some(api_key)) => {
+                req.extensions_mut().insert(api_key.clone());
+                Client::ApiKey(api_key)
+            }
+            Ok(None) => {
+                let client = Client::Anonymous(client_addr(&req, rl.trusted_proxies()));
+                return match rl.check(&client).await {
+                    Some(status) if status.exceeded => {
+                        let mut response = too_many_requests(&status);
+                        add_rate_limit_headers(response.headers_mut(), &status);
+                        response
+                    }
+                    _ => StatusCode::UNAUTHORIZED.into_response(),
+                };
+            }
+            Err(err) => {
+                error!("error getting api key: {:#}", err);
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        },
+        None => Client::Anonymous(client_addr(&req, rl.trusted_proxies())),
+    };
+
+    // Check the client's rate limit (if any)
+    let Some(status) = rl.check(&client).await else {
+        return next.run(req).await;
+    };
+    let mut response = if status.exceeded {
+        if let Client::ApiKey(api_key) = &client {
+            debug!(api_key = %api_key.name, "rate limit exceeded");
+        }
+        too_many_requests(&status)
+    } else {
+        next.run(req).await
+    };
+    add_rate_limit_headers(response.headers_mut(), &status);
+    response
+}
+
+/// Middleware that adds an ETag header to the successful responses of GET
+/// requests, so that clients can revalidate them. When the ETag provided by
+/// the client in the If-None-Match header matches, a 304 (Not Modified) is
+/// returned without the body. ETags are weak as responses may be compressed.
+pub(crate) async fn etag<B>(req: Request<B>, next: Next<B>) -> Response {
+    let if_none_match = req
+        .headers()
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string);
+    let is_get = req.method() == Method::GET;
+
+    // Execute next handler
+    let response = next.run(req).await;
+    if !is_get || response.status() != StatusCode::OK || response.headers().contains_key(ETAG) {
+        return response;
+    }
+
+    // Calculate the ETag from the response's body
+    let (mut parts, body) = response.into_parts();
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(err) => {
+            error!("error reading response body: {:#}", err);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let etag = format!(r#"W/"{}""#, hex::encode(&Sha256::digest(&body)[..16]));
+    let Ok(etag_value) = HeaderValue::try_from(&etag) else {
+        return Response::from_parts(parts, boxed(Full::from(body)));
+    };
+    parts.headers.insert(ETAG, etag_value);
+    if !parts.headers.contains_key(CACHE_CONTROL) {
+        parts
+            .headers
+            .insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    }
+
+    // Reply with a 304 if the client's copy is still valid
+    if if_none_match.map_or(false, |if_none_match| etag_matches(&if_none_match, &etag)) {
+        parts.status = StatusCode::NOT_MODIFIED;
+        parts.headers.remove(CONTENT_LENGTH);
+        return Response::from_parts(parts, boxed(Empty::new()));
+    }
+    Response::from_parts(parts, boxed(Full::from(body)))
+}
+
+/// Check if the If-None-Match header value provided matches the ETag given
+/// (weak comparison, as described in RFC 9110).
+fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let opaque_tag = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    if_none_match.trim() == "*"
+        || if_none_match
+            .split(',')
+            .any(|tag| opaque_tag(tag) == opaque_tag(etag))
+}
+
+/// Prepare the response returned when the rate limit has been exceeded.
+fn too_many_requests(status: &RateLimitStatus) -> Response {
+    let retry_after = status.reset.as_secs().max(1);
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(RETRY_AFTER, retry_after.to_string())],
+    )
+        .into_response()
+}
+
+/// Return the address of the client that sent the request provided. When
+/// the apiserver is running behind some trusted proxies, the address is
+/// obtained from the x-forwarded-for header, taking the entry added by the
+/// outermost of them (the ones on its left are set by the client, so they
+/// cannot be trusted). Otherwise the address of the peer is used.
+fn client_addr<B>(req: &Request<B>, trusted_proxies: usize) -> String {
+    req.headers()
+        .get(X_FORWARDED_FOR)
+        .filter(|_| trusted_proxies > 0)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            let addrs: Vec<&str> = value.split(',').map(str::trim).collect();
+            addrs
+                .len()
+                .checked_sub(trusted_proxies)
+                .and_then(|i| addrs.get(i))
+                .map(|addr| addr.to_string())
+        })
+        .or_else(|| {
+            req.extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string())
+        })
+        .unwrap_or_default()
+}
+
+/// Add the headers that describe the rate limit status provided.
+fn add_rate_limit_headers(headers: &mut HeaderMap, status: &RateLimitStatus) {
+    headers.insert(
+        HeaderName::from_static(X_RATELIMIT_LIMIT),
+        HeaderValue::from(status.limit),
+    );
+    headers.insert(
+        HeaderName::from_static(X_RATELIMIT_REMAINING),
+        HeaderValue::from(status.remaining),
+    );
+    headers.insert(
+        HeaderName::from_static(X_RATELIMIT_RESET),
+        HeaderValue::from(status.reset.as_secs()),
+    );
+}
@@ -0,0 +1,132 @@
+use crate::db::DynDB;
+use anyhow::{format_err, Result};
+use clomonitor_core::net;
+use reqwest::StatusCode;
+use std::time::Duration;
+use tokio::{sync::broadcast, task::JoinHandle};
+use tracing::{debug, error, instrument};
+
+/// Default frequency of the syncs with the primary instance (in seconds).
+pub(crate) const DEFAULT_SYNC_INTERVAL: u64 = 300;
+
+/// Number of seconds the changes requested to the primary instance overlap
+/// with the ones already synced. This way changes made by transactions that
+/// were still in progress during the previous sync are not missed (applying
+/// the same changes twice is harmless).
+const SYNC_OVERLAP: i64 = 300;
+
+/// Syncer that keeps a mirror's database up to date with the primary instance
+/// by applying periodically the changes obtained from its delta sync API.
+pub(crate) struct Syncer {
+    stop_tx: Option<broadcast::Sender<()>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Syncer {
+    /// Create a new Syncer instance, launching the worker that will sync the
+    /// mirror's database every interval provided.
+    pub(crate) fn new(db: DynDB, primary_url: String, interval: Duration) -> Result<Self> {
+        let (stop_tx, stop_rx) = broadcast::channel(1);
+        let http_client = net::client_builder().build()?;
+        let worker = tokio::spawn(worker(db, http_client, primary_url, interval, stop_rx));
+
+        Ok(Self {
+            stop_tx: Some(stop_tx),
+            worker: Some(worker),
+        })
+    }
+
+    /// Ask the worker to stop and wait for it to finish.
+    pub(crate) async fn stop(&mut self) {
+        self.stop_tx = None;
+        if let Some(worker) = self.worker.take() {
+            _ = worker.await;
+        }
+    }
+}
+
+/// Worker that syncs the mirror's database every interval provided until it
+/// is asked to stop.
+async fn worker(
+    db: DynDB,
+    http_client: reqwest::Client,
+    primary_url: String,
+    interval: Duration,
+    mut stop_rx: broadcast::Receiver<()>,
+) {
+    let mut sync_interval = tokio::time::interval(interval);
+    loop {
+        tokio::select! {
+            biased;
+
+            // Exit if the worker has been asked to stop
+            _ = stop_rx.recv() => break,
+
+            // Sync mirror's database every interval
+            _ = sync_interval.tick() => {
+                if let Err(err) = sync(db.clone(), &http_client, &primary_url).await {
+                    error!(?err, "error syncing mirror");
+                }
+            }
+        }
+    }
+}
+
+/// Sync the mirror's database applying the changes made in the primary
+/// instance since the last sync.
+#[instrument(skip_all, err)]
+async fn sync(db: DynDB, http_client: &reqwest::Client, primary_url: &str) -> Result<()> {
+    let since = sync_since(db.mirror_synced_until().await?);
+    let resp = http_client
+        .get(delta_url(primary_url, since))
+        .send()
+        .await?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code getting delta from primary: {}",
+            resp.status()
+        ));
+    }
+    let delta = resp.text().await?;
+    db.apply_sync_delta(&delta).await?;
+    debug!(since, "mirror synced");
+    Ok(())
+}
+
+/// Return the unix timestamp the changes should be requested from, based on
+/// the point in time the mirror has been synced until (if any).
+fn sync_since(synced_until: Option<i64>) -> i64 {
+    synced_until.map_or(0, |ts| (ts - SYNC_OVERLAP).max(0))
+}
+
+/// Return the url of the primary's delta sync API endpoint.
+fn delta_url(primary_url: &str, since: i64) -> String {
+    format!(
+        "{}/api/sync/delta?since={since}",
+        primary_url.trim_end_matches('/')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_since_never_synced() {
+        assert_eq!(sync_since(None), 0);
+    }
+
+    #[test]
+    fn sync_since_includes_overlap() {
+        assert_eq!(sync_since(Some(1_000)), 700);
+        assert_eq!(sync_since(Some(100)), 0);
+    }
+
+    #[test]
+    fn delta_url_trims_trailing_slash() {
+        assert_eq!(
+            delta_url("https://clomonitor.io/", 700),
+            "https://clomonitor.io/api/sync/delta?since=700"
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    auth::{Role, RoleAssignment},
+    handlers::{
+        self, Dataset, DatasetFiles, DatasetsManifest, FoundationDataRefInput, FoundationInput,
+        FoundationWeights, NewFoundationInput, ProjectWebhookInput, ScoreSimulationInput,
+        TrackProjectsInput, TrackProjectsOutput, WebhookEvent,
+    },
+    ratelimit::ApiKeyInput,
+};
+use clomonitor_core::{
+    linter::{CheckStatus, ReportSection, Severity},
+    score::{
+        AggregationTrace, CheckTrace, CheckWeight, RepositoryContribution, RepositoryScoreTrace,
+        Score, ScoreSimulation, ScoreTrace, SectionTrace, Weights,
+    },
+};
+use lazy_static::lazy_static;
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+/// Name of the security scheme used by the admin API endpoints.
+pub(crate) const ADMIN_SECURITY_SCHEME: &str = "bearer_token";
+
+lazy_static! {
+    /// OpenAPI specification of the API in JSON format.
+    pub(crate) static ref OPENAPI_JSON: String = ApiDoc::openapi()
+        .to_json()
+        .expect("openapi specification to be serializable");
+}
+
+/// OpenAPI specification of the CLOMonitor HTTP API. It is derived from the
+/// handlers and the types they use, so the paths listed here must be kept in
+/// sync with the routes registered in the router.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "CLOMonitor API",
+        description = "CLOMonitor HTTP API",
+        license(name = "Apache-2.0")
+    ),
+    paths(
+        handlers::datasets,
+        handlers::foundation_maturity_gates,
+        handlers::foundation_report,
+        handlers::foundation_weights,
+        handlers::search_projects,
+        handlers::suggest_projects,
+        handlers::track_view,
+        handlers::foundation_feed,
+        handlers::project,
+        handlers::project_check_history,
+        handlers::badge,
+        handlers::section_badge,
+        handlers::shields,
+        handlers::rating_change_png,
+        handlers::report_pdf,
+        handlers::report_summary_svg,
+        handlers::repository_report_md,
+        handlers::repository,
+        handlers::search_repositories,
+        handlers::score_trace,
+        handlers::simulate_score,
+        handlers::project_snapshot,
+        handlers::project_tracking_errors,
+        handlers::stats,
+        handlers::stats_checks,
+        handlers::stats_leaderboard,
+        handlers::stats_most_improved,
+        handlers::stats_snapshot,
+        handlers::sync_delta,
+        handlers::tracker_run_progress,
+        handlers::consistency_issues,
+        handlers::register_foundation,
+        handlers::update_foundation,
+        handlers::delete_foundation,
+        handlers::update_foundation_data_ref,
+        handlers::request_foundation_registration,
+        handlers::track_projects,
+        handlers::claim_project,
+        handlers::track_project,
+        handlers::project_webhooks,
+        handlers::add_project_webhook,
+        handlers::delete_project_webhook,
+        handlers::exemption_acknowledgements,
+        handlers::acknowledge_exemption,
+        handlers::delete_exemption_acknowledgement,
+        handlers::repository_bundle,
+        handlers::api_keys,
+        handlers::add_api_key,
+        handlers::revoke_api_key,
+        handlers::role_assignments,
+        handlers::add_role_assignment,
+        handlers::delete_role_assignment,
+    ),
+    components(schemas(
+        AggregationTrace,
+        ApiKeyInput,
+        CheckStatus,
+        CheckTrace,
+        CheckWeight,
+        Dataset,
+        DatasetFiles,
+        DatasetsManifest,
+        FoundationDataRefInput,
+        FoundationInput,
+        FoundationWeights,
+        NewFoundationInput,
+        ProjectWebhookInput,
+        RepositoryContribution,
+        RepositoryScoreTrace,
+        ReportSection,
+        Role,
+        RoleAssignment,
+        Score,
+        ScoreSimulation,
+        ScoreSimulationInput,
+        ScoreTrace,
+        SectionTrace,
+        Severity,
+        TrackProjectsInput,
+        TrackProjectsOutput,
+        WebhookEvent,
+        Weights,
+    )),
+    modifiers(&AdminSecurity),
+    tags(
+        (name = "foundations", description = "Foundations information"),
+        (name = "projects", description = "Projects information, reports and badges"),
+        (name = "repositories", description = "Repositories information and reports"),
+        (name = "stats", description = "Stats and tracker progress"),
+        (name = "datasets", description = "Public datasets"),
+        (name = "admin", description = "Administration operations (authentication required)"),
+    )
+)]
+pub(crate) struct ApiDoc;
+
+/// Modifier that registers the security scheme used by the admin endpoints.
+struct AdminSecurity;
+
+impl Modify for AdminSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi
+            .components
+            .as_mut()
+            .expect("some components to be registered");
+        components.add_security_scheme(
+            ADMIN_SECURITY_SCHEME,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .description(Some(
+                        "Admin token or ID token issued by the OIDC provider configured",
+                    ))
+                    .build(),
+            ),
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::Result;
+use flate2::{write::ZlibEncoder, Compression};
+use std::io::Write;
+
+/// PDF page dimensions (A4 landscape, in points).
+const PAGE_WIDTH: f32 = 842.0;
+const PAGE_HEIGHT: f32 = 595.0;
+
+/// Margin around the image in the PDF page (in points).
+const PAGE_MARGIN: f32 = 36.0;
+
+/// Build a single page PDF document embedding the pixmap provided, scaled to
+/// fit the page. Transparent pixels are composited over a white background.
+pub(crate) fn from_pixmap(pixmap: &tiny_skia::Pixmap, title: &str) -> Result<Vec<u8>> {
+    // Prepare image data (RGB, compressed)
+    let mut rgb = Vec::with_capacity(pixmap.width() as usize * pixmap.height() as usize * 3);
+    for pixel in pixmap.pixels() {
+        let white = 255 - pixel.alpha();
+        rgb.extend_from_slice(&[
+            pixel.red() + white,
+            pixel.green() + white,
+            pixel.blue() + white,
+        ]);
+    }
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&rgb)?;
+    let image = encoder.finish()?;
+
+    // Calculate image position and size in the page
+    let (max_width, max_height) = (
+        PAGE_WIDTH - 2.0 * PAGE_MARGIN,
+        PAGE_HEIGHT - 2.0 * PAGE_MARGIN,
+    );
+    let k = f32::min(
+        max_width / pixmap.width() as f32,
+        max_height / pixmap.height() as f32,
+    );
+    let (width, height) = (pixmap.width() as f32 * k, pixmap.height() as f32 * k);
+    let (x, y) = (
+        (PAGE_WIDTH - width) / 2.0,
+        PAGE_HEIGHT - PAGE_MARGIN - height,
+    );
+    let content = format!("q {width:.2} 0 0 {height:.2} {x:.2} {y:.2} cm /Im0 Do Q");
+
+    // Write document objects, keeping track of their offsets
+    let mut pdf: Vec<u8> = Vec::new();
+    let mut offsets = Vec::new();
+    pdf.extend_from_slice(b"%PDF-1.4\n");
+    let mut add_object = |pdf: &mut Vec<u8>, dict: String, stream: Option<&[u8]>| {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{dict}\n", offsets.len()).as_bytes());
+        if let Some(stream) = stream {
+            pdf.extend_from_slice(b"stream\n");
+            pdf.extend_from_slice(stream);
+            pdf.extend_from_slice(b"\nendstream\n");
+        }
+        pdf.extend_from_slice(b"endobj\n");
+    };
+    add_object(
+        &mut pdf,
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        None,
+    );
+    add_object(
+        &mut pdf,
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        None,
+    );
+    add_object(
+        &mut pdf,
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+            /Resources << /XObject << /Im0 4 0 R >> >> /Contents 5 0 R >>"
+        ),
+        None,
+    );
+    add_object(
+        &mut pdf,
+        format!(
+            "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB \
+            /BitsPerComponent 8 /Filter /FlateDecode /Length {} >>",
+            pixmap.width(),
+            pixmap.height(),
+            image.len()
+        ),
+        Some(&image),
+    );
+    add_object(
+        &mut pdf,
+        format!("<< /Length {} >>", content.len()),
+        Some(content.as_bytes()),
+    );
+    add_object(
+        &mut pdf,
+        format!(
+            "<< /Title ({}) /Producer (CLOMonitor) >>",
+            escape_string(title)
+        ),
+        None,
+    );
+
+    // Write cross-reference table and trailer
+    let xref_offset = pdf.len();
+    pdf.extend_from_slice(format!("xref\n0 {}\n", offsets.len() + 1).as_bytes());
+    pdf.extend_from_slice(b"0000000000 65535 f \n");
+    for offset in &offsets {
+        pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info {} 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            offsets.len() + 1,
+            offsets.len()
+        )
+        .as_bytes(),
+    );
+
+    Ok(pdf)
+}
+
+/// Escape the text provided so that it can be used in a PDF literal string.
+fn escape_string(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii() && !c.is_ascii_control())
+        .fold(String::with_capacity(text.len()), |mut s, c| {
+            if matches!(c, '(' | ')' | '\\') {
+                s.push('\\');
+            }
+            s.push(c);
+            s
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_pixmap_builds_valid_document() {
+        let pixmap = tiny_skia::Pixmap::new(10, 5).unwrap();
+        let pdf = from_pixmap(&pixmap, "project").unwrap();
+        let pdf_str = String::from_utf8_lossy(&pdf);
+        let trailer = String::from_utf8_lossy(&pdf[pdf.len() - 64..]);
+
+        assert!(pdf_str.starts_with("%PDF-1.4\n"));
+        assert!(pdf_str.ends_with("%%EOF\n"));
+        assert!(pdf_str.contains("/Width 10 /Height 5"));
+        assert!(pdf_str.contains("/Title (project)"));
+
+        // Offset in startxref must point to the cross-reference table
+        let startxref: usize = trailer
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|s| s.lines().next())
+            .and_then(|s| s.parse().ok())
+            .unwrap();
+        assert!(pdf[startxref..].starts_with(b"xref\n0 7\n"));
+    }
+
+    #[test]
+    fn escape_string_works() {
+        assert_eq!(escape_string("project"), "project");
+        assert_eq!(escape_string(r"a(b)c\d"), r"a\(b\)c\\d");
+        assert_eq!(escape_string("tab\there"), "tabhere");
+    }
+}
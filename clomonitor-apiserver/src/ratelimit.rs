@@ -0,0 +1,309 @@
+use crate::db::DynDB;
+use anyhow::Result;
+use config::Config;
+use openssl::rand::rand_bytes;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Header used to provide the API key.
+pub(crate) const API_KEY_HEADER: &str = "x-api-key";
+
+/// Duration of the rate limit windows.
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Default number of requests per window allowed to API keys that do not
+/// define their own rate limit.
+const DEFAULT_API_KEY_RATE_LIMIT: u32 = 600;
+
+/// How long the API keys looked up in the database are cached. API keys
+/// revoked may still be accepted during this period.
+const API_KEYS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Number of API keys cached above which the expired ones are removed.
+const MAX_API_KEYS: usize = 1_000;
+
+/// Number of windows tracked above which the expired ones are removed.
+const MAX_WINDOWS: usize = 10_000;
+
+/// Prefix of the API keys issued.
+const API_KEY_PREFIX: &str = "clomonitor_";
+
+/// API key used to identify a programmatic consumer of the API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ApiKey {
+    pub api_key_id: Uuid,
+    pub name: String,
+    pub rate_limit: Option<u32>,
+}
+
+/// Input used to issue a new API key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub(crate) struct ApiKeyInput {
+    pub name: String,
+    pub rate_limit: Option<u32>,
+}
+
+/// Client a request has been received from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Client {
+    /// Client identified by its API key.
+    ApiKey(ApiKey),
+    /// Anonymous client, identified by its address.
+    Anonymous(String),
+}
+
+/// Result of checking a request against its client's rate limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RateLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset: Duration,
+    pub exceeded: bool,
+}
+
+/// Requests counter of a client's current window.
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    started_at: Instant,
+    requests: u32,
+}
+
+/// Rate limiter that keeps track of the number of requests received from
+/// each client in fixed windows. API keys are allowed the number of requests
+/// per window they define (or the default one), whereas anonymous clients
+/// share the anonymous rate limit per address. Counters are kept in memory,
+/// so limits are enforced per apiserver instance.
+pub(crate) struct RateLimiter {
+    db: DynDB,
+    anonymous_rate_limit: Option<u32>,
+    api_key_rate_limit: u32,
+    trusted_proxies: usize,
+    windows: Mutex<HashMap<String, Window>>,
+    api_keys: Mutex<HashMap<String, (ApiKey, Instant)>>,
+}
+
+impl RateLimiter {
+    /// Create a new RateLimiter instance.
+    pub(crate) fn new(
+        db: DynDB,
+        anonymous_rate_limit: Option<u32>,
+        api_key_rate_limit: u32,
+    ) -> Self {
+        Self {
+            db,
+            anonymous_rate_limit,
+            api_key_rate_limit,
+            trusted_proxies: 0,
+            windows: Mutex::new(HashMap::new()),
+            api_keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a new RateLimiter instance from the configuration provided.
+    /// Anonymous clients are not rate limited when no anonymous rate limit
+    /// has been set. The x-forwarded-for header is ignored unless the number
+    /// of trusted proxies in front of the apiserver has been set.
+    pub(crate) fn from_config(cfg: &Config, db: DynDB) -> Self {
+        let anonymous_rate_limit = cfg
+            .get::<u32>("apiserver.rateLimit.anonymous")
+            .ok()
+            .filter(|limit| *limit > 0);
+        let api_key_rate_limit = cfg
+            .get::<u32>("apiserver.rateLimit.apiKey")
+            .unwrap_or(DEFAULT_API_KEY_RATE_LIMIT);
+        let trusted_proxies = cfg
+            .get::<usize>("apiserver.rateLimit.trustedProxies")
+            .unwrap_or_default();
+        Self {
+            trusted_proxies,
+            ..Self::new(db, anonymous_rate_limit, api_key_rate_limit)
+        }
+    }
+
+    /// Return the number of proxies in front of the apiserver whose
+    /// x-forwarded-for entries can be trusted.
+    pub(crate) fn trusted_proxies(&self) -> usize {
+        self.trusted_proxies
+    }
+
+    /// Get the API key provided, if it is valid. Valid API keys are cached
+    /// for a while to avoid hitting the database on each request (invalid
+    /// ones are not, so that they cannot be used to fill the cache).
+    pub(crate) async fn api_key(&self, key: &str) -> Result<Option<ApiKey>> {
+        let key_hash = hash_api_key(key);
+        if let Some((api_key, cached_at)) = self.api_keys.lock().await.get(&key_hash) {
+            if cached_at.elapsed() < API_KEYS_CACHE_TTL {
+                return Ok(Some(api_key.clone()));
+            }
+        }
+        let api_key = self.db.api_key(&key_hash).await?;
+        let mut api_keys = self.api_keys.lock().await;
+        match &api_key {
+            Some(api_key) => {
+                if api_keys.len() >= MAX_API_KEYS {
+                    api_keys.retain(|_, (_, cached_at)| cached_at.elapsed() < API_KEYS_CACHE_TTL);
+                }
+                api_keys.insert(key_hash, (api_key.clone(), Instant::now()));
+            }
+            None => {
+                api_keys.remove(&key_hash);
+            }
+        }
+        Ok(api_key)
+    }
+
+    /// Register a request from the client provided, returning its rate limit
+    /// status. None is returned when the client is not rate limited.
+    pub(crate) async fn check(&self, client: &Client) -> Option<RateLimitStatus> {
+        let (key, limit) = match client {
+            Client::ApiKey(api_key) => (
+                format!("key:{}", api_key.api_key_id),
+                api_key.rate_limit.unwrap_or(self.api_key_rate_limit),
+            ),
+            Client::Anonymous(addr) => (format!("anonymous:{addr}"), self.anonymous_rate_limit?),
+        };
+
+        let now = Instant::now();
+        let mut windows = self.windows.lock().await;
+        if windows.len() >= MAX_WINDOWS {
+            windows.retain(|_, w| now.duration_since(w.started_at) < WINDOW);
+        }
+        let window = windows.entry(key).or_insert(Window {
+            started_at: now,
+            requests: 0,
+        });
+        if now.duration_since(window.started_at) >= WINDOW {
+            *window = Window {
+                started_at: now,
+                requests: 0,
+            };
+        }
+        let exceeded = window.requests >= limit;
+        if !exceeded {
+            window.requests += 1;
+        }
+
+        Some(RateLimitStatus {
+            limit,
+            remaining: limit - window.requests,
+            reset: WINDOW.saturating_sub(now.duration_since(window.started_at)),
+            exceeded,
+        })
+    }
+}
+
+/// Generate a new random API key.
+pub(crate) fn generate_api_key() -> Result<String> {
+    let mut buf = [0; 32];
+    rand_bytes(&mut buf)?;
+    Ok(format!("{API_KEY_PREFIX}{}", hex::encode(buf)))
+}
+
+/// Return the hash of the API key provided (only the hashes of the keys are
+/// stored in the database).
+pub(crate) fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::MockDB;
+    use mockall::predicate::eq;
+    use std::{future, sync::Arc};
+
+    const API_KEY: &str = "clomonitor_key";
+
+    fn api_key(rate_limit: Option<u32>) -> ApiKey {
+        ApiKey {
+            api_key_id: Uuid::parse_str("00000000-0000-0000-0001-000000000000").unwrap(),
+            name: "consumer".to_string(),
+            rate_limit,
+        }
+    }
+
+    #[test]
+    fn generate_api_key_is_random() {
+        let key1 = generate_api_key().unwrap();
+        let key2 = generate_api_key().unwrap();
+        assert!(key1.starts_with(API_KEY_PREFIX));
+        assert_eq!(key1.len(), API_KEY_PREFIX.len() + 64);
+        assert_ne!(key1, key2);
+    }
+
+    #[tokio::test]
+    async fn anonymous_clients_not_limited_by_default() {
+        let rl = RateLimiter::new(Arc::new(MockDB::new()), None, 10);
+        let client = Client::Anonymous("127.0.0.1".to_string());
+        assert_eq!(rl.check(&client).await, None);
+    }
+
+    #[tokio::test]
+    async fn anonymous_rate_limit_exceeded() {
+        let rl = RateLimiter::new(Arc::new(MockDB::new()), Some(2), 10);
+        let client = Client::Anonymous("127.0.0.1".to_string());
+        assert_eq!(rl.check(&client).await.unwrap().remaining, 1);
+        assert_eq!(rl.check(&client).await.unwrap().remaining, 0);
+        let status = rl.check(&client).await.unwrap();
+        assert!(status.exceeded);
+        assert_eq!(status.limit, 2);
+
+        // Other clients have their own window
+        let other_client = Client::Anonymous("127.0.0.2".to_string());
+        assert!(!rl.check(&other_client).await.unwrap().exceeded);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_window_resets() {
+        let rl = RateLimiter::new(Arc::new(MockDB::new()), Some(1), 10);
+        let client = Client::Anonymous("127.0.0.1".to_string());
+        assert!(!rl.check(&client).await.unwrap().exceeded);
+        assert!(rl.check(&client).await.unwrap().exceeded);
+
+        // Move the window start back in time to simulate it has expired
+        for window in rl.windows.lock().await.values_mut() {
+            window.started_at -= WINDOW;
+        }
+        assert!(!rl.check(&client).await.unwrap().exceeded);
+    }
+
+    #[tokio::test]
+    async fn api_key_rate_limit() {
+        let rl = RateLimiter::new(Arc::new(MockDB::new()), Some(1), 10);
+        let status = rl.check(&Client::ApiKey(api_key(None))).await.unwrap();
+        assert_eq!(status.limit, 10);
+        let status = rl.check(&Client::ApiKey(api_key(Some(100)))).await.unwrap();
+        assert_eq!(status.limit, 100);
+    }
+
+    #[tokio::test]
+    async fn api_key_lookups_are_cached() {
+        let mut db = MockDB::new();
+        db.expect_api_key()
+            .with(eq(hash_api_key(API_KEY)))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Some(api_key(None))))));
+        let rl = RateLimiter::new(Arc::new(db), None, 10);
+
+        assert_eq!(rl.api_key(API_KEY).await.unwrap(), Some(api_key(None)));
+        assert_eq!(rl.api_key(API_KEY).await.unwrap(), Some(api_key(None)));
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key_lookups_are_not_cached() {
+        let mut db = MockDB::new();
+        db.expect_api_key()
+            .with(eq(hash_api_key(API_KEY)))
+            .times(2)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+        let rl = RateLimiter::new(Arc::new(db), None, 10);
+
+        assert_eq!(rl.api_key(API_KEY).await.unwrap(), None);
+        assert_eq!(rl.api_key(API_KEY).await.unwrap(), None);
+        assert!(rl.api_keys.lock().await.is_empty());
+    }
+}
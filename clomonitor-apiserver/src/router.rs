@@ -1,20 +1,38 @@
-use crate::{db::DynDB, handlers::*, middleware::metrics_collector, views::DynVT};
-use anyhow::Result;
+use crate::{
+    auth::Authenticator,
+    db::DynDB,
+    handlers::*,
+    middleware::{
+        authenticate, etag, metrics_collector, rate_limit, require_foundation_staff,
+        require_platform_admin, require_project_maintainer,
+    },
+    ratelimit::RateLimiter,
+    templates::Overrides,
+    views::DynVT,
+};
+use anyhow::{Context, Result};
 use axum::{
     extract::FromRef,
-    http::{header::CACHE_CONTROL, HeaderValue, StatusCode},
+    http::{header::CACHE_CONTROL, HeaderValue, Request, StatusCode},
     middleware,
-    routing::{get, get_service, post},
+    routing::{delete, get, get_service, post, put},
     Router,
 };
-use config::Config;
+use clomonitor_core::{
+    cache::DynCache,
+    net::{HostPolicy, RestrictedClient},
+};
+use config::{Config, ConfigError};
+use lazy_static::lazy_static;
 use std::{path::Path, sync::Arc};
 use tera::Tera;
 use tower::ServiceBuilder;
 use tower_http::{
-    auth::RequireAuthorizationLayer, services::ServeDir, set_header::SetResponseHeader,
-    trace::TraceLayer,
+    auth::RequireAuthorizationLayer, compression::CompressionLayer, services::ServeDir,
+    set_header::SetResponseHeader, trace::TraceLayer,
 };
+use tracing::{info_span, Span};
+use uuid::Uuid;
 
 /// Static files cache duration.
 pub const STATIC_CACHE_MAX_AGE: usize = 365 * 24 * 60 * 60;
@@ -28,11 +46,20 @@ struct RouterState {
     cfg: Arc<Config>,
     db: DynDB,
     vt: DynVT,
+    cache: DynCache,
     tmpl: Arc<Tera>,
+    overrides: Arc<Overrides>,
+    restricted_client: RestrictedClient,
+}
+
+lazy_static! {
+    /// Identifier of this apiserver run. It is included in the span of all
+    /// the requests served, so that their events can be correlated.
+    pub(crate) static ref RUN_ID: Uuid = Uuid::new_v4();
 }
 
 /// Setup API server router.
-pub(crate) fn setup(cfg: Arc<Config>, db: DynDB, vt: DynVT) -> Result<Router> {
+pub(crate) fn setup(cfg: Arc<Config>, db: DynDB, vt: DynVT, cache: DynCache) -> Result<Router> {
     // Setup error handler
     let error_handler = |err: std::io::Error| async move {
         (
@@ -52,28 +79,130 @@ pub(crate) fn setup(cfg: Arc<Config>, db: DynDB, vt: DynVT) -> Result<Router> {
     tmpl.add_template_file(index_path, Some("index.html"))?;
     let tmpl = Arc::new(tmpl);
 
+    // Setup templates overrides (if any)
+    let overrides = match cfg.get_string("apiserver.templatesOverridesPath") {
+        Ok(path) if !path.is_empty() => {
+            Overrides::load(Path::new(&path)).context("error loading templates overrides")?
+        }
+        _ => Overrides::default(),
+    };
+    let overrides = Arc::new(overrides);
+
+    // Setup the client used to fetch urls provided by users (i.e. data files
+    // or logos), restricted to the hosts allowed by the policy configured
+    let host_policy = match cfg.get::<HostPolicy>("http.hostPolicy") {
+        Ok(host_policy) => host_policy,
+        Err(ConfigError::NotFound(_)) => HostPolicy::default(),
+        Err(err) => return Err(err.into()),
+    };
+    let restricted_client = RestrictedClient::new(host_policy)?;
+
+    // Mirror instances are read-only, as their data is synced from the
+    // primary instance
+    let read_only = cfg.get_bool("apiserver.mirror.enabled").unwrap_or(false);
+
     // Setup API routes
-    let api_routes = Router::new()
-        .route("/projects/search", get(search_projects))
+    let mut api_routes = Router::new()
+        .route("/datasets", get(datasets))
+        .route(
+            "/foundations/:foundation/maturity-gates",
+            get(foundation_maturity_gates),
+        )
+        .route(
+            "/foundations/:foundation/report",
+            get(foundation_report).route_layer(middleware::from_fn(etag)),
+        )
+        .route("/foundations/:foundation/weights", get(foundation_weights))
+        .route("/openapi.json", get(openapi_json))
+        .route(
+            "/projects/search",
+            get(search_projects).route_layer(middleware::from_fn(etag)),
+        )
+        .route(
+            "/projects/suggest",
+            get(suggest_projects).route_layer(middleware::from_fn(etag)),
+        )
         .route("/projects/views/:project_id", post(track_view))
-        .route("/projects/:foundation/:project", get(project))
+        .route("/projects/:foundation/feed.xml", get(foundation_feed))
+        .route(
+            "/projects/:foundation/:project",
+            get(project).route_layer(middleware::from_fn(etag)),
+        )
         .route("/projects/:foundation/:project/badge", get(badge))
+        .route(
+            "/projects/:foundation/:project/badge/:section",
+            get(section_badge),
+        )
+        .route(
+            "/projects/:foundation/:project/checks/:check_id/history",
+            get(project_check_history),
+        )
+        .route(
+            "/projects/:foundation/:project/rating-change.png",
+            get(rating_change_png),
+        )
+        .route("/projects/:foundation/:project/report.pdf", get(report_pdf))
         .route(
             "/projects/:foundation/:project/report-summary",
             get(report_summary_svg),
         )
         .route(
             "/projects/:foundation/:project/:repository/report.md",
-            get(repository_report_md),
+            get(repository_report_md).route_layer(middleware::from_fn(etag)),
+        )
+        .route(
+            "/projects/:foundation/:project/score-trace",
+            get(score_trace),
+        )
+        .route(
+            "/projects/:foundation/:project/score/simulate",
+            post(simulate_score),
         )
+        .route("/projects/:foundation/:project/shields", get(shields))
         .route(
             "/projects/:foundation/:project/snapshots/:date",
-            get(project_snapshot),
+            get(project_snapshot).route_layer(middleware::from_fn(etag)),
+        )
+        .route(
+            "/projects/:foundation/:project/tracking-errors",
+            get(project_tracking_errors),
+        )
+        .route(
+            "/repositories/search",
+            get(search_repositories).route_layer(middleware::from_fn(etag)),
+        )
+        .route(
+            "/repositories/:repository_id/report",
+            get(repository).route_layer(middleware::from_fn(etag)),
+        )
+        .route("/stats", get(stats).route_layer(middleware::from_fn(etag)))
+        .route("/stats/checks", get(stats_checks))
+        .route("/stats/leaderboard", get(stats_leaderboard))
+        .route("/stats/most-improved", get(stats_most_improved))
+        .route(
+            "/stats/snapshots/:date",
+            get(stats_snapshot).route_layer(middleware::from_fn(etag)),
         )
-        .route("/stats", get(stats))
-        .route("/stats/snapshots/:date", get(stats_snapshot));
+        .route("/sync/delta", get(sync_delta))
+        .route("/tracker/progress", get(tracker_run_progress));
+
+    // Setup API rate limits (requests including an API key are limited based
+    // on the key, and anonymous ones based on the client's address)
+    if cfg.get_bool("apiserver.rateLimit.enabled").unwrap_or(false) {
+        let rl = Arc::new(RateLimiter::from_config(&cfg, db.clone()));
+        api_routes = api_routes.route_layer(middleware::from_fn_with_state(rl, rate_limit));
+    }
 
     // Setup router
+    let state = RouterState {
+        cfg: cfg.clone(),
+        db,
+        vt,
+        cache,
+        tmpl,
+        overrides,
+        restricted_client,
+    };
     let mut router = Router::new()
         .route("/", get(index))
         .route("/projects/:foundation/:project", get(index_project))
@@ -81,7 +210,10 @@ pub(crate) fn setup(cfg: Arc<Config>, db: DynDB, vt: DynVT) -> Result<Router> {
             "/projects/:foundation/:project/report-summary.png",
             get(report_summary_png),
         )
-        .route("/data/repositories.csv", get(repositories_checks))
+        .route(
+            "/data/repositories.csv",
+            get(repositories_checks).route_layer(middleware::from_fn(etag)),
+        )
         .nest("/api", api_routes)
         .nest_service(
             "/docs",
@@ -104,15 +236,11 @@ pub(crate) fn setup(cfg: Arc<Config>, db: DynDB, vt: DynVT) -> Result<Router> {
         .fallback(index)
         .layer(
             ServiceBuilder::new()
-                .layer(TraceLayer::new_for_http())
-                .layer(middleware::from_fn(metrics_collector)),
+                .layer(TraceLayer::new_for_http().make_span_with(request_span))
+                .layer(middleware::from_fn(metrics_collector))
+                .layer(CompressionLayer::new()),
         )
-        .with_state(RouterState {
-            cfg: cfg.clone(),
-            db,
-            vt,
-            tmpl,
-        });
+        .with_state(state.clone());
 
     // Setup basic auth
     if cfg.get_bool("apiserver.basicAuth.enabled").unwrap_or(false) {
@@ -121,40 +249,177 @@ pub(crate) fn setup(cfg: Arc<Config>, db: DynDB, vt: DynVT) -> Result<Router> {
         router = router.layer(RequireAuthorizationLayer::basic(&username, &password));
     }
 
+    // Setup admin API routes (only available when an admin token, an OIDC
+    // provider or the GitHub authentication is set). They are merged after
+    // setting up basic auth, as they use their own bearer token authorization.
+    // Each route requires a role, which may be scoped to the foundation or
+    // project in its path, except the one used to claim projects. They are
+    // not available on read-only mirror instances.
+    let auth = Arc::new(
+        Authenticator::from_config(&cfg, state.db.clone())
+            .context("error setting up authenticator")?,
+    );
+    if auth.is_enabled() && !read_only {
+        let admin_routes = Router::new()
+            .route(
+                "/api/admin/api-keys",
+                get(api_keys)
+                    .post(add_api_key)
+                    .route_layer(middleware::from_fn(require_platform_admin)),
+            )
+            .route(
+                "/api/admin/api-keys/:api_key_id",
+                delete(revoke_api_key).route_layer(middleware::from_fn(require_platform_admin)),
+            )
+            .route(
+                "/api/admin/consistency-issues",
+                get(consistency_issues).route_layer(middleware::from_fn(require_platform_admin)),
+            )
+            .route(
+                "/api/admin/foundations",
+                post(register_foundation).route_layer(middleware::from_fn(require_platform_admin)),
+            )
+            .route(
+                "/api/admin/foundations/:foundation",
+                put(update_foundation)
+                    .delete(delete_foundation)
+                    .route_layer(middleware::from_fn(require_platform_admin)),
+            )
+            .route(
+                "/api/admin/foundations/:foundation/data-ref",
+                put(update_foundation_data_ref)
+                    .route_layer(middleware::from_fn(require_foundation_staff)),
+            )
+            .route(
+                "/api/admin/foundations/:foundation/register",
+                post(request_foundation_registration)
+                    .route_layer(middleware::from_fn(require_foundation_staff)),
+            )
+            .route(
+                "/api/admin/foundations/:foundation/track",
+                post(track_projects).route_layer(middleware::from_fn(require_foundation_staff)),
+            )
+            .route(
+                "/api/admin/projects/:foundation/:project/claim",
+                post(claim_project),
+            )
+            .route(
+                "/api/admin/projects/:foundation/:project/exemptions",
+                get(exemption_acknowledgements)
+                    .route_layer(middleware::from_fn(require_project_maintainer)),
+            )
+            .route(
+                "/api/admin/projects/:foundation/:project/exemptions/:check_id",
+                put(acknowledge_exemption)
+                    .delete(delete_exemption_acknowledgement)
+                    .route_layer(middleware::from_fn(require_project_maintainer)),
+            )
+            .route(
+                "/api/admin/projects/:foundation/:project/track",
+                post(track_project).route_layer(middleware::from_fn(require_project_maintainer)),
+            )
+            .route(
+                "/api/admin/projects/:foundation/:project/webhooks",
+                get(project_webhooks)
+                    .post(add_project_webhook)
+                    .route_layer(middleware::from_fn(require_project_maintainer)),
+            )
+            .route(
+                "/api/admin/projects/:foundation/:project/webhooks/:project_webhook_id",
+                delete(delete_project_webhook)
+                    .route_layer(middleware::from_fn(require_project_maintainer)),
+            )
+            .route(
+                "/api/admin/projects/:foundation/:project/:repository/bundle.tar.gz",
+                get(repository_bundle).route_layer(middleware::from_fn(require_project_maintainer)),
+            )
+            .route(
+                "/api/admin/roles",
+                get(role_assignments)
+                    .post(add_role_assignment)
+                    .route_layer(middleware::from_fn(require_platform_admin)),
+            )
+            .route(
+                "/api/admin/roles/:role_assignment_id",
+                delete(delete_role_assignment)
+                    .route_layer(middleware::from_fn(require_platform_admin)),
+            )
+            .route_layer(middleware::from_fn_with_state(auth, authenticate))
+            .layer(
+                ServiceBuilder::new()
+                    .layer(TraceLayer::new_for_http().make_span_with(request_span))
+                    .layer(middleware::from_fn(metrics_collector)),
+            )
+            .with_state(state);
+        router = router.merge(admin_routes);
+    }
+
     Ok(router)
 }
 
+/// Create the span of the request provided. Each request is identified by a
+/// new request id, and is linked to the current apiserver run.
+fn request_span<B>(request: &Request<B>) -> Span {
+    info_span!(
+        "request",
+        run_id = %*RUN_ID,
+        request_id = %Uuid::new_v4(),
+        method = %request.method(),
+        uri = %request.uri(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        db::{MockDB, SearchProjectsInput},
+        auth::{Role, RoleAssignment},
+        bundle::RepositoryBundle,
+        db::{MockDB, SearchProjectsInput, SearchProjectsOutput, UnsupportedError},
+        ratelimit::{hash_api_key, ApiKey, API_KEY_HEADER},
         views::MockViewsTracker,
     };
     use axum::{
         body::Body,
         http::{
-            header::{CACHE_CONTROL, CONTENT_TYPE},
+            header::{
+                ACCEPT_ENCODING, AUTHORIZATION, CACHE_CONTROL, CONTENT_DISPOSITION,
+                CONTENT_ENCODING, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RETRY_AFTER,
+            },
             Request,
         },
     };
-    use clomonitor_core::{linter::*, score::Score};
-    use mime::{APPLICATION_JSON, CSV, HTML};
+    use clomonitor_core::{
+        cache::MemoryCache,
+        linter::*,
+        score::{Score, Weights},
+    };
+    use mime::{APPLICATION_JSON, CSV, HTML, PNG};
     use mockall::predicate::*;
     use serde_json::json;
-    use std::{fs, future, sync::Arc};
+    use std::{collections::BTreeMap, fs, future, sync::Arc};
     use tera::Context;
     use time::Date;
     use tokio::sync::RwLock;
     use tower::ServiceExt;
     use uuid::Uuid;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
 
     const TESTDATA_PATH: &str = "src/testdata";
     const FOUNDATION: &str = "cncf";
     const PROJECT: &str = "artifact-hub";
     const PROJECT_ID: &str = "00000000-0000-0000-0000-000000000001";
     const DATE: &str = "2022-10-28";
+    const ROLE_ASSIGNMENT_ID: &str = "00000000-0000-0000-0001-000000000000";
+    const API_KEY_ID: &str = "00000000-0000-0000-0002-000000000000";
     const REPOSITORY: &str = "artifact-hub";
+    const REPOSITORY_ID: &str = "00000000-0000-0000-0003-000000000000";
+    const ADMIN_TOKEN: &str = "admin-token";
+    const GITHUB_TOKEN: &str = "gho_token";
+    const GITHUB_SUBJECT: &str = "github:tegioz";
 
     #[tokio::test]
     async fn badge_found() {
@@ -199,58 +464,62 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn badge_not_found() {
+    async fn badge_found_translated() {
         let mut db = MockDB::new();
         db.expect_project_rating()
             .with(eq(FOUNDATION), eq(PROJECT))
             .times(1)
-            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(Some("b".to_string())))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}/badge"))
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/badge?lang=ja"
+                    ))
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let badge: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(badge["label"], "CLOMonitor レポート");
+        assert_eq!(badge["message"], "B");
     }
 
     #[tokio::test]
-    async fn docs_files() {
-        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+    async fn badge_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_rating()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri("/docs/topics.html")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}/badge"))
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(
-            response.headers()[CACHE_CONTROL],
-            format!("max-age={}", DOCS_CACHE_MAX_AGE)
-        );
-        assert_eq!(
-            hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            fs::read_to_string(Path::new(TESTDATA_PATH).join("docs").join("topics.html")).unwrap()
-        );
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn index() {
+    async fn docs_files() {
         let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri("/")
+                    .uri("/docs/topics.html")
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -260,26 +529,50 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(
             response.headers()[CACHE_CONTROL],
-            format!("max-age={}", INDEX_CACHE_MAX_AGE)
+            format!("max-age={}", DOCS_CACHE_MAX_AGE)
         );
-        assert_eq!(response.headers()[CONTENT_TYPE], HTML.as_ref());
         assert_eq!(
             hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            render_index(
-                INDEX_META_TITLE,
-                INDEX_META_DESCRIPTION,
-                "http://localhost:8000/static/media/clomonitor.png"
-            )
+            fs::read_to_string(Path::new(TESTDATA_PATH).join("docs").join("topics.html")).unwrap()
         );
     }
 
     #[tokio::test]
-    async fn index_fallback() {
-        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+    async fn foundation_feed_found() {
+        let mut db = MockDB::new();
+        db.expect_foundation_feed()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_: &str| {
+                let feed = FoundationFeed {
+                    foundation: "cncf".to_string(),
+                    foundation_display_name: "CNCF".to_string(),
+                    entries: vec![
+                        FoundationFeedEntry {
+                            kind: "rating_change".to_string(),
+                            name: "project1".to_string(),
+                            display_name: "Project 1".to_string(),
+                            rating_from: "b".to_string(),
+                            rating_to: "a".to_string(),
+                            updated_at: "2023-01-01T00:00:00Z".to_string(),
+                        },
+                        FoundationFeedEntry {
+                            kind: "new_project".to_string(),
+                            name: "project2".to_string(),
+                            display_name: "Project 2".to_string(),
+                            updated_at: "2022-03-15T00:00:00Z".to_string(),
+                            ..FoundationFeedEntry::default()
+                        },
+                    ],
+                };
+                Box::pin(future::ready(Ok(Some(feed))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri("/not-found")
+                    .uri(format!("/api/projects/{FOUNDATION}/feed.xml"))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -289,96 +582,130 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(
             response.headers()[CACHE_CONTROL],
-            format!("max-age={}", INDEX_CACHE_MAX_AGE)
-        );
-        assert_eq!(response.headers()[CONTENT_TYPE], HTML.as_ref());
-        assert_eq!(
-            hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            render_index(
-                INDEX_META_TITLE,
-                INDEX_META_DESCRIPTION,
-                "http://localhost:8000/static/media/clomonitor.png"
-            )
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
         );
+        assert_eq!(response.headers()[CONTENT_TYPE], ATOM);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("<updated>2023-01-01T00:00:00Z</updated>"));
+        assert!(body.contains("<title>Project 1 rating changed from B to A</title>"));
+        assert!(body.contains("<title>Project 2 joined CNCF</title>"));
     }
 
     #[tokio::test]
-    async fn index_project() {
-        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+    async fn foundation_feed_not_found() {
+        let mut db = MockDB::new();
+        db.expect_foundation_feed()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!("/projects/{FOUNDATION}/{PROJECT}"))
+                    .uri(format!("/api/projects/{FOUNDATION}/feed.xml"))
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(
-            response.headers()[CACHE_CONTROL],
-            format!("max-age={}", INDEX_CACHE_MAX_AGE)
-        );
-        assert_eq!(response.headers()[CONTENT_TYPE], HTML.as_ref());
-        assert_eq!(
-            hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            render_index(
-                PROJECT,
-                INDEX_META_DESCRIPTION_PROJECT,
-                "http://localhost:8000/projects/cncf/artifact-hub/report-summary.png"
-            )
-        );
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn project_found() {
+    async fn foundation_feed_not_supported() {
         let mut db = MockDB::new();
-        db.expect_project_data()
-            .with(eq(FOUNDATION), eq(PROJECT))
+        db.expect_foundation_feed()
+            .with(eq(FOUNDATION))
             .times(1)
-            .returning(|_, _| {
-                Box::pin(future::ready(Ok(Some(
-                    r#"{"project": "info"}"#.to_string(),
-                ))))
+            .returning(|_: &str| {
+                let err = UnsupportedError {
+                    operation: "foundation feeds".to_string(),
+                };
+                Box::pin(future::ready(Err(err.into())))
             });
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}"))
+                    .uri(format!("/api/projects/{FOUNDATION}/feed.xml"))
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn datasets_found() {
+        let mut db = MockDB::new();
+        db.expect_datasets().times(1).returning(|| {
+            Box::pin(future::ready(Ok(vec![Date::parse(
+                DATE,
+                &SNAPSHOT_DATE_FORMAT,
+            )
+            .unwrap()])))
+        });
+        let cfg = Config::builder()
+            .add_source(setup_test_config())
+            .set_override(
+                "apiserver.datasets.baseURL",
+                "https://datasets.example.com/",
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let response = setup(
+            Arc::new(cfg),
+            Arc::new(db),
+            Arc::new(RwLock::new(MockViewsTracker::new())),
+            Arc::new(MemoryCache::new()),
+        )
+        .unwrap()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/api/datasets")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(
             response.headers()[CACHE_CONTROL],
             format!("max-age={}", DEFAULT_API_MAX_AGE)
         );
-        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
         assert_eq!(
             hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            r#"{"project": "info"}"#.to_string(),
+            json!({
+                "version": "v1",
+                "datasets": [{
+                    "date": DATE,
+                    "files": {
+                        "json": format!("https://datasets.example.com/v1/{DATE}/projects.json"),
+                        "parquet": format!("https://datasets.example.com/v1/{DATE}/checks.parquet"),
+                    }
+                }]
+            })
+            .to_string()
         );
     }
 
     #[tokio::test]
-    async fn project_not_found() {
-        let mut db = MockDB::new();
-        db.expect_project_data()
-            .with(eq(FOUNDATION), eq(PROJECT))
-            .times(1)
-            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
-
-        let response = setup_test_router(db, MockViewsTracker::new())
+    async fn datasets_not_configured() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}"))
+                    .uri("/api/datasets")
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -389,7 +716,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn project_snapshot_invalid_date_format() {
+    async fn foundation_report_invalid_date_format() {
         let db = MockDB::new();
 
         let response = setup_test_router(db, MockViewsTracker::new())
@@ -397,7 +724,7 @@ mod tests {
                 Request::builder()
                     .method("GET")
                     .uri(format!(
-                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/20221028"
+                        "/api/foundations/{FOUNDATION}/report?from=20220101"
                     ))
                     .body(Body::empty())
                     .unwrap(),
@@ -409,19 +736,49 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn project_snapshot_found() {
+    async fn foundation_report_md_found() {
         let mut db = MockDB::new();
-        db.expect_project_snapshot()
+        db.expect_foundation_report()
             .with(
                 eq(FOUNDATION),
-                eq(PROJECT),
-                eq(Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()),
+                eq(Date::parse("2022-01-01", &SNAPSHOT_DATE_FORMAT).unwrap()),
+                eq(Date::parse("2022-12-31", &SNAPSHOT_DATE_FORMAT).unwrap()),
             )
             .times(1)
-            .returning(|_, _, _| {
-                Box::pin(future::ready(Ok(Some(
-                    r#"{"snapshot": "data"}"#.to_string(),
-                ))))
+            .returning(|_: &str, _: &Date, _: &Date| {
+                let report = FoundationReport {
+                    foundation: "cncf".to_string(),
+                    foundation_display_name: "CNCF".to_string(),
+                    from: "2022-01-01".to_string(),
+                    to: "2022-12-31".to_string(),
+                    score_evolution: vec![
+                        FoundationReportScore {
+                            month: "2022-01".to_string(),
+                            average_score: 70.0,
+                        },
+                        FoundationReportScore {
+                            month: "2022-06".to_string(),
+                            average_score: 72.0,
+                        },
+                    ],
+                    checks_improvement: vec![FoundationReportCheck {
+                        check: "adopters".to_string(),
+                        passing_from: 20.0,
+                        passing_to: 50.0,
+                    }],
+                    rating_changes: vec![FoundationReportRatingChange {
+                        name: "project1".to_string(),
+                        display_name: "Project 1".to_string(),
+                        rating_from: "b".to_string(),
+                        rating_to: "a".to_string(),
+                    }],
+                    new_projects: vec![FoundationReportProject {
+                        name: "project2".to_string(),
+                        display_name: "Project 2".to_string(),
+                    }],
+                    removed_projects: vec![],
+                };
+                Box::pin(future::ready(Ok(Some(report))))
             });
 
         let response = setup_test_router(db, MockViewsTracker::new())
@@ -429,7 +786,7 @@ mod tests {
                 Request::builder()
                     .method("GET")
                     .uri(format!(
-                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/{DATE}"
+                        "/api/foundations/{FOUNDATION}/report?from=2022-01-01&to=2022-12-31"
                     ))
                     .body(Body::empty())
                     .unwrap(),
@@ -438,56 +795,64 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=86400");
-        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
         assert_eq!(
-            hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            r#"{"snapshot": "data"}"#.to_string(),
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
         );
+        assert_eq!(response.headers()[CONTENT_TYPE], MARKDOWN);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let golden_path = "src/testdata/foundation-report.golden.md";
+        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
+        let golden = fs::read(golden_path).unwrap();
+        assert_eq!(body, golden);
     }
 
     #[tokio::test]
-    async fn project_snapshot_not_found() {
+    async fn foundation_maturity_gates_md_found() {
         let mut db = MockDB::new();
-        db.expect_project_snapshot()
-            .with(
-                eq(FOUNDATION),
-                eq(PROJECT),
-                eq(Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()),
-            )
+        db.expect_foundation_maturity_gates()
+            .with(eq(FOUNDATION))
             .times(1)
-            .returning(|_, _, _| Box::pin(future::ready(Ok(None))));
+            .returning(|_| Box::pin(future::ready(Ok(Some(sample_maturity_gates_report())))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!(
-                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/{DATE}"
-                    ))
+                    .uri(format!("/api/foundations/{FOUNDATION}/maturity-gates"))
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], MARKDOWN);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let golden_path = "src/testdata/maturity-gates-report.golden.md";
+        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
+        let golden = fs::read(golden_path).unwrap();
+        assert_eq!(body, golden);
     }
 
     #[tokio::test]
-    async fn report_summary_png_not_found() {
+    async fn foundation_maturity_gates_json_found() {
         let mut db = MockDB::new();
-        db.expect_project_score()
-            .with(eq(FOUNDATION), eq(PROJECT))
+        db.expect_foundation_maturity_gates()
+            .with(eq(FOUNDATION))
             .times(1)
-            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+            .returning(|_| Box::pin(future::ready(Ok(Some(sample_maturity_gates_report())))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
                     .uri(format!(
-                        "/projects/{FOUNDATION}/{PROJECT}/report-summary.png"
+                        "/api/foundations/{FOUNDATION}/maturity-gates?format=json"
                     ))
                     .body(Body::empty())
                     .unwrap(),
@@ -495,31 +860,27 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        let body: MaturityGatesReport =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        assert_eq!(body, sample_maturity_gates_report());
     }
 
     #[tokio::test]
-    async fn report_summary_svg_found() {
+    async fn foundation_maturity_gates_invalid_format() {
         let mut db = MockDB::new();
-        db.expect_project_score()
-            .with(eq(FOUNDATION), eq(PROJECT))
+        db.expect_foundation_maturity_gates()
             .times(1)
-            .returning(|_: &str, _: &str| {
-                let score = Score {
-                    global: 80.0,
-                    documentation: Some(80.0),
-                    license: Some(50.0),
-                    ..Score::default()
-                };
-                Box::pin(future::ready(Ok(Some(score))))
-            });
+            .returning(|_| Box::pin(future::ready(Ok(Some(sample_maturity_gates_report())))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
                     .uri(format!(
-                        "/api/projects/{FOUNDATION}/{PROJECT}/report-summary"
+                        "/api/foundations/{FOUNDATION}/maturity-gates?format=pdf"
                     ))
                     .body(Body::empty())
                     .unwrap(),
@@ -527,33 +888,21 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(
-            response.headers()[CACHE_CONTROL],
-            format!("max-age={}", DEFAULT_API_MAX_AGE)
-        );
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let golden_path = "src/testdata/project-report-summary.golden.svg";
-        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
-        let golden = fs::read(golden_path).unwrap();
-        assert_eq!(body, golden);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn report_summary_svg_not_found() {
+    async fn foundation_maturity_gates_not_found() {
         let mut db = MockDB::new();
-        db.expect_project_score()
-            .with(eq(FOUNDATION), eq(PROJECT))
+        db.expect_foundation_maturity_gates()
             .times(1)
-            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+            .returning(|_| Box::pin(future::ready(Ok(None))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!(
-                        "/api/projects/{FOUNDATION}/{PROJECT}/report-summary"
-                    ))
+                    .uri(format!("/api/foundations/{FOUNDATION}/maturity-gates"))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -564,116 +913,45 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn repositories_checks() {
+    async fn foundation_report_not_found() {
         let mut db = MockDB::new();
-        db.expect_repositories_with_checks()
+        db.expect_foundation_report()
             .times(1)
-            .returning(|| Box::pin(future::ready(Ok("CSV data".to_string()))));
+            .returning(|_: &str, _: &Date, _: &Date| Box::pin(future::ready(Ok(None))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri("/data/repositories.csv")
+                    .uri(format!("/api/foundations/{FOUNDATION}/report"))
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
-        assert_eq!(response.headers()[CONTENT_TYPE], CSV.as_ref());
-        assert_eq!(
-            hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            "CSV data".to_string(),
-        );
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn repository_report_md_found() {
+    async fn foundation_weights_found() {
         let mut db = MockDB::new();
-        db.expect_repository_report_md()
-            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
             .times(1)
-            .returning(|_: &str, _: &str, _: &str| {
-                let report_md = RepositoryReportMDTemplate {
-                    name: "artifact-hub".to_string(),
-                    url: "https://github.com/artifacthub/hub".to_string(),
-                    check_sets: vec![CheckSet::Code],
-                    score: Some(Score {
-                        global: 99.99999999999999,
-                        global_weight: 95,
-                        documentation: Some(100.0),
-                        documentation_weight: Some(30),
-                        license: Some(100.0),
-                        license_weight: Some(20),
-                        best_practices: Some(100.0),
-                        best_practices_weight: Some(20),
-                        security: Some(100.0),
-                        security_weight: Some(20),
-                        legal: Some(100.0),
-                        legal_weight: Some(5),
-                    }),
-                    report: Some(Report {
-                        documentation: Documentation {
-                            adopters: Some(CheckOutput::passed()),
-                            code_of_conduct: Some(CheckOutput::passed()),
-                            contributing: Some(CheckOutput::passed()),
-                            changelog: Some(CheckOutput::passed()),
-                            governance: Some(CheckOutput::passed()),
-                            maintainers: Some(CheckOutput::passed()),
-                            readme: Some(CheckOutput::passed()),
-                            roadmap: Some(CheckOutput::passed()),
-                            website: Some(CheckOutput::passed()),
-                        },
-                        license: License {
-                            license_approved: Some(CheckOutput::passed()),
-                            license_scanning: Some(
-                                CheckOutput::passed()
-                                    .url(Some("https://license-scanning.url".to_string())),
-                            ),
-                            license_spdx_id: Some(
-                                CheckOutput::passed().value(Some("Apache-2.0".to_string())),
-                            ),
-                        },
-                        best_practices: BestPractices {
-                            analytics: Some(CheckOutput::passed()),
-                            artifacthub_badge: Some(CheckOutput::exempt()),
-                            cla: Some(CheckOutput::passed()),
-                            community_meeting: Some(CheckOutput::passed()),
-                            dco: Some(CheckOutput::passed()),
-                            github_discussions: Some(CheckOutput::passed()),
-                            openssf_badge: Some(CheckOutput::passed()),
-                            recent_release: Some(CheckOutput::passed()),
-                            slack_presence: Some(CheckOutput::passed()),
-                        },
-                        security: Security {
-                            binary_artifacts: Some(CheckOutput::passed()),
-                            code_review: Some(CheckOutput::passed()),
-                            dangerous_workflow: Some(CheckOutput::passed()),
-                            dependency_update_tool: Some(CheckOutput::passed()),
-                            maintained: Some(CheckOutput::passed()),
-                            sbom: Some(CheckOutput::passed()),
-                            security_policy: Some(CheckOutput::passed()),
-                            signed_releases: Some(CheckOutput::passed()),
-                            token_permissions: Some(CheckOutput::passed()),
-                        },
-                        legal: Legal {
-                            trademark_disclaimer: Some(CheckOutput::passed()),
-                        },
-                    }),
-                };
-                Box::pin(future::ready(Ok(Some(report_md))))
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(Weights {
+                    checks: BTreeMap::from([("adopters".to_string(), 3)]),
+                    sections: BTreeMap::from([(ReportSection::Security, 30)]),
+                    ..Weights::default()
+                }))))
             });
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!(
-                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/report.md"
-                    ))
+                    .uri(format!("/api/foundations/{FOUNDATION}/weights"))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -685,28 +963,36 @@ mod tests {
             response.headers()[CACHE_CONTROL],
             format!("max-age={}", DEFAULT_API_MAX_AGE)
         );
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let golden_path = "src/testdata/repository-report.golden.md";
-        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
-        let golden = fs::read(golden_path).unwrap();
-        assert_eq!(body, golden);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        assert_eq!(
+            body["checks"]["adopters"],
+            json!({"default": 1, "effective": 3})
+        );
+        assert_eq!(
+            body["checks"]["openssf_badge"],
+            json!({"default": 10, "effective": 10})
+        );
+        assert_eq!(body["sections"], json!({"security": 30}));
+        assert_eq!(body["severities"]["readme"], json!("required"));
+        assert_eq!(body["severities"]["roadmap"], json!("optional"));
     }
 
     #[tokio::test]
-    async fn repository_report_md_not_found() {
+    async fn foundation_weights_not_found() {
         let mut db = MockDB::new();
-        db.expect_repository_report_md()
-            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
             .times(1)
-            .returning(|_: &str, _: &str, _: &str| Box::pin(future::ready(Ok(None))));
+            .returning(|_| Box::pin(future::ready(Ok(None))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!(
-                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/report.md"
-                    ))
+                    .uri(format!("/api/foundations/{FOUNDATION}/weights"))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -717,55 +1003,12 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn search_projects() {
-        let mut db = MockDB::new();
-        db.expect_search_projects()
-            .with(eq(SearchProjectsInput {
-                limit: Some(10),
-                offset: Some(1),
-                sort_by: Some("name".to_string()),
-                sort_direction: Some("asc".to_string()),
-                text: Some("hub".to_string()),
-                foundation: Some(vec!["cncf".to_string()]),
-                maturity: Some(vec!["graduated".to_string(), "incubating".to_string()]),
-                rating: Some(vec!['a', 'b']),
-                accepted_from: Some("20200101".to_string()),
-                accepted_to: Some("20210101".to_string()),
-                passing_check: Some(vec!["dco".to_string(), "readme".to_string()]),
-                not_passing_check: Some(vec!["website".to_string()]),
-            }))
-            .times(1)
-            .returning(|_| {
-                Box::pin(future::ready(Ok((
-                    1,
-                    r#"[{"project": "info"}]"#.to_string(),
-                ))))
-            });
-
-        let response = setup_test_router(db, MockViewsTracker::new())
+    async fn index() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(
-                        "\
-                        /api/projects/search?\
-                            limit=10&\
-                            offset=1&\
-                            sort_by=name&\
-                            sort_direction=asc&\
-                            text=hub&\
-                            foundation[0]=cncf&\
-                            maturity[0]=graduated&\
-                            maturity[1]=incubating&\
-                            rating[0]=a&\
-                            rating[1]=b&\
-                            accepted_from=20200101&\
-                            accepted_to=20210101&\
-                            passing_check[0]=dco&\
-                            passing_check[1]=readme&\
-                            not_passing_check[0]=website\
-                        ",
-                    )
+                    .uri("/")
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -775,23 +1018,26 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(
             response.headers()[CACHE_CONTROL],
-            format!("max-age={}", DEFAULT_API_MAX_AGE)
+            format!("max-age={}", INDEX_CACHE_MAX_AGE)
         );
-        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
-        assert_eq!(response.headers()[PAGINATION_TOTAL_COUNT], "1");
+        assert_eq!(response.headers()[CONTENT_TYPE], HTML.as_ref());
         assert_eq!(
             hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            r#"[{"project": "info"}]"#.to_string(),
+            render_index(
+                INDEX_META_TITLE,
+                INDEX_META_DESCRIPTION,
+                "http://localhost:8000/static/media/clomonitor.png"
+            )
         );
     }
 
     #[tokio::test]
-    async fn static_files() {
+    async fn index_fallback() {
         let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri("/static/lib.js")
+                    .uri("/not-found")
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -801,27 +1047,26 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(
             response.headers()[CACHE_CONTROL],
-            format!("max-age={}", STATIC_CACHE_MAX_AGE)
+            format!("max-age={}", INDEX_CACHE_MAX_AGE)
         );
+        assert_eq!(response.headers()[CONTENT_TYPE], HTML.as_ref());
         assert_eq!(
             hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            fs::read_to_string(Path::new(TESTDATA_PATH).join("lib.js")).unwrap()
+            render_index(
+                INDEX_META_TITLE,
+                INDEX_META_DESCRIPTION,
+                "http://localhost:8000/static/media/clomonitor.png"
+            )
         );
     }
 
     #[tokio::test]
-    async fn stats() {
-        let mut db = MockDB::new();
-        db.expect_stats()
-            .withf(|v| v.as_deref() == Some(FOUNDATION))
-            .times(1)
-            .returning(|_| Box::pin(future::ready(Ok(r#"{"some": "stats"}"#.to_string()))));
-
-        let response = setup_test_router(db, MockViewsTracker::new())
+    async fn index_project() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!("/api/stats?foundation={FOUNDATION}"))
+                    .uri(format!("/projects/{FOUNDATION}/{PROJECT}"))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -829,45 +1074,109 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
-        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", INDEX_CACHE_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], HTML.as_ref());
         assert_eq!(
             hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            r#"{"some": "stats"}"#.to_string(),
+            render_index(
+                PROJECT,
+                INDEX_META_DESCRIPTION_PROJECT,
+                "http://localhost:8000/projects/cncf/artifact-hub/report-summary.png"
+            )
         );
     }
 
     #[tokio::test]
-    async fn stats_snapshot_invalid_date_format() {
-        let db = MockDB::new();
-
-        let response = setup_test_router(db, MockViewsTracker::new())
+    async fn openapi_json() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(format!(
-                        "/api/stats/snapshots/20230105?foundation={FOUNDATION}"
-                    ))
+                    .uri("/api/openapi.json")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        let mut paths: Vec<&String> = body["paths"].as_object().unwrap().keys().collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "/api/admin/api-keys",
+                "/api/admin/api-keys/{api_key_id}",
+                "/api/admin/consistency-issues",
+                "/api/admin/foundations",
+                "/api/admin/foundations/{foundation}",
+                "/api/admin/foundations/{foundation}/data-ref",
+                "/api/admin/foundations/{foundation}/register",
+                "/api/admin/foundations/{foundation}/track",
+                "/api/admin/projects/{foundation}/{project}/claim",
+                "/api/admin/projects/{foundation}/{project}/exemptions",
+                "/api/admin/projects/{foundation}/{project}/exemptions/{check_id}",
+                "/api/admin/projects/{foundation}/{project}/track",
+                "/api/admin/projects/{foundation}/{project}/webhooks",
+                "/api/admin/projects/{foundation}/{project}/webhooks/{project_webhook_id}",
+                "/api/admin/projects/{foundation}/{project}/{repository}/bundle.tar.gz",
+                "/api/admin/roles",
+                "/api/admin/roles/{role_assignment_id}",
+                "/api/datasets",
+                "/api/foundations/{foundation}/maturity-gates",
+                "/api/foundations/{foundation}/report",
+                "/api/foundations/{foundation}/weights",
+                "/api/projects/search",
+                "/api/projects/views/{project_id}",
+                "/api/projects/{foundation}/feed.xml",
+                "/api/projects/{foundation}/{project}",
+                "/api/projects/{foundation}/{project}/badge",
+                "/api/projects/{foundation}/{project}/badge/{section}",
+                "/api/projects/{foundation}/{project}/checks/{check_id}/history",
+                "/api/projects/{foundation}/{project}/rating-change.png",
+                "/api/projects/{foundation}/{project}/report-summary",
+                "/api/projects/{foundation}/{project}/report.pdf",
+                "/api/projects/{foundation}/{project}/score-trace",
+                "/api/projects/{foundation}/{project}/score/simulate",
+                "/api/projects/{foundation}/{project}/snapshots/{date}",
+                "/api/projects/{foundation}/{project}/tracking-errors",
+                "/api/projects/{foundation}/{project}/{repository}/report.md",
+                "/api/repositories/search",
+                "/api/repositories/{repository_id}/report",
+                "/api/stats",
+                "/api/stats/checks",
+                "/api/stats/leaderboard",
+                "/api/stats/most-improved",
+                "/api/stats/snapshots/{date}",
+                "/api/sync/delta",
+                "/api/tracker/progress",
+            ]
+        );
+        assert!(body["components"]["schemas"]["ScoreTrace"].is_object());
+        assert!(body["components"]["securitySchemes"]["bearer_token"].is_object());
     }
 
     #[tokio::test]
-    async fn stats_snapshot_found() {
+    async fn project_check_history_found() {
         let mut db = MockDB::new();
-        db.expect_stats_snapshot()
-            .withf(|foundation, date| {
-                foundation.as_deref() == Some(FOUNDATION)
-                    && *date == Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()
-            })
+        db.expect_project_check_history()
+            .with(eq(FOUNDATION), eq(PROJECT), eq("security_policy"))
             .times(1)
-            .returning(|_, _| {
-                Box::pin(future::ready(Ok(Some(r#"{"some": "stats"}"#.to_string()))))
+            .returning(|_, _, _| {
+                Box::pin(future::ready(Ok(Some(
+                    r#"{"check_id": "security_policy", "repositories": []}"#.to_string(),
+                ))))
             });
 
         let response = setup_test_router(db, MockViewsTracker::new())
@@ -875,7 +1184,7 @@ mod tests {
                 Request::builder()
                     .method("GET")
                     .uri(format!(
-                        "/api/stats/snapshots/{DATE}?foundation={FOUNDATION}"
+                        "/api/projects/{FOUNDATION}/{PROJECT}/checks/security_policy/history"
                     ))
                     .body(Body::empty())
                     .unwrap(),
@@ -884,30 +1193,2942 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
-        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=86400");
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
         assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
         assert_eq!(
             hyper::body::to_bytes(response.into_body()).await.unwrap(),
-            r#"{"some": "stats"}"#.to_string(),
+            r#"{"check_id": "security_policy", "repositories": []}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn project_check_history_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_check_history()
+            .with(eq(FOUNDATION), eq(PROJECT), eq("security_policy"))
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/checks/security_policy/history"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn project_tracking_errors_found() {
+        let mut db = MockDB::new();
+        db.expect_project_tracking_errors()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(Some(
+                    r#"[{"check_id": "openssf_badge", "class": "timeout"}]"#.to_string(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/tracking-errors"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"check_id": "openssf_badge", "class": "timeout"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn project_tracking_errors_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_tracking_errors()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/tracking-errors"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn project_found() {
+        let mut db = MockDB::new();
+        db.expect_project_data()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(Some(
+                    r#"{"project": "info"}"#.to_string(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"project": "info"}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn project_found_with_remediations() {
+        let mut db = MockDB::new();
+        db.expect_project_data()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(Some(
+                    json!({
+                        "name": "artifact-hub",
+                        "repositories": [{
+                            "name": "hub",
+                            "report": {
+                                "data": {
+                                    "documentation": {
+                                        "readme": {"passed": true, "exempt": false, "failed": false},
+                                    },
+                                    "security": {
+                                        "security_policy": {"passed": false, "exempt": false, "failed": false},
+                                    },
+                                },
+                            },
+                        }],
+                    })
+                    .to_string(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        let data = &body["repositories"][0]["report"]["data"];
+        assert!(data["documentation"]["readme"].get("remediation").is_none());
+        assert_eq!(
+            data["security"]["security_policy"]["remediation"],
+            json!(remediation("security_policy").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn project_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_data()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn project_snapshot_invalid_date_format() {
+        let db = MockDB::new();
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/20221028"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn project_snapshot_found() {
+        let mut db = MockDB::new();
+        db.expect_project_snapshot()
+            .with(
+                eq(FOUNDATION),
+                eq(PROJECT),
+                eq(Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(future::ready(Ok(Some(
+                    r#"{"snapshot": "data"}"#.to_string(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/{DATE}"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=86400");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"snapshot": "data"}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn project_snapshot_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_snapshot()
+            .with(
+                eq(FOUNDATION),
+                eq(PROJECT),
+                eq(Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/{DATE}"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn rating_change_png_improved() {
+        let mut db = MockDB::new();
+        db.expect_project_rating_change()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                Box::pin(future::ready(Ok(Some(ProjectRatingChange {
+                    name: "Artifact Hub".to_string(),
+                    logo_url: None,
+                    rating: Some("a".to_string()),
+                    previous_rating: Some("b".to_string()),
+                }))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/rating-change.png"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], PNG.as_ref());
+    }
+
+    #[tokio::test]
+    async fn rating_change_png_not_improved() {
+        let mut db = MockDB::new();
+        db.expect_project_rating_change()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                Box::pin(future::ready(Ok(Some(ProjectRatingChange {
+                    name: "Artifact Hub".to_string(),
+                    logo_url: None,
+                    rating: Some("b".to_string()),
+                    previous_rating: Some("a".to_string()),
+                }))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/rating-change.png"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn rating_change_png_project_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_rating_change()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/rating-change.png"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn report_pdf_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}/report.pdf"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn report_summary_png_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/projects/{FOUNDATION}/{PROJECT}/report-summary.png"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn report_summary_png_invalid_width() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/projects/{FOUNDATION}/{PROJECT}/report-summary.png?width=wide"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn report_summary_svg_found() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                let score = Score {
+                    global: 80.0,
+                    documentation: Some(80.0),
+                    license: Some(50.0),
+                    ..Score::default()
+                };
+                Box::pin(future::ready(Ok(Some(score))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/report-summary"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let golden_path = "src/testdata/project-report-summary.golden.svg";
+        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
+        let golden = fs::read(golden_path).unwrap();
+        assert_eq!(body, golden);
+    }
+
+    #[tokio::test]
+    async fn report_summary_svg_with_theme_and_accent() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(2)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(Some(Score::default())))));
+        let router = setup_test_router(db, MockViewsTracker::new());
+        let request = |query: &str| {
+            Request::builder()
+                .method("GET")
+                .uri(format!(
+                    "/api/projects/{FOUNDATION}/{PROJECT}/report-summary?{query}"
+                ))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        // Valid theme and accent color
+        let response = router
+            .clone()
+            .oneshot(request("theme=dark&accent=FC0"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let svg = std::str::from_utf8(&body).unwrap();
+        assert!(svg.contains(r#"class="dark""#));
+        assert!(svg.contains("fill: #ffcc00;"));
+        assert!(svg.contains("fill: #000000;"));
+
+        // Unknown theme and invalid accent color are ignored
+        let response = router
+            .oneshot(request("theme=%22%3E&accent=red"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let svg = std::str::from_utf8(&body).unwrap();
+        assert!(svg.contains(r#"class="light""#));
+        assert!(!svg.contains(".header-logo path"));
+    }
+
+    #[tokio::test]
+    async fn report_summary_svg_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/report-summary"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn repositories_checks() {
+        let mut db = MockDB::new();
+        db.expect_repositories_with_checks()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok("CSV data".to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/data/repositories.csv")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
+        assert_eq!(response.headers()[CONTENT_TYPE], CSV.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            "CSV data".to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_data()
+            .withf(|repository_id| *repository_id == Uuid::parse_str(REPOSITORY_ID).unwrap())
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(
+                    r#"{"repository": "info"}"#.to_string(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/repositories/{REPOSITORY_ID}/report"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"repository": "info"}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_found_with_remediations() {
+        let mut db = MockDB::new();
+        db.expect_repository_data()
+            .withf(|repository_id| *repository_id == Uuid::parse_str(REPOSITORY_ID).unwrap())
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(
+                    json!({
+                        "name": "hub",
+                        "report": {
+                            "data": {
+                                "security": {
+                                    "security_policy": {"passed": false, "exempt": false, "failed": false},
+                                },
+                            },
+                        },
+                    })
+                    .to_string(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/repositories/{REPOSITORY_ID}/report"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        assert!(body
+            .pointer("/report/data/security/security_policy/remediation")
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn repository_invalid_id() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/repositories/invalid/report")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn repository_not_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_data()
+            .withf(|repository_id| *repository_id == Uuid::parse_str(REPOSITORY_ID).unwrap())
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/repositories/{REPOSITORY_ID}/report"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn repository_report_md_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_report_md()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| {
+                let report_md = RepositoryReportMDTemplate {
+                    name: "artifact-hub".to_string(),
+                    url: "https://github.com/artifacthub/hub".to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    score: Some(Score {
+                        global: 99.99999999999999,
+                        global_weight: 95,
+                        documentation: Some(100.0),
+                        documentation_weight: Some(30),
+                        license: Some(100.0),
+                        license_weight: Some(20),
+                        best_practices: Some(100.0),
+                        best_practices_weight: Some(20),
+                        security: Some(100.0),
+                        security_weight: Some(20),
+                        legal: Some(100.0),
+                        legal_weight: Some(5),
+                        artifacts: None,
+                        artifacts_weight: None,
+                        organization: None,
+                        organization_weight: None,
+                    }),
+                    report: Some(Report {
+                        documentation: Documentation {
+                            accessibility_statement: None,
+                            adopters: Some(CheckOutput::passed()),
+                            code_of_conduct: Some(CheckOutput::passed()),
+                            contributing: Some(CheckOutput::passed()),
+                            changelog: Some(CheckOutput::passed()),
+                            governance: Some(CheckOutput::passed()),
+                            maintainers: Some(CheckOutput::passed()),
+                            maintainers_activity: None,
+                            readme: Some(CheckOutput::passed()),
+                            roadmap: Some(CheckOutput::passed()),
+                            website: Some(CheckOutput::passed()),
+                        },
+                        license: License {
+                            license_approved: Some(CheckOutput::passed()),
+                            license_compatibility: None,
+                            license_scanning: Some(
+                                CheckOutput::passed()
+                                    .url(Some("https://license-scanning.url".to_string())),
+                            ),
+                            license_spdx_id: Some(
+                                CheckOutput::passed().value(Some("Apache-2.0".to_string())),
+                            ),
+                        },
+                        best_practices: BestPractices {
+                            analytics: Some(CheckOutput::passed()),
+                            artifacthub_badge: Some(CheckOutput::exempt()),
+                            cla: Some(CheckOutput::passed()),
+                            community_meeting: Some(CheckOutput::passed()),
+                            dco: Some(CheckOutput::passed()),
+                            dependency_update_automation: Some(CheckOutput::passed()),
+                            github_discussions: Some(CheckOutput::passed()),
+                            openssf_badge: Some(CheckOutput::passed()),
+                            recent_release: Some(CheckOutput::passed()),
+                            release_notes: None,
+                            slack_presence: Some(CheckOutput::passed()),
+                        },
+                        security: Security {
+                            binary_artifacts: Some(CheckOutput::passed()),
+                            branch_protection: None,
+                            code_review: Some(CheckOutput::passed()),
+                            dangerous_workflow: Some(CheckOutput::passed()),
+                            dependency_update_tool: Some(CheckOutput::passed()),
+                            fuzzing: Some(CheckOutput::passed()),
+                            maintained: Some(CheckOutput::passed()),
+                            sbom: Some(CheckOutput::passed()),
+                            security_insights: Some(CheckOutput::passed()),
+                            security_policy: Some(CheckOutput::passed()),
+                            signed_releases: Some(CheckOutput::passed()),
+                            slsa_provenance: None,
+                            supported_versions: Some(CheckOutput::passed()),
+                            token_permissions: Some(CheckOutput::passed()),
+                            vulnerability_disclosure: Some(CheckOutput::passed()),
+                        },
+                        legal: Legal {
+                            dco_or_cla: Some(CheckOutput::passed()),
+                            privacy_policy: None,
+                            trademark_disclaimer: Some(CheckOutput::passed()),
+                        },
+                        artifacts: Artifacts::default(),
+                        organization: Organization::default(),
+                        external: BTreeMap::new(),
+                        provider_limited: BTreeMap::new(),
+                        durations: BTreeMap::new(),
+                    }),
+                    failed_checks: vec![],
+                };
+                Box::pin(future::ready(Ok(Some(report_md))))
+            });
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/report.md"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let golden_path = "src/testdata/repository-report.golden.md";
+        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
+        let golden = fs::read(golden_path).unwrap();
+        assert_eq!(body, golden);
+    }
+
+    #[tokio::test]
+    async fn repository_report_md_failed_checks_grouped_by_severity() {
+        let mut db = MockDB::new();
+        db.expect_repository_report_md()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| {
+                let report_md = RepositoryReportMDTemplate {
+                    name: "artifact-hub".to_string(),
+                    url: "https://github.com/artifacthub/hub".to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    score: Some(Score::default()),
+                    report: Some(Report {
+                        documentation: Documentation {
+                            adopters: Some(CheckOutput::not_passed()),
+                            readme: Some(CheckOutput::not_passed()),
+                            roadmap: Some(CheckOutput::not_passed()),
+                            website: Some(CheckOutput::passed()),
+                            ..Documentation::default()
+                        },
+                        ..Report::default()
+                    }),
+                    failed_checks: vec![],
+                };
+                Box::pin(future::ready(Ok(Some(report_md))))
+            });
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(Weights {
+                    severities: BTreeMap::from([("adopters".to_string(), Severity::Required)]),
+                    ..Weights::default()
+                }))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/report.md"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(
+            "## Checks to fix\n\nThe following checks did not pass. Required checks should be fixed first.\n\n### Required\n\n  - `adopters`\n  - `readme`\n\n### Optional\n\n  - `roadmap`\n\n## Checks"
+        ));
+    }
+
+    #[tokio::test]
+    async fn repository_report_md_not_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_report_md()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/report.md"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn score_trace_found() {
+        let mut db = MockDB::new();
+        db.expect_project_reports()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                Box::pin(future::ready(Ok(vec![(
+                    "hub".to_string(),
+                    1.0,
+                    Report {
+                        documentation: Documentation {
+                            adopters: Some(CheckOutput::passed()),
+                            readme: Some(CheckOutput::not_passed()),
+                            ..Documentation::default()
+                        },
+                        ..Report::default()
+                    },
+                )])))
+            });
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(Weights {
+                    checks: BTreeMap::from([("adopters".to_string(), 10)]),
+                    ..Weights::default()
+                }))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}/score-trace"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        assert_eq!(body["rating"], "b");
+        assert_eq!(body["score"]["documentation"], 50.0);
+        assert_eq!(
+            body["repositories"][0]["sections"][0]["checks"][0],
+            json!({
+                "check_id": "adopters",
+                "status": "passed",
+                "external": false,
+                "default_weight": 1,
+                "weight": 10,
+                "credit": 100,
+                "points": 10.0
+            })
+        );
+        assert_eq!(body["aggregation"]["global"][0]["coefficient"], 1.0);
+    }
+
+    #[tokio::test]
+    async fn score_trace_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_reports()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(vec![]))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}/score-trace"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn simulate_score_found() {
+        let mut db = MockDB::new();
+        db.expect_project_reports()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                Box::pin(future::ready(Ok(vec![(
+                    "hub".to_string(),
+                    1.0,
+                    Report {
+                        documentation: Documentation {
+                            adopters: Some(CheckOutput::passed()),
+                            readme: Some(CheckOutput::not_passed()),
+                            ..Documentation::default()
+                        },
+                        ..Report::default()
+                    },
+                )])))
+            });
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/score/simulate"
+                    ))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(r#"{"checks": ["readme"]}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        assert_eq!(body["simulated_score"]["documentation"], 100.0);
+        assert_eq!(body["simulated_rating"], "a");
+        assert_eq!(body["fixed_checks"], json!(["readme"]));
+    }
+
+    #[tokio::test]
+    async fn simulate_score_unknown_check() {
+        let mut db = MockDB::new();
+        db.expect_project_reports()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                Box::pin(future::ready(Ok(vec![(
+                    "hub".to_string(),
+                    1.0,
+                    Report::default(),
+                )])))
+            });
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/score/simulate"
+                    ))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(r#"{"checks": ["unknown"]}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn search_projects() {
+        let mut db = MockDB::new();
+        db.expect_search_projects()
+            .with(eq(SearchProjectsInput {
+                limit: Some(10),
+                offset: Some(1),
+                sort_by: Some("name".to_string()),
+                sort_direction: Some("asc".to_string()),
+                text: Some("hub".to_string()),
+                foundation: Some(vec!["cncf".to_string()]),
+                maturity: Some(vec!["graduated".to_string(), "incubating".to_string()]),
+                rating: Some(vec!['a', 'b']),
+                accepted_from: Some("20200101".to_string()),
+                accepted_to: Some("20210101".to_string()),
+                passing_check: Some(vec!["dco".to_string(), "readme".to_string()]),
+                not_passing_check: Some(vec!["website".to_string()]),
+                ..SearchProjectsInput::default()
+            }))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(SearchProjectsOutput {
+                    count: 1,
+                    projects: r#"[{"project": "info"}]"#.to_string(),
+                    ..SearchProjectsOutput::default()
+                })))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(
+                        "\
+                        /api/projects/search?\
+                            limit=10&\
+                            offset=1&\
+                            sort_by=name&\
+                            sort_direction=asc&\
+                            text=hub&\
+                            foundation[0]=cncf&\
+                            maturity[0]=graduated&\
+                            maturity[1]=incubating&\
+                            rating[0]=a&\
+                            rating[1]=b&\
+                            accepted_from=20200101&\
+                            accepted_to=20210101&\
+                            passing_check[0]=dco&\
+                            passing_check[1]=readme&\
+                            not_passing_check[0]=website\
+                        ",
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(response.headers()[PAGINATION_TOTAL_COUNT], "1");
+        assert!(response.headers().get(PAGINATION_NEXT_CURSOR).is_none());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"project": "info"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn search_projects_invalid_sort_by() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/projects/search?sort_by=unknown")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn search_projects_not_modified() {
+        let mut db = MockDB::new();
+        db.expect_search_projects().times(3).returning(|_| {
+            Box::pin(future::ready(Ok(SearchProjectsOutput {
+                count: 1,
+                projects: r#"[{"project": "info"}]"#.to_string(),
+                ..SearchProjectsOutput::default()
+            })))
+        });
+        let router = setup_test_router(db, MockViewsTracker::new());
+        let request = |if_none_match: Option<&str>| {
+            let mut builder = Request::builder()
+                .method("GET")
+                .uri("/api/projects/search?limit=10");
+            if let Some(if_none_match) = if_none_match {
+                builder = builder.header(IF_NONE_MATCH, if_none_match);
+            }
+            builder.body(Body::empty()).unwrap()
+        };
+
+        // First request returns the ETag of the response
+        let response = router.clone().oneshot(request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let etag = response.headers()[ETAG].to_str().unwrap().to_string();
+        assert!(etag.starts_with(r#"W/""#));
+
+        // Requests including a matching ETag get a 304 without body
+        let response = router.clone().oneshot(request(Some(&etag))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[ETAG], etag.as_str());
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert!(hyper::body::to_bytes(response.into_body())
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Requests including a different ETag get the full response
+        let response = router.oneshot(request(Some(r#"W/"other""#))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"project": "info"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn search_projects_compressed() {
+        let mut db = MockDB::new();
+        db.expect_search_projects().times(1).returning(|_| {
+            Box::pin(future::ready(Ok(SearchProjectsOutput {
+                count: 1,
+                projects: format!("[{}]", vec![r#"{"project": "info"}"#; 100].join(",")),
+                ..SearchProjectsOutput::default()
+            })))
+        });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/projects/search?limit=10")
+                    .header(ACCEPT_ENCODING, "gzip")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+    }
+
+    #[tokio::test]
+    async fn search_projects_with_cursor_and_facets() {
+        let mut db = MockDB::new();
+        db.expect_search_projects()
+            .with(eq(SearchProjectsInput {
+                limit: Some(1),
+                cursor: Some("abcd".to_string()),
+                sort_by: Some("security".to_string()),
+                sort_direction: Some("desc".to_string()),
+                failing_check: Some(vec!["signed_releases".to_string()]),
+                facets: Some(true),
+                ..SearchProjectsInput::default()
+            }))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(SearchProjectsOutput {
+                    count: 2,
+                    next_cursor: Some("ef01".to_string()),
+                    projects: r#"[{"project": "info"}]"#.to_string(),
+                    facets: r#"{"rating": {"a": 2}}"#.to_string(),
+                })))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(
+                        "\
+                        /api/projects/search?\
+                            limit=1&\
+                            cursor=abcd&\
+                            sort_by=security&\
+                            sort_direction=desc&\
+                            failing_check[0]=signed_releases&\
+                            facets=true\
+                        ",
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[PAGINATION_TOTAL_COUNT], "2");
+        assert_eq!(response.headers()[PAGINATION_NEXT_CURSOR], "ef01");
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"projects":[{"project": "info"}],"facets":{"rating": {"a": 2}}}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn search_repositories() {
+        let mut db = MockDB::new();
+        db.expect_search_repositories()
+            .with(eq("https://github.com/artifacthub/hub"))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(r#"[{"repository": "info"}]"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(
+                        "/api/repositories/search?url=https%3A%2F%2Fgithub.com%2Fartifacthub%2Fhub",
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"repository": "info"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn search_repositories_url_missing() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/repositories/search")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn suggest_projects() {
+        let mut db = MockDB::new();
+        db.expect_suggest_projects()
+            .with(eq("artifct hub"), eq(SUGGESTIONS_DEFAULT_LIMIT))
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(r#"[{"project": "info"}]"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/projects/suggest?q=%20artifct%20hub%20")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"project": "info"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn suggest_projects_invalid_input() {
+        for uri in [
+            "/api/projects/suggest",
+            "/api/projects/suggest?q=%20",
+            "/api/projects/suggest?q=hub&limit=0",
+            "/api/projects/suggest?q=hub&limit=50",
+        ] {
+            let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+                .oneshot(
+                    Request::builder()
+                        .method("GET")
+                        .uri(uri)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn static_files() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/static/lib.js")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", STATIC_CACHE_MAX_AGE)
+        );
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            fs::read_to_string(Path::new(TESTDATA_PATH).join("lib.js")).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn section_badge_found() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                let score = Score {
+                    global: 80.0,
+                    security: Some(92.0),
+                    ..Score::default()
+                };
+                Box::pin(future::ready(Ok(Some(score))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/badge/security"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let golden_path = "src/testdata/project-section-badge.golden.svg";
+        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
+        let golden = fs::read(golden_path).unwrap();
+        assert_eq!(body, golden);
+    }
+
+    #[tokio::test]
+    async fn section_badge_found_translated() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                let score = Score {
+                    global: 80.0,
+                    security: Some(92.0),
+                    ..Score::default()
+                };
+                Box::pin(future::ready(Ok(Some(score))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/badge/security?lang=es"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let svg = std::str::from_utf8(&body).unwrap();
+        assert!(svg.contains("Seguridad"));
+        assert!(!svg.contains("Security"));
+    }
+
+    #[tokio::test]
+    async fn section_badge_found_using_template_override() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| {
+                let score = Score {
+                    global: 80.0,
+                    security: Some(92.0),
+                    ..Score::default()
+                };
+                Box::pin(future::ready(Ok(Some(score))))
+            });
+        let cfg = Config::builder()
+            .add_source(setup_test_config())
+            .set_override(
+                "apiserver.templatesOverridesPath",
+                "src/testdata/templates-overrides/valid",
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let response = setup(
+            Arc::new(cfg),
+            Arc::new(db),
+            Arc::new(RwLock::new(MockViewsTracker::new())),
+            Arc::new(MemoryCache::new()),
+        )
+        .unwrap()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!(
+                    "/api/projects/{FOUNDATION}/{PROJECT}/badge/security?theme=dark"
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "image/svg+xml");
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            "<svg><text>Security (dark)</text></svg>\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn section_badge_invalid_section() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(Some(Score::default())))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/badge/invalid"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn section_badge_project_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_score()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/badge/security"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn shields_found() {
+        let mut db = MockDB::new();
+        db.expect_project_rating()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(Some("c".to_string())))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}/shields"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={}", DEFAULT_API_MAX_AGE)
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            json!({
+                "schemaVersion": 1,
+                "label": "CLOMonitor Report",
+                "message": "C",
+                "color": "orange",
+                "cacheSeconds": DEFAULT_API_MAX_AGE
+            })
+            .to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn shields_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_rating()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/projects/{FOUNDATION}/{PROJECT}/shields"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn stats() {
+        let mut db = MockDB::new();
+        db.expect_stats()
+            .withf(|v| v.as_deref() == Some(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(r#"{"some": "stats"}"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/stats?foundation={FOUNDATION}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"some": "stats"}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn stats_checks() {
+        let mut db = MockDB::new();
+        db.expect_stats_checks()
+            .withf(|v| v.as_deref() == Some(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(r#"{"some": "stats"}"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/stats/checks?foundation={FOUNDATION}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"some": "stats"}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn stats_leaderboard() {
+        let mut db = MockDB::new();
+        db.expect_stats_leaderboard()
+            .withf(|foundation, limit| foundation.as_deref() == Some(FOUNDATION) && *limit == 10)
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(r#"{"sandbox": []}"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/stats/leaderboard?foundation={FOUNDATION}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"sandbox": []}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn stats_leaderboard_invalid_limit() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/stats/leaderboard?limit=100")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn stats_most_improved() {
+        let mut db = MockDB::new();
+        db.expect_stats_most_improved()
+            .withf(|foundation, period, limit| foundation.is_none() && *period == 90 && *limit == 5)
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok("[]".to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/stats/most-improved?period=90&limit=5")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            "[]".to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn stats_most_improved_invalid_period() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/stats/most-improved?period=60")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn stats_snapshot_invalid_date_format() {
+        let db = MockDB::new();
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/stats/snapshots/20230105?foundation={FOUNDATION}"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn stats_snapshot_found() {
+        let mut db = MockDB::new();
+        db.expect_stats_snapshot()
+            .withf(|foundation, date| {
+                foundation.as_deref() == Some(FOUNDATION)
+                    && *date == Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()
+            })
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(Some(r#"{"some": "stats"}"#.to_string()))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/stats/snapshots/{DATE}?foundation={FOUNDATION}"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=86400");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"some": "stats"}"#.to_string(),
         );
     }
 
     #[tokio::test]
     async fn stats_snapshot_not_found() {
         let mut db = MockDB::new();
-        db.expect_stats_snapshot()
-            .withf(|foundation, date| {
-                foundation.as_deref().is_none()
-                    && *date == Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()
-            })
+        db.expect_stats_snapshot()
+            .withf(|foundation, date| {
+                foundation.as_deref().is_none()
+                    && *date == Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/stats/snapshots/{DATE}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn sync_delta() {
+        let mut db = MockDB::new();
+        db.expect_sync_delta()
+            .with(eq(1_654_041_600))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(r#"{"delta": "info"}"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/sync/delta?since=1654041600")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=0");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"delta": "info"}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_delta_invalid_since() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/sync/delta?since=yesterday")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn mirror_admin_routes_not_available() {
+        let cfg = Config::builder()
+            .add_source(setup_test_config())
+            .set_override("apiserver.mirror.enabled", true)
+            .unwrap()
+            .build()
+            .unwrap();
+        let router = setup(
+            Arc::new(cfg),
+            Arc::new(MockDB::new()),
+            Arc::new(RwLock::new(MockViewsTracker::new())),
+            Arc::new(MemoryCache::new()),
+        )
+        .unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/admin/roles")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Request is served by the fallback handler (index document)
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], HTML.as_ref());
+    }
+
+    #[tokio::test]
+    async fn tracker_run_progress_found() {
+        let mut db = MockDB::new();
+        db.expect_tracker_run_progress()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(Some(r#"{"total": 2}"#.to_string())))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/tracker/progress")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "max-age=60");
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"total": 2}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn tracker_run_progress_not_found() {
+        let mut db = MockDB::new();
+        db.expect_tracker_run_progress()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/tracker/progress")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn track_view() {
+        let mut vt = MockViewsTracker::new();
+        vt.expect_track_view()
+            .withf(|project_id| *project_id == Uuid::parse_str(PROJECT_ID).unwrap())
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(()))));
+
+        let response = setup_test_router(MockDB::new(), vt)
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/projects/views/{PROJECT_ID}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn repository_bundle_unauthorized() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/bundle.tar.gz"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn repository_bundle_not_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_bundle()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/bundle.tar.gz"
+                    ))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn repository_bundle_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_bundle()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| {
+                Box::pin(future::ready(Ok(Some(RepositoryBundle {
+                    foundation: FOUNDATION.to_string(),
+                    project: PROJECT.to_string(),
+                    name: REPOSITORY.to_string(),
+                    url: "https://github.com/artifacthub/hub".to_string(),
+                    ..RepositoryBundle::default()
+                }))))
+            });
+        db.expect_foundation_weights()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Some(Weights::default())))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/bundle.tar.gz"
+                    ))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], "no-store");
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/gzip");
+        assert_eq!(
+            response.headers()[CONTENT_DISPOSITION],
+            format!(r#"attachment; filename="{PROJECT}-{REPOSITORY}-bundle.tar.gz""#)
+        );
+    }
+
+    #[tokio::test]
+    async fn consistency_issues_found() {
+        let mut db = MockDB::new();
+        db.expect_consistency_issues().times(1).returning(|| {
+            Box::pin(future::ready(Ok(
+                r#"[{"kind": "digest_mismatch"}]"#.to_string()
+            )))
+        });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/admin/consistency-issues")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"kind": "digest_mismatch"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn api_keys() {
+        let mut db = MockDB::new();
+        db.expect_api_keys()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(r#"[{"name": "consumer"}]"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/admin/api-keys")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"name": "consumer"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn add_api_key_created() {
+        let mut db = MockDB::new();
+        db.expect_add_api_key()
+            .withf(|name, key_hash, rate_limit| {
+                name == "consumer" && key_hash.len() == 64 && *rate_limit == Some(100)
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok(Uuid::parse_str(API_KEY_ID).unwrap()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/api-keys")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({"name": "consumer", "rate_limit": 100}).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await.unwrap())
+                .unwrap();
+        assert_eq!(body["api_key_id"], API_KEY_ID);
+        assert!(body["key"].as_str().unwrap().starts_with("clomonitor_"));
+    }
+
+    #[tokio::test]
+    async fn add_api_key_invalid_input() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/api-keys")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({"name": " "}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn revoke_api_key_not_found() {
+        let mut db = MockDB::new();
+        db.expect_revoke_api_key()
+            .withf(|id| *id == Uuid::parse_str(API_KEY_ID).unwrap())
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(false))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/api/admin/api-keys/{API_KEY_ID}"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_anonymous_exceeded() {
+        let mut db = MockDB::new();
+        db.expect_stats()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(r#"{"some": "stats"}"#.to_string()))));
+        let router = setup_test_router_with_rate_limit(db);
+
+        let request = || {
+            Request::builder()
+                .method("GET")
+                .uri("/api/stats")
+                .header("x-forwarded-for", "10.0.0.1")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = router.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-ratelimit-limit"], "1");
+        assert_eq!(response.headers()["x-ratelimit-remaining"], "0");
+
+        let response = router.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key(RETRY_AFTER));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_anonymous_spoofed_forwarded_for_ignored() {
+        let mut db = MockDB::new();
+        db.expect_stats()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(r#"{"some": "stats"}"#.to_string()))));
+        let router = setup_test_router_with_rate_limit(db);
+
+        let request = |spoofed_addr: &str| {
+            Request::builder()
+                .method("GET")
+                .uri("/api/stats")
+                .header("x-forwarded-for", format!("{spoofed_addr}, 10.0.0.1"))
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = router.clone().oneshot(request("1.1.1.1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router.oneshot(request("2.2.2.2")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_api_key() {
+        let mut db = MockDB::new();
+        db.expect_api_key()
+            .with(eq(hash_api_key("valid-key")))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(ApiKey {
+                    api_key_id: Uuid::parse_str(API_KEY_ID).unwrap(),
+                    name: "consumer".to_string(),
+                    rate_limit: Some(100),
+                }))))
+            });
+        db.expect_stats()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(r#"{"some": "stats"}"#.to_string()))));
+
+        let response = setup_test_router_with_rate_limit(db)
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/stats")
+                    .header(API_KEY_HEADER, "valid-key")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-ratelimit-limit"], "100");
+    }
+
+    #[tokio::test]
+    async fn rate_limit_invalid_api_key() {
+        let mut db = MockDB::new();
+        db.expect_api_key()
+            .with(eq(hash_api_key("invalid-key")))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router_with_rate_limit(db)
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/stats")
+                    .header(API_KEY_HEADER, "invalid-key")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_invalid_api_keys_charged_to_client_address() {
+        let mut db = MockDB::new();
+        db.expect_api_key()
+            .times(2)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+        let router = setup_test_router_with_rate_limit(db);
+
+        let request = |key: &str| {
+            Request::builder()
+                .method("GET")
+                .uri("/api/stats")
+                .header("x-forwarded-for", "10.0.0.1")
+                .header(API_KEY_HEADER, key)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = router
+            .clone()
+            .oneshot(request("invalid-key1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = router.oneshot(request("invalid-key2")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn claim_project_succeeded() {
+        let server = setup_test_github_server(true).await;
+        let mut db = MockDB::new();
+        db.expect_subject_roles()
+            .with(eq(GITHUB_SUBJECT))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
+        db.expect_project_data()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(Some(
+                    json!({"repositories": [{"url": "https://github.com/artifacthub/hub"}]})
+                        .to_string(),
+                ))))
+            });
+        db.expect_add_role_assignment()
+            .with(eq(RoleAssignment {
+                subject: GITHUB_SUBJECT.to_string(),
+                role: Role::ProjectMaintainer {
+                    foundation: FOUNDATION.to_string(),
+                    project: PROJECT.to_string(),
+                },
+            }))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(
+                    Uuid::parse_str(ROLE_ASSIGNMENT_ID).unwrap(),
+                ))))
+            });
+
+        let response = setup_test_router_with_github(db, &server.uri())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/projects/{FOUNDATION}/{PROJECT}/claim"))
+                    .header(AUTHORIZATION, format!("Bearer {GITHUB_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            json!({ "role_assignment_id": ROLE_ASSIGNMENT_ID }).to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_project_without_write_access() {
+        let server = setup_test_github_server(false).await;
+        let mut db = MockDB::new();
+        db.expect_subject_roles()
+            .with(eq(GITHUB_SUBJECT))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
+        db.expect_project_data()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(Some(
+                    json!({"repositories": [{"url": "https://github.com/artifacthub/hub"}]})
+                        .to_string(),
+                ))))
+            });
+
+        let response = setup_test_router_with_github(db, &server.uri())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/projects/{FOUNDATION}/{PROJECT}/claim"))
+                    .header(AUTHORIZATION, format!("Bearer {GITHUB_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn claim_project_not_github_user() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/projects/{FOUNDATION}/{PROJECT}/claim"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn role_assignments_unauthorized() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/admin/roles")
+                    .header(AUTHORIZATION, "Bearer invalid-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn role_assignments_found() {
+        let mut db = MockDB::new();
+        db.expect_role_assignments()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(r#"[{"subject": "user"}]"#.to_string()))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/admin/roles")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            r#"[{"subject": "user"}]"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn add_role_assignment_created() {
+        let mut db = MockDB::new();
+        db.expect_add_role_assignment()
+            .with(eq(RoleAssignment {
+                subject: "user".to_string(),
+                role: Role::ProjectMaintainer {
+                    foundation: FOUNDATION.to_string(),
+                    project: PROJECT.to_string(),
+                },
+            }))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(Some(
+                    Uuid::parse_str(ROLE_ASSIGNMENT_ID).unwrap(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/roles")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "subject": "user",
+                            "role": "project_maintainer",
+                            "foundation": FOUNDATION,
+                            "project": PROJECT,
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            json!({ "role_assignment_id": ROLE_ASSIGNMENT_ID }).to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn add_role_assignment_scope_not_found() {
+        let mut db = MockDB::new();
+        db.expect_add_role_assignment()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/roles")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "subject": "user",
+                            "role": "foundation_staff",
+                            "foundation": "not-found",
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn add_role_assignment_invalid_role() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/roles")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "subject": "user",
+                            "role": "foundation_staff",
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn delete_role_assignment_not_found() {
+        let mut db = MockDB::new();
+        db.expect_delete_role_assignment()
+            .withf(|id| *id == Uuid::parse_str(ROLE_ASSIGNMENT_ID).unwrap())
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(false))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/api/admin/roles/{ROLE_ASSIGNMENT_ID}"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn register_foundation_invalid_input() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/foundations")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "foundation_id": "Invalid ID",
+                            "display_name": "Foundation",
+                            "data_url": "https://example.com/data.yaml"
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn register_foundation_invalid_data_file() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/data.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("invalid data file"))
+            .mount(&server)
+            .await;
+
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/foundations")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "foundation_id": "foundation",
+                            "display_name": "Foundation",
+                            "data_url": format!("{}/data.yaml", server.uri())
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn register_foundation_registered() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/data.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("- name: project\n"))
+            .mount(&server)
+            .await;
+        let data_url = format!("{}/data.yaml", server.uri());
+
+        let mut db = MockDB::new();
+        let expected_data_url = data_url.clone();
+        db.expect_register_foundation()
+            .withf(move |input| {
+                input.foundation_id == "foundation"
+                    && input.foundation.data_url == expected_data_url
+                    && input.foundation.logo_url.as_deref() == Some("https://example.com/logo.svg")
+            })
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(true))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/foundations")
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "foundation_id": "foundation",
+                            "display_name": "Foundation",
+                            "data_url": data_url,
+                            "logo_url": "https://example.com/logo.svg"
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn update_foundation_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1.0.0/data.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("- name: project\n"))
+            .mount(&server)
+            .await;
+
+        let mut db = MockDB::new();
+        db.expect_update_foundation()
+            .withf(|foundation, input| {
+                foundation == FOUNDATION && input.data_ref.as_deref() == Some("v1.0.0")
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(false))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "display_name": "Foundation",
+                            "data_url": format!("{}/{{ref}}/data.yaml", server.uri()),
+                            "ref": "v1.0.0"
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn delete_foundation_with_projects() {
+        let mut db = MockDB::new();
+        db.expect_delete_foundation()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn delete_foundation_deleted() {
+        let mut db = MockDB::new();
+        db.expect_delete_foundation()
+            .with(eq(FOUNDATION))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Some(true)))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn update_foundation_data_ref_unauthorized() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/data-ref"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({"ref": "v1.0.0"}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn update_foundation_data_ref_invalid_ref() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/data-ref"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({"ref": " "}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn update_foundation_data_ref_foundation_not_found() {
+        let mut db = MockDB::new();
+        db.expect_update_foundation_data_ref()
+            .withf(|foundation, data_ref| foundation == FOUNDATION && *data_ref == Some("v1.0.0"))
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(false))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/data-ref"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({"ref": "v1.0.0"}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn update_foundation_data_ref_updated() {
+        let mut db = MockDB::new();
+        db.expect_update_foundation_data_ref()
+            .withf(|foundation, data_ref| foundation == FOUNDATION && *data_ref == Some("v1.0.0"))
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(true))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/data-ref"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({"ref": "v1.0.0"}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn request_foundation_registration_unauthorized() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/register"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn request_foundation_registration_foundation_not_found() {
+        let mut db = MockDB::new();
+        db.expect_request_foundation_registration()
+            .with(eq(FOUNDATION))
             .times(1)
-            .returning(|_, _| Box::pin(future::ready(Ok(None))));
+            .returning(|_| Box::pin(future::ready(Ok(false))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
-                    .method("GET")
-                    .uri(format!("/api/stats/snapshots/{DATE}"))
+                    .method("POST")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/register"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -918,18 +4139,63 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn track_view() {
-        let mut vt = MockViewsTracker::new();
-        vt.expect_track_view()
-            .withf(|project_id| *project_id == Uuid::parse_str(PROJECT_ID).unwrap())
+    async fn request_foundation_registration_requested() {
+        let mut db = MockDB::new();
+        db.expect_request_foundation_registration()
+            .with(eq(FOUNDATION))
             .times(1)
-            .returning(|_| Box::pin(future::ready(Ok(()))));
+            .returning(|_| Box::pin(future::ready(Ok(true))));
 
-        let response = setup_test_router(MockDB::new(), vt)
+        let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri(format!("/api/projects/views/{PROJECT_ID}"))
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/register"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn acknowledge_exemption_unknown_check() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/exemptions/unknown"
+                    ))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn acknowledge_exemption_acknowledged() {
+        let mut db = MockDB::new();
+        db.expect_acknowledge_exemption()
+            .with(eq(FOUNDATION), eq(PROJECT), eq("sbom"), eq("admin"))
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(future::ready(Ok(true))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/exemptions/sbom"
+                    ))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -939,9 +4205,367 @@ mod tests {
         assert_eq!(response.status(), StatusCode::NO_CONTENT);
     }
 
+    #[tokio::test]
+    async fn add_project_webhook_invalid_input() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/webhooks"
+                    ))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({"url": "http://example.com/hook", "events": ["rating_drop"]})
+                            .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn add_project_webhook_added() {
+        let project_webhook_id = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
+        let mut db = MockDB::new();
+        db.expect_add_project_webhook()
+            .withf(|foundation, project, created_by, input| {
+                foundation == FOUNDATION
+                    && project == PROJECT
+                    && created_by == "admin"
+                    && *input
+                        == ProjectWebhookInput {
+                            url: "https://hooks.slack.com/services/test".to_string(),
+                            events: vec![WebhookEvent::RatingDrop, WebhookEvent::ScoreDrop],
+                        }
+            })
+            .times(1)
+            .returning(move |_, _, _, _| Box::pin(future::ready(Ok(Some(project_webhook_id)))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/webhooks"
+                    ))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        json!({
+                            "url": "https://hooks.slack.com/services/test",
+                            "events": ["rating_drop", "score_drop"]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            json!({ "project_webhook_id": project_webhook_id }).to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_project_webhook_not_found() {
+        let project_webhook_id = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
+        let mut db = MockDB::new();
+        db.expect_delete_project_webhook()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(project_webhook_id))
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok(false))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!(
+                        "/api/admin/projects/{FOUNDATION}/{PROJECT}/webhooks/{project_webhook_id}"
+                    ))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn track_project_unauthorized() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/projects/{FOUNDATION}/{PROJECT}/track"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn track_project_not_found() {
+        let mut db = MockDB::new();
+        db.expect_request_projects_tracking()
+            .withf(|foundation, project_names| {
+                foundation == FOUNDATION && *project_names == Some(&[PROJECT.to_string()][..])
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(vec![]))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/projects/{FOUNDATION}/{PROJECT}/track"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn track_project_requested() {
+        let mut db = MockDB::new();
+        db.expect_request_projects_tracking()
+            .withf(|foundation, project_names| {
+                foundation == FOUNDATION && *project_names == Some(&[PROJECT.to_string()][..])
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(vec![PROJECT.to_string()]))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/projects/{FOUNDATION}/{PROJECT}/track"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn track_projects_unauthorized() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/track"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn track_projects_invalid_input() {
+        let response = setup_test_router(MockDB::new(), MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/track"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({"projects": []}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn track_projects_all_requested() {
+        let mut db = MockDB::new();
+        db.expect_request_projects_tracking()
+            .withf(|foundation, project_names| foundation == FOUNDATION && project_names.is_none())
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(vec![
+                    "project1".to_string(),
+                    "project2".to_string(),
+                ])))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/track"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert_eq!(
+            hyper::body::to_bytes(response.into_body()).await.unwrap(),
+            json!({"projects": ["project1", "project2"]}).to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn track_projects_not_found() {
+        let mut db = MockDB::new();
+        db.expect_request_projects_tracking()
+            .withf(|foundation, project_names| {
+                foundation == FOUNDATION && *project_names == Some(&["unknown".to_string()][..])
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(vec![]))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/foundations/{FOUNDATION}/track"))
+                    .header(AUTHORIZATION, format!("Bearer {ADMIN_TOKEN}"))
+                    .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
+                    .body(Body::from(json!({"projects": ["unknown"]}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    fn sample_maturity_gates_report() -> MaturityGatesReport {
+        MaturityGatesReport {
+            foundation: "cncf".to_string(),
+            foundation_display_name: "CNCF".to_string(),
+            projects: vec![
+                MaturityGatesReportProject {
+                    name: "artifact-hub".to_string(),
+                    display_name: "Artifact Hub".to_string(),
+                    maturity: "incubating".to_string(),
+                    gates: vec![
+                        MaturityGateResult {
+                            maturity: "incubating".to_string(),
+                            name: "Security policy".to_string(),
+                            checks: vec!["security_policy".to_string()],
+                            passed: true,
+                            checks_not_passed: vec![],
+                        },
+                        MaturityGateResult {
+                            maturity: "graduated".to_string(),
+                            name: "OpenSSF best practices".to_string(),
+                            checks: vec![
+                                "openssf_badge".to_string(),
+                                "security_insights".to_string(),
+                            ],
+                            passed: false,
+                            checks_not_passed: vec![
+                                "openssf_badge".to_string(),
+                                "security_insights".to_string(),
+                            ],
+                        },
+                    ],
+                },
+                MaturityGatesReportProject {
+                    name: "falco".to_string(),
+                    display_name: "Falco".to_string(),
+                    maturity: "graduated".to_string(),
+                    gates: vec![],
+                },
+            ],
+        }
+    }
+
     fn setup_test_router(db: MockDB, vt: MockViewsTracker) -> Router {
         let cfg = setup_test_config();
-        setup(Arc::new(cfg), Arc::new(db), Arc::new(RwLock::new(vt))).unwrap()
+        setup(
+            Arc::new(cfg),
+            Arc::new(db),
+            Arc::new(RwLock::new(vt)),
+            Arc::new(MemoryCache::new()),
+        )
+        .unwrap()
+    }
+
+    fn setup_test_router_with_rate_limit(db: MockDB) -> Router {
+        let cfg = Config::builder()
+            .add_source(setup_test_config())
+            .set_override("apiserver.rateLimit.enabled", true)
+            .unwrap()
+            .set_override("apiserver.rateLimit.anonymous", 1)
+            .unwrap()
+            .set_override("apiserver.rateLimit.trustedProxies", 1)
+            .unwrap()
+            .build()
+            .unwrap();
+        setup(
+            Arc::new(cfg),
+            Arc::new(db),
+            Arc::new(RwLock::new(MockViewsTracker::new())),
+            Arc::new(MemoryCache::new()),
+        )
+        .unwrap()
+    }
+
+    fn setup_test_router_with_github(db: MockDB, github_api_url: &str) -> Router {
+        let cfg = Config::builder()
+            .add_source(setup_test_config())
+            .set_override("apiserver.githubAuth.enabled", true)
+            .unwrap()
+            .set_override("apiserver.githubAuth.apiUrl", github_api_url)
+            .unwrap()
+            .build()
+            .unwrap();
+        setup(
+            Arc::new(cfg),
+            Arc::new(db),
+            Arc::new(RwLock::new(MockViewsTracker::new())),
+            Arc::new(MemoryCache::new()),
+        )
+        .unwrap()
+    }
+
+    async fn setup_test_github_server(push: bool) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"login": "tegioz"})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/artifacthub/hub"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"permissions": {"push": push}})),
+            )
+            .mount(&server)
+            .await;
+        server
     }
 
     fn setup_test_config() -> Config {
@@ -952,6 +4576,8 @@ mod tests {
             .unwrap()
             .set_default("apiserver.basicAuth.enabled", false)
             .unwrap()
+            .set_default("apiserver.admin.token", ADMIN_TOKEN)
+            .unwrap()
             .build()
             .unwrap()
     }
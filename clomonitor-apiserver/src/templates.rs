@@ -0,0 +1,287 @@
+use crate::handlers::{
+    FoundationReport, FoundationReportHTMLTemplate, FoundationReportMDTemplate,
+    FoundationReportProject, MaturityGateResult, MaturityGatesReport,
+    MaturityGatesReportMDTemplate, MaturityGatesReportProject, RatingChangeTemplate,
+    ReportSummaryTemplate, RepositoryReportMDTemplate, SectionBadgeTemplate,
+};
+use crate::i18n::Lang;
+use anyhow::{format_err, Context as _, Result};
+use askama_axum::Template;
+use clomonitor_core::{
+    linter::{CheckSet, Report},
+    score::Score,
+};
+use serde::Serialize;
+use std::{fs, path::Path};
+use tera::{Context, Tera};
+
+/// Names of the templates that can be overridden.
+const OVERRIDABLE: [&str; 7] = [
+    FoundationReportHTMLTemplate::NAME,
+    FoundationReportMDTemplate::NAME,
+    MaturityGatesReportMDTemplate::NAME,
+    RatingChangeTemplate::NAME,
+    ReportSummaryTemplate::NAME,
+    RepositoryReportMDTemplate::NAME,
+    SectionBadgeTemplate::NAME,
+];
+
+/// Trait implemented by the templates that can be overridden.
+pub(crate) trait Overridable: Template + Serialize {
+    /// Name of the template's file.
+    const NAME: &'static str;
+
+    /// Sample instance used to validate the template's overrides.
+    fn sample() -> Self;
+}
+
+/// Overrides of the templates used to render some assets (report summary,
+/// badges, reports...). When a template has not been overridden, the one
+/// built into the apiserver is used.
+#[derive(Default)]
+pub(crate) struct Overrides {
+    tmpl: Tera,
+}
+
+impl Overrides {
+    /// Load the templates overrides available in the directory provided.
+    /// Overrides are validated rendering them using a sample context, so that
+    /// any problems are detected on startup.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let mut tmpl = Tera::default();
+        tmpl.autoescape_on(vec![".html", ".svg"]);
+        let entries = fs::read_dir(path).context("error reading templates overrides directory")?;
+        for entry in entries {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if name.starts_with('.') || !path.is_file() {
+                continue;
+            }
+            if !OVERRIDABLE.contains(&name) {
+                return Err(format_err!("template {name} cannot be overridden"));
+            }
+            tmpl.add_template_file(&path, Some(name))
+                .context(format!("error loading template {name}"))?;
+        }
+
+        let overrides = Self { tmpl };
+        overrides.validate::<FoundationReportHTMLTemplate>()?;
+        overrides.validate::<FoundationReportMDTemplate>()?;
+        overrides.validate::<MaturityGatesReportMDTemplate>()?;
+        overrides.validate::<RatingChangeTemplate>()?;
+        overrides.validate::<ReportSummaryTemplate>()?;
+        overrides.validate::<RepositoryReportMDTemplate>()?;
+        overrides.validate::<SectionBadgeTemplate>()?;
+        Ok(overrides)
+    }
+
+    /// Render the template provided, using its override when available.
+    pub(crate) fn render<T: Overridable>(&self, template: &T) -> Result<String> {
+        if !self.is_overridden(T::NAME) {
+            return Ok(template.render()?);
+        }
+        let ctx = Context::from_serialize(template)?;
+        Ok(self.tmpl.render(T::NAME, &ctx)?)
+    }
+
+    /// Check if the template provided has been overridden.
+    fn is_overridden(&self, name: &str) -> bool {
+        self.tmpl.get_template_names().any(|n| n == name)
+    }
+
+    /// Check that the template's override (if any) renders successfully with
+    /// the template's sample context.
+    fn validate<T: Overridable>(&self) -> Result<()> {
+        if self.is_overridden(T::NAME) {
+            self.render(&T::sample())
+                .context(format!("invalid template override {}", T::NAME))?;
+        }
+        Ok(())
+    }
+}
+
+/// Sample foundation report used to validate the reports overrides.
+fn sample_foundation_report() -> FoundationReport {
+    let project = FoundationReportProject {
+        name: "project".to_string(),
+        display_name: "Project".to_string(),
+    };
+    FoundationReport {
+        foundation: "foundation".to_string(),
+        foundation_display_name: "Foundation".to_string(),
+        from: "2022-01-01".to_string(),
+        to: "2022-12-31".to_string(),
+        new_projects: vec![project.clone()],
+        removed_projects: vec![project],
+        ..FoundationReport::default()
+    }
+}
+
+/// Sample score used to validate the overrides of the templates using it.
+fn sample_score() -> Score {
+    Score {
+        global: 80.0,
+        global_weight: 95,
+        documentation: Some(80.0),
+        documentation_weight: Some(30),
+        license: Some(80.0),
+        license_weight: Some(20),
+        best_practices: Some(80.0),
+        best_practices_weight: Some(20),
+        security: Some(80.0),
+        security_weight: Some(20),
+        legal: Some(80.0),
+        legal_weight: Some(5),
+        artifacts: None,
+        artifacts_weight: None,
+        organization: None,
+        organization_weight: None,
+    }
+}
+
+impl Overridable for FoundationReportHTMLTemplate {
+    const NAME: &'static str = "foundation-report.html";
+
+    fn sample() -> Self {
+        Self {
+            report: sample_foundation_report(),
+        }
+    }
+}
+
+impl Overridable for FoundationReportMDTemplate {
+    const NAME: &'static str = "foundation-report.md";
+
+    fn sample() -> Self {
+        Self {
+            report: sample_foundation_report(),
+        }
+    }
+}
+
+impl Overridable for MaturityGatesReportMDTemplate {
+    const NAME: &'static str = "maturity-gates-report.md";
+
+    fn sample() -> Self {
+        Self {
+            report: MaturityGatesReport {
+                foundation: "foundation".to_string(),
+                foundation_display_name: "Foundation".to_string(),
+                projects: vec![MaturityGatesReportProject {
+                    name: "project".to_string(),
+                    display_name: "Project".to_string(),
+                    maturity: "incubating".to_string(),
+                    gates: vec![MaturityGateResult {
+                        maturity: "graduated".to_string(),
+                        name: "Gate".to_string(),
+                        checks: vec!["adopters".to_string()],
+                        passed: false,
+                        checks_not_passed: vec!["adopters".to_string()],
+                    }],
+                }],
+            },
+        }
+    }
+}
+
+impl Overridable for RatingChangeTemplate {
+    const NAME: &'static str = "rating-change.svg";
+
+    fn sample() -> Self {
+        Self {
+            name: "project".to_string(),
+            logo: None,
+            previous_rating: "b".to_string(),
+            rating: "a".to_string(),
+        }
+    }
+}
+
+impl Overridable for ReportSummaryTemplate {
+    const NAME: &'static str = "report-summary.svg";
+
+    fn sample() -> Self {
+        Self {
+            score: sample_score(),
+            theme: "light".to_string(),
+            accent: Some("#2a0552".to_string()),
+            msg: Lang::default().messages(),
+        }
+    }
+}
+
+impl Overridable for RepositoryReportMDTemplate {
+    const NAME: &'static str = "repository-report.md";
+
+    fn sample() -> Self {
+        Self {
+            name: "repository".to_string(),
+            url: "https://github.com/org/repo".to_string(),
+            check_sets: vec![CheckSet::Code],
+            score: Some(sample_score()),
+            report: Some(Report::default()),
+            failed_checks: vec![],
+        }
+    }
+}
+
+impl Overridable for SectionBadgeTemplate {
+    const NAME: &'static str = "section-badge.svg";
+
+    fn sample() -> Self {
+        Self {
+            section: "Security".to_string(),
+            score: Some(80.0),
+            theme: "light".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA_PATH: &str = "src/testdata/templates-overrides";
+
+    #[test]
+    fn load_valid_overrides() {
+        let overrides = Overrides::load(&Path::new(TESTDATA_PATH).join("valid")).unwrap();
+        assert!(overrides.is_overridden(SectionBadgeTemplate::NAME));
+        assert!(!overrides.is_overridden(ReportSummaryTemplate::NAME));
+    }
+
+    #[test]
+    fn load_unknown_template() {
+        let result = Overrides::load(&Path::new(TESTDATA_PATH).join("unknown"));
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "template unknown.svg cannot be overridden"
+        );
+    }
+
+    #[test]
+    fn load_template_not_matching_context() {
+        let result = Overrides::load(&Path::new(TESTDATA_PATH).join("invalid-context"));
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "invalid template override section-badge.svg"
+        );
+    }
+
+    #[test]
+    fn render_uses_override_when_available() {
+        let overrides = Overrides::load(&Path::new(TESTDATA_PATH).join("valid")).unwrap();
+        let badge = SectionBadgeTemplate::sample();
+        assert_eq!(
+            overrides.render(&badge).unwrap().trim(),
+            "<svg><text>Security (light)</text></svg>"
+        );
+        let summary = ReportSummaryTemplate::sample();
+        assert_eq!(
+            overrides.render(&summary).unwrap(),
+            summary.render().unwrap()
+        );
+    }
+}
@@ -1,34 +1,150 @@
-use crate::db::DynDB;
-use anyhow::{Context, Result};
-use time::{ext::NumericalDuration, Date, OffsetDateTime};
-use tracing::{debug, info, instrument};
+use crate::{
+    dataset,
+    db::DynDB,
+    storage::{DynObjectStorage, JSON_CONTENT_TYPE},
+};
+use anyhow::{format_err, Context, Result};
+use config::{Config, ConfigError};
+use serde_json::Value;
+use time::{ext::NumericalDuration, Date, Duration, OffsetDateTime};
+use tracing::{debug, error, info, instrument};
 use uuid::Uuid;
 
+/// Value that can be used to keep monthly snapshots forever.
+const FOREVER: &str = "forever";
+
+/// Snapshots retention policy. Snapshots are kept daily for some days, then
+/// weekly for some months and monthly after that. Once the monthly retention
+/// period is over, only the latest snapshot of each year is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RetentionPolicy {
+    /// Number of days daily snapshots are kept for.
+    pub daily_days: u32,
+    /// Number of months weekly snapshots are kept for.
+    pub weekly_months: u32,
+    /// Number of months monthly snapshots are kept for (forever when None).
+    pub monthly_months: Option<u32>,
+    /// Number of days the projects' daily views are kept for before they are
+    /// rolled up into monthly totals.
+    pub views_daily_days: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            daily_days: 2,
+            weekly_months: 1,
+            monthly_months: Some(24),
+            views_daily_days: 90,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Create a new retention policy from the configuration provided, using
+    /// the default values for the settings not provided.
+    pub(crate) fn from_config(cfg: &Config) -> Result<Self> {
+        let default = Self::default();
+        let get_u32 = |key: &str, default: u32| -> Result<u32> {
+            match cfg.get::<u32>(key) {
+                Ok(value) => Ok(value),
+                Err(ConfigError::NotFound(_)) => Ok(default),
+                Err(err) => Err(format_err!("invalid retention setting ({key}): {err}")),
+            }
+        };
+        let monthly_months = match cfg.get_string("archiver.retention.monthlyMonths") {
+            Ok(value) if value == FOREVER => None,
+            Ok(value) => Some(value.parse().map_err(|err| {
+                format_err!("invalid retention setting (archiver.retention.monthlyMonths): {err}")
+            })?),
+            Err(ConfigError::NotFound(_)) => default.monthly_months,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self {
+            daily_days: get_u32("archiver.retention.dailyDays", default.daily_days)?,
+            weekly_months: get_u32("archiver.retention.weeklyMonths", default.weekly_months)?,
+            monthly_months,
+            views_daily_days: get_u32(
+                "archiver.retention.viewsDailyDays",
+                default.views_daily_days,
+            )?,
+        })
+    }
+
+    /// Period daily snapshots are kept for.
+    fn daily_period(&self) -> Duration {
+        i64::from(self.daily_days).days()
+    }
+
+    /// Period weekly snapshots are kept for.
+    fn weekly_period(&self) -> Duration {
+        months_to_days(self.weekly_months).days()
+    }
+
+    /// Period monthly snapshots are kept for (forever when None).
+    fn monthly_period(&self) -> Option<Duration> {
+        self.monthly_months
+            .map(|months| months_to_days(months).days())
+    }
+}
+
+/// Convert the number of months provided to days.
+fn months_to_days(months: u32) -> i64 {
+    i64::from(months) * 365 / 12
+}
+
 /// Process projects and stats, generating snapshots when needed and removing
-/// the ones that are no longer needed.
+/// the ones that are no longer needed. The daily public dataset is published
+/// as well when a dataset storage has been provided.
 #[instrument(skip_all, err)]
-pub(crate) async fn run(db: DynDB) -> Result<()> {
+pub(crate) async fn run(
+    db: DynDB,
+    retention: &RetentionPolicy,
+    storage: Option<DynObjectStorage>,
+    dataset_storage: Option<DynObjectStorage>,
+) -> Result<()> {
     info!("started");
 
     debug!("processing projects");
     for project_id in db.projects_ids().await?.iter() {
-        process_project(db.clone(), project_id).await?;
+        process_project(db.clone(), project_id, retention, storage.clone()).await?;
     }
 
     debug!("processing stats");
     for foundation in db.foundations().await?.iter() {
-        process_stats(db.clone(), Some(foundation)).await?;
+        process_stats(db.clone(), Some(foundation), retention).await?;
+    }
+    process_stats(db.clone(), None, retention).await?; // All foundations
+
+    // Publish dataset (errors are logged but don't prevent the archiver from
+    // completing the remaining tasks)
+    let today = OffsetDateTime::now_utc().date();
+    if let Some(dataset_storage) = &dataset_storage {
+        debug!("publishing dataset");
+        if let Err(err) = dataset::publish(db.clone(), dataset_storage, today).await {
+            error!("error publishing dataset [{}]: {:#}", today, err);
+        }
     }
-    process_stats(db.clone(), None).await?; // All foundations
+
+    debug!("rolling up projects views");
+    db.rollup_projects_views(&(today - i64::from(retention.views_daily_days).days()))
+        .await
+        .context("error rolling up projects views")?;
 
     info!("finished");
     Ok(())
 }
 
 /// Process project provided, generating a snapshot for the current day when
-/// needed and cleaning up the ones no longer needed.
+/// needed and cleaning up the ones no longer needed. New snapshots are also
+/// exported to the object storage provided (if any).
 #[instrument(fields(project_id = project_id.to_string()), skip_all, err)]
-async fn process_project(db: DynDB, project_id: &Uuid) -> Result<()> {
+async fn process_project(
+    db: DynDB,
+    project_id: &Uuid,
+    retention: &RetentionPolicy,
+    storage: Option<DynObjectStorage>,
+) -> Result<()> {
     // Get project's snapshots
     let snapshots = db
         .project_snapshots(project_id)
@@ -44,6 +160,14 @@ async fn process_project(db: DynDB, project_id: &Uuid) -> Result<()> {
             .await
             .context("error getting project data")?;
         if let Some(data) = data {
+            // Export snapshot to the object storage when configured (errors
+            // are logged but don't prevent the snapshot from being stored)
+            if let Some(storage) = &storage {
+                if let Err(err) = export_snapshot(storage, &data, today).await {
+                    error!("error exporting snapshot [{}]: {:#}", today, err);
+                }
+            }
+
             db.store_project_snapshot(project_id, data)
                 .await
                 .context("error storing snapshot")?;
@@ -52,7 +176,7 @@ async fn process_project(db: DynDB, project_id: &Uuid) -> Result<()> {
     }
 
     // Delete snapshots no longer needed
-    let snapshots_to_keep = get_snapshots_to_keep(today, snapshots.as_slice());
+    let snapshots_to_keep = get_snapshots_to_keep(today, snapshots.as_slice(), retention);
     for snapshot in snapshots.iter() {
         if !snapshots_to_keep.contains(snapshot) {
             db.delete_project_snapshot(project_id, snapshot)
@@ -65,10 +189,33 @@ async fn process_project(db: DynDB, project_id: &Uuid) -> Result<()> {
     Ok(())
 }
 
+/// Export the project's snapshot provided to the object storage.
+async fn export_snapshot(storage: &DynObjectStorage, data: &Value, date: Date) -> Result<()> {
+    let key = snapshot_key(data, date)
+        .ok_or_else(|| format_err!("project's foundation or name not found in snapshot"))?;
+    storage
+        .put_object(&key, &serde_json::to_vec(data)?, JSON_CONTENT_TYPE)
+        .await?;
+    debug!("snapshot [{}] exported to {}", date, key);
+    Ok(())
+}
+
+/// Return the key used to export the project's snapshot provided. Snapshots
+/// are partitioned by foundation and project: `foundation/project/date.json`.
+fn snapshot_key(data: &Value, date: Date) -> Option<String> {
+    let foundation = data.get("foundation")?.as_str()?;
+    let project = data.get("name")?.as_str()?;
+    Some(format!("{foundation}/{project}/{date}.json"))
+}
+
 /// Process stats, generating a snapshot for the current day when needed and
 /// cleaning up the ones no longer needed.
 #[instrument(fields(foundation = foundation.unwrap_or_default()), skip_all, err)]
-async fn process_stats(db: DynDB, foundation: Option<&str>) -> Result<()> {
+async fn process_stats(
+    db: DynDB,
+    foundation: Option<&str>,
+    retention: &RetentionPolicy,
+) -> Result<()> {
     // Get stats's snapshots
     let snapshots = db
         .stats_snapshots(foundation)
@@ -92,7 +239,7 @@ async fn process_stats(db: DynDB, foundation: Option<&str>) -> Result<()> {
     }
 
     // Delete snapshots no longer needed
-    let snapshots_to_keep = get_snapshots_to_keep(today, snapshots.as_slice());
+    let snapshots_to_keep = get_snapshots_to_keep(today, snapshots.as_slice(), retention);
     for snapshot in snapshots.iter() {
         if !snapshots_to_keep.contains(snapshot) {
             db.delete_stats_snapshot(foundation, snapshot)
@@ -105,31 +252,42 @@ async fn process_stats(db: DynDB, foundation: Option<&str>) -> Result<()> {
     Ok(())
 }
 
-/// Return a list of snapshots that we'd like to keep.
-fn get_snapshots_to_keep(ref_date: Date, snapshots: &[Date]) -> Vec<Date> {
+/// Return a list of snapshots that we'd like to keep, based on the retention
+/// policy provided.
+fn get_snapshots_to_keep(
+    ref_date: Date,
+    snapshots: &[Date],
+    retention: &RetentionPolicy,
+) -> Vec<Date> {
     let mut snapshots_to_keep = Vec::new();
 
     for snapshot in snapshots.iter().copied() {
-        // Include snapshots for the previous 2 days
-        if ref_date - snapshot <= 2.days() {
+        // Include snapshots for the daily retention period
+        if ref_date - snapshot <= retention.daily_period() {
             snapshots_to_keep.push(snapshot);
             continue;
         }
 
-        // Include latest snapshot for each week in the last month
-        if ref_date - snapshot <= 30.days()
+        // Include latest snapshot for each week in the weekly retention period
+        if ref_date - snapshot <= retention.weekly_period()
             && snapshots_to_keep.last().map_or(true, |last_snapshot_kept| {
-                last_snapshot_kept.iso_week() > snapshot.iso_week()
+                let (last_year, last_week, _) = last_snapshot_kept.to_iso_week_date();
+                let (year, week, _) = snapshot.to_iso_week_date();
+                (last_year, last_week) > (year, week)
             })
         {
             snapshots_to_keep.push(snapshot);
             continue;
         }
 
-        // Include latest snapshot for each month in the last 2 years
-        if ref_date - snapshot <= (2 * 365).days()
+        // Include latest snapshot for each month in the monthly retention
+        // period
+        if retention
+            .monthly_period()
+            .map_or(true, |period| ref_date - snapshot <= period)
             && snapshots_to_keep.last().map_or(true, |last_snapshot_kept| {
-                last_snapshot_kept.month() as u8 > snapshot.month() as u8
+                (last_snapshot_kept.year(), last_snapshot_kept.month() as u8)
+                    > (snapshot.year(), snapshot.month() as u8)
             })
         {
             snapshots_to_keep.push(snapshot);
@@ -161,7 +319,8 @@ mod tests {
                     date!(2022 - 10 - 28),
                     date!(2022 - 10 - 27),
                     date!(2022 - 10 - 26),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![
                 date!(2022 - 10 - 28),
@@ -180,7 +339,8 @@ mod tests {
                     date!(2022 - 10 - 28),
                     date!(2022 - 10 - 25),
                     date!(2022 - 10 - 24),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![date!(2022 - 10 - 28)]
         );
@@ -195,7 +355,8 @@ mod tests {
                     date!(2022 - 10 - 24),
                     date!(2022 - 10 - 20),
                     date!(2022 - 10 - 19),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![date!(2022 - 10 - 24), date!(2022 - 10 - 20),]
         );
@@ -213,7 +374,8 @@ mod tests {
                     date!(2022 - 10 - 19),
                     date!(2022 - 10 - 13),
                     date!(2022 - 10 - 10),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![
                 date!(2022 - 10 - 25),
@@ -233,7 +395,8 @@ mod tests {
                     date!(2022 - 10 - 25),
                     date!(2022 - 10 - 13),
                     date!(2022 - 10 - 10),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![date!(2022 - 10 - 25), date!(2022 - 10 - 13),]
         );
@@ -250,7 +413,8 @@ mod tests {
                     date!(2022 - 9 - 29),
                     date!(2022 - 9 - 11),
                     date!(2022 - 8 - 1),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![
                 date!(2022 - 10 - 18),
@@ -271,7 +435,8 @@ mod tests {
                     date!(2021 - 9 - 29),
                     date!(2020 - 9 - 11),
                     date!(2020 - 8 - 1),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![
                 date!(2022 - 10 - 18),
@@ -301,7 +466,8 @@ mod tests {
                     date!(2021 - 9 - 29),
                     date!(2020 - 9 - 11),
                     date!(2020 - 8 - 23),
-                ]
+                ],
+                &RetentionPolicy::default(),
             ),
             vec![
                 date!(2022 - 10 - 25),
@@ -316,4 +482,84 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn get_snapshots_to_keep_custom_retention_policy() {
+        let retention = RetentionPolicy {
+            daily_days: 7,
+            weekly_months: 0,
+            monthly_months: None,
+            views_daily_days: 90,
+        };
+        assert_eq!(
+            get_snapshots_to_keep(
+                date!(2022 - 10 - 25),
+                &[
+                    date!(2022 - 10 - 25),
+                    date!(2022 - 10 - 20),
+                    date!(2022 - 10 - 18),
+                    date!(2022 - 10 - 17),
+                    date!(2019 - 12 - 5),
+                    date!(2019 - 11 - 5),
+                    date!(2019 - 11 - 1),
+                ],
+                &retention,
+            ),
+            vec![
+                date!(2022 - 10 - 25),
+                date!(2022 - 10 - 20),
+                date!(2022 - 10 - 18),
+                date!(2019 - 12 - 5),
+                date!(2019 - 11 - 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn retention_policy_from_config() {
+        let cfg = Config::builder()
+            .set_default("archiver.retention.dailyDays", 7)
+            .unwrap()
+            .set_default("archiver.retention.monthlyMonths", "forever")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            RetentionPolicy::from_config(&cfg).unwrap(),
+            RetentionPolicy {
+                daily_days: 7,
+                weekly_months: 1,
+                monthly_months: None,
+                views_daily_days: 90,
+            }
+        );
+    }
+
+    #[test]
+    fn retention_policy_from_config_invalid_monthly_months() {
+        let cfg = Config::builder()
+            .set_default("archiver.retention.monthlyMonths", "invalid")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(RetentionPolicy::from_config(&cfg)
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid retention setting (archiver.retention.monthlyMonths)"));
+    }
+
+    #[test]
+    fn snapshot_key_partitioned_by_foundation_and_project() {
+        let data = serde_json::json!({"foundation": "cncf", "name": "artifact-hub"});
+        assert_eq!(
+            snapshot_key(&data, date!(2022 - 10 - 28)),
+            Some("cncf/artifact-hub/2022-10-28.json".to_string())
+        );
+    }
+
+    #[test]
+    fn snapshot_key_missing_project_name() {
+        let data = serde_json::json!({"foundation": "cncf"});
+        assert_eq!(snapshot_key(&data, date!(2022 - 10 - 28)), None);
+    }
 }
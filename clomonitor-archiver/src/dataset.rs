@@ -0,0 +1,365 @@
+use crate::{
+    db::DynDB,
+    storage::{DynObjectStorage, JSON_CONTENT_TYPE},
+};
+use anyhow::{format_err, Context, Result};
+use clomonitor_core::linter::{CheckStatus, Report};
+use parquet::{
+    basic::Compression,
+    data_type::{ByteArray, ByteArrayType, DataType, DoubleType},
+    file::{
+        properties::WriterProperties,
+        writer::{SerializedFileWriter, SerializedRowGroupWriter},
+    },
+    schema::parser::parse_message_type,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::BTreeMap, io::Write, sync::Arc};
+use time::Date;
+use tracing::{debug, instrument};
+
+/// Version of the dataset format. It is part of the datasets' keys, and must
+/// be bumped when backwards incompatible changes are made to the files.
+pub(crate) const DATASET_VERSION: &str = "v1";
+
+/// Name of the file containing the projects in JSON format.
+const PROJECTS_FILE: &str = "projects.json";
+
+/// Name of the file containing the checks results in Parquet format.
+const CHECKS_FILE: &str = "checks.parquet";
+
+/// Content type of the Parquet objects.
+const PARQUET_CONTENT_TYPE: &str = "application/vnd.apache.parquet";
+
+/// Schema of the checks results Parquet file (one row per repository check).
+const CHECKS_SCHEMA: &str = "
+    message check {
+        required binary date (UTF8);
+        required binary foundation (UTF8);
+        required binary project (UTF8);
+        optional binary project_rating (UTF8);
+        optional double project_score;
+        required binary repository (UTF8);
+        required binary repository_url (UTF8);
+        optional double repository_score;
+        required binary check_id (UTF8);
+        required binary status (UTF8);
+    }
+";
+
+/// Project's data as returned by the database (only the fields included in
+/// the dataset are deserialized).
+#[derive(Debug, Deserialize)]
+struct ProjectData {
+    foundation: String,
+    name: String,
+    display_name: Option<String>,
+    maturity: Option<String>,
+    rating: Option<String>,
+    score: Option<Value>,
+    #[serde(default)]
+    repositories: Vec<RepositoryData>,
+}
+
+/// Repository's data as returned by the database.
+#[derive(Debug, Deserialize)]
+struct RepositoryData {
+    name: String,
+    url: String,
+    score: Option<Value>,
+    report: Option<ReportData>,
+}
+
+/// Repository's report as returned by the database.
+#[derive(Debug, Deserialize)]
+struct ReportData {
+    data: Option<Report>,
+}
+
+/// Project entry in the dataset.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct DatasetProject {
+    pub foundation: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maturity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<Value>,
+    pub repositories: Vec<DatasetRepository>,
+}
+
+/// Repository entry in the dataset, including the status of each of the
+/// checks in its latest report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct DatasetRepository {
+    pub name: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<Value>,
+    pub checks: BTreeMap<String, CheckStatus>,
+}
+
+impl DatasetProject {
+    /// Create a new dataset project entry from the project's data provided.
+    fn from_data(data: Value) -> Result<Self> {
+        let data: ProjectData = serde_json::from_value(data)?;
+        let repositories = data
+            .repositories
+            .into_iter()
+            .map(|r| {
+                let checks = r
+                    .report
+                    .and_then(|report| report.data)
+                    .map(|report| {
+                        report
+                            .checks()
+                            .into_iter()
+                            .map(|(check_id, status, _)| (check_id.to_string(), status))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                DatasetRepository {
+                    name: r.name,
+                    url: r.url,
+                    score: r.score,
+                    checks,
+                }
+            })
+            .collect();
+        Ok(Self {
+            foundation: data.foundation,
+            name: data.name,
+            display_name: data.display_name,
+            maturity: data.maturity,
+            rating: data.rating,
+            score: data.score,
+            repositories,
+        })
+    }
+}
+
+/// Publish the dataset of the date provided to the object storage, unless it
+/// has already been published. The dataset includes all projects, along with
+/// their scores and checks results, in JSON and Parquet formats.
+#[instrument(fields(date = date.to_string()), skip_all, err)]
+pub(crate) async fn publish(db: DynDB, storage: &DynObjectStorage, date: Date) -> Result<()> {
+    if db.datasets().await?.contains(&date) {
+        return Ok(());
+    }
+
+    // Collect projects
+    let mut projects = Vec::new();
+    for project_id in db.projects_ids().await? {
+        let Some(data) = db
+            .project_data(&project_id)
+            .await
+            .context("error getting project data")?
+        else {
+            continue;
+        };
+        let project = DatasetProject::from_data(data)
+            .context(format!("invalid project data [{project_id}]"))?;
+        projects.push(project);
+    }
+    projects.sort_by(|a, b| (&a.foundation, &a.name).cmp(&(&b.foundation, &b.name)));
+
+    // Write dataset files and register the dataset once they are available
+    storage
+        .put_object(
+            &dataset_key(date, PROJECTS_FILE),
+            &serde_json::to_vec(&projects)?,
+            JSON_CONTENT_TYPE,
+        )
+        .await
+        .context("error writing projects file")?;
+    storage
+        .put_object(
+            &dataset_key(date, CHECKS_FILE),
+            &checks_parquet(date, &projects)?,
+            PARQUET_CONTENT_TYPE,
+        )
+        .await
+        .context("error writing checks file")?;
+    db.store_dataset(&date)
+        .await
+        .context("error storing dataset")?;
+    debug!("dataset published ({} projects)", projects.len());
+
+    Ok(())
+}
+
+/// Return the key of the dataset file provided: `version/date/file`.
+fn dataset_key(date: Date, file: &str) -> String {
+    format!("{DATASET_VERSION}/{date}/{file}")
+}
+
+/// Build the checks results Parquet file of the projects provided.
+fn checks_parquet(date: Date, projects: &[DatasetProject]) -> Result<Vec<u8>> {
+    // Flatten projects into one row per repository check
+    let rows: Vec<(&DatasetProject, &DatasetRepository, &str, CheckStatus)> = projects
+        .iter()
+        .flat_map(|p| {
+            p.repositories.iter().flat_map(move |r| {
+                r.checks
+                    .iter()
+                    .map(move |(check_id, status)| (p, r, check_id.as_str(), *status))
+            })
+        })
+        .collect();
+    let global = |score: &Option<Value>| score.as_ref()?.get("global")?.as_f64();
+    let date = date.to_string();
+
+    // Write columns (in the same order they are defined in the schema)
+    let schema = Arc::new(parse_message_type(CHECKS_SCHEMA)?);
+    let props = Arc::new(
+        WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build(),
+    );
+    let mut writer = SerializedFileWriter::new(Vec::new(), schema, props)?;
+    let mut rg = writer.next_row_group()?;
+    write_strings(&mut rg, rows.iter().map(|_| date.as_str()))?;
+    write_strings(&mut rg, rows.iter().map(|(p, ..)| p.foundation.as_str()))?;
+    write_strings(&mut rg, rows.iter().map(|(p, ..)| p.name.as_str()))?;
+    write_column::<ByteArrayType, _>(
+        &mut rg,
+        rows.iter()
+            .map(|(p, ..)| p.rating.as_deref().map(ByteArray::from)),
+        true,
+    )?;
+    write_column::<DoubleType, _>(&mut rg, rows.iter().map(|(p, ..)| global(&p.score)), true)?;
+    write_strings(&mut rg, rows.iter().map(|(_, r, ..)| r.name.as_str()))?;
+    write_strings(&mut rg, rows.iter().map(|(_, r, ..)| r.url.as_str()))?;
+    write_column::<DoubleType, _>(
+        &mut rg,
+        rows.iter().map(|(_, r, ..)| global(&r.score)),
+        true,
+    )?;
+    write_strings(&mut rg, rows.iter().map(|(_, _, check_id, _)| *check_id))?;
+    let statuses: Vec<String> = rows
+        .iter()
+        .map(|(.., status)| {
+            serde_json::to_value(status)
+                .ok()
+                .and_then(|v| v.as_str().map(ToString::to_string))
+                .unwrap_or_default()
+        })
+        .collect();
+    write_strings(&mut rg, statuses.iter().map(String::as_str))?;
+    rg.close()?;
+
+    Ok(writer.into_inner()?)
+}
+
+/// Write the required strings column provided to the row group.
+fn write_strings<'a, W: Write + Send>(
+    rg: &mut SerializedRowGroupWriter<'_, W>,
+    values: impl Iterator<Item = &'a str>,
+) -> Result<()> {
+    write_column::<ByteArrayType, _>(rg, values.map(|v| Some(ByteArray::from(v))), false)
+}
+
+/// Write the next column of the row group, using the values provided. Empty
+/// values are only allowed in optional columns.
+fn write_column<T: DataType, W: Write + Send>(
+    rg: &mut SerializedRowGroupWriter<'_, W>,
+    values: impl Iterator<Item = Option<T::T>>,
+    optional: bool,
+) -> Result<()> {
+    let mut def_levels = Vec::new();
+    let mut present = Vec::new();
+    for value in values {
+        def_levels.push(i16::from(value.is_some()));
+        present.extend(value);
+    }
+    if !optional && present.len() != def_levels.len() {
+        return Err(format_err!("empty value found in required column"));
+    }
+    let mut column = rg
+        .next_column()?
+        .ok_or_else(|| format_err!("column not found in schema"))?;
+    column
+        .typed::<T>()
+        .write_batch(&present, optional.then_some(&def_levels[..]), None)?;
+    column.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{CheckOutput, Documentation, Security};
+    use serde_json::json;
+    use time::macros::date;
+
+    fn project_data() -> Value {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed()),
+                ..Documentation::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::exempt()),
+                ..Security::default()
+            },
+            ..Report::default()
+        };
+        json!({
+            "foundation": "cncf",
+            "name": "artifact-hub",
+            "maturity": "incubating",
+            "rating": "a",
+            "score": {"global": 80.0, "global_weight": 95},
+            "repositories": [
+                {
+                    "name": "hub",
+                    "url": "https://github.com/artifacthub/hub",
+                    "score": {"global": 80.0},
+                    "report": {"data": report}
+                },
+                {
+                    "name": "docs",
+                    "url": "https://github.com/artifacthub/docs"
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn dataset_project_from_data() {
+        let project = DatasetProject::from_data(project_data()).unwrap();
+        assert_eq!(project.foundation, "cncf");
+        assert_eq!(project.rating, Some("a".to_string()));
+        assert_eq!(
+            project.repositories[0].checks,
+            BTreeMap::from([
+                ("adopters".to_string(), CheckStatus::Passed),
+                ("readme".to_string(), CheckStatus::NotPassed),
+                ("sbom".to_string(), CheckStatus::Exempt),
+            ])
+        );
+        assert!(project.repositories[1].checks.is_empty());
+    }
+
+    #[test]
+    fn dataset_key_versioned_and_partitioned_by_date() {
+        assert_eq!(
+            dataset_key(date!(2022 - 10 - 28), PROJECTS_FILE),
+            "v1/2022-10-28/projects.json"
+        );
+    }
+
+    #[test]
+    fn checks_parquet_written() {
+        let project = DatasetProject::from_data(project_data()).unwrap();
+        let data = checks_parquet(date!(2022 - 10 - 28), &[project]).unwrap();
+        assert_eq!(&data[..4], b"PAR1");
+        assert_eq!(&data[data.len() - 4..], b"PAR1");
+    }
+}
@@ -12,6 +12,9 @@ pub(crate) type DynDB = Arc<dyn DB + Send + Sync>;
 /// Trait that defines some operations a DB implementation must support.
 #[async_trait]
 pub(crate) trait DB {
+    /// Get the dates of all the datasets published.
+    async fn datasets(&self) -> Result<Vec<Date>>;
+
     /// Delete the provided project's snapshot.
     async fn delete_project_snapshot(&self, project_id: &Uuid, date: &Date) -> Result<()>;
 
@@ -30,12 +33,19 @@ pub(crate) trait DB {
     /// Get the ids of all projects registered in the database.
     async fn projects_ids(&self) -> Result<Vec<Uuid>>;
 
+    /// Roll up the projects' daily views before the date provided into
+    /// monthly totals.
+    async fn rollup_projects_views(&self, before: &Date) -> Result<()>;
+
     /// Get stats' data.
     async fn stats_data(&self, foundation: Option<&str>) -> Result<Option<Value>>;
 
     /// Get the dates of all the stats' snapshots.
     async fn stats_snapshots(&self, foundation: Option<&str>) -> Result<Vec<Date>>;
 
+    /// Register the dataset of the date provided as published.
+    async fn store_dataset(&self, date: &Date) -> Result<()>;
+
     /// Store the provided project's snapshot.
     async fn store_project_snapshot(&self, project_id: &Uuid, data: Value) -> Result<()>;
 
@@ -57,6 +67,17 @@ impl PgDB {
 
 #[async_trait]
 impl DB for PgDB {
+    async fn datasets(&self) -> Result<Vec<Date>> {
+        let db = self.pool.get().await?;
+        let datasets = db
+            .query("select date from dataset order by date desc", &[])
+            .await?
+            .iter()
+            .map(|row| row.get("date"))
+            .collect();
+        Ok(datasets)
+    }
+
     async fn delete_project_snapshot(&self, project_id: &Uuid, date: &Date) -> Result<()> {
         let db = self.pool.get().await?;
         db.execute(
@@ -133,6 +154,13 @@ impl DB for PgDB {
         Ok(projects)
     }
 
+    async fn rollup_projects_views(&self, before: &Date) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute("select rollup_projects_views($1::date)", &[&before])
+            .await?;
+        Ok(())
+    }
+
     async fn stats_data(&self, foundation: Option<&str>) -> Result<Option<Value>> {
         let db = self.pool.get().await?;
         let data: Option<Value> = db
@@ -161,6 +189,16 @@ impl DB for PgDB {
         Ok(snapshots)
     }
 
+    async fn store_dataset(&self, date: &Date) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "insert into dataset (date) values ($1::date) on conflict do nothing",
+            &[&date],
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn store_project_snapshot(&self, project_id: &Uuid, data: Value) -> Result<()> {
         let db = self.pool.get().await?;
         db.execute(
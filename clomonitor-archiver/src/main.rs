@@ -1,16 +1,23 @@
-use crate::db::PgDB;
-use anyhow::{Context, Result};
+use crate::{
+    archiver::RetentionPolicy,
+    db::PgDB,
+    storage::{DynObjectStorage, S3ObjectStorage},
+};
+use anyhow::{format_err, Context, Result};
 use clap::Parser;
 use config::{Config, File};
 use deadpool_postgres::{Config as DbConfig, Runtime};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
 use std::{path::PathBuf, sync::Arc};
-use tracing::debug;
+use tracing::{debug, info_span, Instrument};
 use tracing_subscriber::EnvFilter;
+use uuid::Uuid;
 
 mod archiver;
+mod dataset;
 mod db;
+mod storage;
 
 #[derive(Debug, Parser)]
 #[clap(author, version, about)]
@@ -37,9 +44,15 @@ async fn main() -> Result<()> {
     let s = tracing_subscriber::fmt().with_env_filter(EnvFilter::from_default_env());
     match cfg.get_string("log.format").as_deref() {
         Ok("json") => s.json().init(),
-        _ => s.init(),
+        Ok("pretty") | Err(_) => s.init(),
+        Ok(format) => return Err(format_err!("invalid log format: {format} (log.format)")),
     };
 
+    // All the events logged by this run are recorded within a span identified
+    // by its id, so that they can be correlated
+    let run_id = Uuid::new_v4();
+    let run_span = info_span!("run", %run_id);
+
     // Setup database
     debug!("setting up database");
     let mut builder = SslConnector::builder(SslMethod::tls())?;
@@ -49,8 +62,32 @@ async fn main() -> Result<()> {
     let pool = db_cfg.create_pool(Some(Runtime::Tokio1), connector)?;
     let db = Arc::new(PgDB::new(pool));
 
+    // Setup object storage (snapshots are only exported when a bucket has
+    // been configured)
+    let storage: Option<DynObjectStorage> = match cfg.get_string("archiver.export.bucket") {
+        Ok(_) => {
+            debug!("setting up object storage");
+            Some(Arc::new(S3ObjectStorage::new(&cfg, "archiver.export")?))
+        }
+        Err(_) => None,
+    };
+
+    // Setup dataset object storage (the public dataset is only published when
+    // a bucket has been configured)
+    let dataset_storage: Option<DynObjectStorage> = match cfg.get_string("archiver.dataset.bucket")
+    {
+        Ok(_) => {
+            debug!("setting up dataset object storage");
+            Some(Arc::new(S3ObjectStorage::new(&cfg, "archiver.dataset")?))
+        }
+        Err(_) => None,
+    };
+
     // Run archiver
-    archiver::run(db).await?;
+    let retention = RetentionPolicy::from_config(&cfg)?;
+    archiver::run(db, &retention, storage, dataset_storage)
+        .instrument(run_span)
+        .await?;
 
     Ok(())
 }
@@ -0,0 +1,81 @@
+use anyhow::{format_err, Result};
+use async_trait::async_trait;
+use config::Config;
+use s3::{creds::Credentials, Bucket, Region};
+use std::sync::Arc;
+
+/// Content type of the JSON objects.
+pub(crate) const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Type alias to represent an ObjectStorage trait object.
+pub(crate) type DynObjectStorage = Arc<dyn ObjectStorage + Send + Sync>;
+
+/// Trait that defines some operations an ObjectStorage implementation must
+/// support.
+#[async_trait]
+pub(crate) trait ObjectStorage {
+    /// Write the object provided to the key given.
+    async fn put_object(&self, key: &str, data: &[u8], content_type: &str) -> Result<()>;
+}
+
+/// ObjectStorage implementation backed by a bucket accessible using the S3
+/// API (AWS S3, GCS using HMAC keys, MinIO...).
+pub(crate) struct S3ObjectStorage {
+    bucket: Bucket,
+    prefix: Option<String>,
+}
+
+impl S3ObjectStorage {
+    /// Create a new S3ObjectStorage instance, using the settings in the
+    /// configuration section provided (i.e. `archiver.export`).
+    pub(crate) fn new(cfg: &Config, section: &str) -> Result<Self> {
+        let key = |name: &str| format!("{section}.{name}");
+        let region = match cfg.get_string(&key("endpoint")) {
+            Ok(endpoint) => Region::Custom {
+                region: cfg
+                    .get_string(&key("region"))
+                    .unwrap_or_else(|_| "us-east-1".to_string()),
+                endpoint,
+            },
+            Err(_) => cfg.get_string(&key("region"))?.parse()?,
+        };
+        let credentials = Credentials::new(
+            Some(&cfg.get_string(&key("accessKeyId"))?),
+            Some(&cfg.get_string(&key("secretAccessKey"))?),
+            None,
+            None,
+            None,
+        )?;
+        let mut bucket = Bucket::new(&cfg.get_string(&key("bucket"))?, region, credentials)?;
+        if cfg.get_bool(&key("pathStyle")).unwrap_or(false) {
+            bucket = bucket.with_path_style();
+        }
+        let prefix = cfg
+            .get_string(&key("prefix"))
+            .ok()
+            .map(|prefix| prefix.trim_matches('/').to_string())
+            .filter(|prefix| !prefix.is_empty());
+        Ok(Self { bucket, prefix })
+    }
+}
+
+#[async_trait]
+impl ObjectStorage for S3ObjectStorage {
+    async fn put_object(&self, key: &str, data: &[u8], content_type: &str) -> Result<()> {
+        let key = match &self.prefix {
+            Some(prefix) => format!("{prefix}/{key}"),
+            None => key.to_string(),
+        };
+        let resp = self
+            .bucket
+            .put_object_with_content_type(&key, data, content_type)
+            .await?;
+        if resp.status_code() != 200 {
+            return Err(format_err!(
+                "unexpected status code writing object {key}: {}",
+                resp.status_code()
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+#[cfg(feature = "mocks")]
+use mockall::automock;
+use redis::aio::ConnectionManager;
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Prefix used in all keys stored in Redis.
+const REDIS_KEY_PREFIX: &str = "clomonitor:";
+
+/// Type alias to represent a Cache trait object.
+pub type DynCache = Arc<dyn Cache + Send + Sync>;
+
+/// Trait that defines some operations a Cache implementation must support.
+#[async_trait]
+#[cfg_attr(feature = "mocks", automock)]
+pub trait Cache {
+    /// Get the value stored for the key provided, if any.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Store the value provided for the given key for the duration of the ttl.
+    async fn set(&self, key: &str, value: &[u8], ttl: Duration) -> Result<()>;
+}
+
+impl fmt::Debug for dyn Cache + Send + Sync {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Cache")
+    }
+}
+
+/// Setup a cache backed by Redis when a url is provided. Otherwise an in
+/// memory cache, local to this instance, will be used.
+pub async fn setup(redis_url: Option<&str>) -> Result<DynCache> {
+    let cache: DynCache = match redis_url {
+        Some(url) if !url.is_empty() => Arc::new(RedisCache::new(url).await?),
+        _ => Arc::new(MemoryCache::new()),
+    };
+    Ok(cache)
+}
+
+/// Get the value for the key provided from the cache. When it is not found,
+/// it will be obtained from the future provided and stored in the cache.
+/// Errors interacting with the cache are logged, but they do not prevent the
+/// value from being returned.
+pub async fn get_or_insert_with<F>(
+    cache: Option<&DynCache>,
+    key: &str,
+    ttl: Duration,
+    f: F,
+) -> Result<Vec<u8>>
+where
+    F: Future<Output = Result<Vec<u8>>>,
+{
+    let Some(cache) = cache else {
+        return f.await;
+    };
+    match cache.get(key).await {
+        Ok(Some(value)) => return Ok(value),
+        Ok(None) => {}
+        Err(err) => warn!(key, "error getting value from cache: {err:#}"),
+    }
+    let value = f.await?;
+    if let Err(err) = cache.set(key, &value, ttl).await {
+        warn!(key, "error storing value in cache: {err:#}");
+    }
+    Ok(value)
+}
+
+/// Cache implementation that keeps entries in memory.
+#[derive(Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, (Vec<u8>, Instant)>>,
+}
+
+impl MemoryCache {
+    /// Create a new MemoryCache instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Cache for MemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let mut entries = self.entries.lock().expect("lock not to be poisoned");
+        match entries.get(key) {
+            Some((value, expires_at)) if *expires_at > Instant::now() => Ok(Some(value.clone())),
+            Some(_) => {
+                entries.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: &[u8], ttl: Duration) -> Result<()> {
+        let mut entries = self.entries.lock().expect("lock not to be poisoned");
+        let now = Instant::now();
+        entries.retain(|_, (_, expires_at)| *expires_at > now);
+        entries.insert(key.to_string(), (value.to_vec(), now + ttl));
+        Ok(())
+    }
+}
+
+/// Cache implementation backed by Redis, allowing multiple instances to
+/// share the entries stored.
+pub struct RedisCache {
+    conn: ConnectionManager,
+}
+
+impl RedisCache {
+    /// Create a new RedisCache instance.
+    pub async fn new(url: &str) -> Result<Self> {
+        let client = redis::Client::open(url).context("invalid redis url")?;
+        let conn = ConnectionManager::new(client)
+            .await
+            .context("error connecting to redis")?;
+        Ok(Self { conn })
+    }
+}
+
+#[async_trait]
+impl Cache for RedisCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let value = redis::cmd("GET")
+            .arg(format!("{REDIS_KEY_PREFIX}{key}"))
+            .query_async(&mut self.conn.clone())
+            .await?;
+        Ok(value)
+    }
+
+    async fn set(&self, key: &str, value: &[u8], ttl: Duration) -> Result<()> {
+        redis::cmd("SET")
+            .arg(format!("{REDIS_KEY_PREFIX}{key}"))
+            .arg(value)
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async::<_, ()>(&mut self.conn.clone())
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::format_err;
+
+    const KEY: &str = "key";
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[tokio::test]
+    async fn memory_cache_get_not_found() {
+        let cache = MemoryCache::new();
+        assert_eq!(cache.get(KEY).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn memory_cache_set_and_get() {
+        let cache = MemoryCache::new();
+        cache.set(KEY, b"value", TTL).await.unwrap();
+        assert_eq!(cache.get(KEY).await.unwrap(), Some(b"value".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn memory_cache_entry_expired() {
+        let cache = MemoryCache::new();
+        cache.set(KEY, b"value", Duration::ZERO).await.unwrap();
+        assert_eq!(cache.get(KEY).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_with_no_cache() {
+        let value = get_or_insert_with(None, KEY, TTL, async { Ok(b"value".to_vec()) })
+            .await
+            .unwrap();
+        assert_eq!(value, b"value".to_vec());
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_with_value_cached() {
+        let cache: DynCache = Arc::new(MemoryCache::new());
+        cache.set(KEY, b"cached", TTL).await.unwrap();
+
+        let value = get_or_insert_with(Some(&cache), KEY, TTL, async {
+            Err(format_err!("should not be called"))
+        })
+        .await
+        .unwrap();
+        assert_eq!(value, b"cached".to_vec());
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_with_value_not_cached() {
+        let cache: DynCache = Arc::new(MemoryCache::new());
+
+        let value = get_or_insert_with(Some(&cache), KEY, TTL, async { Ok(b"value".to_vec()) })
+            .await
+            .unwrap();
+        assert_eq!(value, b"value".to_vec());
+        assert_eq!(cache.get(KEY).await.unwrap(), Some(b"value".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_with_error_not_cached() {
+        let cache: DynCache = Arc::new(MemoryCache::new());
+
+        let result =
+            get_or_insert_with(Some(&cache), KEY, TTL, async { Err(format_err!("error")) }).await;
+        assert!(result.is_err());
+        assert_eq!(cache.get(KEY).await.unwrap(), None);
+    }
+}
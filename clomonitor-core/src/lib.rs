@@ -1,2 +1,63 @@
+//! CLOMonitor core library.
+//!
+//! This crate provides the checks CLOMonitor runs on repositories, as well as
+//! the logic used to score their results. It does not depend on the database
+//! or any of the other CLOMonitor components, so it can be embedded in other
+//! tools (i.e. bots or CI integrations) to lint repositories programmatically.
+//!
+//! The [`lint`] function is the simplest way to get started: it runs the
+//! built-in checks on a repository already available locally and returns its
+//! report along with the corresponding score.
+//!
+//! ```no_run
+//! use clomonitor_core::{lint, CheckSet, LinterInput, Provider};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let options = LinterInput {
+//!     root: "/path/to/artifacthub/hub".into(),
+//!     url: "https://github.com/artifacthub/hub".to_string(),
+//!     check_sets: vec![CheckSet::Code, CheckSet::Community],
+//!     github_token: std::env::var("GITHUB_TOKEN")?,
+//!     provider: Provider::Github,
+//!     ..LinterInput::default()
+//! };
+//! let output = lint(&options).await?;
+//! println!("score: {} ({})", output.score.global, output.score.rating());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! For more control over how repositories are linted (i.e. to use a cache or
+//! register some external checks), a [`linter::CoreLinter`] instance can be
+//! used instead, scoring its reports with [`score::calculate`].
+//!
+//! The `cli` and `postgres` features (enabled by default) add support for
+//! using some of the types as command line arguments and database params.
+//! They can be disabled when they are not needed.
+
+use anyhow::Result;
+use linter::{CoreLinter, Linter};
+use serde::{Deserialize, Serialize};
+
+pub mod cache;
 pub mod linter;
+pub mod net;
 pub mod score;
+
+pub use linter::{CheckSet, LinterInput, Provider, Report};
+pub use score::Score;
+
+/// Report of a repository along with its score, as returned by [`lint`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintOutput {
+    pub report: Report,
+    pub score: Score,
+}
+
+/// Lint the repository provided using the built-in checks, returning its
+/// report and score.
+pub async fn lint(options: &LinterInput) -> Result<LintOutput> {
+    let report = CoreLinter::new().lint(options).await?;
+    let score = score::calculate(&report);
+    Ok(LintOutput { report, score })
+}
@@ -2,27 +2,87 @@ use super::{
     checks::{
         signed_releases,
         util::{
-            github,
+            gitea, github,
             scorecard::{Scorecard, ScorecardCheck},
         },
         CHECKS,
     },
     metadata::{Exemption, Metadata, METADATA_FILE},
     util::scorecard::scorecard,
-    CheckSet, LinterInput,
+    CheckSet, LinterInput, Provider, ProviderSupport, Severity,
 };
+use crate::{cache::DynCache, net::RestrictedClient};
 use anyhow::{format_err, Context, Error, Result};
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 use which::which;
 
 /// Type alias to represent a check identifier.
 pub type CheckId = &'static str;
 
+/// Credit awarded to checks that pass (percentage of the check's weight).
+pub(crate) const FULL_CREDIT: usize = 100;
+
+/// Maximum time an async check can take to run. Checks that don't complete
+/// in time are marked as failed, so that a slow remote service doesn't stall
+/// the whole repository's lint.
+pub(crate) const CHECK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Guidance on how to make a check pass. It is defined alongside each check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Remediation {
+    /// Steps to follow to make the check pass.
+    pub steps: &'static [&'static str],
+
+    /// Example of the file expected by the check (if any).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<RemediationTemplate>,
+
+    /// Url of the check's documentation.
+    pub docs_url: &'static str,
+}
+
+/// Example file that can be used as a starting point to make a check pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RemediationTemplate {
+    pub path: &'static str,
+    pub content: &'static str,
+}
+
 /// Check configuration.
 pub(crate) struct CheckConfig {
     pub weight: usize,
+    pub severity: Severity,
     pub check_sets: Vec<CheckSet>,
     pub scorecard_name: Option<String>,
+    pub github_scopes: Vec<&'static str>,
+    pub provider_support: ProviderSupport,
+    pub gitea_support: ProviderSupport,
+    pub remediation: Remediation,
+
+    /// Checks that must pass for this check to make sense. When any of them
+    /// does not pass, this check is not run and is reported as not applicable.
+    pub depends_on: Vec<CheckId>,
+}
+
+impl CheckConfig {
+    /// Return the support level of the check on the provider given. Checks
+    /// are fully supported on GitHub, whereas on other providers the ones
+    /// relying on the GitHub API or the OpenSSF Scorecard are limited. Some
+    /// of them can use the Gitea API instead on repositories hosted on Gitea.
+    pub(crate) fn support(&self, provider: Provider) -> ProviderSupport {
+        match provider {
+            Provider::Github => ProviderSupport::Full,
+            Provider::Gitea => self.gitea_support,
+            Provider::Gitlab => self.provider_support,
+        }
+    }
 }
 
 /// Input used by checks to perform their operations.
@@ -32,10 +92,55 @@ pub(crate) struct CheckInput<'a> {
     pub cm_md: Option<Metadata>,
     pub gh_md: github::md::MdRepository,
     pub scorecard: Result<Scorecard>,
+    pub cache: Option<DynCache>,
+    pub restricted_client: RestrictedClient,
+
+    /// Status of the checks other checks depend on, recorded once they have
+    /// been run.
+    pub prerequisites: BTreeMap<CheckId, CheckStatus>,
 }
 
 impl<'a> CheckInput<'a> {
-    pub(crate) async fn new(li: &LinterInput) -> Result<CheckInput> {
+    pub(crate) async fn new(
+        li: &LinterInput,
+        cache: Option<DynCache>,
+        restricted_client: RestrictedClient,
+    ) -> Result<CheckInput> {
+        // Get CLOMonitor metadata
+        let cm_md = Metadata::from(li.root.join(METADATA_FILE))?;
+
+        // The GitHub metadata and the OpenSSF scorecard are only available
+        // for repositories hosted on GitHub. The metadata of repositories
+        // hosted on Gitea is obtained from the Gitea API instead.
+        if li.provider != Provider::Github {
+            let mut gh_md = match li.provider {
+                Provider::Gitea => gitea::metadata(&li.url)
+                    .await
+                    .context("error getting gitea metadata")?,
+                _ => github::md::MdRepository {
+                    default_branch_ref: None,
+                    ..Default::default()
+                },
+            };
+            if gh_md.default_branch_ref.is_none() {
+                gh_md.default_branch_ref = local_default_branch(&li.root)
+                    .map(|name| github::md::MdRepositoryDefaultBranchRef { name });
+            }
+            let scorecard = Err(format_err!(
+                "scorecard not available for repositories hosted on {}",
+                li.provider
+            ));
+            return Ok(CheckInput {
+                li,
+                cm_md,
+                gh_md,
+                scorecard,
+                cache,
+                restricted_client,
+                prerequisites: BTreeMap::new(),
+            });
+        }
+
         // Check if required external tools are available
         if which("scorecard").is_err() {
             return Err(format_err!(
@@ -43,9 +148,6 @@ impl<'a> CheckInput<'a> {
             ));
         }
 
-        // Get CLOMonitor metadata
-        let cm_md = Metadata::from(li.root.join(METADATA_FILE))?;
-
         // The next both actions (get GitHub metadata and get scorecard) make use
         // of the GitHub token, which when used concurrently, may trigger some
         // GitHub secondary rate limits. So they should not be run concurrently.
@@ -64,9 +166,59 @@ impl<'a> CheckInput<'a> {
             cm_md,
             gh_md,
             scorecard,
+            cache,
+            restricted_client,
+            prerequisites: BTreeMap::new(),
         };
         Ok(ci)
     }
+
+    /// Record the status of the check provided, so that the checks depending
+    /// on it can be skipped when it does not pass.
+    pub(crate) fn record_prerequisite<T>(
+        &mut self,
+        check_id: CheckId,
+        output: Option<&CheckOutput<T>>,
+    ) {
+        if let Some(output) = output {
+            self.prerequisites.insert(check_id, output.status());
+        }
+    }
+
+    /// Return the checks the check provided depends on that did not pass.
+    /// Dependencies that were not run are not taken into account.
+    pub(crate) fn unmet_dependencies(&self, check_id: &str) -> Vec<CheckId> {
+        CHECKS.get(check_id).map_or(vec![], |check| {
+            check
+                .depends_on
+                .iter()
+                .filter(|id| self.prerequisites.get(*id) == Some(&CheckStatus::NotPassed))
+                .copied()
+                .collect()
+        })
+    }
+}
+
+/// Return the name of the branch checked out in the local copy of the
+/// repository provided, if any.
+fn local_default_branch(root: &Path) -> Option<String> {
+    fs::read_to_string(root.join(".git").join("HEAD"))
+        .ok()?
+        .trim()
+        .strip_prefix("ref: refs/heads/")
+        .map(ToString::to_string)
+}
+
+/// Status of a check, as considered when calculating the score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Passed,
+    NotPassed,
+    Exempt,
+    Failed,
+    NotApplicable,
 }
 
 /// Check output information.
@@ -92,6 +244,20 @@ pub struct CheckOutput<T = ()> {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fail_reason: Option<String>,
+
+    /// The check was not run because some of the checks it depends on did
+    /// not pass. Checks not applicable are not taken into account when
+    /// calculating the score.
+    #[serde(default)]
+    pub not_applicable: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_applicable_reason: Option<String>,
+
+    /// Percentage of the check's weight awarded when it passes. When not set,
+    /// passing the check awards its full weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit: Option<usize>,
 }
 
 impl<T> CheckOutput<T> {
@@ -127,6 +293,15 @@ impl<T> CheckOutput<T> {
         }
     }
 
+    /// Create a new CheckOutput instance with the not_applicable field set to
+    /// true.
+    pub fn not_applicable() -> Self {
+        Self {
+            not_applicable: true,
+            ..Default::default()
+        }
+    }
+
     /// Url field setter.
     pub fn url(mut self, url: Option<String>) -> CheckOutput<T> {
         self.url = url;
@@ -156,6 +331,44 @@ impl<T> CheckOutput<T> {
         self.fail_reason = reason;
         self
     }
+
+    /// Not applicable reason field setter.
+    pub fn not_applicable_reason(mut self, reason: Option<String>) -> CheckOutput<T> {
+        self.not_applicable_reason = reason;
+        self
+    }
+
+    /// Credit field setter.
+    pub fn credit(mut self, credit: Option<usize>) -> CheckOutput<T> {
+        self.credit = credit;
+        self
+    }
+
+    /// Return the percentage of the check's weight awarded by this output.
+    pub(crate) fn awarded_credit(&self) -> usize {
+        if self.exempt {
+            FULL_CREDIT
+        } else if self.passed {
+            self.credit.unwrap_or(FULL_CREDIT).min(FULL_CREDIT)
+        } else {
+            0
+        }
+    }
+
+    /// Return the status of the check based on this output.
+    pub fn status(&self) -> CheckStatus {
+        if self.not_applicable {
+            CheckStatus::NotApplicable
+        } else if self.exempt {
+            CheckStatus::Exempt
+        } else if self.passed {
+            CheckStatus::Passed
+        } else if self.failed {
+            CheckStatus::Failed
+        } else {
+            CheckStatus::NotPassed
+        }
+    }
 }
 
 impl<T> Default for CheckOutput<T> {
@@ -169,6 +382,9 @@ impl<T> Default for CheckOutput<T> {
             exemption_reason: None,
             failed: false,
             fail_reason: None,
+            not_applicable: false,
+            not_applicable_reason: None,
+            credit: None,
         }
     }
 }
@@ -223,13 +439,60 @@ impl<T> From<Result<Option<&ScorecardCheck>, &Error>> for CheckOutput<T> {
     }
 }
 
+/// Records the time it takes to run each of the checks.
+#[derive(Debug, Default)]
+pub(crate) struct Durations(Mutex<BTreeMap<String, u64>>);
+
+impl Durations {
+    /// Record the time elapsed since the instant provided for the check.
+    pub(crate) fn record(&self, check_id: &str, start: Instant) {
+        let elapsed = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+        self.0
+            .lock()
+            .expect("durations lock not to be poisoned")
+            .insert(check_id.to_string(), elapsed);
+    }
+
+    /// Return the durations recorded.
+    pub(crate) fn into_inner(self) -> BTreeMap<String, u64> {
+        self.0
+            .into_inner()
+            .expect("durations lock not to be poisoned")
+    }
+}
+
+/// Make sure the async check provided completes within the timeout given,
+/// returning a failed check output otherwise.
+pub(crate) async fn with_timeout<F, T>(timeout: Duration, check: F) -> Result<CheckOutput<T>>
+where
+    F: std::future::Future<Output = Result<CheckOutput<T>>>,
+{
+    match tokio::time::timeout(timeout, check).await {
+        Ok(result) => result,
+        Err(_) => Ok(CheckOutput::failed().fail_reason(Some(format!(
+            "check timed out after {}s",
+            timeout.as_secs()
+        )))),
+    }
+}
+
+/// Build the output of a check that was not run because some of the checks it
+/// depends on did not pass.
+pub(crate) fn not_applicable_output<T>(unmet_dependencies: &[CheckId]) -> CheckOutput<T> {
+    CheckOutput::not_applicable().not_applicable_reason(Some(format!(
+        "Checks this check depends on did not pass: {}",
+        unmet_dependencies.join(", ")
+    )))
+}
+
 /// Wrapper macro that takes care of running some common pre-check operations
-/// and the synchronous check function.
+/// and the synchronous check function. The time the check takes to run is
+/// recorded in the durations provided.
 macro_rules! run {
-    ($check:ident, $input:expr) => {
+    ($check:ident, $input:expr, $durations:expr) => {
         (|| {
             // Check if this check should be skipped
-            if should_skip_check($check::ID, &$input.li.check_sets) {
+            if should_skip_check($check::ID, $input.li) {
                 return None;
             }
 
@@ -238,11 +501,19 @@ macro_rules! run {
                 return Some(CheckOutput::from(exemption));
             }
 
+            // Check if any of the checks this check depends on did not pass
+            let unmet_dependencies = $input.unmet_dependencies($check::ID);
+            if !unmet_dependencies.is_empty() {
+                return Some(not_applicable_output(&unmet_dependencies));
+            }
+
             // Call sync check function and wrap returned check output in an option
+            let start = std::time::Instant::now();
             let output = match $check::check($input) {
                 Ok(output) => output,
                 Err(err) => CheckOutput::failed().fail_reason(Some(format!("{:#}", err))),
             };
+            $durations.record($check::ID, start);
             Some(output)
         })()
     };
@@ -250,12 +521,14 @@ macro_rules! run {
 pub(crate) use run;
 
 /// Wrapper macro that takes care of running some common pre-check operations
-/// and the asynchronous check function.
+/// and the asynchronous check function. Checks that take longer than the
+/// CHECK_TIMEOUT are marked as failed. The time the check takes to run is
+/// recorded in the durations provided.
 macro_rules! run_async {
-    ($check:ident, $input:expr) => {
+    ($check:ident, $input:expr, $durations:expr) => {
         (|| async {
             // Check if this check should be skipped
-            if should_skip_check($check::ID, &$input.li.check_sets) {
+            if should_skip_check($check::ID, $input.li) {
                 return None;
             }
 
@@ -264,11 +537,19 @@ macro_rules! run_async {
                 return Some(CheckOutput::from(exemption));
             }
 
+            // Check if any of the checks this check depends on did not pass
+            let unmet_dependencies = $input.unmet_dependencies($check::ID);
+            if !unmet_dependencies.is_empty() {
+                return Some(not_applicable_output(&unmet_dependencies));
+            }
+
             // Call async check function and wrap returned check output in an option
-            let output = match $check::check($input).await {
+            let start = std::time::Instant::now();
+            let output = match with_timeout(CHECK_TIMEOUT, $check::check($input)).await {
                 Ok(output) => output,
                 Err(err) => CheckOutput::failed().fail_reason(Some(format!("{:#}", err))),
             };
+            $durations.record($check::ID, start);
             Some(output)
         })()
     };
@@ -298,6 +579,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_output_awarded_credit() {
+        let passed = CheckOutput::<()>::passed();
+        assert_eq!(passed.awarded_credit(), 100);
+        assert_eq!(passed.clone().credit(Some(70)).awarded_credit(), 70);
+        assert_eq!(passed.credit(Some(150)).awarded_credit(), 100);
+        assert_eq!(CheckOutput::<()>::exempt().awarded_credit(), 100);
+        assert_eq!(CheckOutput::<()>::not_passed().awarded_credit(), 0);
+    }
+
+    #[test]
+    fn check_output_status() {
+        assert_eq!(CheckOutput::<()>::passed().status(), CheckStatus::Passed);
+        assert_eq!(
+            CheckOutput::<()>::not_passed().status(),
+            CheckStatus::NotPassed
+        );
+        assert_eq!(CheckOutput::<()>::exempt().status(), CheckStatus::Exempt);
+        assert_eq!(CheckOutput::<()>::failed().status(), CheckStatus::Failed);
+        assert_eq!(
+            CheckOutput::<()>::not_applicable().status(),
+            CheckStatus::NotApplicable
+        );
+    }
+
     #[test]
     fn check_output_from_scorecard_check_passed() {
         let sc_check = ScorecardCheck {
@@ -367,4 +673,44 @@ mod tests {
             }
         );
     }
+
+    #[tokio::test]
+    async fn check_timed_out() {
+        let output = with_timeout(
+            Duration::from_millis(10),
+            std::future::pending::<Result<CheckOutput>>(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            output,
+            CheckOutput {
+                failed: true,
+                fail_reason: Some("check timed out after 0s".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn check_completed_within_timeout() {
+        let output = with_timeout(Duration::from_secs(1), async { Ok(CheckOutput::passed()) })
+            .await
+            .unwrap();
+
+        assert_eq!(output, CheckOutput::passed());
+    }
+
+    #[test]
+    fn durations_recorded() {
+        let durations = Durations::default();
+        durations.record("readme", Instant::now());
+        durations.record("license_spdx_id", Instant::now());
+
+        assert_eq!(
+            durations.into_inner().into_keys().collect::<Vec<String>>(),
+            vec!["license_spdx_id".to_string(), "readme".to_string()]
+        );
+    }
 }
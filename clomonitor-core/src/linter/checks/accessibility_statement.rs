@@ -0,0 +1,106 @@
+use super::util::{content, helpers::build_file_url, path, site};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::RegexSet;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "accessibility_statement";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 3;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::DocsSite];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add an accessibility statement page to the website content (i.e. content/accessibility.md or docs/accessibility.md).",
+        "Alternatively, add an ACCESSIBILITY.md file to the repository and link to it from the website.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#accessibility-statement",
+};
+
+/// Patterns used to locate a file in the repository.
+const FILE_PATTERNS: [&str; 3] = [
+    "accessibility*",
+    ".github/accessibility*",
+    "docs/accessibility*",
+];
+
+/// Patterns used to locate a page in the site's content directories.
+const CONTENT_PATTERNS: [&str; 2] = ["**/accessibility*", "**/a11y*"];
+
+lazy_static! {
+    #[rustfmt::skip]
+    pub(crate) static ref WEBSITE_REF: RegexSet = RegexSet::new([
+        r#"(?i)<a[^>]+href="[^"]*(?:accessibility|a11y)[^"]*""#,
+    ]).expect("exprs in WEBSITE_REF to be valid");
+}
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // File in repo
+    let root = &input.li.root;
+    if let Some(path) = path::find(&path::Globs {
+        root,
+        patterns: &FILE_PATTERNS,
+        case_sensitive: false,
+    })? {
+        return Ok(CheckOutput::passed().url(Some(build_file_url(input, &path))));
+    }
+
+    // Page in the site's content
+    if let Some(site) = site::detect(root)? {
+        for dir in site.content_dirs() {
+            let globs = path::Globs {
+                root: &dir,
+                patterns: &CONTENT_PATTERNS,
+                case_sensitive: false,
+            };
+            if let Some(path) = path::matches(&globs)?.first() {
+                let path = path.strip_prefix(root).unwrap_or(path);
+                return Ok(CheckOutput::passed()
+                    .url(Some(build_file_url(input, path)))
+                    .details(Some(format!(
+                        "Accessibility statement found in the {} site content",
+                        site.generator
+                    ))));
+            }
+        }
+    }
+
+    // Reference in website setup in Github
+    if let Some(url) = &input.gh_md.homepage_url {
+        if !url.is_empty()
+            && content::remote_matches(
+                &input.restricted_client,
+                url,
+                &WEBSITE_REF,
+                input.cache.as_ref(),
+            )
+            .await?
+        {
+            return Ok(CheckOutput::passed());
+        }
+    }
+
+    Ok(CheckOutput::not_passed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn website_ref_match() {
+        assert!(WEBSITE_REF.is_match(r#"<a href="/accessibility/">Accessibility</a>"#));
+        assert!(WEBSITE_REF.is_match(r#"<a class="link" href="https://example.io/a11y">A11y</a>"#));
+        assert!(!WEBSITE_REF.is_match(r#"<a href="/docs/">Docs</a>"#));
+    }
+}
@@ -1,6 +1,9 @@
-use super::util::helpers::find_file_or_readme_ref;
+use super::util::{
+    helpers::find_file_or_readme_ref,
+    locale::{find_translated_file_or_readme_ref, Document},
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,6 +19,19 @@ pub(crate) const WEIGHT: usize = 1;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add an ADOPTERS.md file to the repository listing the organizations using the project.",
+        "Alternatively, add an Adopters section to the README file (or link to the list of adopters from it).",
+    ],
+    template: Some(RemediationTemplate {
+        path: "ADOPTERS.md",
+        content: include_str!("data/templates/ADOPTERS.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#adopters",
+};
+
 /// Patterns used to locate a file in the repository.
 const FILE_PATTERNS: [&str; 2] = ["adopters*", "users*"];
 
@@ -31,7 +47,17 @@ lazy_static! {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo or reference in README file
-    find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)
+    let r = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
+    if r.passed {
+        return Ok(r);
+    }
+
+    // Translated file in repo or reference in README file
+    if let Some(r) = find_translated_file_or_readme_ref(input, Document::Adopters)? {
+        return Ok(r);
+    }
+
+    Ok(CheckOutput::not_passed())
 }
 
 #[cfg(test)]
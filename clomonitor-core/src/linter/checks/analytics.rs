@@ -1,10 +1,12 @@
+use super::util::{content, helpers::project_website_content, path, site};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::fs;
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "analytics";
@@ -13,7 +15,18 @@ pub(crate) const ID: CheckId = "analytics";
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
-pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Community, CheckSet::DocsSite];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Set up web analytics (Google Analytics or HubSpot) in the project's website.",
+        "Make sure the website url is configured in the GitHub repository.",
+        "Documentation sites can also configure analytics in the static site generator's configuration file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#analytics",
+};
 
 lazy_static! {
     #[rustfmt::skip]
@@ -34,42 +47,89 @@ lazy_static! {
 
 /// Check main function.
 pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput<Vec<String>>> {
+    // Analytics configured in the documentation site sources
+    if let Some(site) = site::detect(&input.li.root)? {
+        let content = path::matches(&site.config())?
+            .into_iter()
+            .chain(path::matches(&site.layouts())?)
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .collect::<Vec<String>>()
+            .join("\n");
+        if let Some((analytics_detected, details)) = detect_analytics(
+            &content,
+            &format!(
+                "# Analytics providers detected in project's {} site configuration \n\n",
+                site.generator
+            ),
+        ) {
+            return Ok(CheckOutput::passed()
+                .value(Some(analytics_detected))
+                .details(Some(details)));
+        }
+    }
+
+    // Analytics detected in the project's live website
+    if let Some(content) = project_website_content(input).await? {
+        if let Some((analytics_detected, details)) = detect_analytics(
+            &content,
+            "# Analytics providers detected in project's website \n\n",
+        ) {
+            return Ok(CheckOutput::passed()
+                .value(Some(analytics_detected))
+                .details(Some(details)));
+        }
+    }
+
     // Get website content
     let content = match &input.gh_md.homepage_url {
-        Some(url) if !url.is_empty() => reqwest::get(url).await?.text().await?,
+        Some(url) if !url.is_empty() => {
+            content::remote(&input.restricted_client, url, input.cache.as_ref()).await?
+        }
         _ => return Ok(CheckOutput::not_passed()),
     };
 
+    // Analytics detected in the website
+    if let Some((analytics_detected, details)) = detect_analytics(
+        &content,
+        "# Analytics providers detected in project's website \n\n",
+    ) {
+        return Ok(CheckOutput::passed()
+            .value(Some(analytics_detected))
+            .details(Some(details)));
+    }
+
+    Ok(CheckOutput::not_passed())
+}
+
+/// Detect the analytics providers used in the content provided, returning
+/// them along with some details (starting with the header given) if any is
+/// found.
+fn detect_analytics(content: &str, header: &str) -> Option<(Vec<String>, String)> {
     let mut analytics_detected: Vec<String> = Vec::new();
-    let mut details: String =
-        "# Analytics providers detected in project's website \n\n".to_string();
+    let mut details = header.to_string();
 
     // Check Google Analytics 3 (Universal Analytics) tracking ID
-    if GA3.is_match(&content) {
+    if GA3.is_match(content) {
         analytics_detected.push("GA3".to_string());
         details.push_str("· Google Analytics 3 (Universal Analytics)\n")
     }
 
     // Check Google Analytics 4 measurement ID
-    if GA4.is_match(&content) {
+    if GA4.is_match(content) {
         analytics_detected.push("GA4".to_string());
         details.push_str("· Google Analytics 4\n")
     }
 
     // Check HubSpot tracking code
-    if HUBSPOT.is_match(&content) {
+    if HUBSPOT.is_match(content) {
         analytics_detected.push("HubSpot".to_string());
         details.push_str("· HubSpot\n")
     }
 
-    // Return check output
-    if !analytics_detected.is_empty() {
-        return Ok(CheckOutput::passed()
-            .value(Some(analytics_detected))
-            .details(Some(details)));
+    if analytics_detected.is_empty() {
+        return None;
     }
-
-    Ok(CheckOutput::not_passed())
+    Some((analytics_detected, details))
 }
 
 #[cfg(test)]
@@ -93,4 +153,17 @@ mod tests {
     fn hubspot_match() {
         assert!(HUBSPOT.is_match("https://js.hs-scripts.com/123.js"));
     }
+
+    #[test]
+    fn detect_analytics_in_site_config() {
+        let content = fs::read_to_string("src/testdata/sites/hugo/hugo.toml").unwrap();
+        let (analytics_detected, details) = detect_analytics(&content, "# Header\n\n").unwrap();
+        assert_eq!(analytics_detected, vec!["GA4".to_string()]);
+        assert_eq!(details, "# Header\n\n· Google Analytics 4\n");
+    }
+
+    #[test]
+    fn detect_analytics_not_found() {
+        assert!(detect_analytics("no analytics here", "# Header\n\n").is_none());
+    }
 }
@@ -1,6 +1,6 @@
 use super::util::helpers::readme_capture;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,6 +16,16 @@ pub(crate) const WEIGHT: usize = 1;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "List the project's packages on Artifact Hub (https://artifacthub.io).",
+        "Add the Artifact Hub repository badge to the README file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#artifact-hub-badge",
+};
+
 lazy_static! {
     #[rustfmt::skip]
     static ref ARTIFACTHUB_URL: Regex = Regex::new(
@@ -1,6 +1,6 @@
 use super::util::scorecard;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -14,6 +14,16 @@ pub(crate) const WEIGHT: usize = 2;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Remove the generated executable (binary) artifacts from the repository.",
+        "Build the artifacts from source as part of the release process instead.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#binary-artifacts-from-openssf-scorecard",
+};
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     Ok(scorecard::get_check(&input.scorecard, ID).into())
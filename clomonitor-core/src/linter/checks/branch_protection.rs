@@ -0,0 +1,160 @@
+use super::util::github::{self, BranchProtection};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "branch_protection";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 4;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Protect the default branch using a branch protection rule or a ruleset.",
+        "Require status checks to pass and pull requests to be approved before merging, dismissing stale approvals when new commits are pushed.",
+        "Require signed commits and block force pushes to the default branch.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#branch-protection",
+};
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // The organization token, when provided, may have admin permissions on
+    // the repository, which are required to read the classic protection rules
+    let token = input
+        .li
+        .github_org_token
+        .as_ref()
+        .unwrap_or(&input.li.github_token);
+    let branch = github::default_branch(input.gh_md.default_branch_ref.as_ref());
+    let protection = github::branch_protection(&input.li.url, &branch, token).await?;
+    Ok(grade(&branch, &protection))
+}
+
+/// Grade the branch protection settings provided, awarding an equal share of
+/// the credit to each of the settings enabled.
+fn grade(branch: &str, protection: &BranchProtection) -> CheckOutput {
+    if !protection.protected {
+        return CheckOutput::not_passed()
+            .details(Some(format!("Branch `{branch}` is not protected")));
+    }
+
+    let settings = [
+        (protection.required_status_checks, "Required status checks"),
+        (
+            protection.required_approving_review_count > 0,
+            "Required pull request approvals",
+        ),
+        (
+            protection.dismiss_stale_reviews,
+            "Stale approvals dismissal",
+        ),
+        (protection.required_signatures, "Signed commits required"),
+        (protection.force_pushes_blocked, "Force pushes blocked"),
+    ];
+    let enabled = settings.iter().filter(|(enabled, _)| *enabled).count();
+    if enabled == 0 {
+        return CheckOutput::not_passed().details(Some(format!(
+            "Branch `{branch}` is protected, but none of the settings graded are enabled"
+        )));
+    }
+
+    let mut details = format!(
+        "**Required approvals**: {}",
+        protection.required_approving_review_count
+    );
+    let missing: Vec<&str> = settings
+        .iter()
+        .filter(|(enabled, _)| !*enabled)
+        .map(|(_, name)| *name)
+        .collect();
+    if !missing.is_empty() {
+        details.push_str(&format!(
+            "\n\nSettings missing on branch `{branch}`:\n\n{}",
+            missing
+                .iter()
+                .map(|name| format!("- {name}"))
+                .collect::<Vec<String>>()
+                .join("\n")
+        ));
+    }
+    CheckOutput::passed()
+        .details(Some(details))
+        .credit(Some(enabled * 100 / settings.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grade_unprotected_branch() {
+        assert_eq!(
+            grade("main", &BranchProtection::default()),
+            CheckOutput::not_passed().details(Some("Branch `main` is not protected".to_string()))
+        );
+    }
+
+    #[test]
+    fn grade_protected_branch_without_settings() {
+        let output = grade(
+            "main",
+            &BranchProtection {
+                protected: true,
+                ..BranchProtection::default()
+            },
+        );
+        assert!(!output.passed);
+    }
+
+    #[test]
+    fn grade_partially_protected_branch() {
+        let output = grade(
+            "main",
+            &BranchProtection {
+                protected: true,
+                required_status_checks: true,
+                required_approving_review_count: 2,
+                force_pushes_blocked: true,
+                ..BranchProtection::default()
+            },
+        );
+        assert!(output.passed);
+        assert_eq!(output.credit, Some(60));
+        assert_eq!(
+            output.details,
+            Some(
+                "**Required approvals**: 2\n\nSettings missing on branch `main`:\n\n- Stale approvals dismissal\n- Signed commits required".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn grade_fully_protected_branch() {
+        let output = grade(
+            "main",
+            &BranchProtection {
+                protected: true,
+                required_status_checks: true,
+                required_approving_review_count: 1,
+                dismiss_stale_reviews: true,
+                required_signatures: true,
+                force_pushes_blocked: true,
+            },
+        );
+        assert!(output.passed);
+        assert_eq!(output.credit, Some(100));
+        assert_eq!(
+            output.details,
+            Some("**Required approvals**: 1".to_string())
+        );
+    }
+}
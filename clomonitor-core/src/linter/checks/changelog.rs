@@ -1,6 +1,10 @@
-use super::util::{github, helpers::find_file_or_readme_ref};
+use super::util::{
+    github,
+    helpers::find_file_or_readme_ref,
+    locale::{find_translated_file_or_readme_ref, Document},
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,6 +20,19 @@ pub(crate) const WEIGHT: usize = 1;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a CHANGELOG.md file to the repository with the notable changes of each version.",
+        "Alternatively, reference the changelog in the README file or in the releases notes.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "CHANGELOG.md",
+        content: include_str!("data/templates/CHANGELOG.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#changelog",
+};
+
 /// Patterns used to locate a file in the repository.
 pub(crate) static FILE_PATTERNS: [&str; 1] = ["changelog*"];
 
@@ -42,6 +59,11 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
         return Ok(r);
     }
 
+    // Translated file in repo or reference in README file
+    if let Some(r) = find_translated_file_or_readme_ref(input, Document::Changelog)? {
+        return Ok(r);
+    }
+
     // Reference in last release
     if github::latest_release_description_matches(&input.gh_md, &RELEASE_REF) {
         return Ok(CheckOutput::passed());
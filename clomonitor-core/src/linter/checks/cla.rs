@@ -1,6 +1,6 @@
 use super::util::github;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,9 +16,18 @@ pub(crate) const WEIGHT: usize = 1;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Set up a Contributor License Agreement check (i.e. EasyCLA) on the repository's pull requests.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#contributor-license-agreement",
+};
+
 lazy_static! {
     #[rustfmt::skip]
-    static ref CHECK_REF: RegexSet = RegexSet::new([
+    pub(crate) static ref CHECK_REF: RegexSet = RegexSet::new([
         r"(?i)cncf-cla",
         r"(?i)cla/linuxfoundation",
         r"(?i)easycla",
@@ -1,6 +1,9 @@
-use super::util::helpers::find_file_or_readme_ref;
+use super::util::{
+    helpers::find_file_or_readme_ref,
+    locale::{find_translated_file_or_readme_ref, Document},
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,10 +19,24 @@ pub(crate) const WEIGHT: usize = 2;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a CODE_OF_CONDUCT.md file to the repository (or to the organization's .github repository).",
+        "Alternatively, reference the code of conduct in the README file.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "CODE_OF_CONDUCT.md",
+        content: include_str!("data/templates/CODE_OF_CONDUCT.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#code-of-conduct",
+};
+
 /// Patterns used to locate a file in the repository.
-pub(crate) static FILE_PATTERNS: [&str; 3] = [
+pub(crate) static FILE_PATTERNS: [&str; 4] = [
     "code*of*conduct*",
     ".github/code*of*conduct*",
+    ".gitea/code*of*conduct*",
     "docs/code*of*conduct*",
 ];
 
@@ -40,6 +57,11 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
         return Ok(r);
     }
 
+    // Translated file in repo or reference in README file
+    if let Some(r) = find_translated_file_or_readme_ref(input, Document::CodeOfConduct)? {
+        return Ok(r);
+    }
+
     // File in Github (default community health file, for example)
     if let Some(coc) = &input.gh_md.code_of_conduct {
         if coc.url.is_some() {
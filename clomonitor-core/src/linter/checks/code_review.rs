@@ -1,6 +1,6 @@
-use super::util::scorecard;
+use super::util::{gerrit, scorecard};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -14,7 +14,96 @@ pub(crate) const WEIGHT: usize = 3;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Enable branch protection on the default branch requiring pull requests to be reviewed before merging.",
+        "Make sure changes are reviewed by someone other than their author.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#code-review-from-openssf-scorecard",
+};
+
+/// Number of days of merged changes looked up on Gerrit.
+const GERRIT_MAX_AGE_DAYS: usize = 90;
+
 /// Check main function.
-pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // Repositories mirrored from Gerrit are reviewed there, so the changes
+    // recently merged are looked up in the canonical host instead
+    if let Some(gerrit_url) = &input.li.gerrit_url {
+        let changes =
+            gerrit::merged_changes(gerrit_url, GERRIT_MAX_AGE_DAYS, gerrit::MAX_MERGED_CHANGES)
+                .await?;
+        return Ok(gerrit_output(&changes).url(Some(gerrit_url.clone())));
+    }
+
     Ok(scorecard::get_check(&input.scorecard, ID).into())
 }
+
+/// Build the check output from the changes merged in the Gerrit project.
+fn gerrit_output(changes: &[gerrit::Change]) -> CheckOutput {
+    if changes.is_empty() {
+        return CheckOutput::not_passed().details(Some(format!(
+            "No changes merged in the last {GERRIT_MAX_AGE_DAYS} days found on Gerrit"
+        )));
+    }
+    let reviewed = changes.iter().filter(|change| change.reviewed()).count();
+    if reviewed == 0 {
+        return CheckOutput::not_passed().details(Some(
+            "None of the changes recently merged were approved by someone other than their owner"
+                .to_string(),
+        ));
+    }
+    let details = (reviewed < changes.len()).then(|| {
+        format!(
+            "{} out of {} changes recently merged were not approved by someone other than their owner",
+            changes.len() - reviewed,
+            changes.len()
+        )
+    });
+    CheckOutput::passed()
+        .details(details)
+        .credit(Some(reviewed * 100 / changes.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(owner: u64, reviewers: &[u64]) -> gerrit::Change {
+        gerrit::Change {
+            owner: gerrit::Account { account_id: owner },
+            labels: [(
+                "Code-Review".to_string(),
+                gerrit::Label {
+                    all: reviewers
+                        .iter()
+                        .map(|account_id| gerrit::Approval {
+                            account_id: *account_id,
+                            value: Some(2),
+                        })
+                        .collect(),
+                },
+            )]
+            .into(),
+        }
+    }
+
+    #[test]
+    fn gerrit_output_no_changes() {
+        assert!(!gerrit_output(&[]).passed);
+    }
+
+    #[test]
+    fn gerrit_output_some_changes_not_reviewed() {
+        let output = gerrit_output(&[change(1, &[2]), change(1, &[1]), change(2, &[1])]);
+        assert!(output.passed);
+        assert_eq!(output.credit, Some(66));
+    }
+
+    #[test]
+    fn gerrit_output_no_changes_reviewed() {
+        assert!(!gerrit_output(&[change(1, &[1]), change(2, &[])]).passed);
+    }
+}
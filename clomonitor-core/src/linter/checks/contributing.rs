@@ -1,7 +1,11 @@
-use super::util::{github, helpers::find_file_or_readme_ref};
+use super::util::{
+    github,
+    helpers::find_file_or_readme_ref,
+    locale::{find_translated_file_or_readme_ref, Document},
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
-    CheckSet,
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
+    CheckSet, Provider,
 };
 use anyhow::Result;
 use lazy_static::lazy_static;
@@ -17,10 +21,24 @@ pub(crate) const WEIGHT: usize = 4;
 pub(crate) const CHECK_SETS: [CheckSet; 3] =
     [CheckSet::Code, CheckSet::CodeLite, CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a CONTRIBUTING.md file to the repository (or to the organization's .github repository) explaining how to contribute.",
+        "Alternatively, add a Contributing section to the README file.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "CONTRIBUTING.md",
+        content: include_str!("data/templates/CONTRIBUTING.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#contributing",
+};
+
 /// Patterns used to locate a file in the repository.
-const FILE_PATTERNS: [&str; 3] = [
+const FILE_PATTERNS: [&str; 4] = [
     "contributing*",
     ".github/contributing*",
+    ".gitea/contributing*",
     "docs/contributing*",
 ];
 
@@ -41,9 +59,19 @@ pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
         return Ok(r);
     }
 
-    // File in .github repo
-    if let Some(url) = github::has_community_health_file("CONTRIBUTING.md", &input.gh_md).await? {
-        return Ok(CheckOutput::passed().url(Some(url)));
+    // Translated file in repo or reference in README file
+    if let Some(r) = find_translated_file_or_readme_ref(input, Document::Contributing)? {
+        return Ok(r);
+    }
+
+    // File in .github repo (only available on GitHub)
+    if input.li.provider == Provider::Github {
+        if let Some(url) =
+            github::has_community_health_file("CONTRIBUTING.md", &input.gh_md, input.cache.as_ref())
+                .await?
+        {
+            return Ok(CheckOutput::passed().url(Some(url)));
+        }
     }
 
     Ok(CheckOutput::not_passed())
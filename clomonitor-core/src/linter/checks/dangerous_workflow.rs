@@ -1,6 +1,6 @@
 use super::util::scorecard;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -14,6 +14,16 @@ pub(crate) const WEIGHT: usize = 2;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Review the GitHub Actions workflows for dangerous patterns, like checking out untrusted code in pull_request_target workflows or script injections.",
+        "Pass untrusted inputs to scripts using environment variables instead of expanding them inline.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#dangerous-workflow-from-openssf-scorecard",
+};
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     Ok(scorecard::get_check(&input.scorecard, ID).into())
@@ -1,6 +1,6 @@
 use super::util::github;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -17,12 +17,22 @@ pub(crate) const WEIGHT: usize = 1;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Ask contributors to sign off their commits (git commit -s).",
+        "Set up a DCO check (i.e. the DCO GitHub app) on the repository's pull requests.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#developer-certificate-of-origin",
+};
+
 /// Maximum number of commits used to check if the repository requires DCO.
 const DCO_MAX_COMMITS: usize = 20;
 
 lazy_static! {
     #[rustfmt::skip]
-    static ref CHECK_REF: RegexSet = RegexSet::new([
+    pub(crate) static ref CHECK_REF: RegexSet = RegexSet::new([
         r"(?i)dco",
     ]).expect("exprs in CHECK_REF to be valid");
 }
@@ -46,7 +56,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 
 /// Check if the last commits on the git repository located in the path
 /// provided have the DCO signature.
-fn commits_have_dco_signature(path: &Path) -> Result<bool, git2::Error> {
+pub(crate) fn commits_have_dco_signature(path: &Path) -> Result<bool, git2::Error> {
     lazy_static! {
         static ref MERGE_PR_RE: Regex =
             Regex::new(r"^Merge pull request ").expect("valid expression");
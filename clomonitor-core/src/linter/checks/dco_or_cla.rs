@@ -0,0 +1,128 @@
+use super::{cla, dco, util::github};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "dco_or_cla";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Enforce the Developer Certificate of Origin by setting up a DCO check on the repository's pull requests.",
+        "Alternatively, set up a Contributor License Agreement check (i.e. EasyCLA).",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#dco-or-cla",
+};
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    // DCO signature in recent commits
+    if let Ok(true) = dco::commits_have_dco_signature(&input.li.root) {
+        return Ok(
+            CheckOutput::passed().details(Some("Recent commits are signed off (DCO)".to_string()))
+        );
+    }
+
+    // DCO check in recent PRs
+    if github::recent_prs_have_check(&input.gh_md, &dco::CHECK_REF)? {
+        return Ok(CheckOutput::passed()
+            .details(Some("DCO check found in recent pull requests".to_string())));
+    }
+
+    // CLA check in recent PRs
+    if github::recent_prs_have_check(&input.gh_md, &cla::CHECK_REF)? {
+        return Ok(CheckOutput::passed()
+            .details(Some("CLA check found in recent pull requests".to_string())));
+    }
+
+    Ok(CheckOutput::not_passed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linter::{util::github::md::*, LinterInput},
+        net::RestrictedClient,
+    };
+    use anyhow::format_err;
+    use std::collections::BTreeMap;
+
+    fn gh_md(contexts: &[&str]) -> MdRepository {
+        let pr = |context: &str| {
+            Some(MdRepositoryPullRequestsNodes {
+                commits: MdRepositoryPullRequestsNodesCommits {
+                    nodes: Some(vec![Some(MdRepositoryPullRequestsNodesCommitsNodes {
+                        commit: MdRepositoryPullRequestsNodesCommitsNodesCommit {
+                            check_suites: None,
+                            status: Some(MdRepositoryPullRequestsNodesCommitsNodesCommitStatus {
+                                contexts: vec![
+                                    MdRepositoryPullRequestsNodesCommitsNodesCommitStatusContexts {
+                                        context: context.to_string(),
+                                    },
+                                ],
+                            }),
+                        },
+                    })]),
+                },
+            })
+        };
+        MdRepository {
+            pull_requests: MdRepositoryPullRequests {
+                nodes: Some(contexts.iter().copied().map(pr).collect()),
+            },
+            ..MdRepository::default()
+        }
+    }
+
+    fn check_input<'a>(li: &'a LinterInput, gh_md: MdRepository) -> CheckInput<'a> {
+        CheckInput {
+            li,
+            cm_md: None,
+            gh_md,
+            scorecard: Err(format_err!("no scorecard available")),
+            cache: None,
+            restricted_client: RestrictedClient::default(),
+            prerequisites: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn passed_dco_check_in_recent_pr() {
+        let li = LinterInput::default();
+        assert_eq!(
+            check(&check_input(&li, gh_md(&["ci", "DCO"]))).unwrap(),
+            CheckOutput::passed()
+                .details(Some("DCO check found in recent pull requests".to_string())),
+        );
+    }
+
+    #[test]
+    fn passed_cla_check_in_recent_pr() {
+        let li = LinterInput::default();
+        assert_eq!(
+            check(&check_input(&li, gh_md(&["ci", "EasyCLA"]))).unwrap(),
+            CheckOutput::passed()
+                .details(Some("CLA check found in recent pull requests".to_string())),
+        );
+    }
+
+    #[test]
+    fn not_passed_no_dco_or_cla_found() {
+        let li = LinterInput::default();
+        assert_eq!(
+            check(&check_input(&li, gh_md(&["ci", "lint"]))).unwrap(),
+            CheckOutput::not_passed(),
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use super::util::{
+    helpers::build_file_url,
+    path::{self, Globs},
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
+    CheckSet,
+};
+use anyhow::Result;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "dependency_update_automation";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &["Configure a dependency update bot like Dependabot or Renovate in the repository."],
+    template: Some(RemediationTemplate {
+        path: ".github/dependabot.yml",
+        content: include_str!("data/templates/dependabot.yml"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#dependency-update-automation",
+};
+
+/// Patterns used to locate configuration files of some dependency update
+/// bots (Dependabot, Renovate, PyUp and Depfu) in the repository.
+pub(crate) static FILE_PATTERNS: [&str; 13] = [
+    ".github/dependabot.yml",
+    ".github/dependabot.yaml",
+    "renovate.json",
+    "renovate.json5",
+    ".github/renovate.json",
+    ".github/renovate.json5",
+    ".gitlab/renovate.json",
+    ".gitlab/renovate.json5",
+    ".renovaterc",
+    ".renovaterc.json",
+    ".renovaterc.json5",
+    ".pyup.yml",
+    ".depfu.yml",
+];
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    // Dependency update bot configuration file in repo
+    if let Some(path) = path::find(&Globs {
+        root: &input.li.root,
+        patterns: &FILE_PATTERNS,
+        case_sensitive: true,
+    })? {
+        return Ok(CheckOutput::passed().url(Some(build_file_url(input, &path))));
+    }
+
+    Ok(CheckOutput::not_passed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glob::{MatchOptions, Pattern};
+
+    fn file_patterns_match(path: &str) -> bool {
+        let options = MatchOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        FILE_PATTERNS
+            .iter()
+            .any(|p| Pattern::new(p).unwrap().matches_with(path, options))
+    }
+
+    #[test]
+    fn file_patterns_match_bots_config_files() {
+        assert!(file_patterns_match(".github/dependabot.yml"));
+        assert!(file_patterns_match("renovate.json"));
+        assert!(file_patterns_match(".github/renovate.json5"));
+        assert!(file_patterns_match(".renovaterc"));
+    }
+
+    #[test]
+    fn file_patterns_do_not_match_other_files() {
+        assert!(!file_patterns_match("dependabot.yml"));
+        assert!(!file_patterns_match("Renovate.json"));
+        assert!(!file_patterns_match(".github/workflows/ci.yml"));
+        assert!(!file_patterns_match("README.md"));
+    }
+}
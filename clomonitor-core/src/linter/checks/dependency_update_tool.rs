@@ -1,6 +1,6 @@
 use super::util::scorecard;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
     CheckSet,
 };
 use anyhow::Result;
@@ -14,6 +14,17 @@ pub(crate) const WEIGHT: usize = 2;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &["Configure a dependency update tool like Dependabot or Renovate in the repository."],
+    template: Some(RemediationTemplate {
+        path: ".github/dependabot.yml",
+        content: include_str!("data/templates/dependabot.yml"),
+    }),
+    docs_url:
+        "https://clomonitor.io/docs/topics/checks/#dependency-update-tool-from-openssf-scorecard",
+};
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     Ok(scorecard::get_check(&input.scorecard, ID).into())
@@ -0,0 +1,321 @@
+use super::util::{
+    helpers::build_file_url,
+    path::{self, Globs},
+};
+use crate::{
+    cache::{self, DynCache},
+    linter::{
+        check::{CheckId, CheckInput, CheckOutput, Remediation},
+        CheckSet,
+    },
+    net,
+};
+use anyhow::{format_err, Context, Result};
+use lazy_static::lazy_static;
+use regex::RegexSet;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "fuzzing";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 1;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Integrate the project with OSS-Fuzz (https://google.github.io/oss-fuzz/), or run ClusterFuzzLite (https://google.github.io/clusterfuzzlite/) in the CI workflows.",
+        "Alternatively, add some fuzz targets to the repository (i.e. using cargo-fuzz or Go native fuzzing).",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#fuzzing",
+};
+
+/// OSS-Fuzz projects definitions base url.
+const OSS_FUZZ_PROJECTS_URL: &str =
+    "https://raw.githubusercontent.com/google/oss-fuzz/master/projects";
+
+/// OSS-Fuzz projects web base url.
+const OSS_FUZZ_PROJECTS_WEB_URL: &str = "https://github.com/google/oss-fuzz/tree/master/projects";
+
+/// How long the OSS-Fuzz projects definitions will be kept in the cache.
+const OSS_FUZZ_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Patterns used to locate the ClusterFuzzLite configuration files.
+static CLUSTERFUZZLITE_FILE_PATTERNS: [&str; 1] = [".clusterfuzzlite/*"];
+
+/// Patterns used to locate the GitHub workflows files.
+static WORKFLOWS_FILE_PATTERNS: [&str; 2] = [".github/workflows/*.yml", ".github/workflows/*.yaml"];
+
+/// Patterns used to locate the cargo-fuzz manifests.
+static CARGO_FUZZ_FILE_PATTERNS: [&str; 2] = ["fuzz/Cargo.toml", "**/fuzz/Cargo.toml"];
+
+/// Patterns used to locate the Go tests files.
+static GO_TESTS_FILE_PATTERNS: [&str; 1] = ["**/*_test.go"];
+
+/// Patterns used to locate the go-fuzz targets files.
+static GO_FUZZ_FILE_PATTERNS: [&str; 1] = ["**/*.go"];
+
+lazy_static! {
+    static ref CLUSTERFUZZLITE_ACTION: RegexSet =
+        RegexSet::new([r"google/clusterfuzzlite/actions/"])
+            .expect("exprs in CLUSTERFUZZLITE_ACTION to be valid");
+    static ref CARGO_FUZZ_MANIFEST: RegexSet = RegexSet::new([
+        r"(?m)^\s*libfuzzer-sys\s*=",
+        r"(?m)^\s*cargo-fuzz\s*=\s*true"
+    ])
+    .expect("exprs in CARGO_FUZZ_MANIFEST to be valid");
+    static ref GO_NATIVE_FUZZ_TARGET: RegexSet =
+        RegexSet::new([r"(?m)^func\s+Fuzz\w*\s*\(\s*\w+\s+\*testing\.F\s*\)"])
+            .expect("exprs in GO_NATIVE_FUZZ_TARGET to be valid");
+    static ref GO_FUZZ_TARGET: RegexSet =
+        RegexSet::new([r"(?m)^func\s+Fuzz\w*\s*\(\s*\w+\s+\[\]byte\s*\)\s*int\b"])
+            .expect("exprs in GO_FUZZ_TARGET to be valid");
+}
+
+/// OSS-Fuzz project definition (only the fields used are included).
+#[derive(Debug, Clone, Deserialize)]
+struct OssFuzzProject {
+    main_repo: Option<String>,
+}
+
+/// Fuzzing integration found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Integration {
+    name: &'static str,
+    url: String,
+}
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    let mut integrations = vec![];
+
+    // Project integrated with OSS-Fuzz
+    if let Some(url) = oss_fuzz_project_url(&input.li.url, input.cache.as_ref()).await? {
+        integrations.push(Integration {
+            name: "OSS-Fuzz",
+            url,
+        });
+    }
+
+    // Integrations defined in the repository
+    let root = &input.li.root;
+    let in_tree = [
+        (
+            "ClusterFuzzLite",
+            path::find(&Globs {
+                root,
+                patterns: &CLUSTERFUZZLITE_FILE_PATTERNS,
+                case_sensitive: true,
+            })?,
+        ),
+        (
+            "ClusterFuzzLite",
+            find_content(root, &WORKFLOWS_FILE_PATTERNS, &CLUSTERFUZZLITE_ACTION)?,
+        ),
+        (
+            "cargo-fuzz",
+            find_content(root, &CARGO_FUZZ_FILE_PATTERNS, &CARGO_FUZZ_MANIFEST)?,
+        ),
+        (
+            "Go fuzzing",
+            find_content(root, &GO_TESTS_FILE_PATTERNS, &GO_NATIVE_FUZZ_TARGET)?,
+        ),
+        (
+            "go-fuzz",
+            find_content(root, &GO_FUZZ_FILE_PATTERNS, &GO_FUZZ_TARGET)?,
+        ),
+    ];
+    for (name, path) in in_tree {
+        let Some(path) = path else {
+            continue;
+        };
+        if integrations.iter().any(|i| i.name == name) {
+            continue;
+        }
+        integrations.push(Integration {
+            name,
+            url: build_file_url(input, &path),
+        });
+    }
+
+    // Build check output
+    let Some(first) = integrations.first() else {
+        return Ok(CheckOutput::not_passed());
+    };
+    Ok(CheckOutput::passed()
+        .url(Some(first.url.clone()))
+        .details(Some(details(&integrations))))
+}
+
+/// Find the first file matching the globs provided whose content matches any
+/// of the regular expressions given, returning its path relative to the root.
+fn find_content(root: &Path, patterns: &[&str], re: &RegexSet) -> Result<Option<PathBuf>> {
+    let paths = path::matches(&Globs {
+        root,
+        patterns,
+        case_sensitive: true,
+    })?;
+    for path in paths {
+        if let Ok(content) = fs::read_to_string(&path) {
+            if re.is_match(&content) {
+                return Ok(Some(path.strip_prefix(root).unwrap_or(&path).to_owned()));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Return the url of the OSS-Fuzz project of the repository provided, if it
+/// has been integrated. OSS-Fuzz projects are expected to be named after the
+/// repository, and their main repository must match the one provided.
+async fn oss_fuzz_project_url(repo_url: &str, cache: Option<&DynCache>) -> Result<Option<String>> {
+    let Some(name) = repository_name(repo_url) else {
+        return Ok(None);
+    };
+    let key = format!("probe:oss-fuzz-project:{name}");
+    let body = cache::get_or_insert_with(cache, &key, OSS_FUZZ_CACHE_TTL, async {
+        let resp = net::client_builder()
+            .build()?
+            .get(format!("{OSS_FUZZ_PROJECTS_URL}/{name}/project.yaml"))
+            .send()
+            .await
+            .context("error querying oss-fuzz projects")?;
+        match resp.status() {
+            StatusCode::OK => Ok(resp.bytes().await?.to_vec()),
+            StatusCode::NOT_FOUND => Ok(vec![]),
+            status => Err(format_err!(
+                "unexpected status code querying oss-fuzz projects: {status}"
+            )),
+        }
+    })
+    .await?;
+    if body.is_empty() {
+        return Ok(None);
+    }
+    let project: OssFuzzProject = serde_yaml::from_slice(&body)?;
+    if !same_repository(project.main_repo.as_deref().unwrap_or_default(), repo_url) {
+        return Ok(None);
+    }
+    Ok(Some(format!("{OSS_FUZZ_PROJECTS_WEB_URL}/{name}")))
+}
+
+/// Extract the repository name (lowercase) from the repository url provided.
+fn repository_name(repo_url: &str) -> Option<String> {
+    let name = repo_url
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .rsplit('/')
+        .next()?
+        .to_lowercase();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || "-_.".contains(c))
+    {
+        return None;
+    }
+    Some(name)
+}
+
+/// Check if both repository urls provided point to the same repository.
+fn same_repository(url1: &str, url2: &str) -> bool {
+    let normalize = |url: &str| {
+        url.trim()
+            .trim_end_matches('/')
+            .trim_end_matches(".git")
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .to_lowercase()
+    };
+    !url1.trim().is_empty() && normalize(url1) == normalize(url2)
+}
+
+/// Build the check output details from the integrations found.
+fn details(integrations: &[Integration]) -> String {
+    let list: Vec<String> = integrations
+        .iter()
+        .map(|i| format!("- {} ([link]({}))", i.name, i.url))
+        .collect();
+    format!("**Fuzzing integrations found**:\n\n{}", list.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clusterfuzzlite_action_match() {
+        assert!(CLUSTERFUZZLITE_ACTION
+            .is_match("uses: google/clusterfuzzlite/actions/build_fuzzers@v1"));
+        assert!(!CLUSTERFUZZLITE_ACTION.is_match("uses: actions/checkout@v3"));
+    }
+
+    #[test]
+    fn cargo_fuzz_manifest_match() {
+        assert!(CARGO_FUZZ_MANIFEST.is_match("[dependencies]\nlibfuzzer-sys = \"0.4\"\n"));
+        assert!(CARGO_FUZZ_MANIFEST.is_match("[package.metadata]\ncargo-fuzz = true\n"));
+        assert!(!CARGO_FUZZ_MANIFEST.is_match("[dependencies]\nserde = \"1.0\"\n"));
+    }
+
+    #[test]
+    fn go_fuzz_targets_match() {
+        assert!(GO_NATIVE_FUZZ_TARGET.is_match("func FuzzParse(f *testing.F) {"));
+        assert!(!GO_NATIVE_FUZZ_TARGET.is_match("func TestParse(t *testing.T) {"));
+        assert!(GO_FUZZ_TARGET.is_match("func Fuzz(data []byte) int {"));
+        assert!(!GO_FUZZ_TARGET.is_match("func Fuzz(data []byte) error {"));
+    }
+
+    #[test]
+    fn repository_name_extract() {
+        assert_eq!(
+            repository_name("https://github.com/envoyproxy/Envoy.git"),
+            Some("envoy".to_string())
+        );
+        assert_eq!(
+            repository_name("https://github.com/org/repo/"),
+            Some("repo".to_string())
+        );
+        assert_eq!(repository_name(""), None);
+    }
+
+    #[test]
+    fn same_repository_normalized() {
+        assert!(same_repository(
+            "https://github.com/envoyproxy/envoy.git",
+            "https://github.com/EnvoyProxy/envoy/"
+        ));
+        assert!(!same_repository(
+            "https://github.com/other/envoy",
+            "https://github.com/envoyproxy/envoy"
+        ));
+        assert!(!same_repository("", "https://github.com/envoyproxy/envoy"));
+    }
+
+    #[test]
+    fn details_list_integrations() {
+        assert_eq!(
+            details(&[
+                Integration {
+                    name: "OSS-Fuzz",
+                    url: "https://github.com/google/oss-fuzz/tree/master/projects/envoy"
+                        .to_string(),
+                },
+                Integration {
+                    name: "Go fuzzing",
+                    url: "https://github.com/org/repo/blob/main/parse_test.go".to_string(),
+                },
+            ]),
+            "**Fuzzing integrations found**:\n\n- OSS-Fuzz ([link](https://github.com/google/oss-fuzz/tree/master/projects/envoy))\n- Go fuzzing ([link](https://github.com/org/repo/blob/main/parse_test.go))"
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -14,6 +14,15 @@ pub(crate) const WEIGHT: usize = 0;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Enable GitHub discussions in the repository and use them to engage with the community.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#github-discussions",
+};
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     if let Some(latest_discussion) = input
@@ -35,11 +44,17 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::{
-        util::github::md::{MdRepository, MdRepositoryDiscussions, MdRepositoryDiscussionsNodes},
-        LinterInput,
+    use crate::{
+        linter::{
+            util::github::md::{
+                MdRepository, MdRepositoryDiscussions, MdRepositoryDiscussionsNodes,
+            },
+            LinterInput,
+        },
+        net::RestrictedClient,
     };
     use anyhow::format_err;
+    use std::collections::BTreeMap;
 
     #[test]
     fn not_passed_no_discussion_found() {
@@ -52,6 +67,9 @@ mod tests {
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -78,6 +96,9 @@ mod tests {
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -104,6 +125,9 @@ mod tests {
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::passed().url(Some("discussion_url".to_string())),
@@ -0,0 +1,77 @@
+use super::util::{
+    helpers::{find_exemption, should_skip_check},
+    oci::{self, InspectedImage},
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "image_recent_rebuild";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 1;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Rebuild the container images periodically (at least every 90 days) so that they include the latest base image fixes.",
+        "Dependency update tools like Dependabot or Renovate can keep the base images used up to date.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#image-recent-rebuild",
+};
+
+/// Number of days after which an image is not considered recent anymore.
+const MAX_AGE_DAYS: i64 = 90;
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput, images: &[InspectedImage]) -> Option<CheckOutput> {
+    // Check if this check should be skipped
+    if should_skip_check(ID, input.li) || images.is_empty() {
+        return None;
+    }
+
+    // Check if an exemption has been declared for this check
+    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
+        return Some(CheckOutput::from(exemption));
+    }
+
+    // All images rebuilt recently
+    let min_created_at = OffsetDateTime::now_utc() - Duration::days(MAX_AGE_DAYS);
+    Some(oci::images_output(
+        images,
+        &format!("not rebuilt in the last {MAX_AGE_DAYS} days"),
+        |info| is_recent(info.created_at.as_deref(), min_created_at),
+    ))
+}
+
+/// Check if the creation date provided is more recent than the minimum given.
+fn is_recent(created_at: Option<&str>, min_created_at: OffsetDateTime) -> bool {
+    created_at
+        .and_then(|created_at| OffsetDateTime::parse(created_at, &Rfc3339).ok())
+        .map_or(false, |created_at| created_at > min_created_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_image() {
+        let min_created_at = OffsetDateTime::now_utc() - Duration::days(MAX_AGE_DAYS);
+        let one_week_ago = (OffsetDateTime::now_utc() - Duration::days(7))
+            .format(&Rfc3339)
+            .unwrap();
+
+        assert!(is_recent(Some(&one_week_ago), min_created_at));
+        assert!(!is_recent(Some("2020-01-01T00:00:00Z"), min_created_at));
+        assert!(!is_recent(Some("1970-01-01T00:00:00Z"), min_created_at));
+        assert!(!is_recent(None, min_created_at));
+    }
+}
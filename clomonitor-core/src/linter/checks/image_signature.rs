@@ -0,0 +1,150 @@
+use super::util::{
+    helpers::{find_exemption, should_skip_check},
+    oci::{self, InspectedImage},
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "image_signature";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Sign the container images when publishing them (i.e. using cosign sign or notation sign).",
+        "Make sure the signatures are pushed to the same registry as the images.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#image-signature",
+};
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput, images: &[InspectedImage]) -> Option<CheckOutput> {
+    // Check if this check should be skipped
+    if should_skip_check(ID, input.li) || images.is_empty() {
+        return None;
+    }
+
+    // Check if an exemption has been declared for this check
+    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
+        return Some(CheckOutput::from(exemption));
+    }
+
+    // Signatures available for all images
+    Some(oci::images_output(images, "not signed", |info| info.signed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linter::{util::github::md::MdRepository, LinterInput},
+        net::RestrictedClient,
+    };
+    use anyhow::format_err;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const DIGEST: &str = "sha256:0123456789abcdef";
+
+    fn check_input(li: &LinterInput) -> CheckInput {
+        CheckInput {
+            li,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            cache: None,
+            restricted_client: RestrictedClient::default(),
+            prerequisites: BTreeMap::new(),
+        }
+    }
+
+    async fn registry(referrers: serde_json::Value) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/org/image/manifests/v1.0.0"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("docker-content-digest", DIGEST)
+                    .set_body_json(json!({})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/org/image/referrers/sha256:0123456789abcdef"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "manifests": referrers
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    async fn inspected_image(server: &MockServer) -> InspectedImage {
+        let image = format!("{}/org/image:v1.0.0", server.address());
+        let info = oci::inspect(&RestrictedClient::default(), &image, None).await;
+        InspectedImage { image, info }
+    }
+
+    #[tokio::test]
+    async fn passed_image_signed() {
+        let server =
+            registry(json!([{"artifactType": "application/vnd.dev.sigstore.bundle+json"}])).await;
+        let images = [inspected_image(&server).await];
+
+        assert_eq!(
+            check(&check_input(&LinterInput::default()), &images).unwrap(),
+            CheckOutput::passed(),
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_image_not_signed() {
+        let server = registry(json!([])).await;
+        let images = [inspected_image(&server).await];
+
+        assert_eq!(
+            check(&check_input(&LinterInput::default()), &images).unwrap(),
+            CheckOutput::not_passed()
+                .details(Some(format!("Images not signed:\n\n· {}", images[0].image))),
+        );
+    }
+
+    #[test]
+    fn no_images_declared() {
+        assert_eq!(check(&check_input(&LinterInput::default()), &[]), None);
+    }
+
+    #[tokio::test]
+    async fn failed_registry_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+        let images = [inspected_image(&server).await];
+
+        let output = check(&check_input(&LinterInput::default()), &images).unwrap();
+        assert!(output.failed);
+        assert!(output
+            .fail_reason
+            .unwrap()
+            .starts_with("None of the images could be inspected"));
+    }
+}
@@ -1,6 +1,6 @@
 use super::util::helpers::{find_exemption, should_skip_check};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 
@@ -11,7 +11,22 @@ pub(crate) const ID: CheckId = "license_approved";
 pub(crate) const WEIGHT: usize = 10;
 
 /// Check sets this check belongs to.
-pub(crate) const CHECK_SETS: [CheckSet; 3] = [CheckSet::Code, CheckSet::CodeLite, CheckSet::Docs];
+pub(crate) const CHECK_SETS: [CheckSet; 4] = [
+    CheckSet::Code,
+    CheckSet::CodeLite,
+    CheckSet::Docs,
+    CheckSet::DocsSite,
+];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "License the project under one of the approved licenses (i.e. Apache-2.0).",
+        "Make sure the license file is located at the root of the repository so it can be detected.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#approved-license",
+};
 
 /// CNCF approved licenses.
 /// https://github.com/cncf/foundation/blob/master/allowed-third-party-license-policy.md
@@ -32,7 +47,7 @@ static APPROVED_LICENSES: [&str; 11] = [
 /// Check main function.
 pub(crate) fn check(input: &CheckInput, spdx_id: Option<String>) -> Option<CheckOutput> {
     // Check if this check should be skipped
-    if should_skip_check(ID, &input.li.check_sets) {
+    if should_skip_check(ID, input.li) {
         return None;
     }
 
@@ -0,0 +1,280 @@
+use super::{
+    license_spdx_id,
+    util::{
+        helpers::{find_exemption, should_skip_check},
+        path::{self, Globs},
+    },
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+use std::path::PathBuf;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "license_compatibility";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Replace the vendored dependencies listed in the check details with alternatives licensed under terms compatible with the project's license.",
+        "If a dependency has been relicensed or it is used in a way that does not affect the project's license, declare an exemption in the .clomonitor.yml metadata file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#license-compatibility",
+};
+
+/// Patterns used to locate the license files of the vendored dependencies.
+const VENDORED_PATTERNS: [&str; 6] = [
+    "vendor/**/LICENSE*",
+    "vendor/**/COPYING*",
+    "third_party/**/LICENSE*",
+    "third_party/**/COPYING*",
+    "third-party/**/LICENSE*",
+    "third-party/**/COPYING*",
+];
+
+/// Permissive licenses. They can be embedded in projects using any license,
+/// with the exception of Apache-2.0 in GPL-2.0 projects.
+static PERMISSIVE_LICENSES: [&str; 13] = [
+    "0BSD",
+    "Apache-2.0",
+    "BSD-2-Clause",
+    "BSD-2-Clause-FreeBSD",
+    "BSD-3-Clause",
+    "BSL-1.0",
+    "CC0-1.0",
+    "ISC",
+    "MIT",
+    "PostgreSQL",
+    "Unlicense",
+    "X11",
+    "Zlib",
+];
+
+/// Weak copyleft licenses. They can be embedded in projects using any
+/// license, with the exception of EPL and CDDL in GPL projects.
+static WEAK_COPYLEFT_LICENSES: [&str; 8] = [
+    "CDDL-1.0",
+    "CDDL-1.1",
+    "EPL-1.0",
+    "EPL-2.0",
+    "LGPL-2.1",
+    "LGPL-3.0",
+    "MPL-2.0",
+    "MPL-2.0-no-copyleft-exception",
+];
+
+/// License file of a vendored dependency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VendoredLicense {
+    path: PathBuf,
+    spdx_id: Option<String>,
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput, spdx_id: Option<String>) -> Option<CheckOutput> {
+    // Check if this check should be skipped
+    if should_skip_check(ID, input.li) {
+        return None;
+    }
+
+    // Check if an exemption has been declared for this check
+    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
+        return Some(CheckOutput::from(exemption));
+    }
+
+    // Licenses of the vendored dependencies
+    let root = &input.li.root;
+    let vendored = match vendored_licenses(&Globs {
+        root,
+        patterns: &VENDORED_PATTERNS,
+        case_sensitive: true,
+    }) {
+        Ok(vendored) => vendored,
+        Err(err) => return Some(CheckOutput::failed().fail_reason(Some(err.to_string()))),
+    };
+    if vendored.is_empty() {
+        return Some(CheckOutput::passed());
+    }
+    let list = |licenses: &[&VendoredLicense]| {
+        licenses
+            .iter()
+            .map(|l| {
+                let path = l.path.strip_prefix(root).unwrap_or(&l.path);
+                let spdx_id = l.spdx_id.as_deref().unwrap_or("unidentified");
+                format!("· {} ({spdx_id})", path.display())
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    // Project's license must be known to check the vendored ones against it
+    let Some(spdx_id) = spdx_id else {
+        let vendored: Vec<&VendoredLicense> = vendored.iter().collect();
+        return Some(CheckOutput::not_passed().details(Some(format!(
+            "Project's license could not be detected, vendored dependencies licenses:\n\n{}",
+            list(&vendored)
+        ))));
+    };
+
+    // Vendored licenses not compatible with the project's one
+    let incompatible: Vec<&VendoredLicense> = vendored
+        .iter()
+        .filter(|l| match &l.spdx_id {
+            Some(dep_spdx_id) => !is_compatible(dep_spdx_id, &spdx_id),
+            None => false,
+        })
+        .collect();
+    if !incompatible.is_empty() {
+        return Some(CheckOutput::not_passed().details(Some(format!(
+            "Vendored dependencies with licenses not compatible with {spdx_id}:\n\n{}",
+            list(&incompatible)
+        ))));
+    }
+
+    // Vendored licenses that could not be identified are reported, but they
+    // don't make the check fail
+    let unidentified: Vec<&VendoredLicense> =
+        vendored.iter().filter(|l| l.spdx_id.is_none()).collect();
+    if !unidentified.is_empty() {
+        return Some(CheckOutput::passed().details(Some(format!(
+            "Vendored dependencies licenses that could not be identified:\n\n{}",
+            list(&unidentified)
+        ))));
+    }
+
+    Some(CheckOutput::passed())
+}
+
+/// Detect the licenses of the vendored dependencies matching the globs
+/// provided.
+fn vendored_licenses(globs: &Globs) -> Result<Vec<VendoredLicense>> {
+    let mut licenses: Vec<VendoredLicense> = path::matches(globs)?
+        .into_iter()
+        .filter(|path| path.is_file())
+        .map(|path| VendoredLicense {
+            spdx_id: license_spdx_id::detect_file(&path),
+            path,
+        })
+        .collect();
+    licenses.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(licenses)
+}
+
+/// Check if a dependency licensed under `dep_spdx_id` can be embedded in a
+/// project licensed under `project_spdx_id`.
+fn is_compatible(dep_spdx_id: &str, project_spdx_id: &str) -> bool {
+    let dep = normalize(dep_spdx_id);
+    let project = normalize(project_spdx_id);
+    if dep == project {
+        return true;
+    }
+
+    // Permissive licenses
+    if PERMISSIVE_LICENSES.contains(&dep) {
+        return !(dep == "Apache-2.0" && project == "GPL-2.0");
+    }
+
+    // Weak copyleft licenses
+    if WEAK_COPYLEFT_LICENSES.contains(&dep) {
+        let is_gpl = project.starts_with("GPL-") || project.starts_with("AGPL-");
+        return !(is_gpl && (dep.starts_with("EPL-") || dep.starts_with("CDDL-")));
+    }
+
+    // Strong copyleft licenses
+    match dep {
+        "GPL-2.0+" => matches!(project, "GPL-2.0" | "GPL-3.0" | "GPL-3.0+" | "AGPL-3.0"),
+        "GPL-3.0" | "GPL-3.0+" => matches!(project, "GPL-3.0" | "GPL-3.0+" | "AGPL-3.0"),
+        "AGPL-3.0" => false,
+        // Any other license (i.e. proprietary or unknown) is considered
+        // compatible, as it cannot be reasoned about
+        _ => !dep.starts_with("GPL-") && !dep.starts_with("AGPL-"),
+    }
+}
+
+/// Normalize the SPDX id provided, so that the deprecated and current ids of
+/// the same license can be compared (i.e. GPL-3.0 and GPL-3.0-only).
+fn normalize(spdx_id: &str) -> &str {
+    if let Some(spdx_id) = spdx_id.strip_suffix("-only") {
+        return spdx_id;
+    }
+    if let Some(spdx_id) = spdx_id.strip_suffix("-or-later") {
+        return match spdx_id {
+            "GPL-2.0" => "GPL-2.0+",
+            "GPL-3.0" => "GPL-3.0+",
+            _ => spdx_id,
+        };
+    }
+    spdx_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const TESTDATA_PATH: &str = "src/testdata/vendored";
+
+    #[test]
+    fn vendored_licenses_detected() {
+        let root = Path::new(TESTDATA_PATH);
+        let licenses = vendored_licenses(&Globs {
+            root,
+            patterns: &VENDORED_PATTERNS,
+            case_sensitive: true,
+        })
+        .unwrap();
+
+        assert_eq!(
+            licenses
+                .iter()
+                .map(|l| l.path.strip_prefix(root).unwrap().to_owned())
+                .collect::<Vec<PathBuf>>(),
+            vec![
+                PathBuf::from("third_party/mit-dep/LICENSE"),
+                PathBuf::from("vendor/github.com/org/apache-dep/LICENSE"),
+                PathBuf::from("vendor/github.com/org/gpl-dep/LICENSE"),
+            ]
+        );
+        assert_eq!(licenses[0].spdx_id.as_deref(), Some("MIT"));
+        assert_eq!(licenses[1].spdx_id.as_deref(), Some("Apache-2.0"));
+        assert!(licenses[2]
+            .spdx_id
+            .as_deref()
+            .map_or(false, |spdx_id| spdx_id.starts_with("GPL-3.0")));
+    }
+
+    #[test]
+    fn permissive_licenses_compatible() {
+        assert!(is_compatible("MIT", "Apache-2.0"));
+        assert!(is_compatible("BSD-3-Clause", "GPL-3.0-only"));
+        assert!(is_compatible("Apache-2.0", "GPL-3.0-or-later"));
+        assert!(!is_compatible("Apache-2.0", "GPL-2.0-only"));
+    }
+
+    #[test]
+    fn weak_copyleft_licenses_compatible() {
+        assert!(is_compatible("MPL-2.0", "Apache-2.0"));
+        assert!(is_compatible("LGPL-2.1-only", "MIT"));
+        assert!(!is_compatible("EPL-2.0", "GPL-3.0"));
+        assert!(!is_compatible("CDDL-1.0", "GPL-2.0-only"));
+    }
+
+    #[test]
+    fn strong_copyleft_licenses_compatible() {
+        assert!(is_compatible("GPL-3.0", "GPL-3.0-only"));
+        assert!(is_compatible("GPL-2.0-or-later", "GPL-3.0-only"));
+        assert!(is_compatible("GPL-3.0-only", "AGPL-3.0-only"));
+        assert!(!is_compatible("GPL-3.0-only", "Apache-2.0"));
+        assert!(!is_compatible("GPL-2.0-only", "GPL-3.0-only"));
+        assert!(!is_compatible("AGPL-3.0-only", "GPL-3.0-only"));
+    }
+}
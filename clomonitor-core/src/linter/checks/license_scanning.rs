@@ -1,6 +1,6 @@
 use super::util::{content, helpers::readme_globs};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,6 +16,16 @@ pub(crate) const WEIGHT: usize = 5;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Set up license scanning using FOSSA or Snyk.",
+        "Add the link to the scanning results to the README file or to the .clomonitor.yml metadata file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#license-scanning",
+};
+
 lazy_static! {
     #[rustfmt::skip]
     pub(crate) static ref FOSSA_URL: Regex = Regex::new(
@@ -51,12 +61,16 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::{
-        metadata::{LicenseScanning, Metadata},
-        util::github::md::MdRepository,
-        LinterInput,
+    use crate::{
+        linter::{
+            metadata::{LicenseScanning, Metadata},
+            util::github::md::MdRepository,
+            LinterInput,
+        },
+        net::RestrictedClient,
     };
     use anyhow::format_err;
+    use std::collections::BTreeMap;
 
     #[test]
     fn not_passed_no_md_found() {
@@ -66,6 +80,9 @@ mod tests {
                 cm_md: None,
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -80,9 +97,13 @@ mod tests {
                 cm_md: Some(Metadata {
                     exemptions: None,
                     license_scanning: None,
+                    locales: None,
                 }),
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -99,9 +120,13 @@ mod tests {
                     license_scanning: Some(LicenseScanning {
                         url: Some("license_scanning_url".to_string()),
                     }),
+                    locales: None,
                 }),
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::passed().url(Some("license_scanning_url".to_string())),
@@ -1,11 +1,11 @@
 use super::util::path::Globs;
-use crate::linter::check::{CheckId, CheckInput, CheckOutput};
-use crate::linter::checks::util::path;
+use crate::linter::check::{CheckId, CheckInput, CheckOutput, Remediation};
+use crate::linter::checks::util::{content_cache, path};
 use crate::linter::CheckSet;
 use anyhow::Result;
 use askalono::*;
 use lazy_static::lazy_static;
-use std::fs;
+use std::{fs, path::Path};
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "license_spdx_id";
@@ -14,7 +14,19 @@ pub(crate) const ID: CheckId = "license_spdx_id";
 pub(crate) const WEIGHT: usize = 5;
 
 /// Check sets this check belongs to.
-pub(crate) const CHECK_SETS: [CheckSet; 3] = [CheckSet::Code, CheckSet::CodeLite, CheckSet::Docs];
+pub(crate) const CHECK_SETS: [CheckSet; 4] = [
+    CheckSet::Code,
+    CheckSet::CodeLite,
+    CheckSet::Docs,
+    CheckSet::DocsSite,
+];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &["Add a LICENSE file with the full text of the license to the root of the repository."],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#spdx-id",
+};
 
 /// SPDX licenses data. Used to detect license used by repositories.
 const LICENSES_DATA: &[u8] = include_bytes!("data/licenses.bin.zstd");
@@ -50,29 +62,28 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput<String>> {
 
 /// Detect repository's license and return its SPDX id if possible.
 pub(crate) fn detect(globs: &Globs) -> Result<Option<String>> {
+    Ok(path::matches(globs)?
+        .iter()
+        .find_map(|path| detect_file(path)))
+}
+
+/// Detect the license in the file provided and return its SPDX id if possible.
+pub(crate) fn detect_file(path: &Path) -> Option<String> {
     lazy_static! {
         static ref LICENSES: Store =
             Store::from_cache(LICENSES_DATA).expect("valid licenses data file present");
     }
-    let mut spdx_id: Option<String> = None;
-    path::matches(globs)?.iter().any(|path| {
-        if let Ok(content) = fs::read_to_string(path) {
-            let m = LICENSES.analyze(&TextData::from(content));
-            if m.score > 0.9 {
-                spdx_id = Some(m.name.to_string());
-                return true;
-            }
-        }
-        false
-    });
-    Ok(spdx_id)
+    let content = fs::read_to_string(path).ok()?;
+    content_cache::analyze(ID, &content, |content| {
+        let m = LICENSES.analyze(&TextData::from(content));
+        (m.score > 0.9).then(|| m.name.to_string())
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::linter::checks::license_spdx_id;
-    use std::path::Path;
 
     const TESTDATA_PATH: &str = "src/testdata";
 
@@ -1,6 +1,6 @@
-use super::util::scorecard;
+use super::util::{gerrit, scorecard};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -14,7 +14,34 @@ pub(crate) const WEIGHT: usize = 3;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Keep the project active: merge changes, respond to issues and publish releases regularly.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#maintained-from-openssf-scorecard",
+};
+
+/// Number of days without changes merged on Gerrit after which a project is
+/// not considered maintained (same period used by the OpenSSF Scorecard).
+const GERRIT_MAX_AGE_DAYS: usize = 90;
+
 /// Check main function.
-pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // Repositories mirrored from Gerrit are developed there, so the activity
+    // is looked up in the canonical host instead
+    if let Some(gerrit_url) = &input.li.gerrit_url {
+        let changes = gerrit::merged_changes(gerrit_url, GERRIT_MAX_AGE_DAYS, 1).await?;
+        if changes.is_empty() {
+            return Ok(CheckOutput::not_passed()
+                .url(Some(gerrit_url.clone()))
+                .details(Some(format!(
+                    "No changes merged in the last {GERRIT_MAX_AGE_DAYS} days found on Gerrit"
+                ))));
+        }
+        return Ok(CheckOutput::passed().url(Some(gerrit_url.clone())));
+    }
+
     Ok(scorecard::get_check(&input.scorecard, ID).into())
 }
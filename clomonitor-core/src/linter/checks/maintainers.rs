@@ -1,6 +1,9 @@
-use super::util::helpers::find_file_or_readme_ref;
+use super::util::{
+    helpers::find_file_or_readme_ref,
+    locale::{find_translated_file_or_readme_ref, Document},
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,14 +19,28 @@ pub(crate) const WEIGHT: usize = 3;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a MAINTAINERS.md (or CODEOWNERS) file to the repository listing the current maintainers.",
+        "Alternatively, add a Maintainers section to the README file.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "MAINTAINERS.md",
+        content: include_str!("data/templates/MAINTAINERS.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#maintainers",
+};
+
 /// Patterns used to locate a file in the repository.
-pub(crate) static FILE_PATTERNS: [&str; 7] = [
+pub(crate) static FILE_PATTERNS: [&str; 8] = [
     "maintainers*",
     "docs/maintainers*",
     "owners*",
     "docs/owners*",
     "codeowners*",
     ".github/codeowners*",
+    ".gitea/codeowners*",
     "docs/codeowners*",
 ];
 
@@ -39,7 +56,17 @@ lazy_static! {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo or reference in README file
-    find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)
+    let r = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
+    if r.passed {
+        return Ok(r);
+    }
+
+    // Translated file in repo or reference in README file
+    if let Some(r) = find_translated_file_or_readme_ref(input, Document::Maintainers)? {
+        return Ok(r);
+    }
+
+    Ok(CheckOutput::not_passed())
 }
 
 #[cfg(test)]
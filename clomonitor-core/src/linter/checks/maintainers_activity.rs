@@ -0,0 +1,343 @@
+use super::{
+    maintainers,
+    util::{
+        gerrit, github,
+        helpers::build_file_url,
+        path::{self, Globs},
+    },
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use std::{fs, path::Path};
+use time::{Duration, OffsetDateTime};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "maintainers_activity";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::Community];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "List the maintainers in the MAINTAINERS, OWNERS or CODEOWNERS file using their GitHub handles (i.e. @janedoe).",
+        "Move the maintainers that are no longer active in the repository to an emeritus section, or remove them from the file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#maintainers-activity",
+};
+
+/// Number of days without commits or reviews after which a maintainer is
+/// considered stale (~6 months).
+const MAX_INACTIVITY_DAYS: i64 = 180;
+
+/// Maximum number of maintainers whose activity will be looked up.
+const MAX_MAINTAINERS: usize = 30;
+
+lazy_static! {
+    static ref GITHUB_HANDLE: Regex =
+        Regex::new(r"(?:^|[\s(\[|,:])@([A-Za-z0-9][A-Za-z0-9-]{0,38})(/[A-Za-z0-9_.-]+)?")
+            .expect("exprs in GITHUB_HANDLE to be valid");
+    static ref GITHUB_PROFILE_URL: Regex =
+        Regex::new(r"https?://github\.com/([A-Za-z0-9][A-Za-z0-9-]{0,38})/?(?:[\s)\]|>]|$)")
+            .expect("exprs in GITHUB_PROFILE_URL to be valid");
+    static ref EMERITUS_HEADING: Regex =
+        Regex::new(r"(?im)^#+.*(emeritus|alumni|former|inactive).*$")
+            .expect("exprs in EMERITUS_HEADING to be valid");
+    static ref CODEOWNERS_OWNER: Regex =
+        Regex::new(r"^(@[A-Za-z0-9](?:[A-Za-z0-9-]{0,38})(?:/[A-Za-z0-9_.-]+)?|[^@\s]+@[^@\s]+)$")
+            .expect("exprs in CODEOWNERS_OWNER to be valid");
+}
+
+/// Maintainers listed in an OWNERS file (Kubernetes format).
+#[derive(Debug, Default, Deserialize)]
+struct Owners {
+    #[serde(default)]
+    approvers: Vec<String>,
+    #[serde(default)]
+    reviewers: Vec<String>,
+}
+
+/// Maintainers found in a maintainers file.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Maintainers {
+    /// GitHub handles of the maintainers (lowercase, without duplicates).
+    handles: Vec<String>,
+    /// Lines that don't follow the file's format.
+    invalid_lines: Vec<usize>,
+}
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // Maintainers file in repo
+    let Some(path) = path::find(&Globs {
+        root: &input.li.root,
+        patterns: &maintainers::FILE_PATTERNS,
+        case_sensitive: false,
+    })?
+    else {
+        return Ok(CheckOutput::not_passed().details(Some("No maintainers file found".to_string())));
+    };
+    let url = build_file_url(input, &path);
+    let content = fs::read_to_string(input.li.root.join(&path))
+        .context(format!("error reading {}", path.display()))?;
+    let maintainers = parse(&path, &content);
+
+    // Validate the file's format
+    let mut details = vec![];
+    if !maintainers.invalid_lines.is_empty() {
+        details.push(format!(
+            "Lines not following the expected format: {}",
+            maintainers
+                .invalid_lines
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+    }
+    if maintainers.handles.is_empty() {
+        details.push("No GitHub handles found in the maintainers file".to_string());
+        return Ok(CheckOutput::not_passed()
+            .url(Some(url))
+            .details(Some(details.join("\n\n"))));
+    }
+
+    // Look for the maintainers' recent activity in the repository
+    let since = (OffsetDateTime::now_utc() - Duration::days(MAX_INACTIVITY_DAYS))
+        .date()
+        .to_string();
+    let handles: Vec<&String> = maintainers.handles.iter().take(MAX_MAINTAINERS).collect();
+    let mut stale = vec![];
+    for handle in &handles {
+        // Repositories mirrored from Gerrit are reviewed there, so the
+        // handles are looked up as Gerrit usernames in the canonical host
+        let active = match &input.li.gerrit_url {
+            Some(gerrit_url) => gerrit::user_active_since(gerrit_url, handle, &since).await?,
+            None => {
+                github::user_active_since(&input.li.url, handle, &since, &input.li.github_token)
+                    .await?
+            }
+        };
+        if !active {
+            stale.push(*handle);
+        }
+    }
+
+    // Build check output
+    if !stale.is_empty() {
+        details.push(format!(
+            "Maintainers without commits or reviews in the last 6 months:\n\n{}",
+            stale
+                .iter()
+                .map(|handle| format!("- @{handle}"))
+                .collect::<Vec<String>>()
+                .join("\n")
+        ));
+    }
+    let details = if details.is_empty() {
+        None
+    } else {
+        Some(details.join("\n\n"))
+    };
+    if stale.len() == handles.len() {
+        return Ok(CheckOutput::not_passed().url(Some(url)).details(details));
+    }
+    Ok(CheckOutput::passed()
+        .url(Some(url))
+        .details(details)
+        .credit(Some(credit(handles.len(), stale.len()))))
+}
+
+/// Parse the content of the maintainers file provided, based on its name.
+fn parse(path: &Path, content: &str) -> Maintainers {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let mut maintainers = if file_name.starts_with("codeowners") {
+        parse_codeowners(content)
+    } else if file_name == "owners" || file_name == "owners.yaml" || file_name == "owners.yml" {
+        parse_owners(content)
+    } else {
+        parse_markdown(content)
+    };
+
+    let mut handles: Vec<String> = vec![];
+    for handle in maintainers.handles.drain(..).map(|h| h.to_lowercase()) {
+        if !handles.contains(&handle) {
+            handles.push(handle);
+        }
+    }
+    maintainers.handles = handles;
+    maintainers
+}
+
+/// Parse a CODEOWNERS file. Each line must contain a pattern followed by the
+/// owners of the matching files (users, teams or emails). Only users' handles
+/// are extracted, as teams' members cannot be looked up.
+fn parse_codeowners(content: &str) -> Maintainers {
+    let mut maintainers = Maintainers::default();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let owners: Vec<&str> = line.split_whitespace().skip(1).collect();
+        if owners.iter().any(|owner| !CODEOWNERS_OWNER.is_match(owner)) {
+            maintainers.invalid_lines.push(i + 1);
+            continue;
+        }
+        for owner in owners {
+            if let Some(handle) = owner.strip_prefix('@') {
+                if !handle.contains('/') {
+                    maintainers.handles.push(handle.to_string());
+                }
+            }
+        }
+    }
+    maintainers
+}
+
+/// Parse an OWNERS file (YAML document listing the approvers and reviewers).
+fn parse_owners(content: &str) -> Maintainers {
+    match serde_yaml::from_str::<Owners>(content) {
+        Ok(owners) => Maintainers {
+            handles: owners
+                .approvers
+                .into_iter()
+                .chain(owners.reviewers)
+                .map(|handle| handle.trim_start_matches('@').to_string())
+                .collect(),
+            invalid_lines: vec![],
+        },
+        Err(err) => Maintainers {
+            handles: vec![],
+            invalid_lines: vec![err.location().map_or(1, |location| location.line())],
+        },
+    }
+}
+
+/// Parse a markdown (or plain text) maintainers file, extracting the GitHub
+/// handles and profiles urls found. Maintainers listed in an emeritus section
+/// are ignored.
+fn parse_markdown(content: &str) -> Maintainers {
+    let content = match EMERITUS_HEADING.find(content) {
+        Some(m) => &content[..m.start()],
+        None => content,
+    };
+    let mut maintainers = Maintainers::default();
+    for line in content.lines() {
+        for re in [&*GITHUB_HANDLE, &*GITHUB_PROFILE_URL] {
+            for c in re.captures_iter(line) {
+                // Teams (@org/team) are ignored
+                if c.get(2).is_none() {
+                    maintainers.handles.push(c[1].to_string());
+                }
+            }
+        }
+    }
+    maintainers
+}
+
+/// Return the credit awarded based on the proportion of active maintainers.
+fn credit(total: usize, stale: usize) -> usize {
+    (total - stale) * 100 / total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_codeowners_file() {
+        let content = "
+# Default owners
+*       @Org/maintainers @janedoe
+/docs/  @johndoe docs@example.com
+/src/   @JaneDoe
+/api/   janedoe
+";
+        assert_eq!(
+            parse(Path::new(".github/CODEOWNERS"), content),
+            Maintainers {
+                handles: vec!["janedoe".to_string(), "johndoe".to_string()],
+                invalid_lines: vec![6],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_owners_file() {
+        let content = "
+approvers:
+  - janedoe
+  - johndoe
+reviewers:
+  - janedoe
+  - alice
+";
+        assert_eq!(
+            parse(Path::new("OWNERS"), content),
+            Maintainers {
+                handles: vec![
+                    "janedoe".to_string(),
+                    "johndoe".to_string(),
+                    "alice".to_string()
+                ],
+                invalid_lines: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_invalid_owners_file() {
+        let maintainers = parse(Path::new("OWNERS"), "approvers: [janedoe\n");
+        assert!(maintainers.handles.is_empty());
+        assert_eq!(maintainers.invalid_lines.len(), 1);
+    }
+
+    #[test]
+    fn parse_markdown_file() {
+        let content = "
+# Maintainers
+
+| Name | GitHub | Organization |
+| ---- | ------ | ------------ |
+| Jane Doe | @janedoe | Example |
+| John Doe | [johndoe](https://github.com/johndoe) | Example |
+| Alice | @alice (alice@example.com) | Example |
+
+## Emeritus maintainers
+
+- Bob (@bob)
+";
+        assert_eq!(
+            parse(Path::new("MAINTAINERS.md"), content),
+            Maintainers {
+                handles: vec![
+                    "janedoe".to_string(),
+                    "johndoe".to_string(),
+                    "alice".to_string()
+                ],
+                invalid_lines: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn credit_awarded() {
+        assert_eq!(credit(4, 0), 100);
+        assert_eq!(credit(4, 1), 75);
+        assert_eq!(credit(3, 2), 33);
+    }
+}
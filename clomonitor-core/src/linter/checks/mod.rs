@@ -1,11 +1,17 @@
-use crate::linter::check::{CheckConfig, CheckId};
+use crate::linter::{
+    check::{CheckConfig, CheckId},
+    checks::util::scorecard::GITHUB_SCOPES as SCORECARD_GITHUB_SCOPES,
+    ProviderSupport, Severity,
+};
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+pub(crate) mod accessibility_statement;
 pub(crate) mod adopters;
 pub(crate) mod analytics;
 pub(crate) mod artifacthub_badge;
 pub(crate) mod binary_artifacts;
+pub(crate) mod branch_protection;
 pub(crate) mod changelog;
 pub(crate) mod cla;
 pub(crate) mod code_of_conduct;
@@ -14,25 +20,42 @@ pub(crate) mod community_meeting;
 pub(crate) mod contributing;
 pub(crate) mod dangerous_workflow;
 pub(crate) mod dco;
+pub(crate) mod dco_or_cla;
+pub(crate) mod dependency_update_automation;
 pub(crate) mod dependency_update_tool;
+pub(crate) mod fuzzing;
 pub(crate) mod github_discussions;
 pub(crate) mod governance;
+pub(crate) mod image_recent_rebuild;
+pub(crate) mod image_sbom;
+pub(crate) mod image_signature;
 pub(crate) mod license_approved;
+pub(crate) mod license_compatibility;
 pub(crate) mod license_scanning;
 pub(crate) mod license_spdx_id;
 pub(crate) mod maintained;
 pub(crate) mod maintainers;
+pub(crate) mod maintainers_activity;
 pub(crate) mod openssf_badge;
+pub(crate) mod org_default_permissions;
+pub(crate) mod org_secret_scanning;
+pub(crate) mod org_two_factor_auth;
+pub(crate) mod privacy_policy;
 pub(crate) mod readme;
 pub(crate) mod recent_release;
+pub(crate) mod release_notes;
 pub(crate) mod roadmap;
 pub(crate) mod sbom;
+pub(crate) mod security_insights;
 pub(crate) mod security_policy;
 pub(crate) mod signed_releases;
 pub(crate) mod slack_presence;
+pub(crate) mod slsa_provenance;
+pub(crate) mod supported_versions;
 pub(crate) mod token_permissions;
 pub(crate) mod trademark_disclaimer;
 pub(crate) mod util;
+pub(crate) mod vulnerability_disclosure;
 pub(crate) mod website;
 
 lazy_static! {
@@ -45,8 +68,14 @@ lazy_static! {
                     $check::ID,
                     CheckConfig {
                         weight: $check::WEIGHT,
+                        severity: Severity::Recommended,
                         check_sets: $check::CHECK_SETS.to_vec(),
                         scorecard_name: None,
+                        github_scopes: vec![],
+                        provider_support: ProviderSupport::Full,
+                        gitea_support: ProviderSupport::Full,
+                        remediation: $check::REMEDIATION,
+                        depends_on: vec![],
                     },
                 );
             };
@@ -55,17 +84,25 @@ lazy_static! {
                     $check::ID,
                     CheckConfig {
                         weight: $check::WEIGHT,
+                        severity: Severity::Recommended,
                         check_sets: $check::CHECK_SETS.to_vec(),
                         scorecard_name: Some($scorecard_name.to_string()),
+                        github_scopes: SCORECARD_GITHUB_SCOPES.to_vec(),
+                        provider_support: ProviderSupport::Unsupported,
+                        gitea_support: ProviderSupport::Unsupported,
+                        remediation: $check::REMEDIATION,
+                        depends_on: vec![],
                     },
                 );
             };
         }
 
+        register_check!(accessibility_statement);
         register_check!(adopters);
         register_check!(analytics);
         register_check!(artifacthub_badge);
         register_check!(binary_artifacts, "Binary-Artifacts");
+        register_check!(branch_protection);
         register_check!(changelog);
         register_check!(cla);
         register_check!(code_of_conduct);
@@ -74,26 +111,132 @@ lazy_static! {
         register_check!(contributing);
         register_check!(dangerous_workflow, "Dangerous-Workflow");
         register_check!(dco);
+        register_check!(dco_or_cla);
+        register_check!(dependency_update_automation);
         register_check!(dependency_update_tool, "Dependency-Update-Tool");
+        register_check!(fuzzing);
         register_check!(github_discussions);
         register_check!(governance);
+        register_check!(image_recent_rebuild);
+        register_check!(image_sbom);
+        register_check!(image_signature);
         register_check!(license_approved);
+        register_check!(license_compatibility);
         register_check!(license_scanning);
         register_check!(license_spdx_id);
         register_check!(maintained, "Maintained");
         register_check!(maintainers);
+        register_check!(maintainers_activity);
         register_check!(openssf_badge);
+        register_check!(org_default_permissions);
+        register_check!(org_secret_scanning);
+        register_check!(org_two_factor_auth);
+        register_check!(privacy_policy);
         register_check!(readme);
         register_check!(recent_release);
+        register_check!(release_notes);
         register_check!(roadmap);
         register_check!(sbom);
+        register_check!(security_insights);
         register_check!(security_policy);
         register_check!(signed_releases, "Signed-Releases");
+        register_check!(slsa_provenance);
         register_check!(slack_presence);
+        register_check!(supported_versions);
         register_check!(token_permissions, "Token-Permissions");
         register_check!(trademark_disclaimer);
+        register_check!(vulnerability_disclosure);
         register_check!(website);
 
+        // Support level on providers other than GitHub of the checks that rely
+        // on the GitHub API (checks not listed here are fully supported)
+        for (check_id, provider_support) in [
+            (accessibility_statement::ID, ProviderSupport::Partial),
+            (analytics::ID, ProviderSupport::Partial),
+            (branch_protection::ID, ProviderSupport::Unsupported),
+            (changelog::ID, ProviderSupport::Partial),
+            (cla::ID, ProviderSupport::Unsupported),
+            (code_of_conduct::ID, ProviderSupport::Partial),
+            (contributing::ID, ProviderSupport::Partial),
+            (dco::ID, ProviderSupport::Partial),
+            (dco_or_cla::ID, ProviderSupport::Partial),
+            (github_discussions::ID, ProviderSupport::Unsupported),
+            (license_spdx_id::ID, ProviderSupport::Partial),
+            (maintainers_activity::ID, ProviderSupport::Unsupported),
+            (org_default_permissions::ID, ProviderSupport::Unsupported),
+            (org_secret_scanning::ID, ProviderSupport::Unsupported),
+            (org_two_factor_auth::ID, ProviderSupport::Unsupported),
+            (privacy_policy::ID, ProviderSupport::Partial),
+            (recent_release::ID, ProviderSupport::Unsupported),
+            (release_notes::ID, ProviderSupport::Unsupported),
+            (sbom::ID, ProviderSupport::Partial),
+            (security_policy::ID, ProviderSupport::Partial),
+            (slsa_provenance::ID, ProviderSupport::Partial),
+            (vulnerability_disclosure::ID, ProviderSupport::Unsupported),
+            (website::ID, ProviderSupport::Unsupported),
+        ] {
+            if let Some(check) = checks.get_mut(check_id) {
+                check.provider_support = provider_support;
+                check.gitea_support = provider_support;
+            }
+        }
+
+        // Default severity of the checks (checks not listed here are
+        // recommended)
+        for (check_id, severity) in [
+            (accessibility_statement::ID, Severity::Optional),
+            (adopters::ID, Severity::Optional),
+            (analytics::ID, Severity::Optional),
+            (artifacthub_badge::ID, Severity::Optional),
+            (code_of_conduct::ID, Severity::Required),
+            (community_meeting::ID, Severity::Optional),
+            (contributing::ID, Severity::Required),
+            (fuzzing::ID, Severity::Optional),
+            (github_discussions::ID, Severity::Optional),
+            (license_approved::ID, Severity::Required),
+            (license_spdx_id::ID, Severity::Required),
+            (maintainers::ID, Severity::Required),
+            (readme::ID, Severity::Required),
+            (roadmap::ID, Severity::Optional),
+            (security_policy::ID, Severity::Required),
+            (slack_presence::ID, Severity::Optional),
+            (vulnerability_disclosure::ID, Severity::Optional),
+        ] {
+            if let Some(check) = checks.get_mut(check_id) {
+                check.severity = severity;
+            }
+        }
+
+        // Checks that rely on the GitHub API whose data (releases, homepage
+        // and license) is available from the Gitea API as well
+        for check_id in [
+            accessibility_statement::ID,
+            analytics::ID,
+            changelog::ID,
+            license_spdx_id::ID,
+            privacy_policy::ID,
+            recent_release::ID,
+            release_notes::ID,
+            sbom::ID,
+            website::ID,
+        ] {
+            if let Some(check) = checks.get_mut(check_id) {
+                check.gitea_support = ProviderSupport::Full;
+            }
+        }
+
+        // Checks that only make sense when the ones they depend on pass. The
+        // checks depended on must be run before the async checks (see
+        // CheckInput::record_prerequisite)
+        for (check_id, depends_on) in [
+            (maintainers_activity::ID, vec![maintainers::ID]),
+            (release_notes::ID, vec![recent_release::ID]),
+        ] {
+            if let Some(check) = checks.get_mut(check_id) {
+                check.depends_on = depends_on;
+            }
+        }
+
         checks
     };
 }
@@ -1,11 +1,18 @@
 use super::util::helpers::readme_capture;
-use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
-    CheckSet,
+use crate::{
+    cache::{self, DynCache},
+    linter::{
+        check::{CheckId, CheckInput, CheckOutput, Remediation},
+        CheckSet,
+    },
+    net,
 };
-use anyhow::Result;
+use anyhow::{format_err, Context, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::time::Duration;
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "openssf_badge";
@@ -16,21 +23,126 @@ pub(crate) const WEIGHT: usize = 10;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Apply for the OpenSSF Best Practices badge (https://www.bestpractices.dev) and achieve at least the passing level.",
+        "Add the badge to the README file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#openssf-badge",
+};
+
+/// OpenSSF Best Practices API project details URL.
+const OPENSSF_API_PROJECT_URL: &str = "https://www.bestpractices.dev/projects";
+
+/// How long the project details returned by the API will be kept in the cache.
+const OPENSSF_API_CACHE_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
 lazy_static! {
     #[rustfmt::skip]
     static ref OPENSSF_URL: Regex = Regex::new(
-        r"(https://bestpractices.coreinfrastructure.org/projects/\d+)",
+        r"(https://(?:bestpractices\.coreinfrastructure\.org|www\.bestpractices\.dev)/(?:[a-z]{2}/)?projects/\d+)",
     ).expect("exprs in OPENSSF_URL to be valid");
 }
 
+/// OpenSSF Best Practices badge level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum BadgeLevel {
+    InProgress,
+    Passing,
+    Silver,
+    Gold,
+}
+
+impl BadgeLevel {
+    /// Credit awarded to the check for this badge level (percentage of the
+    /// check's weight).
+    fn credit(&self) -> usize {
+        match self {
+            BadgeLevel::InProgress => 0,
+            BadgeLevel::Passing => 70,
+            BadgeLevel::Silver => 85,
+            BadgeLevel::Gold => 100,
+        }
+    }
+
+    /// Badge level name as displayed by OpenSSF.
+    fn name(&self) -> &'static str {
+        match self {
+            BadgeLevel::InProgress => "in progress",
+            BadgeLevel::Passing => "passing",
+            BadgeLevel::Silver => "silver",
+            BadgeLevel::Gold => "gold",
+        }
+    }
+}
+
+/// Project details returned by the OpenSSF Best Practices API.
+#[derive(Debug, Clone, Deserialize)]
+struct Project {
+    badge_level: BadgeLevel,
+}
+
 /// Check main function.
-pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
     // Reference in README file
-    if let Some(url) = readme_capture(&input.li.root, &[&OPENSSF_URL])? {
-        return Ok(CheckOutput::passed().url(Some(url)));
-    }
+    let url = match readme_capture(&input.li.root, &[&OPENSSF_URL])? {
+        Some(url) => url,
+        None => return Ok(CheckOutput::not_passed()),
+    };
+
+    // Get badge level from the OpenSSF Best Practices API. When it cannot be
+    // obtained, the lowest badge level is assumed.
+    let (level, details) = match get_badge_level(&url, input.cache.as_ref()).await {
+        Ok(level) => (level, format!("**Badge level**: {}", level.name())),
+        Err(err) => (
+            BadgeLevel::Passing,
+            format!(
+                "**Badge level**: unknown, credit for the {} level awarded ({:#})",
+                BadgeLevel::Passing.name(),
+                err
+            ),
+        ),
+    };
+    let output = match level {
+        BadgeLevel::InProgress => CheckOutput::not_passed(),
+        _ => CheckOutput::passed().credit(Some(level.credit())),
+    };
+    Ok(output.url(Some(url)).details(Some(details)))
+}
 
-    Ok(CheckOutput::not_passed())
+/// Get the badge level of the project the OpenSSF Best Practices url provided
+/// points to.
+async fn get_badge_level(url: &str, cache: Option<&DynCache>) -> Result<BadgeLevel> {
+    let project_id = project_id(url).ok_or_else(|| format_err!("invalid project url"))?;
+    let key = format!("probe:openssf-project:{project_id}");
+    let body = cache::get_or_insert_with(cache, &key, OPENSSF_API_CACHE_TTL, async {
+        let resp = net::client_builder()
+            .build()?
+            .get(format!("{OPENSSF_API_PROJECT_URL}/{project_id}.json"))
+            .send()
+            .await
+            .context("error querying openssf best practices api")?;
+        if resp.status() != StatusCode::OK {
+            return Err(format_err!(
+                "unexpected status code querying openssf best practices api: {}",
+                resp.status()
+            ));
+        }
+        Ok(resp.bytes().await?.to_vec())
+    })
+    .await?;
+    let project: Project = serde_json::from_slice(&body)?;
+    Ok(project.badge_level)
+}
+
+/// Extract the project id from the OpenSSF Best Practices url provided.
+fn project_id(url: &str) -> Option<&str> {
+    url.rsplit('/')
+        .next()
+        .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
 }
 
 #[cfg(test)]
@@ -43,5 +155,34 @@ mod tests {
             OPENSSF_URL.captures("[![CII Best Practices](https://bestpractices.coreinfrastructure.org/projects/4106/badge)](https://bestpractices.coreinfrastructure.org/projects/4106)").unwrap()[1].to_string(),
             "https://bestpractices.coreinfrastructure.org/projects/4106"
         );
+        assert_eq!(
+            OPENSSF_URL.captures("[![OpenSSF Best Practices](https://www.bestpractices.dev/projects/4106/badge)](https://www.bestpractices.dev/en/projects/4106)").unwrap()[1].to_string(),
+            "https://www.bestpractices.dev/projects/4106"
+        );
+    }
+
+    #[test]
+    fn project_id_extract() {
+        assert_eq!(
+            project_id("https://www.bestpractices.dev/projects/4106"),
+            Some("4106")
+        );
+        assert_eq!(project_id("https://www.bestpractices.dev/projects/"), None);
+    }
+
+    #[test]
+    fn badge_level_deserialize() {
+        let project: Project = serde_json::from_str(r#"{"badge_level": "in_progress"}"#).unwrap();
+        assert_eq!(project.badge_level, BadgeLevel::InProgress);
+        let project: Project = serde_json::from_str(r#"{"badge_level": "silver"}"#).unwrap();
+        assert_eq!(project.badge_level, BadgeLevel::Silver);
+    }
+
+    #[test]
+    fn badge_level_credit() {
+        assert_eq!(BadgeLevel::InProgress.credit(), 0);
+        assert_eq!(BadgeLevel::Passing.credit(), 70);
+        assert_eq!(BadgeLevel::Silver.credit(), 85);
+        assert_eq!(BadgeLevel::Gold.credit(), 100);
     }
 }
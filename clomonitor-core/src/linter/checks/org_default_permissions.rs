@@ -0,0 +1,83 @@
+use super::util::{
+    github::OrgSettings,
+    helpers::{find_exemption, should_skip_check},
+    org,
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "org_default_permissions";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::Community];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Set the base permissions of the GitHub organization members to read or no permission (Settings > Member privileges).",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#organization-default-permissions",
+};
+
+/// Default repository permissions that make this check pass.
+const RESTRICTED_PERMISSIONS: [&str; 2] = ["none", "read"];
+
+/// Check main function.
+pub(crate) fn check(
+    input: &CheckInput,
+    settings: Option<&Result<OrgSettings>>,
+) -> Option<CheckOutput> {
+    // Check if this check should be skipped
+    let settings = match settings {
+        Some(settings) if !should_skip_check(ID, input.li) => settings,
+        _ => return None,
+    };
+
+    // Check if an exemption has been declared for this check
+    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
+        return Some(CheckOutput::from(exemption));
+    }
+
+    // Members' default repository permission restricted to read (or none)
+    let output = org::setting_output(settings, "Default repository permission", |s| {
+        s.default_repository_permission
+            .as_deref()
+            .map(is_restricted)
+    });
+    let permission = settings
+        .as_ref()
+        .ok()
+        .and_then(|s| s.default_repository_permission.as_deref());
+    Some(match permission {
+        Some(permission) => output.details(Some(format!(
+            "**Default repository permission**: {permission}"
+        ))),
+        None => output,
+    })
+}
+
+/// Check if the default repository permission provided is restricted.
+fn is_restricted(permission: &str) -> bool {
+    RESTRICTED_PERMISSIONS.contains(&permission.to_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_restricted_works() {
+        assert!(is_restricted("none"));
+        assert!(is_restricted("read"));
+        assert!(!is_restricted("write"));
+        assert!(!is_restricted("admin"));
+    }
+}
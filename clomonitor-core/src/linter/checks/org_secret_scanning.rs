@@ -0,0 +1,105 @@
+use super::util::{
+    github::OrgSettings,
+    helpers::{find_exemption, should_skip_check},
+    org,
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "org_secret_scanning";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Enable secret scanning for new repositories in the GitHub organization (Settings > Code security).",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#organization-secret-scanning",
+};
+
+/// Check main function.
+pub(crate) fn check(
+    input: &CheckInput,
+    settings: Option<&Result<OrgSettings>>,
+) -> Option<CheckOutput> {
+    // Check if this check should be skipped
+    let settings = match settings {
+        Some(settings) if !should_skip_check(ID, input.li) => settings,
+        _ => return None,
+    };
+
+    // Check if an exemption has been declared for this check
+    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
+        return Some(CheckOutput::from(exemption));
+    }
+
+    // Secret scanning enabled for new repositories in the organization
+    Some(org::setting_output(settings, "Secret scanning", |s| {
+        s.secret_scanning_enabled_for_new_repositories
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linter::{util::github::md::MdRepository, LinterInput},
+        net::RestrictedClient,
+    };
+    use anyhow::format_err;
+    use std::collections::BTreeMap;
+
+    fn check_input(li: &LinterInput) -> CheckInput {
+        CheckInput {
+            li,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            cache: None,
+            restricted_client: RestrictedClient::default(),
+            prerequisites: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn passed_secret_scanning_enabled() {
+        let settings = Ok(OrgSettings {
+            secret_scanning_enabled_for_new_repositories: Some(true),
+            ..OrgSettings::default()
+        });
+        assert_eq!(
+            check(&check_input(&LinterInput::default()), Some(&settings)).unwrap(),
+            CheckOutput::passed()
+        );
+    }
+
+    #[test]
+    fn not_passed_secret_scanning_disabled() {
+        let settings = Ok(OrgSettings {
+            secret_scanning_enabled_for_new_repositories: Some(false),
+            ..OrgSettings::default()
+        });
+        assert_eq!(
+            check(&check_input(&LinterInput::default()), Some(&settings)).unwrap(),
+            CheckOutput::not_passed()
+        );
+    }
+
+    #[tokio::test]
+    async fn not_run_without_org_token() {
+        let li = LinterInput::default();
+        let input = check_input(&li);
+        assert!(org::settings(&input).await.is_none());
+        assert_eq!(check(&input, None), None);
+    }
+}
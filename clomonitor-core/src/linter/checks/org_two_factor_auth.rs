@@ -0,0 +1,107 @@
+use super::util::{
+    github::OrgSettings,
+    helpers::{find_exemption, should_skip_check},
+    org,
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "org_two_factor_auth";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 3;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::Community];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Require two-factor authentication for all the members of the GitHub organization (Settings > Authentication security).",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#organization-two-factor-authentication",
+};
+
+/// Check main function.
+pub(crate) fn check(
+    input: &CheckInput,
+    settings: Option<&Result<OrgSettings>>,
+) -> Option<CheckOutput> {
+    // Check if this check should be skipped
+    let settings = match settings {
+        Some(settings) if !should_skip_check(ID, input.li) => settings,
+        _ => return None,
+    };
+
+    // Check if an exemption has been declared for this check
+    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
+        return Some(CheckOutput::from(exemption));
+    }
+
+    // Two-factor authentication required for the organization members
+    Some(org::setting_output(
+        settings,
+        "Two-factor authentication requirement",
+        |s| s.two_factor_requirement_enabled,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linter::{util::github::md::MdRepository, LinterInput},
+        net::RestrictedClient,
+    };
+    use anyhow::format_err;
+    use std::collections::BTreeMap;
+
+    fn check_input(li: &LinterInput) -> CheckInput {
+        CheckInput {
+            li,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            cache: None,
+            restricted_client: RestrictedClient::default(),
+            prerequisites: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn passed_two_factor_auth_required() {
+        let settings = Ok(OrgSettings {
+            two_factor_requirement_enabled: Some(true),
+            ..OrgSettings::default()
+        });
+        assert_eq!(
+            check(&check_input(&LinterInput::default()), Some(&settings)).unwrap(),
+            CheckOutput::passed()
+        );
+    }
+
+    #[test]
+    fn not_passed_two_factor_auth_not_required() {
+        let settings = Ok(OrgSettings {
+            two_factor_requirement_enabled: Some(false),
+            ..OrgSettings::default()
+        });
+        assert_eq!(
+            check(&check_input(&LinterInput::default()), Some(&settings)).unwrap(),
+            CheckOutput::not_passed()
+        );
+    }
+
+    #[tokio::test]
+    async fn not_run_without_org_token() {
+        let li = LinterInput::default();
+        let input = check_input(&li);
+        assert!(org::settings(&input).await.is_none());
+        assert_eq!(check(&input, None), None);
+    }
+}
@@ -0,0 +1,76 @@
+use super::util::{content, helpers::project_website_content};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::RegexSet;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "privacy_policy";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Community, CheckSet::DocsSite];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a link to the privacy policy that applies to the project's website (usually in the footer).",
+        "Projects hosted by the Linux Foundation can link to https://www.linuxfoundation.org/legal/privacy-policy.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#privacy-policy",
+};
+
+lazy_static! {
+    #[rustfmt::skip]
+    pub(crate) static ref PRIVACY_POLICY: RegexSet = RegexSet::new([
+        r#"(?i)<a[^>]+href="[^"]*privacy[^"]*""#,
+        r"(?i)privacy\s+(?:policy|statement|notice)",
+    ]).expect("exprs in PRIVACY_POLICY to be valid");
+}
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // Privacy policy link in the project's live website
+    if let Some(content) = project_website_content(input).await? {
+        if PRIVACY_POLICY.is_match(&content) {
+            return Ok(CheckOutput::passed().url(input.li.home_url.clone()));
+        }
+    }
+
+    // Privacy policy link in website setup in Github
+    if let Some(url) = &input.gh_md.homepage_url {
+        if !url.is_empty() {
+            if let Some(content) =
+                content::remote_if_allowed(&input.restricted_client, url, input.cache.as_ref())
+                    .await?
+            {
+                if PRIVACY_POLICY.is_match(&content) {
+                    return Ok(CheckOutput::passed().url(Some(url.clone())));
+                }
+            }
+        }
+    }
+
+    Ok(CheckOutput::not_passed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn privacy_policy_match() {
+        assert!(PRIVACY_POLICY.is_match(
+            r#"<a href="https://www.linuxfoundation.org/legal/privacy-policy">Privacy</a>"#
+        ));
+        assert!(PRIVACY_POLICY.is_match("Read our Privacy Policy"));
+        assert!(PRIVACY_POLICY.is_match("privacy statement"));
+        assert!(!PRIVACY_POLICY.is_match(r#"<a href="/docs/">Docs</a>"#));
+    }
+}
@@ -1,7 +1,10 @@
 /// Patterns used to locate a file in the repository.
-use super::util::{github, helpers::readme_globs, path};
+use super::util::{
+    helpers::{build_file_url, readme_globs},
+    path,
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -13,13 +16,23 @@ pub(crate) const ID: CheckId = "readme";
 pub(crate) const WEIGHT: usize = 10;
 
 /// Check sets this check belongs to.
-pub(crate) const CHECK_SETS: [CheckSet; 4] = [
+pub(crate) const CHECK_SETS: [CheckSet; 5] = [
     CheckSet::Code,
     CheckSet::CodeLite,
     CheckSet::Community,
     CheckSet::Docs,
+    CheckSet::DocsSite,
 ];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a README.md file to the root of the repository introducing and explaining the project.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#readme",
+};
+
 /// Patterns used to locate a file in the repository.
 pub(crate) static FILE_PATTERNS: [&str; 3] = ["README*", ".github/README*", "docs/README*"];
 
@@ -27,13 +40,7 @@ pub(crate) static FILE_PATTERNS: [&str; 3] = ["README*", ".github/README*", "doc
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo
     if let Some(path) = path::find(&readme_globs(&input.li.root))? {
-        let url = github::build_url(
-            &path,
-            &input.gh_md.owner.login,
-            &input.gh_md.name,
-            &github::default_branch(input.gh_md.default_branch_ref.as_ref()),
-        );
-        return Ok(CheckOutput::passed().url(Some(url)));
+        return Ok(CheckOutput::passed().url(Some(build_file_url(input, &path))));
     }
 
     Ok(CheckOutput::not_passed())
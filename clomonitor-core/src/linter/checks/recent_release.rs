@@ -1,6 +1,6 @@
 use super::util::github;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -15,6 +15,13 @@ pub(crate) const WEIGHT: usize = 3;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &["Publish a new release of the project (at least one per year)."],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#recent-release",
+};
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Recent release (< 1 year old) in GitHub
@@ -32,14 +39,18 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::{
-        util::github::md::{
-            MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
-            MdRepositoryReleasesNodesReleaseAssets,
+    use crate::{
+        linter::{
+            util::github::md::{
+                MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
+                MdRepositoryReleasesNodesReleaseAssets,
+            },
+            LinterInput,
         },
-        LinterInput,
+        net::RestrictedClient,
     };
     use anyhow::format_err;
+    use std::collections::BTreeMap;
 
     #[test]
     fn not_passed_no_release_found() {
@@ -51,6 +62,9 @@ mod tests {
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -74,12 +88,16 @@ mod tests {
                             description: None,
                             is_prerelease: false,
                             release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -103,12 +121,16 @@ mod tests {
                             description: None,
                             is_prerelease: false,
                             release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::passed().url(Some("release_url".to_string())),
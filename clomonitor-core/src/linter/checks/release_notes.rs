@@ -0,0 +1,212 @@
+use super::{
+    changelog,
+    util::{
+        github,
+        helpers::build_file_url,
+        path::{self, Globs},
+    },
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fs;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "release_notes";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Describe the notable changes of each release in its GitHub release notes.",
+        "If the repository has a CHANGELOG file, add an entry for each release including its version.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#release-notes",
+};
+
+/// Credit awarded when the latest release is only documented in one of the
+/// sources available (release notes or CHANGELOG file).
+const PARTIAL_CREDIT: usize = 50;
+
+lazy_static! {
+    #[rustfmt::skip]
+    static ref VERSION: Regex = Regex::new(
+        r"\d+\.\d+(?:\.\d+)?(?:-[0-9A-Za-z.-]+)?"
+    ).expect("exprs in VERSION to be valid");
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    // Latest release in GitHub
+    let Some(release) = github::latest_release(&input.gh_md) else {
+        return Ok(CheckOutput::not_passed().details(Some("No releases found".to_string())));
+    };
+    let tag = &release.tag_name;
+    let version = version(tag);
+
+    // Release notes
+    let notes_documented = release
+        .description
+        .as_ref()
+        .map_or(false, |description| !description.trim().is_empty());
+
+    // Entry for the release's version in the CHANGELOG file (if available)
+    let changelog = path::find(&Globs {
+        root: &input.li.root,
+        patterns: &changelog::FILE_PATTERNS,
+        case_sensitive: false,
+    })?;
+    let changelog_documented = match &changelog {
+        Some(path) => {
+            let content = fs::read_to_string(input.li.root.join(path)).unwrap_or_default();
+            Some(changelog_documents(&content, version))
+        }
+        None => None,
+    };
+
+    // Build output, awarding partial credit when the release is only
+    // documented in some of the sources available
+    let mut missing = vec![];
+    if !notes_documented {
+        missing.push("release notes".to_string());
+    }
+    if changelog_documented == Some(false) {
+        missing.push(format!("CHANGELOG entry for version {version}"));
+    }
+    let details = format!("Latest release {tag} is missing: {}", missing.join(", "));
+    let output = if missing.is_empty() {
+        CheckOutput::passed()
+    } else if notes_documented || changelog_documented == Some(true) {
+        CheckOutput::passed()
+            .credit(Some(PARTIAL_CREDIT))
+            .details(Some(details))
+    } else {
+        CheckOutput::not_passed().details(Some(details))
+    };
+    let url = match (&changelog, notes_documented) {
+        (Some(path), false) if changelog_documented == Some(true) => build_file_url(input, path),
+        _ => release.url.clone(),
+    };
+
+    Ok(output.url(Some(url)))
+}
+
+/// Extract the version from the release tag provided (i.e. v1.2.3 -> 1.2.3).
+/// When the tag does not contain a version, it is returned as is.
+fn version(tag: &str) -> &str {
+    VERSION.find(tag).map_or(tag, |m| m.as_str())
+}
+
+/// Check if the CHANGELOG content provided has an entry for the version given.
+fn changelog_documents(content: &str, version: &str) -> bool {
+    let re = format!(r"(?m)(?:^|[^\d.]){}(?:[^\d.]|\.?$)", regex::escape(version));
+    Regex::new(&re).map_or(false, |re| re.is_match(content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linter::{
+            util::github::md::{
+                MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
+                MdRepositoryReleasesNodesReleaseAssets,
+            },
+            LinterInput,
+        },
+        net::RestrictedClient,
+    };
+    use anyhow::format_err;
+    use std::{collections::BTreeMap, path::PathBuf};
+
+    fn gh_md(description: Option<&str>) -> MdRepository {
+        MdRepository {
+            releases: MdRepositoryReleases {
+                nodes: Some(vec![Some(MdRepositoryReleasesNodes {
+                    created_at: "created_at_date".to_string(),
+                    description: description.map(ToString::to_string),
+                    is_prerelease: false,
+                    release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                    tag_name: "v1.0.0".to_string(),
+                    url: "release_url".to_string(),
+                })]),
+            },
+            ..MdRepository::default()
+        }
+    }
+
+    #[test]
+    fn passed_release_notes_found() {
+        let li = LinterInput {
+            root: PathBuf::from("src/testdata"),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &li,
+                cm_md: None,
+                gh_md: gh_md(Some("## Features\n\n- New feature")),
+                scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
+            })
+            .unwrap(),
+            CheckOutput::passed().url(Some("release_url".to_string())),
+        );
+    }
+
+    #[test]
+    fn not_passed_release_notes_empty() {
+        let li = LinterInput {
+            root: PathBuf::from("src/testdata"),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &li,
+                cm_md: None,
+                gh_md: gh_md(Some("  ")),
+                scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
+            })
+            .unwrap(),
+            CheckOutput::not_passed()
+                .url(Some("release_url".to_string()))
+                .details(Some(
+                    "Latest release v1.0.0 is missing: release notes".to_string()
+                )),
+        );
+    }
+
+    #[test]
+    fn version_from_tag() {
+        assert_eq!(version("v1.2.3"), "1.2.3");
+        assert_eq!(version("release-1.2"), "1.2");
+        assert_eq!(version("1.2.3-rc.1"), "1.2.3-rc.1");
+        assert_eq!(version("latest"), "latest");
+    }
+
+    #[test]
+    fn changelog_documents_version() {
+        let content = "# Changelog\n\n## [1.2.3] - 2022-10-01\n\n- Fix\n\n## v1.2.0\n\n- Feature";
+        assert!(changelog_documents(content, "1.2.3"));
+        assert!(changelog_documents(content, "1.2.0"));
+        assert!(!changelog_documents(content, "1.2"));
+        assert!(!changelog_documents(content, "1.3.0"));
+    }
+}
@@ -1,6 +1,9 @@
-use super::util::helpers::find_file_or_readme_ref;
+use super::util::{
+    helpers::find_file_or_readme_ref,
+    locale::{find_translated_file_or_readme_ref, Document},
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,6 +19,19 @@ pub(crate) const WEIGHT: usize = 1;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a ROADMAP.md file to the repository describing the project's goals and planned deliverables.",
+        "Alternatively, reference the roadmap in the README file.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "ROADMAP.md",
+        content: include_str!("data/templates/ROADMAP.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#roadmap",
+};
+
 /// Patterns used to locate a file in the repository.
 const FILE_PATTERNS: [&str; 1] = ["roadmap*"];
 
@@ -31,7 +47,17 @@ lazy_static! {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo or reference in README file
-    find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)
+    let r = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
+    if r.passed {
+        return Ok(r);
+    }
+
+    // Translated file in repo or reference in README file
+    if let Some(r) = find_translated_file_or_readme_ref(input, Document::Roadmap)? {
+        return Ok(r);
+    }
+
+    Ok(CheckOutput::not_passed())
 }
 
 #[cfg(test)]
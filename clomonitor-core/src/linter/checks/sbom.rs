@@ -1,6 +1,6 @@
 use super::util::{github, helpers::readme_matches};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,6 +16,16 @@ pub(crate) const WEIGHT: usize = 1;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Generate a software bill of materials for each release and publish it as a release asset (with sbom in its name).",
+        "Alternatively, add a SBOM section to the README file explaining where they are published and the format used.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#software-bill-of-materials-sbom",
+};
+
 lazy_static! {
     #[rustfmt::skip]
     static ref README_REF: RegexSet = RegexSet::new([
@@ -57,14 +67,19 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::{
-        util::github::md::{
-            MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
-            MdRepositoryReleasesNodesReleaseAssets, MdRepositoryReleasesNodesReleaseAssetsNodes,
+    use crate::{
+        linter::{
+            util::github::md::{
+                MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
+                MdRepositoryReleasesNodesReleaseAssets,
+                MdRepositoryReleasesNodesReleaseAssetsNodes,
+            },
+            LinterInput,
         },
-        LinterInput,
+        net::RestrictedClient,
     };
     use anyhow::format_err;
+    use std::collections::BTreeMap;
 
     #[test]
     fn not_passed_no_release_found() {
@@ -76,6 +91,9 @@ mod tests {
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -101,12 +119,16 @@ mod tests {
                                     }
                                 )])
                             },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -132,12 +154,16 @@ mod tests {
                                     }
                                 )])
                             },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
             })
             .unwrap(),
             CheckOutput::passed(),
@@ -0,0 +1,211 @@
+use super::util::{
+    helpers::build_file_url,
+    path::{self, Globs},
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
+    CheckSet,
+};
+use anyhow::Result;
+use serde::Deserialize;
+use std::fs;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "security_insights";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 1;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a SECURITY-INSIGHTS.yml file to the repository following the OpenSSF Security Insights specification.",
+        "Provide at least the security contacts and the vulnerability reporting information.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "SECURITY-INSIGHTS.yml",
+        content: include_str!("data/templates/SECURITY-INSIGHTS.yml"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#security-insights",
+};
+
+/// Patterns used to locate a file in the repository.
+pub(crate) static FILE_PATTERNS: [&str; 2] =
+    ["security-insights.yml", ".github/security-insights.yml"];
+
+/// OpenSSF Security Insights file (only the fields validated are included).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct SecurityInsights {
+    security_contacts: Option<Vec<SecurityContact>>,
+    vulnerability_reporting: Option<VulnerabilityReporting>,
+}
+
+/// Security contact entry.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct SecurityContact {
+    value: Option<String>,
+}
+
+/// Vulnerability reporting section.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct VulnerabilityReporting {
+    accepts_vulnerability_reports: Option<bool>,
+    email_contact: Option<String>,
+    security_policy: Option<String>,
+}
+
+impl SecurityInsights {
+    /// Return the required fields missing in the security insights file.
+    fn missing_fields(&self) -> Vec<&'static str> {
+        let mut missing = vec![];
+
+        // At least one security contact is required
+        let has_contact = self.security_contacts.iter().flatten().any(|contact| {
+            contact
+                .value
+                .as_ref()
+                .map_or(false, |value| !value.trim().is_empty())
+        });
+        if !has_contact {
+            missing.push("security-contacts");
+        }
+
+        // Vulnerability reporting policy is required
+        match &self.vulnerability_reporting {
+            Some(vr) => match vr.accepts_vulnerability_reports {
+                Some(true) if vr.security_policy.is_none() && vr.email_contact.is_none() => {
+                    missing.push("vulnerability-reporting.security-policy");
+                }
+                Some(_) => {}
+                None => missing.push("vulnerability-reporting.accepts-vulnerability-reports"),
+            },
+            None => missing.push("vulnerability-reporting"),
+        }
+
+        missing
+    }
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    // File in repo
+    let path = match path::find(&Globs {
+        root: &input.li.root,
+        patterns: &FILE_PATTERNS,
+        case_sensitive: false,
+    })? {
+        Some(path) => path,
+        None => return Ok(CheckOutput::not_passed()),
+    };
+    let url = build_file_url(input, &path);
+
+    // Validate file content
+    let content = fs::read_to_string(input.li.root.join(&path))?;
+    let details = match serde_yaml::from_str::<SecurityInsights>(&content) {
+        Ok(si) => {
+            let missing = si.missing_fields();
+            if missing.is_empty() {
+                return Ok(CheckOutput::passed().url(Some(url)));
+            }
+            format!("**Required fields missing**: {}", missing.join(", "))
+        }
+        Err(err) => format!("**Invalid security insights file**: {err}"),
+    };
+
+    Ok(CheckOutput::not_passed()
+        .url(Some(url))
+        .details(Some(details)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn missing_fields(content: &str) -> Vec<&'static str> {
+        serde_yaml::from_str::<SecurityInsights>(content)
+            .unwrap()
+            .missing_fields()
+    }
+
+    #[test]
+    fn valid_file() {
+        assert!(missing_fields(
+            r"
+header:
+  schema-version: 1.0.0
+security-contacts:
+  - type: email
+    value: security@example.com
+    primary: true
+vulnerability-reporting:
+  accepts-vulnerability-reports: true
+  security-policy: https://example.com/SECURITY.md
+"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn valid_file_not_accepting_reports() {
+        assert!(missing_fields(
+            r"
+security-contacts:
+  - type: email
+    value: security@example.com
+vulnerability-reporting:
+  accepts-vulnerability-reports: false
+"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn missing_security_contacts() {
+        assert_eq!(
+            missing_fields(
+                r"
+security-contacts: []
+vulnerability-reporting:
+  accepts-vulnerability-reports: true
+  email-contact: security@example.com
+"
+            ),
+            vec!["security-contacts"]
+        );
+    }
+
+    #[test]
+    fn missing_vulnerability_reporting() {
+        assert_eq!(
+            missing_fields(
+                r"
+security-contacts:
+  - type: email
+    value: security@example.com
+"
+            ),
+            vec!["vulnerability-reporting"]
+        );
+    }
+
+    #[test]
+    fn missing_vulnerability_reporting_policy() {
+        assert_eq!(
+            missing_fields(
+                r"
+vulnerability-reporting:
+  accepts-vulnerability-reports: true
+"
+            ),
+            vec![
+                "security-contacts",
+                "vulnerability-reporting.security-policy"
+            ]
+        );
+    }
+}
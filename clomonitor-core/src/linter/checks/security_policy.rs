@@ -1,6 +1,9 @@
-use super::util::helpers::find_file_or_readme_ref;
+use super::util::{
+    helpers::find_file_or_readme_ref,
+    locale::{find_translated_file_or_readme_ref, Document},
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,8 +19,26 @@ pub(crate) const WEIGHT: usize = 3;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add a SECURITY.md file to the repository (or to the organization's .github repository) explaining how to report vulnerabilities.",
+        "Alternatively, add a Security section to the README file.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "SECURITY.md",
+        content: include_str!("data/templates/SECURITY.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#security-policy",
+};
+
 /// Patterns used to locate a file in the repository.
-static FILE_PATTERNS: [&str; 3] = ["security*", ".github/security*", "docs/security*"];
+static FILE_PATTERNS: [&str; 4] = [
+    "security*",
+    ".github/security*",
+    ".gitea/security*",
+    "docs/security*",
+];
 
 lazy_static! {
     #[rustfmt::skip]
@@ -36,6 +57,11 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
         return Ok(r);
     }
 
+    // Translated file in repo or reference in README file
+    if let Some(r) = find_translated_file_or_readme_ref(input, Document::SecurityPolicy)? {
+        return Ok(r);
+    }
+
     // File in Github (default community health file, for example)
     if let Some(url) = input.gh_md.security_policy_url.as_ref() {
         return Ok(CheckOutput::passed().url(Some(url.to_owned())));
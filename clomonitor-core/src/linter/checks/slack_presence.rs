@@ -1,6 +1,6 @@
 use super::util::helpers::readme_matches;
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -16,6 +16,16 @@ pub(crate) const WEIGHT: usize = 0;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Create a channel for the project in the CNCF or Kubernetes Slack.",
+        "Reference it in the README file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#slack-presence",
+};
+
 lazy_static! {
     #[rustfmt::skip]
     static ref README_REF: RegexSet = RegexSet::new([
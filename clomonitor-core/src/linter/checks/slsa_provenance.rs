@@ -0,0 +1,218 @@
+use super::util::{
+    github,
+    path::{self, Globs},
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet, Provider,
+};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::{Regex, RegexSet};
+use std::fs;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "slsa_provenance";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Generate provenance for the release artifacts using the SLSA GitHub generator reusable workflows (SLSA build level 3).",
+        "Alternatively, use actions/attest-build-provenance to register GitHub artifact attestations for them (SLSA build level 2).",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#slsa-provenance",
+};
+
+/// Patterns used to locate the repository's GitHub Actions workflows.
+const WORKFLOWS_PATTERNS: [&str; 2] = [".github/workflows/*.yml", ".github/workflows/*.yaml"];
+
+lazy_static! {
+    #[rustfmt::skip]
+    static ref SLSA3_WORKFLOW: Regex = Regex::new(
+        r"slsa-framework/slsa-github-generator/\.github/workflows/(?:generator|builder)_\w+_slsa3\.yml"
+    ).expect("exprs in SLSA3_WORKFLOW to be valid");
+
+    #[rustfmt::skip]
+    static ref ATTESTATION_WORKFLOW: Regex = Regex::new(
+        r"actions/attest-build-provenance@"
+    ).expect("exprs in ATTESTATION_WORKFLOW to be valid");
+
+    #[rustfmt::skip]
+    static ref PROVENANCE_ASSET: RegexSet = RegexSet::new([
+        r"(?i)\.intoto\.jsonl$",
+        r"(?i)provenance\.json$",
+        r"(?i)\.sigstore(?:\.json)?$",
+    ]).expect("exprs in PROVENANCE_ASSET to be valid");
+}
+
+/// SLSA build level achieved by the project's release process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SlsaLevel {
+    L0,
+    L1,
+    L2,
+    L3,
+}
+
+impl SlsaLevel {
+    /// Credit awarded to the check for this level (percentage of the check's
+    /// weight).
+    fn credit(&self) -> usize {
+        match self {
+            SlsaLevel::L0 => 0,
+            SlsaLevel::L1 => 40,
+            SlsaLevel::L2 => 70,
+            SlsaLevel::L3 => 100,
+        }
+    }
+
+    /// Level number.
+    fn number(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    let mut level = SlsaLevel::L0;
+    let mut evidence: Vec<String> = vec![];
+
+    // Provenance files in the latest release's assets
+    let release = github::latest_release(&input.gh_md);
+    let provenance_assets: Vec<&str> = release
+        .and_then(|r| r.release_assets.nodes.as_ref())
+        .map(|nodes| {
+            nodes
+                .iter()
+                .flatten()
+                .map(|asset| asset.name.as_str())
+                .filter(|name| PROVENANCE_ASSET.is_match(name))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !provenance_assets.is_empty() {
+        level = SlsaLevel::L1;
+        evidence.push(format!(
+            "provenance files in the latest release: {}",
+            provenance_assets.join(", ")
+        ));
+    }
+
+    // GitHub artifact attestations registered for the latest release's assets
+    if let Some(release) = release {
+        if input.li.provider == Provider::Github {
+            let attested = github::release_attested_assets(
+                &input.li.url,
+                &release.tag_name,
+                &input.li.github_token,
+            )
+            .await?;
+            if !attested.is_empty() {
+                level = level.max(SlsaLevel::L2);
+                evidence.push(format!(
+                    "GitHub artifact attestations for: {}",
+                    attested.join(", ")
+                ));
+            }
+        }
+    }
+
+    // Provenance generation in the repository's workflows
+    match workflows_level(input)? {
+        Some(SlsaLevel::L3) if level >= SlsaLevel::L1 => {
+            level = SlsaLevel::L3;
+            evidence.push("provenance generated by the SLSA GitHub generator".to_string());
+        }
+        Some(SlsaLevel::L2) if level == SlsaLevel::L1 => {
+            level = SlsaLevel::L2;
+            evidence.push("provenance generated by actions/attest-build-provenance".to_string());
+        }
+        Some(_) if level == SlsaLevel::L0 => {
+            // Provenance generation is set up, but no provenance could be
+            // found in the latest release
+            level = SlsaLevel::L1;
+            evidence.push("provenance generation set up in workflows".to_string());
+        }
+        _ => {}
+    }
+
+    if level == SlsaLevel::L0 {
+        return Ok(CheckOutput::not_passed());
+    }
+    let details = format!(
+        "**SLSA build level**: {} ({})",
+        level.number(),
+        evidence.join("; ")
+    );
+    Ok(CheckOutput::passed()
+        .credit(Some(level.credit()))
+        .details(Some(details)))
+}
+
+/// Return the highest SLSA level the provenance generation set up in the
+/// repository's GitHub Actions workflows could achieve, if any.
+fn workflows_level(input: &CheckInput) -> Result<Option<SlsaLevel>> {
+    let mut level = None;
+    for path in path::matches(&Globs {
+        root: &input.li.root,
+        patterns: &WORKFLOWS_PATTERNS,
+        case_sensitive: true,
+    })? {
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+        if SLSA3_WORKFLOW.is_match(&content) {
+            return Ok(Some(SlsaLevel::L3));
+        }
+        if ATTESTATION_WORKFLOW.is_match(&content) {
+            level = Some(SlsaLevel::L2);
+        }
+    }
+    Ok(level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slsa_level_credit() {
+        assert_eq!(SlsaLevel::L0.credit(), 0);
+        assert_eq!(SlsaLevel::L1.credit(), 40);
+        assert_eq!(SlsaLevel::L2.credit(), 70);
+        assert_eq!(SlsaLevel::L3.credit(), 100);
+        assert_eq!(SlsaLevel::L2.number(), 2);
+    }
+
+    #[test]
+    fn slsa3_workflow_match() {
+        assert!(SLSA3_WORKFLOW.is_match(
+            "uses: slsa-framework/slsa-github-generator/.github/workflows/generator_generic_slsa3.yml@v1.9.0"
+        ));
+        assert!(SLSA3_WORKFLOW.is_match(
+            "uses: slsa-framework/slsa-github-generator/.github/workflows/builder_go_slsa3.yml@v1.9.0"
+        ));
+        assert!(!SLSA3_WORKFLOW.is_match("uses: actions/checkout@v4"));
+    }
+
+    #[test]
+    fn attestation_workflow_match() {
+        assert!(ATTESTATION_WORKFLOW.is_match("uses: actions/attest-build-provenance@v1"));
+        assert!(!ATTESTATION_WORKFLOW.is_match("uses: actions/attest-sbom@v1"));
+    }
+
+    #[test]
+    fn provenance_asset_match() {
+        assert!(PROVENANCE_ASSET.is_match("multiple.intoto.jsonl"));
+        assert!(PROVENANCE_ASSET.is_match("app_linux_amd64.sigstore.json"));
+        assert!(PROVENANCE_ASSET.is_match("provenance.json"));
+        assert!(!PROVENANCE_ASSET.is_match("app_linux_amd64.tar.gz"));
+    }
+}
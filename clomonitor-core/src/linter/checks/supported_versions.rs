@@ -0,0 +1,121 @@
+use super::util::{
+    helpers::build_file_url,
+    path::{self, Globs},
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation, RemediationTemplate},
+    CheckSet,
+};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::RegexSet;
+use std::fs;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "supported_versions";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 1;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Document which versions are supported and how security fixes are backported to them.",
+        "Add a Supported versions section to the security policy, or a SUPPORTED_VERSIONS.md file.",
+    ],
+    template: Some(RemediationTemplate {
+        path: "SECURITY.md",
+        content: include_str!("data/templates/SECURITY.md"),
+    }),
+    docs_url: "https://clomonitor.io/docs/topics/checks/#supported-versions",
+};
+
+/// Patterns used to locate a dedicated file in the repository.
+static FILE_PATTERNS: [&str; 3] = [
+    "supported?versions*",
+    "docs/supported?versions*",
+    "docs/**/supported?versions*",
+];
+
+/// Patterns used to locate the security policy files in the repository.
+static SECURITY_POLICY_FILE_PATTERNS: [&str; 3] =
+    ["security*", ".github/security*", "docs/security*"];
+
+lazy_static! {
+    #[rustfmt::skip]
+    static ref SUPPORTED_VERSIONS_REF: RegexSet = RegexSet::new([
+        r"(?im)^#+.*supported\s+(versions|releases).*$",
+        r"(?im)^#+.*(version|release)\s+support.*$",
+        r"(?im)^#+.*(support|maintenance|backport)\s+policy.*$",
+        r"(?im)^#+.*security\s+(fix|patch|update)(es|s)?\s+policy.*$",
+        r"(?i)\|\s*versions?\s*\|\s*supported\s*\|",
+        r"(?i)security\s+(fixes|patches|updates)\s+(are|will\s+be)\s+(backported|provided|released)",
+        r"(?i)(only|currently)\s+(the\s+)?(latest|most\s+recent|current|last\s+(two|three|\d+))\s+(minor\s+|major\s+)?(releases?|versions?)\s+(is|are)\s+(supported|maintained)",
+    ]).expect("exprs in SUPPORTED_VERSIONS_REF to be valid");
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    // Dedicated file in repo
+    if let Some(path) = path::find(&Globs {
+        root: &input.li.root,
+        patterns: &FILE_PATTERNS,
+        case_sensitive: false,
+    })? {
+        return Ok(CheckOutput::passed().url(Some(build_file_url(input, &path))));
+    }
+
+    // Supported versions section in security policy file
+    let security_policy_files = path::matches(&Globs {
+        root: &input.li.root,
+        patterns: &SECURITY_POLICY_FILE_PATTERNS,
+        case_sensitive: false,
+    })?;
+    for path in security_policy_files {
+        if let Ok(content) = fs::read_to_string(&path) {
+            if SUPPORTED_VERSIONS_REF.is_match(&content) {
+                let path = path.strip_prefix(&input.li.root).unwrap_or(&path);
+                return Ok(CheckOutput::passed().url(Some(build_file_url(input, path))));
+            }
+        }
+    }
+
+    Ok(CheckOutput::not_passed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supported_versions_ref_match() {
+        assert!(SUPPORTED_VERSIONS_REF.is_match("## Supported Versions"));
+        assert!(SUPPORTED_VERSIONS_REF.is_match("### Supported releases"));
+        assert!(SUPPORTED_VERSIONS_REF.is_match("## Version support"));
+        assert!(SUPPORTED_VERSIONS_REF.is_match("## Backport policy"));
+        assert!(SUPPORTED_VERSIONS_REF.is_match("# Security fix policy"));
+        assert!(SUPPORTED_VERSIONS_REF.is_match(
+            r"
+| Version | Supported          |
+| ------- | ------------------ |
+| 5.1.x   | :white_check_mark: |
+            "
+        ));
+        assert!(SUPPORTED_VERSIONS_REF
+            .is_match("Security fixes will be backported to the previous minor release."));
+        assert!(SUPPORTED_VERSIONS_REF.is_match("Only the latest release is supported."));
+        assert!(SUPPORTED_VERSIONS_REF
+            .is_match("Currently the last two minor versions are maintained."));
+    }
+
+    #[test]
+    fn supported_versions_ref_no_match() {
+        assert!(!SUPPORTED_VERSIONS_REF.is_match("# Security Policy"));
+        assert!(!SUPPORTED_VERSIONS_REF.is_match("## Reporting a Vulnerability"));
+        assert!(!SUPPORTED_VERSIONS_REF
+            .is_match("Please report security issues to security@example.com"));
+    }
+}
@@ -1,11 +1,16 @@
-use super::util::content;
+use super::util::{
+    content,
+    helpers::{build_file_url, project_website_content},
+    path, site,
+};
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
 use lazy_static::lazy_static;
 use regex::RegexSet;
+use std::fs;
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "trademark_disclaimer";
@@ -14,7 +19,17 @@ pub(crate) const ID: CheckId = "trademark_disclaimer";
 pub(crate) const WEIGHT: usize = 5;
 
 /// Check sets this check belongs to.
-pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Community, CheckSet::DocsSite];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Add the Linux Foundation trademark disclaimer to the project's website (usually in the footer).",
+        "Documentation sites can add it to the footer template or the copyright setting of the static site generator's configuration file.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#trademark-disclaimer",
+};
 
 lazy_static! {
     #[rustfmt::skip]
@@ -26,9 +41,40 @@ lazy_static! {
 
 /// Check main function.
 pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // Trademark disclaimer in the documentation site layout or configuration
+    if let Some(site) = site::detect(&input.li.root)? {
+        let paths = path::matches(&site.layouts())?
+            .into_iter()
+            .chain(path::matches(&site.config())?);
+        for path in paths {
+            let Ok(content) = fs::read_to_string(&path) else {
+                continue;
+            };
+            if TRADEMARK_DISCLAIMER.is_match(&content) {
+                let path = path.strip_prefix(&input.li.root).unwrap_or(path.as_path());
+                return Ok(CheckOutput::passed().url(Some(build_file_url(input, path))));
+            }
+        }
+    }
+
+    // Trademark disclaimer in the project's live website
+    if let Some(content) = project_website_content(input).await? {
+        if TRADEMARK_DISCLAIMER.is_match(&content) {
+            return Ok(CheckOutput::passed().url(input.li.home_url.clone()));
+        }
+    }
+
     // Trademark disclaimer in website setup in Github
     if let Some(url) = &input.gh_md.homepage_url {
-        if !url.is_empty() && content::remote_matches(url, &TRADEMARK_DISCLAIMER).await? {
+        if !url.is_empty()
+            && content::remote_matches(
+                &input.restricted_client,
+                url,
+                &TRADEMARK_DISCLAIMER,
+                input.cache.as_ref(),
+            )
+            .await?
+        {
             return Ok(CheckOutput::passed());
         }
     }
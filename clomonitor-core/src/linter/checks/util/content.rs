@@ -1,21 +1,53 @@
-use super::path::{self, Globs};
-use anyhow::Result;
+use super::{
+    content_cache,
+    path::{self, Globs},
+};
+use crate::{
+    cache::{self, DynCache},
+    net::RestrictedClient,
+};
+use anyhow::{format_err, Context, Result};
 use regex::{Regex, RegexSet};
-use std::fs;
+use reqwest::{
+    header::{HeaderName, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    StatusCode, Url,
+};
+use serde::{Deserialize, Serialize};
+use std::{fs, time::Duration};
+use tracing::warn;
+
+/// How long the content of remote urls will be kept in the cache.
+const REMOTE_CONTENT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// How long the robots.txt files of the sites fetched will be kept in the
+/// cache.
+const ROBOTS_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How long the content fetched using conditional requests will be kept in
+/// the cache. Entries are revalidated every time they are used, so they can be
+/// kept much longer than the remote content ones.
+const CONDITIONAL_CONTENT_CACHE_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// User agent token used to find the robots.txt rules that apply to us.
+const ROBOTS_USER_AGENT: &str = "clomonitor";
 
 /// Check if the content of any of the files that match the globs provided
 /// matches any of the regular expressions given, returning the captured value
 /// when there is a match. This function expects that the regular expressions
 /// provided contain one capture group.
 pub(crate) fn find(globs: &Globs, regexps: &[&Regex]) -> Result<Option<String>> {
+    let patterns: Vec<&str> = regexps.iter().map(|re| re.as_str()).collect();
+    let analysis = format!("find:{}", patterns.join("\0"));
     for path in path::matches(globs)?.iter() {
         if let Ok(content) = fs::read_to_string(path) {
-            for re in regexps.iter() {
-                if let Some(c) = re.captures(&content) {
-                    if c.len() > 1 {
-                        return Ok(Some(c[1].to_string()));
-                    }
-                }
+            let found = content_cache::analyze(&analysis, &content, |content| {
+                regexps.iter().find_map(|re| {
+                    let c = re.captures(content)?;
+                    (c.len() > 1).then(|| c[1].to_string())
+                })
+            });
+            if found.is_some() {
+                return Ok(found);
             }
         }
     }
@@ -25,27 +57,250 @@ pub(crate) fn find(globs: &Globs, regexps: &[&Regex]) -> Result<Option<String>>
 /// Check if the content of any of the files that match the globs provided
 /// matches any of the regular expressions given.
 pub(crate) fn matches(globs: &Globs, re: &RegexSet) -> Result<bool> {
+    let analysis = format!("matches:{}", re.patterns().join("\0"));
     Ok(path::matches(globs)?.iter().any(|path| {
         if let Ok(content) = fs::read_to_string(path) {
-            return re.is_match(&content);
+            return content_cache::analyze(&analysis, &content, |content| re.is_match(content));
         }
         false
     }))
 }
 
+/// Get the content of the url provided using the restricted client given.
+/// When a cache is provided, the content will be looked up there first, and
+/// stored on it once fetched.
+pub(crate) async fn remote(
+    restricted_client: &RestrictedClient,
+    url: &str,
+    cache: Option<&DynCache>,
+) -> Result<String> {
+    let key = format!("probe:remote-content:{url}");
+    let content = cache::get_or_insert_with(cache, &key, REMOTE_CONTENT_CACHE_TTL, async {
+        let resp = restricted_client.get(url)?.send().await?;
+        Ok(resp.bytes().await?.to_vec())
+    })
+    .await?;
+    Ok(String::from_utf8_lossy(&content).into_owned())
+}
+
+/// Remote content stored in the cache, along with the validators returned by
+/// the server needed to revalidate it using conditional requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedContent {
+    content: Option<String>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// Get the content of the url provided using conditional requests. When a
+/// cache is provided, the content previously stored under the key given is
+/// revalidated using the ETag and Last-Modified validators returned by the
+/// server, and reused when it hasn't changed. Immutable content (i.e. a file
+/// at a given commit) is reused without revalidating it. None is returned when
+/// the url is not found.
+pub(crate) async fn remote_conditional(
+    http_client: &reqwest::Client,
+    url: &str,
+    key: &str,
+    immutable: bool,
+    cache: Option<&DynCache>,
+) -> Result<Option<String>> {
+    // Content previously fetched
+    let cached: Option<CachedContent> = match cache {
+        Some(cache) => match cache.get(key).await {
+            Ok(value) => value.and_then(|value| serde_json::from_slice(&value).ok()),
+            Err(err) => {
+                warn!(key, "error getting value from cache: {err:#}");
+                None
+            }
+        },
+        None => None,
+    };
+    if let Some(cached) = &cached {
+        if immutable {
+            return Ok(cached.content.clone());
+        }
+    }
+
+    // Fetch content, revalidating the cached one when available
+    let mut req = http_client.get(url);
+    if let Some(cached) = &cached {
+        if let Some(etag) = &cached.etag {
+            req = req.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            req = req.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+    let resp = req.send().await.context(format!("error fetching {url}"))?;
+    let header = |name: HeaderName| {
+        resp.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string)
+    };
+    let etag = header(ETAG);
+    let last_modified = header(LAST_MODIFIED);
+    let entry = match (resp.status(), cached) {
+        (StatusCode::NOT_MODIFIED, Some(cached)) => cached,
+        (status, _) if status.is_success() => CachedContent {
+            content: Some(resp.text().await?),
+            etag,
+            last_modified,
+        },
+        (StatusCode::NOT_FOUND, _) => CachedContent {
+            content: None,
+            etag,
+            last_modified,
+        },
+        (status, _) => return Err(format_err!("unexpected status fetching {url}: {status}")),
+    };
+
+    // Store (or refresh) entry in the cache
+    if let Some(cache) = cache {
+        let value = serde_json::to_vec(&entry)?;
+        if let Err(err) = cache.set(key, &value, CONDITIONAL_CONTENT_CACHE_TTL).await {
+            warn!(key, "error storing value in cache: {err:#}");
+        }
+    }
+
+    Ok(entry.content)
+}
+
 /// Check if the content of the url provided matches any of the regular
 /// expressions given.
-pub(crate) async fn remote_matches(url: &str, re: &RegexSet) -> Result<bool> {
-    let content = reqwest::get(url).await?.text().await?;
+pub(crate) async fn remote_matches(
+    restricted_client: &RestrictedClient,
+    url: &str,
+    re: &RegexSet,
+    cache: Option<&DynCache>,
+) -> Result<bool> {
+    let content = remote(restricted_client, url, cache).await?;
     Ok(re.is_match(&content))
 }
 
+/// Get the content of the url provided, honoring the rules defined in the
+/// site's robots.txt file. None is returned when fetching the url is not
+/// allowed. Both the content and the robots.txt file are fetched using the
+/// restricted client given, and cached when a cache is provided.
+pub(crate) async fn remote_if_allowed(
+    restricted_client: &RestrictedClient,
+    url: &str,
+    cache: Option<&DynCache>,
+) -> Result<Option<String>> {
+    let parsed_url = Url::parse(url)?;
+    let robots_url = parsed_url.join("/robots.txt")?;
+    let key = format!("probe:robots:{robots_url}");
+    let robots = cache::get_or_insert_with(cache, &key, ROBOTS_CACHE_TTL, async {
+        // Sites without a robots.txt file (or not serving it successfully)
+        // don't restrict access
+        let resp = restricted_client.get(robots_url.as_str())?.send().await?;
+        if !resp.status().is_success() {
+            return Ok(vec![]);
+        }
+        Ok(resp.bytes().await?.to_vec())
+    })
+    .await?;
+
+    let mut path = parsed_url.path().to_string();
+    if let Some(query) = parsed_url.query() {
+        path.push('?');
+        path.push_str(query);
+    }
+    if !robots_allow(&String::from_utf8_lossy(&robots), ROBOTS_USER_AGENT, &path) {
+        return Ok(None);
+    }
+    Ok(Some(remote(restricted_client, url, cache).await?))
+}
+
+/// Check if the robots.txt content provided allows the user agent given to
+/// fetch the path provided. The rules of the groups matching the user agent
+/// are used when available, falling back to the ones of the `*` group. The
+/// most specific (longest) rule matching the path wins, and allow rules take
+/// precedence over disallow ones of the same length.
+fn robots_allow(robots: &str, user_agent: &str, path: &str) -> bool {
+    let mut groups: Vec<(Vec<String>, Vec<(bool, String)>)> = vec![];
+    let mut in_agents_list = false;
+    for line in robots.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        let Some((field, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match field.trim().to_lowercase().as_str() {
+            "user-agent" => {
+                if !in_agents_list {
+                    groups.push((vec![], vec![]));
+                }
+                if let Some((agents, _)) = groups.last_mut() {
+                    agents.push(value.to_lowercase());
+                }
+                in_agents_list = true;
+            }
+            field @ ("allow" | "disallow") => {
+                in_agents_list = false;
+                if value.is_empty() {
+                    continue;
+                }
+                if let Some((_, rules)) = groups.last_mut() {
+                    rules.push((field == "allow", value.to_string()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Select the rules that apply to the user agent provided
+    let has_group = |agent: &str| {
+        groups
+            .iter()
+            .any(|(agents, _)| agents.iter().any(|a| a == agent))
+    };
+    let agent = if has_group(user_agent) {
+        user_agent
+    } else {
+        "*"
+    };
+    let rules = groups
+        .iter()
+        .filter(|(agents, _)| agents.iter().any(|a| a == agent))
+        .flat_map(|(_, rules)| rules);
+
+    // Find the most specific rule matching the path
+    rules
+        .filter(|(_, pattern)| robots_pattern_matches(pattern, path))
+        .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+        .map_or(true, |(allow, _)| *allow)
+}
+
+/// Check if the robots.txt rule pattern provided matches the path given.
+/// Patterns may contain `*` wildcards and end with `$` to anchor them.
+fn robots_pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+    let mut expr = String::from("^");
+    expr.push_str(
+        &pattern
+            .split('*')
+            .map(regex::escape)
+            .collect::<Vec<String>>()
+            .join(".*"),
+    );
+    if anchored {
+        expr.push('$');
+    }
+    Regex::new(&expr).map_or(false, |re| re.is_match(path))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::Path;
+    use crate::cache::MemoryCache;
+    use std::{path::Path, sync::Arc};
     use wiremock::{
-        matchers::{method, path},
+        matchers::{header, method, path},
         Mock, MockServer, ResponseTemplate,
     };
 
@@ -180,16 +435,155 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        assert!(
-            remote_matches(&mock_server.uri(), &RegexSet::new(["data"]).unwrap(),)
+        assert!(remote_matches(
+            &RestrictedClient::default(),
+            &mock_server.uri(),
+            &RegexSet::new(["data"]).unwrap(),
+            None
+        )
+        .await
+        .unwrap());
+    }
+
+    #[tokio::test]
+    async fn remote_matches_no_match() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("sample data"))
+            .expect(1)
+            .named("root GET")
+            .mount(&mock_server)
+            .await;
+
+        assert!(!remote_matches(
+            &RestrictedClient::default(),
+            &mock_server.uri(),
+            &RegexSet::new(["notfound"]).unwrap(),
+            None
+        )
+        .await
+        .unwrap());
+    }
+
+    #[tokio::test]
+    async fn remote_conditional_revalidated() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/file.md"))
+            .and(header("if-none-match", "\"etag1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .named("file conditional GET")
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/file.md"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"etag1\"")
+                    .set_body_string("sample data"),
+            )
+            .expect(1)
+            .named("file GET")
+            .mount(&mock_server)
+            .await;
+
+        let cache: DynCache = Arc::new(MemoryCache::new());
+        let http_client = reqwest::Client::new();
+        let url = format!("{}/file.md", mock_server.uri());
+        for _ in 0..2 {
+            assert_eq!(
+                remote_conditional(&http_client, &url, "key", false, Some(&cache))
+                    .await
+                    .unwrap(),
+                Some("sample data".to_string())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn remote_conditional_immutable_not_revalidated() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/file.md"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"etag1\"")
+                    .set_body_string("sample data"),
+            )
+            .expect(1)
+            .named("file GET")
+            .mount(&mock_server)
+            .await;
+
+        let cache: DynCache = Arc::new(MemoryCache::new());
+        let http_client = reqwest::Client::new();
+        let url = format!("{}/file.md", mock_server.uri());
+        for _ in 0..2 {
+            assert_eq!(
+                remote_conditional(&http_client, &url, "key", true, Some(&cache))
+                    .await
+                    .unwrap(),
+                Some("sample data".to_string())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn remote_conditional_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/file.md"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .named("file GET")
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/file.md", mock_server.uri());
+        assert_eq!(
+            remote_conditional(&reqwest::Client::new(), &url, "key", false, None)
                 .await
-                .unwrap()
+                .unwrap(),
+            None
         );
     }
 
+    #[test]
+    fn robots_allow_rules() {
+        let robots = r"
+# Sample robots.txt
+User-agent: *
+Disallow: /private/
+Allow: /private/public.html
+
+User-agent: otherbot
+User-agent: clomonitor
+Disallow: /
+Allow: /$
+Allow: /docs/*.html
+";
+        assert!(robots_allow(robots, "clomonitor", "/"));
+        assert!(robots_allow(robots, "clomonitor", "/docs/index.html"));
+        assert!(!robots_allow(robots, "clomonitor", "/about"));
+        assert!(robots_allow(robots, "somebot", "/about"));
+        assert!(!robots_allow(robots, "somebot", "/private/index.html"));
+        assert!(robots_allow(robots, "somebot", "/private/public.html"));
+        assert!(robots_allow("", "clomonitor", "/private/"));
+        assert!(robots_allow("User-agent: *\nDisallow:", "clomonitor", "/"));
+    }
+
     #[tokio::test]
-    async fn remote_matches_no_match() {
+    async fn remote_if_allowed_allowed() {
         let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/robots.txt"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .named("robots GET")
+            .mount(&mock_server)
+            .await;
         Mock::given(method("GET"))
             .and(path("/"))
             .respond_with(ResponseTemplate::new(200).set_body_string("sample data"))
@@ -198,17 +592,52 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        assert!(
-            !remote_matches(&mock_server.uri(), &RegexSet::new(["notfound"]).unwrap(),)
+        assert_eq!(
+            remote_if_allowed(&RestrictedClient::default(), &mock_server.uri(), None)
+                .await
+                .unwrap(),
+            Some("sample data".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn remote_if_allowed_disallowed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: clomonitor\nDisallow: /"),
+            )
+            .expect(1)
+            .named("robots GET")
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("sample data"))
+            .expect(0)
+            .named("root GET")
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(
+            remote_if_allowed(&RestrictedClient::default(), &mock_server.uri(), None)
                 .await
-                .unwrap()
+                .unwrap(),
+            None
         );
     }
 
     #[tokio::test]
     async fn remote_matches_request_failed() {
         assert!(matches!(
-            remote_matches("http://localhost:0", &RegexSet::new(["data"]).unwrap(),).await,
+            remote_matches(
+                &RestrictedClient::default(),
+                "http://localhost:0",
+                &RegexSet::new(["data"]).unwrap(),
+                None
+            )
+            .await,
             Err(_)
         ));
     }
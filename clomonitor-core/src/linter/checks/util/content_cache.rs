@@ -0,0 +1,87 @@
+use lazy_static::lazy_static;
+use sha2::{Digest, Sha256};
+use std::{any::Any, collections::HashMap, sync::Mutex};
+
+/// Maximum number of results kept in the cache. The cache is cleared when it
+/// is full, so that it does not grow unbounded in long running processes.
+const MAX_ENTRIES: usize = 10_000;
+
+/// Digest of an analysis identifier and the content analyzed.
+type ContentDigest = [u8; 32];
+
+lazy_static! {
+    /// Results of the analyses of files content, indexed by the digest of the
+    /// analysis identifier and the content analyzed. It is shared by all the
+    /// repositories linted by this process, so identical files (i.e. licenses
+    /// or codes of conduct adopted verbatim by forks and multi-repo projects)
+    /// are only analyzed once.
+    static ref RESULTS: Mutex<HashMap<ContentDigest, Box<dyn Any + Send + Sync>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Return the result of running the analysis provided on the content given,
+/// reusing the one obtained previously for an identical content when
+/// available. Analyses must only depend on the content, and their identifier
+/// must change whenever the same content may produce a different result (i.e.
+/// it should include the regular expressions used).
+pub(crate) fn analyze<T, F>(analysis: &str, content: &str, f: F) -> T
+where
+    T: Clone + Send + Sync + 'static,
+    F: FnOnce(&str) -> T,
+{
+    let key = digest(analysis, content);
+    if let Some(result) = RESULTS
+        .lock()
+        .expect("lock not to be poisoned")
+        .get(&key)
+        .and_then(|result| result.downcast_ref::<T>())
+    {
+        return result.clone();
+    }
+
+    // The lock is not held while the analysis runs, so that other analyses
+    // can proceed concurrently
+    let result = f(content);
+    let mut results = RESULTS.lock().expect("lock not to be poisoned");
+    if results.len() >= MAX_ENTRIES {
+        results.clear();
+    }
+    results.insert(key, Box::new(result.clone()));
+    result
+}
+
+/// Calculate the digest of the analysis identifier and content provided.
+fn digest(analysis: &str, content: &str) -> ContentDigest {
+    let mut hasher = Sha256::new();
+    hasher.update(analysis.as_bytes());
+    hasher.update([0]);
+    hasher.update(content.as_bytes());
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn analyze_reuses_results_for_identical_content() {
+        let runs = Cell::new(0);
+        let analysis = |content: &str| {
+            runs.set(runs.get() + 1);
+            content.len()
+        };
+
+        assert_eq!(analyze("test:reuse", "content", analysis), 7);
+        assert_eq!(analyze("test:reuse", "content", analysis), 7);
+        assert_eq!(runs.get(), 1);
+        assert_eq!(analyze("test:reuse", "other content", analysis), 13);
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn analyze_results_scoped_to_analysis() {
+        assert!(analyze("test:scope1", "content", |_| true));
+        assert!(!analyze("test:scope2", "content", |_| false));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::net;
+use anyhow::{format_err, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::collections::HashMap;
+
+/// Prefix Gerrit adds to all its JSON responses to prevent XSSI.
+const XSSI_PREFIX: &str = ")]}'";
+
+/// Maximum number of merged changes fetched from the Gerrit API when looking
+/// for the reviews they got.
+pub(crate) const MAX_MERGED_CHANGES: usize = 30;
+
+/// Label used by Gerrit to vote on the changes under review.
+const CODE_REVIEW_LABEL: &str = "Code-Review";
+
+lazy_static! {
+    static ref GERRIT_PROJECT_URL: Regex = Regex::new(
+        "^(?P<base>https?://[^/]+(?:/[^/]+)*?)/admin/repos/(?P<project>[^,]+?)(?:,[a-z]+)?/?$"
+    )
+    .expect("exprs in GERRIT_PROJECT_URL to be valid");
+}
+
+/// Change information returned by the Gerrit API.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct Change {
+    pub owner: Account,
+    #[serde(default)]
+    pub labels: HashMap<String, Label>,
+}
+
+impl Change {
+    /// Check if the change has been approved by someone other than its
+    /// owner.
+    pub(crate) fn reviewed(&self) -> bool {
+        self.labels.get(CODE_REVIEW_LABEL).map_or(false, |label| {
+            label.all.iter().any(|approval| {
+                approval.value.unwrap_or_default() > 0
+                    && approval.account_id != self.owner.account_id
+            })
+        })
+    }
+}
+
+/// Account information returned by the Gerrit API.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct Account {
+    #[serde(rename = "_account_id")]
+    pub account_id: u64,
+}
+
+/// Label information (detailed) returned by the Gerrit API.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct Label {
+    #[serde(default)]
+    pub all: Vec<Approval>,
+}
+
+/// Approval information returned by the Gerrit API.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct Approval {
+    #[serde(rename = "_account_id")]
+    pub account_id: u64,
+    pub value: Option<i32>,
+}
+
+/// Get the changes merged in the Gerrit project provided in the last days
+/// given (most recently updated first, up to the limit provided).
+pub(crate) async fn merged_changes(
+    project_url: &str,
+    max_age_days: usize,
+    limit: usize,
+) -> Result<Vec<Change>> {
+    let (base, project) = get_base_and_project(project_url)?;
+    let query = format!("project:{project} status:merged -age:{max_age_days}d");
+    let changes: Option<Vec<Change>> = query_changes(
+        &base,
+        &query,
+        limit,
+        &["DETAILED_LABELS", "DETAILED_ACCOUNTS"],
+    )
+    .await?;
+    changes.ok_or_else(|| format_err!("invalid changes query"))
+}
+
+/// Check if the Gerrit user provided has owned or commented on any of the
+/// changes of the project given since the date provided (yyyy-mm-dd).
+pub(crate) async fn user_active_since(
+    project_url: &str,
+    username: &str,
+    since: &str,
+) -> Result<bool> {
+    let (base, project) = get_base_and_project(project_url)?;
+    let query =
+        format!("project:{project} (owner:{username} OR commentby:{username}) after:{since}");
+    let changes: Option<Vec<serde_json::Value>> = query_changes(&base, &query, 1, &[]).await?;
+
+    // Users that don't have an account in the Gerrit instance make the query
+    // invalid, so they are considered inactive
+    Ok(changes.map_or(false, |changes| !changes.is_empty()))
+}
+
+/// Query the changes in the Gerrit instance provided. None is returned when
+/// Gerrit rejects the query (i.e. it references unknown accounts).
+async fn query_changes<T: DeserializeOwned>(
+    base: &str,
+    query: &str,
+    limit: usize,
+    options: &[&str],
+) -> Result<Option<T>> {
+    let http_client = net::client_builder().user_agent("clomonitor").build()?;
+    let mut params = vec![("q", query.to_string()), ("n", limit.to_string())];
+    params.extend(options.iter().map(|option| ("o", option.to_string())));
+    let resp = http_client
+        .get(format!("{base}/changes/"))
+        .query(&params)
+        .send()
+        .await
+        .context("error querying changes api")?;
+    match resp.status() {
+        StatusCode::OK => Ok(Some(parse(&resp.text().await?)?)),
+        StatusCode::BAD_REQUEST => Ok(None),
+        status => Err(format_err!(
+            "unexpected status code querying changes api: {} - {}",
+            status,
+            resp.text().await?,
+        )),
+    }
+}
+
+/// Parse the Gerrit API response body provided.
+fn parse<T: DeserializeOwned>(body: &str) -> Result<T> {
+    let json = body.trim_start().trim_start_matches(XSSI_PREFIX);
+    serde_json::from_str(json).context(format!("error deserializing gerrit response: {body}"))
+}
+
+/// Extract the base url and the project name from the Gerrit project url
+/// (i.e. `https://gerrit.example.org/r/admin/repos/org/project`).
+fn get_base_and_project(project_url: &str) -> Result<(String, String)> {
+    let c = GERRIT_PROJECT_URL
+        .captures(project_url)
+        .ok_or_else(|| format_err!("invalid gerrit project url"))?;
+    Ok((c["base"].to_string(), c["project"].to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn get_base_and_project_valid_url() {
+        assert_eq!(
+            get_base_and_project("https://gerrit.onap.org/r/admin/repos/ccsdk/parent").unwrap(),
+            (
+                "https://gerrit.onap.org/r".to_string(),
+                "ccsdk/parent".to_string()
+            )
+        );
+        assert_eq!(
+            get_base_and_project("https://gerrit.example.org/admin/repos/project,general").unwrap(),
+            (
+                "https://gerrit.example.org".to_string(),
+                "project".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn get_base_and_project_invalid_url() {
+        assert!(get_base_and_project("https://gerrit.example.org/r/project").is_err());
+    }
+
+    #[test]
+    fn change_reviewed_by_someone_else() {
+        let change: Change = parse(
+            r#")]}'
+            {
+                "owner": {"_account_id": 1},
+                "labels": {"Code-Review": {"all": [
+                    {"_account_id": 1, "value": 2},
+                    {"_account_id": 2, "value": 0}
+                ]}}
+            }"#,
+        )
+        .unwrap();
+        assert!(!change.reviewed());
+
+        let change: Change = parse(
+            r#")]}'
+            {
+                "owner": {"_account_id": 1},
+                "labels": {"Code-Review": {"all": [{"_account_id": 2, "value": 2}]}}
+            }"#,
+        )
+        .unwrap();
+        assert!(change.reviewed());
+    }
+
+    #[tokio::test]
+    async fn user_active_since_unknown_account() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/r/changes/"))
+            .and(query_param("n", "1"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("Account 'user' not found"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let project_url = format!("{}/r/admin/repos/org/project", mock_server.uri());
+        assert!(!user_active_since(&project_url, "user", "2023-01-01")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn merged_changes_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/r/changes/"))
+            .and(query_param(
+                "q",
+                "project:org/project status:merged -age:90d",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                ")]}'\n[{\"owner\": {\"_account_id\": 1}}, {\"owner\": {\"_account_id\": 2}}]",
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let project_url = format!("{}/r/admin/repos/org/project", mock_server.uri());
+        let changes = merged_changes(&project_url, 90, MAX_MERGED_CHANGES)
+            .await
+            .unwrap();
+        assert_eq!(changes.len(), 2);
+    }
+}
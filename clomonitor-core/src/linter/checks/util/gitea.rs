@@ -0,0 +1,278 @@
+use super::github::md::*;
+use crate::net;
+use anyhow::{format_err, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+
+/// Maximum number of releases fetched from the Gitea API (same number of
+/// releases fetched from the GitHub one).
+const MAX_RELEASES: usize = 30;
+
+lazy_static! {
+    static ref GITEA_REPO_URL: Regex =
+        Regex::new("^(?P<base>https?://[^/]+)/(?P<owner>[^/]+)/(?P<repo>[^/]+?)(?:\\.git)?/?$")
+            .expect("exprs in GITEA_REPO_URL to be valid");
+}
+
+/// Repository information returned by the Gitea API.
+#[derive(Debug, Deserialize)]
+struct Repository {
+    name: String,
+    owner: Owner,
+    default_branch: Option<String>,
+    website: Option<String>,
+    #[serde(default)]
+    licenses: Vec<String>,
+}
+
+/// Repository owner information returned by the Gitea API.
+#[derive(Debug, Deserialize)]
+struct Owner {
+    login: String,
+}
+
+/// Release information returned by the Gitea API.
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    body: Option<String>,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    created_at: String,
+    published_at: Option<String>,
+    html_url: String,
+    #[serde(default)]
+    assets: Vec<Asset>,
+}
+
+/// Release asset information returned by the Gitea API.
+#[derive(Debug, Deserialize)]
+struct Asset {
+    name: String,
+}
+
+/// Get repository's metadata from the Gitea API. The metadata is returned in
+/// the same format used for repositories hosted on GitHub, so that the checks
+/// relying on it can run on repositories hosted on Gitea as well.
+pub(crate) async fn metadata(repo_url: &str) -> Result<MdRepository> {
+    let (base, owner, repo) = get_base_owner_and_repo(repo_url)?;
+    let http_client = net::client_builder().user_agent("clomonitor").build()?;
+    let api_url = format!("{base}/api/v1/repos/{owner}/{repo}");
+
+    // Get repository and releases information
+    let repository: Repository = get(&http_client, &api_url, "repository").await?;
+    let releases: Vec<Release> = get(
+        &http_client,
+        &format!("{api_url}/releases?draft=false&limit={MAX_RELEASES}"),
+        "releases",
+    )
+    .await?;
+
+    // Build repository metadata
+    let releases_nodes = releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .map(|release| {
+            Some(MdRepositoryReleasesNodes {
+                created_at: release.published_at.unwrap_or(release.created_at),
+                description: release.body,
+                is_prerelease: release.prerelease,
+                release_assets: MdRepositoryReleasesNodesReleaseAssets {
+                    nodes: Some(
+                        release
+                            .assets
+                            .into_iter()
+                            .map(|asset| {
+                                Some(MdRepositoryReleasesNodesReleaseAssetsNodes {
+                                    name: asset.name,
+                                })
+                            })
+                            .collect(),
+                    ),
+                },
+                tag_name: release.tag_name,
+                url: release.html_url,
+            })
+        })
+        .collect();
+    Ok(MdRepository {
+        default_branch_ref: repository
+            .default_branch
+            .filter(|name| !name.is_empty())
+            .map(|name| MdRepositoryDefaultBranchRef { name }),
+        homepage_url: repository.website.filter(|url| !url.trim().is_empty()),
+        license_info: repository.licenses.into_iter().next().map(|spdx_id| {
+            MdRepositoryLicenseInfo {
+                spdx_id: Some(spdx_id),
+            }
+        }),
+        name: repository.name,
+        owner: MdRepositoryOwner {
+            login: repository.owner.login,
+            on: MdRepositoryOwnerOn::Organization,
+        },
+        releases: MdRepositoryReleases {
+            nodes: Some(releases_nodes),
+        },
+        ..MdRepository::default()
+    })
+}
+
+/// Get the resource available at the Gitea API url provided.
+async fn get<T: DeserializeOwned>(
+    http_client: &reqwest::Client,
+    url: &str,
+    resource: &str,
+) -> Result<T> {
+    let resp = http_client
+        .get(url)
+        .send()
+        .await
+        .context(format!("error querying {resource} api"))?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying {resource} api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let body = resp.text().await?;
+    serde_json::from_str(&body).context(format!("error deserializing {resource}: {body}"))
+}
+
+/// Extract the base url, owner and repository from the repository url.
+fn get_base_owner_and_repo(repo_url: &str) -> Result<(String, String, String)> {
+    let c = GITEA_REPO_URL
+        .captures(repo_url)
+        .ok_or_else(|| format_err!("invalid repository url"))?;
+    Ok((
+        c["base"].to_string(),
+        c["owner"].to_string(),
+        c["repo"].to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn get_base_owner_and_repo_valid_url() {
+        assert_eq!(
+            get_base_owner_and_repo("https://codeberg.org/forgejo/forgejo.git").unwrap(),
+            (
+                "https://codeberg.org".to_string(),
+                "forgejo".to_string(),
+                "forgejo".to_string()
+            )
+        );
+        assert_eq!(
+            get_base_owner_and_repo("https://gitea.example.com/org/repo/").unwrap(),
+            (
+                "https://gitea.example.com".to_string(),
+                "org".to_string(),
+                "repo".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn get_base_owner_and_repo_invalid_url() {
+        assert!(get_base_owner_and_repo("https://codeberg.org/forgejo").is_err());
+    }
+
+    #[tokio::test]
+    async fn metadata_built_from_gitea_api() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/org/repo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "name": "repo",
+                "owner": {"login": "org"},
+                "default_branch": "main",
+                "website": "https://repo.example.com",
+                "licenses": ["Apache-2.0"]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/org/repo/releases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "tag_name": "v1.1.0-rc.1",
+                    "body": "",
+                    "draft": false,
+                    "prerelease": true,
+                    "created_at": "2023-02-01T10:00:00Z",
+                    "published_at": "2023-02-01T10:00:00Z",
+                    "html_url": "https://gitea.example.com/org/repo/releases/tag/v1.1.0-rc.1",
+                    "assets": []
+                },
+                {
+                    "tag_name": "v1.0.0",
+                    "body": "## Features",
+                    "draft": false,
+                    "prerelease": false,
+                    "created_at": "2023-01-01T10:00:00Z",
+                    "published_at": "2023-01-02T10:00:00Z",
+                    "html_url": "https://gitea.example.com/org/repo/releases/tag/v1.0.0",
+                    "assets": [{"name": "sbom.spdx.json"}]
+                }
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let md = metadata(&format!("{}/org/repo", mock_server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(md.name, "repo");
+        assert_eq!(md.owner.login, "org");
+        assert_eq!(md.default_branch_ref.unwrap().name, "main");
+        assert_eq!(md.homepage_url.as_deref(), Some("https://repo.example.com"));
+        assert_eq!(
+            md.license_info.unwrap().spdx_id.as_deref(),
+            Some("Apache-2.0")
+        );
+        let releases: Vec<MdRepositoryReleasesNodes> =
+            md.releases.nodes.unwrap().into_iter().flatten().collect();
+        assert_eq!(releases.len(), 2);
+        assert!(releases[0].is_prerelease);
+        assert_eq!(releases[1].tag_name, "v1.0.0");
+        assert_eq!(releases[1].created_at, "2023-01-02T10:00:00Z");
+        assert_eq!(releases[1].description.as_deref(), Some("## Features"));
+        assert_eq!(
+            releases[1].release_assets.nodes.as_ref().unwrap()[0]
+                .as_ref()
+                .unwrap()
+                .name,
+            "sbom.spdx.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn metadata_repository_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/repos/org/repo"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        assert!(metadata(&format!("{}/org/repo", mock_server.uri()))
+            .await
+            .is_err());
+    }
+}
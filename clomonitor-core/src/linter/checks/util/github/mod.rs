@@ -1,14 +1,33 @@
 use self::md::*;
+use super::content;
+use crate::{cache::DynCache, net};
 use anyhow::{format_err, Context, Result};
 use graphql_client::{GraphQLQuery, Response};
 use http::StatusCode;
 use lazy_static::lazy_static;
 use regex::{Regex, RegexSet};
+use serde::Deserialize;
 use std::path::Path;
 
 /// GitHub GraphQL API URL.
 const GITHUB_GRAPHQL_API: &str = "https://api.github.com/graphql";
 
+/// GitHub API rate limit endpoint URL (requests to it do not count against
+/// the rate limit).
+const GITHUB_RATE_LIMIT_API: &str = "https://api.github.com/rate_limit";
+
+/// GitHub REST API URL.
+const GITHUB_REST_API: &str = "https://api.github.com";
+
+/// GitHub raw content URL.
+const GITHUB_RAW_CONTENT: &str = "https://raw.githubusercontent.com";
+
+/// Maximum number of release assets whose attestations will be looked up.
+const MAX_ATTESTED_ASSETS: usize = 10;
+
+/// Header used by GitHub to report the scopes granted to a token.
+const GITHUB_OAUTH_SCOPES_HEADER: &str = "x-oauth-scopes";
+
 lazy_static! {
     static ref GITHUB_REPO_URL: Regex =
         Regex::new("^https://github.com/(?P<org>[^/]+)/(?P<repo>[^/]+)/?$")
@@ -31,9 +50,8 @@ type DateTime = String;
 )]
 pub struct Md;
 
-impl MdRepository {
-    #[cfg(test)]
-    pub(crate) fn default() -> Self {
+impl Default for MdRepository {
+    fn default() -> Self {
         Self {
             code_of_conduct: None,
             default_branch_ref: Some(MdRepositoryDefaultBranchRef {
@@ -88,6 +106,366 @@ pub(crate) async fn metadata(repo_url: &str, token: &str) -> Result<MdRepository
     Ok(repo)
 }
 
+/// Get the scopes granted to the GitHub token provided. None is returned when
+/// the token does not report any scopes (i.e. fine-grained tokens).
+pub(crate) async fn token_scopes(token: &str) -> Result<Option<Vec<String>>> {
+    let http_client = setup_http_client(token)?;
+    let resp = http_client
+        .get(GITHUB_RATE_LIMIT_API)
+        .send()
+        .await
+        .context("error querying rate limit api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying rate limit api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let scopes = resp
+        .headers()
+        .get(GITHUB_OAUTH_SCOPES_HEADER)
+        .map(|v| parse_scopes(&String::from_utf8_lossy(v.as_bytes())));
+    Ok(scopes)
+}
+
+/// Return the names of the assets of the release provided that have a build
+/// provenance attestation registered in GitHub (artifact attestations API).
+pub(crate) async fn release_attested_assets(
+    repo_url: &str,
+    tag: &str,
+    token: &str,
+) -> Result<Vec<String>> {
+    let (owner, repo) = get_owner_and_repo(repo_url)?;
+    let http_client = setup_http_client(token)?;
+
+    // Get release assets digests
+    let url = format!("{GITHUB_REST_API}/repos/{owner}/{repo}/releases/tags/{tag}");
+    let resp = http_client
+        .get(&url)
+        .send()
+        .await
+        .context("error querying releases api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying releases api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let release: serde_json::Value = resp.json().await?;
+    let assets: Vec<(String, String)> = release["assets"]
+        .as_array()
+        .map(|assets| {
+            assets
+                .iter()
+                .filter_map(|asset| {
+                    Some((
+                        asset["name"].as_str()?.to_string(),
+                        asset["digest"].as_str()?.to_string(),
+                    ))
+                })
+                .take(MAX_ATTESTED_ASSETS)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Check which of them have a provenance attestation
+    let mut attested = vec![];
+    for (name, digest) in assets {
+        let url = format!(
+            "{GITHUB_REST_API}/repos/{owner}/{repo}/attestations/{digest}?predicate_type=provenance"
+        );
+        let resp = http_client
+            .get(&url)
+            .send()
+            .await
+            .context("error querying attestations api")?;
+        match resp.status() {
+            StatusCode::OK => {
+                let body: serde_json::Value = resp.json().await?;
+                if body["attestations"]
+                    .as_array()
+                    .map_or(false, |attestations| !attestations.is_empty())
+                {
+                    attested.push(name);
+                }
+            }
+            StatusCode::NOT_FOUND => {}
+            status => {
+                return Err(format_err!(
+                    "unexpected status code querying attestations api: {} - {}",
+                    status,
+                    resp.text().await?,
+                ))
+            }
+        }
+    }
+
+    Ok(attested)
+}
+
+/// Organization settings returned by the GitHub REST API. Some of them are
+/// only returned when the token used has admin permissions on the org.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub(crate) struct OrgSettings {
+    pub two_factor_requirement_enabled: Option<bool>,
+    pub default_repository_permission: Option<String>,
+    pub secret_scanning_enabled_for_new_repositories: Option<bool>,
+}
+
+/// Get the settings of the GitHub organization provided.
+pub(crate) async fn org_settings(org: &str, token: &str) -> Result<OrgSettings> {
+    let http_client = setup_http_client(token)?;
+    let resp = http_client
+        .get(format!("{GITHUB_REST_API}/orgs/{org}"))
+        .send()
+        .await
+        .context("error querying organizations api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying organizations api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let body = resp.text().await?;
+    serde_json::from_str(&body).context(format!("error deserializing organization: {body}"))
+}
+
+/// Branch protection settings in effect on a repository's branch, combining
+/// the classic branch protection rules and the rulesets that apply to it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct BranchProtection {
+    pub protected: bool,
+    pub required_status_checks: bool,
+    pub required_approving_review_count: u64,
+    pub dismiss_stale_reviews: bool,
+    pub required_signatures: bool,
+    pub force_pushes_blocked: bool,
+}
+
+impl BranchProtection {
+    /// Apply the classic branch protection settings provided (as returned
+    /// by the branch protection REST API).
+    fn apply_classic(&mut self, protection: &serde_json::Value) {
+        self.protected = true;
+        if !protection["required_status_checks"].is_null() {
+            self.required_status_checks = true;
+        }
+        let reviews = &protection["required_pull_request_reviews"];
+        if !reviews.is_null() {
+            self.required_approving_review_count = self.required_approving_review_count.max(
+                reviews["required_approving_review_count"]
+                    .as_u64()
+                    .unwrap_or(1),
+            );
+            self.dismiss_stale_reviews |= reviews["dismiss_stale_reviews"].as_bool() == Some(true);
+        }
+        self.required_signatures |=
+            protection["required_signatures"]["enabled"].as_bool() == Some(true);
+        self.force_pushes_blocked |=
+            protection["allow_force_pushes"]["enabled"].as_bool() != Some(true);
+    }
+
+    /// Apply the rules provided (as returned by the branch rules REST API,
+    /// which include the rules of all the rulesets that apply to a branch).
+    fn apply_rules(&mut self, rules: &[serde_json::Value]) {
+        for rule in rules {
+            self.protected = true;
+            let parameters = &rule["parameters"];
+            match rule["type"].as_str() {
+                Some("required_status_checks") => self.required_status_checks = true,
+                Some("pull_request") => {
+                    self.required_approving_review_count =
+                        self.required_approving_review_count.max(
+                            parameters["required_approving_review_count"]
+                                .as_u64()
+                                .unwrap_or_default(),
+                        );
+                    self.dismiss_stale_reviews |=
+                        parameters["dismiss_stale_reviews_on_push"].as_bool() == Some(true);
+                }
+                Some("required_signatures") => self.required_signatures = true,
+                Some("non_fast_forward") => self.force_pushes_blocked = true,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Get the branch protection settings in effect on the branch provided. The
+/// classic branch protection settings are only available when the token has
+/// admin permissions on the repository, whereas rulesets are public.
+pub(crate) async fn branch_protection(
+    repo_url: &str,
+    branch: &str,
+    token: &str,
+) -> Result<BranchProtection> {
+    let (owner, repo) = get_owner_and_repo(repo_url)?;
+    let http_client = setup_http_client(token)?;
+    let mut protection = BranchProtection::default();
+
+    // Classic branch protection
+    let url = format!("{GITHUB_REST_API}/repos/{owner}/{repo}/branches/{branch}/protection");
+    let resp = http_client
+        .get(&url)
+        .send()
+        .await
+        .context("error querying branch protection api")?;
+    match resp.status() {
+        StatusCode::OK => protection.apply_classic(&resp.json().await?),
+        StatusCode::FORBIDDEN | StatusCode::NOT_FOUND => {}
+        status => {
+            return Err(format_err!(
+                "unexpected status code querying branch protection api: {} - {}",
+                status,
+                resp.text().await?,
+            ))
+        }
+    }
+
+    // Rulesets
+    let url = format!("{GITHUB_REST_API}/repos/{owner}/{repo}/rules/branches/{branch}");
+    let resp = http_client
+        .get(&url)
+        .send()
+        .await
+        .context("error querying branch rules api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying branch rules api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let rules: Vec<serde_json::Value> = resp.json().await?;
+    protection.apply_rules(&rules);
+
+    Ok(protection)
+}
+
+/// Security advisory published in a repository, as returned by the GitHub
+/// REST API (only the fields used are included).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub(crate) struct SecurityAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub html_url: String,
+}
+
+/// Check if private vulnerability reporting is enabled on the repository
+/// provided.
+pub(crate) async fn private_vulnerability_reporting(repo_url: &str, token: &str) -> Result<bool> {
+    let (owner, repo) = get_owner_and_repo(repo_url)?;
+    let http_client = setup_http_client(token)?;
+    let url = format!("{GITHUB_REST_API}/repos/{owner}/{repo}/private-vulnerability-reporting");
+    let resp = http_client
+        .get(&url)
+        .send()
+        .await
+        .context("error querying private vulnerability reporting api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying private vulnerability reporting api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let body: serde_json::Value = resp.json().await?;
+    Ok(body["enabled"].as_bool() == Some(true))
+}
+
+/// Get the security advisories published in the repository provided (up to
+/// the most recent 100).
+pub(crate) async fn published_security_advisories(
+    repo_url: &str,
+    token: &str,
+) -> Result<Vec<SecurityAdvisory>> {
+    let (owner, repo) = get_owner_and_repo(repo_url)?;
+    let http_client = setup_http_client(token)?;
+    let url = format!(
+        "{GITHUB_REST_API}/repos/{owner}/{repo}/security-advisories?state=published&per_page=100"
+    );
+    let resp = http_client
+        .get(&url)
+        .send()
+        .await
+        .context("error querying security advisories api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying security advisories api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let body = resp.text().await?;
+    serde_json::from_str(&body).context(format!("error deserializing security advisories: {body}"))
+}
+
+/// Check if the GitHub user provided has been active in the repository since
+/// the date given (YYYY-MM-DD). Users are considered active when they have
+/// authored any commits or reviewed any pull requests in that period.
+pub(crate) async fn user_active_since(
+    repo_url: &str,
+    login: &str,
+    since: &str,
+    token: &str,
+) -> Result<bool> {
+    let (owner, repo) = get_owner_and_repo(repo_url)?;
+    let http_client = setup_http_client(token)?;
+
+    // Commits authored
+    let url = format!(
+        "{GITHUB_REST_API}/repos/{owner}/{repo}/commits?author={login}&since={since}T00:00:00Z&per_page=1"
+    );
+    let resp = http_client
+        .get(&url)
+        .send()
+        .await
+        .context("error querying commits api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying commits api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let commits: Vec<serde_json::Value> = resp.json().await?;
+    if !commits.is_empty() {
+        return Ok(true);
+    }
+
+    // Pull requests reviewed
+    let url = format!(
+        "{GITHUB_REST_API}/search/issues?q=repo:{owner}/{repo}+type:pr+reviewed-by:{login}+updated:>={since}&per_page=1"
+    );
+    let resp = http_client
+        .get(&url)
+        .send()
+        .await
+        .context("error querying search api")?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying search api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let body: serde_json::Value = resp.json().await?;
+    Ok(body["total_count"].as_u64().unwrap_or_default() > 0)
+}
+
+/// Parse the scopes list provided (as returned in the scopes header).
+fn parse_scopes(scopes: &str) -> Vec<String> {
+    scopes
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
 /// Build a url from the path and metadata provided.
 pub(crate) fn build_url(path: &Path, owner: &str, repo: &str, branch: &str) -> String {
     format!(
@@ -112,20 +490,48 @@ pub(crate) fn default_branch(r: Option<&MdRepositoryDefaultBranchRef>) -> String
 /// the regular expressions provided.
 pub(crate) fn has_check(gh_md: &MdRepository, re: &RegexSet) -> Result<bool> {
     // Get latest PR head commit from metadata
-    let latest_pr_head_commit = gh_md
+    let latest_pr_head_commit = prs_head_commits(gh_md).next();
+
+    Ok(latest_pr_head_commit.map_or(false, |commit| commit_has_check(commit, re)))
+}
+
+/// Check if any of the recently merged PRs available in the metadata has a
+/// check that matches any of the regular expressions provided.
+pub(crate) fn recent_prs_have_check(gh_md: &MdRepository, re: &RegexSet) -> Result<bool> {
+    Ok(prs_head_commits(gh_md).any(|commit| commit_has_check(commit, re)))
+}
+
+/// Return an iterator over the head commits of the merged PRs available in the
+/// metadata provided (latest first).
+fn prs_head_commits(
+    gh_md: &MdRepository,
+) -> impl Iterator<Item = &MdRepositoryPullRequestsNodesCommitsNodesCommit> {
+    gh_md
         .pull_requests
         .nodes
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|pr| {
+            pr.commits
+                .nodes
+                .as_ref()
+                .and_then(|commits| commits.iter().next())
+                .and_then(|commit_opt| commit_opt.as_ref())
+                .map(|commit| &commit.commit)
+        })
+}
+
+/// Check if the commit provided has a check that matches any of the regular
+/// expressions given.
+fn commit_has_check(
+    commit: &MdRepositoryPullRequestsNodesCommitsNodesCommit,
+    re: &RegexSet,
+) -> bool {
+    // Get check suites from commit provided
+    let check_suites = commit
+        .check_suites
         .as_ref()
-        .and_then(|prs| prs.iter().next())
-        .and_then(|pr_opt| pr_opt.as_ref())
-        .and_then(|pr| pr.commits.nodes.as_ref())
-        .and_then(|commits| commits.iter().next())
-        .and_then(|commit_opt| commit_opt.as_ref())
-        .map(|commit| &commit.commit);
-
-    // Get check suites from commit obtained above
-    let check_suites = latest_pr_head_commit
-        .and_then(|commit| commit.check_suites.as_ref())
         .and_then(|check_suites| check_suites.nodes.as_ref());
 
     // Search in check suites apps name
@@ -137,7 +543,7 @@ pub(crate) fn has_check(gh_md: &MdRepository, re: &RegexSet) -> Result<bool> {
             false
         })
     }) {
-        return Ok(true);
+        return true;
     }
 
     // Search in check suites check runs name
@@ -159,18 +565,19 @@ pub(crate) fn has_check(gh_md: &MdRepository, re: &RegexSet) -> Result<bool> {
             false
         })
     }) {
-        return Ok(true);
+        return true;
     }
 
     // Search in commit statuses context
-    if let Some(true) = latest_pr_head_commit
-        .and_then(|commit| commit.status.as_ref())
+    if let Some(true) = commit
+        .status
+        .as_ref()
         .map(|status| status.contexts.iter().any(|c| re.is_match(&c.context)))
     {
-        return Ok(true);
+        return true;
     }
 
-    Ok(false)
+    false
 }
 
 /// Check if the given default community health file is available in the
@@ -178,31 +585,41 @@ pub(crate) fn has_check(gh_md: &MdRepository, re: &RegexSet) -> Result<bool> {
 pub(crate) async fn has_community_health_file(
     file: &str,
     gh_md: &MdRepository,
+    cache: Option<&DynCache>,
 ) -> Result<Option<String>> {
-    // Check if the file is in the repo
-    let file_raw_url = format!(
-        "https://raw.githubusercontent.com/{}/.github/HEAD/{}",
-        &gh_md.owner.login, file
-    );
-    let http_client = reqwest::Client::new();
-    match http_client
-        .head(&file_raw_url)
-        .send()
-        .await
-        .context(format!(
-            "error checking community health file {}",
-            &file_raw_url
-        ))?
-        .status()
-    {
-        StatusCode::OK => {
-            let url = build_url(Path::new(file), &gh_md.owner.login, ".github", "HEAD");
-            Ok(Some(url))
-        }
-        _ => Ok(None),
+    let owner = &gh_md.owner.login;
+    match file_content(owner, ".github", "HEAD", file, cache).await? {
+        Some(_) => Ok(Some(build_url(Path::new(file), owner, ".github", "HEAD"))),
+        None => Ok(None),
     }
 }
 
+/// Get the content of the file located at the path provided in the repository
+/// given, at the reference (branch, tag or commit SHA) specified. Files are
+/// cached by repository, path and reference. When the reference is a commit
+/// SHA the cached content is reused as is, as it cannot change. Otherwise it
+/// is revalidated using a conditional request, so unchanged files are not
+/// downloaded again across checks and runs.
+pub(crate) async fn file_content(
+    owner: &str,
+    repo: &str,
+    reference: &str,
+    path: &str,
+    cache: Option<&DynCache>,
+) -> Result<Option<String>> {
+    let url = format!("{GITHUB_RAW_CONTENT}/{owner}/{repo}/{reference}/{path}");
+    let key = format!("probe:github-content:{owner}/{repo}:{reference}:{path}");
+    let http_client = net::client_builder().build()?;
+    content::remote_conditional(&http_client, &url, &key, is_commit_sha(reference), cache)
+        .await
+        .context(format!("error getting file {path} from {owner}/{repo}"))
+}
+
+/// Check if the reference provided is a full commit SHA.
+fn is_commit_sha(reference: &str) -> bool {
+    reference.len() == 40 && reference.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 /// Get the repository's latest release from the metadata provided.
 pub(crate) fn latest_release(gh_md: &MdRepository) -> Option<&MdRepositoryReleasesNodes> {
     gh_md.releases.nodes.as_ref().and_then(|nodes| {
@@ -224,7 +641,7 @@ pub(crate) fn latest_release_description_matches(gh_md: &MdRepository, re: &Rege
 
 // Setup a new authenticated http client to interact with the GitHub API.
 pub fn setup_http_client(token: &str) -> Result<reqwest::Client, reqwest::Error> {
-    reqwest::Client::builder()
+    net::client_builder()
         .user_agent("clomonitor")
         .default_headers(
             std::iter::once((
@@ -255,6 +672,14 @@ mod tests {
         assert!(GITHUB_REPO_URL.is_match("https://github.com/owner/repo/"));
     }
 
+    #[test]
+    fn is_commit_sha_works() {
+        assert!(is_commit_sha("0a6a2321b4d2c6f8a0b0fd38ad63fd25d2d8fbc5"));
+        assert!(!is_commit_sha("HEAD"));
+        assert!(!is_commit_sha("main"));
+        assert!(!is_commit_sha("0a6a2321"));
+    }
+
     #[test]
     fn build_url_works() {
         assert_eq!(
@@ -366,6 +791,38 @@ mod tests {
         assert!(has_check(&gh_md, &RegexSet::new(["dco"]).unwrap()).unwrap());
     }
 
+    #[test]
+    fn recent_prs_have_check_in_older_pr() {
+        let pr = |context: &str| {
+            Some(MdRepositoryPullRequestsNodes {
+                commits: MdRepositoryPullRequestsNodesCommits {
+                    nodes: Some(vec![Some(MdRepositoryPullRequestsNodesCommitsNodes {
+                        commit: MdRepositoryPullRequestsNodesCommitsNodesCommit {
+                            check_suites: None,
+                            status: Some(MdRepositoryPullRequestsNodesCommitsNodesCommitStatus {
+                                contexts: vec![
+                                    MdRepositoryPullRequestsNodesCommitsNodesCommitStatusContexts {
+                                        context: context.to_string(),
+                                    },
+                                ],
+                            }),
+                        },
+                    })]),
+                },
+            })
+        };
+        let gh_md = MdRepository {
+            pull_requests: MdRepositoryPullRequests {
+                nodes: Some(vec![pr("ci"), pr("dco")]),
+            },
+            ..MdRepository::default()
+        };
+
+        let re = RegexSet::new(["dco"]).unwrap();
+        assert!(!has_check(&gh_md, &re).unwrap());
+        assert!(recent_prs_have_check(&gh_md, &re).unwrap());
+    }
+
     #[test]
     fn latest_release_found() {
         let gh_md = MdRepository {
@@ -375,6 +832,7 @@ mod tests {
                     description: None,
                     is_prerelease: false,
                     release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                    tag_name: "v1.0.0".to_string(),
                     url: "release_url".to_string(),
                 })]),
             },
@@ -401,6 +859,7 @@ mod tests {
                     description: Some("description".to_string()),
                     is_prerelease: false,
                     release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                    tag_name: "v1.0.0".to_string(),
                     url: "release_url".to_string(),
                 })]),
             },
@@ -413,6 +872,75 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_scopes_works() {
+        assert_eq!(
+            parse_scopes("public_repo, read:org"),
+            vec!["public_repo".to_string(), "read:org".to_string()]
+        );
+        assert!(parse_scopes("").is_empty());
+    }
+
+    #[test]
+    fn branch_protection_from_classic_settings() {
+        let mut protection = BranchProtection::default();
+        protection.apply_classic(&serde_json::json!({
+            "required_status_checks": {"strict": true, "contexts": ["ci"]},
+            "required_pull_request_reviews": {
+                "dismiss_stale_reviews": true,
+                "required_approving_review_count": 2
+            },
+            "required_signatures": {"enabled": false},
+            "allow_force_pushes": {"enabled": false}
+        }));
+        assert_eq!(
+            protection,
+            BranchProtection {
+                protected: true,
+                required_status_checks: true,
+                required_approving_review_count: 2,
+                dismiss_stale_reviews: true,
+                required_signatures: false,
+                force_pushes_blocked: true,
+            }
+        );
+    }
+
+    #[test]
+    fn branch_protection_from_rules() {
+        let mut protection = BranchProtection::default();
+        protection.apply_rules(&[
+            serde_json::json!({"type": "deletion"}),
+            serde_json::json!({"type": "non_fast_forward"}),
+            serde_json::json!({"type": "required_signatures"}),
+            serde_json::json!({
+                "type": "pull_request",
+                "parameters": {
+                    "dismiss_stale_reviews_on_push": false,
+                    "required_approving_review_count": 1
+                }
+            }),
+        ]);
+        assert_eq!(
+            protection,
+            BranchProtection {
+                protected: true,
+                required_status_checks: false,
+                required_approving_review_count: 1,
+                dismiss_stale_reviews: false,
+                required_signatures: true,
+                force_pushes_blocked: true,
+            }
+        );
+    }
+
+    #[test]
+    fn branch_protection_no_rules() {
+        let mut protection = BranchProtection::default();
+        protection.apply_rules(&[]);
+        assert_eq!(protection, BranchProtection::default());
+    }
+
     #[test]
     fn get_owner_and_repo_valid_url() {
         assert_eq!(
@@ -6,11 +6,11 @@ use crate::linter::{
     check::{CheckInput, CheckOutput},
     checks::readme,
     metadata::{Exemption, Metadata},
-    CheckSet, CHECKS,
+    LinterInput, Provider, ProviderSupport, CHECKS,
 };
 use anyhow::Result;
 use regex::{Regex, RegexSet};
-use std::path::Path;
+use std::{collections::BTreeMap, path::Path};
 
 /// Check if a file matching the patterns provided is found in the repo or if
 /// any of the regular expressions provided matches the README file content.
@@ -25,13 +25,7 @@ pub(crate) fn find_file_or_readme_ref(
         patterns,
         case_sensitive: false,
     })? {
-        let url = github::build_url(
-            &path,
-            &input.gh_md.owner.login,
-            &input.gh_md.name,
-            &github::default_branch(input.gh_md.default_branch_ref.as_ref()),
-        );
-        return Ok(CheckOutput::passed().url(Some(url)));
+        return Ok(CheckOutput::passed().url(Some(build_file_url(input, &path))));
     }
 
     // Reference in README file
@@ -42,6 +36,37 @@ pub(crate) fn find_file_or_readme_ref(
     Ok(CheckOutput::not_passed())
 }
 
+/// Build the url of the file provided (path relative to the repository root)
+/// in the web interface of the provider hosting the repository.
+pub(crate) fn build_file_url(input: &CheckInput, path: &Path) -> String {
+    let branch = github::default_branch(input.gh_md.default_branch_ref.as_ref());
+    let repo_url = input.li.url.trim_end_matches('/');
+    match input.li.provider {
+        Provider::Github => {
+            github::build_url(path, &input.gh_md.owner.login, &input.gh_md.name, &branch)
+        }
+        Provider::Gitlab => format!("{}/-/blob/{}/{}", repo_url, branch, path.to_string_lossy()),
+        Provider::Gitea => format!(
+            "{}/src/branch/{}/{}",
+            repo_url,
+            branch,
+            path.to_string_lossy()
+        ),
+    }
+}
+
+/// Get the content of the project's live website (its home url), honoring the
+/// site's robots.txt rules. None is returned when the project's home url has
+/// not been provided or fetching it is not allowed.
+pub(crate) async fn project_website_content(input: &CheckInput<'_>) -> Result<Option<String>> {
+    match input.li.home_url.as_deref() {
+        Some(url) if !url.is_empty() => {
+            content::remote_if_allowed(&input.restricted_client, url, input.cache.as_ref()).await
+        }
+        _ => Ok(None),
+    }
+}
+
 /// Check if the README file content matches any of the regular expressions
 /// provided.
 pub(crate) fn readme_matches(root: &Path, re: &RegexSet) -> Result<bool> {
@@ -83,26 +108,60 @@ pub(crate) fn find_exemption(check_id: &str, cm_md: Option<&Metadata>) -> Option
 }
 
 /// Check if the check provided should be skipped.
-pub(crate) fn should_skip_check(check_id: &str, check_sets: &[CheckSet]) -> bool {
-    // Skip if the check doesn't belong to any of the check sets provided
-    if !CHECKS[check_id]
-        .check_sets
-        .iter()
-        .any(|check_set| check_sets.contains(check_set))
-    {
+pub(crate) fn should_skip_check(check_id: &str, li: &LinterInput) -> bool {
+    // Skip if the check has not been selected to be run
+    if !is_check_selected(check_id, li) {
+        return true;
+    }
+
+    // Skip if the check is not supported on the repository's provider
+    if CHECKS[check_id].support(li.provider) == ProviderSupport::Unsupported {
         return true;
     }
 
     false
 }
 
+/// Check if the check provided has been selected to be run, based on the
+/// check sets and the checks overrides of the linter input.
+fn is_check_selected(check_id: &str, li: &LinterInput) -> bool {
+    // Checks explicitly disabled or enabled take precedence over check sets
+    let overrides = &li.check_overrides;
+    if overrides.disable.iter().any(|id| id == check_id) {
+        return false;
+    }
+    if overrides.enable.iter().any(|id| id == check_id) {
+        return true;
+    }
+
+    // Select if the check belongs to any of the check sets provided
+    CHECKS[check_id]
+        .check_sets
+        .iter()
+        .any(|check_set| li.check_sets.contains(check_set))
+}
+
+/// Return the checks selected to be run that are limited by the support
+/// available on the repository's provider, along with their support level.
+pub(crate) fn provider_limited_checks(li: &LinterInput) -> BTreeMap<String, ProviderSupport> {
+    CHECKS
+        .iter()
+        .filter(|(check_id, _)| is_check_selected(check_id, li))
+        .map(|(check_id, check)| (check_id.to_string(), check.support(li.provider)))
+        .filter(|(_, support)| *support != ProviderSupport::Full)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::{
-        adopters, sbom,
-        util::github::md::{MdRepository, MdRepositoryOwner, MdRepositoryOwnerOn},
-        LinterInput,
+    use crate::{
+        linter::{
+            adopters, contributing, recent_release, sbom,
+            util::github::md::{MdRepository, MdRepositoryOwner, MdRepositoryOwnerOn},
+            website, CheckOverrides, CheckSet,
+        },
+        net::RestrictedClient,
     };
     use anyhow::format_err;
     use std::path::PathBuf;
@@ -128,6 +187,9 @@ mod tests {
                         ..MdRepository::default()
                     },
                     scorecard: Err(format_err!("no scorecard available")),
+                    cache: None,
+                    restricted_client: RestrictedClient::default(),
+                    prerequisites: BTreeMap::new(),
                 },
                 &["README*"],
                 &RegexSet::new(["nothing"]).unwrap(),
@@ -151,6 +213,9 @@ mod tests {
                     cm_md: None,
                     gh_md: MdRepository::default(),
                     scorecard: Err(format_err!("no scorecard available")),
+                    cache: None,
+                    restricted_client: RestrictedClient::default(),
+                    prerequisites: BTreeMap::new(),
                 },
                 &["ADOPTERS*"],
                 &RegexSet::new([r"(?im)^#+.*adopters.*$"]).unwrap(),
@@ -172,6 +237,9 @@ mod tests {
                     cm_md: None,
                     gh_md: MdRepository::default(),
                     scorecard: Err(format_err!("no scorecard available")),
+                    cache: None,
+                    restricted_client: RestrictedClient::default(),
+                    prerequisites: BTreeMap::new(),
                 },
                 &["inexistent_file*"],
                 &RegexSet::new(["inexistent_ref"]).unwrap(),
@@ -191,7 +259,8 @@ mod tests {
                         check: "check-id".to_string(),
                         reason: "sample reason".to_string(),
                     }]),
-                    license_scanning: None
+                    license_scanning: None,
+                    locales: None,
                 })
             ),
             Some(Exemption {
@@ -211,7 +280,8 @@ mod tests {
                         check: "check-id".to_string(),
                         reason: "sample reason".to_string(),
                     }]),
-                    license_scanning: None
+                    license_scanning: None,
+                    locales: None,
                 })
             ),
             None,
@@ -225,7 +295,8 @@ mod tests {
                 "check-id",
                 Some(&Metadata {
                     exemptions: None,
-                    license_scanning: None
+                    license_scanning: None,
+                    locales: None,
                 })
             ),
             None,
@@ -237,21 +308,168 @@ mod tests {
         assert_eq!(find_exemption("check-id", None), None,);
     }
 
+    fn linter_input(check_sets: &[CheckSet], check_overrides: CheckOverrides) -> LinterInput {
+        LinterInput {
+            check_sets: check_sets.to_vec(),
+            check_overrides,
+            ..LinterInput::default()
+        }
+    }
+
     #[test]
     fn should_skip_check_affirmative() {
-        assert!(should_skip_check(adopters::ID, &[CheckSet::Code]));
-        assert!(should_skip_check(sbom::ID, &[CheckSet::Community]));
+        assert!(should_skip_check(
+            adopters::ID,
+            &linter_input(&[CheckSet::Code], CheckOverrides::default())
+        ));
+        assert!(should_skip_check(
+            sbom::ID,
+            &linter_input(&[CheckSet::Community], CheckOverrides::default())
+        ));
     }
 
     #[test]
     fn should_skip_check_negative() {
         assert!(!should_skip_check(
             adopters::ID,
-            &[CheckSet::Code, CheckSet::Community]
+            &linter_input(
+                &[CheckSet::Code, CheckSet::Community],
+                CheckOverrides::default()
+            )
         ));
         assert!(!should_skip_check(
             sbom::ID,
-            &[CheckSet::Code, CheckSet::Community]
+            &linter_input(
+                &[CheckSet::Code, CheckSet::Community],
+                CheckOverrides::default()
+            )
+        ));
+    }
+
+    #[test]
+    fn should_skip_check_disabled() {
+        assert!(should_skip_check(
+            recent_release::ID,
+            &linter_input(
+                &[CheckSet::Code],
+                CheckOverrides {
+                    disable: vec![recent_release::ID.to_string()],
+                    ..CheckOverrides::default()
+                }
+            )
+        ));
+    }
+
+    #[test]
+    fn should_skip_check_unsupported_by_provider() {
+        let li = LinterInput {
+            provider: Provider::Gitlab,
+            ..linter_input(&[CheckSet::Community], CheckOverrides::default())
+        };
+        assert!(should_skip_check(recent_release::ID, &li));
+        assert!(!should_skip_check(adopters::ID, &li));
+        assert!(!should_skip_check(
+            recent_release::ID,
+            &linter_input(&[CheckSet::Community], CheckOverrides::default())
+        ));
+    }
+
+    #[test]
+    fn provider_limited_checks_github() {
+        assert!(provider_limited_checks(&linter_input(
+            &[CheckSet::Code, CheckSet::Community],
+            CheckOverrides::default()
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn provider_limited_checks_gitlab() {
+        let li = LinterInput {
+            provider: Provider::Gitlab,
+            ..linter_input(
+                &[CheckSet::Docs],
+                CheckOverrides {
+                    enable: vec![sbom::ID.to_string()],
+                    ..CheckOverrides::default()
+                },
+            )
+        };
+        let limited = provider_limited_checks(&li);
+        assert_eq!(limited[sbom::ID], ProviderSupport::Partial);
+        assert_eq!(limited[website::ID], ProviderSupport::Unsupported);
+        assert!(!limited.contains_key(adopters::ID));
+    }
+
+    #[test]
+    fn provider_limited_checks_gitea() {
+        let li = LinterInput {
+            provider: Provider::Gitea,
+            ..linter_input(
+                &[CheckSet::Code, CheckSet::Community],
+                CheckOverrides::default(),
+            )
+        };
+        let limited = provider_limited_checks(&li);
+        assert!(!limited.contains_key(sbom::ID));
+        assert!(!limited.contains_key(website::ID));
+        assert!(!limited.contains_key(recent_release::ID));
+        assert_eq!(limited[contributing::ID], ProviderSupport::Partial);
+        assert!(!limited.contains_key(adopters::ID));
+    }
+
+    #[test]
+    fn build_file_url_per_provider() {
+        let path = Path::new("docs/README.md");
+        let file_url = |provider| {
+            let li = LinterInput {
+                url: "https://host/owner/repo/".to_string(),
+                provider,
+                ..LinterInput::default()
+            };
+            let ci = CheckInput {
+                li: &li,
+                cm_md: None,
+                gh_md: MdRepository {
+                    name: "repo".to_string(),
+                    owner: MdRepositoryOwner {
+                        login: "owner".to_string(),
+                        on: MdRepositoryOwnerOn::Organization,
+                    },
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                cache: None,
+                restricted_client: RestrictedClient::default(),
+                prerequisites: BTreeMap::new(),
+            };
+            build_file_url(&ci, path)
+        };
+        assert_eq!(
+            file_url(Provider::Github),
+            "https://github.com/owner/repo/blob/master/docs/README.md"
+        );
+        assert_eq!(
+            file_url(Provider::Gitlab),
+            "https://host/owner/repo/-/blob/master/docs/README.md"
+        );
+        assert_eq!(
+            file_url(Provider::Gitea),
+            "https://host/owner/repo/src/branch/master/docs/README.md"
+        );
+    }
+
+    #[test]
+    fn should_skip_check_enabled() {
+        assert!(!should_skip_check(
+            adopters::ID,
+            &linter_input(
+                &[CheckSet::Code],
+                CheckOverrides {
+                    enable: vec![adopters::ID.to_string()],
+                    ..CheckOverrides::default()
+                }
+            )
         ));
     }
 }
@@ -0,0 +1,339 @@
+use super::{
+    helpers::{build_file_url, readme_matches},
+    path::{self, Globs},
+};
+use crate::linter::check::{CheckInput, CheckOutput};
+use anyhow::Result;
+use regex::RegexSet;
+
+/// Locales translated documents can be detected in.
+pub(crate) const LOCALES: [&str; 7] = ["de", "es", "fr", "ja", "ko", "pt", "zh"];
+
+/// Directories where translated documents are looked for (relative to the
+/// repository root).
+const DIRS: [&str; 3] = ["", ".github/", "docs/"];
+
+/// Documents that can be detected in languages other than English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Document {
+    Adopters,
+    Changelog,
+    CodeOfConduct,
+    Contributing,
+    Governance,
+    Maintainers,
+    Roadmap,
+    SecurityPolicy,
+}
+
+impl Document {
+    /// Names of the document in the locale provided (in lowercase). They are
+    /// used to build both the file patterns and the README references.
+    fn names(&self, locale: &str) -> &'static [&'static str] {
+        match (self, locale) {
+            (Document::Adopters, "de") => &["anwender"],
+            (Document::Adopters, "es") => &["adoptantes"],
+            (Document::Adopters, "fr") => &["adoptants"],
+            (Document::Adopters, "ja") => &["採用者", "導入事例"],
+            (Document::Adopters, "ko") => &["도입 사례", "사용자"],
+            (Document::Adopters, "pt") => &["adotantes"],
+            (Document::Adopters, "zh") => &["采用者", "用户"],
+            (Document::Changelog, "de") => &["änderungsprotokoll", "änderungen"],
+            (Document::Changelog, "es") => &["registro de cambios", "cambios"],
+            (Document::Changelog, "fr") => &["journal des modifications", "changements"],
+            (Document::Changelog, "ja") => &["変更履歴"],
+            (Document::Changelog, "ko") => &["변경 로그", "변경 사항"],
+            (Document::Changelog, "pt") => &["registro de alterações", "alterações"],
+            (Document::Changelog, "zh") => &["更新日志", "变更日志"],
+            (Document::CodeOfConduct, "de") => &["verhaltenskodex"],
+            (Document::CodeOfConduct, "es") => &["código de conducta", "codigo de conducta"],
+            (Document::CodeOfConduct, "fr") => &["code de conduite"],
+            (Document::CodeOfConduct, "ja") => &["行動規範"],
+            (Document::CodeOfConduct, "ko") => &["행동 강령"],
+            (Document::CodeOfConduct, "pt") => &["código de conduta", "codigo de conduta"],
+            (Document::CodeOfConduct, "zh") => &["行为准则"],
+            (Document::Contributing, "de") => &["mitwirken", "beitragen"],
+            (Document::Contributing, "es") => &["contribuir", "contribuciones"],
+            (Document::Contributing, "fr") => &["contribuer", "contribution"],
+            (Document::Contributing, "ja") => &["貢献"],
+            (Document::Contributing, "ko") => &["기여"],
+            (Document::Contributing, "pt") => &["contribuição", "contribuicao", "contribuindo"],
+            (Document::Contributing, "zh") => &["贡献指南", "贡献"],
+            (Document::Governance, "de") => &["führung", "steuerung"],
+            (Document::Governance, "es") => &["gobernanza"],
+            (Document::Governance, "fr") => &["gouvernance"],
+            (Document::Governance, "ja") => &["ガバナンス"],
+            (Document::Governance, "ko") => &["거버넌스"],
+            (Document::Governance, "pt") => &["governança", "governanca"],
+            (Document::Governance, "zh") => &["治理"],
+            (Document::Maintainers, "de") => &["betreuer"],
+            (Document::Maintainers, "es") => &["mantenedores"],
+            (Document::Maintainers, "fr") => &["mainteneurs"],
+            (Document::Maintainers, "ja") => &["メンテナー", "メンテナ"],
+            (Document::Maintainers, "ko") => &["메인테이너", "관리자"],
+            (Document::Maintainers, "pt") => &["mantenedores"],
+            (Document::Maintainers, "zh") => &["维护者"],
+            (Document::Roadmap, "de") => &["fahrplan"],
+            (Document::Roadmap, "es") => &["hoja de ruta"],
+            (Document::Roadmap, "fr") => &["feuille de route"],
+            (Document::Roadmap, "ja") => &["ロードマップ"],
+            (Document::Roadmap, "ko") => &["로드맵"],
+            (Document::Roadmap, "pt") => &["roteiro"],
+            (Document::Roadmap, "zh") => &["路线图"],
+            (Document::SecurityPolicy, "de") => &["sicherheit"],
+            (Document::SecurityPolicy, "es") => &["seguridad"],
+            (Document::SecurityPolicy, "fr") => &["sécurité", "securite"],
+            (Document::SecurityPolicy, "ja") => &["セキュリティ"],
+            (Document::SecurityPolicy, "ko") => &["보안"],
+            (Document::SecurityPolicy, "pt") => &["segurança", "seguranca"],
+            (Document::SecurityPolicy, "zh") => &["安全"],
+            _ => &[],
+        }
+    }
+
+    /// Return the glob patterns used to locate the document's file in the
+    /// locale provided. Glob matching is only case insensitive for ASCII
+    /// characters, so patterns are provided in lowercase and uppercase.
+    fn file_patterns(&self, locale: &str) -> Vec<String> {
+        let mut patterns = vec![];
+        for name in self.names(locale) {
+            let name = name.replace(' ', "*");
+            let mut variants = vec![name.clone()];
+            if name.to_uppercase() != name {
+                variants.push(name.to_uppercase());
+            }
+            for dir in DIRS {
+                for variant in &variants {
+                    patterns.push(format!("{dir}{variant}*"));
+                }
+            }
+        }
+        patterns
+    }
+
+    /// Return the regular expressions used to find references to the document
+    /// in the README file in the locale provided.
+    fn readme_ref(&self, locale: &str) -> Result<Option<RegexSet>> {
+        let names = self.names(locale);
+        if names.is_empty() {
+            return Ok(None);
+        }
+        let exprs = names.iter().flat_map(|name| {
+            let name = regex::escape(name);
+            [
+                format!(r"(?im)^#+.*{name}.*$"),
+                format!(r"(?im)^{name}$"),
+                format!(r"(?i)\[.*{name}.*\]\(.*\)"),
+            ]
+        });
+        Ok(Some(RegexSet::new(exprs)?))
+    }
+}
+
+/// Return the locales enabled for the repository in its metadata file that
+/// translated documents can be detected in (i.e. pt-BR -> pt).
+pub(crate) fn enabled_locales(input: &CheckInput) -> Vec<&'static str> {
+    let Some(locales) = input.cm_md.as_ref().and_then(|md| md.locales.as_ref()) else {
+        return vec![];
+    };
+    let mut enabled: Vec<&'static str> = vec![];
+    for locale in locales {
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        if let Some(locale) = LOCALES.iter().find(|l| **l == language) {
+            if !enabled.contains(locale) {
+                enabled.push(locale);
+            }
+        }
+    }
+    enabled
+}
+
+/// Check if a translated version of the document provided is found in the
+/// repo or referenced in the README file, in any of the locales enabled for
+/// the repository. The translated document found is surfaced in the check
+/// output details.
+pub(crate) fn find_translated_file_or_readme_ref(
+    input: &CheckInput,
+    document: Document,
+) -> Result<Option<CheckOutput>> {
+    for locale in enabled_locales(input) {
+        // File in repo
+        let patterns = document.file_patterns(locale);
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        if let Some(path) = path::find(&Globs {
+            root: &input.li.root,
+            patterns: &patterns,
+            case_sensitive: false,
+        })? {
+            let details = format!("**Translated document**: {} ({locale})", path.display());
+            return Ok(Some(
+                CheckOutput::passed()
+                    .url(Some(build_file_url(input, &path)))
+                    .details(Some(details)),
+            ));
+        }
+
+        // Reference in README file
+        if let Some(re) = document.readme_ref(locale)? {
+            if readme_matches(&input.li.root, &re)? {
+                let details = format!("**Translated reference in README** ({locale})");
+                return Ok(Some(CheckOutput::passed().details(Some(details))));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linter::{
+            metadata::Metadata,
+            util::github::md::{MdRepository, MdRepositoryOwner, MdRepositoryOwnerOn},
+            LinterInput,
+        },
+        net::RestrictedClient,
+    };
+    use anyhow::format_err;
+    use std::{collections::BTreeMap, path::PathBuf};
+
+    const TESTDATA_PATH: &str = "src/testdata/translated";
+
+    fn metadata(locales: &[&str]) -> Metadata {
+        Metadata {
+            exemptions: None,
+            license_scanning: None,
+            locales: Some(locales.iter().map(ToString::to_string).collect()),
+        }
+    }
+
+    fn gh_md() -> MdRepository {
+        MdRepository {
+            name: "repo".to_string(),
+            owner: MdRepositoryOwner {
+                login: "owner".to_string(),
+                on: MdRepositoryOwnerOn::Organization,
+            },
+            ..MdRepository::default()
+        }
+    }
+
+    #[test]
+    fn enabled_locales_normalized() {
+        let li = LinterInput::default();
+        let input = CheckInput {
+            li: &li,
+            cm_md: Some(metadata(&["pt-BR", "zh_CN", "PT", "xx"])),
+            gh_md: gh_md(),
+            scorecard: Err(format_err!("no scorecard available")),
+            cache: None,
+            restricted_client: RestrictedClient::default(),
+            prerequisites: BTreeMap::new(),
+        };
+
+        assert_eq!(enabled_locales(&input), vec!["pt", "zh"]);
+    }
+
+    #[test]
+    fn file_patterns_include_uppercase_variants() {
+        let patterns = Document::Contributing.file_patterns("pt");
+        assert!(patterns.contains(&"contribuição*".to_string()));
+        assert!(patterns.contains(&"CONTRIBUIÇÃO*".to_string()));
+        assert!(patterns.contains(&"docs/contribuição*".to_string()));
+        assert!(Document::CodeOfConduct
+            .file_patterns("es")
+            .contains(&"código*de*conducta*".to_string()));
+    }
+
+    #[test]
+    fn translated_file_found() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            find_translated_file_or_readme_ref(
+                &CheckInput {
+                    li: &li,
+                    cm_md: Some(metadata(&["pt-BR"])),
+                    gh_md: gh_md(),
+                    scorecard: Err(format_err!("no scorecard available")),
+                    cache: None,
+                    restricted_client: RestrictedClient::default(),
+                    prerequisites: BTreeMap::new(),
+                },
+                Document::Contributing,
+            )
+            .unwrap(),
+            Some(
+                CheckOutput::passed()
+                    .url(Some(
+                        "https://github.com/owner/repo/blob/master/CONTRIBUIÇÃO.md".to_string()
+                    ))
+                    .details(Some(
+                        "**Translated document**: CONTRIBUIÇÃO.md (pt)".to_string()
+                    ))
+            )
+        );
+    }
+
+    #[test]
+    fn translated_readme_ref_found() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            find_translated_file_or_readme_ref(
+                &CheckInput {
+                    li: &li,
+                    cm_md: Some(metadata(&["zh"])),
+                    gh_md: gh_md(),
+                    scorecard: Err(format_err!("no scorecard available")),
+                    cache: None,
+                    restricted_client: RestrictedClient::default(),
+                    prerequisites: BTreeMap::new(),
+                },
+                Document::CodeOfConduct,
+            )
+            .unwrap(),
+            Some(
+                CheckOutput::passed()
+                    .details(Some("**Translated reference in README** (zh)".to_string()))
+            )
+        );
+    }
+
+    #[test]
+    fn translated_document_not_found_when_locale_not_enabled() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            find_translated_file_or_readme_ref(
+                &CheckInput {
+                    li: &li,
+                    cm_md: None,
+                    gh_md: gh_md(),
+                    scorecard: Err(format_err!("no scorecard available")),
+                    cache: None,
+                    restricted_client: RestrictedClient::default(),
+                    prerequisites: BTreeMap::new(),
+                },
+                Document::Contributing,
+            )
+            .unwrap(),
+            None
+        );
+    }
+}
@@ -1,5 +1,12 @@
 pub(crate) mod content;
+pub(crate) mod content_cache;
+pub(crate) mod gerrit;
+pub(crate) mod gitea;
 pub(crate) mod github;
 pub(crate) mod helpers;
+pub(crate) mod locale;
+pub(crate) mod oci;
+pub(crate) mod org;
 pub(crate) mod path;
 pub(crate) mod scorecard;
+pub(crate) mod site;
@@ -0,0 +1,576 @@
+use crate::{
+    cache::{self, DynCache},
+    linter::CheckOutput,
+    net::RestrictedClient,
+};
+use anyhow::{format_err, Context, Result};
+use http::StatusCode;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::header::{ACCEPT, AUTHORIZATION, WWW_AUTHENTICATE};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+/// How long the information of the images inspected will be kept in the cache.
+const IMAGE_INFO_CACHE_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Registry used when the image reference does not include one.
+const DEFAULT_REGISTRY: &str = "registry-1.docker.io";
+
+/// Media types of the manifests we can handle.
+const MANIFEST_MEDIA_TYPES: [&str; 4] = [
+    "application/vnd.oci.image.index.v1+json",
+    "application/vnd.oci.image.manifest.v1+json",
+    "application/vnd.docker.distribution.manifest.list.v2+json",
+    "application/vnd.docker.distribution.manifest.v2+json",
+];
+
+/// Header used by registries to return the digest of a manifest.
+const DOCKER_CONTENT_DIGEST: &str = "docker-content-digest";
+
+/// Artifact types (or part of them) of the referrers that contain signatures.
+const SIGNATURE_ARTIFACT_TYPES: [&str; 3] = [
+    "application/vnd.dev.sigstore.bundle",
+    "application/vnd.dev.cosign.artifact.sig",
+    "application/vnd.cncf.notary.signature",
+];
+
+/// Artifact types (or part of them) of the referrers that contain SBOMs.
+const SBOM_ARTIFACT_TYPES: [&str; 2] = ["spdx", "cyclonedx"];
+
+lazy_static! {
+    static ref WWW_AUTHENTICATE_PARAM: Regex =
+        Regex::new(r#"(\w+)="([^"]*)""#).expect("exprs in WWW_AUTHENTICATE_PARAM to be valid");
+}
+
+/// Information collected about a container image from its registry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ImageInfo {
+    pub digest: String,
+    pub signed: bool,
+    pub sbom: bool,
+    pub created_at: Option<String>,
+}
+
+/// Container image declared for a repository, along with the result of
+/// inspecting it.
+#[derive(Debug)]
+pub(crate) struct InspectedImage {
+    pub image: String,
+    pub info: Result<ImageInfo>,
+}
+
+/// Container image reference (i.e. ghcr.io/org/image:tag).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ImageRef {
+    pub registry: String,
+    pub repository: String,
+    pub reference: String,
+}
+
+impl ImageRef {
+    /// Parse the image reference provided.
+    pub(crate) fn parse(image: &str) -> Result<Self> {
+        let image = image.trim();
+        if image.is_empty() || image.contains("://") {
+            return Err(format_err!("invalid image reference: {image}"));
+        }
+
+        // Registry (first component when it looks like a host)
+        let (registry, rest) = match image.split_once('/') {
+            Some((first, rest))
+                if first.contains('.') || first.contains(':') || first == "localhost" =>
+            {
+                (first.to_string(), rest.to_string())
+            }
+            _ => (DEFAULT_REGISTRY.to_string(), image.to_string()),
+        };
+        let registry = match registry.as_str() {
+            "docker.io" | "index.docker.io" => DEFAULT_REGISTRY.to_string(),
+            _ => registry,
+        };
+
+        // Repository and reference (digest or tag)
+        let (repository, reference) = if let Some((repository, digest)) = rest.split_once('@') {
+            (repository.to_string(), digest.to_string())
+        } else {
+            match rest.rsplit_once(':') {
+                Some((repository, tag)) if !tag.contains('/') => {
+                    (repository.to_string(), tag.to_string())
+                }
+                _ => (rest, "latest".to_string()),
+            }
+        };
+        if repository.is_empty() || reference.is_empty() {
+            return Err(format_err!("invalid image reference: {image}"));
+        }
+        let repository = if registry == DEFAULT_REGISTRY && !repository.contains('/') {
+            format!("library/{repository}")
+        } else {
+            repository
+        };
+
+        Ok(Self {
+            registry,
+            repository,
+            reference,
+        })
+    }
+
+    /// Base url of the registry's API for this image's repository. Registries
+    /// running locally are accessed using plain http.
+    fn base_url(&self) -> String {
+        let host = self.registry.split(':').next().unwrap_or_default();
+        let scheme = match host {
+            "localhost" | "127.0.0.1" => "http",
+            _ => "https",
+        };
+        format!("{scheme}://{}/v2/{}", self.registry, self.repository)
+    }
+}
+
+/// Get the information of the container image provided from its registry,
+/// using the restricted client given. When a cache is provided, the
+/// information will be looked up there first, and stored on it once collected.
+pub(crate) async fn inspect(
+    restricted_client: &RestrictedClient,
+    image: &str,
+    cache: Option<&DynCache>,
+) -> Result<ImageInfo> {
+    let image_ref = ImageRef::parse(image)?;
+    let key = format!("probe:oci-image:{image}");
+    let info = cache::get_or_insert_with(cache, &key, IMAGE_INFO_CACHE_TTL, async {
+        let info = Registry::new(restricted_client, &image_ref)
+            .inspect()
+            .await?;
+        Ok(serde_json::to_vec(&info)?)
+    })
+    .await?;
+    Ok(serde_json::from_slice(&info)?)
+}
+
+/// Inspect all the container images provided.
+pub(crate) async fn inspect_all(
+    restricted_client: &RestrictedClient,
+    images: &[String],
+    cache: Option<&DynCache>,
+) -> Vec<InspectedImage> {
+    let mut inspected = Vec::with_capacity(images.len());
+    for image in images {
+        inspected.push(InspectedImage {
+            image: image.clone(),
+            info: inspect(restricted_client, image, cache).await,
+        });
+    }
+    inspected
+}
+
+/// Build the output of a check that verifies that all the images provided
+/// meet the condition given. Images that could not be inspected are listed in
+/// the details, but only make the check fail when none could be inspected.
+pub(crate) fn images_output(
+    images: &[InspectedImage],
+    missing: &str,
+    condition: impl Fn(&ImageInfo) -> bool,
+) -> CheckOutput {
+    let mut not_meeting = vec![];
+    let mut errors = vec![];
+    for image in images {
+        match &image.info {
+            Ok(info) if condition(info) => {}
+            Ok(_) => not_meeting.push(format!("· {}", image.image)),
+            Err(err) => errors.push(format!("· {} ({:#})", image.image, err)),
+        }
+    }
+    if errors.len() == images.len() {
+        return CheckOutput::failed().fail_reason(Some(format!(
+            "None of the images could be inspected:\n\n{}",
+            errors.join("\n")
+        )));
+    }
+
+    let mut details = vec![];
+    if !not_meeting.is_empty() {
+        details.push(format!("Images {missing}:\n\n{}", not_meeting.join("\n")));
+    }
+    if !errors.is_empty() {
+        details.push(format!(
+            "Images that could not be inspected:\n\n{}",
+            errors.join("\n")
+        ));
+    }
+    let output = if not_meeting.is_empty() {
+        CheckOutput::passed()
+    } else {
+        CheckOutput::not_passed()
+    };
+    if details.is_empty() {
+        return output;
+    }
+    output.details(Some(details.join("\n\n")))
+}
+
+/// Client used to interact with the registry of an image (OCI distribution
+/// API), handling the anonymous token authentication most registries require.
+/// As images are provided by users, registries are only reached when they are
+/// allowed by the host policy of the restricted client used.
+struct Registry<'a> {
+    image_ref: &'a ImageRef,
+    restricted_client: &'a RestrictedClient,
+    token: Option<String>,
+}
+
+impl<'a> Registry<'a> {
+    /// Create a new Registry instance.
+    fn new(restricted_client: &'a RestrictedClient, image_ref: &'a ImageRef) -> Self {
+        Self {
+            image_ref,
+            restricted_client,
+            token: None,
+        }
+    }
+
+    /// Collect the information of the image.
+    async fn inspect(&mut self) -> Result<ImageInfo> {
+        // Manifest of the image
+        let reference = self.image_ref.reference.clone();
+        let (digest, manifest) = self
+            .manifest(&reference)
+            .await?
+            .ok_or_else(|| format_err!("image not found"))?;
+        let digest = match digest {
+            Some(digest) => digest,
+            None if reference.starts_with("sha256:") => reference,
+            None => return Err(format_err!("image digest not provided by the registry")),
+        };
+        let referrers = self.referrers(&digest).await?;
+        let tag_prefix = digest.replace(':', "-");
+
+        // Signature (cosign tag based or referrer)
+        let signed = self.manifest_exists(&format!("{tag_prefix}.sig")).await?
+            || referrers
+                .iter()
+                .any(|t| SIGNATURE_ARTIFACT_TYPES.iter().any(|st| t.contains(st)));
+
+        // SBOM (cosign tag based, referrer or buildx attestation)
+        let sbom = self.manifest_exists(&format!("{tag_prefix}.sbom")).await?
+            || self.manifest_exists(&format!("{tag_prefix}.att")).await?
+            || referrers
+                .iter()
+                .any(|t| SBOM_ARTIFACT_TYPES.iter().any(|st| t.contains(st)))
+            || has_attestation_manifest(&manifest);
+
+        // Creation date (from the image's configuration)
+        let created_at = self.created_at(&manifest).await?;
+
+        Ok(ImageInfo {
+            digest,
+            signed,
+            sbom,
+            created_at,
+        })
+    }
+
+    /// Get the manifest for the reference provided, along with its digest (if
+    /// provided by the registry). None is returned if it does not exist.
+    async fn manifest(&mut self, reference: &str) -> Result<Option<(Option<String>, Value)>> {
+        let url = format!("{}/manifests/{reference}", self.image_ref.base_url());
+        let resp = self.get(&url, &MANIFEST_MEDIA_TYPES.join(", ")).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                let digest = resp
+                    .headers()
+                    .get(DOCKER_CONTENT_DIGEST)
+                    .and_then(|v| v.to_str().ok())
+                    .map(ToString::to_string);
+                Ok(Some((digest, resp.json().await?)))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            status => Err(format_err!(
+                "unexpected status code getting manifest {url}: {status}"
+            )),
+        }
+    }
+
+    /// Check if a manifest exists for the reference provided.
+    async fn manifest_exists(&mut self, reference: &str) -> Result<bool> {
+        Ok(self.manifest(reference).await?.is_some())
+    }
+
+    /// Get the artifact types of the referrers of the digest provided. Not
+    /// all registries support the referrers API yet, so an empty list is
+    /// returned when it is not available.
+    async fn referrers(&mut self, digest: &str) -> Result<Vec<String>> {
+        let url = format!("{}/referrers/{digest}", self.image_ref.base_url());
+        let resp = self
+            .get(&url, "application/vnd.oci.image.index.v1+json")
+            .await?;
+        if resp.status() != StatusCode::OK {
+            return Ok(vec![]);
+        }
+        let index: Value = resp.json().await.unwrap_or_default();
+        Ok(index["manifests"]
+            .as_array()
+            .map(|manifests| {
+                manifests
+                    .iter()
+                    .filter_map(|m| m["artifactType"].as_str().map(ToString::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Get the creation date of the image from its configuration. When the
+    /// manifest provided is an index, the first platform image is used.
+    async fn created_at(&mut self, manifest: &Value) -> Result<Option<String>> {
+        let manifest = match first_platform_manifest(manifest) {
+            Some(digest) => match self.manifest(&digest).await? {
+                Some((_, manifest)) => manifest,
+                None => return Ok(None),
+            },
+            None => manifest.clone(),
+        };
+        let Some(config_digest) = manifest["config"]["digest"].as_str() else {
+            return Ok(None);
+        };
+        let url = format!("{}/blobs/{config_digest}", self.image_ref.base_url());
+        let resp = self.get(&url, "*/*").await?;
+        if resp.status() != StatusCode::OK {
+            return Ok(None);
+        }
+        let config: Value = resp.json().await?;
+        Ok(config["created"].as_str().map(ToString::to_string))
+    }
+
+    /// Send a GET request to the url provided, authenticating with the
+    /// registry when it requests it.
+    async fn get(&mut self, url: &str, accept: &str) -> Result<reqwest::Response> {
+        for _ in 0..2 {
+            let mut req = self.restricted_client.get(url)?.header(ACCEPT, accept);
+            if let Some(token) = &self.token {
+                req = req.header(AUTHORIZATION, format!("Bearer {token}"));
+            }
+            let resp = req
+                .send()
+                .await
+                .context(format!("error querying registry: {url}"))?;
+            if resp.status() != StatusCode::UNAUTHORIZED || self.token.is_some() {
+                return Ok(resp);
+            }
+            let challenge = resp
+                .headers()
+                .get(WWW_AUTHENTICATE)
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| format_err!("registry authentication required"))?
+                .to_string();
+            self.token = Some(self.get_token(&challenge).await?);
+        }
+        Err(format_err!("registry authentication failed"))
+    }
+
+    /// Get an anonymous token from the authorization service described in the
+    /// authentication challenge provided.
+    async fn get_token(&self, challenge: &str) -> Result<String> {
+        let params: Vec<(String, String)> = WWW_AUTHENTICATE_PARAM
+            .captures_iter(challenge)
+            .map(|c| (c[1].to_string(), c[2].to_string()))
+            .collect();
+        let realm = params
+            .iter()
+            .find(|(k, _)| k == "realm")
+            .map(|(_, v)| v.clone())
+            .ok_or_else(|| format_err!("realm not found in authentication challenge"))?;
+        let query: Vec<&(String, String)> = params
+            .iter()
+            .filter(|(k, _)| k == "service" || k == "scope")
+            .collect();
+        let resp = self
+            .restricted_client
+            .get(&realm)?
+            .query(&query)
+            .send()
+            .await
+            .context("error getting registry token")?;
+        if resp.status() != StatusCode::OK {
+            return Err(format_err!(
+                "unexpected status code getting registry token: {}",
+                resp.status()
+            ));
+        }
+        let body: Value = resp.json().await?;
+        body["token"]
+            .as_str()
+            .or_else(|| body["access_token"].as_str())
+            .map(ToString::to_string)
+            .ok_or_else(|| format_err!("token not found in registry response"))
+    }
+}
+
+/// Return the digest of the first platform image in the index provided (if
+/// the manifest is an index).
+fn first_platform_manifest(manifest: &Value) -> Option<String> {
+    manifest["manifests"]
+        .as_array()?
+        .iter()
+        .find(|m| {
+            m["platform"]["os"]
+                .as_str()
+                .map_or(true, |os| os != "unknown")
+        })
+        .and_then(|m| m["digest"].as_str())
+        .map(ToString::to_string)
+}
+
+/// Check if the index provided contains attestation manifests (as generated
+/// by docker buildx when provenance or SBOM attestations are enabled).
+fn has_attestation_manifest(manifest: &Value) -> bool {
+    manifest["manifests"].as_array().map_or(false, |manifests| {
+        manifests.iter().any(|m| {
+            m["annotations"]["vnd.docker.reference.type"].as_str() == Some("attestation-manifest")
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const DIGEST: &str = "sha256:0123456789abcdef";
+
+    #[test]
+    fn parse_image_refs() {
+        assert_eq!(
+            ImageRef::parse("nginx").unwrap(),
+            ImageRef {
+                registry: DEFAULT_REGISTRY.to_string(),
+                repository: "library/nginx".to_string(),
+                reference: "latest".to_string(),
+            }
+        );
+        assert_eq!(
+            ImageRef::parse("ghcr.io/org/image:v1.0.0").unwrap(),
+            ImageRef {
+                registry: "ghcr.io".to_string(),
+                repository: "org/image".to_string(),
+                reference: "v1.0.0".to_string(),
+            }
+        );
+        assert_eq!(
+            ImageRef::parse("localhost:5000/image@sha256:abc").unwrap(),
+            ImageRef {
+                registry: "localhost:5000".to_string(),
+                repository: "image".to_string(),
+                reference: "sha256:abc".to_string(),
+            }
+        );
+        assert!(ImageRef::parse("https://ghcr.io/org/image").is_err());
+        assert!(ImageRef::parse("").is_err());
+    }
+
+    #[tokio::test]
+    async fn inspect_signed_image_with_sbom() {
+        let server = MockServer::start().await;
+        let token_realm = format!("{}/token", server.uri());
+        Mock::given(method("GET"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"token": "t"})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/org/image/manifests/v1.0.0"))
+            .and(wiremock::matchers::header("authorization", "Bearer t"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(DOCKER_CONTENT_DIGEST, DIGEST)
+                    .set_body_json(json!({"config": {"digest": "sha256:config"}})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/org/image/manifests/v1.0.0"))
+            .respond_with(ResponseTemplate::new(401).insert_header(
+                "www-authenticate",
+                format!(r#"Bearer realm="{token_realm}",service="registry",scope="repository:org/image:pull""#)
+                    .as_str(),
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/org/image/manifests/sha256-0123456789abcdef.sig"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/org/image/referrers/sha256:0123456789abcdef"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "manifests": [{"artifactType": "application/spdx+json"}]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/org/image/blobs/sha256:config"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"created": "2022-10-01T00:00:00Z"})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let image = format!("{}/org/image:v1.0.0", server.address());
+        assert_eq!(
+            inspect(&RestrictedClient::default(), &image, None)
+                .await
+                .unwrap(),
+            ImageInfo {
+                digest: DIGEST.to_string(),
+                signed: true,
+                sbom: true,
+                created_at: Some("2022-10-01T00:00:00Z".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn inspect_image_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let image = format!("{}/org/image:v1.0.0", server.address());
+        assert!(inspect(&RestrictedClient::default(), &image, None)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn index_helpers() {
+        let index = json!({
+            "manifests": [
+                {"digest": "sha256:amd64", "platform": {"os": "linux"}},
+                {
+                    "digest": "sha256:att",
+                    "platform": {"os": "unknown"},
+                    "annotations": {"vnd.docker.reference.type": "attestation-manifest"}
+                }
+            ]
+        });
+        assert_eq!(
+            first_platform_manifest(&index),
+            Some("sha256:amd64".to_string())
+        );
+        assert!(has_attestation_manifest(&index));
+        assert!(!has_attestation_manifest(&json!({"config": {}})));
+    }
+}
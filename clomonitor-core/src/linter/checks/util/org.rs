@@ -0,0 +1,94 @@
+use super::github::{self, md::MdRepositoryOwnerOn, OrgSettings};
+use crate::linter::{
+    check::{CheckInput, CheckOutput},
+    Provider,
+};
+use anyhow::Result;
+
+/// Get the settings of the GitHub organization that owns the repository. None
+/// is returned when no organization token has been provided or when the
+/// repository is not owned by a GitHub organization.
+pub(crate) async fn settings(input: &CheckInput<'_>) -> Option<Result<OrgSettings>> {
+    let token = input.li.github_org_token.as_ref()?;
+    if input.li.provider != Provider::Github
+        || input.gh_md.owner.on != MdRepositoryOwnerOn::Organization
+    {
+        return None;
+    }
+    Some(github::org_settings(&input.gh_md.owner.login, token).await)
+}
+
+/// Build the output of a check that verifies an organization setting. The
+/// check fails when the setting is not available, as GitHub only returns some
+/// of them when the token provided belongs to an organization owner.
+pub(crate) fn setting_output(
+    settings: &Result<OrgSettings>,
+    setting: &str,
+    enabled: impl Fn(&OrgSettings) -> Option<bool>,
+) -> CheckOutput {
+    match settings {
+        Ok(settings) => match enabled(settings) {
+            Some(true) => CheckOutput::passed(),
+            Some(false) => CheckOutput::not_passed(),
+            None => CheckOutput::failed().fail_reason(Some(format!(
+                "{setting} setting not available (an organization owner token is required)"
+            ))),
+        },
+        Err(err) => CheckOutput::failed().fail_reason(Some(format!("{err:#}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::format_err;
+
+    #[test]
+    fn setting_output_enabled() {
+        let settings = Ok(OrgSettings {
+            two_factor_requirement_enabled: Some(true),
+            ..OrgSettings::default()
+        });
+        assert_eq!(
+            setting_output(&settings, "2FA requirement", |s| s
+                .two_factor_requirement_enabled),
+            CheckOutput::passed()
+        );
+    }
+
+    #[test]
+    fn setting_output_disabled() {
+        let settings = Ok(OrgSettings {
+            two_factor_requirement_enabled: Some(false),
+            ..OrgSettings::default()
+        });
+        assert_eq!(
+            setting_output(&settings, "2FA requirement", |s| s
+                .two_factor_requirement_enabled),
+            CheckOutput::not_passed()
+        );
+    }
+
+    #[test]
+    fn setting_output_not_available() {
+        let settings = Ok(OrgSettings::default());
+        assert_eq!(
+            setting_output(&settings, "2FA requirement", |s| s
+                .two_factor_requirement_enabled),
+            CheckOutput::failed().fail_reason(Some(
+                "2FA requirement setting not available (an organization owner token is required)"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn setting_output_error() {
+        let settings = Err(format_err!("unexpected status code"));
+        assert_eq!(
+            setting_output(&settings, "2FA requirement", |s| s
+                .two_factor_requirement_enabled),
+            CheckOutput::failed().fail_reason(Some("unexpected status code".to_string()))
+        );
+    }
+}
@@ -1,8 +1,11 @@
-use crate::linter::checks::CHECKS;
+use crate::{linter::checks::CHECKS, net};
 use anyhow::{format_err, Error, Result};
 use serde::Deserialize;
 use tokio::process::Command;
 
+/// GitHub token scopes required to run the scorecard checks.
+pub(crate) const GITHUB_SCOPES: &[&str] = &["public_repo"];
+
 #[derive(Debug, Clone, Deserialize)]
 pub(crate) struct Scorecard {
     checks: Vec<ScorecardCheck>,
@@ -24,9 +27,13 @@ pub(crate) struct ScorecardCheckDocs {
 
 /// Get repository's OpenSSF Scorecard.
 pub(crate) async fn scorecard(repo_url: &str, github_token: &str) -> Result<Scorecard> {
-    let output = Command::new("scorecard")
-        .env("GITHUB_TOKEN", github_token)
-        .env_remove("GITHUB_REF")
+    let mut cmd = Command::new("scorecard");
+    cmd.env("GITHUB_TOKEN", github_token)
+        .env_remove("GITHUB_REF");
+    if let Some(proxy) = net::proxy_for(repo_url) {
+        cmd.env("HTTPS_PROXY", proxy);
+    }
+    let output = cmd
         .arg(format!("--repo={repo_url}"))
         .arg("--format=json")
         .arg("--show-details")
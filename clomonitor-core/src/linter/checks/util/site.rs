@@ -0,0 +1,182 @@
+use super::path::{self, Globs};
+use anyhow::Result;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Directories (relative to the repository's root) where the sources of a
+/// documentation site are usually located.
+const SITE_DIRS: [&str; 4] = ["", "website", "site", "docs"];
+
+/// Static site generators supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SiteGenerator {
+    Hugo,
+    Docusaurus,
+    Mkdocs,
+}
+
+impl SiteGenerator {
+    /// Static site generators that will be looked for, in order of priority.
+    const ALL: [SiteGenerator; 3] = [Self::Hugo, Self::Docusaurus, Self::Mkdocs];
+
+    /// Patterns used to locate the configuration files of the generator.
+    fn config_patterns(&self) -> &'static [&'static str] {
+        match self {
+            Self::Hugo => &[
+                "hugo.toml",
+                "hugo.yaml",
+                "hugo.yml",
+                "hugo.json",
+                "config.toml",
+                "config.yaml",
+                "config.yml",
+                "config/_default/*",
+            ],
+            Self::Docusaurus => &[
+                "docusaurus.config.js",
+                "docusaurus.config.mjs",
+                "docusaurus.config.ts",
+            ],
+            Self::Mkdocs => &["mkdocs.yml", "mkdocs.yaml"],
+        }
+    }
+
+    /// Directories where the generator expects the site's content.
+    fn content_dirs(&self) -> &'static [&'static str] {
+        match self {
+            Self::Hugo => &["content"],
+            Self::Docusaurus => &["docs", "blog", "src/pages"],
+            Self::Mkdocs => &["docs"],
+        }
+    }
+
+    /// Patterns used to locate the templates that define the site's layout
+    /// (i.e. where the footer is usually defined).
+    fn layout_patterns(&self) -> &'static [&'static str] {
+        match self {
+            Self::Hugo => &["layouts/**/*.html", "themes/*/layouts/**/*.html"],
+            Self::Docusaurus => &[
+                "src/theme/**/*.js",
+                "src/theme/**/*.tsx",
+                "src/theme/**/*.jsx",
+            ],
+            Self::Mkdocs => &["overrides/**/*.html", "theme/**/*.html"],
+        }
+    }
+}
+
+impl fmt::Display for SiteGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Hugo => "Hugo",
+            Self::Docusaurus => "Docusaurus",
+            Self::Mkdocs => "MkDocs",
+        };
+        write!(f, "{output}")
+    }
+}
+
+/// Documentation site built with a static site generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Site {
+    pub generator: SiteGenerator,
+    pub root: PathBuf,
+}
+
+impl Site {
+    /// Globs matching the site's configuration files.
+    pub(crate) fn config(&self) -> Globs {
+        Globs {
+            root: &self.root,
+            patterns: self.generator.config_patterns(),
+            case_sensitive: true,
+        }
+    }
+
+    /// Globs matching the templates that define the site's layout.
+    pub(crate) fn layouts(&self) -> Globs {
+        Globs {
+            root: &self.root,
+            patterns: self.generator.layout_patterns(),
+            case_sensitive: true,
+        }
+    }
+
+    /// Return the paths of the site's content directories that exist.
+    pub(crate) fn content_dirs(&self) -> Vec<PathBuf> {
+        self.generator
+            .content_dirs()
+            .iter()
+            .map(|dir| self.root.join(dir))
+            .filter(|dir| dir.is_dir())
+            .collect()
+    }
+}
+
+/// Detect the documentation site in the repository provided, if any. A site
+/// is detected when the configuration file of any of the supported static
+/// site generators is found along with some of its content directories.
+pub(crate) fn detect(root: &Path) -> Result<Option<Site>> {
+    for dir in SITE_DIRS {
+        for generator in SiteGenerator::ALL {
+            let site = Site {
+                generator,
+                root: root.join(dir),
+            };
+            if !path::matches(&site.config())?.is_empty() && !site.content_dirs().is_empty() {
+                return Ok(Some(site));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA_PATH: &str = "src/testdata/sites";
+
+    #[test]
+    fn detect_hugo_site() {
+        let root = Path::new(TESTDATA_PATH).join("hugo");
+        assert_eq!(
+            detect(&root).unwrap(),
+            Some(Site {
+                generator: SiteGenerator::Hugo,
+                root,
+            })
+        );
+    }
+
+    #[test]
+    fn detect_docusaurus_site_in_subdirectory() {
+        let root = Path::new(TESTDATA_PATH).join("docusaurus");
+        assert_eq!(
+            detect(&root).unwrap(),
+            Some(Site {
+                generator: SiteGenerator::Docusaurus,
+                root: root.join("website"),
+            })
+        );
+    }
+
+    #[test]
+    fn detect_mkdocs_site() {
+        let site = detect(&Path::new(TESTDATA_PATH).join("mkdocs"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(site.generator, SiteGenerator::Mkdocs);
+        assert_eq!(
+            site.content_dirs(),
+            vec![Path::new(TESTDATA_PATH).join("mkdocs").join("docs")]
+        );
+    }
+
+    #[test]
+    fn detect_no_site() {
+        assert_eq!(detect(Path::new("src/testdata")).unwrap(), None);
+    }
+}
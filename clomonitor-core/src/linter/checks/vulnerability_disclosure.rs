@@ -0,0 +1,198 @@
+use super::util::github::{self, md::MdRepository, SecurityAdvisory};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
+    CheckSet,
+};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::BTreeSet;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "vulnerability_disclosure";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 1;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &[
+        "Enable private vulnerability reporting in the repository (Settings > Code security), so that vulnerabilities can be reported privately.",
+        "Publish a GitHub security advisory for each of the vulnerabilities (CVEs) fixed in the project's releases.",
+    ],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#vulnerability-disclosure",
+};
+
+lazy_static! {
+    static ref CVE_ID: Regex =
+        Regex::new(r"\bCVE-\d{4}-\d{4,}\b").expect("exprs in CVE_ID to be valid");
+}
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    let token = &input.li.github_token;
+    let reporting_enabled = github::private_vulnerability_reporting(&input.li.url, token).await?;
+    let advisories = github::published_security_advisories(&input.li.url, token).await?;
+    Ok(grade(
+        reporting_enabled,
+        &releases_cves(&input.gh_md),
+        &advisories,
+    ))
+}
+
+/// Get the CVEs mentioned in the descriptions of the repository's releases.
+fn releases_cves(gh_md: &MdRepository) -> BTreeSet<String> {
+    gh_md
+        .releases
+        .nodes
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|release| release.description.as_deref())
+        .flat_map(|description| CVE_ID.find_iter(description))
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+/// Grade the vulnerability disclosure practices of the repository. The check
+/// passes when private vulnerability reporting is enabled, and gets full
+/// credit when all the CVEs mentioned in its releases have been published as
+/// security advisories as well.
+fn grade(
+    reporting_enabled: bool,
+    cves: &BTreeSet<String>,
+    advisories: &[SecurityAdvisory],
+) -> CheckOutput {
+    if !reporting_enabled {
+        return CheckOutput::not_passed().details(Some(
+            "Private vulnerability reporting is not enabled".to_string(),
+        ));
+    }
+
+    let mut details = vec![];
+    if !advisories.is_empty() {
+        details.push(format!(
+            "**Security advisories published**:\n\n{}",
+            advisories
+                .iter()
+                .map(|a| match &a.cve_id {
+                    Some(cve_id) => format!("- [{}]({}) ({cve_id})", a.ghsa_id, a.html_url),
+                    None => format!("- [{}]({})", a.ghsa_id, a.html_url),
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        ));
+    }
+    let missing: Vec<&String> = cves
+        .iter()
+        .filter(|cve| !advisories.iter().any(|a| a.cve_id.as_ref() == Some(*cve)))
+        .collect();
+    let credit = if missing.is_empty() {
+        100
+    } else {
+        details.push(format!(
+            "**CVEs mentioned in releases without a security advisory**:\n\n{}",
+            missing
+                .iter()
+                .map(|cve| format!("- {cve}"))
+                .collect::<Vec<String>>()
+                .join("\n")
+        ));
+        50
+    };
+
+    let details = (!details.is_empty()).then(|| details.join("\n\n"));
+    CheckOutput::passed().details(details).credit(Some(credit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::checks::util::github::md::{
+        MdRepositoryReleases, MdRepositoryReleasesNodes, MdRepositoryReleasesNodesReleaseAssets,
+    };
+
+    fn advisory(ghsa_id: &str, cve_id: Option<&str>) -> SecurityAdvisory {
+        SecurityAdvisory {
+            ghsa_id: ghsa_id.to_string(),
+            cve_id: cve_id.map(ToString::to_string),
+            html_url: format!("https://github.com/org/repo/security/advisories/{ghsa_id}"),
+        }
+    }
+
+    fn release(description: &str) -> Option<MdRepositoryReleasesNodes> {
+        Some(MdRepositoryReleasesNodes {
+            created_at: "created_at_date".to_string(),
+            description: Some(description.to_string()),
+            is_prerelease: false,
+            release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+            tag_name: "v1.0.0".to_string(),
+            url: "release_url".to_string(),
+        })
+    }
+
+    #[test]
+    fn releases_cves_found() {
+        let gh_md = MdRepository {
+            releases: MdRepositoryReleases {
+                nodes: Some(vec![
+                    release("Fixes CVE-2023-1234 and CVE-2023-12345"),
+                    release("Fixes CVE-2023-1234 (again)"),
+                ]),
+            },
+            ..MdRepository::default()
+        };
+        assert_eq!(
+            releases_cves(&gh_md),
+            BTreeSet::from(["CVE-2023-1234".to_string(), "CVE-2023-12345".to_string()])
+        );
+    }
+
+    #[test]
+    fn grade_reporting_disabled() {
+        assert_eq!(
+            grade(false, &BTreeSet::new(), &[]),
+            CheckOutput::not_passed().details(Some(
+                "Private vulnerability reporting is not enabled".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn grade_reporting_enabled_no_cves() {
+        assert_eq!(
+            grade(true, &BTreeSet::new(), &[]),
+            CheckOutput::passed().credit(Some(100))
+        );
+    }
+
+    #[test]
+    fn grade_all_cves_published() {
+        let cves = BTreeSet::from(["CVE-2023-1234".to_string()]);
+        let advisories = [advisory("GHSA-xxxx-xxxx-xxxx", Some("CVE-2023-1234"))];
+        assert_eq!(
+            grade(true, &cves, &advisories),
+            CheckOutput::passed()
+                .details(Some(
+                    "**Security advisories published**:\n\n- [GHSA-xxxx-xxxx-xxxx](https://github.com/org/repo/security/advisories/GHSA-xxxx-xxxx-xxxx) (CVE-2023-1234)".to_string()
+                ))
+                .credit(Some(100))
+        );
+    }
+
+    #[test]
+    fn grade_some_cves_not_published() {
+        let cves = BTreeSet::from(["CVE-2023-1234".to_string(), "CVE-2023-5678".to_string()]);
+        let advisories = [advisory("GHSA-xxxx-xxxx-xxxx", Some("CVE-2023-1234"))];
+        let output = grade(true, &cves, &advisories);
+        assert!(output.passed);
+        assert_eq!(output.credit, Some(50));
+        assert!(output.details.unwrap().ends_with(
+            "**CVEs mentioned in releases without a security advisory**:\n\n- CVE-2023-5678"
+        ));
+    }
+}
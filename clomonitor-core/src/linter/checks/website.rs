@@ -1,5 +1,5 @@
 use crate::linter::{
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Remediation},
     CheckSet,
 };
 use anyhow::Result;
@@ -13,6 +13,13 @@ pub(crate) const WEIGHT: usize = 5;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Guidance on how to make this check pass.
+pub(crate) const REMEDIATION: Remediation = Remediation {
+    steps: &["Configure the project's website url in the GitHub repository settings."],
+    template: None,
+    docs_url: "https://clomonitor.io/docs/topics/checks/#website",
+};
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Website in Github
@@ -0,0 +1,319 @@
+use super::{
+    check::{CheckOutput, FULL_CREDIT},
+    checks::util::helpers::find_exemption,
+    metadata::Metadata,
+    CheckSet, LinterInput,
+};
+use anyhow::{format_err, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::PathBuf, process::Stdio, str::FromStr, time::Duration};
+use tokio::{io::AsyncWriteExt, process::Command, time::timeout};
+
+/// Default maximum time an external check can take to run.
+const DEFAULT_TIMEOUT: u64 = 60;
+
+/// Command used by default to run external checks provided as WASM modules.
+const DEFAULT_WASM_RUNTIME: &str = "wasmtime";
+
+/// External check configuration.
+///
+/// External checks are run as a subprocess (an executable or a WASM module
+/// run using a WASM runtime like wasmtime). They receive an `ExternalCheckInput`
+/// document in JSON format in their standard input and are expected to write
+/// an `ExternalCheckResponse` document in JSON format to their standard output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalCheck {
+    pub id: String,
+    pub kind: ExternalCheckKind,
+    pub path: PathBuf,
+
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    pub section: ReportSection,
+    pub weight: usize,
+    pub check_sets: Vec<CheckSet>,
+
+    /// Maximum time the check can take to run (in seconds).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+
+    /// Command used to run WASM modules (defaults to wasmtime).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wasm_runtime: Option<String>,
+}
+
+/// Kinds of external checks supported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalCheckKind {
+    Executable,
+    Wasm,
+}
+
+/// Report sections external checks can be added to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ReportSection {
+    Documentation,
+    License,
+    BestPractices,
+    Security,
+    Legal,
+    Artifacts,
+    Organization,
+}
+
+impl FromStr for ReportSection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "documentation" => Ok(Self::Documentation),
+            "license" => Ok(Self::License),
+            "best_practices" => Ok(Self::BestPractices),
+            "security" => Ok(Self::Security),
+            "legal" => Ok(Self::Legal),
+            "artifacts" => Ok(Self::Artifacts),
+            "organization" => Ok(Self::Organization),
+            _ => Err(format_err!("invalid section: {s}")),
+        }
+    }
+}
+
+/// Input provided to external checks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalCheckInput {
+    pub root: PathBuf,
+    pub url: String,
+    pub check_sets: Vec<CheckSet>,
+}
+
+/// Response expected from external checks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalCheckResponse {
+    pub passed: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+
+    /// Percentage of the check's weight awarded when it passes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit: Option<usize>,
+}
+
+impl From<ExternalCheckResponse> for CheckOutput {
+    fn from(resp: ExternalCheckResponse) -> Self {
+        let output = if resp.passed {
+            CheckOutput::passed()
+        } else {
+            CheckOutput::not_passed()
+        };
+        output
+            .url(resp.url)
+            .details(resp.details)
+            .credit(resp.credit.map(|credit| credit.min(FULL_CREDIT)))
+    }
+}
+
+/// External check output included in the linter report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalCheckOutput {
+    pub section: ReportSection,
+    pub weight: usize,
+
+    #[serde(flatten)]
+    pub output: CheckOutput,
+}
+
+/// Run the external checks provided that apply to the linter input given,
+/// returning their outputs indexed by check id.
+pub(crate) async fn run(
+    checks: &[ExternalCheck],
+    li: &LinterInput,
+    cm_md: Option<&Metadata>,
+) -> BTreeMap<String, ExternalCheckOutput> {
+    let mut outputs = BTreeMap::new();
+    for check in checks {
+        // Skip checks that don't belong to any of the check sets provided,
+        // unless they have been explicitly enabled (or disabled)
+        if li.check_overrides.disable.contains(&check.id)
+            || (!check.check_sets.iter().any(|cs| li.check_sets.contains(cs))
+                && !li.check_overrides.enable.contains(&check.id))
+        {
+            continue;
+        }
+
+        // Use the exemption declared for this check if any, or run it
+        let output = match find_exemption(&check.id, cm_md) {
+            Some(exemption) => CheckOutput::from(exemption),
+            None => match run_check(check, li).await {
+                Ok(resp) => CheckOutput::from(resp),
+                Err(err) => CheckOutput::failed().fail_reason(Some(format!("{err:#}"))),
+            },
+        };
+        outputs.insert(
+            check.id.clone(),
+            ExternalCheckOutput {
+                section: check.section,
+                weight: check.weight,
+                output,
+            },
+        );
+    }
+    outputs
+}
+
+/// Run the external check provided.
+async fn run_check(check: &ExternalCheck, li: &LinterInput) -> Result<ExternalCheckResponse> {
+    // Prepare command
+    let mut cmd = match check.kind {
+        ExternalCheckKind::Executable => Command::new(&check.path),
+        ExternalCheckKind::Wasm => {
+            let mut cmd = Command::new(
+                check
+                    .wasm_runtime
+                    .as_deref()
+                    .unwrap_or(DEFAULT_WASM_RUNTIME),
+            );
+            cmd.arg(&check.path);
+            cmd
+        }
+    };
+    cmd.args(&check.args)
+        .current_dir(&li.root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    // Run it, writing the input to its stdin
+    let input = serde_json::to_vec(&ExternalCheckInput {
+        root: li.root.clone(),
+        url: li.url.clone(),
+        check_sets: li.check_sets.clone(),
+    })?;
+    let mut child = cmd
+        .spawn()
+        .context(format!("error running external check {}", check.id))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(&input).await?;
+    }
+    let timeout_secs = check.timeout.unwrap_or(DEFAULT_TIMEOUT);
+    let output = timeout(Duration::from_secs(timeout_secs), child.wait_with_output())
+        .await
+        .map_err(|_| format_err!("external check timed out after {timeout_secs}s"))??;
+    if !output.status.success() {
+        return Err(format_err!(
+            "external check exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // Parse response
+    serde_json::from_slice(&output.stdout).context("invalid external check response")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_section_from_str() {
+        assert_eq!(
+            "best_practices".parse::<ReportSection>().unwrap(),
+            ReportSection::BestPractices
+        );
+        assert_eq!(
+            "security".parse::<ReportSection>().unwrap(),
+            ReportSection::Security
+        );
+        assert!("unknown".parse::<ReportSection>().is_err());
+    }
+
+    fn external_check(path: &str, args: &[&str], check_sets: Vec<CheckSet>) -> ExternalCheck {
+        ExternalCheck {
+            id: "custom".to_string(),
+            kind: ExternalCheckKind::Executable,
+            path: PathBuf::from(path),
+            args: args.iter().map(ToString::to_string).collect(),
+            section: ReportSection::Security,
+            weight: 2,
+            check_sets,
+            timeout: None,
+            wasm_runtime: None,
+        }
+    }
+
+    fn linter_input() -> LinterInput {
+        LinterInput {
+            root: PathBuf::from("."),
+            url: "https://github.com/org/repo".to_string(),
+            check_sets: vec![CheckSet::Code],
+            ..LinterInput::default()
+        }
+    }
+
+    #[test]
+    fn response_into_check_output() {
+        let resp = ExternalCheckResponse {
+            passed: true,
+            url: Some("https://url".to_string()),
+            details: None,
+            credit: Some(150),
+        };
+        assert_eq!(
+            CheckOutput::from(resp),
+            CheckOutput::passed()
+                .url(Some("https://url".to_string()))
+                .credit(Some(FULL_CREDIT))
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_passed() {
+        let checks = vec![external_check(
+            "sh",
+            &[
+                "-c",
+                r#"cat > /dev/null; echo '{"passed": true, "details": "ok"}'"#,
+            ],
+            vec![CheckSet::Code],
+        )];
+
+        let outputs = run(&checks, &linter_input(), None).await;
+        assert_eq!(
+            outputs["custom"],
+            ExternalCheckOutput {
+                section: ReportSection::Security,
+                weight: 2,
+                output: CheckOutput::passed().details(Some("ok".to_string())),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_invalid_response() {
+        let checks = vec![external_check(
+            "sh",
+            &["-c", "echo invalid"],
+            vec![CheckSet::Code],
+        )];
+
+        let outputs = run(&checks, &linter_input(), None).await;
+        assert!(outputs["custom"].output.failed);
+    }
+
+    #[tokio::test]
+    async fn run_check_skipped() {
+        let checks = vec![external_check("sh", &[], vec![CheckSet::Docs])];
+
+        let outputs = run(&checks, &linter_input(), None).await;
+        assert!(outputs.is_empty());
+    }
+}
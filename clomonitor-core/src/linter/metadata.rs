@@ -13,6 +13,7 @@ pub(crate) const METADATA_FILE: &str = ".clomonitor.yml";
 pub(crate) struct Metadata {
     pub exemptions: Option<Vec<Exemption>>,
     pub license_scanning: Option<LicenseScanning>,
+    pub locales: Option<Vec<String>>,
 }
 
 impl Metadata {
@@ -59,7 +60,8 @@ mod tests {
                 exemptions: Some(vec![Exemption {
                     check: "artifacthub_badge".to_string(),
                     reason: "this is a sample reason".to_string(),
-                }])
+                }]),
+                locales: None,
             },
         );
     }
@@ -1,24 +1,37 @@
 use self::{
     check::*,
-    checks::util::helpers::{find_exemption, should_skip_check},
+    checks::util::{
+        helpers::{find_exemption, provider_limited_checks, should_skip_check},
+        oci, org,
+    },
 };
+use crate::{cache::DynCache, net::RestrictedClient};
 use anyhow::Result;
 use async_trait::async_trait;
+#[cfg(feature = "cli")]
 use clap::ValueEnum;
 #[cfg(feature = "mocks")]
 use mockall::automock;
+#[cfg(feature = "postgres")]
 use postgres_types::ToSql;
 use serde::{Deserialize, Serialize};
-use std::{fmt, path::PathBuf, sync::Arc};
+use std::{collections::BTreeMap, fmt, path::PathBuf, sync::Arc};
 
 mod check;
 mod checks;
+mod external;
 mod metadata;
 mod report;
+mod scopes;
 
 pub use self::{
-    check::{CheckId, CheckOutput},
+    check::{CheckId, CheckOutput, CheckStatus, Remediation, RemediationTemplate},
+    external::{
+        ExternalCheck, ExternalCheckInput, ExternalCheckKind, ExternalCheckOutput,
+        ExternalCheckResponse, ReportSection,
+    },
     report::*,
+    scopes::{validate_github_token_scopes, GithubTokenScopesReport},
 };
 pub use checks::util::github::setup_http_client as setup_github_http_client;
 pub(crate) use checks::*;
@@ -40,23 +53,66 @@ pub struct LinterInput {
     pub root: PathBuf,
     pub url: String,
     pub check_sets: Vec<CheckSet>,
+    pub check_overrides: CheckOverrides,
     pub github_token: String,
+    pub provider: Provider,
+
+    /// Token of an owner of the GitHub organization the repository belongs
+    /// to. The checks in the organization section are only run when it is
+    /// provided.
+    pub github_org_token: Option<String>,
+
+    /// Home url of the project the repository belongs to. Some checks fetch
+    /// it to verify the project's live website.
+    pub home_url: Option<String>,
+
+    /// Container images built from the repository. They are inspected in
+    /// their registries by the checks in the artifacts section.
+    pub container_images: Vec<String>,
+
+    /// Url of the project in the Gerrit instance hosting the canonical
+    /// repository, when the repository url provided points to a mirror of it
+    /// (i.e. `https://gerrit.example.org/r/admin/repos/org/project`). Code
+    /// checks run against the mirror, whereas the review and activity ones
+    /// use the Gerrit REST API.
+    pub gerrit_url: Option<String>,
+}
+
+/// Checks overrides for a repository. They allow enabling checks that don't
+/// belong to any of the repository's check sets, or disabling some that do.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckOverrides {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enable: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disable: Vec<String>,
+}
+
+impl CheckOverrides {
+    /// Check if no overrides have been defined.
+    pub fn is_empty(&self) -> bool {
+        self.enable.is_empty() && self.disable.is_empty()
+    }
 }
 
 /// Check sets define a set of checks that will be run on a given repository.
 /// Multiple check sets can be assigned to a repository.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, ValueEnum, Serialize, Deserialize, ToSql)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[cfg_attr(feature = "postgres", derive(ToSql), postgres(name = "check_set"))]
 #[serde(rename_all = "kebab-case")]
-#[postgres(name = "check_set")]
 pub enum CheckSet {
-    #[postgres(name = "code")]
+    #[cfg_attr(feature = "postgres", postgres(name = "code"))]
     Code,
-    #[postgres(name = "code-lite")]
+    #[cfg_attr(feature = "postgres", postgres(name = "code-lite"))]
     CodeLite,
-    #[postgres(name = "community")]
+    #[cfg_attr(feature = "postgres", postgres(name = "community"))]
     Community,
-    #[postgres(name = "docs")]
+    #[cfg_attr(feature = "postgres", postgres(name = "docs"))]
     Docs,
+    #[cfg_attr(feature = "postgres", postgres(name = "docs-site"))]
+    DocsSite,
 }
 
 impl fmt::Display for CheckSet {
@@ -66,19 +122,175 @@ impl fmt::Display for CheckSet {
             Self::CodeLite => "CODE-LITE",
             Self::Community => "COMMUNITY",
             Self::Docs => "DOCS",
+            Self::DocsSite => "DOCS-SITE",
+        };
+        write!(f, "{output}")
+    }
+}
+
+/// Return the guidance on how to make the check provided pass (if it exists).
+pub fn remediation(check_id: &str) -> Option<Remediation> {
+    CHECKS.get(check_id).map(|config| config.remediation)
+}
+
+/// Extract the path of the file the url provided points to, if it's the url
+/// of a file in the repository given (as built by the linter for any of the
+/// providers supported).
+pub fn file_path_from_url(repository_url: &str, url: &str) -> Option<String> {
+    let rest = url
+        .strip_prefix(repository_url.trim_end_matches('/'))?
+        .trim_start_matches('/');
+    let rest = rest.strip_prefix("-/").unwrap_or(rest);
+    let rest = rest
+        .strip_prefix("blob/")
+        .or_else(|| rest.strip_prefix("src/branch/"))?;
+    let (_branch, path) = rest.split_once('/')?;
+    if path.is_empty() {
+        return None;
+    }
+    Some(path.to_string())
+}
+
+/// Provider hosting a repository. Linting repositories hosted on GitHub can
+/// take advantage of its API and the OpenSSF Scorecard, so some checks may be
+/// limited (or not available at all) on other providers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum Provider {
+    #[default]
+    Github,
+    Gitlab,
+    Gitea,
+}
+
+impl Provider {
+    /// Detect the provider hosting the repository url provided. None is
+    /// returned when the host is not a well known one (self-hosted instances
+    /// must have their provider set explicitly).
+    pub fn from_url(url: &str) -> Option<Self> {
+        let host = url
+            .split_once("://")
+            .map_or(url, |(_, rest)| rest)
+            .split('/')
+            .next()?
+            .to_lowercase();
+        match host.as_str() {
+            "github.com" => Some(Self::Github),
+            "gitlab.com" => Some(Self::Gitlab),
+            "gitea.com" | "codeberg.org" => Some(Self::Gitea),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Github => "GitHub",
+            Self::Gitlab => "GitLab",
+            Self::Gitea => "Gitea",
+        };
+        write!(f, "{output}")
+    }
+}
+
+/// Level of support of a check on a given provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderSupport {
+    /// The check runs as on any other provider.
+    Full,
+    /// The check runs, but some of the ways it has to pass are not available.
+    Partial,
+    /// The check cannot be run at all.
+    Unsupported,
+}
+
+impl fmt::Display for ProviderSupport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Full => "Fully supported",
+            Self::Partial => "Partially supported",
+            Self::Unsupported => "Not supported",
+        };
+        write!(f, "{output}")
+    }
+}
+
+/// Severity of a check, used to prioritize fixing the checks that fail. It
+/// may be overridden by each foundation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// The check is expected to pass in all repositories.
+    Required,
+    /// The check should pass in most repositories.
+    Recommended,
+    /// The check is nice to have, but not applicable to all repositories.
+    Optional,
+}
+
+impl Severity {
+    /// Return the default severity of the check provided (if it exists).
+    pub fn of(check_id: &str) -> Option<Severity> {
+        CHECKS.get(check_id).map(|check| check.severity)
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Required => "Required",
+            Self::Recommended => "Recommended",
+            Self::Optional => "Optional",
         };
         write!(f, "{output}")
     }
 }
 
 /// CLOMonitor core linter (Linter implementation).
-pub struct CoreLinter;
+pub struct CoreLinter {
+    cache: Option<DynCache>,
+    external_checks: Vec<ExternalCheck>,
+    restricted_client: RestrictedClient,
+}
 
 #[allow(clippy::new_without_default)]
 impl CoreLinter {
     /// Create a new CoreLinter instance.
     pub fn new() -> Self {
-        Self
+        Self {
+            cache: None,
+            external_checks: vec![],
+            restricted_client: RestrictedClient::default(),
+        }
+    }
+
+    /// Create a new CoreLinter instance that will use the cache provided to
+    /// store the results of some remote probes.
+    pub fn with_cache(cache: DynCache) -> Self {
+        Self {
+            cache: Some(cache),
+            external_checks: vec![],
+            restricted_client: RestrictedClient::default(),
+        }
+    }
+
+    /// Register the external checks provided, which will be run alongside the
+    /// built-in ones.
+    pub fn with_external_checks(mut self, checks: Vec<ExternalCheck>) -> Self {
+        self.external_checks = checks;
+        self
+    }
+
+    /// Use the restricted client provided to fetch the urls provided by users
+    /// (i.e. the project's home url or its container images' registries), so
+    /// that only the hosts allowed by its host policy are reached.
+    pub fn with_restricted_client(mut self, restricted_client: RestrictedClient) -> Self {
+        self.restricted_client = restricted_client;
+        self
     }
 }
 
@@ -86,17 +298,82 @@ impl CoreLinter {
 impl Linter for CoreLinter {
     async fn lint(&self, li: &LinterInput) -> Result<Report> {
         // Prepare check input
-        let ci = CheckInput::new(li).await?;
+        let mut ci =
+            CheckInput::new(li, self.cache.clone(), self.restricted_client.clone()).await?;
+        let durations = Durations::default();
+
+        // Run the checks other checks depend on first, so that the ones
+        // depending on them are not run when they don't pass
+        let maintainers = run!(maintainers, &ci, &durations);
+        let recent_release = run!(recent_release, &ci, &durations);
+        ci.record_prerequisite(maintainers::ID, maintainers.as_ref());
+        ci.record_prerequisite(recent_release::ID, recent_release.as_ref());
 
         // Run some async checks concurrently
-        let (analytics, contributing, trademark_disclaimer) = tokio::join!(
-            run_async!(analytics, &ci),
-            run_async!(contributing, &ci),
-            run_async!(trademark_disclaimer, &ci),
+        let (
+            accessibility_statement,
+            analytics,
+            branch_protection,
+            code_review,
+            contributing,
+            fuzzing,
+            maintained,
+            maintainers_activity,
+            openssf_badge,
+            privacy_policy,
+            slsa_provenance,
+            trademark_disclaimer,
+            vulnerability_disclosure,
+        ) = tokio::join!(
+            run_async!(accessibility_statement, &ci, &durations),
+            run_async!(analytics, &ci, &durations),
+            run_async!(branch_protection, &ci, &durations),
+            run_async!(code_review, &ci, &durations),
+            run_async!(contributing, &ci, &durations),
+            run_async!(fuzzing, &ci, &durations),
+            run_async!(maintained, &ci, &durations),
+            run_async!(maintainers_activity, &ci, &durations),
+            run_async!(openssf_badge, &ci, &durations),
+            run_async!(privacy_policy, &ci, &durations),
+            run_async!(slsa_provenance, &ci, &durations),
+            run_async!(trademark_disclaimer, &ci, &durations),
+            run_async!(vulnerability_disclosure, &ci, &durations),
         );
 
+        // Inspect the container images built from the repository (if any and
+        // some of the checks that use them will be run)
+        let images_checks = [
+            image_recent_rebuild::ID,
+            image_sbom::ID,
+            image_signature::ID,
+        ];
+        let images = if images_checks.iter().all(|id| should_skip_check(id, li)) {
+            vec![]
+        } else {
+            oci::inspect_all(
+                &ci.restricted_client,
+                &li.container_images,
+                ci.cache.as_ref(),
+            )
+            .await
+        };
+
+        // Get the settings of the organization the repository belongs to (if
+        // a token for it has been provided and some of the checks that use
+        // them will be run)
+        let org_checks = [
+            org_default_permissions::ID,
+            org_secret_scanning::ID,
+            org_two_factor_auth::ID,
+        ];
+        let org_settings = if org_checks.iter().all(|id| should_skip_check(id, li)) {
+            None
+        } else {
+            org::settings(&ci).await
+        };
+
         // Run some sync checks needed in advance
-        let spdx_id = run!(license_spdx_id, &ci);
+        let spdx_id = run!(license_spdx_id, &ci, &durations);
         let mut spdx_id_value: Option<String> = None;
         if let Some(r) = &spdx_id {
             spdx_id_value = r.value.clone();
@@ -105,49 +382,167 @@ impl Linter for CoreLinter {
         // Run the remaining sync checks and build report
         let mut report = Report {
             documentation: Documentation {
-                adopters: run!(adopters, &ci),
-                changelog: run!(changelog, &ci),
-                code_of_conduct: run!(code_of_conduct, &ci),
+                accessibility_statement,
+                adopters: run!(adopters, &ci, &durations),
+                changelog: run!(changelog, &ci, &durations),
+                code_of_conduct: run!(code_of_conduct, &ci, &durations),
                 contributing,
-                governance: run!(governance, &ci),
-                maintainers: run!(maintainers, &ci),
-                readme: run!(readme, &ci),
-                roadmap: run!(roadmap, &ci),
-                website: run!(website, &ci),
+                governance: run!(governance, &ci, &durations),
+                maintainers,
+                maintainers_activity,
+                readme: run!(readme, &ci, &durations),
+                roadmap: run!(roadmap, &ci, &durations),
+                website: run!(website, &ci, &durations),
             },
             license: License {
-                license_approved: license_approved::check(&ci, spdx_id_value),
-                license_scanning: run!(license_scanning, &ci),
+                license_approved: license_approved::check(&ci, spdx_id_value.clone()),
+                license_compatibility: license_compatibility::check(&ci, spdx_id_value),
+                license_scanning: run!(license_scanning, &ci, &durations),
                 license_spdx_id: spdx_id,
             },
             best_practices: BestPractices {
                 analytics,
-                artifacthub_badge: run!(artifacthub_badge, &ci),
-                cla: run!(cla, &ci),
-                community_meeting: run!(community_meeting, &ci),
-                dco: run!(dco, &ci),
-                github_discussions: run!(github_discussions, &ci),
-                openssf_badge: run!(openssf_badge, &ci),
-                recent_release: run!(recent_release, &ci),
-                slack_presence: run!(slack_presence, &ci),
+                artifacthub_badge: run!(artifacthub_badge, &ci, &durations),
+                cla: run!(cla, &ci, &durations),
+                community_meeting: run!(community_meeting, &ci, &durations),
+                dco: run!(dco, &ci, &durations),
+                dependency_update_automation: run!(dependency_update_automation, &ci, &durations),
+                github_discussions: run!(github_discussions, &ci, &durations),
+                openssf_badge,
+                recent_release,
+                release_notes: run!(release_notes, &ci, &durations),
+                slack_presence: run!(slack_presence, &ci, &durations),
             },
             security: Security {
-                binary_artifacts: run!(binary_artifacts, &ci),
-                code_review: run!(code_review, &ci),
-                dangerous_workflow: run!(dangerous_workflow, &ci),
-                dependency_update_tool: run!(dependency_update_tool, &ci),
-                maintained: run!(maintained, &ci),
-                sbom: run!(sbom, &ci),
-                security_policy: run!(security_policy, &ci),
-                signed_releases: run!(signed_releases, &ci),
-                token_permissions: run!(token_permissions, &ci),
+                binary_artifacts: run!(binary_artifacts, &ci, &durations),
+                branch_protection,
+                code_review,
+                dangerous_workflow: run!(dangerous_workflow, &ci, &durations),
+                dependency_update_tool: run!(dependency_update_tool, &ci, &durations),
+                fuzzing,
+                maintained,
+                sbom: run!(sbom, &ci, &durations),
+                security_insights: run!(security_insights, &ci, &durations),
+                security_policy: run!(security_policy, &ci, &durations),
+                signed_releases: run!(signed_releases, &ci, &durations),
+                slsa_provenance,
+                supported_versions: run!(supported_versions, &ci, &durations),
+                token_permissions: run!(token_permissions, &ci, &durations),
+                vulnerability_disclosure,
             },
             legal: Legal {
+                dco_or_cla: run!(dco_or_cla, &ci, &durations),
+                privacy_policy,
                 trademark_disclaimer,
             },
+            artifacts: Artifacts {
+                image_recent_rebuild: image_recent_rebuild::check(&ci, &images),
+                image_sbom: image_sbom::check(&ci, &images),
+                image_signature: image_signature::check(&ci, &images),
+            },
+            organization: Organization {
+                org_default_permissions: org_default_permissions::check(&ci, org_settings.as_ref()),
+                org_secret_scanning: org_secret_scanning::check(&ci, org_settings.as_ref()),
+                org_two_factor_auth: org_two_factor_auth::check(&ci, org_settings.as_ref()),
+            },
+            external: external::run(&self.external_checks, li, ci.cm_md.as_ref()).await,
+            provider_limited: provider_limited_checks(li),
+            durations: BTreeMap::new(),
         };
+        report.durations = durations.into_inner();
         report.apply_exemptions();
 
         Ok(report)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_from_url() {
+        assert_eq!(
+            Provider::from_url("https://github.com/org/repo"),
+            Some(Provider::Github)
+        );
+        assert_eq!(
+            Provider::from_url("https://GitLab.com/group/subgroup/repo"),
+            Some(Provider::Gitlab)
+        );
+        assert_eq!(
+            Provider::from_url("https://codeberg.org/org/repo"),
+            Some(Provider::Gitea)
+        );
+        assert_eq!(Provider::from_url("https://git.example.com/org/repo"), None);
+    }
+
+    #[test]
+    fn remediation_defined_for_all_checks() {
+        for check_id in CHECKS.keys() {
+            let remediation = remediation(check_id).unwrap();
+            assert!(!remediation.steps.is_empty());
+            assert!(remediation
+                .docs_url
+                .starts_with("https://clomonitor.io/docs/topics/checks/#"));
+        }
+        assert_eq!(
+            remediation("security_policy")
+                .unwrap()
+                .template
+                .map(|template| template.path),
+            Some("SECURITY.md")
+        );
+        assert!(remediation("unknown").is_none());
+    }
+
+    #[test]
+    fn checks_dependencies_are_valid() {
+        for check in CHECKS.values() {
+            for dependency in &check.depends_on {
+                // Dependencies are run in advance, so they cannot depend on
+                // other checks themselves
+                assert!(CHECKS[dependency].depends_on.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn file_path_from_url_per_provider() {
+        assert_eq!(
+            file_path_from_url(
+                "https://github.com/org/repo",
+                "https://github.com/org/repo/blob/main/docs/GOVERNANCE.md"
+            ),
+            Some("docs/GOVERNANCE.md".to_string())
+        );
+        assert_eq!(
+            file_path_from_url(
+                "https://gitlab.com/org/repo/",
+                "https://gitlab.com/org/repo/-/blob/main/SECURITY.md"
+            ),
+            Some("SECURITY.md".to_string())
+        );
+        assert_eq!(
+            file_path_from_url(
+                "https://codeberg.org/org/repo",
+                "https://codeberg.org/org/repo/src/branch/main/CHANGELOG.md"
+            ),
+            Some("CHANGELOG.md".to_string())
+        );
+        assert_eq!(
+            file_path_from_url(
+                "https://github.com/org/repo",
+                "https://github.com/org/.github/blob/HEAD/CONTRIBUTING.md"
+            ),
+            None
+        );
+        assert_eq!(
+            file_path_from_url(
+                "https://github.com/org/repo",
+                "https://github.com/org/repo/releases/tag/v1.0.0"
+            ),
+            None
+        );
+    }
+}
@@ -1,5 +1,11 @@
-use super::{check::CheckId, checks::*, CheckOutput};
+use super::{
+    check::{CheckId, CheckStatus, FULL_CREDIT},
+    checks::*,
+    external::{ExternalCheckOutput, ReportSection},
+    CheckOutput, ProviderSupport,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Linter report.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -9,9 +15,114 @@ pub struct Report {
     pub best_practices: BestPractices,
     pub security: Security,
     pub legal: Legal,
+
+    #[serde(default)]
+    pub artifacts: Artifacts,
+
+    #[serde(default)]
+    pub organization: Organization,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub external: BTreeMap<String, ExternalCheckOutput>,
+
+    /// Checks limited by the support available on the provider hosting the
+    /// repository. Unsupported checks are not run, so they are not taken
+    /// into account when calculating the score.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub provider_limited: BTreeMap<String, ProviderSupport>,
+
+    /// Time it took to run each of the checks (in milliseconds).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub durations: BTreeMap<String, u64>,
 }
 
 impl Report {
+    /// Return the external checks outputs that belong to the section provided.
+    pub(crate) fn external_checks(&self, section: ReportSection) -> Vec<&ExternalCheckOutput> {
+        self.external
+            .values()
+            .filter(|check| check.section == section)
+            .collect()
+    }
+
+    /// Return the checks that passed in any of the report's sections
+    /// (ignoring the exempt ones), along with the url of their output.
+    pub fn passed(&self) -> Vec<(CheckId, Option<&str>)> {
+        [
+            self.documentation.passed(),
+            self.license.passed(),
+            self.best_practices.passed(),
+            self.security.passed(),
+            self.legal.passed(),
+            self.artifacts.passed(),
+            self.organization.passed(),
+        ]
+        .concat()
+    }
+
+    /// Return the id, status and output url of each of the checks available
+    /// in the report's sections.
+    pub fn checks(&self) -> Vec<(CheckId, CheckStatus, Option<&str>)> {
+        [
+            self.documentation.checks(),
+            self.license.checks(),
+            self.best_practices.checks(),
+            self.security.checks(),
+            self.legal.checks(),
+            self.artifacts.checks(),
+            self.organization.checks(),
+        ]
+        .concat()
+    }
+
+    /// Return the checks that could not be run in any of the report's
+    /// sections, along with the reason why they failed (if available).
+    pub fn failed(&self) -> Vec<(CheckId, Option<&str>)> {
+        [
+            self.documentation.failed(),
+            self.license.failed(),
+            self.best_practices.failed(),
+            self.security.failed(),
+            self.legal.failed(),
+            self.artifacts.failed(),
+            self.organization.failed(),
+        ]
+        .concat()
+    }
+
+    /// Return the checks that did not pass in any of the report's sections,
+    /// ignoring the exempt ones, the ones not applicable and those that could
+    /// not be run.
+    pub fn not_passed(&self) -> Vec<CheckId> {
+        [
+            self.documentation.not_passed(),
+            self.license.not_passed(),
+            self.best_practices.not_passed(),
+            self.security.not_passed(),
+            self.legal.not_passed(),
+            self.artifacts.not_passed(),
+            self.organization.not_passed(),
+        ]
+        .concat()
+    }
+
+    /// Mark the checks provided as passed in any of the report's sections,
+    /// awarding them their full weight. Returns the checks updated (checks
+    /// not available in the report, exempt or already passing with full credit
+    /// are left untouched).
+    pub fn set_passed(&mut self, check_ids: &[&str]) -> Vec<CheckId> {
+        [
+            self.documentation.set_passed(check_ids),
+            self.license.set_passed(check_ids),
+            self.best_practices.set_passed(check_ids),
+            self.security.set_passed(check_ids),
+            self.legal.set_passed(check_ids),
+            self.artifacts.set_passed(check_ids),
+            self.organization.set_passed(check_ids),
+        ]
+        .concat()
+    }
+
     /// Apply inter-checks exemptions.
     pub(crate) fn apply_exemptions(&mut self) {
         let passed = |o: Option<&CheckOutput>| -> bool {
@@ -54,12 +165,14 @@ impl Report {
 /// Documentation section of the report.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Documentation {
+    pub accessibility_statement: Option<CheckOutput>,
     pub adopters: Option<CheckOutput>,
     pub changelog: Option<CheckOutput>,
     pub code_of_conduct: Option<CheckOutput>,
     pub contributing: Option<CheckOutput>,
     pub governance: Option<CheckOutput>,
     pub maintainers: Option<CheckOutput>,
+    pub maintainers_activity: Option<CheckOutput>,
     pub readme: Option<CheckOutput>,
     pub roadmap: Option<CheckOutput>,
     pub website: Option<CheckOutput>,
@@ -68,12 +181,14 @@ pub struct Documentation {
 #[rustfmt::skip]
 section_impl!(
     Documentation,
+    accessibility_statement,
     adopters,
     changelog,
     code_of_conduct,
     contributing,
     governance,
     maintainers,
+    maintainers_activity,
     readme,
     roadmap,
     website
@@ -83,6 +198,7 @@ section_impl!(
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct License {
     pub license_approved: Option<CheckOutput>,
+    pub license_compatibility: Option<CheckOutput>,
     pub license_scanning: Option<CheckOutput>,
     pub license_spdx_id: Option<CheckOutput<String>>,
 }
@@ -91,6 +207,7 @@ pub struct License {
 section_impl!(
     License,
     license_approved,
+    license_compatibility,
     license_scanning,
     license_spdx_id
 );
@@ -103,9 +220,11 @@ pub struct BestPractices {
     pub cla: Option<CheckOutput>,
     pub community_meeting: Option<CheckOutput>,
     pub dco: Option<CheckOutput>,
+    pub dependency_update_automation: Option<CheckOutput>,
     pub github_discussions: Option<CheckOutput>,
     pub openssf_badge: Option<CheckOutput>,
     pub recent_release: Option<CheckOutput>,
+    pub release_notes: Option<CheckOutput>,
     pub slack_presence: Option<CheckOutput>,
 }
 
@@ -117,9 +236,11 @@ section_impl!(
     cla,
     community_meeting,
     dco,
+    dependency_update_automation,
     github_discussions,
     openssf_badge,
     recent_release,
+    release_notes,
     slack_presence
 );
 
@@ -127,42 +248,90 @@ section_impl!(
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Security {
     pub binary_artifacts: Option<CheckOutput>,
+    pub branch_protection: Option<CheckOutput>,
     pub code_review: Option<CheckOutput>,
     pub dangerous_workflow: Option<CheckOutput>,
     pub dependency_update_tool: Option<CheckOutput>,
+    pub fuzzing: Option<CheckOutput>,
     pub maintained: Option<CheckOutput>,
     pub sbom: Option<CheckOutput>,
+    pub security_insights: Option<CheckOutput>,
     pub security_policy: Option<CheckOutput>,
     pub signed_releases: Option<CheckOutput>,
+    pub slsa_provenance: Option<CheckOutput>,
+    pub supported_versions: Option<CheckOutput>,
     pub token_permissions: Option<CheckOutput>,
+    pub vulnerability_disclosure: Option<CheckOutput>,
 }
 
 #[rustfmt::skip]
 section_impl!(
     Security,
     binary_artifacts,
+    branch_protection,
     code_review,
     dangerous_workflow,
     dependency_update_tool,
+    fuzzing,
     maintained,
     sbom,
+    security_insights,
     security_policy,
     signed_releases,
-    token_permissions
+    slsa_provenance,
+    supported_versions,
+    token_permissions,
+    vulnerability_disclosure
 );
 
 /// Legal section of the report.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Legal {
+    pub dco_or_cla: Option<CheckOutput>,
+    pub privacy_policy: Option<CheckOutput>,
     pub trademark_disclaimer: Option<CheckOutput>,
 }
 
 #[rustfmt::skip]
 section_impl!(
     Legal,
+    dco_or_cla,
+    privacy_policy,
     trademark_disclaimer
 );
 
+/// Artifacts section of the report.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Artifacts {
+    pub image_recent_rebuild: Option<CheckOutput>,
+    pub image_sbom: Option<CheckOutput>,
+    pub image_signature: Option<CheckOutput>,
+}
+
+#[rustfmt::skip]
+section_impl!(
+    Artifacts,
+    image_recent_rebuild,
+    image_sbom,
+    image_signature
+);
+
+/// Organization section of the report.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Organization {
+    pub org_default_permissions: Option<CheckOutput>,
+    pub org_secret_scanning: Option<CheckOutput>,
+    pub org_two_factor_auth: Option<CheckOutput>,
+}
+
+#[rustfmt::skip]
+section_impl!(
+    Organization,
+    org_default_permissions,
+    org_secret_scanning,
+    org_two_factor_auth
+);
+
 /// Prepare the implementation for a section in the report.
 macro_rules! section_impl {
     ( $section:ident, $( $check:ident ),* ) => {
@@ -170,17 +339,96 @@ macro_rules! section_impl {
             pub(crate) fn available(&self) -> Vec<CheckId> {
                 let mut checks = Vec::new();
                 $(
-                if self.$check.as_ref().is_some() {
+                if self.$check.as_ref().map_or(false, |o| !o.not_applicable) {
                     checks.push($check::ID);
                 }
                 )*
                 checks
             }
 
-            pub(crate) fn passed_or_exempt(&self) -> Vec<CheckId> {
+            pub(crate) fn passed_or_exempt(&self) -> Vec<(CheckId, usize)> {
+                let mut checks = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref().filter(|o| o.passed || o.exempt) {
+                    checks.push(($check::ID, o.awarded_credit()));
+                }
+                )*
+                checks
+            }
+
+            /// Return the status and the credit awarded of each of the checks
+            /// available in the section.
+            pub(crate) fn statuses(&self) -> Vec<(CheckId, CheckStatus, usize)> {
+                let mut checks = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref() {
+                    checks.push(($check::ID, o.status(), o.awarded_credit()));
+                }
+                )*
+                checks
+            }
+
+            /// Return the id, status and output url of each of the checks
+            /// available in the section.
+            pub fn checks(&self) -> Vec<(CheckId, CheckStatus, Option<&str>)> {
+                let mut checks = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref() {
+                    checks.push(($check::ID, o.status(), o.url.as_deref()));
+                }
+                )*
+                checks
+            }
+
+            /// Return the checks in the section that passed (ignoring the
+            /// exempt ones), along with the url of their output.
+            pub fn passed(&self) -> Vec<(CheckId, Option<&str>)> {
+                let mut checks = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref().filter(|o| o.passed && !o.exempt) {
+                    checks.push(($check::ID, o.url.as_deref()));
+                }
+                )*
+                checks
+            }
+
+            /// Return the checks in the section that could not be run, along
+            /// with the reason why they failed.
+            pub fn failed(&self) -> Vec<(CheckId, Option<&str>)> {
+                let mut checks = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref().filter(|o| o.failed) {
+                    checks.push(($check::ID, o.fail_reason.as_deref()));
+                }
+                )*
+                checks
+            }
+
+            /// Mark the checks provided available in the section as passed,
+            /// returning the ones updated.
+            pub fn set_passed(&mut self, check_ids: &[&str]) -> Vec<CheckId> {
+                let mut checks = Vec::new();
+                $(
+                if check_ids.contains(&$check::ID) {
+                    if let Some(o) = self.$check.as_mut().filter(|o| o.awarded_credit() < FULL_CREDIT) {
+                        o.passed = true;
+                        o.failed = false;
+                        o.not_applicable = false;
+                        o.credit = None;
+                        checks.push($check::ID);
+                    }
+                }
+                )*
+                checks
+            }
+
+            /// Return the checks in the section that did not pass, ignoring
+            /// the exempt ones, the ones not applicable and those that could
+            /// not be run.
+            pub fn not_passed(&self) -> Vec<CheckId> {
                 let mut checks = Vec::new();
                 $(
-                if self.$check.as_ref().map_or(false, |o| o.passed || o.exempt) {
+                if self.$check.as_ref().map_or(false, |o| !o.passed && !o.exempt && !o.failed && !o.not_applicable) {
                     checks.push($check::ID);
                 }
                 )*
@@ -302,4 +550,145 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn security_not_passed_checks() {
+        let security = Security {
+            binary_artifacts: Some(CheckOutput::passed()),
+            code_review: Some(CheckOutput::not_passed()),
+            maintained: Some(CheckOutput::exempt()),
+            sbom: Some(CheckOutput::failed()),
+            token_permissions: Some(CheckOutput::not_passed()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            security.not_passed(),
+            vec![code_review::ID, token_permissions::ID]
+        );
+    }
+
+    #[test]
+    fn report_passed_and_not_passed_checks() {
+        let report = Report {
+            documentation: Documentation {
+                readme: Some(
+                    CheckOutput::passed().url(Some("https://github.com/o/r/README.md".to_string())),
+                ),
+                roadmap: Some(CheckOutput::exempt()),
+                ..Default::default()
+            },
+            security: Security {
+                code_review: Some(CheckOutput::not_passed()),
+                sbom: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            legal: Legal {
+                dco_or_cla: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            report.passed(),
+            vec![
+                (readme::ID, Some("https://github.com/o/r/README.md")),
+                (sbom::ID, None)
+            ]
+        );
+        assert_eq!(report.not_passed(), vec![code_review::ID, dco_or_cla::ID]);
+        assert_eq!(
+            report.checks(),
+            vec![
+                (
+                    readme::ID,
+                    CheckStatus::Passed,
+                    Some("https://github.com/o/r/README.md")
+                ),
+                (roadmap::ID, CheckStatus::Exempt, None),
+                (code_review::ID, CheckStatus::NotPassed, None),
+                (sbom::ID, CheckStatus::Passed, None),
+                (dco_or_cla::ID, CheckStatus::NotPassed, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_failed_checks() {
+        let report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                openssf_badge: Some(
+                    CheckOutput::failed().fail_reason(Some("rate limit exceeded".to_string())),
+                ),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::failed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            report.failed(),
+            vec![
+                (openssf_badge::ID, Some("rate limit exceeded")),
+                (sbom::ID, None),
+            ]
+        );
+        assert!(report.not_passed().is_empty());
+    }
+
+    #[test]
+    fn report_not_applicable_checks() {
+        let report = Report {
+            best_practices: BestPractices {
+                recent_release: Some(CheckOutput::not_passed()),
+                release_notes: Some(CheckOutput::not_applicable()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(report.best_practices.available(), vec![recent_release::ID]);
+        assert_eq!(report.not_passed(), vec![recent_release::ID]);
+        assert_eq!(
+            report.checks(),
+            vec![
+                (recent_release::ID, CheckStatus::NotPassed, None),
+                (release_notes::ID, CheckStatus::NotApplicable, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_set_passed_checks() {
+        let mut report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::not_passed()),
+                adopters: Some(CheckOutput::passed()),
+                roadmap: Some(CheckOutput::passed().credit(Some(50))),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::failed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            report.set_passed(&["adopters", "readme", "roadmap", "sbom", "changelog"]),
+            vec![readme::ID, roadmap::ID, sbom::ID]
+        );
+        assert!(report.not_passed().is_empty());
+        assert!(report.failed().is_empty());
+        assert_eq!(report.documentation.roadmap, Some(CheckOutput::passed()));
+        assert_eq!(report.documentation.changelog, None);
+    }
 }
@@ -0,0 +1,152 @@
+use super::{
+    check::CheckId,
+    checks::{util::github, CHECKS},
+    CheckSet,
+};
+use anyhow::Result;
+use std::{collections::BTreeMap, fmt};
+
+/// GitHub token scopes that grant the scopes listed as well.
+const PARENT_SCOPES: &[(&str, &[&str])] = &[
+    (
+        "repo",
+        &[
+            "public_repo",
+            "repo:invite",
+            "repo:status",
+            "repo_deployment",
+            "security_events",
+        ],
+    ),
+    ("admin:org", &["read:org", "write:org"]),
+    ("write:org", &["read:org"]),
+    ("user", &["read:user", "user:email", "user:follow"]),
+];
+
+/// Result of validating the scopes granted to a GitHub token against the ones
+/// required by the checks enabled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GithubTokenScopesReport {
+    /// Scopes required by each check that have not been granted to the token.
+    pub missing: BTreeMap<CheckId, Vec<&'static str>>,
+
+    /// Scopes granted to the token that none of the checks enabled require.
+    pub unneeded: Vec<String>,
+}
+
+impl GithubTokenScopesReport {
+    /// Create a new report from the scopes granted to the token, considering
+    /// the checks included in the check sets provided.
+    pub fn new(granted: &[String], check_sets: &[CheckSet]) -> Self {
+        let is_granted = |scope: &str| {
+            granted.iter().any(|g| {
+                g.as_str() == scope
+                    || PARENT_SCOPES.iter().any(|(parent, children)| {
+                        g.as_str() == *parent && children.contains(&scope)
+                    })
+            })
+        };
+
+        // Collect scopes required by the checks enabled that are missing
+        let mut missing = BTreeMap::new();
+        let mut required = vec![];
+        for (check_id, config) in CHECKS.iter() {
+            if !config.check_sets.iter().any(|cs| check_sets.contains(cs)) {
+                continue;
+            }
+            required.extend(config.github_scopes.iter().copied());
+            let check_missing: Vec<&'static str> = config
+                .github_scopes
+                .iter()
+                .filter(|scope| !is_granted(scope))
+                .copied()
+                .collect();
+            if !check_missing.is_empty() {
+                missing.insert(*check_id, check_missing);
+            }
+        }
+
+        // Collect scopes granted that are not required
+        let unneeded = granted
+            .iter()
+            .filter(|scope| !required.contains(&scope.as_str()))
+            .cloned()
+            .collect();
+
+        Self { missing, unneeded }
+    }
+
+    /// Check if the token has all the scopes required.
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl fmt::Display for GithubTokenScopesReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (check_id, scopes) in &self.missing {
+            writeln!(f, "check {check_id} requires: {}", scopes.join(", "))?;
+        }
+        if !self.unneeded.is_empty() {
+            writeln!(f, "scopes not needed: {}", self.unneeded.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Validate the scopes granted to the GitHub token provided against the ones
+/// required by the checks in the check sets provided. None is returned when
+/// the token does not report its scopes (i.e. fine-grained tokens).
+pub async fn validate_github_token_scopes(
+    token: &str,
+    check_sets: &[CheckSet],
+) -> Result<Option<GithubTokenScopesReport>> {
+    let report = github::token_scopes(token)
+        .await?
+        .map(|granted| GithubTokenScopesReport::new(&granted, check_sets));
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_scopes_granted() {
+        let report = GithubTokenScopesReport::new(&["public_repo".to_string()], &[CheckSet::Code]);
+
+        assert!(report.is_valid());
+        assert!(report.missing.is_empty());
+        assert!(report.unneeded.is_empty());
+    }
+
+    #[test]
+    fn report_scopes_granted_by_parent_scope() {
+        let report = GithubTokenScopesReport::new(&["repo".to_string()], &[CheckSet::Code]);
+
+        assert!(report.is_valid());
+        assert_eq!(report.unneeded, vec!["repo".to_string()]);
+    }
+
+    #[test]
+    fn report_scopes_missing() {
+        let report = GithubTokenScopesReport::new(&["gist".to_string()], &[CheckSet::Code]);
+
+        assert!(!report.is_valid());
+        assert_eq!(report.missing["binary_artifacts"], vec!["public_repo"]);
+        assert_eq!(report.missing["token_permissions"], vec!["public_repo"]);
+        assert_eq!(report.unneeded, vec!["gist".to_string()]);
+        assert!(report
+            .to_string()
+            .contains("check binary_artifacts requires: public_repo\n"));
+    }
+
+    #[test]
+    fn report_no_scopes_required() {
+        let report =
+            GithubTokenScopesReport::new(&["public_repo".to_string()], &[CheckSet::Community]);
+
+        assert!(report.is_valid());
+        assert_eq!(report.unneeded, vec!["public_repo".to_string()]);
+    }
+}
@@ -0,0 +1,376 @@
+use anyhow::{format_err, Context, Result};
+use lazy_static::lazy_static;
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect, ClientBuilder, Proxy, RequestBuilder, Url,
+};
+use serde::Deserialize;
+use std::{
+    error::Error as StdError,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::{Arc, RwLock},
+};
+
+/// Maximum number of redirects followed by the clients using a host policy.
+const MAX_REDIRECTS: usize = 10;
+
+lazy_static! {
+    /// Outbound HTTP proxy configured for this process (if any).
+    static ref PROXY: RwLock<Option<ProxyConfig>> = RwLock::new(None);
+}
+
+/// Outbound HTTP proxy configuration.
+#[derive(Debug, Clone)]
+struct ProxyConfig {
+    url: Url,
+    no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    /// Check if the proxy should be used to reach the url provided.
+    fn applies_to(&self, url: &Url) -> bool {
+        let host = url.host_str().unwrap_or_default().to_lowercase();
+        !self
+            .no_proxy
+            .iter()
+            .any(|pattern| pattern == "*" || host_matches(&host, pattern))
+    }
+}
+
+/// Setup the outbound HTTP proxy provided for this process. The proxy is used
+/// by all the HTTP clients built using `client_builder`, except to reach the
+/// hosts (subdomains included) in the comma separated no proxy list. It must
+/// be called on startup, before any of the clients has been built.
+pub fn setup_proxy(proxy: Option<&str>, no_proxy: Option<&str>) -> Result<()> {
+    let Some(proxy) = proxy.filter(|proxy| !proxy.is_empty()) else {
+        return Ok(());
+    };
+    let url = Url::parse(proxy).context("invalid proxy url")?;
+    let no_proxy = no_proxy
+        .unwrap_or_default()
+        .split(',')
+        .map(|host| host.trim().to_lowercase())
+        .filter(|host| !host.is_empty())
+        .collect();
+    *PROXY.write().expect("proxy lock not poisoned") = Some(ProxyConfig { url, no_proxy });
+    Ok(())
+}
+
+/// Return the outbound HTTP proxy that should be used to reach the url
+/// provided, if any. This is used to pass the proxy to the external tools
+/// run, like git.
+pub fn proxy_for(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let proxy = PROXY.read().expect("proxy lock not poisoned").clone()?;
+    proxy.applies_to(&url).then(|| proxy.url.to_string())
+}
+
+/// Return a new HTTP client builder, setup to use the outbound proxy
+/// configured (if any). When no proxy has been configured, the standard proxy
+/// environment variables are honored.
+pub fn client_builder() -> ClientBuilder {
+    let builder = reqwest::Client::builder();
+    match PROXY.read().expect("proxy lock not poisoned").clone() {
+        Some(proxy) => builder.proxy(Proxy::custom(move |url| {
+            proxy.applies_to(url).then(|| proxy.url.clone())
+        })),
+        None => builder,
+    }
+}
+
+/// Policy that defines the hosts that can be reached when fetching urls
+/// provided by users (i.e. foundations' data files or projects' logos), to
+/// prevent server side request forgery.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostPolicy {
+    /// Hosts allowed (subdomains included). When not empty, only these hosts
+    /// can be reached.
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+
+    /// Hosts denied (subdomains included).
+    #[serde(default)]
+    pub denied_hosts: Vec<String>,
+
+    /// Whether hosts resolving to loopback, private or link-local addresses
+    /// should be denied.
+    #[serde(default)]
+    pub deny_private_networks: bool,
+}
+
+impl HostPolicy {
+    /// Check if the url provided can be fetched according to this policy. The
+    /// addresses hosts resolve to are not verified here, but when connecting
+    /// to them using a `RestrictedClient`.
+    pub fn check(&self, url: &str) -> Result<()> {
+        let url = Url::parse(url).context("invalid url")?;
+        self.check_url(&url)
+    }
+
+    /// Return a redirect policy that only follows the redirects allowed by
+    /// this policy.
+    fn redirect_policy(&self) -> redirect::Policy {
+        let policy = self.clone();
+        redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if let Err(err) = policy.check_url(attempt.url()) {
+                attempt.error(err.to_string())
+            } else {
+                attempt.follow()
+            }
+        })
+    }
+
+    /// Check the url provided against the hosts lists of this policy, as well
+    /// as its host when it is an ip address.
+    fn check_url(&self, url: &Url) -> Result<()> {
+        if !["http", "https"].contains(&url.scheme()) {
+            return Err(format_err!("scheme {} not allowed", url.scheme()));
+        }
+        let host = url
+            .host_str()
+            .ok_or_else(|| format_err!("url has no host"))?
+            .to_lowercase();
+        if self.denied_hosts.iter().any(|h| host_matches(&host, h)) {
+            return Err(format_err!("host {host} is denied"));
+        }
+        if !self.allowed_hosts.is_empty()
+            && !self.allowed_hosts.iter().any(|h| host_matches(&host, h))
+        {
+            return Err(format_err!("host {host} is not allowed"));
+        }
+        if self.deny_private_networks {
+            if let Ok(ip) = host.trim_matches(['[', ']']).parse::<IpAddr>() {
+                if is_private(ip) {
+                    return Err(format_err!("host {host} is a private address"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// HTTP client used to fetch urls provided by users, which only reaches the
+/// hosts allowed by the host policy provided. The policy is checked for the
+/// urls requested as well as for all the redirects followed, and the addresses
+/// hosts resolve to are verified when connecting to them, so that they cannot
+/// be pointed to private networks once they have been checked. Requests sent
+/// through the outbound proxy are resolved by the proxy, which is expected to
+/// restrict them as well.
+#[derive(Debug, Clone)]
+pub struct RestrictedClient {
+    http_client: reqwest::Client,
+    host_policy: Arc<HostPolicy>,
+}
+
+impl RestrictedClient {
+    /// Create a new RestrictedClient instance.
+    pub fn new(host_policy: HostPolicy) -> Result<Self> {
+        let proxy_host = PROXY
+            .read()
+            .expect("proxy lock not poisoned")
+            .as_ref()
+            .and_then(|proxy| proxy.url.host_str().map(str::to_lowercase));
+        let resolver = PolicyResolver {
+            deny_private_networks: host_policy.deny_private_networks,
+            proxy_host,
+        };
+        let http_client = client_builder()
+            .redirect(host_policy.redirect_policy())
+            .dns_resolver(Arc::new(resolver))
+            .build()?;
+        Ok(Self {
+            http_client,
+            host_policy: Arc::new(host_policy),
+        })
+    }
+
+    /// Prepare a GET request to the url provided, as long as it is allowed by
+    /// the host policy.
+    pub fn get(&self, url: &str) -> Result<RequestBuilder> {
+        self.host_policy.check(url)?;
+        Ok(self.http_client.get(url))
+    }
+}
+
+impl Default for RestrictedClient {
+    fn default() -> Self {
+        Self::new(HostPolicy::default()).expect("restricted client to be built")
+    }
+}
+
+/// DNS resolver used by the restricted clients. When private networks are
+/// denied, hosts resolving to any private address are rejected. The proxy's
+/// host is not verified, as all requests are sent through it.
+struct PolicyResolver {
+    deny_private_networks: bool,
+    proxy_host: Option<String>,
+}
+
+impl Resolve for PolicyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_lowercase();
+        let verify = self.deny_private_networks && self.proxy_host.as_ref() != Some(&host);
+        Box::pin(resolve(host, verify))
+    }
+}
+
+/// Resolve the host provided. When requested, the addresses it resolves to
+/// are verified to not belong to private networks.
+async fn resolve(host: String, verify: bool) -> Result<Addrs, Box<dyn StdError + Send + Sync>> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+    if verify && addrs.iter().any(|addr| is_private(addr.ip())) {
+        return Err(format!("host {host} resolves to a private address").into());
+    }
+    Ok(Box::new(addrs.into_iter()))
+}
+
+/// Check if the host provided matches the pattern given (the pattern's host
+/// itself or any of its subdomains).
+fn host_matches(host: &str, pattern: &str) -> bool {
+    let pattern = pattern
+        .trim()
+        .trim_start_matches("*.")
+        .trim_start_matches('.')
+        .to_lowercase();
+    !pattern.is_empty() && (host == pattern || host.ends_with(&format!(".{pattern}")))
+}
+
+/// Check if the ip address provided belongs to a loopback, private, link-local
+/// or otherwise non publicly routable network.
+fn is_private(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_private_v4(ip),
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_private_v4(ip);
+            }
+            let first_segment = ip.segments()[0];
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || first_segment & 0xfe00 == 0xfc00 // Unique local (fc00::/7)
+                || first_segment & 0xffc0 == 0xfe80 // Link-local (fe80::/10)
+        }
+    }
+}
+
+/// Check if the IPv4 address provided is not publicly routable.
+fn is_private_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || (a == 100 && (64..128).contains(&b)) // Shared address space (100.64.0.0/10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn check_url_hosts_lists() {
+        let policy = HostPolicy {
+            allowed_hosts: vec!["githubusercontent.com".to_string()],
+            denied_hosts: vec!["gist.githubusercontent.com".to_string()],
+            ..HostPolicy::default()
+        };
+        let url = |url: &str| Url::parse(url).unwrap();
+        assert!(policy
+            .check_url(&url(
+                "https://raw.githubusercontent.com/cncf/foundation/data.yaml"
+            ))
+            .is_ok());
+        assert!(policy
+            .check_url(&url("https://gist.githubusercontent.com/user/data.yaml"))
+            .is_err());
+        assert!(policy
+            .check_url(&url("https://example.com/data.yaml"))
+            .is_err());
+        assert!(policy.check_url(&url("file:///etc/passwd")).is_err());
+    }
+
+    #[test]
+    fn proxy_no_proxy_hosts() {
+        let proxy = ProxyConfig {
+            url: Url::parse("http://proxy.example.org:3128").unwrap(),
+            no_proxy: vec!["localhost".to_string(), ".svc.cluster.local".to_string()],
+        };
+        let url = |url: &str| Url::parse(url).unwrap();
+        assert!(proxy.applies_to(&url("https://github.com/cncf/clomonitor")));
+        assert!(!proxy.applies_to(&url("http://localhost:8000")));
+        assert!(!proxy.applies_to(&url("http://redis.default.svc.cluster.local")));
+    }
+
+    #[test]
+    fn check_private_networks() {
+        let policy = HostPolicy {
+            deny_private_networks: true,
+            ..HostPolicy::default()
+        };
+        assert!(policy.check("http://127.0.0.1:8080/data.yaml").is_err());
+        assert!(policy.check("http://169.254.169.254/latest").is_err());
+        assert!(policy.check("http://[::1]/data.yaml").is_err());
+        assert!(policy.check("http://10.0.0.1/data.yaml").is_err());
+        assert!(HostPolicy::default()
+            .check("http://127.0.0.1:8080/data.yaml")
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn restricted_client_hosts_resolving_to_private_networks() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let url = format!("http://localhost:{}/data.yaml", server.address().port());
+
+        let http_client = RestrictedClient::new(HostPolicy {
+            deny_private_networks: true,
+            ..HostPolicy::default()
+        })
+        .unwrap();
+        assert!(http_client.get(&url).unwrap().send().await.is_err());
+        let http_client = RestrictedClient::default();
+        assert!(http_client.get(&url).unwrap().send().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn restricted_client_redirects_to_denied_hosts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/data.yaml"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("location", "http://example.com/data.yaml"),
+            )
+            .mount(&server)
+            .await;
+
+        let http_client = RestrictedClient::new(HostPolicy {
+            denied_hosts: vec!["example.com".to_string()],
+            ..HostPolicy::default()
+        })
+        .unwrap();
+        let url = format!("{}/data.yaml", server.uri());
+        assert!(http_client.get(&url).unwrap().send().await.is_err());
+    }
+
+    #[test]
+    fn is_private_addresses() {
+        assert!(is_private("192.168.1.1".parse().unwrap()));
+        assert!(is_private("100.64.0.1".parse().unwrap()));
+        assert!(is_private("fd00::1".parse().unwrap()));
+        assert!(is_private("::ffff:127.0.0.1".parse().unwrap()));
+        assert!(!is_private("140.82.112.3".parse().unwrap()));
+        assert!(!is_private("2606:4700::1111".parse().unwrap()));
+    }
+}
@@ -1,8 +1,23 @@
 use crate::linter::*;
+use anyhow::{format_err, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+mod simulation;
+mod trace;
+
+pub use simulation::{simulate, ScoreSimulation};
+pub use trace::{
+    trace, AggregationTrace, CheckTrace, RepositoryContribution, RepositoryScoreTrace, ScoreTrace,
+    SectionTrace,
+};
+
+/// Minimum global score required to get each of the ratings.
+pub const RATING_THRESHOLDS: [(char, usize); 4] = [('a', 75), ('b', 50), ('c', 25), ('d', 0)];
 
 /// Score information.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Score {
     pub global: f64,
     pub global_weight: usize,
@@ -36,6 +51,18 @@ pub struct Score {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub legal_weight: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifacts: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifacts_weight: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_weight: Option<usize>,
 }
 
 impl Score {
@@ -48,31 +75,231 @@ impl Score {
     pub fn rating(&self) -> char {
         rating(self.global())
     }
+
+    /// Return the score of the section provided (if available).
+    pub fn section(&self, section: ReportSection) -> Option<f64> {
+        match section {
+            ReportSection::Documentation => self.documentation,
+            ReportSection::License => self.license,
+            ReportSection::BestPractices => self.best_practices,
+            ReportSection::Security => self.security,
+            ReportSection::Legal => self.legal,
+            ReportSection::Artifacts => self.artifacts,
+            ReportSection::Organization => self.organization,
+        }
+    }
+}
+
+/// Weights overrides that can be applied when calculating a score (i.e.
+/// defined by a foundation). Checks and sections not included will use their
+/// default weights.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Weights {
+    #[serde(default)]
+    pub checks: BTreeMap<String, usize>,
+
+    #[serde(default)]
+    pub sections: BTreeMap<ReportSection, usize>,
+
+    /// Checks severities overrides (checks not included will use their
+    /// default severity).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severities: BTreeMap<String, Severity>,
+
+    /// Multiplier applied to the weight of the checks of each severity
+    /// (severities not included use a multiplier of 1).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity_multipliers: BTreeMap<Severity, usize>,
+}
+
+/// Default and effective weights of a check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CheckWeight {
+    pub default: usize,
+    pub effective: usize,
+}
+
+impl Weights {
+    /// Return the effective weight of the check provided, after applying
+    /// the multiplier of its severity.
+    pub fn check(&self, check_id: &str) -> usize {
+        let weight = match self.checks.get(check_id) {
+            Some(weight) => *weight,
+            None => CHECKS.get(check_id).map_or(0, |check| check.weight),
+        };
+        let multiplier = self
+            .severity_multipliers
+            .get(&self.severity(check_id))
+            .copied()
+            .unwrap_or(1);
+        weight * multiplier
+    }
+
+    /// Return the effective severity of the check provided. Unknown checks
+    /// are considered recommended.
+    pub fn severity(&self, check_id: &str) -> Severity {
+        match self.severities.get(check_id) {
+            Some(severity) => *severity,
+            None => Severity::of(check_id).unwrap_or(Severity::Recommended),
+        }
+    }
+
+    /// Return the effective severity of all the checks available.
+    pub fn checks_severities(&self) -> BTreeMap<String, Severity> {
+        CHECKS
+            .keys()
+            .map(|check_id| (check_id.to_string(), self.severity(check_id)))
+            .collect()
+    }
+
+    /// Group the checks provided by their effective severity (most severe
+    /// first). Severities without checks are not included.
+    pub fn group_by_severity<'a>(&self, check_ids: &[&'a str]) -> Vec<(Severity, Vec<&'a str>)> {
+        let mut groups: BTreeMap<Severity, Vec<&'a str>> = BTreeMap::new();
+        for check_id in check_ids {
+            groups
+                .entry(self.severity(check_id))
+                .or_default()
+                .push(*check_id);
+        }
+        groups.into_iter().collect()
+    }
+
+    /// Return the default and effective weights of all the checks available.
+    pub fn checks_weights(&self) -> BTreeMap<String, CheckWeight> {
+        CHECKS
+            .iter()
+            .map(|(check_id, check)| {
+                (
+                    check_id.to_string(),
+                    CheckWeight {
+                        default: check.weight,
+                        effective: self.check(check_id),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Check that all checks overridden exist.
+    pub fn validate(&self) -> Result<()> {
+        let unknown: Vec<&str> = self
+            .checks
+            .keys()
+            .chain(self.severities.keys())
+            .filter(|check_id| !CHECKS.contains_key(check_id.as_str()))
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            return Err(format_err!("unknown checks: {}", unknown.join(", ")));
+        }
+        Ok(())
+    }
+
+    /// Apply the section weight override (if any) to the section score and
+    /// weight provided.
+    fn apply_section(
+        &self,
+        section: ReportSection,
+        (score, weight): (Option<f64>, Option<usize>),
+    ) -> (Option<f64>, Option<usize>) {
+        match (score, self.sections.get(&section)) {
+            (Some(_), Some(section_weight)) => (score, Some(*section_weight)),
+            _ => (score, weight),
+        }
+    }
 }
 
 /// Calculate score for the given linter report.
 pub fn calculate(report: &Report) -> Score {
+    calculate_with_weights(report, &Weights::default())
+}
+
+/// Calculate score for the given linter report applying the weights
+/// overrides provided.
+pub fn calculate_with_weights(report: &Report, weights: &Weights) -> Score {
     let mut score = Score::default();
 
     // Sections
-    (score.documentation, score.documentation_weight) = calculate_section(
-        &report.documentation.available(),
-        &report.documentation.passed_or_exempt(),
+    (score.documentation, score.documentation_weight) = weights.apply_section(
+        ReportSection::Documentation,
+        add_external_checks(
+            calculate_section(
+                &report.documentation.available(),
+                &report.documentation.passed_or_exempt(),
+                weights,
+            ),
+            &report.external_checks(ReportSection::Documentation),
+        ),
+    );
+    (score.license, score.license_weight) = weights.apply_section(
+        ReportSection::License,
+        add_external_checks(
+            calculate_section(
+                &report.license.available(),
+                &report.license.passed_or_exempt(),
+                weights,
+            ),
+            &report.external_checks(ReportSection::License),
+        ),
+    );
+    (score.best_practices, score.best_practices_weight) = weights.apply_section(
+        ReportSection::BestPractices,
+        add_external_checks(
+            calculate_section(
+                &report.best_practices.available(),
+                &report.best_practices.passed_or_exempt(),
+                weights,
+            ),
+            &report.external_checks(ReportSection::BestPractices),
+        ),
     );
-    (score.license, score.license_weight) = calculate_section(
-        &report.license.available(),
-        &report.license.passed_or_exempt(),
+    (score.security, score.security_weight) = weights.apply_section(
+        ReportSection::Security,
+        add_external_checks(
+            calculate_section(
+                &report.security.available(),
+                &report.security.passed_or_exempt(),
+                weights,
+            ),
+            &report.external_checks(ReportSection::Security),
+        ),
     );
-    (score.best_practices, score.best_practices_weight) = calculate_section(
-        &report.best_practices.available(),
-        &report.best_practices.passed_or_exempt(),
+    (score.legal, score.legal_weight) = weights.apply_section(
+        ReportSection::Legal,
+        add_external_checks(
+            calculate_section(
+                &report.legal.available(),
+                &report.legal.passed_or_exempt(),
+                weights,
+            ),
+            &report.external_checks(ReportSection::Legal),
+        ),
     );
-    (score.security, score.security_weight) = calculate_section(
-        &report.security.available(),
-        &report.security.passed_or_exempt(),
+    (score.artifacts, score.artifacts_weight) = weights.apply_section(
+        ReportSection::Artifacts,
+        add_external_checks(
+            calculate_section(
+                &report.artifacts.available(),
+                &report.artifacts.passed_or_exempt(),
+                weights,
+            ),
+            &report.external_checks(ReportSection::Artifacts),
+        ),
+    );
+    (score.organization, score.organization_weight) = weights.apply_section(
+        ReportSection::Organization,
+        add_external_checks(
+            calculate_section(
+                &report.organization.available(),
+                &report.organization.passed_or_exempt(),
+                weights,
+            ),
+            &report.external_checks(ReportSection::Organization),
+        ),
     );
-    (score.legal, score.legal_weight) =
-        calculate_section(&report.legal.available(), &report.legal.passed_or_exempt());
 
     // Global
     let sections_scores = &[
@@ -81,6 +308,8 @@ pub fn calculate(report: &Report) -> Score {
         score.best_practices,
         score.security,
         score.legal,
+        score.artifacts,
+        score.organization,
     ];
     let sections_weights = &[
         score.documentation_weight,
@@ -88,10 +317,15 @@ pub fn calculate(report: &Report) -> Score {
         score.best_practices_weight,
         score.security_weight,
         score.legal_weight,
+        score.artifacts_weight,
+        score.organization_weight,
     ];
     score.global_weight = sections_weights
         .iter()
         .fold(0, |gw, sw| gw + sw.unwrap_or_default());
+    if score.global_weight == 0 {
+        return score;
+    }
     score.global = sections_scores
         .iter()
         .zip(sections_weights.iter())
@@ -103,44 +337,90 @@ pub fn calculate(report: &Report) -> Score {
     score
 }
 
-/// Calculate score and weight for a report's section from the checks provided.
+/// Calculate score and weight for a report's section from the checks provided
+/// (checks passed or exempt are provided along with the credit awarded).
 fn calculate_section(
     checks_available: &[CheckId],
-    checks_passed_or_exempt: &[CheckId],
+    checks_passed_or_exempt: &[(CheckId, usize)],
+    weights: &Weights,
 ) -> (Option<f64>, Option<usize>) {
     // Calculate section weight
     let weight = checks_available
         .iter()
-        .fold(0, |weight, check_id| weight + CHECKS[check_id].weight);
+        .fold(0, |weight, check_id| weight + weights.check(check_id));
     if weight == 0 {
         return (None, None);
     }
 
     // Calculate section score
-    let score = checks_passed_or_exempt.iter().fold(0.0, |score, check_id| {
-        score + CHECKS[check_id].weight as f64 / weight as f64 * 100.0
-    });
+    let score = checks_passed_or_exempt
+        .iter()
+        .fold(0.0, |score, (check_id, credit)| {
+            let k = *credit as f64 / 100.0;
+            score + weights.check(check_id) as f64 * k / weight as f64 * 100.0
+        });
 
     (Some(score), Some(weight))
 }
 
+/// Add the external checks provided to the section score and weight given,
+/// using the weights configured for them.
+fn add_external_checks(
+    (score, weight): (Option<f64>, Option<usize>),
+    external_checks: &[&ExternalCheckOutput],
+) -> (Option<f64>, Option<usize>) {
+    let external_weight = external_checks
+        .iter()
+        .fold(0, |weight, check| weight + check.weight);
+    if external_weight == 0 {
+        return (score, weight);
+    }
+
+    // Recalculate section score including the external checks
+    let weight = weight.unwrap_or_default();
+    let total_weight = weight + external_weight;
+    let points = external_checks.iter().fold(
+        score.unwrap_or_default() * weight as f64 / 100.0,
+        |points, check| {
+            let k = check.output.awarded_credit() as f64 / 100.0;
+            points + check.weight as f64 * k
+        },
+    );
+
+    (
+        Some(points / total_weight as f64 * 100.0),
+        Some(total_weight),
+    )
+}
+
 /// Merge the scores provided into a single score.
 pub fn merge(scores: &[Score]) -> Score {
+    let scores: Vec<(Score, f64)> = scores.iter().map(|s| (s.clone(), 1.0)).collect();
+    merge_weighted(&scores)
+}
+
+/// Merge the scores provided into a single score, applying to each of them
+/// the weight it was given (i.e. a repository's weight in its project).
+pub fn merge_weighted(scores: &[(Score, f64)]) -> Score {
     // Sum all scores weights for each of the sections. We'll use them to
     // calculate the coefficient we'll apply to each of the scores.
-    let mut global_weights_sum = 0;
-    let mut documentation_weights_sum = 0;
-    let mut license_weights_sum = 0;
-    let mut best_practices_weights_sum = 0;
-    let mut security_weights_sum = 0;
-    let mut legal_weights_sum = 0;
-    for score in scores {
-        global_weights_sum += score.global_weight;
-        documentation_weights_sum += score.documentation_weight.unwrap_or_default();
-        license_weights_sum += score.license_weight.unwrap_or_default();
-        best_practices_weights_sum += score.best_practices_weight.unwrap_or_default();
-        security_weights_sum += score.security_weight.unwrap_or_default();
-        legal_weights_sum += score.legal_weight.unwrap_or_default();
+    let mut global_weights_sum = 0.0;
+    let mut documentation_weights_sum = 0.0;
+    let mut license_weights_sum = 0.0;
+    let mut best_practices_weights_sum = 0.0;
+    let mut security_weights_sum = 0.0;
+    let mut legal_weights_sum = 0.0;
+    let mut artifacts_weights_sum = 0.0;
+    let mut organization_weights_sum = 0.0;
+    for (score, w) in scores {
+        global_weights_sum += score.global_weight as f64 * w;
+        documentation_weights_sum += score.documentation_weight.unwrap_or_default() as f64 * w;
+        license_weights_sum += score.license_weight.unwrap_or_default() as f64 * w;
+        best_practices_weights_sum += score.best_practices_weight.unwrap_or_default() as f64 * w;
+        security_weights_sum += score.security_weight.unwrap_or_default() as f64 * w;
+        legal_weights_sum += score.legal_weight.unwrap_or_default() as f64 * w;
+        artifacts_weights_sum += score.artifacts_weight.unwrap_or_default() as f64 * w;
+        organization_weights_sum += score.organization_weight.unwrap_or_default() as f64 * w;
     }
 
     // Helper function that merges a score into the merged value provided after
@@ -157,32 +437,42 @@ pub fn merge(scores: &[Score]) -> Score {
 
     // Calculate merged score for each of the sections.
     let mut m = Score::default();
-    for s in scores {
-        m.global += s.global * (s.global_weight as f64 / global_weights_sum as f64);
+    for (s, w) in scores {
+        m.global += s.global * (s.global_weight as f64 * w / global_weights_sum);
         m.documentation = merge(
             m.documentation,
             s.documentation,
-            s.documentation_weight.unwrap_or_default() as f64 / documentation_weights_sum as f64,
+            s.documentation_weight.unwrap_or_default() as f64 * w / documentation_weights_sum,
         );
         m.license = merge(
             m.license,
             s.license,
-            s.license_weight.unwrap_or_default() as f64 / license_weights_sum as f64,
+            s.license_weight.unwrap_or_default() as f64 * w / license_weights_sum,
         );
         m.best_practices = merge(
             m.best_practices,
             s.best_practices,
-            s.best_practices_weight.unwrap_or_default() as f64 / best_practices_weights_sum as f64,
+            s.best_practices_weight.unwrap_or_default() as f64 * w / best_practices_weights_sum,
         );
         m.security = merge(
             m.security,
             s.security,
-            s.security_weight.unwrap_or_default() as f64 / security_weights_sum as f64,
+            s.security_weight.unwrap_or_default() as f64 * w / security_weights_sum,
         );
         m.legal = merge(
             m.legal,
             s.legal,
-            s.legal_weight.unwrap_or_default() as f64 / legal_weights_sum as f64,
+            s.legal_weight.unwrap_or_default() as f64 * w / legal_weights_sum,
+        );
+        m.artifacts = merge(
+            m.artifacts,
+            s.artifacts,
+            s.artifacts_weight.unwrap_or_default() as f64 * w / artifacts_weights_sum,
+        );
+        m.organization = merge(
+            m.organization,
+            s.organization,
+            s.organization_weight.unwrap_or_default() as f64 * w / organization_weights_sum,
         );
     }
 
@@ -191,13 +481,14 @@ pub fn merge(scores: &[Score]) -> Score {
 
 /// Return the score's rating (a, b, c or d).
 pub fn rating(score: f64) -> char {
-    match score as usize {
-        75..=100 => 'a',
-        50..=74 => 'b',
-        25..=49 => 'c',
-        0..=24 => 'd',
-        _ => '?',
+    let score = score as usize;
+    if score > 100 {
+        return '?';
     }
+    RATING_THRESHOLDS
+        .iter()
+        .find(|(_, min)| score >= *min)
+        .map_or('?', |(rating, _)| *rating)
 }
 
 #[cfg(test)]
@@ -216,6 +507,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn score_section() {
+        let score = Score {
+            security: Some(80.0),
+            ..Score::default()
+        };
+        assert_eq!(score.section(ReportSection::Security), Some(80.0));
+        assert_eq!(score.section(ReportSection::Documentation), None);
+    }
+
     #[test]
     fn score_rating() {
         assert_eq!(
@@ -244,18 +545,21 @@ mod tests {
         assert_eq!(
             calculate(&Report {
                 documentation: Documentation {
+                    accessibility_statement: None,
                     adopters: Some(CheckOutput::passed()),
                     code_of_conduct: Some(CheckOutput::passed()),
                     contributing: Some(CheckOutput::passed()),
                     changelog: Some(CheckOutput::passed()),
                     governance: Some(CheckOutput::passed()),
                     maintainers: Some(CheckOutput::passed()),
+                    maintainers_activity: None,
                     readme: Some(CheckOutput::passed()),
                     roadmap: Some(CheckOutput::passed()),
                     website: Some(CheckOutput::passed()),
                 },
                 license: License {
                     license_approved: Some(CheckOutput::passed()),
+                    license_compatibility: None,
                     license_scanning: Some(
                         CheckOutput::passed().url(Some("https://license-scanning.url".to_string()))
                     ),
@@ -269,25 +573,40 @@ mod tests {
                     cla: Some(CheckOutput::passed()),
                     community_meeting: Some(CheckOutput::passed()),
                     dco: Some(CheckOutput::passed()),
+                    dependency_update_automation: None,
                     github_discussions: Some(CheckOutput::passed()),
                     openssf_badge: Some(CheckOutput::passed()),
                     recent_release: Some(CheckOutput::passed()),
+                    release_notes: None,
                     slack_presence: Some(CheckOutput::passed()),
                 },
                 security: Security {
                     binary_artifacts: Some(CheckOutput::passed()),
+                    branch_protection: None,
                     code_review: Some(CheckOutput::passed()),
                     dangerous_workflow: Some(CheckOutput::passed()),
                     dependency_update_tool: Some(CheckOutput::passed()),
+                    fuzzing: None,
                     maintained: Some(CheckOutput::passed()),
                     sbom: Some(CheckOutput::passed()),
+                    security_insights: None,
                     security_policy: Some(CheckOutput::passed()),
                     signed_releases: Some(CheckOutput::passed()),
+                    slsa_provenance: None,
+                    supported_versions: None,
                     token_permissions: Some(CheckOutput::passed()),
+                    vulnerability_disclosure: None,
                 },
                 legal: Legal {
+                    dco_or_cla: None,
+                    privacy_policy: None,
                     trademark_disclaimer: Some(CheckOutput::passed()),
                 },
+                artifacts: Artifacts::default(),
+                organization: Organization::default(),
+                external: BTreeMap::new(),
+                provider_limited: BTreeMap::new(),
+                durations: BTreeMap::new(),
             }),
             Score {
                 global: 99.99999999999999,
@@ -302,6 +621,10 @@ mod tests {
                 security_weight: Some(20),
                 legal: Some(100.0),
                 legal_weight: Some(5),
+                artifacts: None,
+                artifacts_weight: None,
+                organization: None,
+                organization_weight: None,
             }
         );
     }
@@ -311,18 +634,21 @@ mod tests {
         assert_eq!(
             calculate(&Report {
                 documentation: Documentation {
+                    accessibility_statement: None,
                     adopters: Some(CheckOutput::not_passed()),
                     code_of_conduct: Some(CheckOutput::not_passed()),
                     contributing: Some(CheckOutput::not_passed()),
                     changelog: Some(CheckOutput::not_passed()),
                     governance: Some(CheckOutput::not_passed()),
                     maintainers: Some(CheckOutput::not_passed()),
+                    maintainers_activity: None,
                     readme: Some(CheckOutput::not_passed()),
                     roadmap: Some(CheckOutput::not_passed()),
                     website: Some(CheckOutput::not_passed()),
                 },
                 license: License {
                     license_approved: Some(CheckOutput::not_passed()),
+                    license_compatibility: None,
                     license_scanning: Some(CheckOutput::not_passed()),
                     license_spdx_id: Some(CheckOutput::not_passed()),
                 },
@@ -332,25 +658,40 @@ mod tests {
                     cla: Some(CheckOutput::not_passed()),
                     community_meeting: Some(CheckOutput::not_passed()),
                     dco: Some(CheckOutput::not_passed()),
+                    dependency_update_automation: None,
                     github_discussions: Some(CheckOutput::not_passed()),
                     openssf_badge: Some(CheckOutput::not_passed()),
                     recent_release: Some(CheckOutput::not_passed()),
+                    release_notes: None,
                     slack_presence: Some(CheckOutput::not_passed()),
                 },
                 security: Security {
                     binary_artifacts: Some(CheckOutput::not_passed()),
+                    branch_protection: None,
                     code_review: Some(CheckOutput::not_passed()),
                     dangerous_workflow: Some(CheckOutput::not_passed()),
                     dependency_update_tool: Some(CheckOutput::not_passed()),
+                    fuzzing: None,
                     maintained: Some(CheckOutput::not_passed()),
                     sbom: Some(CheckOutput::not_passed()),
+                    security_insights: None,
                     security_policy: Some(CheckOutput::not_passed()),
                     signed_releases: Some(CheckOutput::not_passed()),
+                    slsa_provenance: None,
+                    supported_versions: None,
                     token_permissions: Some(CheckOutput::not_passed()),
+                    vulnerability_disclosure: None,
                 },
                 legal: Legal {
+                    dco_or_cla: None,
+                    privacy_policy: None,
                     trademark_disclaimer: Some(CheckOutput::not_passed()),
                 },
+                artifacts: Artifacts::default(),
+                organization: Organization::default(),
+                external: BTreeMap::new(),
+                provider_limited: BTreeMap::new(),
+                durations: BTreeMap::new(),
             }),
             Score {
                 global: 0.0,
@@ -365,6 +706,10 @@ mod tests {
                 security_weight: Some(20),
                 legal: Some(0.0),
                 legal_weight: Some(5),
+                artifacts: None,
+                artifacts_weight: None,
+                organization: None,
+                organization_weight: None,
             }
         );
     }
@@ -374,18 +719,21 @@ mod tests {
         assert_eq!(
             calculate(&Report {
                 documentation: Documentation {
+                    accessibility_statement: None,
                     adopters: None,
                     code_of_conduct: None,
                     contributing: Some(CheckOutput::passed()),
                     changelog: Some(CheckOutput::passed()),
                     governance: None,
                     maintainers: Some(CheckOutput::passed()),
+                    maintainers_activity: None,
                     readme: Some(CheckOutput::passed()),
                     roadmap: None,
                     website: None,
                 },
                 license: License {
                     license_approved: Some(CheckOutput::passed()),
+                    license_compatibility: None,
                     license_scanning: Some(
                         CheckOutput::passed().url(Some("https://license-scanning.url".to_string()))
                     ),
@@ -399,25 +747,40 @@ mod tests {
                     cla: Some(CheckOutput::passed()),
                     community_meeting: None,
                     dco: Some(CheckOutput::passed()),
+                    dependency_update_automation: None,
                     github_discussions: Some(CheckOutput::passed()),
                     openssf_badge: Some(CheckOutput::passed()),
                     recent_release: Some(CheckOutput::passed()),
+                    release_notes: None,
                     slack_presence: None,
                 },
                 security: Security {
                     binary_artifacts: Some(CheckOutput::passed()),
+                    branch_protection: None,
                     code_review: Some(CheckOutput::passed()),
                     dangerous_workflow: Some(CheckOutput::passed()),
                     dependency_update_tool: Some(CheckOutput::passed()),
+                    fuzzing: None,
                     maintained: Some(CheckOutput::passed()),
                     sbom: Some(CheckOutput::passed()),
+                    security_insights: None,
                     security_policy: Some(CheckOutput::passed()),
                     signed_releases: Some(CheckOutput::passed()),
+                    slsa_provenance: None,
+                    supported_versions: None,
                     token_permissions: Some(CheckOutput::passed()),
+                    vulnerability_disclosure: None,
                 },
                 legal: Legal {
+                    dco_or_cla: None,
+                    privacy_policy: None,
                     trademark_disclaimer: None,
                 },
+                artifacts: Artifacts::default(),
+                organization: Organization::default(),
+                external: BTreeMap::new(),
+                provider_limited: BTreeMap::new(),
+                durations: BTreeMap::new(),
             }),
             Score {
                 global: 100.00000000000001,
@@ -432,10 +795,184 @@ mod tests {
                 security_weight: Some(20),
                 legal: None,
                 legal_weight: None,
+                artifacts: None,
+                artifacts_weight: None,
+                organization: None,
+                organization_weight: None,
             }
         );
     }
 
+    #[test]
+    fn calculate_section_with_partial_credit() {
+        assert_eq!(
+            calculate_section(
+                &["openssf_badge"],
+                &[("openssf_badge", 50)],
+                &Weights::default()
+            ),
+            (Some(50.0), Some(10))
+        );
+        assert_eq!(
+            calculate_section(
+                &["openssf_badge"],
+                &[("openssf_badge", 100)],
+                &Weights::default()
+            ),
+            (Some(100.0), Some(10))
+        );
+    }
+
+    #[test]
+    fn calculate_section_with_checks_weights_overridden() {
+        let weights = Weights {
+            checks: BTreeMap::from([
+                ("openssf_badge".to_string(), 30),
+                ("recent_release".to_string(), 10),
+                ("roadmap".to_string(), 0),
+            ]),
+            ..Weights::default()
+        };
+        assert_eq!(
+            calculate_section(
+                &["openssf_badge", "recent_release"],
+                &[("openssf_badge", 100)],
+                &weights
+            ),
+            (Some(75.0), Some(40))
+        );
+        assert_eq!(
+            calculate_section(&["roadmap"], &[("roadmap", 100)], &weights),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn calculate_report_with_dco_credited_in_best_practices_and_legal() {
+        let report = Report {
+            best_practices: BestPractices {
+                cla: Some(CheckOutput::exempt()),
+                dco: Some(CheckOutput::passed()),
+                ..BestPractices::default()
+            },
+            legal: Legal {
+                dco_or_cla: Some(CheckOutput::passed()),
+                ..Legal::default()
+            },
+            ..Report::default()
+        };
+
+        let score = calculate(&report);
+        assert_eq!(score.best_practices, Some(100.0));
+        assert_eq!(score.best_practices_weight, Some(2));
+        assert_eq!(score.legal, Some(100.0));
+        assert_eq!(score.legal_weight, Some(2));
+        assert_eq!(score.global, 100.0);
+        assert_eq!(score.global_weight, 4);
+
+        // Foundations can avoid the overlap by dropping the best practices checks
+        let weights = Weights {
+            checks: BTreeMap::from([("cla".to_string(), 0), ("dco".to_string(), 0)]),
+            ..Weights::default()
+        };
+        let score = calculate_with_weights(&report, &weights);
+        assert_eq!(score.best_practices, None);
+        assert_eq!(score.best_practices_weight, None);
+        assert_eq!(score.legal, Some(100.0));
+        assert_eq!(score.legal_weight, Some(2));
+        assert_eq!(score.global, 100.0);
+        assert_eq!(score.global_weight, 2);
+    }
+
+    #[test]
+    fn weights_apply_section_override() {
+        let weights = Weights {
+            sections: BTreeMap::from([(ReportSection::Security, 50)]),
+            ..Weights::default()
+        };
+        assert_eq!(
+            weights.apply_section(ReportSection::Security, (Some(80.0), Some(20))),
+            (Some(80.0), Some(50))
+        );
+        assert_eq!(
+            weights.apply_section(ReportSection::Legal, (Some(80.0), Some(5))),
+            (Some(80.0), Some(5))
+        );
+        assert_eq!(
+            weights.apply_section(ReportSection::Security, (None, None)),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn weights_checks_weights() {
+        let weights = Weights {
+            checks: BTreeMap::from([("adopters".to_string(), 5)]),
+            ..Weights::default()
+        };
+        let checks_weights = weights.checks_weights();
+        assert_eq!(
+            checks_weights["adopters"],
+            CheckWeight {
+                default: 1,
+                effective: 5
+            }
+        );
+        assert_eq!(
+            checks_weights["openssf_badge"],
+            CheckWeight {
+                default: 10,
+                effective: 10
+            }
+        );
+    }
+
+    #[test]
+    fn weights_validate_unknown_check() {
+        let weights = Weights {
+            checks: BTreeMap::from([("adopters".to_string(), 5), ("unknown".to_string(), 1)]),
+            ..Weights::default()
+        };
+        assert_eq!(
+            weights.validate().unwrap_err().to_string(),
+            "unknown checks: unknown"
+        );
+    }
+
+    #[test]
+    fn weights_validate_unknown_check_severity() {
+        let weights = Weights {
+            severities: BTreeMap::from([("unknown".to_string(), Severity::Required)]),
+            ..Weights::default()
+        };
+        assert_eq!(
+            weights.validate().unwrap_err().to_string(),
+            "unknown checks: unknown"
+        );
+    }
+
+    #[test]
+    fn weights_severities() {
+        let weights = Weights {
+            severities: BTreeMap::from([("adopters".to_string(), Severity::Required)]),
+            severity_multipliers: BTreeMap::from([(Severity::Required, 3)]),
+            ..Weights::default()
+        };
+        assert_eq!(weights.severity("adopters"), Severity::Required);
+        assert_eq!(weights.severity("roadmap"), Severity::Optional);
+        assert_eq!(weights.check("adopters"), 3);
+        assert_eq!(weights.check("readme"), 30);
+        assert_eq!(weights.check("openssf_badge"), 10);
+        assert_eq!(
+            weights.group_by_severity(&["roadmap", "readme", "openssf_badge", "adopters"]),
+            vec![
+                (Severity::Required, vec!["readme", "adopters"]),
+                (Severity::Recommended, vec!["openssf_badge"]),
+                (Severity::Optional, vec!["roadmap"]),
+            ]
+        );
+    }
+
     #[test]
     fn merge_scores() {
         assert_eq!(
@@ -453,6 +990,10 @@ mod tests {
                     security_weight: Some(15),
                     legal: Some(100.0),
                     legal_weight: Some(5),
+                    artifacts: None,
+                    artifacts_weight: None,
+                    organization: None,
+                    organization_weight: None,
                 },
                 Score {
                     global: 0.0,
@@ -467,6 +1008,10 @@ mod tests {
                     security_weight: Some(10),
                     legal: None,
                     legal_weight: None,
+                    artifacts: None,
+                    artifacts_weight: None,
+                    organization: None,
+                    organization_weight: None,
                 }
             ]),
             Score {
@@ -482,6 +1027,33 @@ mod tests {
                 security_weight: None,
                 legal: Some(100.0),
                 legal_weight: None,
+                artifacts: None,
+                artifacts_weight: None,
+                organization: None,
+                organization_weight: None,
+            }
+        )
+    }
+
+    #[test]
+    fn merge_weighted_scores() {
+        let score = |value: f64| Score {
+            global: value,
+            global_weight: 20,
+            documentation: Some(value),
+            documentation_weight: Some(10),
+            security: Some(value),
+            security_weight: Some(10),
+            ..Score::default()
+        };
+
+        assert_eq!(
+            merge_weighted(&[(score(100.0), 3.0), (score(0.0), 1.0)]),
+            Score {
+                global: 75.0,
+                documentation: Some(75.0),
+                security: Some(75.0),
+                ..Score::default()
             }
         )
     }
@@ -0,0 +1,96 @@
+use super::{calculate_with_weights, merge_weighted, Score, Weights};
+use crate::linter::{Report, CHECKS};
+use anyhow::{format_err, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Result of simulating the effect that fixing some checks would have on a
+/// project's score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ScoreSimulation {
+    /// Current project's score.
+    pub score: Score,
+    pub rating: char,
+
+    /// Score the project would get if the checks were fixed.
+    pub simulated_score: Score,
+    pub simulated_rating: char,
+
+    /// Checks that would be fixed in any of the project's repositories
+    /// (checks not available, exempt or already passing are not included).
+    pub fixed_checks: Vec<String>,
+}
+
+/// Simulate the score the project would get if the checks provided passed in
+/// all the repositories' reports given (identified by the repository name,
+/// along with the repository's weight), applying the weights overrides
+/// provided. An error is returned if any of the checks is unknown.
+pub fn simulate(
+    reports: &[(String, f64, Report)],
+    weights: &Weights,
+    check_ids: &[&str],
+) -> Result<ScoreSimulation> {
+    let unknown: Vec<&str> = check_ids
+        .iter()
+        .filter(|check_id| !CHECKS.contains_key(**check_id))
+        .copied()
+        .collect();
+    if !unknown.is_empty() {
+        return Err(format_err!("unknown checks: {}", unknown.join(", ")));
+    }
+
+    let mut scores = vec![];
+    let mut simulated_scores = vec![];
+    let mut fixed_checks = BTreeSet::new();
+    for (_, weight, report) in reports {
+        scores.push((calculate_with_weights(report, weights), *weight));
+        let mut report = report.clone();
+        fixed_checks.extend(report.set_passed(check_ids));
+        simulated_scores.push((calculate_with_weights(&report, weights), *weight));
+    }
+    let score = merge_weighted(&scores);
+    let simulated_score = merge_weighted(&simulated_scores);
+
+    Ok(ScoreSimulation {
+        rating: score.rating(),
+        score,
+        simulated_rating: simulated_score.rating(),
+        simulated_score,
+        fixed_checks: fixed_checks.into_iter().map(ToString::to_string).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{CheckOutput, Documentation};
+
+    #[test]
+    fn simulate_fixing_checks() {
+        let report = |readme| Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(readme),
+                ..Documentation::default()
+            },
+            ..Report::default()
+        };
+        let reports = vec![
+            ("repo1".to_string(), 1.0, report(CheckOutput::not_passed())),
+            ("repo2".to_string(), 1.0, report(CheckOutput::passed())),
+        ];
+
+        let simulation = simulate(&reports, &Weights::default(), &["readme", "adopters"]).unwrap();
+
+        assert!(simulation.simulated_score.global > simulation.score.global);
+        assert_eq!(simulation.simulated_score.documentation, Some(100.0));
+        assert_eq!(simulation.simulated_rating, 'a');
+        assert_eq!(simulation.fixed_checks, vec!["readme".to_string()]);
+    }
+
+    #[test]
+    fn simulate_unknown_check() {
+        assert!(simulate(&[], &Weights::default(), &["unknown"]).is_err());
+    }
+}
@@ -0,0 +1,355 @@
+use super::{calculate_with_weights, merge_weighted, rating, Score, Weights, RATING_THRESHOLDS};
+use crate::linter::{CheckStatus, Report, ReportSection, CHECKS};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Report sections, in the order they are processed.
+const SECTIONS: [ReportSection; 7] = [
+    ReportSection::Documentation,
+    ReportSection::License,
+    ReportSection::BestPractices,
+    ReportSection::Security,
+    ReportSection::Legal,
+    ReportSection::Artifacts,
+    ReportSection::Organization,
+];
+
+/// Full trace of the calculation of a project's score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ScoreTrace {
+    pub score: Score,
+    pub rating: char,
+    pub rating_thresholds: BTreeMap<char, usize>,
+    pub weights_overrides: Weights,
+    pub repositories: Vec<RepositoryScoreTrace>,
+    pub aggregation: AggregationTrace,
+}
+
+/// Trace of the calculation of a repository's score. The repository's weight
+/// is applied when aggregating its score into the project's score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RepositoryScoreTrace {
+    pub name: String,
+    pub weight: f64,
+    pub score: Score,
+    pub sections: Vec<SectionTrace>,
+}
+
+/// Trace of the calculation of a report section's score. The section's score
+/// is the sum of the points of its checks divided by the section's checks
+/// weight, and it contributes to the repository's global score in proportion
+/// to the section's weight (coefficient).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SectionTrace {
+    pub section: ReportSection,
+    pub score: Option<f64>,
+    pub checks_weight: usize,
+    pub weight: Option<usize>,
+    pub weight_overridden: bool,
+    pub coefficient: f64,
+    pub checks: Vec<CheckTrace>,
+}
+
+/// Trace of the contribution of a check to its section's score. The points
+/// awarded are the check's weight multiplied by the credit percentage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CheckTrace {
+    pub check_id: String,
+    pub status: CheckStatus,
+    pub external: bool,
+    pub default_weight: usize,
+    pub weight: usize,
+    pub credit: usize,
+    pub points: f64,
+}
+
+/// Trace of the aggregation of the repositories' scores into the project's
+/// score. Each repository contributes to the global score and to each of the
+/// sections' scores in proportion to its weight on them, multiplied by the
+/// repository's weight.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AggregationTrace {
+    pub global: Vec<RepositoryContribution>,
+    pub sections: BTreeMap<ReportSection, Vec<RepositoryContribution>>,
+}
+
+/// Contribution of a repository's score to the project's score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RepositoryContribution {
+    pub repository: String,
+    pub score: f64,
+    pub weight: usize,
+    pub coefficient: f64,
+}
+
+/// Calculate the score of the repositories' reports provided (identified by
+/// the repository name, along with the repository's weight) applying the
+/// weights overrides given, returning the full trace of the calculation.
+pub fn trace(reports: &[(String, f64, Report)], weights: &Weights) -> ScoreTrace {
+    let repositories: Vec<RepositoryScoreTrace> = reports
+        .iter()
+        .map(|(name, weight, report)| {
+            let mut trace = trace_repository(name, report, weights);
+            trace.weight = *weight;
+            trace
+        })
+        .collect();
+    let scores: Vec<(Score, f64)> = repositories
+        .iter()
+        .map(|r| (r.score.clone(), r.weight))
+        .collect();
+    let score = merge_weighted(&scores);
+
+    // Aggregation across repositories
+    let contributions = |value: &dyn Fn(&Score) -> (Option<f64>, usize)| {
+        let total_weight: f64 = scores.iter().map(|(s, w)| value(s).1 as f64 * w).sum();
+        repositories
+            .iter()
+            .filter_map(|r| match value(&r.score) {
+                (Some(score), weight) if total_weight > 0.0 => Some(RepositoryContribution {
+                    repository: r.name.clone(),
+                    score,
+                    weight,
+                    coefficient: weight as f64 * r.weight / total_weight,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<RepositoryContribution>>()
+    };
+    let aggregation = AggregationTrace {
+        global: contributions(&|s: &Score| (Some(s.global), s.global_weight)),
+        sections: SECTIONS
+            .iter()
+            .map(|section| {
+                let value = |s: &Score| {
+                    let (score, weight) = section_score(s, *section);
+                    (score, weight.unwrap_or_default())
+                };
+                (*section, contributions(&value))
+            })
+            .collect(),
+    };
+
+    ScoreTrace {
+        rating: rating(score.global),
+        score,
+        rating_thresholds: RATING_THRESHOLDS.into_iter().collect(),
+        weights_overrides: weights.clone(),
+        repositories,
+        aggregation,
+    }
+}
+
+/// Calculate the score of the repository's report provided, tracing how each
+/// of the checks contributed to it.
+fn trace_repository(name: &str, report: &Report, weights: &Weights) -> RepositoryScoreTrace {
+    let score = calculate_with_weights(report, weights);
+
+    let sections = SECTIONS
+        .iter()
+        .map(|section| {
+            // Built-in checks
+            let mut checks: Vec<CheckTrace> = section_statuses(report, *section)
+                .into_iter()
+                .map(|(check_id, status, credit)| {
+                    // Checks not applicable don't contribute to the score
+                    let weight = match status {
+                        CheckStatus::NotApplicable => 0,
+                        _ => weights.check(check_id),
+                    };
+                    CheckTrace {
+                        check_id: check_id.to_string(),
+                        status,
+                        external: false,
+                        default_weight: CHECKS.get(check_id).map_or(0, |check| check.weight),
+                        weight,
+                        credit,
+                        points: weight as f64 * credit as f64 / 100.0,
+                    }
+                })
+                .collect();
+
+            // External checks
+            checks.extend(
+                report
+                    .external
+                    .iter()
+                    .filter(|(_, check)| check.section == *section)
+                    .map(|(check_id, check)| {
+                        let credit = check.output.awarded_credit();
+                        CheckTrace {
+                            check_id: check_id.clone(),
+                            status: check.output.status(),
+                            external: true,
+                            default_weight: check.weight,
+                            weight: check.weight,
+                            credit,
+                            points: check.weight as f64 * credit as f64 / 100.0,
+                        }
+                    }),
+            );
+
+            let (section_score, weight) = section_score(&score, *section);
+            let checks_weight = checks.iter().map(|check| check.weight).sum();
+            let coefficient = match weight {
+                Some(weight) if score.global_weight > 0 => {
+                    weight as f64 / score.global_weight as f64
+                }
+                _ => 0.0,
+            };
+            SectionTrace {
+                section: *section,
+                score: section_score,
+                checks_weight,
+                weight,
+                weight_overridden: section_score.is_some()
+                    && weights.sections.contains_key(section),
+                coefficient,
+                checks,
+            }
+        })
+        .collect();
+
+    RepositoryScoreTrace {
+        name: name.to_string(),
+        weight: 1.0,
+        score,
+        sections,
+    }
+}
+
+/// Return the status and credit awarded of the checks available in the
+/// report's section provided.
+fn section_statuses(
+    report: &Report,
+    section: ReportSection,
+) -> Vec<(&'static str, CheckStatus, usize)> {
+    match section {
+        ReportSection::Documentation => report.documentation.statuses(),
+        ReportSection::License => report.license.statuses(),
+        ReportSection::BestPractices => report.best_practices.statuses(),
+        ReportSection::Security => report.security.statuses(),
+        ReportSection::Legal => report.legal.statuses(),
+        ReportSection::Artifacts => report.artifacts.statuses(),
+        ReportSection::Organization => report.organization.statuses(),
+    }
+}
+
+/// Return the score and weight of the section provided.
+fn section_score(score: &Score, section: ReportSection) -> (Option<f64>, Option<usize>) {
+    match section {
+        ReportSection::Documentation => (score.documentation, score.documentation_weight),
+        ReportSection::License => (score.license, score.license_weight),
+        ReportSection::BestPractices => (score.best_practices, score.best_practices_weight),
+        ReportSection::Security => (score.security, score.security_weight),
+        ReportSection::Legal => (score.legal, score.legal_weight),
+        ReportSection::Artifacts => (score.artifacts, score.artifacts_weight),
+        ReportSection::Organization => (score.organization, score.organization_weight),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{BestPractices, CheckOutput, Documentation, ExternalCheckOutput, License};
+
+    fn report() -> Report {
+        Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed()),
+                roadmap: Some(CheckOutput::exempt()),
+                ..Documentation::default()
+            },
+            license: License {
+                license_approved: Some(CheckOutput::passed().credit(Some(50))),
+                ..License::default()
+            },
+            best_practices: BestPractices {
+                openssf_badge: Some(CheckOutput::failed()),
+                ..BestPractices::default()
+            },
+            external: BTreeMap::from([(
+                "custom".to_string(),
+                ExternalCheckOutput {
+                    section: ReportSection::Documentation,
+                    weight: 2,
+                    output: CheckOutput::passed(),
+                },
+            )]),
+            ..Report::default()
+        }
+    }
+
+    #[test]
+    fn trace_matches_score_calculated() {
+        let weights = Weights {
+            checks: BTreeMap::from([("adopters".to_string(), 3)]),
+            sections: BTreeMap::from([(ReportSection::License, 50)]),
+            ..Weights::default()
+        };
+        let reports = vec![
+            ("repo1".to_string(), 2.0, report()),
+            ("repo2".to_string(), 1.0, Report::default()),
+        ];
+        let trace = trace(&reports, &weights);
+
+        let score = merge_weighted(&[
+            (calculate_with_weights(&reports[0].2, &weights), 2.0),
+            (calculate_with_weights(&reports[1].2, &weights), 1.0),
+        ]);
+        assert_eq!(trace.score, score);
+        assert_eq!(trace.rating, score.rating());
+        assert_eq!(trace.repositories.len(), 2);
+        assert_eq!(trace.repositories[0].weight, 2.0);
+        assert_eq!(trace.aggregation.global.len(), 2);
+        let coefficients: f64 = trace.aggregation.global.iter().map(|c| c.coefficient).sum();
+        assert!((coefficients - 1.0).abs() < f64::EPSILON * 10.0);
+    }
+
+    #[test]
+    fn trace_repository_checks_points() {
+        let weights = Weights {
+            checks: BTreeMap::from([("adopters".to_string(), 3)]),
+            sections: BTreeMap::from([(ReportSection::License, 50)]),
+            ..Weights::default()
+        };
+        let trace = trace_repository("repo", &report(), &weights);
+
+        let documentation = &trace.sections[0];
+        assert_eq!(documentation.section, ReportSection::Documentation);
+        let adopters = &documentation.checks[0];
+        assert_eq!(adopters.check_id, "adopters");
+        assert_eq!(adopters.status, CheckStatus::Passed);
+        assert_eq!(adopters.default_weight, 1);
+        assert_eq!(adopters.weight, 3);
+        assert_eq!(adopters.points, 3.0);
+        let custom = documentation.checks.last().unwrap();
+        assert_eq!(custom.check_id, "custom");
+        assert!(custom.external);
+        assert_eq!(custom.points, 2.0);
+        let points: f64 = documentation.checks.iter().map(|c| c.points).sum();
+        assert_eq!(
+            documentation.score,
+            Some(points / documentation.checks_weight as f64 * 100.0)
+        );
+
+        let license = &trace.sections[1];
+        assert_eq!(license.checks[0].credit, 50);
+        assert_eq!(license.weight, Some(50));
+        assert!(license.weight_overridden);
+
+        let best_practices = &trace.sections[2];
+        assert_eq!(best_practices.checks[0].status, CheckStatus::Failed);
+        assert_eq!(best_practices.checks[0].points, 0.0);
+
+        let coefficients: f64 = trace.sections.iter().map(|s| s.coefficient).sum();
+        assert!((coefficients - 1.0).abs() < f64::EPSILON * 10.0);
+    }
+}
@@ -0,0 +1,5 @@
+module.exports = {
+  title: 'Example',
+  url: 'https://example.io',
+  baseUrl: '/',
+};
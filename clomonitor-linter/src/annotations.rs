@@ -0,0 +1,261 @@
+use crate::{sections_below_minimum, Args};
+use anyhow::Result;
+use clomonitor_core::{
+    linter::{file_path_from_url, CheckStatus, Report},
+    score::Score,
+};
+use std::{fs, io, path::Path};
+
+/// Title used in all the annotations.
+const TITLE: &str = "CLOMonitor";
+
+/// Url of the checks documentation.
+const CHECKS_DOCS_URL: &str = "https://clomonitor.io/docs/topics/checks/";
+
+/// Directory where GitHub Actions workflows are located.
+const WORKFLOWS_DIR: &str = ".github/workflows";
+
+/// Checks that rely on the GitHub Actions workflows of the repository.
+const WORKFLOWS_CHECKS: [&str; 2] = ["dangerous_workflow", "token_permissions"];
+
+/// Print the linter results provided as GitHub Actions workflow commands, so
+/// that they are displayed as annotations in the workflow run (and inline in
+/// the pull requests files when possible).
+pub(crate) fn display(
+    report: &Report,
+    score: &Score,
+    args: &Args,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    // Checks not passed or that could not be run
+    for (check_id, status, url) in report.checks() {
+        let (level, message) = match status {
+            CheckStatus::NotPassed => ("error", format!("{check_id} check not passed")),
+            CheckStatus::Failed => ("warning", format!("{check_id} check could not be run")),
+            CheckStatus::Passed | CheckStatus::Exempt | CheckStatus::NotApplicable => continue,
+        };
+        let message = format!("{message} (see {CHECKS_DOCS_URL} for more details)");
+        let files = check_files(check_id, url, args);
+        if files.is_empty() {
+            write_command(w, level, None, &message)?;
+        }
+        for file in files {
+            write_command(w, level, Some(&file), &message)?;
+        }
+    }
+
+    // Global score and sections minimums
+    if score.global() >= args.pass_score {
+        let message = format!(
+            "Succeeded with a global score of {}",
+            score.global().round()
+        );
+        write_command(w, "notice", None, &message)?;
+    } else {
+        let message = format!(
+            "Failed with a global score of {} (pass score is {})",
+            score.global().round(),
+            args.pass_score
+        );
+        write_command(w, "error", None, &message)?;
+    }
+    for (minimum, section_score) in sections_below_minimum(score, &args.fail_below_section) {
+        let message = match section_score {
+            Some(section_score) => format!(
+                "Failed with a {} score of {} (minimum required is {})",
+                minimum.name,
+                section_score.round(),
+                minimum.score
+            ),
+            None => format!(
+                "Failed as the {} section could not be scored (minimum required is {})",
+                minimum.name, minimum.score
+            ),
+        };
+        write_command(w, "error", None, &message)?;
+    }
+
+    Ok(())
+}
+
+/// Return the files of the repository the annotations of the check provided
+/// should point at (paths are relative to the workflow's working directory).
+fn check_files(check_id: &str, url: Option<&str>, args: &Args) -> Vec<String> {
+    let files = match url.and_then(|url| file_path_from_url(args.url(), url)) {
+        // File the check output points to
+        Some(path) => vec![path],
+        // Workflows the check relies on
+        None if WORKFLOWS_CHECKS.contains(&check_id) => workflows(args.path()),
+        // File expected by the check
+        None => expected_file(check_id)
+            .map(|path| vec![path.to_string()])
+            .unwrap_or_default(),
+    };
+
+    files
+        .into_iter()
+        .map(|path| {
+            let path = args.path().join(path);
+            path.to_string_lossy().trim_start_matches("./").to_string()
+        })
+        .collect()
+}
+
+/// Return the file expected by the check provided, if any.
+fn expected_file(check_id: &str) -> Option<&'static str> {
+    let path = match check_id {
+        "adopters" => "ADOPTERS.md",
+        "changelog" => "CHANGELOG.md",
+        "code_of_conduct" => "CODE_OF_CONDUCT.md",
+        "contributing" => "CONTRIBUTING.md",
+        "governance" => "GOVERNANCE.md",
+        "license_approved" | "license_spdx_id" => "LICENSE",
+        "maintainers" => "MAINTAINERS.md",
+        "readme" => "README.md",
+        "roadmap" => "ROADMAP.md",
+        "security_insights" => "SECURITY-INSIGHTS.yml",
+        "security_policy" => "SECURITY.md",
+        _ => return None,
+    };
+    Some(path)
+}
+
+/// Return the paths of the GitHub Actions workflows in the repository
+/// provided (relative to its root).
+fn workflows(root: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(root.join(WORKFLOWS_DIR)) else {
+        return vec![];
+    };
+    let mut workflows: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".yml") || name.ends_with(".yaml"))
+        .map(|name| format!("{WORKFLOWS_DIR}/{name}"))
+        .collect();
+    workflows.sort();
+    workflows
+}
+
+/// Write a workflow command with the level and message provided, pointing at
+/// the file given (if any).
+fn write_command(
+    w: &mut impl io::Write,
+    level: &str,
+    file: Option<&str>,
+    message: &str,
+) -> Result<()> {
+    let mut properties = vec![];
+    if let Some(file) = file {
+        properties.push(format!("file={}", escape_property(file)));
+    }
+    properties.push(format!("title={}", escape_property(TITLE)));
+    writeln!(
+        w,
+        "::{level} {}::{}",
+        properties.join(","),
+        escape_data(message)
+    )?;
+    Ok(())
+}
+
+/// Escape the data of a workflow command.
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape the value of a workflow command property.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Format;
+    use clomonitor_core::linter::{CheckOutput, CheckSet, Documentation, License, Security};
+    use std::{path::PathBuf, str};
+
+    const TESTDATA_PATH: &str = "src/testdata";
+
+    fn args(path: &str) -> Args {
+        Args {
+            path: Some(PathBuf::from(path)),
+            command: None,
+            url: Some("https://github.com/test-org/test-repo".to_string()),
+            check_set: vec![CheckSet::Code, CheckSet::Community],
+            enable_check: vec![],
+            disable_check: vec![],
+            provider: None,
+            home_url: None,
+            container_image: vec![],
+            pass_score: 80.0,
+            fail_below_section: vec![],
+            format: Format::GithubActions,
+            cache_dir: None,
+            full_clone: false,
+            history: None,
+            save_report: None,
+        }
+    }
+
+    #[test]
+    fn display_annotations() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                contributing: Some(CheckOutput::failed()),
+                readme: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            license: License {
+                license_approved: Some(CheckOutput::not_passed().url(Some(
+                    "https://github.com/test-org/test-repo/blob/main/COPYING".to_string(),
+                ))),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let score = Score {
+            global: 50.0,
+            ..Score::default()
+        };
+
+        let mut w = Vec::new();
+        display(&report, &score, &args("."), &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(&w).unwrap(),
+            "::warning file=CONTRIBUTING.md,title=CLOMonitor::contributing check could not be run (see https://clomonitor.io/docs/topics/checks/ for more details)
+::error file=README.md,title=CLOMonitor::readme check not passed (see https://clomonitor.io/docs/topics/checks/ for more details)
+::error file=COPYING,title=CLOMonitor::license_approved check not passed (see https://clomonitor.io/docs/topics/checks/ for more details)
+::error title=CLOMonitor::sbom check not passed (see https://clomonitor.io/docs/topics/checks/ for more details)
+::error title=CLOMonitor::Failed with a global score of 50 (pass score is 80)
+"
+        );
+    }
+
+    #[test]
+    fn check_files_workflows() {
+        let path = format!("{TESTDATA_PATH}/workflows");
+
+        assert_eq!(
+            check_files("dangerous_workflow", None, &args(&path)),
+            vec![
+                format!("{path}/.github/workflows/ci.yml"),
+                format!("{path}/.github/workflows/release.yaml"),
+            ]
+        );
+    }
+
+    #[test]
+    fn escape_command_values() {
+        assert_eq!(escape_data("50%\nscore"), "50%25%0Ascore");
+        assert_eq!(escape_property("a:b,c"), "a%3Ab%2Cc");
+    }
+}
@@ -0,0 +1,172 @@
+use anyhow::{format_err, Context, Result};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+use tokio::process::Command;
+
+/// Number of commits fetched when cloning a repository (some checks, like the
+/// DCO one, rely on the latest commits).
+const CLONE_DEPTH: &str = "--depth=10";
+
+/// Directories checked out in sparse clones, besides the files located at the
+/// root of the repository. They include the locations the checks look for
+/// files in, as well as those where vendored code is usually found.
+const SPARSE_DIRS: [&str; 11] = [
+    ".github",
+    ".gitea",
+    ".gitlab",
+    ".reuse",
+    "doc",
+    "docs",
+    "LICENSES",
+    "third-party",
+    "third_party",
+    "vendor",
+    "website",
+];
+
+/// Cache of the repositories cloned by the linter. Repositories are cloned
+/// the first time they are linted and updated on subsequent runs, so that
+/// checks can run against the local copy without downloading the full
+/// repository each time.
+pub(crate) struct RepositoryCache {
+    root: PathBuf,
+}
+
+impl RepositoryCache {
+    /// Create a new RepositoryCache instance. When no directory is provided,
+    /// the default one in the user's cache directory will be used.
+    pub(crate) fn new(root: Option<PathBuf>) -> Result<Self> {
+        let root = match root {
+            Some(root) => root,
+            None => default_root()?,
+        };
+        Ok(Self { root })
+    }
+
+    /// Clone the repository provided into the cache (or update it if it was
+    /// already there), returning the path of the local copy. Sparse clones
+    /// only check out the files at the root of the repository and the
+    /// directories used by the checks (blobs are fetched as needed).
+    pub(crate) async fn checkout(&self, url: &str, full: bool) -> Result<PathBuf> {
+        let dst = repository_path(&self.root, url)?;
+
+        if dst.join(".git").exists() {
+            // Update existing clone with the latest changes
+            git(Command::new("git").arg("-C").arg(&dst).args([
+                "fetch",
+                "--quiet",
+                CLONE_DEPTH,
+                "origin",
+                "HEAD",
+            ]))
+            .await?;
+            if full {
+                git(Command::new("git")
+                    .arg("-C")
+                    .arg(&dst)
+                    .args(["sparse-checkout", "disable"]))
+                .await?;
+            }
+            git(Command::new("git").arg("-C").arg(&dst).args([
+                "reset",
+                "--quiet",
+                "--hard",
+                "FETCH_HEAD",
+            ]))
+            .await?;
+        } else {
+            // Clone repository
+            if let Some(parent) = dst.parent() {
+                fs::create_dir_all(parent).context(format!(
+                    "error creating cache directory {}",
+                    parent.display()
+                ))?;
+            }
+            let mut cmd = Command::new("git");
+            cmd.args(["clone", "--quiet", CLONE_DEPTH]);
+            if !full {
+                cmd.args(["--filter=blob:none", "--sparse"]);
+            }
+            git(cmd.arg(url).arg(&dst)).await?;
+            if !full {
+                git(Command::new("git")
+                    .arg("-C")
+                    .arg(&dst)
+                    .args(["sparse-checkout", "set"])
+                    .args(SPARSE_DIRS))
+                .await?;
+            }
+        }
+
+        Ok(dst)
+    }
+}
+
+/// Return the default cache directory: `$XDG_CACHE_HOME/clomonitor/repositories`
+/// or `~/.cache/clomonitor/repositories` when it is not set.
+fn default_root() -> Result<PathBuf> {
+    let base = match env::var_os("XDG_CACHE_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(".cache"))
+            .ok_or_else(|| format_err!("HOME not found in environment"))?,
+    };
+    Ok(base.join("clomonitor").join("repositories"))
+}
+
+/// Return the path where the repository provided is cloned in the cache
+/// (i.e. https://github.com/org/repo -> <root>/github.com/org/repo).
+fn repository_path(root: &Path, url: &str) -> Result<PathBuf> {
+    let url = url.trim().to_lowercase();
+    let url = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+    let url = url.trim_end_matches('/');
+    let url = url.strip_suffix(".git").unwrap_or(url);
+
+    let mut path = root.to_path_buf();
+    for component in url.split('/').filter(|c| !c.is_empty()) {
+        if component == "." || component == ".." {
+            return Err(format_err!("invalid repository url"));
+        }
+        path.push(component);
+    }
+    if path == root {
+        return Err(format_err!("invalid repository url"));
+    }
+    Ok(path)
+}
+
+/// Run the git command provided.
+async fn git(cmd: &mut Command) -> Result<()> {
+    let output = cmd.output().await.context("error running git")?;
+    if !output.status.success() {
+        return Err(format_err!("{}", String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repository_path_from_url() {
+        let root = Path::new("/cache");
+        assert_eq!(
+            repository_path(root, "https://github.com/Org/Repo.git/").unwrap(),
+            PathBuf::from("/cache/github.com/org/repo")
+        );
+        assert_eq!(
+            repository_path(root, "https://gitlab.com/group/subgroup/repo").unwrap(),
+            PathBuf::from("/cache/gitlab.com/group/subgroup/repo")
+        );
+    }
+
+    #[test]
+    fn repository_path_invalid_url() {
+        let root = Path::new("/cache");
+        assert!(repository_path(root, "https://github.com/org/../../etc").is_err());
+        assert!(repository_path(root, "https://").is_err());
+    }
+}
@@ -0,0 +1,338 @@
+use crate::DiffArgs;
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use clomonitor_core::{
+    linter::{CheckStatus, Report},
+    score::Score,
+};
+use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table, *};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+const NOT_APPLICABLE_MSG: &str = "n/a";
+
+/// Diff output format options.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum DiffFormat {
+    Json,
+    Table,
+}
+
+/// Linter run saved to a file with `--save-report`. Files produced using the
+/// json output format can be loaded as well.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SavedReport {
+    pub report: Report,
+    pub score: Score,
+}
+
+impl SavedReport {
+    /// Load the saved report from the file provided.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let data = fs::read(path).context(format!("error reading {}", path.display()))?;
+        serde_json::from_slice(&data).context(format!("invalid report file {}", path.display()))
+    }
+
+    /// Save the report to the file provided.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)
+            .context(format!("error writing {}", path.display()))
+    }
+}
+
+/// Change in the status of a check between two runs (the status is none when
+/// the check was not available in the run).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct CheckChange {
+    pub check_id: String,
+    pub old: Option<CheckStatus>,
+    pub new: Option<CheckStatus>,
+}
+
+/// Differences between two runs' scores (old, new and delta), for the global
+/// score and each of the sections.
+type ScoreDelta = (String, Option<f64>, Option<f64>, Option<f64>);
+
+/// Compare the reports provided, returning the checks whose status changed
+/// (sorted by check id).
+pub(crate) fn changed_checks(old: &Report, new: &Report) -> Vec<CheckChange> {
+    let mut statuses: BTreeMap<&str, (Option<CheckStatus>, Option<CheckStatus>)> = BTreeMap::new();
+    for (check_id, status, _) in old.checks() {
+        statuses.entry(check_id).or_default().0 = Some(status);
+    }
+    for (check_id, status, _) in new.checks() {
+        statuses.entry(check_id).or_default().1 = Some(status);
+    }
+    statuses
+        .into_iter()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(check_id, (old, new))| CheckChange {
+            check_id: check_id.to_string(),
+            old,
+            new,
+        })
+        .collect()
+}
+
+/// Compare the scores provided, returning the global and sections scores
+/// along with their delta (sections not scored in any of them are skipped).
+fn scores_delta(old: &Score, new: &Score) -> Vec<ScoreDelta> {
+    let delta = |old: Option<f64>, new: Option<f64>| match (old, new) {
+        (Some(old), Some(new)) => Some(new - old),
+        _ => None,
+    };
+    let sections = [
+        ("Documentation", old.documentation, new.documentation),
+        ("License", old.license, new.license),
+        ("Best practices", old.best_practices, new.best_practices),
+        ("Security", old.security, new.security),
+        ("Legal", old.legal, new.legal),
+        ("Artifacts", old.artifacts, new.artifacts),
+        ("Organization", old.organization, new.organization),
+    ];
+    let mut scores = vec![(
+        "Global".to_string(),
+        Some(old.global()),
+        Some(new.global()),
+        delta(Some(old.global()), Some(new.global())),
+    )];
+    for (name, old, new) in sections {
+        if old.is_some() || new.is_some() {
+            scores.push((name.to_string(), old, new, delta(old, new)));
+        }
+    }
+    scores
+}
+
+/// Load the reports provided and print the differences between them.
+pub(crate) fn run(args: &DiffArgs, w: &mut impl io::Write) -> Result<()> {
+    let old = SavedReport::load(&args.old)?;
+    let new = SavedReport::load(&args.new)?;
+    let changes = changed_checks(&old.report, &new.report);
+    let scores = scores_delta(&old.score, &new.score);
+
+    match args.format {
+        DiffFormat::Json => {
+            let output = json!({
+                "score": {
+                    "old": old.score.global(),
+                    "new": new.score.global(),
+                    "delta": new.score.global() - old.score.global(),
+                },
+                "changes": changes,
+            });
+            writeln!(w, "{output}")?;
+        }
+        DiffFormat::Table => display(&changes, &scores, w)?,
+    }
+    Ok(())
+}
+
+/// Print the differences provided using tables.
+fn display(changes: &[CheckChange], scores: &[ScoreDelta], w: &mut impl io::Write) -> Result<()> {
+    writeln!(w, "\nCLOMonitor linter reports diff\n")?;
+
+    // Scores table
+    writeln!(w, "Score changes\n")?;
+    let mut scores_table = new_table();
+    scores_table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            cell_header("Section"),
+            cell_header("Old"),
+            cell_header("New"),
+            cell_header("Delta"),
+        ]);
+    for (name, old, new, delta) in scores {
+        scores_table.add_row(vec![
+            Cell::new(name),
+            cell_score(*old),
+            cell_score(*new),
+            cell_delta(*delta),
+        ]);
+    }
+    writeln!(w, "{scores_table}\n")?;
+
+    // Checks table
+    if changes.is_empty() {
+        writeln!(w, "No checks changed state\n")?;
+        return Ok(());
+    }
+    writeln!(w, "Checks changed\n")?;
+    let mut checks_table = new_table();
+    checks_table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            cell_header("Check"),
+            cell_header("Old"),
+            cell_header("New"),
+        ]);
+    for change in changes {
+        checks_table.add_row(vec![
+            Cell::new(&change.check_id),
+            cell_status(change.old),
+            cell_status(change.new),
+        ]);
+    }
+    writeln!(w, "{checks_table}\n")?;
+
+    Ok(())
+}
+
+/// Helper function to create a new table that will be forced to use a non-tty
+/// mode when running tests.
+#[allow(clippy::let_and_return, unused_mut)]
+fn new_table() -> Table {
+    let mut table = Table::new();
+
+    #[cfg(test)]
+    table.force_no_tty();
+
+    table
+}
+
+/// Build a cell used for headers text.
+fn cell_header(title: &str) -> Cell {
+    Cell::new(title)
+        .set_alignment(CellAlignment::Center)
+        .add_attribute(Attribute::Bold)
+}
+
+/// Build a cell used for scores.
+fn cell_score(score: Option<f64>) -> Cell {
+    let content = score.map_or(NOT_APPLICABLE_MSG.to_string(), |v| v.round().to_string());
+    Cell::new(content).set_alignment(CellAlignment::Center)
+}
+
+/// Build a cell used for scores deltas.
+fn cell_delta(delta: Option<f64>) -> Cell {
+    let (content, color) = match delta.map(f64::round) {
+        Some(v) if v > 0.0 => (format!("+{v}"), Color::Green),
+        Some(v) if v < 0.0 => (v.to_string(), Color::Red),
+        Some(_) => ("0".to_string(), Color::Grey),
+        None => (NOT_APPLICABLE_MSG.to_string(), Color::Grey),
+    };
+    Cell::new(content)
+        .set_alignment(CellAlignment::Center)
+        .add_attribute(Attribute::Bold)
+        .fg(color)
+}
+
+/// Build a cell used for checks statuses.
+fn cell_status(status: Option<CheckStatus>) -> Cell {
+    let (content, color) = match status {
+        Some(CheckStatus::Passed) => ("Passed", Color::Green),
+        Some(CheckStatus::NotPassed) => ("Not passed", Color::Red),
+        Some(CheckStatus::Exempt) => ("Exempt", Color::Grey),
+        Some(CheckStatus::Failed) => ("Failed", Color::Yellow),
+        Some(CheckStatus::NotApplicable) => ("Not applicable", Color::Grey),
+        None => (NOT_APPLICABLE_MSG, Color::Grey),
+    };
+    Cell::new(content)
+        .set_alignment(CellAlignment::Center)
+        .fg(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{CheckOutput, Documentation, Security};
+
+    #[test]
+    fn changed_checks_detected() {
+        let old = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::not_passed()),
+                readme: Some(CheckOutput::passed()),
+                roadmap: Some(CheckOutput::passed()),
+                ..Documentation::default()
+            },
+            ..Report::default()
+        };
+        let new = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::passed()),
+                ..Documentation::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::failed()),
+                ..Security::default()
+            },
+            ..Report::default()
+        };
+
+        assert_eq!(
+            changed_checks(&old, &new),
+            vec![
+                CheckChange {
+                    check_id: "adopters".to_string(),
+                    old: Some(CheckStatus::NotPassed),
+                    new: Some(CheckStatus::Passed),
+                },
+                CheckChange {
+                    check_id: "roadmap".to_string(),
+                    old: Some(CheckStatus::Passed),
+                    new: None,
+                },
+                CheckChange {
+                    check_id: "sbom".to_string(),
+                    old: None,
+                    new: Some(CheckStatus::Failed),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn scores_delta_calculated() {
+        let old = Score {
+            global: 50.0,
+            documentation: Some(40.0),
+            security: Some(60.0),
+            ..Score::default()
+        };
+        let new = Score {
+            global: 75.0,
+            documentation: Some(90.0),
+            legal: Some(100.0),
+            ..Score::default()
+        };
+
+        assert_eq!(
+            scores_delta(&old, &new),
+            vec![
+                ("Global".to_string(), Some(50.0), Some(75.0), Some(25.0)),
+                (
+                    "Documentation".to_string(),
+                    Some(40.0),
+                    Some(90.0),
+                    Some(50.0)
+                ),
+                ("Security".to_string(), Some(60.0), None, None),
+                ("Legal".to_string(), None, Some(100.0), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn saved_report_roundtrip() {
+        let path =
+            std::env::temp_dir().join(format!("clomonitor-diff-{}.json", std::process::id()));
+        let saved = SavedReport {
+            report: Report::default(),
+            score: Score {
+                global: 80.0,
+                ..Score::default()
+            },
+        };
+        saved.save(&path).unwrap();
+        let loaded = SavedReport::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap(), saved);
+    }
+}
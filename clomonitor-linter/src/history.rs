@@ -0,0 +1,133 @@
+use anyhow::Result;
+use clomonitor_core::{linter::Report, score::Score};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+use time::OffsetDateTime;
+
+/// Schema of the linter runs history database.
+const SCHEMA: &str = r#"
+create table if not exists run (
+    run_id integer primary key autoincrement,
+    url text not null,
+    created_at integer not null,
+    linter_version text not null,
+    score text not null,
+    report text not null
+);
+
+create index if not exists run_url_idx on run (url);
+"#;
+
+/// Linter run recorded in the history.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Run {
+    pub created_at: i64,
+    pub linter_version: String,
+    pub score: Score,
+}
+
+/// Trait that defines the operations a linter runs history must support.
+pub(crate) trait History {
+    /// Get the latest run recorded for the repository provided.
+    fn last(&self, url: &str) -> Result<Option<Run>>;
+
+    /// Record a new run for the repository provided.
+    fn record(&self, url: &str, report: &Report, score: &Score) -> Result<()>;
+}
+
+/// History implementation backed by a SQLite database.
+pub(crate) struct SqliteHistory {
+    conn: Connection,
+}
+
+impl SqliteHistory {
+    /// Create a new SqliteHistory instance using the database file provided,
+    /// setting up its schema if needed.
+    pub(crate) fn new(path: &Path) -> Result<Self> {
+        Self::setup(Connection::open(path)?)
+    }
+
+    /// Set up the database schema and create the instance.
+    fn setup(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+}
+
+impl History for SqliteHistory {
+    fn last(&self, url: &str) -> Result<Option<Run>> {
+        let run = self
+            .conn
+            .query_row(
+                "
+                select created_at, linter_version, score
+                from run
+                where url = ?1
+                order by run_id desc
+                limit 1
+                ",
+                params![url],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get::<_, String>(2)?)),
+            )
+            .optional()?;
+        match run {
+            Some((created_at, linter_version, score)) => Ok(Some(Run {
+                created_at,
+                linter_version,
+                score: serde_json::from_str(&score)?,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    fn record(&self, url: &str, report: &Report, score: &Score) -> Result<()> {
+        self.conn.execute(
+            "
+            insert into run (url, created_at, linter_version, score, report)
+            values (?1, ?2, ?3, ?4, ?5)
+            ",
+            params![
+                url,
+                OffsetDateTime::now_utc().unix_timestamp(),
+                env!("CARGO_PKG_VERSION"),
+                serde_json::to_string(score)?,
+                serde_json::to_string(report)?,
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://github.com/test-org/test-repo";
+
+    #[test]
+    fn last_run_not_found() {
+        let history = SqliteHistory::setup(Connection::open_in_memory().unwrap()).unwrap();
+
+        assert_eq!(history.last(URL).unwrap(), None);
+    }
+
+    #[test]
+    fn record_and_get_last_run() {
+        let history = SqliteHistory::setup(Connection::open_in_memory().unwrap()).unwrap();
+        let score = |global| Score {
+            global,
+            ..Score::default()
+        };
+        history
+            .record(URL, &Report::default(), &score(50.0))
+            .unwrap();
+        history
+            .record(URL, &Report::default(), &score(75.0))
+            .unwrap();
+
+        let run = history.last(URL).unwrap().unwrap();
+        assert_eq!(run.score, score(75.0));
+        assert_eq!(run.linter_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(history.last("https://github.com/other/repo").unwrap(), None);
+    }
+}
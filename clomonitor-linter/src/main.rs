@@ -1,20 +1,45 @@
 use anyhow::{format_err, Result};
-use clap::{Parser, ValueEnum};
+use cache::RepositoryCache;
+use clap::{Parser, Subcommand, ValueEnum};
 use clomonitor_core::{
-    linter::{CheckSet, CoreLinter, Linter, LinterInput},
-    score,
+    lint,
+    linter::{CheckOverrides, CheckSet, LinterInput, Provider, ReportSection},
+    score::Score,
+    LintOutput,
 };
+use diff::{DiffFormat, SavedReport};
+use history::{History, SqliteHistory};
 use serde_json::json;
-use std::{env, io, path::PathBuf};
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+};
+use time::{macros::format_description, OffsetDateTime};
 
+mod annotations;
+mod cache;
+mod diff;
+mod history;
 mod table;
 
 /// Environment variable containing Github token.
 const GITHUB_TOKEN: &str = "GITHUB_TOKEN";
 
+/// Environment variable containing the Github token used for the organization
+/// checks (optional).
+const GITHUB_ORG_TOKEN: &str = "GITHUB_ORG_TOKEN";
+
+/// Exit code used when the global score is below the pass score.
+const EXIT_CODE_SCORE_BELOW: i32 = 1;
+
+/// Exit code used when the score of some sections is below the minimum
+/// required (and the global score is not below the pass score).
+const EXIT_CODE_SECTION_BELOW: i32 = 2;
+
 /// CLI output format options.
 #[derive(Debug, Clone, ValueEnum)]
 pub enum Format {
+    GithubActions,
     Json,
     Table,
 }
@@ -27,73 +52,333 @@ pub enum Format {
 
 The CLOMonitor linter runs some checks on the repository provided and produces
 a report with the result. Some of the checks are done locally using the path
-provided and some remotely as they rely on external APIs. Only GitHub repos
-are supported at the moment. For more information about the checks, please see
+provided and some remotely as they rely on external APIs. Repositories hosted
+on GitLab or Gitea are supported as well, but some checks are limited (or not
+available) on them. For more information about the checks, please see
 https://clomonitor.io/docs/topics/checks/. The exit code will be 0 if the
 linter runs successfully and the score is equal or higher than the pass score
-provided, or non-zero otherwise.
+provided, or non-zero otherwise (1 when the global score is below the pass
+score, 2 when the score of some sections is below the minimum required).
 
 This tool uses the Github GraphQL API for some checks, which requires
 authentication. Please make sure you provide a Github token (with public_repo
-scope) by setting the GITHUB_TOKEN environment variable."
+scope) by setting the GITHUB_TOKEN environment variable. The organization
+checks are only run when a token of an owner of the GitHub organization is
+provided in the GITHUB_ORG_TOKEN environment variable.
+
+Reports saved using --save-report can be compared with the diff subcommand,
+which prints the checks that changed state and the score delta.",
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true
 )]
 struct Args {
-    /// Repository local path (used for checks that can be done locally)
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    /// Repository local path (used for checks that can be done locally). When
+    /// not provided, the repository is cloned into the cache directory
     #[clap(long)]
-    path: PathBuf,
+    path: Option<PathBuf>,
 
     /// Repository url [https://github.com/org/repo] (used for some GitHub remote checks)
-    #[clap(long)]
-    url: String,
+    #[clap(long, required = true)]
+    url: Option<String>,
 
     /// Sets of checks to run
     #[clap(value_enum, long, default_values = &["code", "community"])]
     check_set: Vec<CheckSet>,
 
+    /// Checks to run even if they don't belong to any of the check sets
+    #[clap(long)]
+    enable_check: Vec<String>,
+
+    /// Checks to skip even if they belong to some of the check sets
+    #[clap(long)]
+    disable_check: Vec<String>,
+
+    /// Provider hosting the repository (detected from the url when not set)
+    #[clap(value_enum, long)]
+    provider: Option<Provider>,
+
+    /// Project's home url (used for the checks that verify the live website)
+    #[clap(long)]
+    home_url: Option<String>,
+
+    /// Container image built from the repository [i.e. ghcr.io/org/repo:latest] (repeatable)
+    #[clap(long)]
+    container_image: Vec<String>,
+
+    /// Gerrit project url, when the repository is a mirror of a Gerrit-hosted one
+    /// [i.e. https://gerrit.example.org/r/admin/repos/org/project] (used for the review
+    /// and activity checks)
+    #[clap(long)]
+    gerrit_url: Option<String>,
+
     /// Linter pass score
-    #[clap(long, default_value = "75")]
+    #[clap(long, visible_alias = "fail-below", default_value = "75")]
     pass_score: f64,
 
+    /// Minimum score required for a section [i.e. security=80] (repeatable)
+    #[clap(long, value_parser = parse_section_minimum)]
+    fail_below_section: Vec<SectionMinimum>,
+
     /// Output format
     #[clap(value_enum, long, default_value = "table")]
     format: Format,
+
+    /// Directory where repositories are cloned when no local path is provided
+    /// [default: ~/.cache/clomonitor/repositories]
+    #[clap(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// Check out all the repository's files when cloning it (by default only
+    /// the files at the root and the directories used by the checks are)
+    #[clap(long)]
+    full_clone: bool,
+
+    /// SQLite database file where the linter runs are recorded (the score of
+    /// the previous run of the repository is displayed when available)
+    #[clap(long)]
+    history: Option<PathBuf>,
+
+    /// File where the report and score are saved (in json format), so that
+    /// they can be compared later using the diff subcommand
+    #[clap(long)]
+    save_report: Option<PathBuf>,
+}
+
+/// Linter subcommands.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Compare two reports saved with --save-report, printing the checks that
+    /// changed state and the score delta
+    Diff(DiffArgs),
+}
+
+/// Arguments of the diff subcommand.
+#[derive(Debug, clap::Args)]
+struct DiffArgs {
+    /// Report of the previous run
+    old: PathBuf,
+
+    /// Report of the current run
+    new: PathBuf,
+
+    /// Output format
+    #[clap(value_enum, long, default_value = "table")]
+    format: DiffFormat,
+}
+
+impl Args {
+    /// Return the provider hosting the repository, detecting it from the
+    /// repository url when it hasn't been provided (GitHub is assumed if it
+    /// cannot be detected).
+    fn provider(&self) -> Provider {
+        self.provider
+            .or_else(|| Provider::from_url(self.url()))
+            .unwrap_or_default()
+    }
+
+    /// Return the repository url (it is always provided when linting).
+    fn url(&self) -> &str {
+        self.url.as_deref().unwrap_or_default()
+    }
+
+    /// Return the repository local path. When it hasn't been provided, it is
+    /// set to the path of the repository's clone in the cache before linting.
+    fn path(&self) -> &Path {
+        self.path.as_deref().unwrap_or_else(|| Path::new("."))
+    }
+}
+
+/// Minimum score required for a report's section.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SectionMinimum {
+    pub section: ReportSection,
+    pub name: &'static str,
+    pub score: f64,
+}
+
+/// Parse a section minimum score provided in the `section=score` format.
+fn parse_section_minimum(s: &str) -> Result<SectionMinimum> {
+    let (name, score) = s
+        .split_once('=')
+        .ok_or_else(|| format_err!("expected section=score"))?;
+    let section: ReportSection = name.trim().parse()?;
+    let score: f64 = score
+        .trim()
+        .parse()
+        .map_err(|_| format_err!("invalid score: {score}"))?;
+    let name = match section {
+        ReportSection::Documentation => "documentation",
+        ReportSection::License => "license",
+        ReportSection::BestPractices => "best_practices",
+        ReportSection::Security => "security",
+        ReportSection::Legal => "legal",
+        ReportSection::Artifacts => "artifacts",
+        ReportSection::Organization => "organization",
+    };
+    Ok(SectionMinimum {
+        section,
+        name,
+        score,
+    })
+}
+
+/// Return the sections minimums not met by the score provided, along with the
+/// sections scores. Sections not scored do not meet their minimum, as it
+/// cannot be verified.
+fn sections_below_minimum(
+    score: &Score,
+    minimums: &[SectionMinimum],
+) -> Vec<(SectionMinimum, Option<f64>)> {
+    minimums
+        .iter()
+        .map(|minimum| (*minimum, score.section(minimum.section)))
+        .filter(|(minimum, score)| score.map_or(true, |score| score < minimum.score))
+        .collect()
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let args = Args::parse();
+    let mut args = Args::parse();
 
-    // Check if required Github token is present in environment
+    // Compare the reports provided when running the diff subcommand
+    if let Some(Command::Diff(diff_args)) = &args.command {
+        return diff::run(diff_args, &mut io::stdout());
+    }
+
+    // Check if required Github token is present in environment (it's only
+    // needed for repositories hosted on GitHub)
+    let provider = args.provider();
     let github_token = match env::var(GITHUB_TOKEN) {
-        Err(_) => return Err(format_err!("{} not found in environment", GITHUB_TOKEN)),
+        Err(_) if provider == Provider::Github => {
+            return Err(format_err!("{} not found in environment", GITHUB_TOKEN))
+        }
+        Err(_) => String::new(),
         Ok(token) => token,
     };
 
+    // Clone the repository into the cache when no local path is provided
+    if args.path.is_none() {
+        let cache = RepositoryCache::new(args.cache_dir.clone())?;
+        args.path = Some(cache.checkout(args.url(), args.full_clone).await?);
+    }
+
     // Lint repository provided
     let input = LinterInput {
-        root: args.path.clone(),
-        url: args.url.clone(),
+        root: args.path().to_path_buf(),
+        url: args.url().to_string(),
         check_sets: args.check_set.clone(),
+        check_overrides: CheckOverrides {
+            enable: args.enable_check.clone(),
+            disable: args.disable_check.clone(),
+        },
         github_token,
+        github_org_token: env::var(GITHUB_ORG_TOKEN).ok(),
+        provider,
+        home_url: args.home_url.clone(),
+        container_images: args.container_image.clone(),
+        gerrit_url: args.gerrit_url.clone(),
     };
-    let report = CoreLinter::new().lint(&input).await?;
-    let score = score::calculate(&report);
+    let LintOutput { report, score } = lint(&input).await?;
+
+    // Record run in the history, getting the previous one first
+    let previous_run = match &args.history {
+        Some(path) => {
+            let history = SqliteHistory::new(path)?;
+            let previous_run = history.last(args.url())?;
+            history.record(args.url(), &report, &score)?;
+            previous_run
+        }
+        None => None,
+    };
+
+    // Save report and score to the file provided
+    if let Some(path) = &args.save_report {
+        let saved = SavedReport {
+            report: report.clone(),
+            score: score.clone(),
+        };
+        saved.save(path)?;
+    }
 
     // Display results using the requested format
     match args.format {
-        Format::Table => table::display(&report, &score, &args, &mut io::stdout())?,
+        Format::GithubActions => annotations::display(&report, &score, &args, &mut io::stdout())?,
+        Format::Table => {
+            table::display(&report, &score, &args, &mut io::stdout())?;
+            if let Some(run) = &previous_run {
+                let created_at = OffsetDateTime::from_unix_timestamp(run.created_at)?.format(
+                    format_description!("[year]-[month]-[day] [hour]:[minute]:[second] UTC"),
+                )?;
+                println!(
+                    "Previous score: {:.0} (run on {created_at} with linter {})\n",
+                    run.score.global(),
+                    run.linter_version
+                );
+            }
+        }
         Format::Json => {
             let output = json!({
                 "report": report,
                 "score": score,
+                "previous_score": previous_run.map(|run| run.score),
             });
             println!("{output}");
         }
     }
 
-    // Check if the linter succeeded according to the provided pass score
+    // Check if the linter succeeded according to the provided pass score and
+    // sections minimums
     if score.global() < args.pass_score {
-        std::process::exit(1);
+        std::process::exit(EXIT_CODE_SCORE_BELOW);
+    }
+    if !sections_below_minimum(&score, &args.fail_below_section).is_empty() {
+        std::process::exit(EXIT_CODE_SECTION_BELOW);
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_section_minimum_valid() {
+        assert_eq!(
+            parse_section_minimum("security=80").unwrap(),
+            SectionMinimum {
+                section: ReportSection::Security,
+                name: "security",
+                score: 80.0,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_section_minimum_invalid() {
+        assert!(parse_section_minimum("security").is_err());
+        assert!(parse_section_minimum("unknown=80").is_err());
+        assert!(parse_section_minimum("security=high").is_err());
+    }
+
+    #[test]
+    fn sections_below_minimum_detected() {
+        let score = Score {
+            documentation: Some(90.0),
+            security: Some(70.0),
+            ..Score::default()
+        };
+        let minimums = [
+            parse_section_minimum("documentation=80").unwrap(),
+            parse_section_minimum("security=80").unwrap(),
+            parse_section_minimum("legal=50").unwrap(),
+        ];
+
+        assert_eq!(
+            sections_below_minimum(&score, &minimums),
+            vec![(minimums[1], Some(70.0)), (minimums[2], None)]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Args;
+use crate::{sections_below_minimum, Args};
 use anyhow::Result;
 use clomonitor_core::{
     linter::{CheckOutput, Report},
@@ -23,9 +23,9 @@ pub(crate) fn display(
     writeln!(w, "\nCLOMonitor linter results\n")?;
 
     // Repository information
-    let local_path = match fs::canonicalize(&args.path) {
+    let local_path = match fs::canonicalize(args.path()) {
         Ok(cp) => cp.to_string_lossy().to_string(),
-        Err(_) => args.path.to_string_lossy().to_string(),
+        Err(_) => args.path().to_string_lossy().to_string(),
     };
     writeln!(w, "Repository information\n")?;
     let mut repo_info = new_table();
@@ -33,10 +33,14 @@ pub(crate) fn display(
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
         .add_row(vec![cell_entry("Local path"), cell_entry(&local_path)])
-        .add_row(vec![cell_entry("Remote url"), cell_entry(&args.url)])
+        .add_row(vec![cell_entry("Remote url"), cell_entry(args.url())])
         .add_row(vec![
             cell_entry("Check sets"),
             cell_entry(&format!("{:?}", args.check_set)),
+        ])
+        .add_row(vec![
+            cell_entry("Provider"),
+            cell_entry(&args.provider().to_string()),
         ]);
     writeln!(w, "{}\n", repo_info)?;
 
@@ -58,7 +62,12 @@ pub(crate) fn display(
             cell_score(score.best_practices),
         ])
         .add_row(vec![cell_entry("Security"), cell_score(score.security)])
-        .add_row(vec![cell_entry("Legal"), cell_score(score.legal)]);
+        .add_row(vec![cell_entry("Legal"), cell_score(score.legal)])
+        .add_row(vec![cell_entry("Artifacts"), cell_score(score.artifacts)])
+        .add_row(vec![
+            cell_entry("Organization"),
+            cell_score(score.organization),
+        ]);
     writeln!(w, "{}\n", score_summary)?;
 
     // Checks table
@@ -68,6 +77,10 @@ pub(crate) fn display(
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
         .set_header(vec![cell_header("Check"), cell_header("Passed")])
+        .add_row(vec![
+            cell_entry("Documentation / Accessibility"),
+            cell_check(&report.documentation.accessibility_statement),
+        ])
         .add_row(vec![
             cell_entry("Documentation / Adopters"),
             cell_check(&report.documentation.adopters),
@@ -92,6 +105,10 @@ pub(crate) fn display(
             cell_entry("Documentation / Maintainers"),
             cell_check(&report.documentation.maintainers),
         ])
+        .add_row(vec![
+            cell_entry("Documentation / Maintainers activity"),
+            cell_check(&report.documentation.maintainers_activity),
+        ])
         .add_row(vec![
             cell_entry("Documentation / Readme"),
             cell_check(&report.documentation.readme),
@@ -120,6 +137,10 @@ pub(crate) fn display(
             cell_entry("License / Approved"),
             cell_check(&report.license.license_approved),
         ])
+        .add_row(vec![
+            cell_entry("License / Compatibility"),
+            cell_check(&report.license.license_compatibility),
+        ])
         .add_row(vec![
             cell_entry("License / Scanning"),
             cell_check(&report.license.license_scanning),
@@ -155,6 +176,10 @@ pub(crate) fn display(
             cell_entry("Best practices / DCO"),
             cell_check(&report.best_practices.dco),
         ])
+        .add_row(vec![
+            cell_entry("Best practices / Dependency updates"),
+            cell_check(&report.best_practices.dependency_update_automation),
+        ])
         .add_row(vec![
             cell_entry("Best practices / GitHub discussions"),
             cell_check(&report.best_practices.github_discussions),
@@ -167,6 +192,10 @@ pub(crate) fn display(
             cell_entry("Best practices / Recent release"),
             cell_check(&report.best_practices.recent_release),
         ])
+        .add_row(vec![
+            cell_entry("Best practices / Release notes"),
+            cell_check(&report.best_practices.release_notes),
+        ])
         .add_row(vec![
             cell_entry("Best practices / Slack presence"),
             cell_check(&report.best_practices.slack_presence),
@@ -175,6 +204,10 @@ pub(crate) fn display(
             cell_entry("Security / Binary artifacts"),
             cell_check(&report.security.binary_artifacts),
         ])
+        .add_row(vec![
+            cell_entry("Security / Branch protection"),
+            cell_check(&report.security.branch_protection),
+        ])
         .add_row(vec![
             cell_entry("Security / Code review"),
             cell_check(&report.security.code_review),
@@ -187,6 +220,10 @@ pub(crate) fn display(
             cell_entry("Security / Dependency update tool"),
             cell_check(&report.security.dependency_update_tool),
         ])
+        .add_row(vec![
+            cell_entry("Security / Fuzzing"),
+            cell_check(&report.security.fuzzing),
+        ])
         .add_row(vec![
             cell_entry("Security / Maintained"),
             cell_check(&report.security.maintained),
@@ -195,6 +232,10 @@ pub(crate) fn display(
             cell_entry("Security / SBOM"),
             cell_check(&report.security.sbom),
         ])
+        .add_row(vec![
+            cell_entry("Security / Security insights"),
+            cell_check(&report.security.security_insights),
+        ])
         .add_row(vec![
             cell_entry("Security / Security policy"),
             cell_check(&report.security.security_policy),
@@ -203,16 +244,74 @@ pub(crate) fn display(
             cell_entry("Security / Signed release"),
             cell_check(&report.security.signed_releases),
         ])
+        .add_row(vec![
+            cell_entry("Security / SLSA provenance"),
+            cell_check(&report.security.slsa_provenance),
+        ])
+        .add_row(vec![
+            cell_entry("Security / Supported versions"),
+            cell_check(&report.security.supported_versions),
+        ])
         .add_row(vec![
             cell_entry("Security / Token permissions"),
             cell_check(&report.security.token_permissions),
         ])
+        .add_row(vec![
+            cell_entry("Security / Vulnerability disclosure"),
+            cell_check(&report.security.vulnerability_disclosure),
+        ])
+        .add_row(vec![
+            cell_entry("Legal / DCO or CLA"),
+            cell_check(&report.legal.dco_or_cla),
+        ])
+        .add_row(vec![
+            cell_entry("Legal / Privacy policy"),
+            cell_check(&report.legal.privacy_policy),
+        ])
         .add_row(vec![
             cell_entry("Legal / Trademark disclaimer"),
             cell_check(&report.legal.trademark_disclaimer),
+        ])
+        .add_row(vec![
+            cell_entry("Artifacts / Image recent rebuild"),
+            cell_check(&report.artifacts.image_recent_rebuild),
+        ])
+        .add_row(vec![
+            cell_entry("Artifacts / Image SBOM"),
+            cell_check(&report.artifacts.image_sbom),
+        ])
+        .add_row(vec![
+            cell_entry("Artifacts / Image signature"),
+            cell_check(&report.artifacts.image_signature),
+        ])
+        .add_row(vec![
+            cell_entry("Organization / Default permissions"),
+            cell_check(&report.organization.org_default_permissions),
+        ])
+        .add_row(vec![
+            cell_entry("Organization / Secret scanning"),
+            cell_check(&report.organization.org_secret_scanning),
+        ])
+        .add_row(vec![
+            cell_entry("Organization / Two-factor auth"),
+            cell_check(&report.organization.org_two_factor_auth),
         ]);
     writeln!(w, "{}\n", checks_summary)?;
 
+    // Checks limited by the provider hosting the repository
+    if !report.provider_limited.is_empty() {
+        writeln!(w, "Checks limited by provider support\n")?;
+        let mut provider_limited = new_table();
+        provider_limited
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![cell_header("Check"), cell_header("Support")]);
+        for (check_id, support) in &report.provider_limited {
+            provider_limited.add_row(vec![cell_entry(check_id), cell_entry(&support.to_string())]);
+        }
+        writeln!(w, "{}\n", provider_limited)?;
+    }
+
     // Check if the linter succeeded according to the provided pass score
     if score.global() >= args.pass_score {
         writeln!(
@@ -228,6 +327,17 @@ pub(crate) fn display(
             args.pass_score
         )?;
     }
+    for (minimum, section_score) in sections_below_minimum(score, &args.fail_below_section) {
+        let section_score = section_score.map_or_else(
+            || NOT_APPLICABLE_MSG.to_string(),
+            |score| score.round().to_string(),
+        );
+        writeln!(
+            w,
+            "{FAILURE_SYMBOL} Failed with a {} score of {section_score} (minimum required is {})\n",
+            minimum.name, minimum.score
+        )?;
+    }
 
     Ok(())
 }
@@ -277,6 +387,7 @@ fn cell_score(score: Option<f64>) -> Cell {
 /// Build a cell used for checks output.
 fn cell_check<T>(output: &Option<CheckOutput<T>>) -> Cell {
     let (content, color) = match output {
+        Some(r) if r.not_applicable => (NOT_APPLICABLE_MSG.to_string(), Color::Grey),
         Some(r) => match (r.passed, r.exempt, r.failed) {
             (true, _, _) => (SUCCESS_SYMBOL.to_string(), Color::Green),
             (false, true, _) => (EXEMPT_MSG.to_string(), Color::Grey),
@@ -297,29 +408,33 @@ mod tests {
     use crate::{Args, Format};
     use clomonitor_core::{
         linter::{
-            BestPractices, CheckOutput, CheckSet, Documentation, Legal, License, Report, Security,
+            Artifacts, BestPractices, CheckOutput, CheckSet, Documentation, Legal, License,
+            Organization, Report, Security,
         },
         score::Score,
     };
-    use std::{fs, path::PathBuf, str, str::FromStr};
+    use std::{collections::BTreeMap, fs, path::PathBuf, str, str::FromStr};
 
     #[test]
     fn display_prints_results() {
         // Setup test linter results
         let report = Report {
             documentation: Documentation {
+                accessibility_statement: Some(CheckOutput::passed()),
                 adopters: Some(CheckOutput::passed()),
                 code_of_conduct: Some(CheckOutput::passed()),
                 contributing: Some(CheckOutput::passed()),
                 changelog: Some(CheckOutput::passed()),
                 governance: Some(CheckOutput::passed()),
                 maintainers: Some(CheckOutput::passed()),
+                maintainers_activity: Some(CheckOutput::passed()),
                 readme: Some(CheckOutput::passed()),
                 roadmap: Some(CheckOutput::passed()),
                 website: Some(CheckOutput::passed()),
             },
             license: License {
                 license_approved: Some(CheckOutput::passed()),
+                license_compatibility: Some(CheckOutput::passed()),
                 license_scanning: Some(
                     CheckOutput::passed().url(Some("https://license-scanning.url".to_string())),
                 ),
@@ -333,29 +448,52 @@ mod tests {
                 cla: Some(CheckOutput::passed()),
                 community_meeting: Some(CheckOutput::passed()),
                 dco: Some(CheckOutput::passed()),
+                dependency_update_automation: Some(CheckOutput::passed()),
                 github_discussions: Some(CheckOutput::passed()),
                 openssf_badge: Some(CheckOutput::passed()),
                 recent_release: Some(CheckOutput::passed()),
+                release_notes: Some(CheckOutput::passed()),
                 slack_presence: Some(CheckOutput::passed()),
             },
             security: Security {
                 binary_artifacts: Some(CheckOutput::passed()),
+                branch_protection: Some(CheckOutput::passed()),
                 code_review: Some(CheckOutput::passed()),
                 dangerous_workflow: Some(CheckOutput::passed()),
                 dependency_update_tool: Some(CheckOutput::passed()),
+                fuzzing: Some(CheckOutput::passed()),
                 maintained: Some(CheckOutput::passed()),
                 sbom: Some(CheckOutput::passed()),
+                security_insights: Some(CheckOutput::passed()),
                 security_policy: Some(CheckOutput::passed()),
                 signed_releases: Some(CheckOutput::passed()),
+                slsa_provenance: Some(CheckOutput::passed()),
+                supported_versions: Some(CheckOutput::passed()),
                 token_permissions: Some(CheckOutput::passed()),
+                vulnerability_disclosure: Some(CheckOutput::passed()),
             },
             legal: Legal {
+                dco_or_cla: Some(CheckOutput::passed()),
+                privacy_policy: Some(CheckOutput::passed()),
                 trademark_disclaimer: Some(CheckOutput::passed()),
             },
+            artifacts: Artifacts {
+                image_recent_rebuild: Some(CheckOutput::passed()),
+                image_sbom: Some(CheckOutput::passed()),
+                image_signature: Some(CheckOutput::passed()),
+            },
+            organization: Organization {
+                org_default_permissions: Some(CheckOutput::passed()),
+                org_secret_scanning: Some(CheckOutput::passed()),
+                org_two_factor_auth: Some(CheckOutput::passed()),
+            },
+            external: BTreeMap::new(),
+            provider_limited: BTreeMap::new(),
+            durations: BTreeMap::new(),
         };
         let score = Score {
             global: 99.99999999999999,
-            global_weight: 90,
+            global_weight: 97,
             documentation: Some(100.0),
             documentation_weight: Some(30),
             license: Some(100.0),
@@ -366,13 +504,28 @@ mod tests {
             security_weight: Some(15),
             legal: Some(100.0),
             legal_weight: Some(5),
+            artifacts: Some(100.0),
+            artifacts_weight: Some(5),
+            organization: Some(100.0),
+            organization_weight: Some(7),
         };
         let args = Args {
-            path: PathBuf::from_str("test-repo-path").unwrap(),
-            url: "https://github.com/test-org/test-repo".to_string(),
+            path: Some(PathBuf::from_str("test-repo-path").unwrap()),
+            command: None,
+            url: Some("https://github.com/test-org/test-repo".to_string()),
             check_set: vec![CheckSet::Code, CheckSet::Community],
+            enable_check: vec![],
+            disable_check: vec![],
+            provider: None,
+            home_url: None,
+            container_image: vec![],
             pass_score: 80.0,
+            fail_below_section: vec![],
             format: Format::Table,
+            cache_dir: None,
+            full_clone: false,
+            history: None,
+            save_report: None,
         };
 
         // Display linter results using a vector as output
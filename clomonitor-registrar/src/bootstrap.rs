@@ -0,0 +1,115 @@
+use crate::{
+    github::{GitHubApi, OrgRepository},
+    registrar::Repository,
+};
+use anyhow::{format_err, Result};
+use serde::Serialize;
+use tracing::{debug, instrument};
+
+/// Header added to the data files generated, as some of the projects'
+/// information cannot be obtained from GitHub.
+const DATA_FILE_HEADER: &str = "\
+# Starter data file generated by clomonitor-registrar bootstrap.
+#
+# Please review it before using it: the category and maturity of each project
+# must be set, and the check sets assigned to the repositories have been
+# guessed from their names.
+";
+
+/// Names of the repositories that usually hold an organization's community
+/// files (i.e. default community health files).
+const COMMUNITY_REPOSITORIES: [&str; 2] = ["community", "governance"];
+
+/// Project entry of the data files generated. Only the fields that can be
+/// obtained from GitHub (or that must be filled in) are included.
+#[derive(Debug, Clone, Serialize)]
+struct Project {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    description: String,
+    category: String,
+    maturity: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_url: Option<String>,
+    repositories: Vec<Repository>,
+}
+
+impl From<OrgRepository> for Project {
+    fn from(repository: OrgRepository) -> Self {
+        let name = project_name(&repository.name);
+        Self {
+            display_name: (name != repository.name).then(|| repository.name.clone()),
+            description: repository
+                .description
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            category: String::new(),
+            maturity: String::new(),
+            home_url: repository.homepage.filter(|url| !url.trim().is_empty()),
+            repositories: vec![Repository {
+                check_sets: guess_check_sets(&repository.name),
+                name: repository.name,
+                url: repository.html_url,
+                checks: None,
+                provider: None,
+                container_images: None,
+                gerrit_url: None,
+                weight: None,
+            }],
+            name,
+        }
+    }
+}
+
+/// Generate a starter data file for the GitHub organization provided. Each of
+/// the organization's public repositories (forks and archived ones excluded)
+/// is added as a project.
+#[instrument(skip(github), err)]
+pub(crate) async fn run(github: &GitHubApi, org: &str) -> Result<String> {
+    let repositories = github.org_repositories(org).await?;
+    debug!(repositories = repositories.len(), "repositories found");
+    data_file(repositories)
+}
+
+/// Build the data file from the organization's repositories provided.
+fn data_file(repositories: Vec<OrgRepository>) -> Result<String> {
+    let projects: Vec<Project> = repositories
+        .into_iter()
+        .filter(|r| !r.fork && !r.archived && r.name != ".github")
+        .map(Project::from)
+        .collect();
+    if projects.is_empty() {
+        return Err(format_err!("no repositories found in organization"));
+    }
+    Ok(format!(
+        "{DATA_FILE_HEADER}\n{}",
+        serde_yaml::to_string(&projects)?
+    ))
+}
+
+/// Build a valid project name from the repository name provided.
+fn project_name(repository_name: &str) -> String {
+    repository_name
+        .to_lowercase()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "-")
+        .trim_matches('-')
+        .to_string()
+}
+
+/// Guess the check sets that should be assigned to a repository from its
+/// name.
+fn guess_check_sets(repository_name: &str) -> Vec<String> {
+    let name = repository_name.to_lowercase();
+    let check_sets: &[&str] = if COMMUNITY_REPOSITORIES.contains(&name.as_str()) {
+        &["community"]
+    } else if name.ends_with(".github.io") || name.contains("website") || name.ends_with("-site") {
+        &["docs-site"]
+    } else if name == "docs" || name.ends_with("-docs") || name.contains("documentation") {
+        &["docs"]
+    } else {
+        &["code", "community"]
+    };
+    check_sets.iter().map(ToString::to_string).collect()
+}
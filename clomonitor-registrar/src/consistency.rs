@@ -0,0 +1,176 @@
+use crate::{
+    db::DynDB,
+    registrar::{fetch_data_files, parse_data_files, DataFileClient, Foundation, Project},
+};
+use anyhow::{Context, Result};
+use config::Config;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info, instrument};
+
+/// Kind of discrepancy found by the consistency checker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum IssueKind {
+    /// Project listed in the data file that is not registered.
+    ProjectNotRegistered,
+    /// Project registered that is no longer listed in the data file.
+    ProjectNotInDataFile,
+    /// Project registered whose digest does not match the data file entry.
+    DigestMismatch,
+    /// Repository registered without a report nor a tracking job pending.
+    RepositoryWithoutReport,
+    /// Report of a repository no longer listed in the data file.
+    OrphanedReport,
+}
+
+/// Discrepancy found between a foundation's data file, the database and the
+/// reports.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct ConsistencyIssue {
+    pub kind: IssueKind,
+    pub foundation_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_url: Option<String>,
+    pub details: String,
+}
+
+/// Repository registered in the database.
+#[derive(Debug, Clone)]
+pub(crate) struct RegisteredRepository {
+    pub project_name: String,
+    pub url: String,
+    pub has_report: bool,
+    pub pending: bool,
+}
+
+/// Check the consistency between the foundations' data files, the projects
+/// and repositories registered in the database and their reports. The issues
+/// registered are replaced with the discrepancies found.
+#[instrument(skip_all, err)]
+pub(crate) async fn run(cfg: &Config, db: DynDB) -> Result<()> {
+    info!("consistency check started");
+
+    let client = DataFileClient::new(cfg)?;
+    let mut issues = vec![];
+    for foundation in db.foundations().await? {
+        let foundation_issues = check_foundation(db.clone(), &client, &foundation)
+            .await
+            .context(format!(
+                "error checking foundation {} consistency",
+                foundation.foundation_id
+            ))?;
+        debug!(
+            "{} issues found in foundation {}",
+            foundation_issues.len(),
+            foundation.foundation_id
+        );
+        issues.extend(foundation_issues);
+    }
+    db.update_consistency_issues(&issues).await?;
+
+    info!("consistency check finished ({} issues found)", issues.len());
+    Ok(())
+}
+
+/// Check the consistency of the foundation provided.
+async fn check_foundation(
+    db: DynDB,
+    client: &DataFileClient,
+    foundation: &Foundation,
+) -> Result<Vec<ConsistencyIssue>> {
+    let data_files = fetch_data_files(client, &foundation.resolved_data_url()?).await?;
+    let (projects_available, projects_skipped) = parse_data_files(&data_files)?;
+    let projects_skipped: HashSet<String> = projects_skipped
+        .into_iter()
+        .filter_map(|project| project.name)
+        .collect();
+    let projects_registered = db.foundation_projects(&foundation.foundation_id).await?;
+    let repositories_registered = db
+        .foundation_repositories(&foundation.foundation_id)
+        .await?;
+
+    Ok(find_issues(
+        &foundation.foundation_id,
+        &projects_available,
+        &projects_skipped,
+        &projects_registered,
+        &repositories_registered,
+    ))
+}
+
+/// Find the discrepancies between the projects available in the data file
+/// and the projects and repositories registered in the database.
+fn find_issues(
+    foundation_id: &str,
+    projects_available: &HashMap<String, Project>,
+    projects_skipped: &HashSet<String>,
+    projects_registered: &HashMap<String, Option<String>>,
+    repositories_registered: &[RegisteredRepository],
+) -> Vec<ConsistencyIssue> {
+    let issue =
+        |kind, project_name: &str, repository_url: Option<&str>, details: &str| ConsistencyIssue {
+            kind,
+            foundation_id: foundation_id.to_string(),
+            project_name: Some(project_name.to_string()),
+            repository_url: repository_url.map(ToString::to_string),
+            details: details.to_string(),
+        };
+    let mut issues = vec![];
+
+    // Projects
+    for (name, project) in projects_available {
+        match projects_registered.get(name) {
+            None => issues.push(issue(
+                IssueKind::ProjectNotRegistered,
+                name,
+                None,
+                "project listed in the data file is not registered",
+            )),
+            Some(digest) if digest != &project.digest => issues.push(issue(
+                IssueKind::DigestMismatch,
+                name,
+                None,
+                "project registered does not match the data file entry",
+            )),
+            Some(_) => {}
+        }
+    }
+    for name in projects_registered.keys() {
+        if !projects_available.contains_key(name) && !projects_skipped.contains(name) {
+            issues.push(issue(
+                IssueKind::ProjectNotInDataFile,
+                name,
+                None,
+                "project registered is no longer listed in the data file",
+            ));
+        }
+    }
+
+    // Repositories
+    for repository in repositories_registered {
+        let listed = projects_available
+            .get(&repository.project_name)
+            .map(|project| project.repositories.iter().any(|r| r.url == repository.url));
+        if listed == Some(false) && repository.has_report {
+            issues.push(issue(
+                IssueKind::OrphanedReport,
+                &repository.project_name,
+                Some(&repository.url),
+                "report found for a repository no longer listed in the data file",
+            ));
+        }
+        if !repository.has_report && !repository.pending {
+            issues.push(issue(
+                IssueKind::RepositoryWithoutReport,
+                &repository.project_name,
+                Some(&repository.url),
+                "repository has no report and is not pending in any tracker run",
+            ));
+        }
+    }
+
+    issues
+}
@@ -1,8 +1,13 @@
-use crate::registrar::{Foundation, Project};
+use crate::{
+    consistency::{ConsistencyIssue, RegisteredRepository},
+    registrar::{Foundation, MaturityGates, Project, Weights},
+    reviews::ProjectDueForReview,
+};
 use anyhow::Result;
 use async_trait::async_trait;
 use deadpool_postgres::Pool;
 use std::{collections::HashMap, sync::Arc};
+use time::OffsetDateTime;
 use tokio_postgres::types::Json;
 
 /// Type alias to represent a DB trait object.
@@ -11,20 +16,78 @@ pub(crate) type DynDB = Arc<dyn DB + Send + Sync>;
 /// Trait that defines some operations a DB implementation must support.
 #[async_trait]
 pub(crate) trait DB {
+    /// Clear the registration request of the foundation provided, unless it
+    /// has been requested again after the time provided.
+    async fn clear_registration_request(
+        &self,
+        foundation_id: &str,
+        requested_at: &OffsetDateTime,
+    ) -> Result<()>;
+
     /// Get foundations registered in the database.
     async fn foundations(&self) -> Result<Vec<Foundation>>;
 
+    /// Get the projects registered for the foundation provided in data file
+    /// format (json encoded). Returns None if the foundation was not found.
+    async fn foundation_data_file(&self, foundation_id: &str) -> Result<Option<String>>;
+
     /// Get projects for the foundation provided.
     async fn foundation_projects(
         &self,
         foundation_id: &str,
     ) -> Result<HashMap<String, Option<String>>>;
 
+    /// Get the repositories registered for the foundation provided.
+    async fn foundation_repositories(
+        &self,
+        foundation_id: &str,
+    ) -> Result<Vec<RegisteredRepository>>;
+
+    /// Get the projects whose annual review is due within the number of
+    /// weeks provided and whose contact has not been reminded yet.
+    async fn projects_due_for_review(&self, weeks_before: i32) -> Result<Vec<ProjectDueForReview>>;
+
     /// Register project provided in the database.
     async fn register_project(&self, foundation_id: &str, project: &Project) -> Result<()>;
 
+    /// Rename the project provided, keeping its history. Returns false if the
+    /// project could not be renamed (i.e. the new name is already in use).
+    async fn rename_project(
+        &self,
+        foundation_id: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<bool>;
+
+    /// Get the foundations whose registration has been requested on demand.
+    /// Requests are not cleared until the foundations have been processed.
+    async fn requested_foundations(&self) -> Result<Vec<Foundation>>;
+
     /// Unregister project provided from the database.
     async fn unregister_project(&self, foundation_id: &str, project_name: &str) -> Result<()>;
+
+    /// Replace the consistency issues registered with the ones provided.
+    async fn update_consistency_issues(&self, issues: &[ConsistencyIssue]) -> Result<()>;
+
+    /// Replace the maturity gates of the foundation provided.
+    async fn update_foundation_maturity_gates(
+        &self,
+        foundation_id: &str,
+        gates: &MaturityGates,
+    ) -> Result<()>;
+
+    /// Replace the score weights overrides of the foundation provided.
+    async fn update_foundation_weights(&self, foundation_id: &str, weights: &Weights)
+        -> Result<()>;
+
+    /// Register that the project's contact has been reminded about the
+    /// annual review due on the date provided.
+    async fn update_last_reminded_review(
+        &self,
+        foundation_id: &str,
+        project_name: &str,
+        review_due_at: &str,
+    ) -> Result<()>;
 }
 
 /// DB implementation backed by PostgreSQL.
@@ -41,20 +104,56 @@ impl PgDB {
 
 #[async_trait]
 impl DB for PgDB {
+    async fn clear_registration_request(
+        &self,
+        foundation_id: &str,
+        requested_at: &OffsetDateTime,
+    ) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            update foundation set registration_requested_at = null
+            where foundation_id = $1::text
+            and registration_requested_at <= $2::timestamptz;
+            ",
+            &[&foundation_id, &requested_at],
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn foundations(&self) -> Result<Vec<Foundation>> {
         let db = self.pool.get().await?;
         let foundations = db
-            .query("select foundation_id, data_url from foundation", &[])
+            .query(
+                "select foundation_id, data_url, data_ref, contact_email from foundation",
+                &[],
+            )
             .await?
             .iter()
             .map(|row| Foundation {
                 foundation_id: row.get("foundation_id"),
                 data_url: row.get("data_url"),
+                data_ref: row.get("data_ref"),
+                contact_email: row.get("contact_email"),
+                registration_requested_at: None,
             })
             .collect();
         Ok(foundations)
     }
 
+    async fn foundation_data_file(&self, foundation_id: &str) -> Result<Option<String>> {
+        let db = self.pool.get().await?;
+        let data_file = db
+            .query_one(
+                "select get_foundation_data_file($1::text)::text",
+                &[&foundation_id],
+            )
+            .await?
+            .get(0);
+        Ok(data_file)
+    }
+
     async fn foundation_projects(
         &self,
         foundation_id: &str,
@@ -72,6 +171,63 @@ impl DB for PgDB {
         Ok(projects)
     }
 
+    async fn foundation_repositories(
+        &self,
+        foundation_id: &str,
+    ) -> Result<Vec<RegisteredRepository>> {
+        let db = self.pool.get().await?;
+        let repositories = db
+            .query(
+                "
+                select
+                    p.name as project_name,
+                    r.url,
+                    exists (
+                        select 1 from report where repository_id = r.repository_id
+                    ) as has_report,
+                    (
+                        exists (
+                            select 1
+                            from tracker_run_repository q
+                            join tracker_run t using (tracker_run_id)
+                            where q.repository_id = r.repository_id
+                            and q.status <> 'done'
+                            and t.finished_at is null
+                        )
+                        or r.created_at > (
+                            select coalesce(max(started_at), '-infinity') from tracker_run
+                        )
+                    ) as pending
+                from repository r
+                join project p using (project_id)
+                where p.foundation_id = $1::text
+                ",
+                &[&foundation_id],
+            )
+            .await?
+            .iter()
+            .map(|row| RegisteredRepository {
+                project_name: row.get("project_name"),
+                url: row.get("url"),
+                has_report: row.get("has_report"),
+                pending: row.get("pending"),
+            })
+            .collect();
+        Ok(repositories)
+    }
+
+    async fn projects_due_for_review(&self, weeks_before: i32) -> Result<Vec<ProjectDueForReview>> {
+        let db = self.pool.get().await?;
+        let Json(projects) = db
+            .query_one(
+                "select get_projects_due_for_review($1::int)::jsonb",
+                &[&weeks_before],
+            )
+            .await?
+            .get::<_, Json<Vec<ProjectDueForReview>>>(0);
+        Ok(projects)
+    }
+
     async fn register_project(&self, foundation_id: &str, project: &Project) -> Result<()> {
         let db = self.pool.get().await?;
         db.execute(
@@ -82,6 +238,52 @@ impl DB for PgDB {
         Ok(())
     }
 
+    async fn rename_project(
+        &self,
+        foundation_id: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<bool> {
+        let db = self.pool.get().await?;
+        let renamed = db
+            .query_one(
+                "select rename_project($1::text, $2::text, $3::text)",
+                &[&foundation_id, &old_name, &new_name],
+            )
+            .await?
+            .get(0);
+        Ok(renamed)
+    }
+
+    async fn requested_foundations(&self) -> Result<Vec<Foundation>> {
+        let db = self.pool.get().await?;
+        let foundations = db
+            .query(
+                "
+                select
+                    foundation_id,
+                    data_url,
+                    data_ref,
+                    contact_email,
+                    registration_requested_at
+                from foundation
+                where registration_requested_at is not null;
+                ",
+                &[],
+            )
+            .await?
+            .iter()
+            .map(|row| Foundation {
+                foundation_id: row.get("foundation_id"),
+                data_url: row.get("data_url"),
+                data_ref: row.get("data_ref"),
+                contact_email: row.get("contact_email"),
+                registration_requested_at: row.get("registration_requested_at"),
+            })
+            .collect();
+        Ok(foundations)
+    }
+
     async fn unregister_project(&self, foundation_id: &str, project_name: &str) -> Result<()> {
         let db = self.pool.get().await?;
         db.execute(
@@ -91,4 +293,61 @@ impl DB for PgDB {
         .await?;
         Ok(())
     }
+
+    async fn update_consistency_issues(&self, issues: &[ConsistencyIssue]) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_consistency_issues($1::jsonb)",
+            &[&Json(issues)],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_foundation_maturity_gates(
+        &self,
+        foundation_id: &str,
+        gates: &MaturityGates,
+    ) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_foundation_maturity_gates($1::text, $2::jsonb)",
+            &[&foundation_id, &Json(gates)],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_foundation_weights(
+        &self,
+        foundation_id: &str,
+        weights: &Weights,
+    ) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_foundation_weights($1::text, $2::jsonb)",
+            &[&foundation_id, &Json(weights)],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_last_reminded_review(
+        &self,
+        foundation_id: &str,
+        project_name: &str,
+        review_due_at: &str,
+    ) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            update project set last_reminded_review_due_at = $3::text::date
+            where foundation_id = $1::text
+            and name = $2::text
+            ",
+            &[&foundation_id, &project_name, &review_due_at],
+        )
+        .await?;
+        Ok(())
+    }
 }
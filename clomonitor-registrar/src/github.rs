@@ -0,0 +1,278 @@
+use crate::registrar::FoundationReport;
+use anyhow::{format_err, Result};
+use async_trait::async_trait;
+use clomonitor_core::net;
+use config::Config;
+use http::{
+    header::{ACCEPT, AUTHORIZATION, USER_AGENT},
+    StatusCode,
+};
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+
+/// GitHub API base url.
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Number of repositories requested per page when listing an organization's
+/// repositories.
+const REPOSITORIES_PER_PAGE: usize = 100;
+
+/// Context used for the commit statuses published by the registrar.
+const STATUS_CONTEXT: &str = "clomonitor/registrar";
+
+/// Maximum length of a commit status description.
+const STATUS_DESCRIPTION_MAX_LEN: usize = 140;
+
+/// Type alias to represent a GitHub trait object.
+pub(crate) type DynGitHub = Arc<dyn GitHub + Send + Sync>;
+
+/// Trait that defines some operations a GitHub implementation must support.
+#[async_trait]
+pub(crate) trait GitHub {
+    /// Get the sha of the commit the data file reference provided points to.
+    async fn commit_sha(&self, data_file: &DataFileRef) -> Result<String>;
+
+    /// Publish a summary of the foundation report provided as a status of the
+    /// data file commit processed. When some problems were found, the full
+    /// report is also posted as a comment on the commit. Reports are only
+    /// published the first time a commit is processed.
+    async fn publish_report(
+        &self,
+        commit: &DataFileCommit,
+        report: &FoundationReport,
+    ) -> Result<()>;
+}
+
+/// Reference to a data file hosted in a GitHub repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DataFileRef {
+    pub owner: String,
+    pub repo: String,
+    pub git_ref: String,
+    url: Url,
+    ref_segment: usize,
+}
+
+impl DataFileRef {
+    /// Create a data file reference from the url provided when it points to a
+    /// file in a GitHub repository.
+    pub(crate) fn from_url(data_url: &str) -> Option<Self> {
+        let url = Url::parse(data_url).ok()?;
+        let segments: Vec<String> = url.path_segments()?.map(ToString::to_string).collect();
+        let ref_segment = match (url.host_str()?, segments.as_slice()) {
+            ("raw.githubusercontent.com", [_, _, _, _, ..]) => 2,
+            ("github.com", [_, _, raw, _, _, ..]) if raw == "raw" => 3,
+            _ => return None,
+        };
+        Some(Self {
+            owner: segments[0].clone(),
+            repo: segments[1].clone(),
+            git_ref: segments[ref_segment].clone(),
+            url,
+            ref_segment,
+        })
+    }
+
+    /// Return the data file url pinned to the commit provided.
+    pub(crate) fn pinned_url(&self, sha: &str) -> String {
+        let mut url = self.url.clone();
+        let segments: Vec<String> = self
+            .url
+            .path_segments()
+            .expect("url to have path segments")
+            .enumerate()
+            .map(|(i, segment)| {
+                if i == self.ref_segment {
+                    sha.to_string()
+                } else {
+                    segment.to_string()
+                }
+            })
+            .collect();
+        url.set_path(&segments.join("/"));
+        url.to_string()
+    }
+}
+
+/// Data file commit processed by the registrar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DataFileCommit {
+    pub data_file: DataFileRef,
+    pub sha: String,
+}
+
+/// Combined status of a commit (only the fields used are included).
+#[derive(Debug, Deserialize)]
+struct CombinedStatus {
+    statuses: Vec<Status>,
+}
+
+/// Commit status.
+#[derive(Debug, Deserialize)]
+struct Status {
+    context: String,
+}
+
+/// Repository of a GitHub organization (only the fields used are included).
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct OrgRepository {
+    pub name: String,
+    pub description: Option<String>,
+    pub html_url: String,
+    pub homepage: Option<String>,
+    pub fork: bool,
+    pub archived: bool,
+}
+
+/// GitHub implementation backed by the GitHub REST API.
+pub(crate) struct GitHubApi {
+    http_client: reqwest::Client,
+    token: String,
+}
+
+impl GitHubApi {
+    /// Create a new GitHubApi instance.
+    pub(crate) fn new(cfg: &Config) -> Result<Self> {
+        Self::with_token(&cfg.get_string("creds.githubToken")?)
+    }
+
+    /// Create a new GitHubApi instance that will use the token provided.
+    pub(crate) fn with_token(token: &str) -> Result<Self> {
+        Ok(Self {
+            http_client: net::client_builder().build()?,
+            token: token.to_string(),
+        })
+    }
+
+    /// Prepare a request to the GitHub API endpoint provided.
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        self.http_client
+            .request(method, format!("{GITHUB_API_URL}{path}"))
+            .header(AUTHORIZATION, format!("Bearer {}", self.token))
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, "clomonitor-registrar")
+    }
+
+    /// Get the public repositories of the GitHub organization provided,
+    /// sorted by name.
+    pub(crate) async fn org_repositories(&self, org: &str) -> Result<Vec<OrgRepository>> {
+        let mut repositories = vec![];
+        for page in 1.. {
+            let request = self.request(
+                reqwest::Method::GET,
+                &format!(
+                    "/orgs/{org}/repos?type=public&sort=full_name&per_page={REPOSITORIES_PER_PAGE}&page={page}"
+                ),
+            );
+            let page: Vec<OrgRepository> = serde_json::from_str(&Self::send(request).await?)?;
+            let last_page = page.len() < REPOSITORIES_PER_PAGE;
+            repositories.extend(page);
+            if last_page {
+                break;
+            }
+        }
+        Ok(repositories)
+    }
+
+    /// Send the request provided, returning the response body if successful.
+    async fn send(request: reqwest::RequestBuilder) -> Result<String> {
+        let resp = request.send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(format_err!(
+                "unexpected status code from GitHub API: {status}"
+            ));
+        }
+        Ok(resp.text().await?)
+    }
+}
+
+#[async_trait]
+impl GitHub for GitHubApi {
+    async fn commit_sha(&self, data_file: &DataFileRef) -> Result<String> {
+        let DataFileRef {
+            owner,
+            repo,
+            git_ref,
+            ..
+        } = data_file;
+        let request = self
+            .request(
+                reqwest::Method::GET,
+                &format!("/repos/{owner}/{repo}/commits/{git_ref}"),
+            )
+            .header(ACCEPT, "application/vnd.github.sha");
+        let resp = request.send().await?;
+        if resp.status() != StatusCode::OK {
+            return Err(format_err!(
+                "unexpected status code getting commit sha: {}",
+                resp.status()
+            ));
+        }
+        Ok(resp.text().await?.trim().to_string())
+    }
+
+    async fn publish_report(
+        &self,
+        commit: &DataFileCommit,
+        report: &FoundationReport,
+    ) -> Result<()> {
+        let DataFileCommit { data_file, sha } = commit;
+        let repo_path = format!("/repos/{}/{}", data_file.owner, data_file.repo);
+
+        // Skip commits whose report has already been published
+        let combined_status: CombinedStatus = serde_json::from_str(
+            &Self::send(self.request(
+                reqwest::Method::GET,
+                &format!("{repo_path}/commits/{sha}/status"),
+            ))
+            .await?,
+        )?;
+        if combined_status
+            .statuses
+            .iter()
+            .any(|status| status.context == STATUS_CONTEXT)
+        {
+            return Ok(());
+        }
+
+        // Publish commit status
+        let state = if report.is_empty() {
+            "success"
+        } else {
+            "failure"
+        };
+        let mut description = report.summary();
+        description.truncate(STATUS_DESCRIPTION_MAX_LEN);
+        let body = json!({
+            "state": state,
+            "description": description,
+            "context": STATUS_CONTEXT,
+        });
+        Self::send(
+            self.request(
+                reqwest::Method::POST,
+                &format!("{repo_path}/statuses/{sha}"),
+            )
+            .body(body.to_string()),
+        )
+        .await?;
+
+        // Post full report as a commit comment when problems were found
+        if !report.is_empty() {
+            let body = json!({ "body": report.to_string() });
+            Self::send(
+                self.request(
+                    reqwest::Method::POST,
+                    &format!("{repo_path}/commits/{sha}/comments"),
+                )
+                .body(body.to_string()),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,35 +1,132 @@
-use crate::db::PgDB;
-use anyhow::{Context, Result};
-use clap::Parser;
+use crate::{
+    db::PgDB,
+    github::{DynGitHub, GitHubApi},
+    notifier::{DynNotifier, EmailNotifier},
+};
+use anyhow::{format_err, Context, Result};
+use clap::{Parser, Subcommand};
+use clomonitor_core::net;
 use config::{Config, File};
 use deadpool_postgres::{Config as DbConfig, Runtime};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
-use std::{path::PathBuf, sync::Arc};
-use tracing::debug;
+use std::{env, fs, path::PathBuf, sync::Arc};
+use tracing::{debug, info_span, Instrument};
 use tracing_subscriber::EnvFilter;
+use uuid::Uuid;
 
+mod bootstrap;
+mod consistency;
 mod db;
+mod github;
+mod notifier;
 mod registrar;
+mod reviews;
+
+/// Environment variable containing the GitHub token used by the bootstrap
+/// subcommand.
+const GITHUB_TOKEN: &str = "GITHUB_TOKEN";
 
 #[derive(Debug, Parser)]
-#[clap(author, version, about)]
+#[clap(
+    author,
+    version,
+    about,
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true
+)]
 struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Config file path
+    #[clap(short, long, required = true)]
+    config: Option<PathBuf>,
+
+    /// Export the projects registered for the foundation provided as a data
+    /// file instead of processing the foundations' data files
+    #[clap(long, requires = "output")]
+    export: Option<String>,
+
+    /// Path where the exported data file will be written
+    #[clap(short, long, requires = "export")]
+    output: Option<PathBuf>,
+
+    /// Check the consistency between the foundations' data files, the
+    /// database and the reports instead of processing the data files
+    #[clap(long, conflicts_with = "export")]
+    check_consistency: bool,
+
+    /// Remind the contacts of the projects whose annual review is due soon
+    /// instead of processing the data files
+    #[clap(long, conflicts_with_all = ["export", "check_consistency"])]
+    send_review_reminders: bool,
+
+    /// Only process the data files of the foundations whose registration has
+    /// been requested on demand using the admin API
+    #[clap(
+        long,
+        conflicts_with_all = ["export", "check_consistency", "send_review_reminders"]
+    )]
+    on_demand: bool,
+}
+
+/// Registrar subcommands.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate a starter foundation data file from the public repositories
+    /// of a GitHub organization (requires a GitHub token in the GITHUB_TOKEN
+    /// environment variable)
+    Bootstrap(BootstrapArgs),
+}
+
+/// Arguments of the bootstrap subcommand.
+#[derive(Debug, clap::Args)]
+struct BootstrapArgs {
+    /// GitHub organization
+    #[clap(long)]
+    org: String,
+
+    /// Path where the data file will be written (printed to stdout when not
+    /// provided)
     #[clap(short, long)]
-    config: PathBuf,
+    output: Option<PathBuf>,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
 
+    // Generate a starter data file when running the bootstrap subcommand
+    if let Some(Command::Bootstrap(bootstrap_args)) = &args.command {
+        let token =
+            env::var(GITHUB_TOKEN).context(format!("{GITHUB_TOKEN} not found in environment"))?;
+        let github = GitHubApi::with_token(&token)?;
+        let data_file = bootstrap::run(&github, &bootstrap_args.org).await?;
+        match &bootstrap_args.output {
+            Some(output) => fs::write(output, data_file)
+                .context(format!("error writing data file to {}", output.display()))?,
+            None => print!("{data_file}"),
+        }
+        return Ok(());
+    }
+
     // Setup configuration
+    let config = args.config.context("config file not provided")?;
     let cfg = Config::builder()
-        .add_source(File::from(args.config))
+        .set_default("email.smtp.port", 587)?
+        .set_default("registrar.reviewReminders.weeksBefore", 4)?
+        .add_source(File::from(config))
         .build()
         .context("error setting up configuration")?;
 
+    // Setup outbound HTTP proxy (if any)
+    net::setup_proxy(
+        cfg.get_string("http.proxy").ok().as_deref(),
+        cfg.get_string("http.noProxy").ok().as_deref(),
+    )
+    .context("error setting up outbound http proxy")?;
+
     // Setup logging
     if std::env::var_os("RUST_LOG").is_none() {
         std::env::set_var("RUST_LOG", "clomonitor_registrar=debug")
@@ -37,9 +134,15 @@ async fn main() -> Result<()> {
     let s = tracing_subscriber::fmt().with_env_filter(EnvFilter::from_default_env());
     match cfg.get_string("log.format").as_deref() {
         Ok("json") => s.json().init(),
-        _ => s.init(),
+        Ok("pretty") | Err(_) => s.init(),
+        Ok(format) => return Err(format_err!("invalid log format: {format} (log.format)")),
     };
 
+    // All the events logged by this run are recorded within a span identified
+    // by its id, so that they can be correlated
+    let run_id = Uuid::new_v4();
+    let run_span = info_span!("run", %run_id);
+
     // Setup database
     debug!("setting up database");
     let mut builder = SslConnector::builder(SslMethod::tls())?;
@@ -49,8 +152,49 @@ async fn main() -> Result<()> {
     let pool = db_cfg.create_pool(Some(Runtime::Tokio1), connector)?;
     let db = Arc::new(PgDB::new(pool));
 
+    // Export foundation's data file if requested
+    if let (Some(foundation_id), Some(output)) = (args.export, args.output) {
+        let data_file = registrar::export_data_file(db, &foundation_id).await?;
+        fs::write(&output, data_file)
+            .context(format!("error writing data file to {}", output.display()))?;
+        return Ok(());
+    }
+
+    // Check consistency if requested
+    if args.check_consistency {
+        return consistency::run(&cfg, db).instrument(run_span).await;
+    }
+
+    // Setup notifier (foundations reports are only sent when an SMTP server
+    // has been configured)
+    let notifier: Option<DynNotifier> = match cfg.get_string("email.smtp.host") {
+        Ok(_) => {
+            debug!("setting up email notifier");
+            Some(Arc::new(EmailNotifier::new(&cfg)?))
+        }
+        Err(_) => None,
+    };
+
+    // Send review reminders if requested (an SMTP server is required)
+    if args.send_review_reminders {
+        let notifier = notifier.context("review reminders require an SMTP server")?;
+        return reviews::run(&cfg, db, notifier).instrument(run_span).await;
+    }
+
+    // Setup GitHub client (reports summaries are only published on the data
+    // files commits when a GitHub token has been configured)
+    let github: Option<DynGitHub> = match cfg.get_string("creds.githubToken") {
+        Ok(_) => {
+            debug!("setting up github client");
+            Some(Arc::new(GitHubApi::new(&cfg)?))
+        }
+        Err(_) => None,
+    };
+
     // Run registrar
-    registrar::run(&cfg, db).await?;
+    registrar::run(&cfg, db, notifier, github, args.on_demand)
+        .instrument(run_span)
+        .await?;
 
     Ok(())
 }
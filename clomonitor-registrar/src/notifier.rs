@@ -0,0 +1,83 @@
+use crate::{registrar::FoundationReport, reviews::ReviewReminder};
+use anyhow::Result;
+use async_trait::async_trait;
+use config::Config;
+use lettre::{
+    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+use std::sync::Arc;
+
+/// Type alias to represent a Notifier trait object.
+pub(crate) type DynNotifier = Arc<dyn Notifier + Send + Sync>;
+
+/// Trait that defines some operations a Notifier implementation must support.
+#[async_trait]
+pub(crate) trait Notifier {
+    /// Send the foundation report provided to the recipient given.
+    async fn send_foundation_report(
+        &self,
+        recipient: &str,
+        report: &FoundationReport,
+    ) -> Result<()>;
+
+    /// Send the annual review reminder provided to the recipient given.
+    async fn send_review_reminder(&self, recipient: &str, reminder: &ReviewReminder) -> Result<()>;
+}
+
+/// Notifier implementation that delivers notifications by email.
+pub(crate) struct EmailNotifier {
+    from: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl EmailNotifier {
+    /// Create a new EmailNotifier instance.
+    pub(crate) fn new(cfg: &Config) -> Result<Self> {
+        let from = cfg.get_string("email.from")?.parse()?;
+        let mut builder =
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&cfg.get_string("email.smtp.host")?)?
+                .port(cfg.get("email.smtp.port")?);
+        if let Ok(username) = cfg.get_string("email.smtp.username") {
+            let password = cfg.get_string("email.smtp.password")?;
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+        Ok(Self {
+            from,
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+    async fn send_foundation_report(
+        &self,
+        recipient: &str,
+        report: &FoundationReport,
+    ) -> Result<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(recipient.parse()?)
+            .subject(format!(
+                "CLOMonitor registrar report: {}",
+                report.foundation_id
+            ))
+            .body(report.to_string())?;
+        self.transport.send(message).await?;
+        Ok(())
+    }
+
+    async fn send_review_reminder(&self, recipient: &str, reminder: &ReviewReminder) -> Result<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(recipient.parse()?)
+            .subject(format!(
+                "CLOMonitor annual review reminder: {}",
+                reminder.project.display_name
+            ))
+            .body(reminder.to_string())?;
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
@@ -1,22 +1,212 @@
-use crate::db::DynDB;
+use crate::{
+    db::DynDB,
+    github::{DataFileCommit, DataFileRef, DynGitHub},
+    notifier::DynNotifier,
+};
 use anyhow::{format_err, Context, Error, Result};
-use config::Config;
+use clomonitor_core::net::{HostPolicy, RestrictedClient};
+use config::{Config, ConfigError};
 use futures::stream::{self, StreamExt};
 use http::StatusCode;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    time::Duration,
+};
+use time::OffsetDateTime;
 use tokio::time::{timeout, Instant};
 use tracing::{debug, error, info, instrument};
 
 /// Maximum time that can take processing a foundation data file.
 const FOUNDATION_TIMEOUT: u64 = 300;
 
+/// Check sets that can be assigned to a repository.
+const VALID_CHECK_SETS: [&str; 5] = ["code", "code-lite", "community", "docs", "docs-site"];
+
+/// Providers that can host a repository.
+const VALID_PROVIDERS: [&str; 3] = ["github", "gitlab", "gitea"];
+
+/// Checks that can be enabled or disabled in a repository.
+const VALID_CHECKS: [&str; 50] = [
+    "accessibility_statement",
+    "adopters",
+    "analytics",
+    "artifacthub_badge",
+    "binary_artifacts",
+    "branch_protection",
+    "changelog",
+    "cla",
+    "code_of_conduct",
+    "code_review",
+    "community_meeting",
+    "contributing",
+    "dangerous_workflow",
+    "dco",
+    "dco_or_cla",
+    "dependency_update_automation",
+    "dependency_update_tool",
+    "fuzzing",
+    "github_discussions",
+    "governance",
+    "image_recent_rebuild",
+    "image_sbom",
+    "image_signature",
+    "license_approved",
+    "license_compatibility",
+    "license_scanning",
+    "license_spdx_id",
+    "maintained",
+    "maintainers",
+    "maintainers_activity",
+    "openssf_badge",
+    "org_default_permissions",
+    "org_secret_scanning",
+    "org_two_factor_auth",
+    "privacy_policy",
+    "readme",
+    "recent_release",
+    "release_notes",
+    "roadmap",
+    "sbom",
+    "security_insights",
+    "security_policy",
+    "signed_releases",
+    "slsa_provenance",
+    "slack_presence",
+    "supported_versions",
+    "token_permissions",
+    "trademark_disclaimer",
+    "vulnerability_disclosure",
+    "website",
+];
+
+/// Maturity levels maturity gates can be defined for.
+const VALID_MATURITY_LEVELS: [&str; 3] = ["sandbox", "incubating", "graduated"];
+
+/// Report sections whose weight can be overridden.
+const VALID_SECTIONS: [&str; 7] = [
+    "documentation",
+    "license",
+    "best_practices",
+    "security",
+    "legal",
+    "artifacts",
+    "organization",
+];
+
+/// Severities checks can be assigned.
+const VALID_SEVERITIES: [&str; 3] = ["required", "recommended", "optional"];
+
+/// Placeholder that can be used in the foundation's data url to reference the
+/// data ref pinned (i.e. a git tag).
+const DATA_REF_PLACEHOLDER: &str = "{ref}";
+
 /// Represents a foundation registered in the database.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Foundation {
     pub foundation_id: String,
     pub data_url: String,
+    pub data_ref: Option<String>,
+    pub contact_email: Option<String>,
+    #[serde(skip)]
+    pub registration_requested_at: Option<OffsetDateTime>,
+}
+
+impl Foundation {
+    /// Return the url of the foundation's data file, replacing the ref
+    /// placeholder with the data ref pinned when present.
+    pub(crate) fn resolved_data_url(&self) -> Result<String> {
+        if !self.data_url.contains(DATA_REF_PLACEHOLDER) {
+            return Ok(self.data_url.clone());
+        }
+        match self.data_ref.as_deref() {
+            Some(data_ref) if !data_ref.is_empty() => {
+                Ok(self.data_url.replace(DATA_REF_PLACEHOLDER, data_ref))
+            }
+            _ => Err(format_err!(
+                "data url contains a ref placeholder but no data ref has been set"
+            )),
+        }
+    }
+}
+
+/// Score weights overrides defined for a foundation in the config file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Weights {
+    #[serde(default)]
+    pub checks: HashMap<String, usize>,
+
+    #[serde(default)]
+    pub sections: HashMap<String, usize>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub severities: HashMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub severity_multipliers: HashMap<String, usize>,
+}
+
+impl Weights {
+    /// Check that the sections and severities overridden are valid.
+    fn validate(&self) -> Result<()> {
+        for section in self.sections.keys() {
+            if !VALID_SECTIONS.contains(&section.as_str()) {
+                return Err(format_err!("invalid section: {section}"));
+            }
+        }
+        for severity in self
+            .severities
+            .values()
+            .chain(self.severity_multipliers.keys())
+        {
+            if !VALID_SEVERITIES.contains(&severity.as_str()) {
+                return Err(format_err!("invalid severity: {severity}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Maturity gates defined for a foundation in the config file, grouped by
+/// maturity level.
+pub(crate) type MaturityGates = HashMap<String, Vec<MaturityGate>>;
+
+/// Maturity-level requirement (i.e. a graduation criterion) mapped to the
+/// checks that must pass on the project's repositories to meet it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct MaturityGate {
+    pub name: String,
+    pub checks: Vec<String>,
+}
+
+/// Check that the maturity levels and checks of the gates provided are valid.
+fn validate_maturity_gates(gates: &MaturityGates) -> Result<()> {
+    for (maturity, gates) in gates {
+        if !VALID_MATURITY_LEVELS.contains(&maturity.as_str()) {
+            return Err(format_err!("invalid maturity level: {maturity}"));
+        }
+        for gate in gates {
+            if gate.name.trim().is_empty() {
+                return Err(format_err!("name not provided for {maturity} gate"));
+            }
+            if gate.checks.is_empty() {
+                return Err(format_err!("no checks provided for gate {}", gate.name));
+            }
+            for check_id in &gate.checks {
+                if !VALID_CHECKS.contains(&check_id.as_str()) {
+                    return Err(format_err!(
+                        "invalid check {} in gate {}",
+                        check_id,
+                        gate.name
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
 /// Represents a project to be registered or updated.
@@ -32,11 +222,108 @@ pub(crate) struct Project {
     pub devstats_url: Option<String>,
     pub accepted_at: Option<String>,
     pub maturity: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annual_review_due_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_names: Option<Vec<String>>,
     pub digest: Option<String>,
     pub repositories: Vec<Repository>,
 }
 
 impl Project {
+    /// Check that the project contains the information required to register
+    /// it.
+    fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(format_err!("name not provided"));
+        }
+        if self.repositories.is_empty() {
+            return Err(format_err!("no repositories provided"));
+        }
+        for previous_name in self.previous_names.iter().flatten() {
+            if previous_name.trim().is_empty() || previous_name == &self.name {
+                return Err(format_err!("invalid previous name {}", previous_name));
+            }
+        }
+        for repository in &self.repositories {
+            if repository.url.trim().is_empty() {
+                return Err(format_err!(
+                    "url not provided for repository {}",
+                    repository.name
+                ));
+            }
+            for check_set in &repository.check_sets {
+                if !VALID_CHECK_SETS.contains(&check_set.as_str()) {
+                    return Err(format_err!(
+                        "invalid check set {} in repository {}",
+                        check_set,
+                        repository.name
+                    ));
+                }
+            }
+            if let Some(provider) = &repository.provider {
+                if !VALID_PROVIDERS.contains(&provider.as_str()) {
+                    return Err(format_err!(
+                        "invalid provider {} in repository {}",
+                        provider,
+                        repository.name
+                    ));
+                }
+            }
+            if let Some(checks) = &repository.checks {
+                for check_id in checks.enable.iter().chain(checks.disable.iter()) {
+                    if !VALID_CHECKS.contains(&check_id.as_str()) {
+                        return Err(format_err!(
+                            "invalid check {} in repository {}",
+                            check_id,
+                            repository.name
+                        ));
+                    }
+                }
+                if let Some(check_id) = checks.enable.iter().find(|c| checks.disable.contains(c)) {
+                    return Err(format_err!(
+                        "check {} both enabled and disabled in repository {}",
+                        check_id,
+                        repository.name
+                    ));
+                }
+            }
+            if let Some(weight) = repository.weight {
+                if !weight.is_finite() || weight <= 0.0 {
+                    return Err(format_err!(
+                        "invalid weight {} in repository {}",
+                        weight,
+                        repository.name
+                    ));
+                }
+            }
+            for image in repository.container_images.iter().flatten() {
+                if image.trim().is_empty()
+                    || image.contains("://")
+                    || image.contains(char::is_whitespace)
+                {
+                    return Err(format_err!(
+                        "invalid container image {} in repository {}",
+                        image,
+                        repository.name
+                    ));
+                }
+            }
+            if let Some(gerrit_url) = &repository.gerrit_url {
+                if !gerrit_url.starts_with("http") || !gerrit_url.contains("/admin/repos/") {
+                    return Err(format_err!(
+                        "invalid gerrit url {} in repository {} (expected project url, i.e. https://gerrit.example.org/r/admin/repos/org/project)",
+                        gerrit_url,
+                        repository.name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn set_digest(&mut self) -> Result<()> {
         let data = bincode::serialize(&self)?;
         let digest = hex::encode(Sha256::digest(data));
@@ -51,22 +338,176 @@ pub(crate) struct Repository {
     pub name: String,
     pub url: String,
     pub check_sets: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checks: Option<CheckOverrides>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_images: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gerrit_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+}
+
+/// Checks enabled or disabled in a repository on top of its check sets.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct CheckOverrides {
+    #[serde(default)]
+    pub enable: Vec<String>,
+
+    #[serde(default)]
+    pub disable: Vec<String>,
 }
 
-/// Process foundations registered in the database.
+/// Summary of the changes applied and the problems found while processing a
+/// foundation's data file.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FoundationReport {
+    pub foundation_id: String,
+    pub data_file_commit: Option<DataFileCommit>,
+    pub registered_projects: Vec<String>,
+    pub updated_projects: Vec<String>,
+    pub renamed_projects: Vec<(String, String)>,
+    pub unregistered_projects: Vec<String>,
+    pub skipped_projects: Vec<SkippedProject>,
+    pub errors: Vec<String>,
+}
+
+impl FoundationReport {
+    /// Create a new empty report for the foundation provided.
+    fn new(foundation_id: &str) -> Self {
+        Self {
+            foundation_id: foundation_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Check if the report does not contain any problems.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.skipped_projects.is_empty() && self.errors.is_empty()
+    }
+
+    /// Return a one line summary of the changes applied and the problems
+    /// found.
+    pub(crate) fn summary(&self) -> String {
+        format!(
+            "{} registered, {} updated, {} renamed, {} unregistered, {} skipped, {} errors",
+            self.registered_projects.len(),
+            self.updated_projects.len(),
+            self.renamed_projects.len(),
+            self.unregistered_projects.len(),
+            self.skipped_projects.len(),
+            self.errors.len()
+        )
+    }
+}
+
+impl fmt::Display for FoundationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Some problems were found while processing the {} foundation data file.",
+            self.foundation_id
+        )?;
+        if !self.skipped_projects.is_empty() {
+            writeln!(f, "\nProjects skipped:\n")?;
+            for project in &self.skipped_projects {
+                let name = project.name.as_deref().unwrap_or("(unknown)");
+                writeln!(f, "- {name}: {}", project.reason)?;
+            }
+        }
+        if !self.errors.is_empty() {
+            writeln!(f, "\nErrors:\n")?;
+            for err in &self.errors {
+                writeln!(f, "- {err}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Project in the foundation's data file that could not be processed.
+#[derive(Debug, Clone)]
+pub(crate) struct SkippedProject {
+    pub name: Option<String>,
+    pub reason: String,
+}
+
+/// Process foundations registered in the database. When running on demand,
+/// only the foundations whose registration has been requested are processed.
 #[instrument(skip_all, err)]
-pub(crate) async fn run(cfg: &Config, db: DynDB) -> Result<()> {
+pub(crate) async fn run(
+    cfg: &Config,
+    db: DynDB,
+    notifier: Option<DynNotifier>,
+    github: Option<DynGitHub>,
+    on_demand: bool,
+) -> Result<()> {
     info!("started");
 
     // Process foundations
-    let http_client = reqwest::Client::new();
-    let foundations = db.foundations().await?;
+    let client = DataFileClient::new(cfg)?;
+    let foundations = if on_demand {
+        db.requested_foundations().await?
+    } else {
+        db.foundations().await?
+    };
+    if foundations.is_empty() {
+        info!("no foundations to process, finished");
+        return Ok(());
+    }
+
+    // Sync the score weights overrides defined in the config file
+    let score_weights = match cfg.get::<HashMap<String, Weights>>("registrar.scoreWeights") {
+        Ok(score_weights) => score_weights,
+        Err(ConfigError::NotFound(_)) => HashMap::new(),
+        Err(err) => return Err(err.into()),
+    };
+    for foundation in &foundations {
+        if let Some(weights) = score_weights.get(&foundation.foundation_id) {
+            weights.validate().context(format!(
+                "invalid score weights for foundation {}",
+                foundation.foundation_id
+            ))?;
+            db.update_foundation_weights(&foundation.foundation_id, weights)
+                .await?;
+        }
+    }
+
+    // Sync the maturity gates defined in the config file
+    let maturity_gates = match cfg.get::<HashMap<String, MaturityGates>>("registrar.maturityGates")
+    {
+        Ok(maturity_gates) => maturity_gates,
+        Err(ConfigError::NotFound(_)) => HashMap::new(),
+        Err(err) => return Err(err.into()),
+    };
+    for foundation in &foundations {
+        if let Some(gates) = maturity_gates.get(&foundation.foundation_id) {
+            validate_maturity_gates(gates).context(format!(
+                "invalid maturity gates for foundation {}",
+                foundation.foundation_id
+            ))?;
+            db.update_foundation_maturity_gates(&foundation.foundation_id, gates)
+                .await?;
+        }
+    }
+
     let result = stream::iter(foundations)
         .map(|foundation| async {
             let foundation_id = foundation.foundation_id.clone();
-            match timeout(
+            let contact_email = foundation.contact_email.clone();
+            let registration_requested_at = foundation.registration_requested_at;
+            let mut report = FoundationReport::new(&foundation_id);
+            let result = match timeout(
                 Duration::from_secs(FOUNDATION_TIMEOUT),
-                process_foundation(db.clone(), http_client.clone(), foundation),
+                process_foundation(
+                    db.clone(),
+                    client.clone(),
+                    github.clone(),
+                    foundation,
+                    &mut report,
+                ),
             )
             .await
             {
@@ -76,7 +517,51 @@ pub(crate) async fn run(cfg: &Config, db: DynDB) -> Result<()> {
             .context(format!(
                 "error processing foundation {} data file",
                 foundation_id
-            ))
+            ));
+            if let Err(err) = &result {
+                report.errors.push(format!("{:#}", err));
+            }
+
+            // Clear the registration request once the foundation has been
+            // processed successfully (failed ones are retried in the next run)
+            if let (Ok(()), Some(requested_at)) = (&result, registration_requested_at) {
+                if let Err(err) = db
+                    .clear_registration_request(&foundation_id, &requested_at)
+                    .await
+                {
+                    error!(
+                        "error clearing foundation {} registration request: {:#}",
+                        foundation_id, err
+                    );
+                }
+            }
+
+            // Send report to the foundation contact when problems were found
+            if let (Some(notifier), Some(contact_email)) = (&notifier, contact_email) {
+                if !report.is_empty() {
+                    if let Err(err) = notifier
+                        .send_foundation_report(&contact_email, &report)
+                        .await
+                    {
+                        error!(
+                            "error sending foundation {} report: {:#}",
+                            foundation_id, err
+                        );
+                    }
+                }
+            }
+
+            // Publish report summary on the data file commit processed
+            if let (Some(github), Some(commit)) = (&github, &report.data_file_commit) {
+                if let Err(err) = github.publish_report(commit, &report).await {
+                    error!(
+                        "error publishing foundation {} report on data file commit: {:#}",
+                        foundation_id, err
+                    );
+                }
+            }
+
+            result
         })
         .buffer_unordered(cfg.get("registrar.concurrency")?)
         .collect::<Vec<Result<()>>>()
@@ -97,45 +582,107 @@ pub(crate) async fn run(cfg: &Config, db: DynDB) -> Result<()> {
     result
 }
 
+/// Export the projects registered in the database for the foundation provided
+/// as a data file (yaml). This can be used to bootstrap a foundation's data
+/// file from the projects already registered, or to recover it.
+#[instrument(skip(db), err)]
+pub(crate) async fn export_data_file(db: DynDB, foundation_id: &str) -> Result<String> {
+    let data_file = db
+        .foundation_data_file(foundation_id)
+        .await?
+        .ok_or_else(|| format_err!("foundation {foundation_id} not found"))?;
+    let projects: serde_json::Value = serde_json::from_str(&data_file)?;
+    let data_file = serde_yaml::to_string(&projects)?;
+    Ok(data_file)
+}
+
 /// Process foundation's data file. New projects available will be registered
 /// in the database and existing ones which have changed will be updated. When
 /// a project is removed from the data file, it'll be removed from the database
-/// as well.
+/// as well. Projects that cannot be processed are skipped and added to the
+/// report provided, along with any errors found registering them. When a
+/// GitHub client is provided and the data file is hosted on GitHub, the data
+/// file is fetched from the commit its ref points to, which is recorded in the
+/// report so that a summary can be published on it.
 #[instrument(fields(foundation_id = foundation.foundation_id), skip_all, err)]
 async fn process_foundation(
     db: DynDB,
-    http_client: reqwest::Client,
+    client: DataFileClient,
+    github: Option<DynGitHub>,
     foundation: Foundation,
+    report: &mut FoundationReport,
 ) -> Result<()> {
     let start = Instant::now();
     debug!("started");
 
     // Fetch foundation data file
-    let resp = http_client.get(foundation.data_url).send().await?;
-    if resp.status() != StatusCode::OK {
-        return Err(format_err!(
-            "unexpected status code getting data file: {}",
-            resp.status()
-        ));
+    let mut data_url = foundation.resolved_data_url()?;
+    if let (Some(github), Some(data_file)) = (&github, DataFileRef::from_url(&data_url)) {
+        let sha = github
+            .commit_sha(&data_file)
+            .await
+            .context("error getting data file commit")?;
+        data_url = data_file.pinned_url(&sha);
+        report.data_file_commit = Some(DataFileCommit { data_file, sha });
     }
-    let data = resp.text().await?;
+    let data_files = fetch_data_files(&client, &data_url).await?;
 
-    // Get projects available in the data file
-    let tmp: Vec<Project> = serde_yaml::from_str(&data)?;
-    let mut projects_available: HashMap<String, Project> = HashMap::with_capacity(tmp.len());
-    for mut project in tmp {
-        project.set_digest()?;
-        projects_available.insert(project.name.clone(), project);
-    }
+    // Get projects available in the data files, skipping the invalid ones
+    let (projects_available, skipped_projects) = parse_data_files(&data_files)?;
+    let projects_skipped: HashSet<String> = skipped_projects
+        .iter()
+        .filter_map(|project| project.name.clone())
+        .collect();
+    report.skipped_projects.extend(skipped_projects);
 
     // Get projects registered in the database
     let foundation_id = &foundation.foundation_id;
     let projects_registered = db.foundation_projects(foundation_id).await?;
 
     // Register or update available projects as needed
+    let mut renamed: HashSet<String> = HashSet::new();
     for (name, project) in &projects_available {
+        // Rename the project when it's registered using one of its previous
+        // names, so that its history is kept
+        let mut registered_digest = projects_registered.get(name);
+        if registered_digest.is_none() {
+            let previous_name = project
+                .previous_names
+                .iter()
+                .flatten()
+                .find(|previous_name| {
+                    projects_registered.contains_key(*previous_name)
+                        && !projects_available.contains_key(*previous_name)
+                        && !renamed.contains(*previous_name)
+                });
+            if let Some(previous_name) = previous_name {
+                debug!("renaming project {} to {}", previous_name, name);
+                match db.rename_project(foundation_id, previous_name, name).await {
+                    Ok(true) => {
+                        renamed.insert(previous_name.clone());
+                        report
+                            .renamed_projects
+                            .push((previous_name.clone(), name.clone()));
+                        registered_digest = projects_registered.get(previous_name);
+                    }
+                    Ok(false) => {}
+                    Err(err) => {
+                        error!(
+                            "error renaming project {} to {}: {}",
+                            previous_name, name, err
+                        );
+                        report.errors.push(format!(
+                            "error renaming project {} to {}: {}",
+                            previous_name, name, err
+                        ));
+                        continue;
+                    }
+                }
+            }
+        }
+
         // Check if the project is already registered
-        if let Some(registered_digest) = projects_registered.get(name) {
+        if let Some(registered_digest) = registered_digest {
             if registered_digest == &project.digest {
                 continue;
             }
@@ -143,18 +690,36 @@ async fn process_foundation(
 
         // Register project
         debug!("registering project {}", project.name);
-        if let Err(err) = db.register_project(foundation_id, project).await {
-            error!("error registering project {}: {}", project.name, err);
+        match db.register_project(foundation_id, project).await {
+            Ok(()) if registered_digest.is_some() => report.updated_projects.push(name.clone()),
+            Ok(()) => report.registered_projects.push(name.clone()),
+            Err(err) => {
+                error!("error registering project {}: {}", project.name, err);
+                report.errors.push(format!(
+                    "error registering project {}: {}",
+                    project.name, err
+                ));
+            }
         }
     }
 
-    // Unregister projects no longer available in the data file
+    // Unregister projects no longer available in the data file (projects
+    // skipped are kept, as they are still listed in the data file)
     if !projects_available.is_empty() {
         for name in projects_registered.keys() {
-            if !projects_available.contains_key(name) {
+            if !projects_available.contains_key(name)
+                && !projects_skipped.contains(name)
+                && !renamed.contains(name)
+            {
                 debug!("unregistering project {}", name);
-                if let Err(err) = db.unregister_project(foundation_id, name).await {
-                    error!("error unregistering project {}: {}", name, err);
+                match db.unregister_project(foundation_id, name).await {
+                    Ok(()) => report.unregistered_projects.push(name.clone()),
+                    Err(err) => {
+                        error!("error unregistering project {}: {}", name, err);
+                        report
+                            .errors
+                            .push(format!("error unregistering project {}: {}", name, err));
+                    }
                 };
             }
         }
@@ -163,3 +728,158 @@ async fn process_foundation(
     debug!("completed in {}s", start.elapsed().as_secs());
     Ok(())
 }
+
+/// Manifest listing the data files a foundation's data has been split across
+/// (i.e. one per project or per category).
+#[derive(Debug, Clone, Deserialize)]
+struct DataManifest {
+    files: Vec<String>,
+}
+
+/// Fetch the foundation's data from the url provided. The url can point to a
+/// data file or to a manifest listing multiple data files, in which case all
+/// of them are fetched (relative urls are resolved from the manifest's url).
+/// Each data file's content is returned along with its url.
+pub(crate) async fn fetch_data_files(
+    client: &DataFileClient,
+    data_url: &str,
+) -> Result<Vec<(String, String)>> {
+    let data = client.fetch(data_url).await?;
+    let Ok(manifest) = serde_yaml::from_str::<DataManifest>(&data) else {
+        return Ok(vec![(data_url.to_string(), data)]);
+    };
+    if manifest.files.is_empty() {
+        return Err(format_err!("data manifest does not list any data files"));
+    }
+
+    let base_url = Url::parse(data_url)?;
+    let mut data_files = Vec::with_capacity(manifest.files.len());
+    for file in &manifest.files {
+        let file_url = base_url
+            .join(file)
+            .context(format!("invalid data file url {file}"))?
+            .to_string();
+        let data = client
+            .fetch(&file_url)
+            .await
+            .context(format!("error fetching data file {file_url}"))?;
+        data_files.push((file_url, data));
+    }
+    Ok(data_files)
+}
+
+/// HTTP client used to fetch foundations' data files. Only the urls allowed
+/// by the host policy configured are fetched, as data urls are provided by
+/// the foundations.
+#[derive(Debug, Clone)]
+pub(crate) struct DataFileClient {
+    restricted_client: RestrictedClient,
+}
+
+impl DataFileClient {
+    /// Create a new DataFileClient instance.
+    pub(crate) fn new(cfg: &Config) -> Result<Self> {
+        let host_policy = match cfg.get::<HostPolicy>("http.hostPolicy") {
+            Ok(host_policy) => host_policy,
+            Err(ConfigError::NotFound(_)) => HostPolicy::default(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self {
+            restricted_client: RestrictedClient::new(host_policy)?,
+        })
+    }
+
+    /// Fetch the foundation's data file from the url provided.
+    async fn fetch(&self, data_url: &str) -> Result<String> {
+        let resp = self.restricted_client.get(data_url)?.send().await?;
+        if resp.status() != StatusCode::OK {
+            return Err(format_err!(
+                "unexpected status code getting data file: {}",
+                resp.status()
+            ));
+        }
+        Ok(resp.text().await?)
+    }
+}
+
+/// Parse the projects available in the data file provided, setting their
+/// digests. Projects that are not valid are skipped and returned separately.
+pub(crate) fn parse_data_file(
+    data: &str,
+) -> Result<(HashMap<String, Project>, Vec<SkippedProject>)> {
+    let entries: Vec<serde_yaml::Value> = serde_yaml::from_str(data)?;
+    let mut projects_available: HashMap<String, Project> = HashMap::with_capacity(entries.len());
+    let mut projects_skipped: Vec<SkippedProject> = vec![];
+    for entry in entries {
+        let name = entry
+            .get("name")
+            .and_then(serde_yaml::Value::as_str)
+            .map(ToString::to_string);
+        let project = serde_yaml::from_value::<Project>(entry)
+            .map_err(Error::from)
+            .and_then(|project| project.validate().map(|_| project));
+        match project {
+            Ok(mut project) => {
+                project.set_digest()?;
+                projects_available.insert(project.name.clone(), project);
+            }
+            Err(err) => {
+                debug!(
+                    "skipping project {}: {:#}",
+                    name.as_deref().unwrap_or("?"),
+                    err
+                );
+                projects_skipped.push(SkippedProject {
+                    name,
+                    reason: format!("{:#}", err),
+                });
+            }
+        }
+    }
+    Ok((projects_available, projects_skipped))
+}
+
+/// Parse the projects available in the data files provided and merge them.
+/// Projects found in more than one data file are skipped, as it's not
+/// possible to tell which of the entries should be used.
+pub(crate) fn parse_data_files(
+    data_files: &[(String, String)],
+) -> Result<(HashMap<String, Project>, Vec<SkippedProject>)> {
+    if let [(_, data)] = data_files {
+        return parse_data_file(data);
+    }
+
+    let mut projects_available: HashMap<String, Project> = HashMap::new();
+    let mut projects_skipped: Vec<SkippedProject> = vec![];
+    let mut projects_files: HashMap<String, Vec<&str>> = HashMap::new();
+    for (url, data) in data_files {
+        let (available, skipped) =
+            parse_data_file(data).context(format!("error parsing data file {url}"))?;
+        let names = available
+            .keys()
+            .cloned()
+            .chain(skipped.iter().filter_map(|project| project.name.clone()));
+        for name in names.collect::<HashSet<String>>() {
+            projects_files.entry(name).or_default().push(url);
+        }
+        projects_available.extend(available);
+        projects_skipped.extend(skipped);
+    }
+
+    // Skip duplicate projects
+    let mut duplicates: Vec<(String, Vec<&str>)> = projects_files
+        .into_iter()
+        .filter(|(_, urls)| urls.len() > 1)
+        .collect();
+    duplicates.sort();
+    for (name, urls) in duplicates {
+        debug!("skipping project {}: found in multiple data files", name);
+        projects_available.remove(&name);
+        projects_skipped.push(SkippedProject {
+            name: Some(name),
+            reason: format!("project found in multiple data files: {}", urls.join(", ")),
+        });
+    }
+
+    Ok((projects_available, projects_skipped))
+}
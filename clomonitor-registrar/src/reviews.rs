@@ -0,0 +1,223 @@
+use crate::{db::DynDB, notifier::DynNotifier};
+use anyhow::Result;
+use config::Config;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashMap, fmt};
+use tracing::{error, info, instrument};
+
+/// Report sections, in the order they are displayed.
+const SECTIONS: [&str; 8] = [
+    "documentation",
+    "license",
+    "best_practices",
+    "security",
+    "legal",
+    "artifacts",
+    "organization",
+    "external",
+];
+
+/// Project whose annual review is due soon.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ProjectDueForReview {
+    pub foundation_id: String,
+    pub name: String,
+    pub display_name: String,
+    pub maturity: String,
+    pub contact_email: String,
+    pub annual_review_due_at: String,
+    pub score: Option<HashMap<String, f64>>,
+    pub rating: Option<String>,
+    pub repositories: Vec<RepositoryReport>,
+}
+
+/// Repository's report (as stored in the database).
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RepositoryReport {
+    pub name: String,
+    pub url: String,
+    pub report: Option<Value>,
+}
+
+impl RepositoryReport {
+    /// Return the checks of the report's section provided that did not pass
+    /// (and are not exempt).
+    fn checks_not_passed(&self, section: &str) -> Vec<String> {
+        self.section_checks(section)
+            .filter(|(_, output)| !check_passed_or_exempt(output))
+            .map(|(check_id, _)| check_id.to_string())
+            .collect()
+    }
+
+    /// Return the outputs of the checks in the report's section provided.
+    fn section_checks<'a>(&'a self, section: &str) -> impl Iterator<Item = (&'a str, &'a Value)> {
+        self.report
+            .as_ref()
+            .and_then(|report| report.get(section))
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter(|(_, output)| output.is_object())
+            .map(|(check_id, output)| (check_id.as_str(), output))
+    }
+
+    /// Return the output of the check provided, if it was run.
+    fn check(&self, check_id: &str) -> Option<&Value> {
+        SECTIONS
+            .iter()
+            .find_map(|section| self.section_checks(section).find(|(id, _)| *id == check_id))
+            .map(|(_, output)| output)
+    }
+}
+
+/// Reminder sent to a project's contact ahead of its annual review.
+#[derive(Debug, Clone)]
+pub(crate) struct ReviewReminder {
+    pub project: ProjectDueForReview,
+    pub requirements_gaps: Vec<String>,
+}
+
+impl ReviewReminder {
+    /// Create a new review reminder for the project provided, checking it
+    /// against the requirements of its maturity level.
+    fn new(project: ProjectDueForReview, requirements: &[String]) -> Self {
+        let requirements_gaps = requirements
+            .iter()
+            .filter(|check_id| !requirement_met(&project.repositories, check_id))
+            .cloned()
+            .collect();
+        Self {
+            project,
+            requirements_gaps,
+        }
+    }
+}
+
+impl fmt::Display for ReviewReminder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let p = &self.project;
+        writeln!(
+            f,
+            "The annual review of {} ({} foundation) is due on {}. This is a summary of its current CLOMonitor report to help preparing it.",
+            p.display_name, p.foundation_id, p.annual_review_due_at
+        )?;
+
+        // Score
+        writeln!(f, "\nScore:\n")?;
+        match (&p.score, &p.rating) {
+            (Some(score), Some(rating)) => {
+                writeln!(f, "- Rating: {}", rating.to_uppercase())?;
+                for section in ["global"].iter().chain(SECTIONS.iter()) {
+                    if let Some(value) = score.get(*section) {
+                        writeln!(f, "- {}: {value:.0}", section_title(section))?;
+                    }
+                }
+            }
+            _ => writeln!(f, "- Not processed yet")?,
+        }
+
+        // Maturity requirements gaps
+        writeln!(f, "\nRequirements for the {} maturity level:\n", p.maturity)?;
+        if self.requirements_gaps.is_empty() {
+            writeln!(f, "- All requirements are met")?;
+        } else {
+            for check_id in &self.requirements_gaps {
+                writeln!(f, "- {check_id}: not passing")?;
+            }
+        }
+
+        // Improvement checklist
+        writeln!(f, "\nImprovement checklist:")?;
+        for repository in &p.repositories {
+            writeln!(f, "\n{} ({}):\n", repository.name, repository.url)?;
+            if repository.report.is_none() {
+                writeln!(f, "- [ ] Repository not processed yet")?;
+                continue;
+            }
+            let mut pending = false;
+            for section in SECTIONS {
+                for check_id in repository.checks_not_passed(section) {
+                    writeln!(f, "- [ ] {} / {check_id}", section_title(section))?;
+                    pending = true;
+                }
+            }
+            if !pending {
+                writeln!(f, "- All checks passed")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Send a reminder to the contact of the projects whose annual review is due
+/// soon, including their current report, the gaps versus the requirements of
+/// their maturity level and an improvement checklist.
+#[instrument(skip_all, err)]
+pub(crate) async fn run(cfg: &Config, db: DynDB, notifier: DynNotifier) -> Result<()> {
+    info!("review reminders started");
+
+    let weeks_before = cfg.get("registrar.reviewReminders.weeksBefore")?;
+    let requirements: HashMap<String, Vec<String>> = cfg
+        .get("registrar.reviewReminders.requirements")
+        .unwrap_or_default();
+    let projects = db.projects_due_for_review(weeks_before).await?;
+
+    let mut sent = 0;
+    for project in projects {
+        let (foundation_id, name) = (project.foundation_id.clone(), project.name.clone());
+        let due_at = project.annual_review_due_at.clone();
+        let contact_email = project.contact_email.clone();
+        let requirements = requirements
+            .get(&project.maturity)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let reminder = ReviewReminder::new(project, requirements);
+        if let Err(err) = notifier
+            .send_review_reminder(&contact_email, &reminder)
+            .await
+        {
+            error!(%foundation_id, %name, ?err, "error sending review reminder");
+            continue;
+        }
+        db.update_last_reminded_review(&foundation_id, &name, &due_at)
+            .await?;
+        sent += 1;
+    }
+
+    info!("review reminders finished ({sent} sent)");
+    Ok(())
+}
+
+/// Check if the requirement provided (a check that must pass) is met by the
+/// project's repositories. The check must have been run at least once and
+/// passed (or be exempt) on all the repositories it was run on.
+fn requirement_met(repositories: &[RepositoryReport], check_id: &str) -> bool {
+    let outputs: Vec<&Value> = repositories
+        .iter()
+        .filter_map(|r| r.check(check_id))
+        .collect();
+    !outputs.is_empty() && outputs.into_iter().all(check_passed_or_exempt)
+}
+
+/// Check if the check output provided passed or is exempt.
+fn check_passed_or_exempt(output: &Value) -> bool {
+    let flag = |name: &str| output.get(name).and_then(Value::as_bool).unwrap_or(false);
+    flag("passed") || flag("exempt")
+}
+
+/// Return the title of the report's section provided.
+fn section_title(section: &str) -> &str {
+    match section {
+        "global" => "Global",
+        "documentation" => "Documentation",
+        "license" => "License",
+        "best_practices" => "Best practices",
+        "security" => "Security",
+        "legal" => "Legal",
+        "artifacts" => "Artifacts",
+        "organization" => "Organization",
+        "external" => "External",
+        _ => section,
+    }
+}
@@ -1,17 +1,30 @@
-use crate::tracker::Repository;
+use crate::{
+    errors::TrackingError,
+    events,
+    notifier::{new_not_passed_security_checks, ProjectChanges, ProjectWebhook},
+    schedule::DueSchedule,
+    tracker::{Repository, REPOSITORY_TRACK_TIMEOUT},
+};
 use anyhow::Result;
 use async_trait::async_trait;
 use clomonitor_core::{
-    linter::{CheckSet, Report},
-    score::{self, Score},
+    linter::{CheckOverrides, CheckSet, Provider, Report},
+    score::{self, Score, Weights},
 };
 use deadpool_postgres::{Pool, Transaction};
 #[cfg(test)]
 use mockall::automock;
-use std::sync::Arc;
-use tokio_postgres::types::Json;
+use serde_json::json;
+use std::{env::consts, sync::Arc};
+use tokio_postgres::{types::Json, Row};
 use uuid::Uuid;
 
+/// Version of the linter used to produce the reports.
+const LINTER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Number of days the tracking errors are kept in the audit log.
+const TRACKING_ERRORS_RETENTION_DAYS: i32 = 30;
+
 /// Type alias to represent a DB trait object.
 pub(crate) type DynDB = Arc<dyn DB + Send + Sync>;
 
@@ -19,10 +32,32 @@ pub(crate) type DynDB = Arc<dyn DB + Send + Sync>;
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub(crate) trait DB {
-    /// Get all repositories registered in the database.
-    async fn repositories(&self) -> Result<Vec<Repository>>;
+    /// Start a new tracker run, queueing all repositories registered in the
+    /// database that are due according to the schedules provided. If a
+    /// previous run didn't finish, it'll be resumed instead.
+    async fn start_run(&self, schedules: &[DueSchedule]) -> Result<Uuid>;
+
+    /// Start a new on demand tracker run, queueing the repositories whose
+    /// tracking has been requested. None is returned when there are no
+    /// tracking requests pending.
+    async fn start_on_demand_run(&self) -> Result<Option<Uuid>>;
+
+    /// Get the tracker run in progress, if any (on demand runs are ignored).
+    async fn current_run(&self) -> Result<Option<Uuid>>;
 
-    /// Store the provided tracking results in the database.
+    /// Claim the next repository pending in the tracker run provided, marking
+    /// it as in progress. Repositories claimed by other workers are skipped,
+    /// unless they have been in progress for longer than expected.
+    async fn claim_repository(&self, run_id: &Uuid) -> Result<Option<Repository>>;
+
+    /// Get the repositories pending in the tracker run provided.
+    async fn repositories(&self, run_id: &Uuid) -> Result<Vec<Repository>>;
+
+    /// Mark the repository provided as in progress in the tracker run.
+    async fn start_repository(&self, run_id: &Uuid, repository_id: &Uuid) -> Result<()>;
+
+    /// Store the provided tracking results in the database, returning the
+    /// changes they caused in the repository's project.
     async fn store_results(
         &self,
         repository_id: &Uuid,
@@ -30,7 +65,35 @@ pub(crate) trait DB {
         report: Option<&Report>,
         errors: Option<&String>,
         remote_digest: &str,
+    ) -> Result<ProjectChanges>;
+
+    /// Register the errors found while tracking the repository provided in
+    /// the tracker run's audit log.
+    async fn store_tracking_errors(
+        &self,
+        run_id: &Uuid,
+        repository_id: &Uuid,
+        errors: &[TrackingError],
     ) -> Result<()>;
+
+    /// Update the time the repository provided was last tracked, marking it
+    /// as done in the tracker run.
+    async fn update_tracked_at(&self, run_id: &Uuid, repository_id: &Uuid) -> Result<()>;
+
+    /// Finish the tracker run provided if all its repositories are done.
+    async fn finish_run(&self, run_id: &Uuid) -> Result<()>;
+
+    /// Record that the tracker run provided was interrupted before all its
+    /// repositories were done (it's left unfinished so that it's resumed).
+    async fn interrupt_run(&self, run_id: &Uuid) -> Result<()>;
+
+    /// Get the webhooks registered by the maintainers of the project
+    /// provided.
+    async fn project_webhooks(
+        &self,
+        foundation: &str,
+        project: &str,
+    ) -> Result<Vec<ProjectWebhook>>;
 }
 
 /// DB implementation backed by PostgreSQL.
@@ -40,33 +103,119 @@ pub(crate) struct PgDB {
 
 #[async_trait]
 impl DB for PgDB {
-    async fn repositories(&self) -> Result<Vec<Repository>> {
+    async fn start_run(&self, schedules: &[DueSchedule]) -> Result<Uuid> {
+        let db = self.pool.get().await?;
+        let run_id = db
+            .query_one("select start_tracker_run($1::jsonb)", &[&Json(schedules)])
+            .await?
+            .get(0);
+        Ok(run_id)
+    }
+
+    async fn start_on_demand_run(&self) -> Result<Option<Uuid>> {
+        let db = self.pool.get().await?;
+        let run_id = db
+            .query_one("select start_on_demand_tracker_run()", &[])
+            .await?
+            .get(0);
+        Ok(run_id)
+    }
+
+    async fn current_run(&self) -> Result<Option<Uuid>> {
+        let db = self.pool.get().await?;
+        let run_id = db
+            .query_opt(
+                "
+                select tracker_run_id from tracker_run
+                where finished_at is null
+                and on_demand = false
+                order by started_at desc
+                limit 1
+                ",
+                &[],
+            )
+            .await?
+            .map(|row| row.get("tracker_run_id"));
+        Ok(run_id)
+    }
+
+    async fn claim_repository(&self, run_id: &Uuid) -> Result<Option<Repository>> {
+        let db = self.pool.get().await?;
+        let stale_after = (2 * REPOSITORY_TRACK_TIMEOUT) as i32;
+        let repository = db
+            .query_opt(
+                "
+                with next as (
+                    select tracker_run_id, repository_id
+                    from tracker_run_repository
+                    where tracker_run_id = $1::uuid
+                    and (
+                        status = 'pending'
+                        or (
+                            status = 'in_progress'
+                            and updated_at < current_timestamp - $2::integer * interval '1 second'
+                        )
+                    )
+                    order by position asc
+                    limit 1
+                    for update skip locked
+                )
+                update tracker_run_repository q set
+                    status = 'in_progress',
+                    updated_at = current_timestamp
+                from next
+                join repository r using (repository_id)
+                join project p using (project_id)
+                where q.tracker_run_id = next.tracker_run_id
+                and q.repository_id = next.repository_id
+                returning
+                    r.repository_id,
+                    r.url,
+                    r.digest,
+                    to_json(r.check_sets) as check_sets,
+                    r.check_overrides,
+                    to_json(r.provider) as provider,
+                    r.container_images,
+                    r.gerrit_url,
+                    r.updated_at,
+                    p.home_url
+                ",
+                &[&run_id, &stale_after],
+            )
+            .await?
+            .as_ref()
+            .map(PgDB::repository_from_row);
+        Ok(repository)
+    }
+
+    async fn repositories(&self, run_id: &Uuid) -> Result<Vec<Repository>> {
         let db = self.pool.get().await?;
         let repositories = db
             .query(
                 "
                 select
-                    repository_id,
-                    url,
-                    digest,
-                    to_json(check_sets) as check_sets,
-                    updated_at
-                from repository
+                    r.repository_id,
+                    r.url,
+                    r.digest,
+                    to_json(r.check_sets) as check_sets,
+                    r.check_overrides,
+                    to_json(r.provider) as provider,
+                    r.container_images,
+                    r.gerrit_url,
+                    r.updated_at,
+                    p.home_url
+                from tracker_run_repository q
+                join repository r using (repository_id)
+                join project p using (project_id)
+                where q.tracker_run_id = $1::uuid
+                and q.status <> 'done'
+                order by q.position asc
                 ",
-                &[],
+                &[&run_id],
             )
             .await?
             .iter()
-            .map(|row| {
-                let Json(check_sets): Json<Vec<CheckSet>> = row.get("check_sets");
-                Repository {
-                    repository_id: row.get("repository_id"),
-                    url: row.get("url"),
-                    check_sets,
-                    digest: row.get("digest"),
-                    updated_at: row.get("updated_at"),
-                }
-            })
+            .map(PgDB::repository_from_row)
             .collect();
         Ok(repositories)
     }
@@ -78,16 +227,167 @@ impl DB for PgDB {
         report: Option<&Report>,
         errors: Option<&String>,
         remote_digest: &str,
-    ) -> Result<()> {
+    ) -> Result<ProjectChanges> {
         let mut db = self.pool.get().await?;
         let tx = db.transaction().await?;
+        let (mut changes, previous_report) = PgDB::project_state(&tx, repository_id).await?;
         PgDB::store_report(&tx, repository_id, check_sets, report, errors).await?;
         PgDB::update_repository_score(&tx, repository_id, report).await?;
-        PgDB::update_project_score(&tx, repository_id).await?;
+        let project_score = PgDB::update_project_score(&tx, repository_id).await?;
+        changes.rating_to = project_score
+            .as_ref()
+            .map(|score| score.rating().to_string())
+            .or_else(|| changes.rating_from.clone());
+        changes.score_to = project_score
+            .as_ref()
+            .map(Score::global)
+            .or(changes.score_from);
+        changes.new_not_passed_security_checks =
+            new_not_passed_security_checks(previous_report.as_ref(), report);
+        if changes.score_dropped() {
+            changes.probable_causes =
+                events::detect(&changes.repository_url, previous_report.as_ref(), report)
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+            PgDB::register_score_drop(&tx, repository_id, &changes).await?;
+        }
         PgDB::update_repository_digest(&tx, repository_id, remote_digest).await?;
         tx.commit().await?;
+        Ok(changes)
+    }
+
+    async fn start_repository(&self, run_id: &Uuid, repository_id: &Uuid) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            update tracker_run_repository set
+                status = 'in_progress',
+                updated_at = current_timestamp
+            where tracker_run_id = $1::uuid
+            and repository_id = $2::uuid;
+            ",
+            &[&run_id, &repository_id],
+        )
+        .await?;
         Ok(())
     }
+
+    async fn store_tracking_errors(
+        &self,
+        run_id: &Uuid,
+        repository_id: &Uuid,
+        errors: &[TrackingError],
+    ) -> Result<()> {
+        let check_ids: Vec<Option<&str>> = errors.iter().map(|e| e.check_id.as_deref()).collect();
+        let classes: Vec<String> = errors.iter().map(|e| e.class.to_string()).collect();
+        let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+        tx.execute(
+            "
+            delete from tracking_error
+            where repository_id = $1::uuid
+            and created_at < current_timestamp - $2::integer * interval '1 day';
+            ",
+            &[&repository_id, &TRACKING_ERRORS_RETENTION_DAYS],
+        )
+        .await?;
+        tx.execute(
+            "
+            insert into tracking_error (
+                tracker_run_id,
+                repository_id,
+                check_id,
+                class,
+                message
+            )
+            select $1::uuid, $2::uuid, e.check_id, e.class, e.message
+            from unnest($3::text[], $4::text[], $5::text[]) as e(check_id, class, message);
+            ",
+            &[&run_id, &repository_id, &check_ids, &classes, &messages],
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_tracked_at(&self, run_id: &Uuid, repository_id: &Uuid) -> Result<()> {
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+        tx.execute(
+            "
+            update repository set tracked_at = current_timestamp
+            where repository_id = $1::uuid;
+            ",
+            &[&repository_id],
+        )
+        .await?;
+        tx.execute(
+            "
+            update tracker_run_repository set
+                status = 'done',
+                updated_at = current_timestamp
+            where tracker_run_id = $1::uuid
+            and repository_id = $2::uuid;
+            ",
+            &[&run_id, &repository_id],
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn finish_run(&self, run_id: &Uuid) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            update tracker_run set finished_at = current_timestamp
+            where tracker_run_id = $1::uuid
+            and not exists (
+                select 1 from tracker_run_repository
+                where tracker_run_id = $1::uuid
+                and status <> 'done'
+            );
+            ",
+            &[&run_id],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn interrupt_run(&self, run_id: &Uuid) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            update tracker_run set interrupted_at = current_timestamp
+            where tracker_run_id = $1::uuid
+            and finished_at is null;
+            ",
+            &[&run_id],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn project_webhooks(
+        &self,
+        foundation: &str,
+        project: &str,
+    ) -> Result<Vec<ProjectWebhook>> {
+        let db = self.pool.get().await?;
+        let webhooks: Option<String> = db
+            .query_one(
+                "select get_project_webhooks($1::text, $2::text)::text",
+                &[&foundation, &project],
+            )
+            .await?
+            .get(0);
+        match webhooks {
+            Some(webhooks) => Ok(serde_json::from_str(&webhooks)?),
+            None => Ok(vec![]),
+        }
+    }
 }
 
 impl PgDB {
@@ -96,6 +396,67 @@ impl PgDB {
         Self { pool }
     }
 
+    /// Build a repository from the database row provided.
+    fn repository_from_row(row: &Row) -> Repository {
+        let Json(check_sets): Json<Vec<CheckSet>> = row.get("check_sets");
+        let check_overrides: Option<Json<CheckOverrides>> = row.get("check_overrides");
+        let provider: Option<Json<Provider>> = row.get("provider");
+        Repository {
+            repository_id: row.get("repository_id"),
+            url: row.get("url"),
+            check_sets,
+            check_overrides: check_overrides
+                .map(|Json(check_overrides)| check_overrides)
+                .unwrap_or_default(),
+            provider: provider.map(|Json(provider)| provider),
+            container_images: row
+                .get::<_, Option<Vec<String>>>("container_images")
+                .unwrap_or_default(),
+            gerrit_url: row.get("gerrit_url"),
+            digest: row.get("digest"),
+            updated_at: row.get("updated_at"),
+            home_url: row.get("home_url"),
+        }
+    }
+
+    /// Get the current state of the project the repository provided belongs
+    /// to (locking its row), along with the repository's latest report.
+    async fn project_state(
+        tx: &Transaction<'_>,
+        repository_id: &Uuid,
+    ) -> Result<(ProjectChanges, Option<Report>)> {
+        let row = tx
+            .query_one(
+                "
+                select
+                    p.foundation_id,
+                    p.name,
+                    p.rating,
+                    (p.score->>'global')::float8 as score,
+                    r.url,
+                    rp.data as report
+                from repository r
+                join project p using (project_id)
+                left join report rp using (repository_id)
+                where r.repository_id = $1::uuid
+                for update of p;
+                ",
+                &[&repository_id],
+            )
+            .await?;
+        let changes = ProjectChanges {
+            foundation: row.get("foundation_id"),
+            project: row.get("name"),
+            repository_url: row.get("url"),
+            rating_from: row.get("rating"),
+            score_from: row.get("score"),
+            ..Default::default()
+        };
+        let report: Option<Json<serde_json::Value>> = row.get("report");
+        let report = report.and_then(|Json(report)| serde_json::from_value(report).ok());
+        Ok((changes, report))
+    }
+
     /// Store the provided repository linter report.
     async fn store_report(
         tx: &Transaction<'_>,
@@ -108,16 +469,38 @@ impl PgDB {
             Some(report) => {
                 tx.execute(
                     "
-                    insert into report (check_sets, data, errors, repository_id)
-                    values ($1::check_set[], $2::jsonb, $3::text, $4::uuid)
+                    insert into report (
+                        check_sets,
+                        data,
+                        errors,
+                        linter_version,
+                        environment,
+                        repository_id
+                    ) values (
+                        $1::check_set[],
+                        $2::jsonb,
+                        $3::text,
+                        $4::text,
+                        $5::jsonb,
+                        $6::uuid
+                    )
                     on conflict (repository_id) do update
                     set
                         check_sets = excluded.check_sets,
                         data = excluded.data,
                         errors = excluded.errors,
+                        linter_version = excluded.linter_version,
+                        environment = excluded.environment,
                         updated_at = current_timestamp;
                     ",
-                    &[&check_sets, &Json(&report), &errors, &repository_id],
+                    &[
+                        &check_sets,
+                        &Json(&report),
+                        &errors,
+                        &LINTER_VERSION,
+                        &Json(environment()),
+                        &repository_id,
+                    ],
                 )
                 .await?;
             }
@@ -140,8 +523,48 @@ impl PgDB {
         Ok(())
     }
 
-    /// Update the score of the project the repository provided belongs to.
-    async fn update_project_score(tx: &Transaction<'_>, repository_id: &Uuid) -> Result<()> {
+    /// Register a drop in the score of the project the repository provided
+    /// belongs to, along with its probable causes.
+    async fn register_score_drop(
+        tx: &Transaction<'_>,
+        repository_id: &Uuid,
+        changes: &ProjectChanges,
+    ) -> Result<()> {
+        tx.execute(
+            "
+            insert into score_drop (
+                project_id,
+                repository_id,
+                score_from,
+                score_to,
+                probable_causes
+            )
+            select
+                project_id,
+                $1::uuid,
+                $2::real,
+                $3::real,
+                $4::text[]
+            from repository
+            where repository_id = $1::uuid;
+            ",
+            &[
+                &repository_id,
+                &changes.score_from.map(|score| score as f32),
+                &changes.score_to.map(|score| score as f32),
+                &changes.probable_causes,
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Update the score of the project the repository provided belongs to,
+    /// returning its new score (if it could be calculated).
+    async fn update_project_score(
+        tx: &Transaction<'_>,
+        repository_id: &Uuid,
+    ) -> Result<Option<Score>> {
         // Get project's id and lock project's row
         let project_id: Uuid = tx
             .query_one(
@@ -156,11 +579,12 @@ impl PgDB {
             .await?
             .get("project_id");
 
-        // Calculate project's score from the repositories' scores
-        let repositories_scores: Vec<Score> = tx
+        // Calculate project's score from the repositories' scores, weighted
+        // by the repositories' weights
+        let repositories_scores: Vec<(Score, f64)> = tx
             .query(
                 "
-                select score from repository
+                select score, weight from repository
                 where repository_id in (
                     select repository_id from repository where project_id = $1::uuid
                 );
@@ -171,32 +595,31 @@ impl PgDB {
             .iter()
             .filter_map(|row| {
                 let score: Option<Json<Score>> = row.get("score");
-                score.map(|Json(score)| score)
+                let weight: f32 = row.get("weight");
+                score.map(|Json(score)| (score, weight as f64))
             })
             .collect();
 
         // Update project's score and rating
-        if !repositories_scores.is_empty() {
-            let project_score = score::merge(&repositories_scores[..]);
-            tx.execute(
-                "
-                update project set
-                    score = $1::jsonb,
-                    rating = $2::text,
-                    passed_checks = (select get_project_passed_checks($3::uuid)),
-                    updated_at = current_timestamp
-                where project_id = $3::uuid;
-                ",
-                &[
-                    &Json(&project_score),
-                    &project_score.rating().to_string(),
-                    &project_id,
-                ],
-            )
-            .await?;
+        if repositories_scores.is_empty() {
+            return Ok(None);
         }
+        let project_score = score::merge_weighted(&repositories_scores[..]);
+        let rating = project_score.rating().to_string();
+        tx.execute(
+            "
+            update project set
+                score = $1::jsonb,
+                rating = $2::text,
+                passed_checks = (select get_project_passed_checks($3::uuid)),
+                updated_at = current_timestamp
+            where project_id = $3::uuid;
+            ",
+            &[&Json(&project_score), &rating, &project_id],
+        )
+        .await?;
 
-        Ok(())
+        Ok(Some(project_score))
     }
 
     /// Update the digest of the provided repository.
@@ -220,7 +643,22 @@ impl PgDB {
         report: Option<&Report>,
     ) -> Result<()> {
         if let Some(report) = report {
-            let score = score::calculate(report);
+            // Get the weights overrides defined by the repository's foundation
+            let weights: Option<Json<Weights>> = tx
+                .query_one(
+                    "
+                    select get_foundation_weights(p.foundation_id)::jsonb
+                    from repository r
+                    join project p using (project_id)
+                    where r.repository_id = $1::uuid;
+                    ",
+                    &[&repository_id],
+                )
+                .await?
+                .get(0);
+            let weights = weights.map(|Json(weights)| weights).unwrap_or_default();
+
+            let score = score::calculate_with_weights(report, &weights);
             tx.execute(
                 "
                 update repository set
@@ -236,3 +674,12 @@ impl PgDB {
         Ok(())
     }
 }
+
+/// Return some information about the environment the linter runs on, which is
+/// stored along with the reports to help reproducing them.
+fn environment() -> serde_json::Value {
+    json!({
+        "os": consts::OS,
+        "arch": consts::ARCH,
+    })
+}
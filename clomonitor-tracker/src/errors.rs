@@ -0,0 +1,165 @@
+use clomonitor_core::linter::Report;
+use std::fmt;
+
+/// Patterns found in the messages of the errors caused by rate limits.
+const RATE_LIMIT_PATTERNS: [&str; 4] =
+    ["rate limit", "429", "abuse detection", "too many requests"];
+
+/// Patterns found in the messages of the errors caused by timeouts.
+const TIMEOUT_PATTERNS: [&str; 4] = ["timed out", "timeout", "deadline", "elapsed"];
+
+/// Patterns found in the messages of the errors caused by network issues.
+const NETWORK_PATTERNS: [&str; 6] = [
+    "error sending request",
+    "connection",
+    "dns error",
+    "failed to lookup address",
+    "tls",
+    "502 bad gateway",
+];
+
+/// Class of an error found while tracking a repository. It helps telling
+/// apart checks that failed because of transient issues (i.e. rate limits or
+/// timeouts) from those that failed for any other reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ErrorClass {
+    RateLimit,
+    Timeout,
+    Network,
+    Other,
+}
+
+impl ErrorClass {
+    /// Classify the error message provided.
+    pub(crate) fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        let matches = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+        if matches(&RATE_LIMIT_PATTERNS) {
+            ErrorClass::RateLimit
+        } else if matches(&TIMEOUT_PATTERNS) {
+            ErrorClass::Timeout
+        } else if matches(&NETWORK_PATTERNS) {
+            ErrorClass::Network
+        } else {
+            ErrorClass::Other
+        }
+    }
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let class = match self {
+            ErrorClass::RateLimit => "rate_limit",
+            ErrorClass::Timeout => "timeout",
+            ErrorClass::Network => "network",
+            ErrorClass::Other => "other",
+        };
+        write!(f, "{class}")
+    }
+}
+
+/// Error found while tracking a repository. Errors not related to a specific
+/// check (i.e. the repository could not be cloned) don't have a check id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TrackingError {
+    pub check_id: Option<String>,
+    pub class: ErrorClass,
+    pub message: String,
+}
+
+impl TrackingError {
+    /// Create a new tracking error not related to any check, classifying it
+    /// from its message.
+    pub(crate) fn repository(message: String) -> Self {
+        Self {
+            check_id: None,
+            class: ErrorClass::from_message(&message),
+            message,
+        }
+    }
+}
+
+/// Collect the errors of the checks that could not be run in the report
+/// provided.
+pub(crate) fn collect(report: &Report) -> Vec<TrackingError> {
+    report
+        .failed()
+        .into_iter()
+        .map(|(check_id, fail_reason)| {
+            let message = fail_reason.unwrap_or("check failed").to_string();
+            TrackingError {
+                check_id: Some(check_id.to_string()),
+                class: ErrorClass::from_message(&message),
+                message,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{BestPractices, CheckOutput, Documentation, Security};
+
+    #[test]
+    fn error_class_from_message() {
+        assert_eq!(
+            ErrorClass::from_message("API rate limit exceeded for installation"),
+            ErrorClass::RateLimit
+        );
+        assert_eq!(
+            ErrorClass::from_message("unexpected status code: 429 Too Many Requests"),
+            ErrorClass::RateLimit
+        );
+        assert_eq!(
+            ErrorClass::from_message("operation timed out"),
+            ErrorClass::Timeout
+        );
+        assert_eq!(
+            ErrorClass::from_message("error sending request for url (https://api.github.com)"),
+            ErrorClass::Network
+        );
+        assert_eq!(
+            ErrorClass::from_message("invalid license expression"),
+            ErrorClass::Other
+        );
+        assert_eq!(ErrorClass::RateLimit.to_string(), "rate_limit");
+    }
+
+    #[test]
+    fn collect_failed_checks_errors() {
+        let report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                openssf_badge: Some(
+                    CheckOutput::failed().fail_reason(Some("operation timed out".to_string())),
+                ),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::failed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            collect(&report),
+            vec![
+                TrackingError {
+                    check_id: Some("openssf_badge".to_string()),
+                    class: ErrorClass::Timeout,
+                    message: "operation timed out".to_string(),
+                },
+                TrackingError {
+                    check_id: Some("sbom".to_string()),
+                    class: ErrorClass::Other,
+                    message: "check failed".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use clomonitor_core::linter::{file_path_from_url, Report};
+use std::fmt;
+
+/// Checks whose results depend on the repository's latest release.
+const RELEASE_CHECKS: [&str; 1] = ["recent_release"];
+
+/// Checks whose results depend on the repository's branch protection rules.
+const BRANCH_PROTECTION_CHECKS: [&str; 2] = ["branch_protection", "code_review"];
+
+/// Event detected in a repository while tracking it that may explain a drop
+/// in its score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RepositoryEvent {
+    /// A file a check relied on to pass has been deleted (or moved).
+    FileDeleted { check_id: String, path: String },
+    /// The release a check relied on to pass has been removed (or it is not
+    /// recent anymore).
+    ReleaseRemoved { check_id: String, url: String },
+    /// The branch protection rules a check relied on to pass have changed.
+    BranchProtectionChanged { check_id: String },
+    /// A check that used to pass is not passing anymore, but no specific event
+    /// could be correlated with it.
+    CheckNotPassing { check_id: String },
+}
+
+impl fmt::Display for RepositoryEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::FileDeleted { check_id, path } => {
+                write!(f, "file {path} was deleted or moved ({check_id})")
+            }
+            Self::ReleaseRemoved { check_id, url } => {
+                write!(
+                    f,
+                    "release {url} was removed or is not recent anymore ({check_id})"
+                )
+            }
+            Self::BranchProtectionChanged { check_id } => {
+                write!(f, "branch protection rules changed ({check_id})")
+            }
+            Self::CheckNotPassing { check_id } => {
+                write!(f, "check {check_id} is not passing anymore")
+            }
+        }
+    }
+}
+
+/// Detect the events that may have caused the checks that passed in the
+/// previous report of the repository provided not to pass in the current one.
+/// The outputs of the checks that used to pass are used to find out what they
+/// relied on (i.e. a file in the repository or a release).
+pub(crate) fn detect(
+    repository_url: &str,
+    previous: Option<&Report>,
+    current: Option<&Report>,
+) -> Vec<RepositoryEvent> {
+    let (Some(previous), Some(current)) = (previous, current) else {
+        return vec![];
+    };
+    let not_passed = current.not_passed();
+    previous
+        .passed()
+        .into_iter()
+        .filter(|(check_id, _)| not_passed.contains(check_id))
+        .map(|(check_id, url)| {
+            let check_id = check_id.to_string();
+            if BRANCH_PROTECTION_CHECKS.contains(&check_id.as_str()) {
+                return RepositoryEvent::BranchProtectionChanged { check_id };
+            }
+            match url {
+                Some(url) if RELEASE_CHECKS.contains(&check_id.as_str()) => {
+                    RepositoryEvent::ReleaseRemoved {
+                        check_id,
+                        url: url.to_string(),
+                    }
+                }
+                Some(url) => match file_path_from_url(repository_url, url) {
+                    Some(path) => RepositoryEvent::FileDeleted { check_id, path },
+                    None => RepositoryEvent::CheckNotPassing { check_id },
+                },
+                None => RepositoryEvent::CheckNotPassing { check_id },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{BestPractices, CheckOutput, Documentation, Security};
+
+    const REPOSITORY_URL: &str = "https://github.com/artifacthub/hub";
+
+    #[test]
+    fn detect_no_previous_report() {
+        assert!(detect(REPOSITORY_URL, None, Some(&Report::default())).is_empty());
+    }
+
+    #[test]
+    fn detect_events() {
+        let previous = Report {
+            documentation: Documentation {
+                adopters: Some(
+                    CheckOutput::passed()
+                        .url(Some(format!("{REPOSITORY_URL}/blob/master/ADOPTERS.md"))),
+                ),
+                changelog: Some(CheckOutput::passed()),
+                readme: Some(
+                    CheckOutput::passed()
+                        .url(Some(format!("{REPOSITORY_URL}/blob/master/README.md"))),
+                ),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                recent_release: Some(
+                    CheckOutput::passed()
+                        .url(Some(format!("{REPOSITORY_URL}/releases/tag/v1.0.0"))),
+                ),
+                ..Default::default()
+            },
+            security: Security {
+                code_review: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let current = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::not_passed()),
+                changelog: Some(CheckOutput::not_passed()),
+                readme: Some(
+                    CheckOutput::passed()
+                        .url(Some(format!("{REPOSITORY_URL}/blob/master/README.md"))),
+                ),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                recent_release: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            security: Security {
+                code_review: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            detect(REPOSITORY_URL, Some(&previous), Some(&current)),
+            vec![
+                RepositoryEvent::FileDeleted {
+                    check_id: "adopters".to_string(),
+                    path: "ADOPTERS.md".to_string(),
+                },
+                RepositoryEvent::CheckNotPassing {
+                    check_id: "changelog".to_string(),
+                },
+                RepositoryEvent::ReleaseRemoved {
+                    check_id: "recent_release".to_string(),
+                    url: format!("{REPOSITORY_URL}/releases/tag/v1.0.0"),
+                },
+                RepositoryEvent::BranchProtectionChanged {
+                    check_id: "code_review".to_string(),
+                },
+            ]
+        );
+    }
+}
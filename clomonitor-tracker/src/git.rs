@@ -1,5 +1,6 @@
 use anyhow::{format_err, Result};
 use async_trait::async_trait;
+use clomonitor_core::net;
 #[cfg(test)]
 use mockall::automock;
 use std::{path::Path, sync::Arc};
@@ -36,7 +37,7 @@ impl GitCLI {
 #[async_trait]
 impl Git for GitCLI {
     async fn clone_repository(&self, url: &str, dst: &Path) -> Result<()> {
-        let output = Command::new("git")
+        let output = git_command(url)
             .arg("clone")
             .arg("--depth=10")
             .arg(url)
@@ -50,7 +51,7 @@ impl Git for GitCLI {
     }
 
     async fn remote_digest(&self, url: &str) -> Result<String> {
-        let output = Command::new("git")
+        let output = git_command(url)
             .arg("ls-remote")
             .arg(url)
             .arg("HEAD")
@@ -67,3 +68,13 @@ impl Git for GitCLI {
             .to_string())
     }
 }
+
+/// Prepare a git command to interact with the repository provided, setting
+/// the outbound HTTP proxy configured (if any) to reach it.
+fn git_command(url: &str) -> Command {
+    let mut cmd = Command::new("git");
+    if let Some(proxy) = net::proxy_for(url) {
+        cmd.arg("-c").arg(format!("http.proxy={proxy}"));
+    }
+    cmd
+}
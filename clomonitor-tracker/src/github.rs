@@ -0,0 +1,352 @@
+use crate::notifier::ProjectChanges;
+use anyhow::{format_err, Context, Result};
+use async_trait::async_trait;
+use clomonitor_core::{linter::Report, net};
+use config::Config;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+#[cfg(test)]
+use mockall::automock;
+use reqwest::{
+    header::{ACCEPT, AUTHORIZATION, USER_AGENT},
+    Method, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// GitHub API base url.
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Name of the check runs published by the tracker.
+const CHECK_RUN_NAME: &str = "CLOMonitor";
+
+/// Type alias to represent a GitHub trait object.
+pub(crate) type DynGitHub = Arc<dyn GitHub + Send + Sync>;
+
+/// Trait that defines some operations a GitHub implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub(crate) trait GitHub {
+    /// Publish the check run provided on the repository it belongs to. Check
+    /// runs are only published on the repositories the GitHub App has been
+    /// installed on.
+    async fn publish_check_run(&self, check_run: &CheckRun) -> Result<()>;
+}
+
+/// Summary of the tracking results of a repository, published as a check run
+/// on the head commit of its default branch.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct CheckRun {
+    pub foundation: String,
+    pub project: String,
+    pub repository_url: String,
+    pub head_sha: String,
+    pub score: Option<f64>,
+    pub rating: Option<String>,
+    pub not_passed: Vec<String>,
+}
+
+impl CheckRun {
+    /// Create a new check run from the project changes detected when storing
+    /// the report of the repository provided. The score and rating included
+    /// are the project's ones, as displayed on the dashboard.
+    pub(crate) fn new(
+        repository_url: &str,
+        head_sha: &str,
+        changes: &ProjectChanges,
+        report: &Report,
+    ) -> Self {
+        Self {
+            foundation: changes.foundation.clone(),
+            project: changes.project.clone(),
+            repository_url: repository_url.to_string(),
+            head_sha: head_sha.to_string(),
+            score: changes.score_to,
+            rating: changes.rating_to.clone(),
+            not_passed: report
+                .not_passed()
+                .into_iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+
+    /// Return the check run conclusion. Check runs never fail, so that they
+    /// don't block any of the repository's workflows.
+    fn conclusion(&self) -> &'static str {
+        if self.not_passed.is_empty() {
+            "success"
+        } else {
+            "neutral"
+        }
+    }
+
+    /// Return the check run output title.
+    fn title(&self) -> String {
+        match (self.score, &self.rating) {
+            (Some(score), Some(rating)) => {
+                format!("Score {score:.0} (rating {})", rating.to_uppercase())
+            }
+            _ => "Score not available".to_string(),
+        }
+    }
+
+    /// Return the check run output summary (markdown).
+    fn summary(&self, base_url: &str) -> String {
+        let mut summary = match (self.score, &self.rating) {
+            (Some(score), Some(rating)) => format!(
+                "The CLOMonitor score of **{}** is **{score:.0}** (rating **{}**).",
+                self.project,
+                rating.to_uppercase()
+            ),
+            _ => format!(
+                "The CLOMonitor score of **{}** is not available yet.",
+                self.project
+            ),
+        };
+        if self.not_passed.is_empty() {
+            summary.push_str("\n\nAll checks passed in this repository.");
+        } else {
+            summary.push_str("\n\n**Checks not passed in this repository**:\n\n");
+            summary.push_str(
+                &self
+                    .not_passed
+                    .iter()
+                    .map(|check_id| format!("- {check_id}"))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            );
+        }
+        summary.push_str(&format!(
+            "\n\nSee the [full report]({}) for more details.",
+            self.details_url(base_url)
+        ));
+        summary
+    }
+
+    /// Return the url of the project's page on the dashboard.
+    fn details_url(&self, base_url: &str) -> String {
+        format!(
+            "{}/projects/{}/{}",
+            base_url.trim_end_matches('/'),
+            self.foundation,
+            self.project
+        )
+    }
+}
+
+/// Claims of the JWTs used to authenticate as the GitHub App.
+#[derive(Debug, Serialize)]
+struct AppClaims {
+    iat: i64,
+    exp: i64,
+    iss: String,
+}
+
+/// GitHub App installation (only the fields used are included).
+#[derive(Debug, Deserialize)]
+struct Installation {
+    id: u64,
+}
+
+/// GitHub App installation access token (only the fields used are included).
+#[derive(Debug, Deserialize)]
+struct InstallationToken {
+    token: String,
+}
+
+/// GitHub implementation backed by the GitHub REST API, authenticated as a
+/// GitHub App.
+pub(crate) struct GitHubApp {
+    http_client: reqwest::Client,
+    app_id: String,
+    key: EncodingKey,
+    base_url: String,
+}
+
+impl GitHubApp {
+    /// Create a new GitHubApp instance.
+    pub(crate) fn new(cfg: &Config) -> Result<Self> {
+        let private_key = cfg.get_string("tracker.githubApp.privateKey")?;
+        Ok(Self {
+            http_client: net::client_builder().build()?,
+            app_id: cfg.get_string("tracker.githubApp.appId")?,
+            key: EncodingKey::from_rsa_pem(private_key.as_bytes())
+                .context("invalid GitHub App private key (tracker.githubApp.privateKey)")?,
+            base_url: cfg.get_string("tracker.githubApp.baseURL")?,
+        })
+    }
+
+    /// Create a JWT to authenticate as the GitHub App. Tokens are valid for
+    /// ten minutes at most, and their issue time is set in the past to allow
+    /// for some clock drift.
+    fn jwt(&self) -> Result<String> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let claims = AppClaims {
+            iat: now - 60,
+            exp: now + 9 * 60,
+            iss: self.app_id.clone(),
+        };
+        Ok(encode(&Header::new(Algorithm::RS256), &claims, &self.key)?)
+    }
+
+    /// Prepare a request to the GitHub API endpoint provided.
+    fn request(&self, method: Method, path: &str, token: &str) -> reqwest::RequestBuilder {
+        self.http_client
+            .request(method, format!("{GITHUB_API_URL}{path}"))
+            .header(AUTHORIZATION, format!("Bearer {token}"))
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, "clomonitor-tracker")
+    }
+
+    /// Get an access token for the GitHub App installation on the repository
+    /// provided. None is returned when the app hasn't been installed on it.
+    async fn installation_token(&self, owner: &str, repo: &str) -> Result<Option<String>> {
+        let jwt = self.jwt()?;
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("/repos/{owner}/{repo}/installation"),
+                &jwt,
+            )
+            .send()
+            .await?;
+        let installation: Installation = match resp.status() {
+            StatusCode::OK => serde_json::from_str(&resp.text().await?)?,
+            StatusCode::NOT_FOUND => return Ok(None),
+            status => {
+                return Err(format_err!(
+                    "unexpected status code getting app installation: {status}"
+                ))
+            }
+        };
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("/app/installations/{}/access_tokens", installation.id),
+                &jwt,
+            )
+            .send()
+            .await?;
+        if resp.status() != StatusCode::CREATED {
+            return Err(format_err!(
+                "unexpected status code creating installation token: {}",
+                resp.status()
+            ));
+        }
+        let token: InstallationToken = serde_json::from_str(&resp.text().await?)?;
+        Ok(Some(token.token))
+    }
+}
+
+#[async_trait]
+impl GitHub for GitHubApp {
+    async fn publish_check_run(&self, check_run: &CheckRun) -> Result<()> {
+        let (owner, repo) = get_owner_and_repo(&check_run.repository_url)?;
+        let Some(token) = self.installation_token(&owner, &repo).await? else {
+            return Ok(());
+        };
+        let body = json!({
+            "name": CHECK_RUN_NAME,
+            "head_sha": check_run.head_sha,
+            "status": "completed",
+            "conclusion": check_run.conclusion(),
+            "details_url": check_run.details_url(&self.base_url),
+            "output": {
+                "title": check_run.title(),
+                "summary": check_run.summary(&self.base_url),
+            },
+        });
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("/repos/{owner}/{repo}/check-runs"),
+                &token,
+            )
+            .body(body.to_string())
+            .send()
+            .await?;
+        if resp.status() != StatusCode::CREATED {
+            return Err(format_err!(
+                "unexpected status code creating check run: {}",
+                resp.status()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Extract the owner and repository name from the GitHub repository url
+/// provided.
+fn get_owner_and_repo(repository_url: &str) -> Result<(String, String)> {
+    let path = repository_url
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .split_once("github.com/")
+        .map(|(_, path)| path)
+        .ok_or_else(|| format_err!("invalid github repository url"))?;
+    match path.split('/').collect::<Vec<&str>>()[..] {
+        [owner, repo] if !owner.is_empty() && !repo.is_empty() => {
+            Ok((owner.to_string(), repo.to_string()))
+        }
+        _ => Err(format_err!("invalid github repository url")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_run(not_passed: &[&str]) -> CheckRun {
+        CheckRun {
+            foundation: "cncf".to_string(),
+            project: "artifact-hub".to_string(),
+            repository_url: "https://github.com/artifacthub/hub".to_string(),
+            head_sha: "sha".to_string(),
+            score: Some(85.4),
+            rating: Some("a".to_string()),
+            not_passed: not_passed.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn get_owner_and_repo_valid_url() {
+        assert_eq!(
+            get_owner_and_repo("https://github.com/artifacthub/hub.git").unwrap(),
+            ("artifacthub".to_string(), "hub".to_string())
+        );
+    }
+
+    #[test]
+    fn get_owner_and_repo_invalid_url() {
+        assert!(get_owner_and_repo("https://gitlab.com/group/subgroup/repo").is_err());
+        assert!(get_owner_and_repo("https://github.com/artifacthub").is_err());
+    }
+
+    #[test]
+    fn check_run_all_checks_passed() {
+        let check_run = check_run(&[]);
+        assert_eq!(check_run.conclusion(), "success");
+        assert_eq!(check_run.title(), "Score 85 (rating A)");
+        assert_eq!(
+            check_run.summary("https://clomonitor.io/"),
+            "The CLOMonitor score of **artifact-hub** is **85** (rating **A**).\n\nAll checks passed in this repository.\n\nSee the [full report](https://clomonitor.io/projects/cncf/artifact-hub) for more details."
+        );
+    }
+
+    #[test]
+    fn check_run_some_checks_not_passed() {
+        let check_run = CheckRun {
+            score: None,
+            rating: None,
+            ..check_run(&["license_scanning", "sbom"])
+        };
+        assert_eq!(check_run.conclusion(), "neutral");
+        assert_eq!(check_run.title(), "Score not available");
+        assert_eq!(
+            check_run.summary("https://clomonitor.io"),
+            "The CLOMonitor score of **artifact-hub** is not available yet.\n\n**Checks not passed in this repository**:\n\n- license_scanning\n- sbom\n\nSee the [full report](https://clomonitor.io/projects/cncf/artifact-hub) for more details."
+        );
+    }
+}
@@ -1,17 +1,40 @@
-use crate::{db::PgDB, git::GitCLI};
-use anyhow::{Context, Result};
+use crate::{
+    db::PgDB,
+    git::GitCLI,
+    github::{DynGitHub, GitHubApp},
+    notifier::{ChannelsNotifier, DynNotifier, Subscription},
+};
+use anyhow::{format_err, Context, Result};
 use clap::Parser;
-use clomonitor_core::linter::CoreLinter;
-use config::{Config, File};
+use clomonitor_core::{
+    cache,
+    linter::{CoreLinter, ExternalCheck},
+    net::{self, HostPolicy, RestrictedClient},
+};
+use config::{Config, ConfigError, File};
 use deadpool_postgres::{Config as DbConfig, Runtime};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
-use std::{path::PathBuf, sync::Arc};
-use tracing::debug;
+use std::{
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::signal;
+use tracing::{debug, info, info_span, warn, Instrument};
 use tracing_subscriber::EnvFilter;
+use uuid::Uuid;
 
 mod db;
+mod errors;
+mod events;
 mod git;
+mod github;
+mod notifier;
+mod schedule;
 mod tracker;
 
 #[derive(Debug, Parser)]
@@ -20,6 +43,16 @@ struct Args {
     /// Config file path
     #[clap(short, long)]
     config: PathBuf,
+
+    /// Maximum time the tracker can run for (i.e. 2h, 90m). Once reached, no
+    /// more repositories are processed and the next run resumes from there
+    #[clap(long)]
+    max_duration: Option<String>,
+
+    /// Role the tracker plays in the run (standalone, coordinator, worker or
+    /// ondemand)
+    #[clap(long)]
+    role: Option<String>,
 }
 
 #[tokio::main]
@@ -29,10 +62,20 @@ async fn main() -> Result<()> {
     // Setup configuration
     let cfg = Config::builder()
         .set_default("tracker.concurrency", 10)?
+        .set_default("email.smtp.port", 587)?
         .add_source(File::from(args.config))
+        .set_override_option("tracker.maxDuration", args.max_duration)?
+        .set_override_option("tracker.role", args.role)?
         .build()
         .context("error setting up configuration")?;
 
+    // Setup outbound HTTP proxy (if any)
+    net::setup_proxy(
+        cfg.get_string("http.proxy").ok().as_deref(),
+        cfg.get_string("http.noProxy").ok().as_deref(),
+    )
+    .context("error setting up outbound http proxy")?;
+
     // Setup logging
     if std::env::var_os("RUST_LOG").is_none() {
         std::env::set_var("RUST_LOG", "clomonitor_tracker=debug")
@@ -40,9 +83,15 @@ async fn main() -> Result<()> {
     let s = tracing_subscriber::fmt().with_env_filter(EnvFilter::from_default_env());
     match cfg.get_string("log.format").as_deref() {
         Ok("json") => s.json().init(),
-        _ => s.init(),
+        Ok("pretty") | Err(_) => s.init(),
+        Ok(format) => return Err(format_err!("invalid log format: {format} (log.format)")),
     };
 
+    // All the events logged by this run are recorded within a span identified
+    // by its id, so that they can be correlated
+    let run_id = Uuid::new_v4();
+    let run_span = info_span!("run", %run_id);
+
     // Setup database
     debug!("setting up database");
     let mut builder = SslConnector::builder(SslMethod::tls())?;
@@ -52,8 +101,94 @@ async fn main() -> Result<()> {
     let pool = db_cfg.create_pool(Some(Runtime::Tokio1), connector)?;
     let db = Arc::new(PgDB::new(pool));
 
+    // Setup cache
+    debug!("setting up cache");
+    let redis_url = cfg.get_string("cache.redis.url").ok();
+    let cache = cache::setup(redis_url.as_deref()).await?;
+
+    // Setup notifier (subscriptions can be configured, and projects'
+    // maintainers can register webhooks as well)
+    debug!("setting up notifier");
+    let subscriptions = match cfg.get::<Vec<Subscription>>("tracker.notifications") {
+        Ok(subscriptions) => subscriptions,
+        Err(ConfigError::NotFound(_)) => vec![],
+        Err(err) => return Err(err.into()),
+    };
+    let notifier: Option<DynNotifier> = Some(Arc::new(ChannelsNotifier::new(
+        &cfg,
+        db.clone(),
+        subscriptions,
+    )?));
+
+    // Setup GitHub client (check runs are only published when a GitHub App
+    // has been configured)
+    let github: Option<DynGitHub> = match cfg.get_string("tracker.githubApp.appId") {
+        Ok(_) => {
+            debug!("setting up github client");
+            Some(Arc::new(GitHubApp::new(&cfg)?))
+        }
+        Err(_) => None,
+    };
+
     // Run tracker
     let git = Arc::new(GitCLI::new()?);
-    let linter = Arc::new(CoreLinter::new());
-    tracker::run(&cfg, db, git, linter).await
+    let external_checks = match cfg.get::<Vec<ExternalCheck>>("tracker.externalChecks") {
+        Ok(external_checks) => external_checks,
+        Err(ConfigError::NotFound(_)) => vec![],
+        Err(err) => return Err(err.into()),
+    };
+    let host_policy = match cfg.get::<HostPolicy>("http.hostPolicy") {
+        Ok(host_policy) => host_policy,
+        Err(ConfigError::NotFound(_)) => HostPolicy::default(),
+        Err(err) => return Err(err.into()),
+    };
+    let linter = Arc::new(
+        CoreLinter::with_cache(cache)
+            .with_external_checks(external_checks)
+            .with_restricted_client(RestrictedClient::new(host_policy)?),
+    );
+    let shutdown = Arc::new(AtomicBool::new(false));
+    tokio::spawn(shutdown_on_signal(shutdown.clone()).instrument(run_span.clone()));
+    tracker::run(&cfg, db, git, linter, notifier, github, shutdown)
+        .instrument(run_span)
+        .await
+}
+
+/// Request the tracker to shut down when a termination signal is received,
+/// so that it stops processing repositories once the ones in progress have
+/// been tracked. If a second signal is received, the tracker exits right away.
+async fn shutdown_on_signal(shutdown: Arc<AtomicBool>) {
+    shutdown_signal().await;
+    info!("tracker stopping, waiting for the repositories in progress to be tracked");
+    shutdown.store(true, Ordering::SeqCst);
+
+    shutdown_signal().await;
+    warn!("tracker stopped before the repositories in progress were tracked");
+    process::exit(1);
+}
+
+async fn shutdown_signal() {
+    // Setup signal handlers
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("ctrl+c signal handler to be installed");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("terminate signal handler to be installed")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    // Wait for any of the signals
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
 }
@@ -0,0 +1,574 @@
+use crate::db::DynDB;
+use anyhow::{format_err, Result};
+use askama::Template;
+use async_trait::async_trait;
+use clomonitor_core::{linter::Report, net};
+use config::Config;
+use lettre::{
+    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+#[cfg(test)]
+use mockall::automock;
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Type alias to represent a Notifier trait object.
+pub(crate) type DynNotifier = Arc<dyn Notifier + Send + Sync>;
+
+/// Trait that defines some operations a Notifier implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub(crate) trait Notifier {
+    /// Notify the project changes provided to the channels subscribed to them.
+    async fn notify(&self, changes: &ProjectChanges) -> Result<()>;
+}
+
+/// Changes detected in a project when storing the tracking results of one of
+/// its repositories.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ProjectChanges {
+    pub foundation: String,
+    pub project: String,
+    pub repository_url: String,
+    pub rating_from: Option<String>,
+    pub rating_to: Option<String>,
+    pub score_from: Option<f64>,
+    pub score_to: Option<f64>,
+    pub new_not_passed_security_checks: Vec<String>,
+
+    /// Probable causes of the score drop (if any), based on the events
+    /// detected in the repository.
+    pub probable_causes: Vec<String>,
+}
+
+impl ProjectChanges {
+    /// Check if the project's rating dropped.
+    pub(crate) fn rating_dropped(&self) -> bool {
+        // Ratings go from a (best) to d (worst)
+        match (&self.rating_from, &self.rating_to) {
+            (Some(from), Some(to)) => to > from,
+            _ => false,
+        }
+    }
+
+    /// Check if the project's global score dropped.
+    pub(crate) fn score_dropped(&self) -> bool {
+        match (self.score_from, self.score_to) {
+            (Some(from), Some(to)) => to.round() < from.round(),
+            _ => false,
+        }
+    }
+}
+
+/// Return the security checks not passed in the current report that were not
+/// failing in the previous one. Nothing is returned when there is no previous
+/// report, to avoid notifying about repositories tracked for the first time.
+pub(crate) fn new_not_passed_security_checks(
+    previous: Option<&Report>,
+    current: Option<&Report>,
+) -> Vec<String> {
+    let (Some(previous), Some(current)) = (previous, current) else {
+        return vec![];
+    };
+    let previously_not_passed = previous.security.not_passed();
+    current
+        .security
+        .not_passed()
+        .into_iter()
+        .filter(|check_id| !previously_not_passed.contains(check_id))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Events channels can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Event {
+    /// The project's rating dropped (i.e. from A to B).
+    RatingDrop,
+    /// The project's global score dropped.
+    ScoreDrop,
+    /// Some security checks that used to pass are not passing anymore.
+    SecurityCheckFailed,
+}
+
+/// Channels notifications can be delivered through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Channel {
+    /// Slack incoming webhook (the target is the webhook url).
+    Slack,
+    /// Email (the target is the recipient address).
+    Email,
+}
+
+/// Subscription of a channel to some events of a foundation's projects, or of
+/// a single project when one is provided.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct Subscription {
+    pub foundation: String,
+    pub project: Option<String>,
+    pub channel: Channel,
+    pub target: String,
+    pub events: Vec<Event>,
+
+    /// Ratings the project must be dropping from/to to trigger rating drop
+    /// notifications (any drop triggers them when not provided).
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl Subscription {
+    /// Create a subscription for the webhook registered by the maintainers of
+    /// the project provided.
+    pub(crate) fn from_webhook(foundation: &str, project: &str, webhook: ProjectWebhook) -> Self {
+        Self {
+            foundation: foundation.to_string(),
+            project: Some(project.to_string()),
+            channel: Channel::Slack,
+            target: webhook.url,
+            events: webhook.events,
+            from: None,
+            to: None,
+        }
+    }
+
+    /// Return the events the subscription is interested in that match the
+    /// project changes provided.
+    pub(crate) fn matching_events(&self, changes: &ProjectChanges) -> Vec<Event> {
+        if self.foundation != changes.foundation
+            || self
+                .project
+                .as_ref()
+                .map_or(false, |project| *project != changes.project)
+        {
+            return vec![];
+        }
+
+        let rating_matches = |filter: &Option<String>, rating: &Option<String>| match filter {
+            Some(filter) => Some(filter.to_lowercase()) == *rating,
+            None => true,
+        };
+        let mut events = vec![];
+        for event in &self.events {
+            let matches = match event {
+                Event::RatingDrop => {
+                    changes.rating_dropped()
+                        && rating_matches(&self.from, &changes.rating_from)
+                        && rating_matches(&self.to, &changes.rating_to)
+                }
+                Event::ScoreDrop => changes.score_dropped(),
+                Event::SecurityCheckFailed => !changes.new_not_passed_security_checks.is_empty(),
+            };
+            if matches && !events.contains(event) {
+                events.push(*event);
+            }
+        }
+        events
+    }
+}
+
+/// Webhook registered by the maintainers of a project using the admin API.
+/// Webhooks are expected to accept the payload of Slack incoming webhooks.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct ProjectWebhook {
+    pub url: String,
+    pub events: Vec<Event>,
+}
+
+/// Template for the notifications messages.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notification.txt")]
+pub(crate) struct NotificationTemplate {
+    pub foundation: String,
+    pub project: String,
+    pub repository_url: String,
+    pub rating_from: String,
+    pub rating_to: String,
+    pub score_from: f64,
+    pub score_to: f64,
+    pub security_checks: Vec<String>,
+    pub probable_causes: Vec<String>,
+    pub rating_drop: bool,
+    pub score_drop: bool,
+    pub security_check_failed: bool,
+}
+
+impl NotificationTemplate {
+    /// Create a new NotificationTemplate instance for the project changes and
+    /// events provided.
+    fn new(changes: &ProjectChanges, events: &[Event]) -> Self {
+        Self {
+            foundation: changes.foundation.clone(),
+            project: changes.project.clone(),
+            repository_url: changes.repository_url.clone(),
+            rating_from: changes.rating_from.clone().unwrap_or_default(),
+            rating_to: changes.rating_to.clone().unwrap_or_default(),
+            score_from: changes.score_from.unwrap_or_default(),
+            score_to: changes.score_to.unwrap_or_default(),
+            security_checks: changes.new_not_passed_security_checks.clone(),
+            probable_causes: changes.probable_causes.clone(),
+            rating_drop: events.contains(&Event::RatingDrop),
+            score_drop: events.contains(&Event::ScoreDrop),
+            security_check_failed: events.contains(&Event::SecurityCheckFailed),
+        }
+    }
+}
+
+/// Notifier implementation that delivers notifications through the channels
+/// subscribed to the projects events, including the ones configured and the
+/// webhooks registered by the projects' maintainers.
+pub(crate) struct ChannelsNotifier {
+    db: DynDB,
+    subscriptions: Vec<Subscription>,
+    http_client: reqwest::Client,
+    email: Option<(Mailbox, AsyncSmtpTransport<Tokio1Executor>)>,
+}
+
+impl ChannelsNotifier {
+    /// Create a new ChannelsNotifier instance.
+    pub(crate) fn new(cfg: &Config, db: DynDB, subscriptions: Vec<Subscription>) -> Result<Self> {
+        // Email notifications require an SMTP server to be configured
+        let email = match cfg.get_string("email.smtp.host") {
+            Ok(host) => {
+                let from = cfg.get_string("email.from")?.parse()?;
+                let mut builder = AsyncSmtpTransport::<Tokio1Executor>::relay(&host)?
+                    .port(cfg.get("email.smtp.port")?);
+                if let Ok(username) = cfg.get_string("email.smtp.username") {
+                    let password = cfg.get_string("email.smtp.password")?;
+                    builder = builder.credentials(Credentials::new(username, password));
+                }
+                Some((from, builder.build()))
+            }
+            Err(_) => None,
+        };
+        if email.is_none()
+            && subscriptions
+                .iter()
+                .any(|subscription| subscription.channel == Channel::Email)
+        {
+            return Err(format_err!(
+                "email notifications subscriptions found, but no SMTP server has been configured (email.smtp.host)"
+            ));
+        }
+
+        Ok(Self {
+            db,
+            subscriptions,
+            http_client: net::client_builder().build()?,
+            email,
+        })
+    }
+
+    /// Post the message provided to the Slack incoming webhook given.
+    async fn send_slack(&self, webhook_url: &str, message: &str) -> Result<()> {
+        let resp = self
+            .http_client
+            .post(webhook_url)
+            .header("content-type", "application/json")
+            .body(json!({ "text": message }).to_string())
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            return Err(format_err!(
+                "unexpected status code posting to slack webhook: {}",
+                resp.status()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Email the message provided to the recipient given.
+    async fn send_email(
+        &self,
+        recipient: &str,
+        changes: &ProjectChanges,
+        message: String,
+    ) -> Result<()> {
+        let (from, transport) = self.email.as_ref().expect("email to be configured");
+        let message = Message::builder()
+            .from(from.clone())
+            .to(recipient.parse()?)
+            .subject(format!(
+                "CLOMonitor notification: {} ({})",
+                changes.project, changes.foundation
+            ))
+            .body(message)?;
+        transport.send(message).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Notifier for ChannelsNotifier {
+    async fn notify(&self, changes: &ProjectChanges) -> Result<()> {
+        // Nothing to notify, no need to look up the project's webhooks
+        if !changes.rating_dropped()
+            && !changes.score_dropped()
+            && changes.new_not_passed_security_checks.is_empty()
+        {
+            return Ok(());
+        }
+
+        let webhooks = self
+            .db
+            .project_webhooks(&changes.foundation, &changes.project)
+            .await?
+            .into_iter()
+            .map(|webhook| {
+                Subscription::from_webhook(&changes.foundation, &changes.project, webhook)
+            });
+        let subscriptions: Vec<Subscription> =
+            self.subscriptions.iter().cloned().chain(webhooks).collect();
+
+        let mut errors = vec![];
+        for subscription in &subscriptions {
+            let events = subscription.matching_events(changes);
+            if events.is_empty() {
+                continue;
+            }
+            let message = NotificationTemplate::new(changes, &events).render()?;
+            let result = match subscription.channel {
+                Channel::Slack => self.send_slack(&subscription.target, &message).await,
+                Channel::Email => {
+                    self.send_email(&subscription.target, changes, message)
+                        .await
+                }
+            };
+            if let Err(err) = result {
+                errors.push(format!(
+                    "{:?} notification: {:#}",
+                    subscription.channel, err
+                ));
+            }
+        }
+        if !errors.is_empty() {
+            return Err(format_err!("{}", errors.join("\n")));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{CheckOutput, Security};
+
+    const FOUNDATION: &str = "cncf";
+    const PROJECT: &str = "artifact-hub";
+
+    fn subscription(events: Vec<Event>) -> Subscription {
+        Subscription {
+            foundation: FOUNDATION.to_string(),
+            project: None,
+            channel: Channel::Slack,
+            target: "https://hooks.slack.com/services/test".to_string(),
+            events,
+            from: None,
+            to: None,
+        }
+    }
+
+    fn changes(rating_from: &str, rating_to: &str, checks: &[&str]) -> ProjectChanges {
+        ProjectChanges {
+            foundation: FOUNDATION.to_string(),
+            project: PROJECT.to_string(),
+            repository_url: "https://github.com/artifacthub/hub".to_string(),
+            rating_from: Some(rating_from.to_string()),
+            rating_to: Some(rating_to.to_string()),
+            new_not_passed_security_checks: checks.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matching_events_other_foundation() {
+        let subscription = Subscription {
+            foundation: "other".to_string(),
+            ..subscription(vec![Event::RatingDrop])
+        };
+
+        assert!(subscription
+            .matching_events(&changes("a", "b", &[]))
+            .is_empty());
+    }
+
+    #[test]
+    fn matching_events_other_project() {
+        let subscription = Subscription {
+            project: Some("other".to_string()),
+            ..subscription(vec![Event::RatingDrop])
+        };
+
+        assert!(subscription
+            .matching_events(&changes("a", "b", &[]))
+            .is_empty());
+    }
+
+    #[test]
+    fn matching_events_rating_drop() {
+        let subscription = subscription(vec![Event::RatingDrop, Event::SecurityCheckFailed]);
+
+        assert_eq!(
+            subscription.matching_events(&changes("a", "c", &[])),
+            vec![Event::RatingDrop]
+        );
+        assert!(subscription
+            .matching_events(&changes("c", "a", &[]))
+            .is_empty());
+        assert!(subscription
+            .matching_events(&changes("b", "b", &[]))
+            .is_empty());
+    }
+
+    #[test]
+    fn matching_events_rating_drop_transition() {
+        let subscription = Subscription {
+            project: Some(PROJECT.to_string()),
+            from: Some("A".to_string()),
+            to: Some("B".to_string()),
+            ..subscription(vec![Event::RatingDrop])
+        };
+
+        assert_eq!(
+            subscription.matching_events(&changes("a", "b", &[])),
+            vec![Event::RatingDrop]
+        );
+        assert!(subscription
+            .matching_events(&changes("a", "c", &[]))
+            .is_empty());
+    }
+
+    #[test]
+    fn matching_events_security_check_failed() {
+        let subscription = subscription(vec![Event::SecurityCheckFailed]);
+
+        assert_eq!(
+            subscription.matching_events(&changes("a", "a", &["code_review"])),
+            vec![Event::SecurityCheckFailed]
+        );
+        assert!(subscription
+            .matching_events(&changes("a", "b", &[]))
+            .is_empty());
+    }
+
+    #[test]
+    fn matching_events_score_drop() {
+        let subscription = subscription(vec![Event::ScoreDrop]);
+        let score_changes = |from: f64, to: f64| ProjectChanges {
+            score_from: Some(from),
+            score_to: Some(to),
+            ..changes("a", "a", &[])
+        };
+
+        assert_eq!(
+            subscription.matching_events(&score_changes(90.0, 85.0)),
+            vec![Event::ScoreDrop]
+        );
+        assert!(subscription
+            .matching_events(&score_changes(85.0, 90.0))
+            .is_empty());
+        assert!(subscription
+            .matching_events(&score_changes(90.2, 89.8))
+            .is_empty());
+        assert!(subscription
+            .matching_events(&changes("a", "b", &[]))
+            .is_empty());
+    }
+
+    #[test]
+    fn matching_events_project_webhook() {
+        let webhook = ProjectWebhook {
+            url: "https://hooks.slack.com/services/test".to_string(),
+            events: vec![Event::SecurityCheckFailed],
+        };
+        let subscription = Subscription::from_webhook(FOUNDATION, PROJECT, webhook);
+
+        assert_eq!(
+            subscription.matching_events(&changes("a", "a", &["sbom"])),
+            vec![Event::SecurityCheckFailed]
+        );
+        assert!(Subscription {
+            project: Some("other".to_string()),
+            ..subscription
+        }
+        .matching_events(&changes("a", "a", &["sbom"]))
+        .is_empty());
+    }
+
+    #[test]
+    fn new_not_passed_security_checks_detected() {
+        let previous = Report {
+            security: Security {
+                code_review: Some(CheckOutput::not_passed()),
+                sbom: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let current = Report {
+            security: Security {
+                code_review: Some(CheckOutput::not_passed()),
+                maintained: Some(CheckOutput::not_passed()),
+                sbom: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            new_not_passed_security_checks(Some(&previous), Some(&current)),
+            vec!["maintained".to_string(), "sbom".to_string()]
+        );
+        assert!(new_not_passed_security_checks(None, Some(&current)).is_empty());
+        assert!(new_not_passed_security_checks(Some(&previous), None).is_empty());
+    }
+
+    #[test]
+    fn notification_template_renders() {
+        let changes = changes("a", "b", &["sbom"]);
+        let message =
+            NotificationTemplate::new(&changes, &[Event::RatingDrop, Event::SecurityCheckFailed])
+                .render()
+                .unwrap();
+
+        assert_eq!(
+            message,
+            "CLOMonitor update for artifact-hub (cncf)
+
+- The project's rating dropped from A to B.
+- Some security checks are not passing anymore in https://github.com/artifacthub/hub: sbom."
+        );
+    }
+
+    #[test]
+    fn notification_template_renders_probable_causes() {
+        let changes = ProjectChanges {
+            score_from: Some(92.4),
+            score_to: Some(81.6),
+            probable_causes: vec![
+                "file ADOPTERS.md was deleted or moved (adopters)".to_string(),
+                "branch protection rules changed (code_review)".to_string(),
+            ],
+            ..changes("a", "b", &[])
+        };
+        let message = NotificationTemplate::new(&changes, &[Event::RatingDrop, Event::ScoreDrop])
+            .render()
+            .unwrap();
+
+        assert_eq!(
+            message,
+            "CLOMonitor update for artifact-hub (cncf)
+
+- The project's rating dropped from A to B.
+- The project's score dropped from 92 to 82.
+
+Probable causes in https://github.com/artifacthub/hub:
+
+- file ADOPTERS.md was deleted or moved (adopters)
+- branch protection rules changed (code_review)"
+        );
+    }
+}
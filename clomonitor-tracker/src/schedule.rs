@@ -0,0 +1,139 @@
+use anyhow::{format_err, Context, Result};
+use chrono::{TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use time::OffsetDateTime;
+
+/// Tracking schedule of some projects, defined in the configuration. Projects
+/// can be selected by foundation and/or maturity, and are tracked once per
+/// interval (i.e. 1d, 7d) or on each cron occurrence (i.e. `0 0 0 * * Sun`,
+/// seconds included). Projects not matched by any schedule are tracked on
+/// every run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub(crate) struct Schedule {
+    pub foundation: Option<String>,
+    pub maturity: Option<String>,
+    pub interval: Option<String>,
+    pub cron: Option<String>,
+}
+
+/// Schedule resolved at a given point in time. The repositories of the
+/// projects it matches are only queued for tracking if they haven't been
+/// tracked since `tracked_before` (unix timestamp).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct DueSchedule {
+    pub foundation: Option<String>,
+    pub maturity: Option<String>,
+    pub tracked_before: i64,
+}
+
+impl Schedule {
+    /// Resolve the schedule at the point in time provided.
+    fn due(&self, now: OffsetDateTime) -> Result<DueSchedule> {
+        let tracked_before = match (&self.interval, &self.cron) {
+            (Some(interval), None) => {
+                let interval = humantime::parse_duration(interval)
+                    .context(format!("invalid interval {interval}"))?;
+                now - interval
+            }
+            (None, Some(cron)) => {
+                let schedule =
+                    cron::Schedule::from_str(cron).context(format!("invalid cron {cron}"))?;
+                let now = Utc
+                    .timestamp_opt(now.unix_timestamp(), 0)
+                    .single()
+                    .ok_or_else(|| format_err!("invalid current time"))?;
+                let last = schedule
+                    .after(&now)
+                    .next_back()
+                    .ok_or_else(|| format_err!("cron {cron} has no past occurrences"))?;
+                OffsetDateTime::from_unix_timestamp(last.timestamp())?
+            }
+            _ => {
+                return Err(format_err!(
+                    "either an interval or a cron expression must be provided"
+                ))
+            }
+        };
+        Ok(DueSchedule {
+            foundation: self.foundation.clone(),
+            maturity: self.maturity.clone(),
+            tracked_before: tracked_before.unix_timestamp(),
+        })
+    }
+}
+
+/// Resolve the schedules provided at the point in time given. Schedules are
+/// matched in order, so the most specific ones should be defined first.
+pub(crate) fn due(schedules: &[Schedule], now: OffsetDateTime) -> Result<Vec<DueSchedule>> {
+    schedules
+        .iter()
+        .enumerate()
+        .map(|(i, schedule)| {
+            schedule
+                .due(now)
+                .context(format!("invalid schedule {} (tracker.schedules)", i + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    const NOW: OffsetDateTime = datetime!(2023-01-18 10:30:00 UTC);
+
+    #[test]
+    fn due_interval() {
+        let schedules = vec![Schedule {
+            maturity: Some("sandbox".to_string()),
+            interval: Some("7d".to_string()),
+            ..Schedule::default()
+        }];
+        assert_eq!(
+            due(&schedules, NOW).unwrap(),
+            vec![DueSchedule {
+                foundation: None,
+                maturity: Some("sandbox".to_string()),
+                tracked_before: datetime!(2023-01-11 10:30:00 UTC).unix_timestamp(),
+            }]
+        );
+    }
+
+    #[test]
+    fn due_cron() {
+        let schedules = vec![Schedule {
+            foundation: Some("cncf".to_string()),
+            cron: Some("0 0 0 * * Sun".to_string()),
+            ..Schedule::default()
+        }];
+        assert_eq!(
+            due(&schedules, NOW).unwrap(),
+            vec![DueSchedule {
+                foundation: Some("cncf".to_string()),
+                maturity: None,
+                tracked_before: datetime!(2023-01-15 00:00:00 UTC).unix_timestamp(),
+            }]
+        );
+    }
+
+    #[test]
+    fn due_invalid_schedule() {
+        let schedules = vec![
+            Schedule {
+                interval: Some("1d".to_string()),
+                ..Schedule::default()
+            },
+            Schedule {
+                interval: Some("1d".to_string()),
+                cron: Some("0 0 0 * * *".to_string()),
+                ..Schedule::default()
+            },
+        ];
+        assert_eq!(
+            due(&schedules, NOW).unwrap_err().to_string(),
+            "invalid schedule 2 (tracker.schedules)"
+        );
+    }
+}
@@ -1,22 +1,53 @@
-use crate::{db::DynDB, git::DynGit};
+use crate::{
+    db::DynDB,
+    errors::{self, ErrorClass, TrackingError},
+    git::DynGit,
+    github::{CheckRun, DynGitHub},
+    notifier::DynNotifier,
+    schedule::{self, Schedule},
+};
 use anyhow::{format_err, Error, Result};
 #[cfg(not(test))]
-use clomonitor_core::linter::setup_github_http_client;
-use clomonitor_core::linter::{CheckSet, DynLinter, LinterInput};
-use config::Config;
+use clomonitor_core::linter::{setup_github_http_client, validate_github_token_scopes};
+use clomonitor_core::linter::{CheckOverrides, CheckSet, DynLinter, LinterInput, Provider};
+use config::{Config, ConfigError};
 use deadpool::unmanaged::{Object, Pool};
 use futures::stream::{self, StreamExt};
+use serde::Deserialize;
 #[cfg(not(test))]
 use serde_json::Value;
-use std::time::{Duration, Instant};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 use tempfile::Builder;
 use time::{self, OffsetDateTime};
 use tokio::{task::JoinError, time::timeout};
-use tracing::{debug, error, info, instrument, warn};
+use tracing::{debug, error, field, info, instrument, warn, Instrument, Span};
 use uuid::Uuid;
 
 /// Maximum time that can take tracking a single repository.
-const REPOSITORY_TRACK_TIMEOUT: u64 = 600;
+pub(crate) const REPOSITORY_TRACK_TIMEOUT: u64 = 600;
+
+/// Role the tracker plays in a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Role {
+    /// Start (or resume) a run and track all its repositories.
+    Standalone,
+    /// Start (or resume) a run, leaving the tracking to the workers.
+    Coordinator,
+    /// Track the repositories claimed from the queue of the run in progress.
+    Worker,
+    /// Start an on demand run and track the repositories whose tracking has
+    /// been requested, even if they haven't changed.
+    #[serde(rename = "ondemand")]
+    OnDemand,
+}
 
 /// A project's repository.
 #[derive(Debug, Clone)]
@@ -24,12 +55,43 @@ pub(crate) struct Repository {
     pub repository_id: Uuid,
     pub url: String,
     pub check_sets: Vec<CheckSet>,
+    pub check_overrides: CheckOverrides,
+    pub provider: Option<Provider>,
+    pub container_images: Vec<String>,
+    pub gerrit_url: Option<String>,
     pub digest: Option<String>,
     pub updated_at: OffsetDateTime,
+    pub home_url: Option<String>,
+}
+
+impl Repository {
+    /// Return the provider hosting the repository. When it hasn't been set
+    /// explicitly, it's detected from the repository url (GitHub is assumed
+    /// if it cannot be detected).
+    pub(crate) fn provider(&self) -> Provider {
+        self.provider
+            .or_else(|| Provider::from_url(&self.url))
+            .unwrap_or_default()
+    }
 }
 
-/// Track all repositories registered in the database.
-pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter) -> Result<()> {
+/// Track all repositories registered in the database. The id of the tracker
+/// run processed is recorded in the span, so that the events logged while
+/// tracking its repositories can be correlated.
+///
+/// Once a shutdown has been requested no more repositories are processed, but
+/// the ones in progress are tracked to completion. The run is then recorded as
+/// interrupted, and the next one resumes from the repositories still pending.
+#[instrument(fields(tracker_run_id = field::Empty), skip_all, err)]
+pub(crate) async fn run(
+    cfg: &Config,
+    db: DynDB,
+    git: DynGit,
+    linter: DynLinter,
+    notifier: Option<DynNotifier>,
+    github: Option<DynGitHub>,
+    shutdown: Arc<AtomicBool>,
+) -> Result<()> {
     info!("tracker started");
 
     // Setup GitHub tokens pool
@@ -41,44 +103,216 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
     }
     let gh_tokens_pool = Pool::from(gh_tokens.clone());
 
-    // Get repositories to process
+    // Setup the tokens of the GitHub organizations owners (optional), used by
+    // the checks that verify the organizations settings
+    let gh_org_tokens = match cfg.get::<HashMap<String, String>>("creds.githubOrgTokens") {
+        Ok(tokens) => tokens,
+        Err(ConfigError::NotFound(_)) => HashMap::new(),
+        Err(err) => {
+            return Err(format_err!(
+                "invalid GitHub organizations tokens (creds.githubOrgTokens): {err}"
+            ))
+        }
+    };
+    let gh_org_tokens: Arc<HashMap<String, String>> = Arc::new(
+        gh_org_tokens
+            .into_iter()
+            .map(|(org, token)| (org.to_lowercase(), token))
+            .collect(),
+    );
+
+    // Setup deadline from the maximum duration provided (if any)
+    let deadline = match cfg.get_string("tracker.maxDuration") {
+        Ok(max_duration) => {
+            let max_duration = humantime::parse_duration(&max_duration)
+                .map_err(|err| format_err!("invalid max duration (tracker.maxDuration): {err}"))?;
+            Some(Instant::now() + max_duration)
+        }
+        Err(_) => None,
+    };
+
+    // Setup the role the tracker will play in the run
+    let role = match cfg.get::<Role>("tracker.role") {
+        Ok(role) => role,
+        Err(ConfigError::NotFound(_)) => Role::Standalone,
+        Err(err) => return Err(format_err!("invalid role (tracker.role): {err}")),
+    };
+
+    // Setup the tracking schedules (repositories not matched by any schedule
+    // are tracked on every run)
+    let schedules = match cfg.get::<Vec<Schedule>>("tracker.schedules") {
+        Ok(schedules) => schedules,
+        Err(ConfigError::NotFound(_)) => vec![],
+        Err(err) => return Err(format_err!("invalid schedules (tracker.schedules): {err}")),
+    };
+    let schedules = schedule::due(&schedules, OffsetDateTime::now_utc())?;
+
+    // Start a new run (or resume the one in progress) and get the
+    // repositories pending in it. Workers join the run in progress.
     debug!("getting repositories");
-    let repositories = db.repositories().await?;
+    let run_id = match role {
+        Role::Standalone | Role::Coordinator => db.start_run(&schedules).await?,
+        Role::Worker => match db.current_run().await? {
+            Some(run_id) => run_id,
+            None => {
+                info!("no tracker run in progress");
+                info!("tracker finished");
+                return Ok(());
+            }
+        },
+        Role::OnDemand => match db.start_on_demand_run().await? {
+            Some(run_id) => run_id,
+            None => {
+                info!("no tracking requests pending");
+                info!("tracker finished");
+                return Ok(());
+            }
+        },
+    };
+    Span::current().record("tracker_run_id", field::display(run_id));
+    if role == Role::Coordinator {
+        info!(
+            "tracker run {} ready to be processed by the workers",
+            run_id
+        );
+        info!("tracker finished");
+        return Ok(());
+    }
+    let repositories = db.repositories(&run_id).await?;
     if repositories.is_empty() {
         info!("no repositories found");
+        db.finish_run(&run_id).await?;
         info!("tracker finished");
         return Ok(());
     }
 
-    // Track repositories
+    // Check GitHub tokens have the scopes required by the checks to run
+    #[cfg(not(test))]
+    {
+        let mut check_sets: Vec<CheckSet> = vec![];
+        for check_set in repositories.iter().flat_map(|r| r.check_sets.iter()) {
+            if !check_sets.contains(check_set) {
+                check_sets.push(check_set.clone());
+            }
+        }
+        validate_github_tokens(&gh_tokens, &check_sets).await?;
+    }
+
+    // Track repositories (those tracked least recently first). Workers claim
+    // them one at a time from the run's queue, as other workers may be
+    // processing the same run concurrently.
     info!("tracking repositories");
-    let result = stream::iter(repositories)
+    let repositories = match role {
+        Role::Worker => stream::unfold((), |_| {
+            let db = db.clone();
+            let shutdown = shutdown.clone();
+            async move {
+                if should_stop(&shutdown, deadline) {
+                    return None;
+                }
+                match db.claim_repository(&run_id).await {
+                    Ok(repository) => repository.map(|repository| (repository, ())),
+                    Err(err) => {
+                        error!("error claiming repository: {:#}", err);
+                        None
+                    }
+                }
+            }
+        })
+        .boxed(),
+        _ => stream::iter(repositories).boxed(),
+    };
+    let result = repositories
         .map(|repository| async {
+            // Once the deadline has been reached (or a shutdown has been
+            // requested) no more repositories are processed. The next run
+            // will resume from the ones skipped.
+            if should_stop(&shutdown, deadline) {
+                return Ok(());
+            }
+
             let db = db.clone();
             let git = git.clone();
             let linter = linter.clone();
+            let notifier = notifier.clone();
+            let github = github.clone();
             let github_token = gh_tokens_pool.get().await.expect("token -when available-");
+            let github_org_token = org_token(&gh_org_tokens, &repository.url);
             let repository_id = repository.repository_id;
 
-            tokio::spawn(async move {
-                match timeout(
+            // Tasks are linked to the current span, so that their events
+            // are recorded within the tracker run
+            let task = async move {
+                // Repositories claimed by workers are already in progress
+                if role != Role::Worker {
+                    if let Err(err) = db.start_repository(&run_id, &repository_id).await {
+                        error!(
+                            "error marking repository {} as in progress: {:#}",
+                            repository_id, err
+                        );
+                    }
+                }
+
+                // Repositories requested on demand are tracked even if they
+                // haven't changed
+                let force = role == Role::OnDemand;
+                let tracking_errors = match timeout(
                     Duration::from_secs(REPOSITORY_TRACK_TIMEOUT),
-                    track_repository(db, git, linter, github_token, repository),
+                    track_repository(
+                        db.clone(),
+                        git,
+                        linter,
+                        notifier,
+                        github,
+                        github_token,
+                        github_org_token,
+                        repository,
+                        force,
+                    ),
                 )
                 .await
                 {
                     Ok(result) => match result {
-                        Ok(()) => {}
+                        Ok(tracking_errors) => tracking_errors,
                         Err(err) => {
-                            error!("error tracking repository {}: {:#}", repository_id, err)
+                            error!("error tracking repository {}: {:#}", repository_id, err);
+                            vec![TrackingError::repository(format!(
+                                "error tracking repository: {err:#}"
+                            ))]
                         }
                     },
                     Err(err) => {
-                        warn!("timeout tracking repository {}: {}", repository_id, err)
+                        warn!("timeout tracking repository {}: {}", repository_id, err);
+                        vec![TrackingError {
+                            check_id: None,
+                            class: ErrorClass::Timeout,
+                            message: format!("timeout tracking repository: {err}"),
+                        }]
+                    }
+                };
+
+                // Register the errors found in the run's audit log
+                if !tracking_errors.is_empty() {
+                    if let Err(err) = db
+                        .store_tracking_errors(&run_id, &repository_id, &tracking_errors)
+                        .await
+                    {
+                        error!(
+                            "error storing repository {} tracking errors: {:#}",
+                            repository_id, err
+                        );
                     }
                 }
-            })
-            .await
+
+                // Checkpoint progress, so that the next run resumes from here
+                if let Err(err) = db.update_tracked_at(&run_id, &repository_id).await {
+                    error!(
+                        "error updating repository {} tracked at: {:#}",
+                        repository_id, err
+                    );
+                }
+            };
+            tokio::spawn(task.in_current_span()).await
         })
         .buffer_unordered(cfg.get("tracker.concurrency")?)
         .collect::<Vec<Result<(), JoinError>>>()
@@ -95,6 +329,20 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
             },
         );
 
+    // Finish run (only if all its repositories have been processed)
+    if let Err(err) = db.finish_run(&run_id).await {
+        error!("error finishing tracker run {}: {:#}", run_id, err);
+    }
+
+    // Record the run as interrupted if a shutdown was requested (all the
+    // results of the repositories tracked have been stored at this point)
+    let shutdown_requested = shutdown.load(Ordering::SeqCst);
+    if shutdown_requested {
+        if let Err(err) = db.interrupt_run(&run_id).await {
+            error!("error interrupting tracker run {}: {:#}", run_id, err);
+        }
+    }
+
     // Check Github API rate limit status for each token
     #[cfg(not(test))]
     for (i, token) in gh_tokens.into_iter().enumerate() {
@@ -111,20 +359,72 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
         );
     }
 
+    if shutdown_requested {
+        info!("tracker interrupted, remaining repositories will be tracked in the next run");
+    } else if deadline.map_or(false, |deadline| Instant::now() >= deadline) {
+        info!("max duration reached, remaining repositories will be tracked in the next run");
+    }
     info!("tracker finished");
     result
 }
 
-/// Track repository if it has changed since the last time it was tracked.
-/// This involves cloning the repository, linting it and storing the results.
+/// Check if the tracker should stop processing repositories, as a shutdown
+/// has been requested or the deadline provided has been reached.
+fn should_stop(shutdown: &AtomicBool, deadline: Option<Instant>) -> bool {
+    shutdown.load(Ordering::SeqCst) || deadline.map_or(false, |deadline| Instant::now() >= deadline)
+}
+
+/// Validate that the GitHub tokens provided have the scopes required by the
+/// checks in the check sets provided, returning an error listing the scopes
+/// missing per check when they don't.
+#[cfg(not(test))]
+async fn validate_github_tokens(gh_tokens: &[String], check_sets: &[CheckSet]) -> Result<()> {
+    let mut errors = vec![];
+    for (i, token) in gh_tokens.iter().enumerate() {
+        match validate_github_token_scopes(token, check_sets).await? {
+            Some(report) if !report.is_valid() => errors.push(format!("token [{i}]:\n{report}")),
+            Some(report) if !report.unneeded.is_empty() => warn!(
+                "token [{}] has some scopes not needed: {}",
+                i,
+                report.unneeded.join(", ")
+            ),
+            Some(_) => {}
+            None => debug!(
+                "token [{}] does not report its scopes, skipping validation",
+                i
+            ),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(format_err!(
+            "some GitHub tokens do not have the required scopes\n{}",
+            errors.join("\n")
+        ));
+    }
+    Ok(())
+}
+
+/// Track repository if it has changed since the last time it was tracked (or
+/// if tracking it is forced). This involves cloning the repository, linting
+/// it and storing the results.
+/// The changes detected in the project are notified to the channels
+/// subscribed to them, and a summary of the results is published as a check
+/// run on the repository's head commit when a GitHub App has been set up.
+/// The errors found while linting the repository are returned, so that they
+/// can be registered in the run's audit log.
+#[allow(clippy::too_many_arguments)]
 #[instrument(fields(repository_id = %repository.repository_id), skip_all, err)]
 async fn track_repository(
     db: DynDB,
     git: DynGit,
     linter: DynLinter,
+    notifier: Option<DynNotifier>,
+    github: Option<DynGitHub>,
     github_token: Object<String>,
+    github_org_token: Option<String>,
     repository: Repository,
-) -> Result<()> {
+    force: bool,
+) -> Result<Vec<TrackingError>> {
     let start = Instant::now();
 
     // Process only if the repository has changed since the last time it
@@ -132,8 +432,8 @@ async fn track_repository(
     let remote_digest = git.remote_digest(&repository.url).await?;
     if let Some(digest) = &repository.digest {
         let one_day_ago = OffsetDateTime::now_utc() - time::Duration::days(1);
-        if &remote_digest == digest && repository.updated_at > one_day_ago {
-            return Ok(());
+        if !force && &remote_digest == digest && repository.updated_at > one_day_ago {
+            return Ok(vec![]);
         }
     }
 
@@ -150,40 +450,88 @@ async fn track_repository(
         root: tmp_dir.into_path(),
         url: repository.url.clone(),
         check_sets: repository.check_sets.clone(),
+        check_overrides: repository.check_overrides.clone(),
         github_token: github_token.to_owned(),
+        github_org_token,
+        provider: repository.provider(),
+        home_url: repository.home_url.clone(),
+        container_images: repository.container_images.clone(),
+        gerrit_url: repository.gerrit_url.clone(),
     };
-    let report = match linter.lint(&input).await {
-        Ok(report) => Some(report),
+    let (report, tracking_errors) = match linter.lint(&input).await {
+        Ok(report) => {
+            let tracking_errors = errors::collect(&report);
+            (Some(report), tracking_errors)
+        }
         Err(err) => {
             warn!("error linting repository: {:#}", err);
-            errors = Some(format!("error linting repository: {:#}", err));
-            None
+            let message = format!("error linting repository: {:#}", err);
+            errors = Some(message.clone());
+            (None, vec![TrackingError::repository(message)])
         }
     };
 
     // Store tracking results in database
-    db.store_results(
-        &repository.repository_id,
-        &repository.check_sets,
-        report.as_ref(),
-        errors.as_ref(),
-        &remote_digest,
-    )
-    .await?;
+    let changes = db
+        .store_results(
+            &repository.repository_id,
+            &repository.check_sets,
+            report.as_ref(),
+            errors.as_ref(),
+            &remote_digest,
+        )
+        .await?;
+
+    // Notify project changes
+    if let Some(notifier) = notifier {
+        if let Err(err) = notifier.notify(&changes).await {
+            warn!("error sending notifications: {:#}", err);
+        }
+    }
+
+    // Publish check run on the repository's head commit (GitHub only)
+    if let (Some(github), Some(report)) = (github, &report) {
+        if repository.provider() == Provider::Github {
+            let check_run = CheckRun::new(&repository.url, &remote_digest, &changes, report);
+            if let Err(err) = github.publish_check_run(&check_run).await {
+                warn!("error publishing check run: {:#}", err);
+            }
+        }
+    }
 
     debug!("completed in {}s", start.elapsed().as_secs());
-    Ok(())
+    Ok(tracking_errors)
+}
+
+/// Return the token configured for the GitHub organization that owns the
+/// repository provided, if any.
+fn org_token(org_tokens: &HashMap<String, String>, repository_url: &str) -> Option<String> {
+    let org = repository_url
+        .split_once("://")
+        .map_or(repository_url, |(_, rest)| rest)
+        .split('/')
+        .nth(1)?
+        .to_lowercase();
+    org_tokens.get(&org).cloned()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{db::MockDB, git::MockGit};
+    use crate::{
+        db::MockDB,
+        git::MockGit,
+        github::MockGitHub,
+        notifier::{MockNotifier, ProjectChanges},
+    };
     use clomonitor_core::linter::{MockLinter, Report};
     use futures::future;
+    use mockall::Sequence;
     use predicates::prelude::{predicate::*, *};
     use std::{path::Path, sync::Arc};
 
+    const RUN_ID: &str = "00000000-0000-0000-0001-000000000000";
+
     #[tokio::test]
     async fn error_getting_github_tokens() {
         let cfg = Config::builder().build().unwrap();
@@ -191,7 +539,16 @@ mod tests {
         let git = MockGit::new();
         let linter = MockLinter::new();
 
-        let result = run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter)).await;
+        let result = run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await;
         assert_eq!(
             result.unwrap_err().to_string(),
             r#"configuration property "creds.githubTokens" not found"#
@@ -209,7 +566,16 @@ mod tests {
         let git = MockGit::new();
         let linter = MockLinter::new();
 
-        let result = run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter)).await;
+        let result = run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await;
         assert_eq!(
             result.unwrap_err().to_string(),
             "GitHub tokens not found in config file (creds.githubTokens)"
@@ -227,14 +593,101 @@ mod tests {
         let git = MockGit::new();
         let linter = MockLinter::new();
 
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
         db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
             .times(1)
-            .returning(|| Box::pin(future::ready(Err(format_err!("fake error")))));
+            .returning(|_: &Uuid| Box::pin(future::ready(Err(format_err!("fake error")))));
 
-        let result = run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter)).await;
+        let result = run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await;
         assert_eq!(result.unwrap_err().to_string(), "fake error");
     }
 
+    #[tokio::test]
+    async fn new_run_started_with_due_schedules() {
+        let cfg = Config::builder()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .add_source(config::File::from_str(
+                "tracker:\n  schedules:\n    - maturity: sandbox\n      interval: 7d\n",
+                config::FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        db.expect_start_run()
+            .withf(|schedules| {
+                schedules.len() == 1 && schedules[0].maturity == Some("sandbox".to_string())
+            })
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(vec![]))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalid_schedule() {
+        let cfg = Config::builder()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .add_source(config::File::from_str(
+                "tracker:\n  schedules:\n    - maturity: sandbox\n",
+                config::FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap();
+        let db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        let result = run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid schedule 1 (tracker.schedules)"
+        );
+    }
+
     #[tokio::test]
     async fn no_repositories_found() {
         let cfg = Config::builder()
@@ -246,13 +699,484 @@ mod tests {
         let git = MockGit::new();
         let linter = MockLinter::new();
 
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(vec![]))));
+
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalid_max_duration_provided() {
+        let cfg = Config::builder()
+            .set_default("tracker.maxDuration", "invalid")
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        let result = run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid max duration (tracker.maxDuration)"));
+    }
+
+    #[tokio::test]
+    async fn invalid_role_provided() {
+        let cfg = Config::builder()
+            .set_default("tracker.role", "invalid")
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        let result = run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid role (tracker.role)"));
+    }
+
+    #[tokio::test]
+    async fn coordinator_starts_run() {
+        let cfg = Config::builder()
+            .set_default("tracker.role", "coordinator")
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn worker_no_run_in_progress() {
+        let cfg = Config::builder()
+            .set_default("tracker.role", "worker")
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        db.expect_current_run()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(None))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn worker_tracks_claimed_repositories() {
+        let cfg = Config::builder()
+            .set_default("tracker.role", "worker")
+            .unwrap()
+            .set_default("tracker.concurrency", 1)
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let mut git = MockGit::new();
+        let linter = MockLinter::new();
+        let mut seq = Sequence::new();
+
+        let r1_id = "00000000-0000-0000-0000-000000000001";
+        let r1_url = "url1";
+        let r1 = Repository {
+            repository_id: Uuid::parse_str(r1_id).unwrap(),
+            url: r1_url.to_string(),
+            check_sets: vec![CheckSet::Code],
+            check_overrides: CheckOverrides::default(),
+            provider: None,
+            container_images: vec![],
+            gerrit_url: None,
+            digest: Some("r1_digest".to_string()),
+            updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+            home_url: None,
+        };
+        db.expect_current_run()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(Some(Uuid::parse_str(RUN_ID).unwrap())))));
+        let r1_pending = r1.clone();
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(move |_: &Uuid| Box::pin(future::ready(Ok(vec![r1_pending.clone()]))));
+        db.expect_claim_repository()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |_: &Uuid| Box::pin(future::ready(Ok(Some(r1.clone())))));
+        db.expect_claim_repository()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(None))));
+        git.expect_remote_digest()
+            .with(eq(r1_url))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok("r1_digest".to_string()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn on_demand_no_tracking_requests_pending() {
+        let cfg = Config::builder()
+            .set_default("tracker.role", "ondemand")
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        db.expect_start_on_demand_run()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(None))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn on_demand_repository_tracked_even_if_it_has_not_changed() {
+        let cfg = Config::builder()
+            .set_default("tracker.role", "ondemand")
+            .unwrap()
+            .set_default("tracker.concurrency", 1)
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let mut git = MockGit::new();
+        let linter = MockLinter::new();
+
+        let r1_id = "00000000-0000-0000-0000-000000000001";
+        let r1_url = "url1";
+        db.expect_start_on_demand_run()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(Some(Uuid::parse_str(RUN_ID).unwrap())))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: Some("r1_digest".to_string()),
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                    home_url: None,
+                }])))
+            });
+        git.expect_remote_digest()
+            .with(eq(r1_url))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok("r1_digest".to_string()))));
+        git.expect_clone_repository()
+            .with(eq(r1_url), path::exists().and(path::is_dir()))
+            .times(1)
+            .returning(|_: &str, _: &Path| Box::pin(future::ready(Err(format_err!("fake error")))));
+        db.expect_store_tracking_errors()
+            .withf(|run_id, repository_id, errors| {
+                *run_id == Uuid::parse_str(RUN_ID).unwrap()
+                    && *repository_id == Uuid::parse_str(r1_id).unwrap()
+                    && errors.len() == 1
+                    && errors[0].check_id.is_none()
+            })
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid, _: &[TrackingError]| Box::pin(future::ready(Ok(()))));
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn max_duration_reached() {
+        let cfg = Config::builder()
+            .set_default("tracker.concurrency", 1)
+            .unwrap()
+            .set_default("tracker.maxDuration", "0s")
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
         db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap(),
+                    url: "url1".to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: None,
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                    home_url: None,
+                }])))
+            });
+
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
             .times(1)
-            .returning(|| Box::pin(future::ready(Ok(vec![]))));
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
 
-        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
-            .await
+    #[tokio::test]
+    async fn shutdown_requested_while_tracking() {
+        let cfg = Config::builder()
+            .set_default("tracker.concurrency", 1)
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
             .unwrap();
+        let mut db = MockDB::new();
+        let mut git = MockGit::new();
+        let linter = MockLinter::new();
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let r1_id = "00000000-0000-0000-0000-000000000001";
+        let r1_url = "url1";
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                let repository = Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: Some("r1_digest".to_string()),
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                    home_url: None,
+                };
+                Box::pin(future::ready(Ok(vec![
+                    repository.clone(),
+                    Repository {
+                        repository_id: Uuid::parse_str("00000000-0000-0000-0000-000000000002")
+                            .unwrap(),
+                        url: "url2".to_string(),
+                        ..repository
+                    },
+                ])))
+            });
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        let shutdown_clone = shutdown.clone();
+        git.expect_remote_digest()
+            .with(eq(r1_url))
+            .times(1)
+            .returning(move |_: &str| {
+                shutdown_clone.store(true, Ordering::SeqCst);
+                Box::pin(future::ready(Ok("r1_digest".to_string())))
+            });
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_interrupt_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            shutdown,
+        )
+        .await
+        .unwrap();
     }
 
     #[tokio::test]
@@ -270,23 +1194,73 @@ mod tests {
 
         let r1_id = "00000000-0000-0000-0000-000000000001";
         let r1_url = "url1";
-        db.expect_repositories().times(1).returning(|| {
-            Box::pin(future::ready(Ok(vec![Repository {
-                repository_id: Uuid::parse_str(r1_id).unwrap(),
-                url: r1_url.to_string(),
-                check_sets: vec![CheckSet::Code],
-                digest: None,
-                updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
-            }])))
-        });
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: None,
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                    home_url: None,
+                }])))
+            });
         git.expect_remote_digest()
             .with(eq(r1_url))
             .times(1)
             .returning(|_: &str| Box::pin(future::ready(Err(format_err!("fake error")))));
+        db.expect_store_tracking_errors()
+            .withf(|run_id, repository_id, errors| {
+                *run_id == Uuid::parse_str(RUN_ID).unwrap()
+                    && *repository_id == Uuid::parse_str(r1_id).unwrap()
+                    && errors
+                        == [TrackingError {
+                            check_id: None,
+                            class: ErrorClass::Other,
+                            message: "error tracking repository: fake error".to_string(),
+                        }]
+            })
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid, _: &[TrackingError]| Box::pin(future::ready(Ok(()))));
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
 
-        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
-            .await
-            .unwrap();
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
     }
 
     #[tokio::test]
@@ -304,23 +1278,60 @@ mod tests {
 
         let r1_id = "00000000-0000-0000-0000-000000000001";
         let r1_url = "url1";
-        db.expect_repositories().times(1).returning(|| {
-            Box::pin(future::ready(Ok(vec![Repository {
-                repository_id: Uuid::parse_str(r1_id).unwrap(),
-                url: r1_url.to_string(),
-                check_sets: vec![CheckSet::Code],
-                digest: Some("r1_digest".to_string()),
-                updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
-            }])))
-        });
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: Some("r1_digest".to_string()),
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                    home_url: None,
+                }])))
+            });
         git.expect_remote_digest()
             .with(eq(r1_url))
             .times(1)
             .returning(|_: &str| Box::pin(future::ready(Ok("r1_digest".to_string()))));
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
 
-        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
-            .await
-            .unwrap();
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
     }
 
     #[tokio::test]
@@ -338,15 +1349,26 @@ mod tests {
 
         let r1_id = "00000000-0000-0000-0000-000000000001";
         let r1_url = "url1";
-        db.expect_repositories().times(1).returning(|| {
-            Box::pin(future::ready(Ok(vec![Repository {
-                repository_id: Uuid::parse_str(r1_id).unwrap(),
-                url: r1_url.to_string(),
-                check_sets: vec![CheckSet::Code],
-                digest: None,
-                updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
-            }])))
-        });
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: None,
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                    home_url: None,
+                }])))
+            });
         git.expect_remote_digest()
             .with(eq(r1_url))
             .times(1)
@@ -355,10 +1377,49 @@ mod tests {
             .with(eq(r1_url), path::exists().and(path::is_dir()))
             .times(1)
             .returning(|_: &str, _: &Path| Box::pin(future::ready(Err(format_err!("fake error")))));
+        db.expect_store_tracking_errors()
+            .withf(|run_id, repository_id, errors| {
+                *run_id == Uuid::parse_str(RUN_ID).unwrap()
+                    && *repository_id == Uuid::parse_str(r1_id).unwrap()
+                    && errors
+                        == [TrackingError {
+                            check_id: None,
+                            class: ErrorClass::Other,
+                            message: "error tracking repository: fake error".to_string(),
+                        }]
+            })
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid, _: &[TrackingError]| Box::pin(future::ready(Ok(()))));
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
 
-        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
-            .await
-            .unwrap();
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
     }
 
     #[tokio::test]
@@ -376,15 +1437,33 @@ mod tests {
 
         let r1_id = "00000000-0000-0000-0000-000000000001";
         let r1_url = "url1";
-        db.expect_repositories().times(1).returning(|| {
-            Box::pin(future::ready(Ok(vec![Repository {
-                repository_id: Uuid::parse_str(r1_id).unwrap(),
-                url: r1_url.to_string(),
-                check_sets: vec![CheckSet::Code],
-                digest: None,
-                updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
-            }])))
-        });
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: None,
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                    home_url: None,
+                }])))
+            });
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
         git.expect_remote_digest()
             .with(eq(r1_url))
             .times(1)
@@ -404,9 +1483,22 @@ mod tests {
             .times(1)
             .returning(|_: &LinterInput| panic!("fake panic"));
 
-        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
-            .await
-            .unwrap_err();
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap_err();
     }
 
     #[tokio::test]
@@ -431,24 +1523,40 @@ mod tests {
         let r1_url = "url1";
         let r2_id = "00000000-0000-0000-0000-000000000002";
         let r2_url = "url2";
-        db.expect_repositories().times(1).returning(|| {
-            Box::pin(future::ready(Ok(vec![
-                Repository {
-                    repository_id: Uuid::parse_str(r1_id).unwrap(),
-                    url: r1_url.to_string(),
-                    check_sets: vec![CheckSet::Code],
-                    digest: None,
-                    updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
-                },
-                Repository {
-                    repository_id: Uuid::parse_str(r2_id).unwrap(),
-                    url: r2_url.to_string(),
-                    check_sets: vec![CheckSet::Code],
-                    digest: None,
-                    updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
-                },
-            ])))
-        });
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![
+                    Repository {
+                        repository_id: Uuid::parse_str(r1_id).unwrap(),
+                        url: r1_url.to_string(),
+                        check_sets: vec![CheckSet::Code],
+                        check_overrides: CheckOverrides::default(),
+                        provider: None,
+                        container_images: vec![],
+                        gerrit_url: None,
+                        digest: None,
+                        updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
+                        home_url: None,
+                    },
+                    Repository {
+                        repository_id: Uuid::parse_str(r2_id).unwrap(),
+                        url: r2_url.to_string(),
+                        check_sets: vec![CheckSet::Code],
+                        check_overrides: CheckOverrides::default(),
+                        provider: None,
+                        container_images: vec![],
+                        gerrit_url: None,
+                        digest: None,
+                        updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
+                        home_url: None,
+                    },
+                ])))
+            });
 
         // Track repository 1
         let github_tokens_copy = github_tokens.clone();
@@ -481,9 +1589,23 @@ mod tests {
             .times(1)
             .returning(
                 |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
-                    Box::pin(future::ready(Ok(())))
+                    Box::pin(future::ready(Ok(ProjectChanges::default())))
                 },
             );
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
 
         // Track repository 2
         git.expect_remote_digest()
@@ -504,6 +1626,19 @@ mod tests {
             })
             .times(1)
             .returning(|_: &LinterInput| Box::pin(future::ready(Err(format_err!("fake error")))));
+        db.expect_store_tracking_errors()
+            .withf(|run_id, repository_id, errors| {
+                *run_id == Uuid::parse_str(RUN_ID).unwrap()
+                    && *repository_id == Uuid::parse_str(r2_id).unwrap()
+                    && errors
+                        == [TrackingError {
+                            check_id: None,
+                            class: ErrorClass::Other,
+                            message: "error linting repository: fake error".to_string(),
+                        }]
+            })
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid, _: &[TrackingError]| Box::pin(future::ready(Ok(()))));
         db.expect_store_results()
             .withf(|repository_id, check_sets, report, errors, digest| {
                 *repository_id == Uuid::parse_str(r2_id).unwrap()
@@ -515,13 +1650,284 @@ mod tests {
             .times(1)
             .returning(
                 |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
-                    Box::pin(future::ready(Ok(())))
+                    Box::pin(future::ready(Ok(ProjectChanges::default())))
                 },
             );
+        db.expect_start_repository()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r2_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r2_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        // Run tracker
+
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn project_changes_notified() {
+        // Setup config
+        let cfg = Config::builder()
+            .set_default("tracker.concurrency", 1)
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // Setup mocks and expectations
+        let mut db = MockDB::new();
+        let mut git = MockGit::new();
+        let mut linter = MockLinter::new();
+        let mut notifier = MockNotifier::new();
+
+        let r1_id = "00000000-0000-0000-0000-000000000001";
+        let r1_url = "url1";
+        let changes = ProjectChanges {
+            foundation: "cncf".to_string(),
+            project: "artifact-hub".to_string(),
+            repository_url: r1_url.to_string(),
+            rating_from: Some("a".to_string()),
+            rating_to: Some("b".to_string()),
+            ..Default::default()
+        };
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: None,
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
+                    home_url: None,
+                }])))
+            });
+        db.expect_start_repository()
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        git.expect_remote_digest()
+            .with(eq(r1_url))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok("r1_digest".to_string()))));
+        git.expect_clone_repository()
+            .times(1)
+            .returning(|_: &str, _: &Path| Box::pin(future::ready(Ok(()))));
+        linter
+            .expect_lint()
+            .times(1)
+            .returning(|_: &LinterInput| Box::pin(future::ready(Ok(Report::default()))));
+        let changes_copy = changes.clone();
+        db.expect_store_results().times(1).returning(
+            move |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
+                Box::pin(future::ready(Ok(changes_copy.clone())))
+            },
+        );
+        notifier
+            .expect_notify()
+            .with(eq(changes))
+            .times(1)
+            .returning(|_: &ProjectChanges| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
 
         // Run tracker
-        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
-            .await
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            Some(Arc::new(notifier)),
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_run_published() {
+        // Setup config
+        let cfg = Config::builder()
+            .set_default("tracker.concurrency", 1)
+            .unwrap()
+            .set_default("creds.githubTokens", vec!["0000".to_string()])
+            .unwrap()
+            .build()
             .unwrap();
+
+        // Setup mocks and expectations
+        let mut db = MockDB::new();
+        let mut git = MockGit::new();
+        let mut linter = MockLinter::new();
+        let mut github = MockGitHub::new();
+
+        let r1_id = "00000000-0000-0000-0000-000000000001";
+        let r1_url = "https://github.com/artifacthub/hub";
+        let changes = ProjectChanges {
+            foundation: "cncf".to_string(),
+            project: "artifact-hub".to_string(),
+            repository_url: r1_url.to_string(),
+            score_to: Some(85.0),
+            rating_to: Some("a".to_string()),
+            ..Default::default()
+        };
+        db.expect_start_run()
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(Uuid::parse_str(RUN_ID).unwrap()))));
+        db.expect_repositories()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| {
+                Box::pin(future::ready(Ok(vec![Repository {
+                    repository_id: Uuid::parse_str(r1_id).unwrap(),
+                    url: r1_url.to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    check_overrides: CheckOverrides::default(),
+                    provider: None,
+                    container_images: vec![],
+                    gerrit_url: None,
+                    digest: None,
+                    updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
+                    home_url: None,
+                }])))
+            });
+        db.expect_start_repository()
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        git.expect_remote_digest()
+            .with(eq(r1_url))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok("r1_digest".to_string()))));
+        git.expect_clone_repository()
+            .times(1)
+            .returning(|_: &str, _: &Path| Box::pin(future::ready(Ok(()))));
+        linter
+            .expect_lint()
+            .times(1)
+            .returning(|_: &LinterInput| Box::pin(future::ready(Ok(Report::default()))));
+        let changes_copy = changes.clone();
+        db.expect_store_results().times(1).returning(
+            move |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
+                Box::pin(future::ready(Ok(changes_copy.clone())))
+            },
+        );
+        github
+            .expect_publish_check_run()
+            .with(eq(CheckRun::new(
+                r1_url,
+                "r1_digest",
+                &changes,
+                &Report::default(),
+            )))
+            .times(1)
+            .returning(|_: &CheckRun| Box::pin(future::ready(Ok(()))));
+        db.expect_update_tracked_at()
+            .with(
+                eq(Uuid::parse_str(RUN_ID).unwrap()),
+                eq(Uuid::parse_str(r1_id).unwrap()),
+            )
+            .times(1)
+            .returning(|_: &Uuid, _: &Uuid| Box::pin(future::ready(Ok(()))));
+        db.expect_finish_run()
+            .with(eq(Uuid::parse_str(RUN_ID).unwrap()))
+            .times(1)
+            .returning(|_: &Uuid| Box::pin(future::ready(Ok(()))));
+
+        // Run tracker
+        run(
+            &cfg,
+            Arc::new(db),
+            Arc::new(git),
+            Arc::new(linter),
+            None,
+            Some(Arc::new(github)),
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn repository_provider() {
+        let repository = |url: &str, provider| Repository {
+            repository_id: Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap(),
+            url: url.to_string(),
+            check_sets: vec![CheckSet::Code],
+            check_overrides: CheckOverrides::default(),
+            provider,
+            container_images: vec![],
+            gerrit_url: None,
+            digest: None,
+            updated_at: OffsetDateTime::now_utc(),
+            home_url: None,
+        };
+
+        assert_eq!(
+            repository("https://gitlab.com/org/repo", None).provider(),
+            Provider::Gitlab
+        );
+        assert_eq!(
+            repository("https://git.example.com/org/repo", Some(Provider::Gitea)).provider(),
+            Provider::Gitea
+        );
+        assert_eq!(
+            repository("https://git.example.com/org/repo", None).provider(),
+            Provider::Github
+        );
+    }
+
+    #[test]
+    fn org_token_found() {
+        let org_tokens = HashMap::from([("org".to_string(), "1111".to_string())]);
+
+        assert_eq!(
+            org_token(&org_tokens, "https://github.com/Org/repo"),
+            Some("1111".to_string())
+        );
+        assert_eq!(
+            org_token(&org_tokens, "https://github.com/other/repo"),
+            None
+        );
     }
 }
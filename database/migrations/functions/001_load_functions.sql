@@ -1,16 +1,63 @@
+{{ template "api_keys/add_api_key.sql" }}
+{{ template "api_keys/get_api_key.sql" }}
+{{ template "api_keys/get_api_keys.sql" }}
+{{ template "api_keys/revoke_api_key.sql" }}
+{{ template "consistency/get_consistency_issues.sql" }}
+{{ template "consistency/update_consistency_issues.sql" }}
+{{ template "foundations/delete_foundation.sql" }}
+{{ template "foundations/get_foundation_data_file.sql" }}
+{{ template "foundations/get_foundation_feed.sql" }}
+{{ template "foundations/get_foundation_maturity_gates.sql" }}
+{{ template "foundations/get_foundation_report.sql" }}
+{{ template "foundations/get_foundation_weights.sql" }}
+{{ template "foundations/register_foundation.sql" }}
+{{ template "foundations/request_foundation_registration.sql" }}
+{{ template "foundations/update_foundation.sql" }}
+{{ template "foundations/update_foundation_data_ref.sql" }}
+{{ template "foundations/update_foundation_maturity_gates.sql" }}
+{{ template "foundations/update_foundation_weights.sql" }}
+{{ template "projects/acknowledge_exemption.sql" }}
+{{ template "projects/add_project_webhook.sql" }}
+{{ template "projects/delete_exemption_acknowledgement.sql" }}
+{{ template "projects/delete_project_webhook.sql" }}
+{{ template "projects/get_cohort_stats.sql" }}
+{{ template "projects/get_exemption_acknowledgements.sql" }}
 {{ template "projects/get_project_by_id.sql" }}
 {{ template "projects/get_project_by_name.sql" }}
+{{ template "projects/get_project_check_history.sql" }}
 {{ template "projects/get_project_checks.sql" }}
 {{ template "projects/get_project_passed_checks.sql" }}
+{{ template "projects/get_project_tracking_errors.sql" }}
+{{ template "projects/get_project_webhooks.sql" }}
+{{ template "projects/get_projects_due_for_review.sql" }}
 {{ template "projects/register_project.sql" }}
+{{ template "projects/rename_project.sql" }}
+{{ template "projects/rollup_projects_views.sql" }}
 {{ template "projects/search_projects.sql" }}
+{{ template "projects/suggest_projects.sql" }}
 {{ template "projects/unregister_project.sql" }}
 {{ template "projects/update_projects_views.sql" }}
 {{ template "repositories/get_repositories_with_checks.sql" }}
+{{ template "repositories/get_repository_bundle.sql" }}
+{{ template "repositories/get_repository_by_id.sql" }}
 {{ template "repositories/get_repository_report.sql" }}
+{{ template "repositories/search_repositories_by_url.sql" }}
+{{ template "roles/add_role_assignment.sql" }}
+{{ template "roles/delete_role_assignment.sql" }}
+{{ template "roles/get_role_assignments.sql" }}
+{{ template "roles/get_subject_roles.sql" }}
 {{ template "stats/average_section_score.sql" }}
 {{ template "stats/repositories_passing_check.sql" }}
+{{ template "stats/get_checks_stats.sql" }}
+{{ template "stats/get_most_improved_projects.sql" }}
+{{ template "stats/get_projects_leaderboard.sql" }}
 {{ template "stats/get_stats.sql" }}
+{{ template "sync/apply_sync_delta.sql" }}
+{{ template "sync/get_sync_delta.sql" }}
+{{ template "tracker/get_tracker_run_progress.sql" }}
+{{ template "tracker/request_projects_tracking.sql" }}
+{{ template "tracker/start_on_demand_tracker_run.sql" }}
+{{ template "tracker/start_tracker_run.sql" }}
 
 ---- create above / drop below ----
 
@@ -0,0 +1,15 @@
+-- Registers the API key provided, returning its id. Only the hash of the key
+-- is stored.
+create or replace function add_api_key(p_input jsonb)
+returns uuid as $$
+    insert into api_key (
+        name,
+        key_hash,
+        rate_limit
+    ) values (
+        p_input->>'name',
+        p_input->>'key_hash',
+        (p_input->>'rate_limit')::int
+    )
+    returning api_key_id;
+$$ language sql;
@@ -0,0 +1,13 @@
+-- Returns the API key matching the hash provided in json format, provided it
+-- has not been revoked.
+create or replace function get_api_key(p_key_hash text)
+returns json as $$
+    select json_strip_nulls(json_build_object(
+        'api_key_id', api_key_id,
+        'name', name,
+        'rate_limit', rate_limit
+    ))
+    from api_key
+    where key_hash = p_key_hash
+    and revoked_at is null;
+$$ language sql;
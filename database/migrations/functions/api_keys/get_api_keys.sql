@@ -0,0 +1,12 @@
+-- Returns all the API keys registered (without their hashes) in json format.
+create or replace function get_api_keys()
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'api_key_id', api_key_id,
+        'name', name,
+        'rate_limit', rate_limit,
+        'created_at', floor(extract(epoch from created_at)),
+        'revoked_at', floor(extract(epoch from revoked_at))
+    )) order by name asc, created_at asc), '[]')
+    from api_key;
+$$ language sql;
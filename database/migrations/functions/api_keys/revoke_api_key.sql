@@ -0,0 +1,12 @@
+-- Revokes the API key provided. Returns true if the API key was found and it
+-- had not been revoked yet.
+create or replace function revoke_api_key(p_api_key_id uuid)
+returns boolean as $$
+    with api_key_revoked as (
+        update api_key set revoked_at = current_timestamp
+        where api_key_id = p_api_key_id
+        and revoked_at is null
+        returning 1
+    )
+    select exists (select 1 from api_key_revoked);
+$$ language sql;
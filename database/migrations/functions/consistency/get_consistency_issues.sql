@@ -0,0 +1,14 @@
+-- Returns the discrepancies found by the latest consistency check in json
+-- format.
+create or replace function get_consistency_issues()
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'kind', kind,
+        'foundation', foundation_id,
+        'project', project_name,
+        'repository_url', repository_url,
+        'details', details,
+        'detected_at', floor(extract(epoch from detected_at))
+    )) order by foundation_id asc, kind asc, project_name asc, repository_url asc), '[]')
+    from consistency_issue;
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Replaces the consistency issues registered with the ones provided.
+create or replace function update_consistency_issues(p_issues jsonb)
+returns void as $$
+    delete from consistency_issue;
+    insert into consistency_issue (
+        kind,
+        foundation_id,
+        project_name,
+        repository_url,
+        details
+    )
+    select
+        i->>'kind',
+        i->>'foundation_id',
+        i->>'project_name',
+        i->>'repository_url',
+        i->>'details'
+    from jsonb_array_elements(p_issues) i;
+$$ language sql;
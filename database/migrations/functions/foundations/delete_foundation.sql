@@ -0,0 +1,14 @@
+-- Deletes the provided foundation. Returns true if the foundation was found,
+-- or null if it cannot be deleted as it still has projects registered.
+create or replace function delete_foundation(p_foundation text)
+returns boolean as $$
+begin
+    perform from project where foundation_id = p_foundation;
+    if found then
+        return null;
+    end if;
+
+    delete from foundation where foundation_id = p_foundation;
+    return found;
+end
+$$ language plpgsql;
@@ -0,0 +1,38 @@
+-- Returns the projects registered for the provided foundation in the format
+-- used by the foundations' data files. Returns null if the foundation was not
+-- found.
+create or replace function get_foundation_data_file(p_foundation text)
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'name', p.name,
+        'display_name', p.display_name,
+        'description', p.description,
+        'category', p.category,
+        'home_url', p.home_url,
+        'logo_url', p.logo_url,
+        'logo_dark_url', p.logo_dark_url,
+        'devstats_url', p.devstats_url,
+        'accepted_at', to_char(p.accepted_at, 'YYYY-MM-DD'),
+        'maturity', p.maturity,
+        'contact_email', p.contact_email,
+        'annual_review_due_at', to_char(p.annual_review_due_at, 'YYYY-MM-DD'),
+        'repositories', (
+            select json_agg(json_build_object(
+                'name', r.name,
+                'url', r.url,
+                'check_sets', r.check_sets,
+                'checks', r.check_overrides,
+                'provider', r.provider,
+                'container_images', r.container_images,
+                'gerrit_url', r.gerrit_url,
+                'weight', r.weight
+            ) order by r.name)
+            from repository r
+            where r.project_id = p.project_id
+        )
+    )) order by p.name) filter (where p.project_id is not null), '[]'::json)
+    from foundation f
+    left join project p using (foundation_id)
+    where f.foundation_id = p_foundation
+    having count(f.foundation_id) > 0;
+$$ language sql;
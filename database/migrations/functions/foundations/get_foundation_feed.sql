@@ -0,0 +1,66 @@
+-- Returns the most recent rating changes and new projects of the foundation
+-- provided in json format, used to build the foundation's feed.
+create or replace function get_foundation_feed(p_foundation text, p_limit int default 50)
+returns json as $$
+    set local timezone to 'utc';
+
+    with projects_ratings as (
+        select
+            p.name,
+            coalesce(p.display_name, p.name) as display_name,
+            s.date,
+            s.data->>'rating' as rating,
+            lag(s.data->>'rating') over (
+                partition by s.project_id
+                order by s.date asc
+            ) as previous_rating
+        from project_snapshot s
+        join project p using (project_id)
+        where p.foundation_id = p_foundation
+    ),
+    entries as (
+        select
+            'rating_change' as kind,
+            name,
+            display_name,
+            previous_rating as rating_from,
+            rating as rating_to,
+            date::timestamptz as updated_at
+        from projects_ratings
+        where previous_rating is not null
+        and rating is not null
+        and rating <> previous_rating
+        union all
+        select
+            'new_project' as kind,
+            name,
+            coalesce(display_name, name) as display_name,
+            null::text as rating_from,
+            null::text as rating_to,
+            created_at as updated_at
+        from project
+        where foundation_id = p_foundation
+    )
+    select json_build_object(
+        'foundation', f.foundation_id,
+        'foundation_display_name', f.display_name,
+        'entries', (
+            select coalesce(json_agg(json_strip_nulls(json_build_object(
+                'kind', kind,
+                'name', name,
+                'display_name', display_name,
+                'rating_from', rating_from,
+                'rating_to', rating_to,
+                'updated_at', to_char(updated_at, 'YYYY-MM-DD"T"HH24:MI:SS"Z"')
+            ))), '[]')
+            from (
+                select *
+                from entries
+                order by updated_at desc, name asc
+                limit p_limit
+            ) e
+        )
+    )
+    from foundation f
+    where f.foundation_id = p_foundation;
+$$ language sql;
@@ -0,0 +1,38 @@
+-- Returns a report evaluating the foundation's projects against the maturity
+-- gates defined for it in json format. A gate passes when all its checks have
+-- passed on the project's repositories. Returns null if the foundation was not
+-- found.
+create or replace function get_foundation_maturity_gates(p_foundation text)
+returns json as $$
+    select json_build_object(
+        'foundation', f.foundation_id,
+        'foundation_display_name', f.display_name,
+        'projects', coalesce((
+            select json_agg(json_build_object(
+                'name', p.name,
+                'display_name', coalesce(p.display_name, p.name),
+                'maturity', p.maturity,
+                'gates', coalesce((
+                    select json_agg(json_build_object(
+                        'maturity', g.maturity,
+                        'name', g.name,
+                        'checks', g.check_ids,
+                        'passed', g.check_ids <@ coalesce(p.passed_checks, '{}'),
+                        'checks_not_passed', array(
+                            select c.check_id
+                            from unnest(g.check_ids) with ordinality as c(check_id, n)
+                            where c.check_id <> all(coalesce(p.passed_checks, '{}'))
+                            order by c.n
+                        )
+                    ) order by g.maturity desc, g.name)
+                    from foundation_maturity_gate g
+                    where g.foundation_id = f.foundation_id
+                ), '[]')
+            ) order by p.name)
+            from project p
+            where p.foundation_id = f.foundation_id
+        ), '[]')
+    )
+    from foundation f
+    where f.foundation_id = p_foundation;
+$$ language sql;
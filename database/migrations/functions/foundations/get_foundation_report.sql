@@ -0,0 +1,136 @@
+-- Returns a report summarizing how the foundation's projects evolved during
+-- the period provided in json format.
+create or replace function get_foundation_report(p_foundation text, p_from date, p_to date)
+returns json as $$
+    set local timezone to 'utc';
+
+    with projects_snapshots as (
+        select
+            p.name,
+            coalesce(p.display_name, p.name) as display_name,
+            s.date,
+            (s.data->'score'->>'global')::real as score,
+            s.data->>'rating' as rating
+        from project_snapshot s
+        join project p using (project_id)
+        where p.foundation_id = p_foundation
+        and s.date between p_from and p_to
+    ),
+    projects_ratings as (
+        select distinct
+            name,
+            display_name,
+            first_value(rating) over w as rating_from,
+            last_value(rating) over w as rating_to
+        from projects_snapshots
+        window w as (
+            partition by name
+            order by date asc
+            rows between unbounded preceding and unbounded following
+        )
+    ),
+    stats_snapshots as (
+        select
+            (
+                select data from stats_snapshot
+                where foundation_id = p_foundation
+                and date between p_from and p_to
+                order by date asc
+                limit 1
+            ) as data_from,
+            (
+                select data from stats_snapshot
+                where foundation_id = p_foundation
+                and date between p_from and p_to
+                order by date desc
+                limit 1
+            ) as data_to
+    ),
+    checks_evolution as (
+        select
+            c.key as check_name,
+            (c.value::text)::real as passing_from,
+            (t.data_to->'repositories'->'passing_check'->s.key->>c.key)::real as passing_to
+        from stats_snapshots t,
+        jsonb_each(t.data_from->'repositories'->'passing_check') s,
+        jsonb_each(s.value) c
+        where t.data_to->'repositories'->'passing_check'->s.key ? c.key
+    )
+    select json_strip_nulls(json_build_object(
+        'foundation', f.foundation_id,
+        'foundation_display_name', f.display_name,
+        'from', to_char(p_from, 'YYYY-MM-DD'),
+        'to', to_char(p_to, 'YYYY-MM-DD'),
+        'score_evolution', (
+            select coalesce(json_agg(json_build_object(
+                'month', to_char(month, 'YYYY-MM'),
+                'average_score', average_score
+            )), '[]')
+            from (
+                select
+                    date_trunc('month', date) as month,
+                    round(avg(score))::real as average_score
+                from projects_snapshots
+                where score is not null
+                group by date_trunc('month', date)
+                order by month asc
+            ) se
+        ),
+        'checks_improvement', (
+            select coalesce(json_agg(json_build_object(
+                'check', check_name,
+                'passing_from', passing_from,
+                'passing_to', passing_to
+            )), '[]')
+            from (
+                select *
+                from checks_evolution
+                where passing_to > passing_from
+                order by passing_to - passing_from desc, check_name asc
+                limit 10
+            ) ci
+        ),
+        'rating_changes', (
+            select coalesce(json_agg(json_build_object(
+                'name', name,
+                'display_name', display_name,
+                'rating_from', rating_from,
+                'rating_to', rating_to
+            )), '[]')
+            from (
+                select *
+                from projects_ratings
+                where rating_from <> rating_to
+                order by name asc
+            ) rc
+        ),
+        'new_projects', (
+            select coalesce(json_agg(json_build_object(
+                'name', name,
+                'display_name', coalesce(display_name, name)
+            )), '[]')
+            from (
+                select name, display_name
+                from project
+                where foundation_id = p_foundation
+                and created_at::date between p_from and p_to
+                order by name asc
+            ) np
+        ),
+        'removed_projects', (
+            select coalesce(json_agg(json_build_object(
+                'name', name,
+                'display_name', coalesce(display_name, name)
+            )), '[]')
+            from (
+                select name, display_name
+                from project_removal
+                where foundation_id = p_foundation
+                and removed_at::date between p_from and p_to
+                order by name asc
+            ) rp
+        )
+    ))
+    from foundation f
+    where f.foundation_id = p_foundation;
+$$ language sql;
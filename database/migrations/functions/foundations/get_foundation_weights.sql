@@ -0,0 +1,21 @@
+-- Returns the checks and sections weights overrides defined for the provided
+-- foundation. Returns null if the foundation was not found.
+create or replace function get_foundation_weights(p_foundation text)
+returns json as $$
+    select json_build_object(
+        'checks', coalesce((
+            select json_object_agg(name, weight order by name)
+            from foundation_weight
+            where foundation_id = p_foundation
+            and kind = 'check'
+        ), '{}'::json),
+        'sections', coalesce((
+            select json_object_agg(name, weight order by name)
+            from foundation_weight
+            where foundation_id = p_foundation
+            and kind = 'section'
+        ), '{}'::json)
+    )
+    from foundation
+    where foundation_id = p_foundation;
+$$ language sql;
@@ -0,0 +1,23 @@
+-- Registers the foundation provided. Returns false if a foundation with the
+-- same id already exists.
+create or replace function register_foundation(p_foundation jsonb)
+returns boolean as $$
+    with foundation_added as (
+        insert into foundation (
+            foundation_id,
+            display_name,
+            data_url,
+            data_ref,
+            logo_url
+        ) values (
+            p_foundation->>'foundation_id',
+            p_foundation->>'display_name',
+            p_foundation->>'data_url',
+            p_foundation->>'ref',
+            p_foundation->>'logo_url'
+        )
+        on conflict (foundation_id) do nothing
+        returning 1
+    )
+    select exists (select 1 from foundation_added);
+$$ language sql;
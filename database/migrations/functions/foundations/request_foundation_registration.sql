@@ -0,0 +1,12 @@
+-- Requests processing the data file of the provided foundation on demand.
+-- Returns true if the foundation was found.
+create or replace function request_foundation_registration(p_foundation text)
+returns boolean as $$
+    with foundation_updated as (
+        update foundation set
+            registration_requested_at = coalesce(registration_requested_at, current_timestamp)
+        where foundation_id = p_foundation
+        returning 1
+    )
+    select exists (select 1 from foundation_updated);
+$$ language sql;
@@ -0,0 +1,15 @@
+-- Updates the details of the provided foundation. Returns true if the
+-- foundation was found.
+create or replace function update_foundation(p_foundation text, p_input jsonb)
+returns boolean as $$
+    with foundation_updated as (
+        update foundation set
+            display_name = p_input->>'display_name',
+            data_url = p_input->>'data_url',
+            data_ref = p_input->>'ref',
+            logo_url = p_input->>'logo_url'
+        where foundation_id = p_foundation
+        returning 1
+    )
+    select exists (select 1 from foundation_updated);
+$$ language sql;
@@ -0,0 +1,11 @@
+-- Updates the ref used to build the data file url of the provided
+-- foundation. Returns true if the foundation was found.
+create or replace function update_foundation_data_ref(p_foundation text, p_data_ref text)
+returns boolean as $$
+    with foundation_updated as (
+        update foundation set data_ref = p_data_ref
+        where foundation_id = p_foundation
+        returning 1
+    )
+    select exists (select 1 from foundation_updated);
+$$ language sql;
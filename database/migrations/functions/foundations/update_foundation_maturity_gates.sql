@@ -0,0 +1,15 @@
+-- Replaces the maturity gates of the provided foundation with the ones in the
+-- gates document provided (gates are grouped by maturity level).
+create or replace function update_foundation_maturity_gates(p_foundation text, p_gates jsonb)
+returns void as $$
+    delete from foundation_maturity_gate where foundation_id = p_foundation;
+
+    insert into foundation_maturity_gate (foundation_id, maturity, name, check_ids)
+    select
+        p_foundation,
+        m.key::maturity,
+        g->>'name',
+        (select array(select jsonb_array_elements_text(g->'checks')))
+    from jsonb_each(coalesce(p_gates, '{}'::jsonb)) m,
+    jsonb_array_elements(m.value) g;
+$$ language sql;
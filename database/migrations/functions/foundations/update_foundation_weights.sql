@@ -0,0 +1,14 @@
+-- Replaces the checks and sections weights overrides of the provided
+-- foundation with the ones in the weights document provided.
+create or replace function update_foundation_weights(p_foundation text, p_weights jsonb)
+returns void as $$
+    delete from foundation_weight where foundation_id = p_foundation;
+
+    insert into foundation_weight (foundation_id, kind, name, weight)
+    select p_foundation, 'check', key, value::integer
+    from jsonb_each_text(coalesce(p_weights->'checks', '{}'::jsonb));
+
+    insert into foundation_weight (foundation_id, kind, name, weight)
+    select p_foundation, 'section', key, value::integer
+    from jsonb_each_text(coalesce(p_weights->'sections', '{}'::jsonb));
+$$ language sql;
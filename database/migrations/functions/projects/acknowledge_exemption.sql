@@ -0,0 +1,24 @@
+-- Records that the exemption of the check provided has been acknowledged by
+-- one of the project's maintainers (acknowledging it again updates who did it
+-- and when). Returns true if the project was found.
+create or replace function acknowledge_exemption(
+    p_foundation text,
+    p_project text,
+    p_check_id text,
+    p_acknowledged_by text
+)
+returns boolean as $$
+    with exemption_acknowledged as (
+        insert into exemption_acknowledgement (project_id, check_id, acknowledged_by)
+        select p.project_id, p_check_id, p_acknowledged_by
+        from project p
+        where p.foundation_id = p_foundation
+        and p.name = p_project
+        on conflict (project_id, check_id) do update
+        set
+            acknowledged_by = excluded.acknowledged_by,
+            acknowledged_at = current_timestamp
+        returning 1
+    )
+    select exists (select 1 from exemption_acknowledged);
+$$ language sql;
@@ -0,0 +1,20 @@
+-- Registers a webhook for the provided project, returning its id (or null if
+-- the project was not found).
+create or replace function add_project_webhook(
+    p_foundation text,
+    p_project text,
+    p_created_by text,
+    p_input jsonb
+)
+returns uuid as $$
+    insert into project_webhook (project_id, url, events, created_by)
+    select
+        p.project_id,
+        p_input->>'url',
+        array(select jsonb_array_elements_text(p_input->'events')),
+        p_created_by
+    from project p
+    where p.foundation_id = p_foundation
+    and p.name = p_project
+    returning project_webhook_id;
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Deletes the acknowledgement of the exemption of the check provided. Returns
+-- true if the acknowledgement was found.
+create or replace function delete_exemption_acknowledgement(
+    p_foundation text,
+    p_project text,
+    p_check_id text
+)
+returns boolean as $$
+    with exemption_acknowledgement_deleted as (
+        delete from exemption_acknowledgement ea
+        using project p
+        where ea.project_id = p.project_id
+        and p.foundation_id = p_foundation
+        and p.name = p_project
+        and ea.check_id = p_check_id
+        returning 1
+    )
+    select exists (select 1 from exemption_acknowledgement_deleted);
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Deletes the webhook provided, as long as it belongs to the given project.
+-- Returns true if the webhook was found.
+create or replace function delete_project_webhook(
+    p_foundation text,
+    p_project text,
+    p_project_webhook_id uuid
+)
+returns boolean as $$
+    with project_webhook_deleted as (
+        delete from project_webhook pw
+        using project p
+        where pw.project_id = p.project_id
+        and p.foundation_id = p_foundation
+        and p.name = p_project
+        and pw.project_webhook_id = p_project_webhook_id
+        returning 1
+    )
+    select exists (select 1 from project_webhook_deleted);
+$$ language sql;
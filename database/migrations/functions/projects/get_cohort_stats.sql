@@ -0,0 +1,42 @@
+-- Returns some statistics about how the project provided compares to the
+-- other projects in its cohort (projects of the same foundation and maturity
+-- level, or without one if the project has no maturity level) in json format. The percentile of each score section is the
+-- percentage of projects in the cohort whose score is lower than or equal to
+-- the project's one.
+create or replace function get_cohort_stats(p_project_id uuid)
+returns json as $$
+    with cohort as (
+        select c.project_id, c.score
+        from project p
+        join project c on c.foundation_id = p.foundation_id
+            and c.maturity is not distinct from p.maturity
+        where p.project_id = p_project_id
+        and c.score is not null
+    ),
+    percentiles as (
+        select s.section, ranked.percentile
+        from unnest(array[
+            'global',
+            'documentation',
+            'license',
+            'best_practices',
+            'security',
+            'legal',
+            'artifacts',
+            'organization'
+        ]) as s(section)
+        cross join lateral (
+            select
+                project_id,
+                round((cume_dist() over (order by (score->>s.section)::real) * 100)::numeric) as percentile
+            from cohort
+            where score->>s.section is not null
+        ) ranked
+        where ranked.project_id = p_project_id
+    )
+    select json_build_object(
+        'projects', (select count(*) from cohort),
+        'percentiles', (select json_object_agg(section, percentile) from percentiles)
+    )
+    where exists (select from percentiles where section = 'global');
+$$ language sql;
@@ -0,0 +1,17 @@
+-- Returns the exemptions acknowledged by the maintainers of the provided
+-- project in json format (or null if the project was not found).
+create or replace function get_exemption_acknowledgements(p_foundation text, p_project text)
+returns json as $$
+    select (
+        select coalesce(json_agg(json_build_object(
+            'check_id', ea.check_id,
+            'acknowledged_by', ea.acknowledged_by,
+            'acknowledged_at', floor(extract(epoch from ea.acknowledged_at))
+        ) order by ea.check_id asc), '[]')
+        from exemption_acknowledgement ea
+        where ea.project_id = p.project_id
+    )
+    from project p
+    where p.foundation_id = p_foundation
+    and p.name = p_project;
+$$ language sql;
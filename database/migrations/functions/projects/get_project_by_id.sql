@@ -16,6 +16,7 @@ returns json as $$
         'accepted_at', extract(epoch from p.accepted_at),
         'updated_at', floor(extract(epoch from p.updated_at)),
         'maturity', p.maturity,
+        'cohort', get_cohort_stats(p.project_id),
         'repositories', (
             select json_agg(json_build_object(
                 'repository_id', r.repository_id,
@@ -24,6 +25,7 @@ returns json as $$
                 'check_sets', r.check_sets,
                 'digest', r.digest,
                 'score', r.score,
+                'weight', r.weight,
                 'report', (
                     select json_build_object(
                         'report_id', report_id,
@@ -0,0 +1,62 @@
+-- Returns when the check provided started or stopped passing on each of the
+-- project's repositories, based on the project's snapshots.
+create or replace function get_project_check_history(
+    p_foundation_id text,
+    p_project_name text,
+    p_check_id text
+)
+returns json as $$
+    with selected_project as (
+        select project_id
+        from project
+        where foundation_id = p_foundation_id
+        and name = p_project_name
+    ), check_status as (
+        select
+            s.date,
+            r->>'name' as repository_name,
+            r->>'url' as repository_url,
+            case when jsonb_typeof(r->'report'->'data') = 'object' then (
+                select (section.value->p_check_id->>'passed')::boolean
+                from jsonb_each(r->'report'->'data') section
+                where jsonb_typeof(section.value) = 'object'
+                and jsonb_typeof(section.value->p_check_id) = 'object'
+                limit 1
+            ) end as passed
+        from project_snapshot s
+        join selected_project using (project_id)
+        cross join jsonb_array_elements(s.data->'repositories') r
+    ), check_status_changes as (
+        select
+            date,
+            repository_name,
+            repository_url,
+            passed,
+            lag(passed) over (partition by repository_url order by date asc) as previous_passed
+        from check_status
+        where passed is not null
+    )
+    select json_build_object(
+        'check_id', p_check_id,
+        'repositories', coalesce((
+            select json_agg(json_build_object(
+                'name', repository_name,
+                'url', repository_url,
+                'changes', changes
+            ) order by repository_name asc)
+            from (
+                select
+                    repository_name,
+                    repository_url,
+                    json_agg(json_build_object(
+                        'date', date,
+                        'passed', passed
+                    ) order by date asc) as changes
+                from check_status_changes
+                where previous_passed is distinct from passed
+                group by repository_name, repository_url
+            ) rc
+        ), '[]')
+    )
+    from selected_project;
+$$ language sql;
@@ -0,0 +1,27 @@
+-- Returns the errors found while tracking the project's repositories, newest
+-- first. Checks that could not be run are listed along with the class of the
+-- error (i.e. rate_limit or timeout), so that they can be told apart from the
+-- checks that did not pass.
+create or replace function get_project_tracking_errors(
+    p_foundation_id text,
+    p_project_name text
+)
+returns json as $$
+    select (
+        select coalesce(json_agg(json_build_object(
+            'tracker_run_id', e.tracker_run_id,
+            'repository_name', r.name,
+            'repository_url', r.url,
+            'check_id', e.check_id,
+            'class', e.class,
+            'message', e.message,
+            'created_at', floor(extract(epoch from e.created_at))
+        ) order by e.created_at desc, r.name asc, e.check_id asc nulls first), '[]')
+        from repository r
+        join tracking_error e using (repository_id)
+        where r.project_id = p.project_id
+    )
+    from project p
+    where p.foundation_id = p_foundation_id
+    and p.name = p_project_name;
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Returns the webhooks registered for the provided project in json format
+-- (or null if the project was not found).
+create or replace function get_project_webhooks(p_foundation text, p_project text)
+returns json as $$
+    select (
+        select coalesce(json_agg(json_build_object(
+            'project_webhook_id', pw.project_webhook_id,
+            'url', pw.url,
+            'events', pw.events,
+            'created_by', pw.created_by,
+            'created_at', floor(extract(epoch from pw.created_at))
+        ) order by pw.created_at asc), '[]')
+        from project_webhook pw
+        where pw.project_id = p.project_id
+    )
+    from project p
+    where p.foundation_id = p_foundation
+    and p.name = p_project;
+$$ language sql;
@@ -0,0 +1,30 @@
+-- Returns the projects whose annual review is due within the number of weeks
+-- provided and whose contact has not been reminded about it yet, including
+-- the reports of their repositories.
+create or replace function get_projects_due_for_review(p_weeks_before int)
+returns json as $$
+    select coalesce(json_agg(json_build_object(
+        'foundation_id', p.foundation_id,
+        'name', p.name,
+        'display_name', coalesce(p.display_name, p.name),
+        'maturity', p.maturity,
+        'contact_email', p.contact_email,
+        'annual_review_due_at', to_char(p.annual_review_due_at, 'YYYY-MM-DD'),
+        'score', p.score,
+        'rating', p.rating,
+        'repositories', (
+            select coalesce(json_agg(json_build_object(
+                'name', r.name,
+                'url', r.url,
+                'report', rp.data
+            ) order by r.name), '[]'::json)
+            from repository r
+            left join report rp using (repository_id)
+            where r.project_id = p.project_id
+        )
+    ) order by p.foundation_id, p.name), '[]'::json)
+    from project p
+    where p.contact_email is not null
+    and p.annual_review_due_at between current_date and current_date + p_weeks_before * 7
+    and p.last_reminded_review_due_at is distinct from p.annual_review_due_at;
+$$ language sql;
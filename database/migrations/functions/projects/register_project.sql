@@ -4,6 +4,8 @@ returns void as $$
 declare
     v_project_id uuid;
     v_repository jsonb;
+    v_repository_position bigint;
+    v_repositories_count int := jsonb_array_length(p_project->'repositories');
 begin
     -- Register project or update existing one
     insert into project (
@@ -18,6 +20,8 @@ begin
         accepted_at,
         maturity,
         digest,
+        contact_email,
+        annual_review_due_at,
         foundation_id
     ) values (
         p_project->>'name',
@@ -31,6 +35,8 @@ begin
         (p_project->>'accepted_at')::date,
         (p_project->>'maturity')::maturity,
         p_project->>'digest',
+        p_project->>'contact_email',
+        (p_project->>'annual_review_due_at')::date,
         p_foundation_id
     )
     on conflict (foundation_id, name) do update
@@ -44,27 +50,50 @@ begin
         devstats_url = excluded.devstats_url,
         accepted_at = excluded.accepted_at,
         maturity = excluded.maturity,
-        digest = excluded.digest
+        digest = excluded.digest,
+        contact_email = excluded.contact_email,
+        annual_review_due_at = excluded.annual_review_due_at
     returning project_id into v_project_id;
 
-    -- Register repositories or update existing ones
-    for v_repository in select * from jsonb_array_elements(p_project->'repositories')
+    -- Register repositories or update existing ones. When no weight is
+    -- provided, the first repository listed is considered the primary one and
+    -- weighs twice as much as the others in the project's score.
+    for v_repository, v_repository_position in
+        select * from jsonb_array_elements(p_project->'repositories') with ordinality
     loop
         insert into repository (
             name,
             url,
             check_sets,
+            check_overrides,
+            provider,
+            container_images,
+            gerrit_url,
+            weight,
             project_id
         ) values (
             v_repository->>'name',
             v_repository->>'url',
             (select array(select jsonb_array_elements_text(v_repository->'check_sets')))::check_set[],
+            nullif(v_repository->'checks', 'null'::jsonb),
+            v_repository->>'provider',
+            nullif(array(select jsonb_array_elements_text(v_repository->'container_images')), '{}'),
+            v_repository->>'gerrit_url',
+            coalesce(
+                (v_repository->>'weight')::real,
+                case when v_repository_position = 1 and v_repositories_count > 1 then 2 else 1 end
+            ),
             v_project_id
         )
         on conflict (project_id, url) do update
         set
             name = excluded.name,
             check_sets = excluded.check_sets,
+            check_overrides = excluded.check_overrides,
+            provider = excluded.provider,
+            container_images = excluded.container_images,
+            gerrit_url = excluded.gerrit_url,
+            weight = excluded.weight,
             digest = null;
     end loop;
 
@@ -0,0 +1,39 @@
+-- rename_project renames the provided project, keeping its repositories,
+-- snapshots and views. The rename is recorded in the project_rename table.
+-- Returns true if the project was renamed (the project must exist and the new
+-- name must not be in use by another project of the foundation).
+create or replace function rename_project(
+    p_foundation_id text,
+    p_old_name text,
+    p_new_name text
+)
+returns boolean as $$
+declare
+    v_project_id uuid;
+begin
+    -- Rename project
+    update project set name = p_new_name
+    where foundation_id = p_foundation_id
+    and name = p_old_name
+    and not exists (
+        select 1 from project
+        where foundation_id = p_foundation_id
+        and name = p_new_name
+    )
+    returning project_id into v_project_id;
+    if v_project_id is null then
+        return false;
+    end if;
+
+    -- Update the project's name in its snapshots
+    update project_snapshot set data = jsonb_set(data, '{name}', to_jsonb(p_new_name))
+    where project_id = v_project_id
+    and data ? 'name';
+
+    -- Record rename
+    insert into project_rename (project_id, foundation_id, old_name, new_name)
+    values (v_project_id, p_foundation_id, p_old_name, p_new_name);
+
+    return true;
+end
+$$ language plpgsql;
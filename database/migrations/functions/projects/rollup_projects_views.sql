@@ -0,0 +1,20 @@
+-- rollup_projects_views compacts the daily views of the projects before the
+-- date provided into monthly totals (stored on the first day of the month).
+create or replace function rollup_projects_views(p_before date)
+returns void as $$
+    with daily_views as (
+        delete from project_views
+        where day < p_before
+        and day <> date_trunc('month', day)::date
+        returning project_id, day, total
+    )
+    insert into project_views (project_id, day, total)
+    select
+        project_id,
+        date_trunc('month', day)::date as day,
+        sum(total) as total
+    from daily_views
+    group by project_id, date_trunc('month', day)
+    on conflict (project_id, day) do
+    update set total = project_views.total + excluded.total;
+$$ language sql;
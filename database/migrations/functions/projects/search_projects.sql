@@ -1,21 +1,54 @@
 -- Search for projects using the input parameters provided and returns the
--- results in json format.
+-- results in json format, along with the total number of projects matching
+-- the filters, the cursor to fetch the next page of results (if any) and the
+-- number of projects matching per foundation, maturity level and rating.
+--
+-- Results are sorted by the sort key (project name or global/section score),
+-- name and id. The cursor is an opaque token encoding the sort key values of
+-- the last project returned, so pages remain consistent even if projects are
+-- added or removed between requests. When a cursor is provided, the offset is
+-- ignored.
 create or replace function search_projects(p_input jsonb)
-returns table(projects json, total_count bigint) as $$
+returns table(projects json, total_count bigint, next_cursor text, facets json) as $$
 declare
     v_limit int := coalesce((p_input->>'limit')::int, 20);
     v_offset int := coalesce((p_input->>'offset')::int, 0);
+    v_cursor jsonb;
     v_sort_by text := coalesce(p_input->>'sort_by', 'name');
     v_sort_direction text := coalesce(p_input->>'sort_direction', 'asc');
+    v_sort_score text;
     v_text text := (p_input->>'text');
     v_foundation text[];
     v_maturity text[];
     v_rating text[];
     v_passing_check text[];
     v_not_passing_check text[];
+    v_failing_check text[];
     v_accepted_from date := (p_input->>'accepted_from');
     v_accepted_to date := (p_input->>'accepted_to');
 begin
+    -- Prepare sorting and pagination
+    v_sort_score := case
+        when v_sort_by = 'score' then 'global'
+        when v_sort_by in (
+            'documentation',
+            'license',
+            'best_practices',
+            'security',
+            'legal',
+            'artifacts',
+            'organization'
+        ) then v_sort_by
+    end;
+    if p_input ? 'cursor' and p_input->'cursor' <> 'null' then
+        begin
+            v_cursor := convert_from(decode(p_input->>'cursor', 'hex'), 'utf8')::jsonb;
+        exception when others then
+            raise exception 'invalid cursor' using errcode = 'invalid_parameter_value';
+        end;
+        v_offset := 0;
+    end if;
+
     -- Prepare filters
     if p_input ? 'foundation' and p_input->'foundation' <> 'null' then
         select array_agg(e::text) into v_foundation
@@ -37,6 +70,10 @@ begin
         select array_agg(e::text) into v_not_passing_check
         from jsonb_array_elements_text(p_input->'not_passing_check') e;
     end if;
+    if p_input ? 'failing_check' and p_input->'failing_check' <> 'null' then
+        select array_agg(e::text) into v_failing_check
+        from jsonb_array_elements_text(p_input->'failing_check') e;
+    end if;
 
     return query
     with filtered_projects as (
@@ -56,7 +93,11 @@ begin
             p.accepted_at,
             p.updated_at,
             p.maturity,
-            p.foundation_id
+            p.foundation_id,
+            case
+                when v_sort_score is null then 0
+                else coalesce((p.score->>v_sort_score)::real, -1)
+            end as sort_key
         from project p
         where score is not null
         and
@@ -84,6 +125,51 @@ begin
         and
             case when cardinality(v_not_passing_check) > 0 then
             not passed_checks && v_not_passing_check else true end
+        and
+            case when cardinality(v_failing_check) > 0 then
+                not coalesce(passed_checks, '{}') && v_failing_check
+                and (
+                    select array_agg(distinct pc.check_id)
+                    from get_project_checks(p.project_id) pc
+                ) @> v_failing_check
+            else true end
+    ),
+    sorted_projects as (
+        select
+            fp.*,
+            row_number() over (
+                order by
+                    (case when v_sort_direction = 'asc' then sort_key end) asc,
+                    (case when v_sort_direction = 'desc' then sort_key end) desc,
+                    (case when v_sort_direction = 'asc' then name end) asc,
+                    (case when v_sort_direction = 'desc' then name end) desc,
+                    (case when v_sort_direction = 'asc' then project_id end) asc,
+                    (case when v_sort_direction = 'desc' then project_id end) desc
+            ) as position
+        from filtered_projects fp
+    ),
+    page as (
+        select *
+        from sorted_projects sp
+        where
+            case
+                when v_cursor is null then true
+                when v_sort_direction = 'desc' then
+                    (sp.sort_key, sp.name, sp.project_id) < (
+                        (v_cursor->>'sort_key')::real,
+                        v_cursor->>'name',
+                        (v_cursor->>'id')::uuid
+                    )
+                else
+                    (sp.sort_key, sp.name, sp.project_id) > (
+                        (v_cursor->>'sort_key')::real,
+                        v_cursor->>'name',
+                        (v_cursor->>'id')::uuid
+                    )
+            end
+        order by position
+        limit v_limit + 1
+        offset v_offset
     )
     select
         (
@@ -117,18 +203,59 @@ begin
             ))), '[]')
             from (
                 select *
-                from filtered_projects
-                order by
-                    (case when v_sort_by = 'score' and v_sort_direction = 'asc' then (score->>'global')::real end) asc,
-                    (case when v_sort_by = 'score' and v_sort_direction = 'desc' then (score->>'global')::real end) desc,
-                    (case when v_sort_by = 'name' and v_sort_direction = 'asc' then name end) asc,
-                    (case when v_sort_by = 'name' and v_sort_direction = 'desc' then name end) desc
+                from page
+                order by position
                 limit v_limit
-                offset v_offset
             ) fp
         ),
         (
             select count(*) from filtered_projects
+        ),
+        (
+            select encode(convert_to(json_build_object(
+                'sort_key', lp.sort_key,
+                'name', lp.name,
+                'id', lp.project_id
+            )::text, 'utf8'), 'hex')
+            from (
+                select *
+                from page
+                order by position
+                offset greatest(v_limit - 1, 0)
+                limit 1
+            ) lp
+            where v_limit > 0
+            and (select count(*) from page) > v_limit
+        ),
+        (
+            select json_build_object(
+                'foundation', (
+                    select coalesce(json_object_agg(foundation_id, total), '{}')
+                    from (
+                        select foundation_id, count(*) as total
+                        from filtered_projects
+                        group by foundation_id
+                    ) f
+                ),
+                'maturity', (
+                    select coalesce(json_object_agg(maturity, total), '{}')
+                    from (
+                        select maturity, count(*) as total
+                        from filtered_projects
+                        where maturity is not null
+                        group by maturity
+                    ) m
+                ),
+                'rating', (
+                    select coalesce(json_object_agg(rating, total), '{}')
+                    from (
+                        select rating, count(*) as total
+                        from filtered_projects
+                        where rating is not null
+                        group by rating
+                    ) r
+                )
+            )
         );
 end
 $$ language plpgsql;
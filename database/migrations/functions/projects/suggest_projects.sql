@@ -0,0 +1,54 @@
+-- Returns the projects whose name, display name or repositories (names or
+-- urls) are similar to the text provided in json format, so that they can be
+-- suggested while the user types a search. Trigram similarity is used to
+-- tolerate typos, and repositories urls can be partial (the scheme and the
+-- .git suffix are ignored). Projects whose name or display name start with the
+-- text provided are ranked first, followed by the most similar ones.
+create or replace function suggest_projects(p_text text, p_limit int default 10)
+returns json as $$
+    with query as (
+        select regexp_replace(lower(trim(p_text)), '^https?://|(\.git)?/*$', '', 'g') as text
+    ),
+    ranked_projects as (
+        select
+            p.project_id,
+            p.name,
+            p.display_name,
+            p.foundation_id,
+            p.logo_url,
+            p.rating,
+            (
+                starts_with(lower(p.name), q.text)
+                or starts_with(lower(p.display_name), q.text)
+            ) is true as prefix_match,
+            greatest(
+                similarity(lower(p.name), q.text),
+                similarity(lower(p.display_name), q.text),
+                (
+                    select max(greatest(
+                        similarity(lower(r.name), q.text),
+                        word_similarity(q.text, regexp_replace(lower(r.url), '^https?://|(\.git)?/*$', '', 'g'))
+                    ))
+                    from repository r
+                    where r.project_id = p.project_id
+                )
+            ) as rank
+        from project p, query q
+        where q.text <> ''
+    )
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'id', rp.project_id,
+        'name', rp.name,
+        'display_name', rp.display_name,
+        'foundation', rp.foundation_id,
+        'logo_url', rp.logo_url,
+        'rating', rp.rating
+    ))), '[]')
+    from (
+        select *
+        from ranked_projects
+        where prefix_match or rank >= 0.3
+        order by prefix_match desc, rank desc, name asc, foundation_id asc
+        limit p_limit
+    ) rp;
+$$ language sql;
@@ -1,7 +1,12 @@
 -- unregister_project unregisters the provided project from the database.
 create or replace function unregister_project(p_foundation_id text, p_project_name text)
 returns void as $$
-    delete from project
-    where foundation_id = p_foundation_id
-    and name = p_project_name;
+    with removed_project as (
+        delete from project
+        where foundation_id = p_foundation_id
+        and name = p_project_name
+        returning foundation_id, name, display_name
+    )
+    insert into project_removal (foundation_id, name, display_name)
+    select foundation_id, name, display_name from removed_project;
 $$ language sql;
@@ -27,6 +27,7 @@ returns setof text as $$
             (rp.data->'best_practices'->'cla'->'passed')::boolean as cla,
             (rp.data->'best_practices'->'community_meeting'->'passed')::boolean as community_meeting,
             (rp.data->'best_practices'->'dco'->'passed')::boolean as dco,
+            (rp.data->'best_practices'->'dependency_update_automation'->'passed')::boolean as dependency_update_automation,
             (rp.data->'best_practices'->'github_discussions'->'passed')::boolean as github_discussions,
             (rp.data->'best_practices'->'openssf_badge'->'passed')::boolean as openssf_badge,
             (rp.data->'best_practices'->'recent_release'->'passed')::boolean as recent_release,
@@ -35,18 +36,23 @@ returns setof text as $$
             (rp.data->'security'->'code_review'->'passed')::boolean as code_review,
             (rp.data->'security'->'dangerous_workflow'->'passed')::boolean as dangerous_workflow,
             (rp.data->'security'->'dependency_update_tool'->'passed')::boolean as dependency_update_tool,
+            (rp.data->'security'->'fuzzing'->'passed')::boolean as fuzzing,
             (rp.data->'security'->'maintained'->'passed')::boolean as maintained,
             (rp.data->'security'->'sbom'->'passed')::boolean as sbom,
+            (rp.data->'security'->'security_insights'->'passed')::boolean as security_insights,
             (rp.data->'security'->'security_policy'->'passed')::boolean as security_policy,
             (rp.data->'security'->'signed_releases'->'passed')::boolean as signed_releases,
+            (rp.data->'security'->'supported_versions'->'passed')::boolean as supported_versions,
             (rp.data->'security'->'token_permissions'->'passed')::boolean as token_permissions,
+            (rp.data->'security'->'vulnerability_disclosure'->'passed')::boolean as vulnerability_disclosure,
+            (rp.data->'legal'->'dco_or_cla'->'passed')::boolean as dco_or_cla,
             (rp.data->'legal'->'trademark_disclaimer'->'passed')::boolean as trademark_disclaimer
         from project p
         join repository r using (project_id)
         join report rp using (repository_id)
         order by p.foundation_id asc, p.name asc
     )
-    select 'Foundation,Project,Repository URL,Check Sets,Adopters,Changelog,Code of Conduct,Contributing,Governance,Maintainers,Readme,Roadmap,Website,License Approved,License Scanning,License SPDX ID,Analytics,ArtifactHub Badge,CLA,Community Meeting,DCO,GitHub discussions,OpenSSF Badge,Recent Release,Slack Presence,Binary Artifacts,Code Review,Dangerous Workflow,Dependency Update Tool,Maintained,SBOM,Security Policy,Signed Releases,Token Permissions,Trademark Disclaimer'
+    select 'Foundation,Project,Repository URL,Check Sets,Adopters,Changelog,Code of Conduct,Contributing,Governance,Maintainers,Readme,Roadmap,Website,License Approved,License Scanning,License SPDX ID,Analytics,ArtifactHub Badge,CLA,Community Meeting,DCO,Dependency Update Automation,GitHub discussions,OpenSSF Badge,Recent Release,Slack Presence,Binary Artifacts,Code Review,Dangerous Workflow,Dependency Update Tool,Fuzzing,Maintained,SBOM,Security Insights,Security Policy,Signed Releases,Supported Versions,Token Permissions,Vulnerability Disclosure,DCO or CLA,Trademark Disclaimer'
     union all
     select rtrim(ltrim(r.*::text, '('), ')') from repositories r;
 $$ language sql;
@@ -0,0 +1,33 @@
+-- Returns the information needed to build a reproducibility bundle of the
+-- provided repository's report in json format.
+create or replace function get_repository_bundle(
+    p_foundation text,
+    p_project_name text,
+    p_repository_name text
+)
+returns json as $$
+    select json_strip_nulls(json_build_object(
+        'foundation', p.foundation_id,
+        'project', p.name,
+        'name', repo.name,
+        'url', repo.url,
+        'digest', repo.digest,
+        'check_sets', report.check_sets,
+        'check_overrides', repo.check_overrides,
+        'provider', repo.provider,
+        'container_images', repo.container_images,
+        'gerrit_url', repo.gerrit_url,
+        'score', repo.score,
+        'report', report.data,
+        'errors', report.errors,
+        'linter_version', report.linter_version,
+        'environment', report.environment,
+        'updated_at', floor(extract(epoch from report.updated_at))
+    ))
+    from repository repo
+    join project p using (project_id)
+    join report report using (repository_id)
+    where p.foundation_id = p_foundation
+    and p.name = p_project_name
+    and repo.name = p_repository_name;
+$$ language sql;
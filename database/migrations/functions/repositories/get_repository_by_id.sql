@@ -0,0 +1,34 @@
+-- Returns some information about a repository, including its report, in json
+-- format.
+create or replace function get_repository_by_id(p_repository_id uuid)
+returns json as $$
+    select json_strip_nulls(json_build_object(
+        'repository_id', r.repository_id,
+        'name', r.name,
+        'url', r.url,
+        'check_sets', r.check_sets,
+        'digest', r.digest,
+        'score', r.score,
+        'weight', r.weight,
+        'report', (
+            select json_build_object(
+                'report_id', report_id,
+                'check_sets', check_sets,
+                'data', data,
+                'errors', errors,
+                'updated_at', floor(extract(epoch from updated_at))
+            )
+            from report
+            where repository_id = r.repository_id
+        ),
+        'project', json_build_object(
+            'id', p.project_id,
+            'name', p.name,
+            'display_name', p.display_name,
+            'foundation', p.foundation_id
+        )
+    ))
+    from repository r
+    join project p using (project_id)
+    where r.repository_id = p_repository_id;
+$$ language sql;
@@ -0,0 +1,14 @@
+-- Returns the repositories matching the url provided in json format. Urls are
+-- compared ignoring the case, trailing slashes and the .git suffix.
+create or replace function search_repositories_by_url(p_url text)
+returns json as $$
+    select coalesce(json_agg(get_repository_by_id(r.repository_id)), '[]')
+    from (
+        select r.repository_id
+        from repository r
+        join project p using (project_id)
+        where regexp_replace(lower(r.url), '(\.git)?/*$', '') =
+            regexp_replace(lower(trim(p_url)), '(\.git)?/*$', '')
+        order by p.foundation_id asc, p.name asc, r.name asc
+    ) r;
+$$ language sql;
@@ -0,0 +1,55 @@
+-- Assigns a role to the subject provided, returning the id of the role
+-- assignment (or null if the foundation or project it is scoped to was not
+-- found). Foundation staff roles are scoped to a foundation, and project
+-- maintainer roles to a project. Assigning a role already assigned returns
+-- the id of the existing assignment.
+create or replace function add_role_assignment(p_input jsonb)
+returns uuid as $$
+declare
+    v_foundation_id text := p_input->>'foundation';
+    v_project_id uuid;
+    v_role_assignment_id uuid;
+begin
+    -- Check the foundation and project the role is scoped to exist
+    if v_foundation_id is not null then
+        perform from foundation where foundation_id = v_foundation_id;
+        if not found then
+            return null;
+        end if;
+    end if;
+    if p_input->>'project' is not null then
+        select project_id into v_project_id
+        from project
+        where foundation_id = v_foundation_id
+        and name = p_input->>'project';
+        if not found then
+            return null;
+        end if;
+    end if;
+
+    -- Add role assignment (if it doesn't exist yet)
+    insert into role_assignment (
+        subject,
+        role,
+        foundation_id,
+        project_id
+    ) values (
+        p_input->>'subject',
+        p_input->>'role',
+        v_foundation_id,
+        v_project_id
+    )
+    on conflict do nothing
+    returning role_assignment_id into v_role_assignment_id;
+    if v_role_assignment_id is null then
+        select role_assignment_id into v_role_assignment_id
+        from role_assignment
+        where subject = p_input->>'subject'
+        and role = p_input->>'role'
+        and foundation_id is not distinct from v_foundation_id
+        and project_id is not distinct from v_project_id;
+    end if;
+
+    return v_role_assignment_id;
+end
+$$ language plpgsql;
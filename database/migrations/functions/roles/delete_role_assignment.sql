@@ -0,0 +1,11 @@
+-- Deletes the role assignment provided. Returns true if the role assignment
+-- was found.
+create or replace function delete_role_assignment(p_role_assignment_id uuid)
+returns boolean as $$
+    with role_assignment_deleted as (
+        delete from role_assignment
+        where role_assignment_id = p_role_assignment_id
+        returning 1
+    )
+    select exists (select 1 from role_assignment_deleted);
+$$ language sql;
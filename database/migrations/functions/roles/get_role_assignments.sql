@@ -0,0 +1,14 @@
+-- Returns all the role assignments registered in json format.
+create or replace function get_role_assignments()
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'role_assignment_id', ra.role_assignment_id,
+        'subject', ra.subject,
+        'role', ra.role,
+        'foundation', ra.foundation_id,
+        'project', p.name,
+        'created_at', floor(extract(epoch from ra.created_at))
+    )) order by ra.subject asc, ra.created_at asc), '[]')
+    from role_assignment ra
+    left join project p using (project_id);
+$$ language sql;
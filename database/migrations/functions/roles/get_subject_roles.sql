@@ -0,0 +1,12 @@
+-- Returns the roles assigned to the subject provided in json format.
+create or replace function get_subject_roles(p_subject text)
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'role', ra.role,
+        'foundation', ra.foundation_id,
+        'project', p.name
+    )) order by ra.role asc, ra.foundation_id asc, p.name asc), '[]')
+    from role_assignment ra
+    left join project p using (project_id)
+    where ra.subject = p_subject;
+$$ language sql;
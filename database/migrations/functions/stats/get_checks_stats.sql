@@ -0,0 +1,63 @@
+-- Returns the percentage of repositories passing each of the checks, now and
+-- monthly in the past (from the stats snapshots available), in json format.
+create or replace function get_checks_stats(p_foundation text)
+returns json as $$
+    set local timezone to 'utc';
+
+    with current_checks as (
+        select
+            c.key as check_id,
+            count(*) filter (where c.value @> '{"passed": true}') as passed,
+            count(*) as total
+        from report rp
+        join repository r using (repository_id)
+        join project p using (project_id)
+        cross join jsonb_each(rp.data) s
+        cross join jsonb_each(s.value) c
+        where c.value <> 'null'
+        and
+            case when p_foundation is not null then
+            p.foundation_id = p_foundation else true end
+        group by c.key
+    ),
+    monthly_snapshots as (
+        select distinct on (date_trunc('month', date))
+            date_trunc('month', date) as month,
+            data
+        from stats_snapshot
+        where
+            case when p_foundation is not null then
+                foundation_id = p_foundation
+            else
+                foundation_id is null
+            end
+        order by date_trunc('month', date) asc, date desc
+    ),
+    monthly_checks as (
+        select
+            c.key as check_id,
+            ms.month,
+            (c.value::text)::real as passing
+        from monthly_snapshots ms
+        cross join jsonb_each(ms.data->'repositories'->'passing_check') s
+        cross join jsonb_each(s.value) c
+        where jsonb_typeof(c.value) = 'number'
+    )
+    select json_build_object(
+        'generated_at', floor(extract(epoch from current_timestamp) * 1000),
+        'checks', (
+            select coalesce(json_object_agg(check_id, json_build_object(
+                'current', round(passed::real / total * 100)::real,
+                'monthly', (
+                    select coalesce(json_agg(json_build_array(
+                        floor(extract(epoch from mc.month) * 1000),
+                        mc.passing
+                    ) order by mc.month asc), '[]')
+                    from monthly_checks mc
+                    where mc.check_id = cc.check_id
+                )
+            ) order by check_id asc), '{}')
+            from current_checks cc
+        )
+    );
+$$ language sql;
@@ -0,0 +1,58 @@
+-- Returns the projects whose global score increased the most during the
+-- period provided (in days), in json format. The current score is compared
+-- with the one in the latest project's snapshot taken before the period
+-- started, so projects without snapshots that old are not included.
+create or replace function get_most_improved_projects(
+    p_foundation text,
+    p_period int,
+    p_limit int
+)
+returns json as $$
+    with baseline as (
+        select distinct on (project_id)
+            project_id,
+            date,
+            (data->'score'->>'global')::real as score
+        from project_snapshot
+        where date <= current_date - p_period
+        order by project_id asc, date desc
+    ),
+    gains as (
+        select
+            p.foundation_id,
+            p.name,
+            p.display_name,
+            p.logo_url,
+            p.maturity,
+            p.rating,
+            (p.score->>'global')::real as score,
+            b.score as previous_score,
+            b.date as previous_date
+        from project p
+        join baseline b using (project_id)
+        where p.score is not null
+        and b.score is not null
+        and
+            case when p_foundation is not null then
+            p.foundation_id = p_foundation else true end
+    )
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'foundation', foundation_id,
+        'name', name,
+        'display_name', display_name,
+        'logo_url', logo_url,
+        'maturity', maturity,
+        'rating', rating,
+        'score', score,
+        'previous_score', previous_score,
+        'previous_date', previous_date,
+        'gain', score - previous_score
+    )) order by score - previous_score desc, name asc), '[]')
+    from (
+        select *
+        from gains
+        where score > previous_score
+        order by score - previous_score desc, name asc
+        limit p_limit
+    ) g;
+$$ language sql;
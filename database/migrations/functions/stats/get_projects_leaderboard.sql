@@ -0,0 +1,42 @@
+-- Returns the top rated projects of each maturity level (sorted by their
+-- global score), in json format.
+create or replace function get_projects_leaderboard(p_foundation text, p_limit int)
+returns json as $$
+    with ranked_projects as (
+        select
+            foundation_id,
+            name,
+            display_name,
+            logo_url,
+            maturity,
+            rating,
+            (score->>'global')::real as score,
+            row_number() over (
+                partition by maturity
+                order by (score->>'global')::real desc, name asc
+            ) as rank
+        from project
+        where score is not null
+        and maturity is not null
+        and
+            case when p_foundation is not null then
+            foundation_id = p_foundation else true end
+    )
+    select coalesce(json_object_agg(maturity, projects), '{}')
+    from (
+        select
+            maturity,
+            json_agg(json_strip_nulls(json_build_object(
+                'rank', rank,
+                'foundation', foundation_id,
+                'name', name,
+                'display_name', display_name,
+                'logo_url', logo_url,
+                'rating', rating,
+                'score', score
+            )) order by rank asc) as projects
+        from ranked_projects
+        where rank <= p_limit
+        group by maturity
+    ) l;
+$$ language sql;
@@ -176,6 +176,7 @@ returns json as $$
                     'cla', repositories_passing_check(p_foundation, 'best_practices', 'cla'),
                     'community_meeting', repositories_passing_check(p_foundation, 'best_practices', 'community_meeting'),
                     'dco', repositories_passing_check(p_foundation, 'best_practices', 'dco'),
+                    'dependency_update_automation', repositories_passing_check(p_foundation, 'best_practices', 'dependency_update_automation'),
                     'github_discussions', repositories_passing_check(p_foundation, 'best_practices', 'github_discussions'),
                     'openssf_badge', repositories_passing_check(p_foundation, 'best_practices', 'openssf_badge'),
                     'recent_release', repositories_passing_check(p_foundation, 'best_practices', 'recent_release'),
@@ -186,13 +187,18 @@ returns json as $$
                     'code_review', repositories_passing_check(p_foundation, 'security', 'code_review'),
                     'dangerous_workflow', repositories_passing_check(p_foundation, 'security', 'dangerous_workflow'),
                     'dependency_update_tool', repositories_passing_check(p_foundation, 'security', 'dependency_update_tool'),
+                    'fuzzing', repositories_passing_check(p_foundation, 'security', 'fuzzing'),
                     'maintained', repositories_passing_check(p_foundation, 'security', 'maintained'),
                     'sbom', repositories_passing_check(p_foundation, 'security', 'sbom'),
+                    'security_insights', repositories_passing_check(p_foundation, 'security', 'security_insights'),
                     'security_policy', repositories_passing_check(p_foundation, 'security', 'security_policy'),
                     'signed_releases', repositories_passing_check(p_foundation, 'security', 'signed_releases'),
-                    'token_permissions', repositories_passing_check(p_foundation, 'security', 'token_permissions')
+                    'supported_versions', repositories_passing_check(p_foundation, 'security', 'supported_versions'),
+                    'token_permissions', repositories_passing_check(p_foundation, 'security', 'token_permissions'),
+                    'vulnerability_disclosure', repositories_passing_check(p_foundation, 'security', 'vulnerability_disclosure')
                 ),
                 'legal', json_build_object(
+                    'dco_or_cla', repositories_passing_check(p_foundation, 'legal', 'dco_or_cla'),
                     'trademark_disclaimer', repositories_passing_check(p_foundation, 'legal', 'trademark_disclaimer')
                 )
             )
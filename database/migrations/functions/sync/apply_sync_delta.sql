@@ -0,0 +1,229 @@
+-- Applies the delta provided (as returned by get_sync_delta on the primary
+-- instance) to the mirror's database, recording the point in time it has been
+-- synced until.
+create or replace function apply_sync_delta(p_delta jsonb)
+returns void as $$
+declare
+    v_project jsonb;
+    v_project_id uuid;
+    v_repository jsonb;
+    v_repository_id uuid;
+begin
+    -- Foundations
+    insert into foundation (foundation_id, display_name, data_url)
+    select f->>'foundation_id', f->>'display_name', f->>'data_url'
+    from jsonb_array_elements(p_delta->'foundations') f
+    on conflict (foundation_id) do update
+    set
+        display_name = excluded.display_name,
+        data_url = excluded.data_url;
+    delete from foundation_weight
+    where foundation_id in (
+        select f->>'foundation_id'
+        from jsonb_array_elements(p_delta->'foundations') f
+    );
+    insert into foundation_weight (foundation_id, kind, name, weight)
+    select f->>'foundation_id', w->>'kind', w->>'name', (w->>'weight')::int
+    from jsonb_array_elements(p_delta->'foundations') f,
+    jsonb_array_elements(f->'weights') w;
+    delete from foundation_maturity_gate
+    where foundation_id in (
+        select f->>'foundation_id'
+        from jsonb_array_elements(p_delta->'foundations') f
+    );
+    insert into foundation_maturity_gate (foundation_id, maturity, name, check_ids)
+    select
+        f->>'foundation_id',
+        (g->>'maturity')::maturity,
+        g->>'name',
+        (select array(select jsonb_array_elements_text(g->'check_ids')))
+    from jsonb_array_elements(p_delta->'foundations') f,
+    jsonb_array_elements(coalesce(f->'maturity_gates', '[]')) g;
+
+    -- Removed projects (projects added again are registered back below)
+    delete from project p
+    using jsonb_array_elements(p_delta->'removed_projects') rp
+    where p.foundation_id = rp->>'foundation_id'
+    and p.name = rp->>'name';
+    insert into project_removal (foundation_id, name, display_name, removed_at)
+    select
+        rp->>'foundation_id',
+        rp->>'name',
+        rp->>'display_name',
+        (rp->>'removed_at')::timestamptz
+    from jsonb_array_elements(p_delta->'removed_projects') rp
+    where not exists (
+        select 1 from project_removal pr
+        where pr.foundation_id = rp->>'foundation_id'
+        and pr.name = rp->>'name'
+        and pr.removed_at = (rp->>'removed_at')::timestamptz
+    );
+
+    -- Projects
+    for v_project in select * from jsonb_array_elements(p_delta->'projects')
+    loop
+        v_project_id := (v_project->>'project_id')::uuid;
+
+        -- Delete project registered with the same name but a different id
+        delete from project
+        where foundation_id = v_project->>'foundation_id'
+        and name = v_project->>'name'
+        and project_id <> v_project_id;
+
+        insert into project (
+            project_id,
+            name,
+            display_name,
+            description,
+            category,
+            home_url,
+            logo_url,
+            logo_dark_url,
+            devstats_url,
+            score,
+            rating,
+            passed_checks,
+            accepted_at,
+            created_at,
+            updated_at,
+            maturity,
+            foundation_id
+        ) values (
+            v_project_id,
+            v_project->>'name',
+            v_project->>'display_name',
+            v_project->>'description',
+            v_project->>'category',
+            v_project->>'home_url',
+            v_project->>'logo_url',
+            v_project->>'logo_dark_url',
+            v_project->>'devstats_url',
+            nullif(v_project->'score', 'null'),
+            v_project->>'rating',
+            case when jsonb_typeof(v_project->'passed_checks') = 'array' then
+                array(select jsonb_array_elements_text(v_project->'passed_checks'))
+            end,
+            (v_project->>'accepted_at')::date,
+            (v_project->>'created_at')::timestamptz,
+            (v_project->>'updated_at')::timestamptz,
+            (v_project->>'maturity')::maturity,
+            v_project->>'foundation_id'
+        )
+        on conflict (project_id) do update
+        set
+            name = excluded.name,
+            display_name = excluded.display_name,
+            description = excluded.description,
+            category = excluded.category,
+            home_url = excluded.home_url,
+            logo_url = excluded.logo_url,
+            logo_dark_url = excluded.logo_dark_url,
+            devstats_url = excluded.devstats_url,
+            score = excluded.score,
+            rating = excluded.rating,
+            passed_checks = excluded.passed_checks,
+            accepted_at = excluded.accepted_at,
+            updated_at = excluded.updated_at,
+            maturity = excluded.maturity,
+            foundation_id = excluded.foundation_id;
+
+        -- Delete repositories that are no longer available
+        delete from repository
+        where project_id = v_project_id
+        and repository_id not in (
+            select (r->>'repository_id')::uuid
+            from jsonb_array_elements(v_project->'repositories') r
+        );
+
+        -- Repositories and reports
+        for v_repository in select * from jsonb_array_elements(v_project->'repositories')
+        loop
+            v_repository_id := (v_repository->>'repository_id')::uuid;
+
+            insert into repository (
+                repository_id,
+                name,
+                url,
+                score,
+                created_at,
+                updated_at,
+                check_sets,
+                tracked_at,
+                check_overrides,
+                provider,
+                container_images,
+                gerrit_url,
+                weight,
+                project_id
+            ) values (
+                v_repository_id,
+                v_repository->>'name',
+                v_repository->>'url',
+                nullif(v_repository->'score', 'null'),
+                (v_repository->>'created_at')::timestamptz,
+                (v_repository->>'updated_at')::timestamptz,
+                (select array(select jsonb_array_elements_text(v_repository->'check_sets')))::check_set[],
+                (v_repository->>'tracked_at')::timestamptz,
+                nullif(v_repository->'check_overrides', 'null'),
+                v_repository->>'provider',
+                nullif(array(select jsonb_array_elements_text(v_repository->'container_images')), '{}'),
+                v_repository->>'gerrit_url',
+                coalesce((v_repository->>'weight')::real, 1),
+                v_project_id
+            )
+            on conflict (repository_id) do update
+            set
+                name = excluded.name,
+                url = excluded.url,
+                score = excluded.score,
+                updated_at = excluded.updated_at,
+                check_sets = excluded.check_sets,
+                tracked_at = excluded.tracked_at,
+                check_overrides = excluded.check_overrides,
+                provider = excluded.provider,
+                container_images = excluded.container_images,
+                gerrit_url = excluded.gerrit_url,
+                weight = excluded.weight;
+
+            if jsonb_typeof(v_repository->'report') = 'object' then
+                insert into report (
+                    report_id,
+                    check_sets,
+                    data,
+                    errors,
+                    created_at,
+                    updated_at,
+                    linter_version,
+                    environment,
+                    repository_id
+                ) values (
+                    (v_repository->'report'->>'report_id')::uuid,
+                    (select array(select jsonb_array_elements_text(v_repository->'report'->'check_sets')))::check_set[],
+                    nullif(v_repository->'report'->'data', 'null'),
+                    v_repository->'report'->>'errors',
+                    (v_repository->'report'->>'created_at')::timestamptz,
+                    (v_repository->'report'->>'updated_at')::timestamptz,
+                    v_repository->'report'->>'linter_version',
+                    nullif(v_repository->'report'->'environment', 'null'),
+                    v_repository_id
+                )
+                on conflict (repository_id) do update
+                set
+                    check_sets = excluded.check_sets,
+                    data = excluded.data,
+                    errors = excluded.errors,
+                    updated_at = excluded.updated_at,
+                    linter_version = excluded.linter_version,
+                    environment = excluded.environment;
+            else
+                delete from report where repository_id = v_repository_id;
+            end if;
+        end loop;
+    end loop;
+
+    -- Record the point in time the mirror has been synced until
+    delete from mirror_sync;
+    insert into mirror_sync (synced_until)
+    values (to_timestamp((p_delta->>'until')::double precision));
+end
+$$ language plpgsql;
@@ -0,0 +1,102 @@
+-- Returns the foundations, the projects (including their repositories and
+-- reports) updated and the projects removed since the timestamp provided, in
+-- json format. Mirror instances use it to keep their databases in sync with
+-- the primary one.
+create or replace function get_sync_delta(p_since timestamptz)
+returns json as $$
+    select json_build_object(
+        'until', floor(extract(epoch from current_timestamp)),
+        'foundations', (
+            select coalesce(json_agg(json_build_object(
+                'foundation_id', f.foundation_id,
+                'display_name', f.display_name,
+                'data_url', f.data_url,
+                'weights', (
+                    select coalesce(json_agg(json_build_object(
+                        'kind', fw.kind,
+                        'name', fw.name,
+                        'weight', fw.weight
+                    )), '[]')
+                    from foundation_weight fw
+                    where fw.foundation_id = f.foundation_id
+                ),
+                'maturity_gates', (
+                    select coalesce(json_agg(json_build_object(
+                        'maturity', fmg.maturity,
+                        'name', fmg.name,
+                        'check_ids', fmg.check_ids
+                    )), '[]')
+                    from foundation_maturity_gate fmg
+                    where fmg.foundation_id = f.foundation_id
+                )
+            )), '[]')
+            from foundation f
+        ),
+        'projects', (
+            select coalesce(json_agg(json_build_object(
+                'project_id', p.project_id,
+                'name', p.name,
+                'display_name', p.display_name,
+                'description', p.description,
+                'category', p.category,
+                'home_url', p.home_url,
+                'logo_url', p.logo_url,
+                'logo_dark_url', p.logo_dark_url,
+                'devstats_url', p.devstats_url,
+                'score', p.score,
+                'rating', p.rating,
+                'passed_checks', p.passed_checks,
+                'accepted_at', p.accepted_at,
+                'created_at', p.created_at,
+                'updated_at', p.updated_at,
+                'maturity', p.maturity,
+                'foundation_id', p.foundation_id,
+                'repositories', (
+                    select coalesce(json_agg(json_build_object(
+                        'repository_id', r.repository_id,
+                        'name', r.name,
+                        'url', r.url,
+                        'score', r.score,
+                        'created_at', r.created_at,
+                        'updated_at', r.updated_at,
+                        'check_sets', r.check_sets,
+                        'tracked_at', r.tracked_at,
+                        'check_overrides', r.check_overrides,
+                        'provider', r.provider,
+                        'container_images', r.container_images,
+                        'gerrit_url', r.gerrit_url,
+                        'weight', r.weight,
+                        'report', (
+                            select json_build_object(
+                                'report_id', rp.report_id,
+                                'check_sets', rp.check_sets,
+                                'data', rp.data,
+                                'errors', rp.errors,
+                                'created_at', rp.created_at,
+                                'updated_at', rp.updated_at,
+                                'linter_version', rp.linter_version,
+                                'environment', rp.environment
+                            )
+                            from report rp
+                            where rp.repository_id = r.repository_id
+                        )
+                    )), '[]')
+                    from repository r
+                    where r.project_id = p.project_id
+                )
+            )), '[]')
+            from project p
+            where p.updated_at > p_since
+        ),
+        'removed_projects', (
+            select coalesce(json_agg(json_build_object(
+                'foundation_id', pr.foundation_id,
+                'name', pr.name,
+                'display_name', pr.display_name,
+                'removed_at', pr.removed_at
+            )), '[]')
+            from project_removal pr
+            where pr.removed_at > p_since
+        )
+    );
+$$ language sql;
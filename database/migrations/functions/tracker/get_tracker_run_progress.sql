@@ -0,0 +1,35 @@
+-- Returns the progress of the latest scheduled tracker run in json format.
+-- The time the run was last interrupted (i.e. the tracker was stopped before
+-- completing it) is included as well.
+create or replace function get_tracker_run_progress()
+returns json as $$
+    select json_build_object(
+        'tracker_run_id', r.tracker_run_id,
+        'started_at', floor(extract(epoch from r.started_at)),
+        'finished_at', floor(extract(epoch from r.finished_at)),
+        'interrupted_at', floor(extract(epoch from r.interrupted_at)),
+        'total', (
+            select count(*) from tracker_run_repository
+            where tracker_run_id = r.tracker_run_id
+        ),
+        'pending', (
+            select count(*) from tracker_run_repository
+            where tracker_run_id = r.tracker_run_id
+            and status = 'pending'
+        ),
+        'in_progress', (
+            select count(*) from tracker_run_repository
+            where tracker_run_id = r.tracker_run_id
+            and status = 'in_progress'
+        ),
+        'done', (
+            select count(*) from tracker_run_repository
+            where tracker_run_id = r.tracker_run_id
+            and status = 'done'
+        )
+    )
+    from tracker_run r
+    where r.on_demand = false
+    order by r.started_at desc
+    limit 1;
+$$ language sql;
@@ -0,0 +1,20 @@
+-- Requests tracking the repositories of the foundation's projects provided
+-- on demand (all the foundation's projects when no projects are provided).
+-- Returns the names of the projects whose tracking has been requested.
+create or replace function request_projects_tracking(
+    p_foundation text,
+    p_project_names text[]
+)
+returns text[] as $$
+    with requested as (
+        update repository r set
+            track_requested_at = coalesce(r.track_requested_at, current_timestamp)
+        from project p
+        where r.project_id = p.project_id
+        and p.foundation_id = p_foundation
+        and (p_project_names is null or p.name = any(p_project_names))
+        returning p.name
+    )
+    select coalesce(array_agg(distinct name order by name), '{}')
+    from requested;
+$$ language sql;
@@ -0,0 +1,47 @@
+-- Starts a new on demand tracker run, queuing the repositories whose tracking
+-- has been requested (those requested first first). Repositories left behind
+-- by previous on demand runs that didn't finish are requested again, and those
+-- runs are closed. Returns the id of the run, or null when there are no
+-- tracking requests pending.
+create or replace function start_on_demand_tracker_run()
+returns uuid as $$
+declare
+    v_tracker_run_id uuid;
+begin
+    -- Request again the repositories of unfinished on demand runs
+    update repository set
+        track_requested_at = coalesce(track_requested_at, current_timestamp)
+    where repository_id in (
+        select q.repository_id
+        from tracker_run_repository q
+        join tracker_run r using (tracker_run_id)
+        where r.on_demand = true
+        and r.finished_at is null
+        and q.status <> 'done'
+    );
+    update tracker_run set finished_at = current_timestamp
+    where on_demand = true
+    and finished_at is null;
+
+    -- Nothing to do when there are no tracking requests pending
+    perform from repository where track_requested_at is not null;
+    if not found then
+        return null;
+    end if;
+
+    -- Start a new run
+    insert into tracker_run (on_demand) values (true)
+    returning tracker_run_id into v_tracker_run_id;
+    insert into tracker_run_repository (tracker_run_id, repository_id, position)
+    select
+        v_tracker_run_id,
+        repository_id,
+        row_number() over (order by track_requested_at asc)
+    from repository
+    where track_requested_at is not null;
+    update repository set track_requested_at = null
+    where track_requested_at is not null;
+
+    return v_tracker_run_id;
+end
+$$ language plpgsql;
@@ -0,0 +1,50 @@
+-- Starts a new tracker run, queuing all the repositories registered that are
+-- due according to the schedules provided (those tracked least recently
+-- first). Each repository is matched against the first schedule selecting its
+-- project's foundation and maturity, and it's only queued if it hasn't been
+-- tracked since the schedule's tracked_before time. Repositories not matched
+-- by any schedule are always queued. If there is a run that hasn't finished
+-- yet, it is resumed instead (on demand runs are not taken into account).
+-- Returns the id of the run.
+create or replace function start_tracker_run(p_schedules jsonb default '[]')
+returns uuid as $$
+declare
+    v_tracker_run_id uuid;
+begin
+    -- Resume the unfinished run if any
+    select tracker_run_id into v_tracker_run_id
+    from tracker_run
+    where finished_at is null
+    and on_demand = false
+    order by started_at desc
+    limit 1
+    for update;
+    if found then
+        return v_tracker_run_id;
+    end if;
+
+    -- Start a new run
+    insert into tracker_run default values
+    returning tracker_run_id into v_tracker_run_id;
+    insert into tracker_run_repository (tracker_run_id, repository_id, position)
+    select
+        v_tracker_run_id,
+        r.repository_id,
+        row_number() over (order by r.tracked_at asc nulls first)
+    from repository r
+    join project p using (project_id)
+    left join lateral (
+        select to_timestamp((s->>'tracked_before')::bigint) as tracked_before
+        from jsonb_array_elements(p_schedules) with ordinality as e(s, n)
+        where (s->>'foundation' is null or s->>'foundation' = p.foundation_id)
+        and (s->>'maturity' is null or s->>'maturity' = p.maturity::text)
+        order by n asc
+        limit 1
+    ) s on true
+    where s.tracked_before is null
+    or r.tracked_at is null
+    or r.tracked_at < s.tracked_before;
+
+    return v_tracker_run_id;
+end
+$$ language plpgsql;
@@ -0,0 +1,11 @@
+create table if not exists project_removal (
+    foundation_id text not null references foundation on delete cascade,
+    name text not null check (name <> ''),
+    display_name text check (display_name <> ''),
+    removed_at timestamptz default current_timestamp not null
+);
+create index project_removal_foundation_id_idx on project_removal (foundation_id);
+
+---- create above / drop below ----
+
+drop table if exists project_removal;
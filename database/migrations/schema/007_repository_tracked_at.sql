@@ -0,0 +1,5 @@
+alter table repository add column tracked_at timestamptz;
+
+---- create above / drop below ----
+
+alter table repository drop column tracked_at;
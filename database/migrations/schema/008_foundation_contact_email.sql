@@ -0,0 +1,5 @@
+alter table foundation add column contact_email text;
+
+---- create above / drop below ----
+
+alter table foundation drop column contact_email;
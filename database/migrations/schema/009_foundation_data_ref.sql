@@ -0,0 +1,5 @@
+alter table foundation add column data_ref text;
+
+---- create above / drop below ----
+
+alter table foundation drop column data_ref;
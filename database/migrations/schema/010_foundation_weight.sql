@@ -0,0 +1,11 @@
+create table if not exists foundation_weight (
+    foundation_id text not null references foundation on delete cascade,
+    kind text not null check (kind in ('check', 'section')),
+    name text not null check (name <> ''),
+    weight integer not null check (weight >= 0),
+    primary key (foundation_id, kind, name)
+);
+
+---- create above / drop below ----
+
+drop table if exists foundation_weight;
@@ -0,0 +1,7 @@
+alter table report add column linter_version text;
+alter table report add column environment jsonb;
+
+---- create above / drop below ----
+
+alter table report drop column environment;
+alter table report drop column linter_version;
@@ -0,0 +1,20 @@
+create table if not exists tracker_run (
+    tracker_run_id uuid primary key default gen_random_uuid(),
+    started_at timestamptz default current_timestamp not null,
+    finished_at timestamptz
+);
+
+create table if not exists tracker_run_repository (
+    tracker_run_id uuid not null references tracker_run on delete cascade,
+    repository_id uuid not null references repository on delete cascade,
+    position integer not null,
+    status text not null default 'pending' check (status in ('pending', 'in_progress', 'done')),
+    updated_at timestamptz default current_timestamp not null,
+    primary key (tracker_run_id, repository_id)
+);
+create index tracker_run_repository_repository_id_idx on tracker_run_repository (repository_id);
+
+---- create above / drop below ----
+
+drop table if exists tracker_run_repository;
+drop table if exists tracker_run;
@@ -0,0 +1,25 @@
+create table if not exists role_assignment (
+    role_assignment_id uuid primary key default gen_random_uuid(),
+    subject text not null check (subject <> ''),
+    role text not null check (role in ('platform_admin', 'foundation_staff', 'project_maintainer')),
+    foundation_id text references foundation on delete cascade,
+    project_id uuid references project on delete cascade,
+    created_at timestamptz default current_timestamp not null,
+    check (
+        (role = 'platform_admin' and foundation_id is null and project_id is null) or
+        (role = 'foundation_staff' and foundation_id is not null and project_id is null) or
+        (role = 'project_maintainer' and foundation_id is not null and project_id is not null)
+    )
+);
+create unique index role_assignment_subject_role_scope_idx on role_assignment (
+    subject,
+    role,
+    coalesce(foundation_id, ''),
+    coalesce(project_id, '00000000-0000-0000-0000-000000000000')
+);
+create index role_assignment_foundation_id_idx on role_assignment (foundation_id);
+create index role_assignment_project_id_idx on role_assignment (project_id);
+
+---- create above / drop below ----
+
+drop table if exists role_assignment;
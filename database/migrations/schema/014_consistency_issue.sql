@@ -0,0 +1,20 @@
+create table if not exists consistency_issue (
+    consistency_issue_id uuid primary key default gen_random_uuid(),
+    kind text not null check (kind in (
+        'project_not_registered',
+        'project_not_in_data_file',
+        'digest_mismatch',
+        'repository_without_report',
+        'orphaned_report'
+    )),
+    foundation_id text not null references foundation on delete cascade,
+    project_name text check (project_name <> ''),
+    repository_url text check (repository_url <> ''),
+    details text not null check (details <> ''),
+    detected_at timestamptz default current_timestamp not null
+);
+create index consistency_issue_foundation_id_idx on consistency_issue (foundation_id);
+
+---- create above / drop below ----
+
+drop table if exists consistency_issue;
@@ -0,0 +1,5 @@
+alter table repository add column check_overrides jsonb;
+
+---- create above / drop below ----
+
+alter table repository drop column check_overrides;
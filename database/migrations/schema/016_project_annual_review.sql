@@ -0,0 +1,9 @@
+alter table project add column contact_email text;
+alter table project add column annual_review_due_at date;
+alter table project add column last_reminded_review_due_at date;
+
+---- create above / drop below ----
+
+alter table project drop column contact_email;
+alter table project drop column annual_review_due_at;
+alter table project drop column last_reminded_review_due_at;
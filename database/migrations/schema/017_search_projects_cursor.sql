@@ -0,0 +1,7 @@
+-- The search_projects function now returns the next page cursor and facets,
+-- so it must be dropped before loading the new version.
+drop function if exists search_projects(jsonb);
+
+---- create above / drop below ----
+
+-- Nothing to do
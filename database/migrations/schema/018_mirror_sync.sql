@@ -0,0 +1,7 @@
+create table if not exists mirror_sync (
+    synced_until timestamptz not null
+);
+
+---- create above / drop below ----
+
+drop table if exists mirror_sync;
@@ -0,0 +1,12 @@
+create table if not exists api_key (
+    api_key_id uuid primary key default gen_random_uuid(),
+    name text not null check (name <> ''),
+    key_hash text not null unique check (key_hash <> ''),
+    rate_limit integer check (rate_limit > 0),
+    created_at timestamptz default current_timestamp not null,
+    revoked_at timestamptz
+);
+
+---- create above / drop below ----
+
+drop table if exists api_key;
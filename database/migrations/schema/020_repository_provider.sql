@@ -0,0 +1,5 @@
+alter table repository add column provider text check (provider in ('github', 'gitlab', 'gitea'));
+
+---- create above / drop below ----
+
+alter table repository drop column provider;
@@ -0,0 +1,16 @@
+create table if not exists score_drop (
+    score_drop_id uuid primary key default gen_random_uuid(),
+    project_id uuid not null references project on delete cascade,
+    repository_id uuid references repository on delete set null,
+    score_from real,
+    score_to real,
+    probable_causes text[],
+    created_at timestamptz default current_timestamp not null
+);
+
+create index score_drop_project_id_idx on score_drop (project_id);
+create index score_drop_repository_id_idx on score_drop (repository_id);
+
+---- create above / drop below ----
+
+drop table if exists score_drop;
@@ -0,0 +1,5 @@
+alter type check_set add value 'docs-site';
+
+---- create above / drop below ----
+
+-- Values cannot be removed from an enum type
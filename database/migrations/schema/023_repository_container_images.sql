@@ -0,0 +1,5 @@
+alter table repository add column container_images text[];
+
+---- create above / drop below ----
+
+alter table repository drop column container_images;
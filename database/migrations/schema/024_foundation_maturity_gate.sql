@@ -0,0 +1,11 @@
+create table if not exists foundation_maturity_gate (
+    foundation_id text not null references foundation on delete cascade,
+    maturity maturity not null,
+    name text not null check (name <> ''),
+    check_ids text[] not null,
+    primary key (foundation_id, maturity, name)
+);
+
+---- create above / drop below ----
+
+drop table if exists foundation_maturity_gate;
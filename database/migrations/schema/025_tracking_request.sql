@@ -0,0 +1,7 @@
+alter table repository add column track_requested_at timestamptz;
+alter table tracker_run add column on_demand boolean not null default false;
+
+---- create above / drop below ----
+
+alter table tracker_run drop column on_demand;
+alter table repository drop column track_requested_at;
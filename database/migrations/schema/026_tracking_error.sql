@@ -0,0 +1,16 @@
+create table if not exists tracking_error (
+    tracking_error_id uuid primary key default gen_random_uuid(),
+    tracker_run_id uuid not null references tracker_run on delete cascade,
+    repository_id uuid not null references repository on delete cascade,
+    check_id text,
+    class text not null check (class in ('rate_limit', 'timeout', 'network', 'other')),
+    message text not null,
+    created_at timestamptz default current_timestamp not null
+);
+
+create index tracking_error_tracker_run_id_idx on tracking_error (tracker_run_id);
+create index tracking_error_repository_id_idx on tracking_error (repository_id);
+
+---- create above / drop below ----
+
+drop table if exists tracking_error;
@@ -0,0 +1,5 @@
+alter table repository add column weight real not null default 1 check (weight > 0);
+
+---- create above / drop below ----
+
+alter table repository drop column weight;
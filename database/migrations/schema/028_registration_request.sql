@@ -0,0 +1,5 @@
+alter table foundation add column registration_requested_at timestamptz;
+
+---- create above / drop below ----
+
+alter table foundation drop column registration_requested_at;
@@ -0,0 +1,14 @@
+create table if not exists project_rename (
+    project_rename_id uuid primary key default gen_random_uuid(),
+    project_id uuid not null references project on delete cascade,
+    foundation_id text not null references foundation on delete cascade,
+    old_name text not null check (old_name <> ''),
+    new_name text not null check (new_name <> ''),
+    renamed_at timestamptz default current_timestamp not null
+);
+
+create index project_rename_project_id_idx on project_rename (project_id);
+
+---- create above / drop below ----
+
+drop table if exists project_rename;
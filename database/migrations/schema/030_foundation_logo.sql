@@ -0,0 +1,5 @@
+alter table foundation add column logo_url text check (logo_url <> '');
+
+---- create above / drop below ----
+
+alter table foundation drop column logo_url;
@@ -0,0 +1,25 @@
+create table if not exists project_webhook (
+    project_webhook_id uuid primary key default gen_random_uuid(),
+    project_id uuid not null references project on delete cascade,
+    url text not null check (url <> ''),
+    events text[] not null check (
+        events <> '{}' and
+        events <@ array['rating_drop', 'score_drop', 'security_check_failed']
+    ),
+    created_by text not null check (created_by <> ''),
+    created_at timestamptz default current_timestamp not null
+);
+create index project_webhook_project_id_idx on project_webhook (project_id);
+
+create table if not exists exemption_acknowledgement (
+    project_id uuid not null references project on delete cascade,
+    check_id text not null check (check_id <> ''),
+    acknowledged_by text not null check (acknowledged_by <> ''),
+    acknowledged_at timestamptz default current_timestamp not null,
+    primary key (project_id, check_id)
+);
+
+---- create above / drop below ----
+
+drop table if exists exemption_acknowledgement;
+drop table if exists project_webhook;
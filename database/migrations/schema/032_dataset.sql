@@ -0,0 +1,8 @@
+create table if not exists dataset (
+    date date primary key,
+    published_at timestamptz default current_timestamp not null
+);
+
+---- create above / drop below ----
+
+drop table if exists dataset;
@@ -0,0 +1,7 @@
+-- The start_tracker_run function now receives the tracking schedules, so the
+-- previous version must be dropped before loading the new one.
+drop function if exists start_tracker_run();
+
+---- create above / drop below ----
+
+-- Nothing to do
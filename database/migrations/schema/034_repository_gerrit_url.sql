@@ -0,0 +1,5 @@
+alter table repository add column gerrit_url text;
+
+---- create above / drop below ----
+
+alter table repository drop column gerrit_url;
@@ -0,0 +1,5 @@
+create extension if not exists pg_trgm;
+
+---- create above / drop below ----
+
+drop extension if exists pg_trgm;
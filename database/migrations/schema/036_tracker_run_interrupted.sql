@@ -0,0 +1,5 @@
+alter table tracker_run add column interrupted_at timestamptz;
+
+---- create above / drop below ----
+
+alter table tracker_run drop column interrupted_at;
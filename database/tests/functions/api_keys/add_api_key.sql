@@ -0,0 +1,19 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Run some tests
+select isnt(
+    add_api_key('{"name": "consumer1", "key_hash": "hash1", "rate_limit": 100}'),
+    null,
+    'API key id should be returned'
+);
+select results_eq(
+    'select name, key_hash, rate_limit, revoked_at from api_key',
+    $$ values ('consumer1', 'hash1', 100, null::timestamptz) $$,
+    'API key should be registered'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
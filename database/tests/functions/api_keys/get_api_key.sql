@@ -0,0 +1,29 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into api_key (api_key_id, name, key_hash, rate_limit, revoked_at) values
+    ('00000000-0000-0000-0001-000000000000', 'consumer1', 'hash1', 100, null),
+    ('00000000-0000-0000-0002-000000000000', 'consumer2', 'hash2', null, current_timestamp);
+
+-- Run some tests
+select is(
+    get_api_key('hash1')::jsonb,
+    '{"api_key_id": "00000000-0000-0000-0001-000000000000", "name": "consumer1", "rate_limit": 100}'::jsonb,
+    'API key matching the hash provided should be returned'
+);
+select is(
+    get_api_key('hash2')::jsonb,
+    null,
+    'API key revoked should not be returned'
+);
+select is(
+    get_api_key('hash3')::jsonb,
+    null,
+    'API key not found should return null'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,39 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- No API keys yet
+select is(
+    get_api_keys()::jsonb,
+    '[]'::jsonb,
+    'No API keys should return an empty array'
+);
+
+-- Seed some data
+insert into api_key (api_key_id, name, key_hash, rate_limit, created_at, revoked_at) values
+    ('00000000-0000-0000-0002-000000000000', 'consumer2', 'hash2', null, '2022-01-01 00:00:00+00', '2022-02-01 00:00:00+00'),
+    ('00000000-0000-0000-0001-000000000000', 'consumer1', 'hash1', 100, '2022-01-01 00:00:00+00', null);
+
+-- Run some tests
+select is(
+    get_api_keys()::jsonb,
+    '[
+        {
+            "api_key_id": "00000000-0000-0000-0001-000000000000",
+            "name": "consumer1",
+            "rate_limit": 100,
+            "created_at": 1640995200
+        },
+        {
+            "api_key_id": "00000000-0000-0000-0002-000000000000",
+            "name": "consumer2",
+            "created_at": 1640995200,
+            "revoked_at": 1643673600
+        }
+    ]'::jsonb,
+    'API keys should be returned without their hashes'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
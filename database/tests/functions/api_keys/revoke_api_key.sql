@@ -0,0 +1,27 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into api_key (api_key_id, name, key_hash)
+values ('00000000-0000-0000-0001-000000000000', 'consumer1', 'hash1');
+
+-- Run some tests
+select is(
+    revoke_api_key('00000000-0000-0000-0001-000000000000'),
+    true,
+    'API key found should return true'
+);
+select isnt_empty(
+    'select * from api_key where revoked_at is not null',
+    'API key should be revoked'
+);
+select is(
+    revoke_api_key('00000000-0000-0000-0001-000000000000'),
+    false,
+    'API key already revoked should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
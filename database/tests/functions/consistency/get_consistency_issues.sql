@@ -0,0 +1,42 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Run some tests
+select is(
+    get_consistency_issues()::jsonb,
+    '[]'::jsonb,
+    'An empty list should be returned when there are no consistency issues'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into consistency_issue (kind, foundation_id, project_name, repository_url, details, detected_at) values
+    ('repository_without_report', 'cncf', 'project2', 'https://repo2.url', 'repository has no report', '2023-01-01'),
+    ('digest_mismatch', 'cncf', 'project1', null, 'digest does not match', '2023-01-01');
+
+select is(
+    get_consistency_issues()::jsonb,
+    '[
+        {
+            "kind": "digest_mismatch",
+            "foundation": "cncf",
+            "project": "project1",
+            "details": "digest does not match",
+            "detected_at": 1672531200
+        },
+        {
+            "kind": "repository_without_report",
+            "foundation": "cncf",
+            "project": "project2",
+            "repository_url": "https://repo2.url",
+            "details": "repository has no report",
+            "detected_at": 1672531200
+        }
+    ]'::jsonb,
+    'All consistency issues should be returned'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
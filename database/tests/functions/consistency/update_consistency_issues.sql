@@ -0,0 +1,32 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into consistency_issue (kind, foundation_id, project_name, details) values
+    ('digest_mismatch', 'cncf', 'project1', 'digest does not match');
+
+-- Run some tests
+select update_consistency_issues('[
+    {
+        "kind": "project_not_registered",
+        "foundation_id": "cncf",
+        "project_name": "project2",
+        "details": "project listed in the data file is not registered"
+    }
+]'::jsonb);
+select results_eq(
+    'select kind, foundation_id, project_name, repository_url, details from consistency_issue',
+    $$ values ('project_not_registered', 'cncf', 'project2', null::text, 'project listed in the data file is not registered') $$,
+    'Existing issues should be replaced by the ones provided'
+);
+select update_consistency_issues('[]'::jsonb);
+select is_empty(
+    'select * from consistency_issue',
+    'No issues should remain when an empty list is provided'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,35 @@
+-- Start transaction and plan tests
+begin;
+select plan(4);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into foundation values ('lfaidata', 'LF AI & Data', 'http://127.0.0.1:8080/lfaidata.yaml');
+insert into project (name, maturity, foundation_id)
+values ('artifact-hub', 'sandbox', 'cncf');
+
+-- Run some tests
+select is(
+    delete_foundation('cncf'),
+    null,
+    'Foundation with projects registered should return null'
+);
+select is(
+    delete_foundation('lfaidata'),
+    true,
+    'Foundation found should return true'
+);
+select is(
+    (select array_agg(foundation_id) from foundation),
+    array['cncf'],
+    'Only foundations without projects should be deleted'
+);
+select is(
+    delete_foundation('foundation-not-found'),
+    false,
+    'Foundation not found should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,91 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/cncf.yaml');
+insert into foundation values ('lfaidata', 'LF AI & Data', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/lfaidata.yaml');
+insert into project (
+    project_id,
+    name,
+    display_name,
+    description,
+    category,
+    logo_url,
+    accepted_at,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'Artifact Hub',
+    'Artifact Hub is a web-based application that enables finding, installing, and publishing packages and configurations for CNCF projects.',
+    'app definition',
+    'https://raw.githubusercontent.com/cncf/artwork/master/projects/artifacthub/icon/color/artifacthub-icon-color.svg',
+    '2020-06-23',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    check_overrides,
+    provider,
+    container_images,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'artifact-hub',
+    'https://github.com/artifacthub/hub',
+    '{code, community}',
+    '{"disable": ["recent_release"]}',
+    'github',
+    '{ghcr.io/artifacthub/hub}',
+    '00000000-0001-0000-0000-000000000000'
+);
+
+-- Run some tests
+select is(
+    get_foundation_data_file('cncf')::jsonb,
+    '[
+        {
+            "name": "artifact-hub",
+            "display_name": "Artifact Hub",
+            "description": "Artifact Hub is a web-based application that enables finding, installing, and publishing packages and configurations for CNCF projects.",
+            "category": "app definition",
+            "logo_url": "https://raw.githubusercontent.com/cncf/artwork/master/projects/artifacthub/icon/color/artifacthub-icon-color.svg",
+            "accepted_at": "2020-06-23",
+            "maturity": "sandbox",
+            "repositories": [
+                {
+                    "name": "artifact-hub",
+                    "url": "https://github.com/artifacthub/hub",
+                    "check_sets": ["code", "community"],
+                    "checks": {
+                        "disable": ["recent_release"]
+                    },
+                    "provider": "github",
+                    "container_images": ["ghcr.io/artifacthub/hub"],
+                    "weight": 1
+                }
+            ]
+        }
+    ]'::jsonb,
+    'Projects registered for the foundation should be returned in data file format'
+);
+select is(
+    get_foundation_data_file('lfaidata')::jsonb,
+    '[]'::jsonb,
+    'An empty list should be returned for a foundation without projects'
+);
+select is(
+    get_foundation_data_file('foundation-not-found')::jsonb,
+    null,
+    'Null should be returned for a foundation not found'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
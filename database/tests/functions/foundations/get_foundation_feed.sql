@@ -0,0 +1,93 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    display_name,
+    maturity,
+    created_at,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'Project 1',
+    'graduated',
+    '2021-06-01',
+    'cncf'
+);
+insert into project (
+    project_id,
+    name,
+    maturity,
+    created_at,
+    foundation_id
+) values (
+    '00000000-0002-0000-0000-000000000000',
+    'project2',
+    'sandbox',
+    '2022-03-15',
+    'cncf'
+);
+insert into project_snapshot (project_id, date, data) values
+    ('00000000-0001-0000-0000-000000000000', '2022-01-01', '{"rating": "b"}'),
+    ('00000000-0001-0000-0000-000000000000', '2022-06-01', '{"rating": "a"}'),
+    ('00000000-0002-0000-0000-000000000000', '2022-06-01', '{"rating": "c"}'),
+    ('00000000-0001-0000-0000-000000000000', '2023-01-01', '{"rating": "d"}');
+
+-- Run some tests
+select is(
+    get_foundation_feed('cncf')::jsonb,
+    '{
+        "foundation": "cncf",
+        "foundation_display_name": "CNCF",
+        "entries": [
+            {
+                "kind": "rating_change",
+                "name": "project1",
+                "display_name": "Project 1",
+                "rating_from": "a",
+                "rating_to": "d",
+                "updated_at": "2023-01-01T00:00:00Z"
+            },
+            {
+                "kind": "rating_change",
+                "name": "project1",
+                "display_name": "Project 1",
+                "rating_from": "b",
+                "rating_to": "a",
+                "updated_at": "2022-06-01T00:00:00Z"
+            },
+            {
+                "kind": "new_project",
+                "name": "project2",
+                "display_name": "project2",
+                "updated_at": "2022-03-15T00:00:00Z"
+            },
+            {
+                "kind": "new_project",
+                "name": "project1",
+                "display_name": "Project 1",
+                "updated_at": "2021-06-01T00:00:00Z"
+            }
+        ]
+    }'::jsonb,
+    'Rating changes and new projects should be returned, most recent first'
+);
+select is(
+    jsonb_array_length(get_foundation_feed('cncf', 1)::jsonb->'entries'),
+    1,
+    'The number of entries returned should be limited to the one provided'
+);
+select is(
+    get_foundation_feed('foundation-not-found')::jsonb,
+    null,
+    'Null should be returned for a foundation not found'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,77 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/cncf.yaml');
+insert into foundation values ('lfaidata', 'LF AI & Data', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/lfaidata.yaml');
+insert into foundation_maturity_gate values ('cncf', 'incubating', 'Security policy', '{security_policy}');
+insert into foundation_maturity_gate values ('cncf', 'graduated', 'OpenSSF best practices', '{openssf_badge, security_insights}');
+insert into project (
+    project_id,
+    name,
+    display_name,
+    category,
+    maturity,
+    passed_checks,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'Artifact Hub',
+    'app definition',
+    'incubating',
+    '{openssf_badge, readme, security_policy}',
+    'cncf'
+);
+
+-- Run some tests
+select is(
+    get_foundation_maturity_gates('cncf')::jsonb,
+    '{
+        "foundation": "cncf",
+        "foundation_display_name": "CNCF",
+        "projects": [
+            {
+                "name": "artifact-hub",
+                "display_name": "Artifact Hub",
+                "maturity": "incubating",
+                "gates": [
+                    {
+                        "maturity": "incubating",
+                        "name": "Security policy",
+                        "checks": ["security_policy"],
+                        "passed": true,
+                        "checks_not_passed": []
+                    },
+                    {
+                        "maturity": "graduated",
+                        "name": "OpenSSF best practices",
+                        "checks": ["openssf_badge", "security_insights"],
+                        "passed": false,
+                        "checks_not_passed": ["security_insights"]
+                    }
+                ]
+            }
+        ]
+    }'::jsonb,
+    'Foundation projects should be evaluated against its maturity gates'
+);
+select is(
+    get_foundation_maturity_gates('lfaidata')::jsonb,
+    '{
+        "foundation": "lfaidata",
+        "foundation_display_name": "LF AI & Data",
+        "projects": []
+    }'::jsonb,
+    'An empty list of projects should be returned for a foundation without projects'
+);
+select is(
+    get_foundation_maturity_gates('foundation-not-found')::jsonb,
+    null,
+    'Null should be returned for a foundation not found'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
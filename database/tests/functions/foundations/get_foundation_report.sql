@@ -0,0 +1,98 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    display_name,
+    maturity,
+    created_at,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'Project 1',
+    'graduated',
+    '2021-06-01',
+    'cncf'
+);
+insert into project (
+    project_id,
+    name,
+    maturity,
+    created_at,
+    foundation_id
+) values (
+    '00000000-0002-0000-0000-000000000000',
+    'project2',
+    'sandbox',
+    '2022-03-15',
+    'cncf'
+);
+insert into project (
+    project_id,
+    name,
+    maturity,
+    created_at,
+    foundation_id
+) values (
+    '00000000-0003-0000-0000-000000000000',
+    'project3',
+    'sandbox',
+    '2021-06-01',
+    'cncf'
+);
+insert into project_snapshot (project_id, date, data) values
+    ('00000000-0001-0000-0000-000000000000', '2022-01-01', '{"score": {"global": 70.0}, "rating": "b"}'),
+    ('00000000-0001-0000-0000-000000000000', '2022-06-01', '{"score": {"global": 80.0}, "rating": "a"}'),
+    ('00000000-0002-0000-0000-000000000000', '2022-06-01', '{"score": {"global": 60.0}, "rating": "c"}'),
+    ('00000000-0001-0000-0000-000000000000', '2023-01-01', '{"score": {"global": 40.0}, "rating": "d"}');
+insert into stats_snapshot (foundation_id, date, data) values
+    ('cncf', '2022-01-01', '{"repositories": {"passing_check": {"documentation": {"adopters": 20, "readme": 90}, "security": {"sbom": 10}}}}'),
+    ('cncf', '2022-06-01', '{"repositories": {"passing_check": {"documentation": {"adopters": 50, "readme": 80}, "security": {"sbom": 40}}}}');
+
+-- Unregister one project (it should be listed as removed)
+select unregister_project('cncf', 'project3');
+
+-- Run some tests
+select is(
+    get_foundation_report('cncf', '2022-01-01', '2022-12-31')::jsonb - 'removed_projects',
+    '{
+        "foundation": "cncf",
+        "foundation_display_name": "CNCF",
+        "from": "2022-01-01",
+        "to": "2022-12-31",
+        "score_evolution": [
+            {"month": "2022-01", "average_score": 70},
+            {"month": "2022-06", "average_score": 70}
+        ],
+        "checks_improvement": [
+            {"check": "adopters", "passing_from": 20, "passing_to": 50},
+            {"check": "sbom", "passing_from": 10, "passing_to": 40}
+        ],
+        "rating_changes": [
+            {"name": "project1", "display_name": "Project 1", "rating_from": "b", "rating_to": "a"}
+        ],
+        "new_projects": [
+            {"name": "project2", "display_name": "project2"}
+        ]
+    }'::jsonb,
+    'Report for the period provided returned as a json object'
+);
+select is(
+    get_foundation_report('cncf', '2022-01-01', current_date)::jsonb->'removed_projects',
+    '[{"name": "project3", "display_name": "project3"}]'::jsonb,
+    'Unregistered project listed as removed'
+);
+select is(
+    get_foundation_report('non-existing', '2022-01-01', '2022-12-31')::jsonb,
+    null,
+    'No report returned for non existing foundation'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
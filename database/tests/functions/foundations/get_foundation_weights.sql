@@ -0,0 +1,42 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/cncf.yaml');
+insert into foundation values ('lfaidata', 'LF AI & Data', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/lfaidata.yaml');
+insert into foundation_weight values ('cncf', 'check', 'adopters', 3);
+insert into foundation_weight values ('cncf', 'check', 'roadmap', 0);
+insert into foundation_weight values ('cncf', 'section', 'security', 10);
+
+-- Run some tests
+select is(
+    get_foundation_weights('cncf')::jsonb,
+    '{
+        "checks": {
+            "adopters": 3,
+            "roadmap": 0
+        },
+        "sections": {
+            "security": 10
+        }
+    }'::jsonb,
+    'Weights overrides of the foundation should be returned'
+);
+select is(
+    get_foundation_weights('lfaidata')::jsonb,
+    '{
+        "checks": {},
+        "sections": {}
+    }'::jsonb,
+    'No weights overrides should be returned for foundation without overrides'
+);
+select is(
+    get_foundation_weights('foundation-not-found')::jsonb,
+    null,
+    'Null should be returned for a foundation not found'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
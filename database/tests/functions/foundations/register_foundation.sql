@@ -0,0 +1,44 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Run some tests
+select is(
+    register_foundation('{
+        "foundation_id": "cncf",
+        "display_name": "CNCF",
+        "data_url": "http://127.0.0.1:8080/cncf.yaml",
+        "logo_url": "http://127.0.0.1:8080/cncf.svg"
+    }'::jsonb),
+    true,
+    'Foundation registered should return true'
+);
+select results_eq(
+    $$
+        select foundation_id, display_name, data_url, data_ref, logo_url
+        from foundation
+    $$,
+    $$
+        values (
+            'cncf',
+            'CNCF',
+            'http://127.0.0.1:8080/cncf.yaml',
+            null::text,
+            'http://127.0.0.1:8080/cncf.svg'
+        )
+    $$,
+    'Foundation should be registered'
+);
+select is(
+    register_foundation('{
+        "foundation_id": "cncf",
+        "display_name": "CNCF (duplicate)",
+        "data_url": "http://127.0.0.1:8080/other.yaml"
+    }'::jsonb),
+    false,
+    'Foundation already registered should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,35 @@
+-- Start transaction and plan tests
+begin;
+select plan(4);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+
+-- Run some tests
+select is(
+    request_foundation_registration('cncf'),
+    true,
+    'Foundation found should return true'
+);
+select isnt(
+    (select registration_requested_at from foundation where foundation_id = 'cncf'),
+    null,
+    'Foundation registration should be requested'
+);
+update foundation set registration_requested_at = '2022-01-01 00:00:00+00'
+where foundation_id = 'cncf';
+select request_foundation_registration('cncf');
+select is(
+    (select registration_requested_at from foundation where foundation_id = 'cncf'),
+    '2022-01-01 00:00:00+00'::timestamptz,
+    'Pending registration requests should be kept when requested again'
+);
+select is(
+    request_foundation_registration('foundation-not-found'),
+    false,
+    'Foundation not found should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
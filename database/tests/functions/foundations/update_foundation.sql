@@ -0,0 +1,46 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+
+-- Run some tests
+select is(
+    update_foundation('cncf', '{
+        "display_name": "CNCF updated",
+        "data_url": "http://127.0.0.1:8080/{ref}/cncf.yaml",
+        "ref": "v1.0.0",
+        "logo_url": "http://127.0.0.1:8080/cncf.svg"
+    }'::jsonb),
+    true,
+    'Foundation found should return true'
+);
+select results_eq(
+    $$
+        select display_name, data_url, data_ref, logo_url
+        from foundation
+        where foundation_id = 'cncf'
+    $$,
+    $$
+        values (
+            'CNCF updated',
+            'http://127.0.0.1:8080/{ref}/cncf.yaml',
+            'v1.0.0',
+            'http://127.0.0.1:8080/cncf.svg'
+        )
+    $$,
+    'Foundation should be updated'
+);
+select is(
+    update_foundation('foundation-not-found', '{
+        "display_name": "Not found",
+        "data_url": "http://127.0.0.1:8080/not-found.yaml"
+    }'::jsonb),
+    false,
+    'Foundation not found should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
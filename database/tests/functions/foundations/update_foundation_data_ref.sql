@@ -0,0 +1,37 @@
+-- Start transaction and plan tests
+begin;
+select plan(5);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/{ref}/cncf.yaml');
+
+-- Run some tests
+select is(
+    update_foundation_data_ref('cncf', 'v1.0.0'),
+    true,
+    'Foundation found should return true'
+);
+select is(
+    (select data_ref from foundation where foundation_id = 'cncf'),
+    'v1.0.0',
+    'Foundation data ref should be updated'
+);
+select is(
+    update_foundation_data_ref('cncf', null),
+    true,
+    'Foundation found should return true when clearing the data ref'
+);
+select is(
+    (select data_ref from foundation where foundation_id = 'cncf'),
+    null,
+    'Foundation data ref should be cleared'
+);
+select is(
+    update_foundation_data_ref('foundation-not-found', 'v1.0.0'),
+    false,
+    'Foundation not found should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
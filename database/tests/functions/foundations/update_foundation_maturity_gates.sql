@@ -0,0 +1,42 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/cncf.yaml');
+insert into foundation_maturity_gate values ('cncf', 'graduated', 'Roadmap', '{roadmap}');
+
+-- Run some tests
+select update_foundation_maturity_gates('cncf', '
+    {
+        "incubating": [
+            {
+                "name": "Security policy",
+                "checks": ["security_policy"]
+            }
+        ],
+        "graduated": [
+            {
+                "name": "OpenSSF best practices",
+                "checks": ["openssf_badge", "security_insights"]
+            }
+        ]
+    }
+'::jsonb);
+select results_eq(
+    $$ select maturity::text, name, check_ids from foundation_maturity_gate where foundation_id = 'cncf' order by name $$,
+    $$ values
+        ('graduated', 'OpenSSF best practices', '{openssf_badge, security_insights}'::text[]),
+        ('incubating', 'Security policy', '{security_policy}'::text[])
+    $$,
+    'Maturity gates should be replaced'
+);
+select update_foundation_maturity_gates('cncf', '{}'::jsonb);
+select is_empty(
+    $$ select * from foundation_maturity_gate where foundation_id = 'cncf' $$,
+    'Maturity gates should be removed'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
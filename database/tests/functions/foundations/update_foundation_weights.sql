@@ -0,0 +1,33 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'https://raw.githubusercontent.com/cncf/clomonitor/main/data/cncf.yaml');
+insert into foundation_weight values ('cncf', 'check', 'roadmap', 0);
+
+-- Run some tests
+select update_foundation_weights('cncf', '
+    {
+        "checks": {
+            "adopters": 3
+        },
+        "sections": {
+            "security": 10
+        }
+    }
+'::jsonb);
+select results_eq(
+    $$ select kind, name, weight from foundation_weight where foundation_id = 'cncf' order by kind, name $$,
+    $$ values ('check', 'adopters', 3), ('section', 'security', 10) $$,
+    'Weights overrides should be replaced'
+);
+select update_foundation_weights('cncf', '{}'::jsonb);
+select is_empty(
+    $$ select * from foundation_weight where foundation_id = 'cncf' $$,
+    'Weights overrides should be removed'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,56 @@
+-- Start transaction and plan tests
+begin;
+select plan(6);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'sandbox',
+    'cncf'
+);
+
+-- Run some tests
+select is(
+    acknowledge_exemption('cncf', 'not-found', 'sbom', 'jane'),
+    false,
+    'Acknowledging an exemption of a project not found should return false'
+);
+select is(
+    acknowledge_exemption('cncf', 'artifact-hub', 'sbom', 'jane'),
+    true,
+    'Acknowledging an exemption should return true'
+);
+select is(
+    acknowledge_exemption('cncf', 'artifact-hub', 'sbom', 'john'),
+    true,
+    'Acknowledging an exemption again should return true'
+);
+select is(
+    (
+        select jsonb_agg(a - 'acknowledged_at')
+        from jsonb_array_elements(get_exemption_acknowledgements('cncf', 'artifact-hub')::jsonb) a
+    ),
+    '[{"check_id": "sbom", "acknowledged_by": "john"}]'::jsonb,
+    'Latest acknowledgement should be returned'
+);
+select is(
+    delete_exemption_acknowledgement('cncf', 'artifact-hub', 'sbom'),
+    true,
+    'Acknowledgement found should return true when deleted'
+);
+select is(
+    delete_exemption_acknowledgement('cncf', 'artifact-hub', 'sbom'),
+    false,
+    'Acknowledgement not found should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,117 @@
+-- Start transaction and plan tests
+begin;
+select plan(5);
+
+-- Non existing project
+select is(
+    get_cohort_stats('00000000-0001-0000-0000-000000000000')::jsonb,
+    (null::jsonb),
+    'Null is returned if the requested project does not exist'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    description,
+    category,
+    maturity,
+    score,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'description',
+    'category1',
+    'sandbox',
+    '{"global": 90, "security": 80}',
+    'cncf'
+), (
+    '00000000-0002-0000-0000-000000000000',
+    'project2',
+    'description',
+    'category1',
+    'sandbox',
+    '{"global": 60, "security": 90}',
+    'cncf'
+), (
+    '00000000-0003-0000-0000-000000000000',
+    'project3',
+    'description',
+    'category1',
+    'sandbox',
+    '{"global": 30}',
+    'cncf'
+), (
+    '00000000-0004-0000-0000-000000000000',
+    'project4',
+    'description',
+    'category1',
+    'incubating',
+    '{"global": 10}',
+    'cncf'
+), (
+    '00000000-0005-0000-0000-000000000000',
+    'project5',
+    'description',
+    'category1',
+    null,
+    '{"global": 50}',
+    'cncf'
+), (
+    '00000000-0006-0000-0000-000000000000',
+    'project6',
+    'description',
+    'category1',
+    null,
+    '{"global": 70}',
+    'cncf'
+);
+
+-- Run some tests
+select is(
+    get_cohort_stats('00000000-0001-0000-0000-000000000000')::jsonb,
+    '{
+        "projects": 3,
+        "percentiles": {
+            "global": 100,
+            "security": 50
+        }
+    }'::jsonb,
+    'Percentiles of all the sections scored returned'
+);
+select is(
+    get_cohort_stats('00000000-0003-0000-0000-000000000000')::jsonb,
+    '{
+        "projects": 3,
+        "percentiles": {
+            "global": 33
+        }
+    }'::jsonb,
+    'Sections not scored in the project are not included'
+);
+select is(
+    get_cohort_stats('00000000-0004-0000-0000-000000000000')::jsonb,
+    '{
+        "projects": 1,
+        "percentiles": {
+            "global": 100
+        }
+    }'::jsonb,
+    'Only projects with the same maturity level are part of the cohort'
+);
+select is(
+    get_cohort_stats('00000000-0005-0000-0000-000000000000')::jsonb,
+    '{
+        "projects": 2,
+        "percentiles": {
+            "global": 50
+        }
+    }'::jsonb,
+    'Projects without maturity level are compared with the ones without it'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -121,6 +121,7 @@ select is(
                 },
                 "repository_id": "00000000-0000-0001-0000-000000000000",
                 "score": {"k": "v"},
+                "weight": 1,
                 "url": "https://github.com/artifacthub/hub"
             }
         ],
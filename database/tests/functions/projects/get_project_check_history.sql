@@ -0,0 +1,138 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Non existing project
+select is(
+    get_project_check_history('cncf', 'artifact-hub', 'security_policy')::jsonb,
+    (null::jsonb),
+    'Null is returned if the requested project does not exist'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'sandbox',
+    'cncf'
+);
+
+-- Run some tests
+select is(
+    get_project_check_history('cncf', 'artifact-hub', 'security_policy')::jsonb,
+    '{
+        "check_id": "security_policy",
+        "repositories": []
+    }'::jsonb,
+    'No changes are returned if the project has no snapshots'
+);
+
+-- Seed some snapshots
+insert into project_snapshot (project_id, date, data) values
+(
+    '00000000-0001-0000-0000-000000000000',
+    '2022-01-01',
+    '{
+        "repositories": [
+            {
+                "name": "hub",
+                "url": "https://github.com/artifacthub/hub",
+                "report": {"data": {"security": {"security_policy": {"passed": false}}}}
+            },
+            {
+                "name": "website",
+                "url": "https://github.com/artifacthub/website",
+                "report": {"data": null}
+            }
+        ]
+    }'
+),
+(
+    '00000000-0001-0000-0000-000000000000',
+    '2022-01-02',
+    '{
+        "repositories": [
+            {
+                "name": "hub",
+                "url": "https://github.com/artifacthub/hub",
+                "report": {"data": {"security": {"security_policy": {"passed": true}}}}
+            },
+            {
+                "name": "website",
+                "url": "https://github.com/artifacthub/website",
+                "report": {"data": {"security": {"security_policy": {"passed": true}}}}
+            }
+        ]
+    }'
+),
+(
+    '00000000-0001-0000-0000-000000000000',
+    '2022-01-03',
+    '{
+        "repositories": [
+            {
+                "name": "hub",
+                "url": "https://github.com/artifacthub/hub",
+                "report": {"data": {"security": {"security_policy": {"passed": true}}}}
+            },
+            {
+                "name": "website",
+                "url": "https://github.com/artifacthub/website",
+                "report": {"data": {"security": {"security_policy": null}}}
+            }
+        ]
+    }'
+),
+(
+    '00000000-0001-0000-0000-000000000000',
+    '2022-01-04',
+    '{
+        "repositories": [
+            {
+                "name": "hub",
+                "url": "https://github.com/artifacthub/hub",
+                "report": {"data": {"security": {"security_policy": {"passed": false}}}}
+            },
+            {
+                "name": "website",
+                "url": "https://github.com/artifacthub/website",
+                "report": {"data": {"security": {"security_policy": {"passed": true}}}}
+            }
+        ]
+    }'
+);
+select is(
+    get_project_check_history('cncf', 'artifact-hub', 'security_policy')::jsonb,
+    '{
+        "check_id": "security_policy",
+        "repositories": [
+            {
+                "name": "hub",
+                "url": "https://github.com/artifacthub/hub",
+                "changes": [
+                    {"date": "2022-01-01", "passed": false},
+                    {"date": "2022-01-02", "passed": true},
+                    {"date": "2022-01-04", "passed": false}
+                ]
+            },
+            {
+                "name": "website",
+                "url": "https://github.com/artifacthub/website",
+                "changes": [
+                    {"date": "2022-01-02", "passed": true}
+                ]
+            }
+        ]
+    }'::jsonb,
+    'Changes in the check status of each repository are returned'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,122 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Non existing project
+select is(
+    get_project_tracking_errors('cncf', 'artifact-hub')::jsonb,
+    (null::jsonb),
+    'Null is returned if the requested project does not exist'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'hub',
+    'https://github.com/artifacthub/hub',
+    '{code,community}',
+    '00000000-0001-0000-0000-000000000000'
+), (
+    '00000000-0000-0002-0000-000000000000',
+    'website',
+    'https://github.com/artifacthub/website',
+    '{docs}',
+    '00000000-0001-0000-0000-000000000000'
+);
+
+-- Run some tests
+select is(
+    get_project_tracking_errors('cncf', 'artifact-hub')::jsonb,
+    '[]'::jsonb,
+    'An empty list is returned if no errors have been found tracking the project'
+);
+
+-- Seed some tracking errors
+insert into tracker_run (tracker_run_id, started_at) values
+    ('00000000-0000-0000-0001-000000000000', '2022-12-19 10:00:00+00'),
+    ('00000000-0000-0000-0002-000000000000', '2022-12-20 10:00:00+00');
+insert into tracking_error (
+    tracker_run_id,
+    repository_id,
+    check_id,
+    class,
+    message,
+    created_at
+) values (
+    '00000000-0000-0000-0001-000000000000',
+    '00000000-0000-0001-0000-000000000000',
+    'openssf_badge',
+    'timeout',
+    'operation timed out',
+    '2022-12-19 10:10:00+00'
+), (
+    '00000000-0000-0000-0002-000000000000',
+    '00000000-0000-0001-0000-000000000000',
+    'sbom',
+    'rate_limit',
+    'API rate limit exceeded',
+    '2022-12-20 10:10:00+00'
+), (
+    '00000000-0000-0000-0002-000000000000',
+    '00000000-0000-0002-0000-000000000000',
+    null,
+    'other',
+    'error tracking repository: fake error',
+    '2022-12-20 10:10:00+00'
+);
+
+select is(
+    get_project_tracking_errors('cncf', 'artifact-hub')::jsonb,
+    '[
+        {
+            "tracker_run_id": "00000000-0000-0000-0002-000000000000",
+            "repository_name": "hub",
+            "repository_url": "https://github.com/artifacthub/hub",
+            "check_id": "sbom",
+            "class": "rate_limit",
+            "message": "API rate limit exceeded",
+            "created_at": 1671531000
+        },
+        {
+            "tracker_run_id": "00000000-0000-0000-0002-000000000000",
+            "repository_name": "website",
+            "repository_url": "https://github.com/artifacthub/website",
+            "check_id": null,
+            "class": "other",
+            "message": "error tracking repository: fake error",
+            "created_at": 1671531000
+        },
+        {
+            "tracker_run_id": "00000000-0000-0000-0001-000000000000",
+            "repository_name": "hub",
+            "repository_url": "https://github.com/artifacthub/hub",
+            "check_id": "openssf_badge",
+            "class": "timeout",
+            "message": "operation timed out",
+            "created_at": 1671444600
+        }
+    ]'::jsonb,
+    'Tracking errors of the project repositories are returned, newest first'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,125 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    display_name,
+    category,
+    score,
+    rating,
+    maturity,
+    contact_email,
+    annual_review_due_at,
+    last_reminded_review_due_at,
+    foundation_id
+) values
+    (
+        '00000000-0001-0000-0000-000000000000',
+        'project1',
+        'Project 1',
+        'category1',
+        '{"global": 80.0}',
+        'a',
+        'sandbox',
+        'project1@example.com',
+        current_date + 14,
+        null,
+        'cncf'
+    ),
+    (
+        '00000000-0002-0000-0000-000000000000',
+        'project2',
+        null,
+        'category1',
+        null,
+        null,
+        'incubating',
+        'project2@example.com',
+        current_date + 60,
+        null,
+        'cncf'
+    ),
+    (
+        '00000000-0003-0000-0000-000000000000',
+        'project3',
+        null,
+        'category1',
+        null,
+        null,
+        'sandbox',
+        'project3@example.com',
+        current_date + 7,
+        current_date + 7,
+        'cncf'
+    ),
+    (
+        '00000000-0004-0000-0000-000000000000',
+        'project4',
+        null,
+        'category1',
+        null,
+        null,
+        'sandbox',
+        null,
+        current_date + 7,
+        null,
+        'cncf'
+    );
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'repository1',
+    'https://repo1.url',
+    '{code}',
+    '00000000-0001-0000-0000-000000000000'
+);
+insert into report (
+    data,
+    repository_id
+) values (
+    '{"documentation": {"adopters": {"passed": true}}}',
+    '00000000-0000-0001-0000-000000000000'
+);
+
+-- Run some tests
+select is(
+    get_projects_due_for_review(4)::jsonb,
+    format('[
+        {
+            "foundation_id": "cncf",
+            "name": "project1",
+            "display_name": "Project 1",
+            "maturity": "sandbox",
+            "contact_email": "project1@example.com",
+            "annual_review_due_at": "%s",
+            "score": {"global": 80.0},
+            "rating": "a",
+            "repositories": [
+                {
+                    "name": "repository1",
+                    "url": "https://repo1.url",
+                    "report": {"documentation": {"adopters": {"passed": true}}}
+                }
+            ]
+        }
+    ]', to_char(current_date + 14, 'YYYY-MM-DD'))::jsonb,
+    'Only projects due within the period provided not reminded yet should be returned'
+);
+select is(
+    get_projects_due_for_review(1)::jsonb,
+    '[]'::jsonb,
+    'An empty list should be returned when no projects are due within the period provided'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
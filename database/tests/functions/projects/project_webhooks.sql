@@ -0,0 +1,84 @@
+-- Start transaction and plan tests
+begin;
+select plan(7);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'sandbox',
+    'cncf'
+), (
+    '00000000-0002-0000-0000-000000000000',
+    'clomonitor',
+    'sandbox',
+    'cncf'
+);
+
+-- Run some tests
+select is(
+    get_project_webhooks('cncf', 'not-found')::jsonb,
+    null::jsonb,
+    'Null is returned if the requested project does not exist'
+);
+select is(
+    add_project_webhook('cncf', 'not-found', 'jane', '{
+        "url": "https://hooks.slack.com/services/test",
+        "events": ["rating_drop"]
+    }'::jsonb),
+    null,
+    'Adding a webhook to a project not found should return null'
+);
+select isnt(
+    add_project_webhook('cncf', 'artifact-hub', 'jane', '{
+        "url": "https://hooks.slack.com/services/test",
+        "events": ["rating_drop", "security_check_failed"]
+    }'::jsonb),
+    null,
+    'Adding a webhook should return its id'
+);
+select is(
+    (
+        select jsonb_agg(w - 'project_webhook_id' - 'created_at')
+        from jsonb_array_elements(get_project_webhooks('cncf', 'artifact-hub')::jsonb) w
+    ),
+    '[{
+        "url": "https://hooks.slack.com/services/test",
+        "events": ["rating_drop", "security_check_failed"],
+        "created_by": "jane"
+    }]'::jsonb,
+    'Webhooks registered for the project should be returned'
+);
+select is(
+    delete_project_webhook(
+        'cncf',
+        'clomonitor',
+        (select project_webhook_id from project_webhook)
+    ),
+    false,
+    'Webhooks should not be deleted using another project'
+);
+select is(
+    delete_project_webhook(
+        'cncf',
+        'artifact-hub',
+        (select project_webhook_id from project_webhook)
+    ),
+    true,
+    'Webhook found should return true when deleted'
+);
+select is(
+    get_project_webhooks('cncf', 'artifact-hub')::jsonb,
+    '[]'::jsonb,
+    'No webhooks should be returned after deleting them'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
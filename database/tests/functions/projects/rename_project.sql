@@ -0,0 +1,48 @@
+-- Start transaction and plan tests
+begin;
+select plan(6);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (project_id, name, maturity, foundation_id)
+values ('00000000-0001-0000-0000-000000000000', 'hub', 'sandbox', 'cncf');
+insert into project (project_id, name, maturity, foundation_id)
+values ('00000000-0002-0000-0000-000000000000', 'clomonitor', 'sandbox', 'cncf');
+insert into project_snapshot (project_id, date, data)
+values ('00000000-0001-0000-0000-000000000000', '2022-01-01', '{"name": "hub", "score": {"global": 90}}');
+
+-- Run some tests
+select is(
+    rename_project('cncf', 'hub', 'artifact-hub'),
+    true,
+    'Project found should be renamed'
+);
+select is(
+    (select name from project where project_id = '00000000-0001-0000-0000-000000000000'),
+    'artifact-hub',
+    'Project row should be kept, using the new name'
+);
+select is(
+    (select data from project_snapshot where project_id = '00000000-0001-0000-0000-000000000000'),
+    '{"name": "artifact-hub", "score": {"global": 90}}'::jsonb,
+    'Project snapshots should use the new name'
+);
+select results_eq(
+    'select project_id, old_name, new_name from project_rename',
+    $$ values ('00000000-0001-0000-0000-000000000000'::uuid, 'hub', 'artifact-hub') $$,
+    'Rename should be recorded'
+);
+select is(
+    rename_project('cncf', 'artifact-hub', 'clomonitor'),
+    false,
+    'Project should not be renamed when the new name is in use'
+);
+select is(
+    rename_project('cncf', 'project-not-found', 'new-name'),
+    false,
+    'Project not found should not be renamed'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
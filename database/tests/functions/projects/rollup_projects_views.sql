@@ -0,0 +1,53 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    category,
+    accepted_at,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0000-0000-0000-000000000001',
+    'project1',
+    'category1',
+    '2022-12-19',
+    'sandbox',
+    'cncf'
+);
+insert into project_views values
+    ('00000000-0000-0000-0000-000000000001', '2022-11-01', 5),
+    ('00000000-0000-0000-0000-000000000001', '2022-11-19', 10),
+    ('00000000-0000-0000-0000-000000000001', '2022-11-20', 10),
+    ('00000000-0000-0000-0000-000000000001', '2022-12-19', 10),
+    ('00000000-0000-0000-0000-000000000001', '2022-12-20', 10);
+
+-- Run some tests
+select rollup_projects_views('2022-12-20');
+select results_eq(
+    'select * from project_views order by day asc',
+    $$ values
+        ('00000000-0000-0000-0000-000000000001'::uuid, '2022-11-01'::date, 25),
+        ('00000000-0000-0000-0000-000000000001'::uuid, '2022-12-01'::date, 10),
+        ('00000000-0000-0000-0000-000000000001'::uuid, '2022-12-20'::date, 10)
+    $$,
+    'Daily views before the date provided should be rolled up into monthly totals'
+);
+select rollup_projects_views('2022-12-20');
+select results_eq(
+    'select * from project_views order by day asc',
+    $$ values
+        ('00000000-0000-0000-0000-000000000001'::uuid, '2022-11-01'::date, 25),
+        ('00000000-0000-0000-0000-000000000001'::uuid, '2022-12-01'::date, 10),
+        ('00000000-0000-0000-0000-000000000001'::uuid, '2022-12-20'::date, 10)
+    $$,
+    'Running the roll up again should not modify the views already compacted'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
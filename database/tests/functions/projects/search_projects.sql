@@ -1,6 +1,6 @@
 -- Start transaction and plan tests
 begin;
-select plan(4);
+select plan(10);
 
 -- No projects yet
 select results_eq(
@@ -337,6 +337,93 @@ select results_eq(
     'Search projects with an accepted date filter'
 );
 
+-- Update some scores and reports
+update project set score = '{"global": 70, "security": 50, "artifacts": 40}'
+where project_id = '00000000-0001-0000-0000-000000000000';
+update project set score = '{"global": 60, "security": 80, "artifacts": 90}'
+where project_id = '00000000-0002-0000-0000-000000000000';
+update report set data = '{"documentation": {"readme": {"passed": false}, "website": {"url": "https://test.url"}}}'
+where repository_id = '00000000-0000-0001-0000-000000000000';
+
+-- Sort by section score
+select results_eq(
+    $$
+        select jsonb_path_query_array(projects::jsonb, '$[*].name')
+        from search_projects('{"sort_by": "security", "sort_direction": "desc"}')
+    $$,
+    $$
+        values ('["containerd", "artifact-hub", "tuf"]'::jsonb)
+    $$,
+    'Search projects sorted by the security section score'
+);
+select results_eq(
+    $$
+        select jsonb_path_query_array(projects::jsonb, '$[*].name')
+        from search_projects('{"sort_by": "artifacts", "sort_direction": "desc"}')
+    $$,
+    $$
+        values ('["containerd", "artifact-hub", "tuf"]'::jsonb)
+    $$,
+    'Search projects sorted by the artifacts section score'
+);
+
+-- Failing check filter
+select results_eq(
+    $$
+        select jsonb_path_query_array(projects::jsonb, '$[*].name'), total_count::integer
+        from search_projects('{"failing_check": ["readme"]}')
+    $$,
+    $$
+        values ('["artifact-hub"]'::jsonb, 1)
+    $$,
+    'Search projects with a failing check filter'
+);
+
+-- Cursor pagination
+select results_eq(
+    $$
+        select jsonb_path_query_array(projects::jsonb, '$[*].name'), next_cursor is not null
+        from search_projects('{"limit": 2, "sort_by": "score", "sort_direction": "desc"}')
+    $$,
+    $$
+        values ('["artifact-hub", "containerd"]'::jsonb, true)
+    $$,
+    'First page of results includes the next page cursor'
+);
+select results_eq(
+    $$
+        select jsonb_path_query_array(projects::jsonb, '$[*].name'), next_cursor is null
+        from search_projects(jsonb_build_object(
+            'limit', 2,
+            'sort_by', 'score',
+            'sort_direction', 'desc',
+            'cursor', (
+                select next_cursor
+                from search_projects('{"limit": 2, "sort_by": "score", "sort_direction": "desc"}')
+            )
+        ))
+    $$,
+    $$
+        values ('["tuf"]'::jsonb, true)
+    $$,
+    'Last page of results fetched using the cursor has no next page cursor'
+);
+
+-- Facets
+select results_eq(
+    $$
+        select facets::jsonb from search_projects('{"maturity": ["graduated"]}')
+    $$,
+    $$
+        values ('{
+            "foundation": {"cncf": 2},
+            "maturity": {"graduated": 2},
+            "rating": {"a": 1, "b": 1}
+        }'::jsonb)
+    $$,
+    'Facets include the number of projects matching per foundation, maturity and rating'
+);
+
 -- Finish tests and rollback transaction
 select * from finish();
 rollback;
@@ -0,0 +1,102 @@
+-- Start transaction and plan tests
+begin;
+select plan(6);
+
+-- No projects
+select is(
+    suggest_projects('artifact hub')::jsonb,
+    '[]'::jsonb,
+    'Empty list is returned if no projects are registered'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    display_name,
+    description,
+    category,
+    maturity,
+    rating,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'Artifact Hub',
+    'Artifact Hub is a web-based application that enables finding, installing, and publishing packages and configurations for CNCF projects.',
+    'category1',
+    'sandbox',
+    'a',
+    'cncf'
+), (
+    '00000000-0002-0000-0000-000000000000',
+    'backstage',
+    null,
+    'Backstage is an open platform for building developer portals.',
+    'category1',
+    'incubating',
+    'b',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'artifact-hub',
+    'https://github.com/artifacthub/hub',
+    '{code, community}',
+    '00000000-0001-0000-0000-000000000000'
+), (
+    '00000000-0000-0002-0000-000000000000',
+    'backstage',
+    'https://github.com/backstage/backstage',
+    '{code, community}',
+    '00000000-0002-0000-0000-000000000000'
+);
+
+-- Run some tests
+select is(
+    suggest_projects('artifct hub')::jsonb,
+    '[{
+        "id": "00000000-0001-0000-0000-000000000000",
+        "name": "artifact-hub",
+        "display_name": "Artifact Hub",
+        "foundation": "cncf",
+        "rating": "a"
+    }]'::jsonb,
+    'Project similar to the text provided (with a typo) returned'
+);
+select is(
+    suggest_projects('Back')::jsonb,
+    '[{
+        "id": "00000000-0002-0000-0000-000000000000",
+        "name": "backstage",
+        "foundation": "cncf",
+        "rating": "b"
+    }]'::jsonb,
+    'Project whose name starts with the text provided returned'
+);
+select is(
+    suggest_projects('https://github.com/artifacthub/hu')::jsonb->0->>'name',
+    'artifact-hub',
+    'Project whose repository matches the partial url provided returned'
+);
+select is(
+    json_array_length(suggest_projects('a', 1)),
+    1,
+    'Number of projects returned is limited'
+);
+select is(
+    suggest_projects('kubernetes')::jsonb,
+    '[]'::jsonb,
+    'Empty list is returned if no projects are similar to the text provided'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,76 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- No repositories
+select is(
+    search_repositories_by_url('https://github.com/artifacthub/hub')::jsonb,
+    '[]'::jsonb,
+    'Empty list is returned if no repositories match the url'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    display_name,
+    description,
+    category,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'Artifact Hub',
+    'Artifact Hub is a web-based application that enables finding, installing, and publishing packages and configurations for CNCF projects.',
+    'category1',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'artifact-hub',
+    'https://github.com/artifacthub/hub',
+    '{code, community}',
+    '00000000-0001-0000-0000-000000000000'
+), (
+    '00000000-0000-0002-0000-000000000000',
+    'artifact-hub-website',
+    'https://github.com/artifacthub/website',
+    '{docs}',
+    '00000000-0001-0000-0000-000000000000'
+);
+
+-- Run some tests
+select is(
+    search_repositories_by_url('https://github.com/ArtifactHub/hub.git/')::jsonb,
+    '[{
+        "repository_id": "00000000-0000-0001-0000-000000000000",
+        "name": "artifact-hub",
+        "url": "https://github.com/artifacthub/hub",
+        "check_sets": ["code", "community"],
+        "project": {
+            "id": "00000000-0001-0000-0000-000000000000",
+            "name": "artifact-hub",
+            "display_name": "Artifact Hub",
+            "foundation": "cncf"
+        }
+    }]'::jsonb,
+    'Repository matching the url ignoring case, trailing slash and .git suffix returned'
+);
+select is(
+    search_repositories_by_url('https://github.com/artifacthub/other')::jsonb,
+    '[]'::jsonb,
+    'Empty list is returned if no repositories match the url'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,65 @@
+-- Start transaction and plan tests
+begin;
+select plan(7);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'sandbox',
+    'cncf'
+);
+
+-- Run some tests
+select isnt(
+    add_role_assignment('{"subject": "user1", "role": "platform_admin"}'),
+    null,
+    'Platform admin role should be assigned'
+);
+select isnt(
+    add_role_assignment('{"subject": "user1", "role": "project_maintainer", "foundation": "cncf", "project": "artifact-hub"}'),
+    null,
+    'Project maintainer role should be assigned'
+);
+select results_eq(
+    $$
+        select subject, role, foundation_id, project_id
+        from role_assignment
+        where role = 'project_maintainer'
+    $$,
+    $$
+        values ('user1', 'project_maintainer', 'cncf', '00000000-0001-0000-0000-000000000000'::uuid)
+    $$,
+    'Project maintainer role assignment should be scoped to the project'
+);
+select is(
+    add_role_assignment('{"subject": "user1", "role": "project_maintainer", "foundation": "cncf", "project": "not-found"}'),
+    null,
+    'Assigning a role on a project not found should return null'
+);
+select is(
+    add_role_assignment('{"subject": "user1", "role": "foundation_staff", "foundation": "not-found"}'),
+    null,
+    'Assigning a role on a foundation not found should return null'
+);
+select is(
+    add_role_assignment('{"subject": "user1", "role": "platform_admin"}'),
+    (select role_assignment_id from role_assignment where role = 'platform_admin'),
+    'Assigning a role already assigned should return the existing assignment'
+);
+select throws_ok(
+    $$select add_role_assignment('{"subject": "user1", "role": "foundation_staff"}')$$,
+    '23514',
+    null,
+    'Foundation staff role without foundation should fail'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
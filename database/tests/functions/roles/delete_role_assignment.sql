@@ -0,0 +1,27 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into role_assignment (role_assignment_id, subject, role)
+values ('00000000-0000-0000-0001-000000000000', 'user1', 'platform_admin');
+
+-- Run some tests
+select is(
+    delete_role_assignment('00000000-0000-0000-0001-000000000000'),
+    true,
+    'Role assignment found should return true'
+);
+select is_empty(
+    'select * from role_assignment',
+    'Role assignment should be deleted'
+);
+select is(
+    delete_role_assignment('00000000-0000-0000-0001-000000000000'),
+    false,
+    'Role assignment not found should return false'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
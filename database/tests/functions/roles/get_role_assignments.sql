@@ -0,0 +1,53 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Run some tests
+select is(
+    get_role_assignments()::jsonb,
+    '[]'::jsonb,
+    'An empty list should be returned when there are no role assignments'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'sandbox',
+    'cncf'
+);
+insert into role_assignment (role_assignment_id, subject, role, foundation_id, project_id, created_at) values
+    ('00000000-0000-0000-0001-000000000000', 'user2', 'foundation_staff', 'cncf', null, '2023-01-01'),
+    ('00000000-0000-0000-0002-000000000000', 'user1', 'project_maintainer', 'cncf', '00000000-0001-0000-0000-000000000000', '2023-01-01');
+
+select is(
+    get_role_assignments()::jsonb,
+    '[
+        {
+            "role_assignment_id": "00000000-0000-0000-0002-000000000000",
+            "subject": "user1",
+            "role": "project_maintainer",
+            "foundation": "cncf",
+            "project": "artifact-hub",
+            "created_at": 1672531200
+        },
+        {
+            "role_assignment_id": "00000000-0000-0000-0001-000000000000",
+            "subject": "user2",
+            "role": "foundation_staff",
+            "foundation": "cncf",
+            "created_at": 1672531200
+        }
+    ]'::jsonb,
+    'All role assignments should be returned sorted by subject'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
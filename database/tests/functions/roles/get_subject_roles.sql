@@ -0,0 +1,47 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'artifact-hub',
+    'sandbox',
+    'cncf'
+);
+insert into role_assignment (subject, role, foundation_id, project_id) values
+    ('user1', 'foundation_staff', 'cncf', null),
+    ('user1', 'project_maintainer', 'cncf', '00000000-0001-0000-0000-000000000000'),
+    ('user2', 'platform_admin', null, null);
+
+-- Run some tests
+select is(
+    get_subject_roles('user1')::jsonb,
+    '[
+        {
+            "role": "foundation_staff",
+            "foundation": "cncf"
+        },
+        {
+            "role": "project_maintainer",
+            "foundation": "cncf",
+            "project": "artifact-hub"
+        }
+    ]'::jsonb,
+    'Roles assigned to the subject should be returned'
+);
+select is(
+    get_subject_roles('user3')::jsonb,
+    '[]'::jsonb,
+    'An empty list should be returned for a subject without roles'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,64 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values
+    ('00000000-0000-0001-0000-000000000000', 'repository1', 'https://repo1.url', '{code}', '00000000-0001-0000-0000-000000000000'),
+    ('00000000-0000-0002-0000-000000000000', 'repository2', 'https://repo2.url', '{code}', '00000000-0001-0000-0000-000000000000');
+insert into report (data, repository_id) values
+    ('{"documentation": {"adopters": {"passed": true}, "readme": {"passed": true}}, "security": {"sbom": null}}', '00000000-0000-0001-0000-000000000000'),
+    ('{"documentation": {"adopters": {"passed": false}, "readme": {"passed": true}}, "security": {"sbom": {"passed": true}}}', '00000000-0000-0002-0000-000000000000');
+insert into stats_snapshot (foundation_id, date, data) values
+    ('cncf', '2022-09-10', '{"repositories": {"passing_check": {"documentation": {"adopters": 10, "readme": 90}}}}'),
+    ('cncf', '2022-09-20', '{"repositories": {"passing_check": {"documentation": {"adopters": 20, "readme": 90}}}}'),
+    ('cncf', '2022-10-20', '{"repositories": {"passing_check": {"documentation": {"adopters": 40, "readme": 100}, "security": {"sbom": null}}}}');
+
+-- Run some tests
+select is(
+    get_checks_stats('cncf')::jsonb - 'generated_at',
+    '{
+        "checks": {
+            "adopters": {
+                "current": 50,
+                "monthly": [[1661990400000, 20], [1664582400000, 40]]
+            },
+            "readme": {
+                "current": 100,
+                "monthly": [[1661990400000, 90], [1664582400000, 100]]
+            },
+            "sbom": {
+                "current": 100,
+                "monthly": []
+            }
+        }
+    }'::jsonb,
+    'Checks stats returned as a json object'
+);
+select is(
+    get_checks_stats('non-existing')::jsonb - 'generated_at',
+    '{"checks": {}}'::jsonb,
+    'No checks stats returned for non existing foundation'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,77 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    rating,
+    score,
+    foundation_id
+) values
+    ('00000000-0001-0000-0000-000000000000', 'project1', 'sandbox', 'a', '{"global": 80}', 'cncf'),
+    ('00000000-0002-0000-0000-000000000000', 'project2', 'incubating', 'b', '{"global": 60}', 'cncf'),
+    ('00000000-0003-0000-0000-000000000000', 'project3', 'sandbox', 'c', '{"global": 40}', 'cncf'),
+    ('00000000-0004-0000-0000-000000000000', 'project4', 'sandbox', 'a', '{"global": 90}', 'cncf');
+insert into project_snapshot (project_id, date, data) values
+    ('00000000-0001-0000-0000-000000000000', current_date - 100, '{"score": {"global": 40}}'),
+    ('00000000-0001-0000-0000-000000000000', current_date - 40, '{"score": {"global": 50}}'),
+    ('00000000-0002-0000-0000-000000000000', current_date - 35, '{"score": {"global": 55}}'),
+    ('00000000-0003-0000-0000-000000000000', current_date - 35, '{"score": {"global": 60}}'),
+    ('00000000-0004-0000-0000-000000000000', current_date - 10, '{"score": {"global": 10}}');
+
+-- Run some tests
+select is(
+    get_most_improved_projects('cncf', 30, 10)::jsonb,
+    format('[
+        {
+            "foundation": "cncf",
+            "name": "project1",
+            "maturity": "sandbox",
+            "rating": "a",
+            "score": 80,
+            "previous_score": 50,
+            "previous_date": "%s",
+            "gain": 30
+        },
+        {
+            "foundation": "cncf",
+            "name": "project2",
+            "maturity": "incubating",
+            "rating": "b",
+            "score": 60,
+            "previous_score": 55,
+            "previous_date": "%s",
+            "gain": 5
+        }
+    ]', current_date - 40, current_date - 35)::jsonb,
+    'Projects whose score increased the most in the last 30 days returned'
+);
+select is(
+    get_most_improved_projects(null, 90, 1)::jsonb,
+    format('[
+        {
+            "foundation": "cncf",
+            "name": "project1",
+            "maturity": "sandbox",
+            "rating": "a",
+            "score": 80,
+            "previous_score": 40,
+            "previous_date": "%s",
+            "gain": 40
+        }
+    ]', current_date - 100)::jsonb,
+    'Projects whose score increased the most in the last 90 days returned (limited)'
+);
+select is(
+    get_most_improved_projects('non-existing', 30, 10)::jsonb,
+    '[]'::jsonb,
+    'No projects returned for non existing foundation'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
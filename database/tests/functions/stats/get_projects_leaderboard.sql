@@ -0,0 +1,53 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    maturity,
+    rating,
+    score,
+    foundation_id
+) values
+    ('00000000-0001-0000-0000-000000000000', 'project1', 'incubating', 'a', '{"global": 80}', 'cncf'),
+    ('00000000-0002-0000-0000-000000000000', 'project2', 'incubating', 'b', '{"global": 60}', 'cncf'),
+    ('00000000-0003-0000-0000-000000000000', 'project3', 'sandbox', 'c', '{"global": 40}', 'cncf'),
+    ('00000000-0004-0000-0000-000000000000', 'project4', 'sandbox', null, null, 'cncf');
+
+-- Run some tests
+select is(
+    get_projects_leaderboard('cncf', 1)::jsonb,
+    '{
+        "incubating": [
+            {
+                "rank": 1,
+                "foundation": "cncf",
+                "name": "project1",
+                "rating": "a",
+                "score": 80
+            }
+        ],
+        "sandbox": [
+            {
+                "rank": 1,
+                "foundation": "cncf",
+                "name": "project3",
+                "rating": "c",
+                "score": 40
+            }
+        ]
+    }'::jsonb,
+    'Top rated projects of each maturity level returned'
+);
+select is(
+    get_projects_leaderboard('non-existing', 10)::jsonb,
+    '{}'::jsonb,
+    'No projects returned for non existing foundation'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
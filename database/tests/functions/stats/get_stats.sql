@@ -598,6 +598,7 @@ select is(
                     "cla": 67,
                     "community_meeting": 0,
                     "dco": 67,
+                    "dependency_update_automation": 0,
                     "analytics": 67,
                     "github_discussions": 67,
                     "openssf_badge": 67,
@@ -609,13 +610,18 @@ select is(
                     "code_review": 67,
                     "dangerous_workflow": 67,
                     "dependency_update_tool": 0,
+                    "fuzzing": 0,
                     "maintained": 67,
                     "sbom": 0,
+                    "security_insights": 0,
                     "security_policy": 67,
                     "signed_releases": 0,
-                    "token_permissions": 0
+                    "supported_versions": 0,
+                    "token_permissions": 0,
+                    "vulnerability_disclosure": 0
                 },
                 "legal": {
+                    "dco_or_cla": 0,
                     "trademark_disclaimer": 0
                 }
             }
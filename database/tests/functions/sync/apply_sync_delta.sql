@@ -0,0 +1,117 @@
+-- Start transaction and plan tests
+begin;
+select plan(6);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (project_id, name, category, maturity, foundation_id)
+values ('00000000-0003-0000-0000-000000000000', 'project3', 'category1', 'sandbox', 'cncf');
+
+-- Apply delta
+select apply_sync_delta('{
+    "until": 1654041600,
+    "foundations": [
+        {
+            "foundation_id": "cncf",
+            "display_name": "CNCF updated",
+            "data_url": "http://127.0.0.1:8080/cncf.yaml",
+            "weights": [{"kind": "check", "name": "readme", "weight": 5}],
+            "maturity_gates": [{"maturity": "incubating", "name": "Security policy", "check_ids": ["security_policy"]}]
+        }
+    ],
+    "projects": [
+        {
+            "project_id": "00000000-0001-0000-0000-000000000000",
+            "name": "project1",
+            "display_name": null,
+            "description": null,
+            "category": "category1",
+            "home_url": null,
+            "logo_url": null,
+            "logo_dark_url": null,
+            "devstats_url": null,
+            "score": {"global": 80.0},
+            "rating": "a",
+            "passed_checks": ["readme"],
+            "accepted_at": "2021-01-01",
+            "created_at": "2022-01-01T00:00:00+00:00",
+            "updated_at": "2022-06-01T00:00:00+00:00",
+            "maturity": "sandbox",
+            "foundation_id": "cncf",
+            "repositories": [
+                {
+                    "repository_id": "00000000-0000-0001-0000-000000000000",
+                    "name": "repo1",
+                    "url": "https://repo1.url",
+                    "score": {"global": 80.0},
+                    "created_at": "2022-01-01T00:00:00+00:00",
+                    "updated_at": "2022-06-01T00:00:00+00:00",
+                    "check_sets": ["code"],
+                    "tracked_at": null,
+                    "check_overrides": null,
+                    "report": {
+                        "report_id": "00000000-0000-0000-0001-000000000000",
+                        "check_sets": ["code"],
+                        "data": {"documentation": {"readme": {"passed": true}}},
+                        "errors": null,
+                        "created_at": "2022-06-01T00:00:00+00:00",
+                        "updated_at": "2022-06-01T00:00:00+00:00",
+                        "linter_version": "1.0.0",
+                        "environment": null
+                    }
+                }
+            ]
+        }
+    ],
+    "removed_projects": [
+        {
+            "foundation_id": "cncf",
+            "name": "project3",
+            "display_name": null,
+            "removed_at": "2022-05-01T00:00:00+00:00"
+        }
+    ]
+}');
+
+-- Run some tests
+select is(
+    (select display_name from foundation where foundation_id = 'cncf'),
+    'CNCF updated',
+    'Foundation has been updated'
+);
+select results_eq(
+    $$ select name, weight from foundation_weight where foundation_id = 'cncf' $$,
+    $$ values ('readme', 5) $$,
+    'Foundation weights have been replaced'
+);
+select results_eq(
+    $$ select maturity::text, name, check_ids from foundation_maturity_gate where foundation_id = 'cncf' $$,
+    $$ values ('incubating', 'Security policy', '{security_policy}'::text[]) $$,
+    'Foundation maturity gates have been replaced'
+);
+select results_eq(
+    $$
+        select p.name, p.rating, rp.data
+        from project p
+        join repository r using (project_id)
+        join report rp using (repository_id)
+    $$,
+    $$
+        values ('project1', 'a', '{"documentation": {"readme": {"passed": true}}}'::jsonb)
+    $$,
+    'Project, repository and report have been registered'
+);
+select results_eq(
+    $$ select name from project_removal $$,
+    $$ values ('project3') $$,
+    'Project removed has been deleted and its removal recorded'
+);
+select is(
+    (select synced_until from mirror_sync),
+    '2022-06-01 00:00:00+00'::timestamptz,
+    'Sync status has been recorded'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -0,0 +1,93 @@
+-- Start transaction and plan tests
+begin;
+select plan(3);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into foundation_weight (foundation_id, kind, name, weight)
+values ('cncf', 'check', 'readme', 5);
+insert into foundation_maturity_gate (foundation_id, maturity, name, check_ids)
+values ('cncf', 'incubating', 'Security policy', '{security_policy}');
+insert into project (
+    project_id,
+    name,
+    category,
+    score,
+    rating,
+    maturity,
+    updated_at,
+    foundation_id
+) values
+    (
+        '00000000-0001-0000-0000-000000000000',
+        'project1',
+        'category1',
+        '{"global": 80.0}',
+        'a',
+        'sandbox',
+        '2022-06-01 00:00:00+00',
+        'cncf'
+    ),
+    (
+        '00000000-0002-0000-0000-000000000000',
+        'project2',
+        'category1',
+        null,
+        null,
+        'incubating',
+        '2022-01-01 00:00:00+00',
+        'cncf'
+    );
+insert into repository (repository_id, name, url, check_sets, project_id)
+values (
+    '00000000-0000-0001-0000-000000000000',
+    'repo1',
+    'https://repo1.url',
+    '{code}',
+    '00000000-0001-0000-0000-000000000000'
+);
+insert into report (repository_id, check_sets, data)
+values (
+    '00000000-0000-0001-0000-000000000000',
+    '{code}',
+    '{"documentation": {"readme": {"passed": true}}}'
+);
+insert into project_removal (foundation_id, name, removed_at)
+values ('cncf', 'project3', '2022-05-01 00:00:00+00');
+
+-- Run some tests
+select is(
+    (select get_sync_delta('2022-03-01 00:00:00+00')::jsonb->'foundations'),
+    '[{
+        "foundation_id": "cncf",
+        "display_name": "CNCF",
+        "data_url": "http://127.0.0.1:8080/cncf.yaml",
+        "weights": [{"kind": "check", "name": "readme", "weight": 5}],
+        "maturity_gates": [{"maturity": "incubating", "name": "Security policy", "check_ids": ["security_policy"]}]
+    }]'::jsonb,
+    'All foundations are returned, including their weights and maturity gates'
+);
+select is(
+    (
+        select jsonb_path_query_array(
+            get_sync_delta('2022-03-01 00:00:00+00')::jsonb,
+            '$.projects[*].repositories[*].report.data'
+        )
+    ),
+    '[{"documentation": {"readme": {"passed": true}}}]'::jsonb,
+    'Only projects updated since the timestamp provided are returned, including their reports'
+);
+select is(
+    (
+        select jsonb_path_query_array(
+            get_sync_delta('2022-03-01 00:00:00+00')::jsonb,
+            '$.removed_projects[*].name'
+        )
+    ),
+    '["project3"]'::jsonb,
+    'Projects removed since the timestamp provided are returned'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
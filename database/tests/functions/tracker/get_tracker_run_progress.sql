@@ -0,0 +1,80 @@
+-- Start transaction and plan tests
+begin;
+select plan(2);
+
+-- No runs yet
+select is(
+    get_tracker_run_progress()::jsonb,
+    null::jsonb,
+    'Null should be returned when there are no runs'
+);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    category,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'category1',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    tracked_at,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'repository1',
+    'https://repo1.url',
+    '{code,community}',
+    '2022-12-19 10:00:00+00',
+    '00000000-0001-0000-0000-000000000000'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0002-0000-000000000000',
+    'repository2',
+    'https://repo2.url',
+    '{code,community}',
+    '00000000-0001-0000-0000-000000000000'
+);
+
+insert into tracker_run (tracker_run_id, started_at, interrupted_at) values
+    ('00000000-0000-0000-0001-000000000000', '2022-12-19 10:00:00+00', '2022-12-19 11:00:00+00');
+insert into tracker_run_repository (tracker_run_id, repository_id, position, status) values
+    ('00000000-0000-0000-0001-000000000000', '00000000-0000-0001-0000-000000000000', 1, 'done'),
+    ('00000000-0000-0000-0001-000000000000', '00000000-0000-0002-0000-000000000000', 2, 'pending');
+
+-- Run some tests
+select is(
+    get_tracker_run_progress()::jsonb,
+    '{
+        "tracker_run_id": "00000000-0000-0000-0001-000000000000",
+        "started_at": 1671444000,
+        "finished_at": null,
+        "interrupted_at": 1671447600,
+        "total": 2,
+        "pending": 1,
+        "in_progress": 0,
+        "done": 1
+    }'::jsonb,
+    'Latest run progress should be returned'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
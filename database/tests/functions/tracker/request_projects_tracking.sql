@@ -0,0 +1,76 @@
+-- Start transaction and plan tests
+begin;
+select plan(4);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    category,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'category1',
+    'sandbox',
+    'cncf'
+), (
+    '00000000-0002-0000-0000-000000000000',
+    'project2',
+    'category1',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'repository1',
+    'https://repo1.url',
+    '{code,community}',
+    '00000000-0001-0000-0000-000000000000'
+), (
+    '00000000-0000-0002-0000-000000000000',
+    'repository2',
+    'https://repo2.url',
+    '{code,community}',
+    '00000000-0002-0000-0000-000000000000'
+);
+
+-- Run some tests
+select is(
+    request_projects_tracking('cncf', '{project1,non-existing}'),
+    '{project1}'::text[],
+    'Only the projects found should be returned'
+);
+select results_eq(
+    $$
+        select repository_id
+        from repository
+        where track_requested_at is not null
+    $$,
+    $$
+        values ('00000000-0000-0001-0000-000000000000'::uuid)
+    $$,
+    'Tracking of the project repositories should be requested'
+);
+select is(
+    request_projects_tracking('cncf', null),
+    '{project1,project2}'::text[],
+    'All foundation projects should be requested when no projects are provided'
+);
+select is(
+    request_projects_tracking('non-existing', null),
+    '{}'::text[],
+    'No projects should be returned for a non existing foundation'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
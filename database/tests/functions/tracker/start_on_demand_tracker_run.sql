@@ -0,0 +1,103 @@
+-- Start transaction and plan tests
+begin;
+select plan(5);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    category,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'category1',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    track_requested_at,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'repository1',
+    'https://repo1.url',
+    '{code,community}',
+    '2022-12-19 11:00:00+00',
+    '00000000-0001-0000-0000-000000000000'
+), (
+    '00000000-0000-0002-0000-000000000000',
+    'repository2',
+    'https://repo2.url',
+    '{code,community}',
+    '2022-12-19 10:00:00+00',
+    '00000000-0001-0000-0000-000000000000'
+), (
+    '00000000-0000-0003-0000-000000000000',
+    'repository3',
+    'https://repo3.url',
+    '{code,community}',
+    null,
+    '00000000-0001-0000-0000-000000000000'
+);
+
+-- Run some tests
+select start_on_demand_tracker_run() as tracker_run_id \gset
+select results_eq(
+    $$
+        select repository_id, position, status
+        from tracker_run_repository
+        where tracker_run_id = (select tracker_run_id from tracker_run where on_demand = true)
+        order by position
+    $$,
+    $$
+        values
+            ('00000000-0000-0002-0000-000000000000'::uuid, 1, 'pending'),
+            ('00000000-0000-0001-0000-000000000000'::uuid, 2, 'pending')
+    $$,
+    'New on demand run should queue the repositories requested, those requested first first'
+);
+select is(
+    (select count(*) from repository where track_requested_at is not null),
+    0::bigint,
+    'Tracking requests should be cleared once queued'
+);
+select isnt(
+    start_tracker_run(),
+    :'tracker_run_id'::uuid,
+    'On demand runs should not be resumed by scheduled runs'
+);
+update tracker_run_repository set status = 'done'
+where tracker_run_id = :'tracker_run_id'
+and repository_id = '00000000-0000-0002-0000-000000000000';
+select start_on_demand_tracker_run() as next_tracker_run_id \gset
+select results_eq(
+    $$
+        select repository_id, position, status
+        from tracker_run_repository
+        where tracker_run_id = (
+            select tracker_run_id from tracker_run
+            where on_demand = true and finished_at is null
+        )
+        order by position
+    $$,
+    $$
+        values ('00000000-0000-0001-0000-000000000000'::uuid, 1, 'pending')
+    $$,
+    'Repositories pending in unfinished on demand runs should be queued again'
+);
+select isnt(
+    (select finished_at from tracker_run where tracker_run_id = :'tracker_run_id'),
+    null,
+    'Unfinished on demand run should be closed'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
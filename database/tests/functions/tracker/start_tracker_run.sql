@@ -0,0 +1,104 @@
+-- Start transaction and plan tests
+begin;
+select plan(5);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    category,
+    maturity,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'category1',
+    'sandbox',
+    'cncf'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    tracked_at,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'repository1',
+    'https://repo1.url',
+    '{code,community}',
+    '2022-12-19 10:00:00+00',
+    '00000000-0001-0000-0000-000000000000'
+);
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0002-0000-000000000000',
+    'repository2',
+    'https://repo2.url',
+    '{code,community}',
+    '00000000-0001-0000-0000-000000000000'
+);
+
+-- Run some tests
+select start_tracker_run() as tracker_run_id \gset
+select results_eq(
+    $$
+        select repository_id, position, status
+        from tracker_run_repository
+        where tracker_run_id = (select tracker_run_id from tracker_run)
+        order by position
+    $$,
+    $$
+        values
+            ('00000000-0000-0002-0000-000000000000'::uuid, 1, 'pending'),
+            ('00000000-0000-0001-0000-000000000000'::uuid, 2, 'pending')
+    $$,
+    'New run should queue all repositories, those tracked least recently first'
+);
+select is(
+    start_tracker_run(),
+    :'tracker_run_id'::uuid,
+    'Unfinished run should be resumed'
+);
+update tracker_run set finished_at = current_timestamp where tracker_run_id = :'tracker_run_id';
+select isnt(
+    start_tracker_run(),
+    :'tracker_run_id'::uuid,
+    'New run should be started once the previous one has finished'
+);
+select is(
+    (select count(*) from tracker_run),
+    2::bigint,
+    'Two runs should exist'
+);
+update tracker_run set finished_at = current_timestamp;
+select start_tracker_run(jsonb_build_array(
+    jsonb_build_object(
+        'foundation', 'cncf',
+        'maturity', 'sandbox',
+        'tracked_before', extract(epoch from '2022-12-19 09:00:00+00'::timestamptz)::bigint
+    )
+)) as scheduled_tracker_run_id \gset
+select results_eq(
+    $$
+        select repository_id, position
+        from tracker_run_repository
+        where tracker_run_id = (select tracker_run_id from tracker_run where finished_at is null)
+        order by position
+    $$,
+    $$
+        values ('00000000-0000-0002-0000-000000000000'::uuid, 1)
+    $$,
+    'New run should only queue the repositories due according to the schedules'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -1,23 +1,85 @@
 -- Start transaction and plan tests
 begin;
-select plan(31);
+select plan(123);
 
 -- Check expected extension exist
 select has_extension('pgcrypto');
+select has_extension('pg_trgm');
 
 -- Check expected tables exist
+select has_table('api_key');
+select has_table('consistency_issue');
+select has_table('dataset');
+select has_table('exemption_acknowledgement');
 select has_table('foundation');
+select has_table('foundation_maturity_gate');
+select has_table('foundation_weight');
+select has_table('mirror_sync');
 select has_table('project');
 select has_table('project_snapshot');
+select has_table('project_removal');
+select has_table('project_rename');
 select has_table('project_views');
+select has_table('project_webhook');
 select has_table('report');
 select has_table('repository');
+select has_table('role_assignment');
+select has_table('score_drop');
+select has_table('tracker_run');
+select has_table('tracker_run_repository');
+select has_table('tracking_error');
 
 -- Check tables have expected columns
+select columns_are('api_key', array[
+    'api_key_id',
+    'name',
+    'key_hash',
+    'rate_limit',
+    'created_at',
+    'revoked_at'
+]);
+select columns_are('consistency_issue', array[
+    'consistency_issue_id',
+    'kind',
+    'foundation_id',
+    'project_name',
+    'repository_url',
+    'details',
+    'detected_at'
+]);
+select columns_are('dataset', array[
+    'date',
+    'published_at'
+]);
+select columns_are('exemption_acknowledgement', array[
+    'project_id',
+    'check_id',
+    'acknowledged_by',
+    'acknowledged_at'
+]);
 select columns_are('foundation', array[
     'foundation_id',
     'display_name',
-    'data_url'
+    'data_url',
+    'contact_email',
+    'data_ref',
+    'registration_requested_at',
+    'logo_url'
+]);
+select columns_are('foundation_maturity_gate', array[
+    'foundation_id',
+    'maturity',
+    'name',
+    'check_ids'
+]);
+select columns_are('foundation_weight', array[
+    'foundation_id',
+    'kind',
+    'name',
+    'weight'
+]);
+select columns_are('mirror_sync', array[
+    'synced_until'
 ]);
 select columns_are('project', array[
     'project_id',
@@ -37,18 +99,43 @@ select columns_are('project', array[
     'updated_at',
     'maturity',
     'digest',
-    'foundation_id'
+    'foundation_id',
+    'contact_email',
+    'annual_review_due_at',
+    'last_reminded_review_due_at'
 ]);
 select columns_are('project_snapshot', array[
     'project_id',
     'date',
     'data'
 ]);
+select columns_are('project_removal', array[
+    'foundation_id',
+    'name',
+    'display_name',
+    'removed_at'
+]);
+select columns_are('project_rename', array[
+    'project_rename_id',
+    'project_id',
+    'foundation_id',
+    'old_name',
+    'new_name',
+    'renamed_at'
+]);
 select columns_are('project_views', array[
     'project_id',
     'day',
     'total'
 ]);
+select columns_are('project_webhook', array[
+    'project_webhook_id',
+    'project_id',
+    'url',
+    'events',
+    'created_by',
+    'created_at'
+]);
 select columns_are('report', array[
     'report_id',
     'check_sets',
@@ -56,7 +143,9 @@ select columns_are('report', array[
     'errors',
     'created_at',
     'updated_at',
-    'repository_id'
+    'repository_id',
+    'linter_version',
+    'environment'
 ]);
 select columns_are('repository', array[
     'repository_id',
@@ -67,13 +156,80 @@ select columns_are('repository', array[
     'created_at',
     'updated_at',
     'check_sets',
-    'project_id'
+    'project_id',
+    'tracked_at',
+    'check_overrides',
+    'provider',
+    'container_images',
+    'gerrit_url',
+    'track_requested_at',
+    'weight'
+]);
+select columns_are('role_assignment', array[
+    'role_assignment_id',
+    'subject',
+    'role',
+    'foundation_id',
+    'project_id',
+    'created_at'
+]);
+select columns_are('score_drop', array[
+    'score_drop_id',
+    'project_id',
+    'repository_id',
+    'score_from',
+    'score_to',
+    'probable_causes',
+    'created_at'
+]);
+select columns_are('tracker_run', array[
+    'tracker_run_id',
+    'started_at',
+    'finished_at',
+    'on_demand',
+    'interrupted_at'
+]);
+select columns_are('tracker_run_repository', array[
+    'tracker_run_id',
+    'repository_id',
+    'position',
+    'status',
+    'updated_at'
+]);
+select columns_are('tracking_error', array[
+    'tracking_error_id',
+    'tracker_run_id',
+    'repository_id',
+    'check_id',
+    'class',
+    'message',
+    'created_at'
 ]);
 
 -- Check tables have expected indexes
+select indexes_are('api_key', array[
+    'api_key_pkey',
+    'api_key_key_hash_key'
+]);
+select indexes_are('consistency_issue', array[
+    'consistency_issue_pkey',
+    'consistency_issue_foundation_id_idx'
+]);
+select indexes_are('dataset', array[
+    'dataset_pkey'
+]);
+select indexes_are('exemption_acknowledgement', array[
+    'exemption_acknowledgement_pkey'
+]);
 select indexes_are('foundation', array[
     'foundation_pkey'
 ]);
+select indexes_are('foundation_maturity_gate', array[
+    'foundation_maturity_gate_pkey'
+]);
+select indexes_are('foundation_weight', array[
+    'foundation_weight_pkey'
+]);
 select indexes_are('project', array[
     'project_pkey',
     'project_foundation_id_idx',
@@ -82,9 +238,20 @@ select indexes_are('project', array[
 select indexes_are('project_snapshot', array[
     'project_snapshot_pkey'
 ]);
+select indexes_are('project_removal', array[
+    'project_removal_foundation_id_idx'
+]);
+select indexes_are('project_rename', array[
+    'project_rename_pkey',
+    'project_rename_project_id_idx'
+]);
 select indexes_are('project_views', array[
     'project_views_project_id_day_key'
 ]);
+select indexes_are('project_webhook', array[
+    'project_webhook_pkey',
+    'project_webhook_project_id_idx'
+]);
 select indexes_are('report', array[
     'report_pkey',
     'report_repository_id_idx',
@@ -95,23 +262,99 @@ select indexes_are('repository', array[
     'repository_project_id_idx',
     'repository_project_id_url_key'
 ]);
+select indexes_are('role_assignment', array[
+    'role_assignment_pkey',
+    'role_assignment_subject_role_scope_idx',
+    'role_assignment_foundation_id_idx',
+    'role_assignment_project_id_idx'
+]);
+select indexes_are('score_drop', array[
+    'score_drop_pkey',
+    'score_drop_project_id_idx',
+    'score_drop_repository_id_idx'
+]);
+select indexes_are('tracker_run', array[
+    'tracker_run_pkey'
+]);
+select indexes_are('tracker_run_repository', array[
+    'tracker_run_repository_pkey',
+    'tracker_run_repository_repository_id_idx'
+]);
+select indexes_are('tracking_error', array[
+    'tracking_error_pkey',
+    'tracking_error_tracker_run_id_idx',
+    'tracking_error_repository_id_idx'
+]);
 
 -- Check expected functions exist
+-- API keys
+select has_function('add_api_key');
+select has_function('get_api_key');
+select has_function('get_api_keys');
+select has_function('revoke_api_key');
+-- Consistency
+select has_function('get_consistency_issues');
+select has_function('update_consistency_issues');
+-- Foundations
+select has_function('delete_foundation');
+select has_function('get_foundation_data_file');
+select has_function('get_foundation_feed');
+select has_function('get_foundation_maturity_gates');
+select has_function('get_foundation_report');
+select has_function('get_foundation_weights');
+select has_function('register_foundation');
+select has_function('request_foundation_registration');
+select has_function('update_foundation');
+select has_function('update_foundation_data_ref');
+select has_function('update_foundation_maturity_gates');
+select has_function('update_foundation_weights');
 -- Projects
+select has_function('acknowledge_exemption');
+select has_function('add_project_webhook');
+select has_function('delete_exemption_acknowledgement');
+select has_function('delete_project_webhook');
+select has_function('get_cohort_stats');
+select has_function('get_exemption_acknowledgements');
 select has_function('get_project_by_id');
 select has_function('get_project_by_name');
+select has_function('get_project_check_history');
 select has_function('get_project_checks');
 select has_function('get_project_passed_checks');
+select has_function('get_project_tracking_errors');
+select has_function('get_project_webhooks');
+select has_function('get_projects_due_for_review');
 select has_function('register_project');
+select has_function('rename_project');
+select has_function('rollup_projects_views');
 select has_function('search_projects');
+select has_function('suggest_projects');
 select has_function('unregister_project');
 select has_function('update_projects_views');
 -- Repositories
 select has_function('get_repositories_with_checks');
+select has_function('get_repository_bundle');
+select has_function('get_repository_by_id');
 select has_function('get_repository_report');
+select has_function('search_repositories_by_url');
+-- Roles
+select has_function('add_role_assignment');
+select has_function('delete_role_assignment');
+select has_function('get_role_assignments');
+select has_function('get_subject_roles');
 -- Stats
 select has_function('repositories_passing_check');
+select has_function('get_checks_stats');
+select has_function('get_most_improved_projects');
+select has_function('get_projects_leaderboard');
 select has_function('get_stats');
+-- Sync
+select has_function('apply_sync_delta');
+select has_function('get_sync_delta');
+-- Tracker
+select has_function('get_tracker_run_progress');
+select has_function('request_projects_tracking');
+select has_function('start_on_demand_tracker_run');
+select has_function('start_tracker_run');
 
 -- Finish tests and rollback transaction
 select * from finish();
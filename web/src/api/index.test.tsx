@@ -93,6 +93,24 @@ describe('API', () => {
       });
     });
 
+    describe('suggestProjects', () => {
+      it('success', async () => {
+        const data = [{ id: '00000000-0001-0000-0000-000000000000', name: 'artifact-hub', foundation: 'cncf' }];
+        fetchMock.mockResponse(JSON.stringify(data), {
+          headers: {
+            'content-type': 'application/json',
+          },
+          status: 200,
+        });
+
+        const response = await API.suggestProjects('artifacthub/hu');
+
+        expect(fetchMock).toHaveBeenCalledTimes(1);
+        expect(fetchMock.mock.calls[0][0]).toEqual('/api/projects/suggest?q=artifacthub%2Fhu');
+        expect(response).toEqual(data);
+      });
+    });
+
     describe('getRepositoriesCSV', () => {
       it('success', async () => {
         const csv: string = `
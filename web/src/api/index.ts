@@ -2,7 +2,7 @@ import { isEmpty, isNull, isUndefined } from 'lodash';
 import isArray from 'lodash/isArray';
 
 import { DEFAULT_SORT_BY, DEFAULT_SORT_DIRECTION } from '../data';
-import { Error, ErrorKind, Project, ProjectDetail, SearchQuery, Stats } from '../types';
+import { Error, ErrorKind, Project, ProjectDetail, ProjectSuggestion, SearchQuery, Stats } from '../types';
 
 interface FetchOptions {
   method: 'POST' | 'GET' | 'PUT' | 'DELETE' | 'HEAD';
@@ -148,6 +148,12 @@ class API_CLASS {
     });
   }
 
+  public suggestProjects(text: string): Promise<ProjectSuggestion[]> {
+    return this.apiFetch({
+      url: `${this.API_BASE_URL}/projects/suggest?q=${encodeURIComponent(text)}`,
+    });
+  }
+
   public getRepositoriesCSV(): Promise<string> {
     return this.apiFetch({
       url: '/data/repositories.csv',
@@ -1,28 +1,41 @@
 import { BiLock, BiMedal, BiShieldQuarter, BiTrophy, BiWorld } from 'react-icons/bi';
-import { BsCalendar3 } from 'react-icons/bs';
+import { BsCalendar3, BsCalendarCheck } from 'react-icons/bs';
 import { CgFileDocument, CgReadme } from 'react-icons/cg';
 import {
   FaBalanceScale,
+  FaBug,
   FaChartBar,
   FaCheckDouble,
   FaExclamationTriangle,
   FaFileContract,
   FaFileSignature,
+  FaKey,
   FaRobot,
   FaSignature,
   FaSlack,
   FaTools,
   FaTrademark,
+  FaUserClock,
   FaUserCog,
+  FaUserLock,
   FaUserSecret,
 } from 'react-icons/fa';
-import { FiHexagon } from 'react-icons/fi';
+import { FiBox, FiHexagon } from 'react-icons/fi';
 import { GiFountainPen, GiStamper, GiTiedScroll } from 'react-icons/gi';
-import { GoCommentDiscussion, GoFileBinary, GoLaw } from 'react-icons/go';
-import { HiOutlinePencilAlt, HiTerminal } from 'react-icons/hi';
+import { GoCommentDiscussion, GoFileBinary, GoGitBranch, GoLaw, GoOrganization, GoPackage } from 'react-icons/go';
+import { HiOutlineDocumentText, HiOutlinePencilAlt, HiTerminal } from 'react-icons/hi';
 import { ImOffice } from 'react-icons/im';
 import { IoIosPeople, IoMdRibbon } from 'react-icons/io';
-import { MdOutlineInventory, MdPreview } from 'react-icons/md';
+import {
+  MdAccessibility,
+  MdOutlineInventory,
+  MdOutlineReportProblem,
+  MdPhonelinkLock,
+  MdPreview,
+  MdPrivacyTip,
+  MdUpdate,
+  MdVerified,
+} from 'react-icons/md';
 import { RiRoadMapLine, RiShieldStarLine } from 'react-icons/ri';
 
 import ExternalLink from './layout/common/ExternalLink';
@@ -95,20 +108,24 @@ export const FILTERS: FiltersSection[] = [
 ];
 
 export const CATEGORY_ICONS = {
+  [ScoreType.Artifacts]: <FiBox />,
   [ScoreType.BestPractices]: <RiShieldStarLine />,
   [ScoreType.Documentation]: <HiOutlinePencilAlt />,
   [ScoreType.Global]: <BiTrophy />,
   [ScoreType.Legal]: <GoLaw />,
   [ScoreType.License]: <IoMdRibbon />,
+  [ScoreType.Organization]: <GoOrganization />,
   [ScoreType.Security]: <BiLock />,
 };
 
 export const CATEGORY_NAMES = {
+  [ScoreType.Artifacts]: 'Artifacts',
   [ScoreType.BestPractices]: 'Best Practices',
   [ScoreType.Documentation]: 'Documentation',
   [ScoreType.Global]: 'Global',
   [ScoreType.Legal]: 'Legal',
   [ScoreType.License]: 'License',
+  [ScoreType.Organization]: 'Organization',
   [ScoreType.Security]: 'Security',
 };
 
@@ -121,6 +138,12 @@ export const FILTER_CATEGORY_NAMES = {
 };
 
 export const REPORT_OPTIONS: ReportOptionInfo = {
+  [ReportOption.AccessibilityStatement]: {
+    icon: <MdAccessibility />,
+    name: 'Accessibility statement',
+    legend: <span>Statement describing the accessibility of the project's documentation site</span>,
+    reference: '/docs/topics/checks/#accessibility-statement',
+  },
   [ReportOption.Adopters]: {
     icon: <ImOffice />,
     name: 'Adopters',
@@ -153,6 +176,17 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>Whether the project has generated executable (binary) artifacts in the source repository</span>,
     reference: '/docs/topics/checks/#binary-artifacts-from-openssf-scorecard',
   },
+  [ReportOption.BranchProtection]: {
+    icon: <GoGitBranch />,
+    name: 'Branch protection',
+    legend: (
+      <span>
+        The default branch requires status checks and reviews, dismisses stale approvals, requires signed commits and
+        blocks force pushes
+      </span>
+    ),
+    reference: '/docs/topics/checks/#branch-protection',
+  },
   [ReportOption.Changelog]: {
     icon: <CgFileDocument />,
     name: 'Changelog',
@@ -227,6 +261,26 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     ),
     reference: '/docs/topics/checks/#developer-certificate-of-origin',
   },
+  [ReportOption.DCOOrCLA]: {
+    icon: <FaFileSignature />,
+    name: 'DCO or CLA',
+    legend: (
+      <span>Contributions are covered by the Developer Certificate of Origin or a Contributor License Agreement</span>
+    ),
+    reference: '/docs/topics/checks/#dco-or-cla',
+  },
+  [ReportOption.DependencyUpdateAutomation]: {
+    icon: <MdUpdate />,
+    name: 'Dependency update automation',
+    legend: <span>Projects should use a bot to keep their dependencies up to date automatically</span>,
+    reference: '/docs/topics/checks/#dependency-update-automation',
+  },
+  [ReportOption.Fuzzing]: {
+    icon: <FaBug />,
+    name: 'Fuzzing',
+    legend: <span>The project is fuzzed continuously (OSS-Fuzz, ClusterFuzzLite) or provides fuzz targets</span>,
+    reference: '/docs/topics/checks/#fuzzing',
+  },
   [ReportOption.Analytics]: {
     icon: <FaChartBar />,
     name: 'Analytics',
@@ -245,6 +299,30 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>Document that explains how the governance and committer process works in the repository</span>,
     reference: '/docs/topics/checks/#governance',
   },
+  [ReportOption.ImageRecentRebuild]: {
+    icon: <MdUpdate />,
+    name: 'Image recent rebuild',
+    legend: <span>The container images built from the repository have been rebuilt in the last 90 days</span>,
+    reference: '/docs/topics/checks/#image-recent-rebuild',
+  },
+  [ReportOption.ImageSBOM]: {
+    icon: <MdOutlineInventory />,
+    name: 'Image SBOM',
+    legend: <span>The container images built from the repository have an SBOM attached</span>,
+    reference: '/docs/topics/checks/#image-sbom',
+  },
+  [ReportOption.ImageSignature]: {
+    icon: <GiFountainPen />,
+    name: 'Image signature',
+    legend: <span>The container images built from the repository are signed</span>,
+    reference: '/docs/topics/checks/#image-signature',
+  },
+  [ReportOption.LicenseCompatibility]: {
+    icon: <GoPackage />,
+    name: 'License compatibility',
+    legend: <span>Vendored dependencies use licenses compatible with the repository's license</span>,
+    reference: '/docs/topics/checks/#license-compatibility',
+  },
   [ReportOption.LicenseScanning]: {
     icon: <GiStamper />,
     name: 'License scanning',
@@ -271,6 +349,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     ),
     reference: '/docs/topics/checks/#maintainers',
   },
+  [ReportOption.MaintainersActivity]: {
+    icon: <FaUserClock />,
+    name: 'Maintainers activity',
+    legend: <span>The maintainers listed in the repository have been active in it recently</span>,
+    reference: '/docs/topics/checks/#maintainers-activity',
+  },
   [ReportOption.OpenSSFBadge]: {
     icon: <BiMedal />,
     name: 'OpenSSF badge',
@@ -282,6 +366,30 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     ),
     reference: '/docs/topics/checks/#openssf-badge',
   },
+  [ReportOption.OrgDefaultPermissions]: {
+    icon: <FaUserLock />,
+    name: 'Organization default permissions',
+    legend: <span>The base permissions of the GitHub organization members are restricted to read</span>,
+    reference: '/docs/topics/checks/#organization-default-permissions',
+  },
+  [ReportOption.OrgSecretScanning]: {
+    icon: <FaKey />,
+    name: 'Organization secret scanning',
+    legend: <span>Secret scanning is enabled for new repositories in the GitHub organization</span>,
+    reference: '/docs/topics/checks/#organization-secret-scanning',
+  },
+  [ReportOption.OrgTwoFactorAuth]: {
+    icon: <MdPhonelinkLock />,
+    name: 'Organization two-factor authentication',
+    legend: <span>Two-factor authentication is required for all the members of the GitHub organization</span>,
+    reference: '/docs/topics/checks/#organization-two-factor-authentication',
+  },
+  [ReportOption.PrivacyPolicy]: {
+    icon: <MdPrivacyTip />,
+    name: 'Privacy policy',
+    legend: <span>Projects sites should link to the privacy policy that applies to them</span>,
+    reference: '/docs/topics/checks/#privacy-policy',
+  },
   [ReportOption.Readme]: {
     icon: <CgReadme />,
     name: 'Readme',
@@ -299,6 +407,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>The project should have released at least one version in the last year</span>,
     reference: '/docs/topics/checks/#recent-release',
   },
+  [ReportOption.ReleaseNotes]: {
+    icon: <HiOutlineDocumentText />,
+    name: 'Release notes',
+    legend: <span>The latest release is documented in its release notes and in the changelog</span>,
+    reference: '/docs/topics/checks/#release-notes',
+  },
   [ReportOption.Roadmap]: {
     icon: <RiRoadMapLine />,
     name: 'Roadmap',
@@ -314,6 +428,16 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>List of components in a piece of software, including licenses, versions, etc</span>,
     reference: '/docs/topics/checks/#software-bill-of-materials-sbom',
   },
+  [ReportOption.SecurityInsights]: {
+    icon: <BiShieldQuarter />,
+    name: 'Security insights',
+    legend: (
+      <span>
+        OpenSSF Security Insights file providing the project's security contacts and vulnerability reporting policy
+      </span>
+    ),
+    reference: '/docs/topics/checks/#security-insights',
+  },
   [ReportOption.SecurityPolicy]: {
     icon: <BiShieldQuarter />,
     name: 'Security policy',
@@ -326,12 +450,24 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>The project cryptographically signs release artifacts</span>,
     reference: '/docs/topics/checks/#signed-releases-from-openssf-scorecard',
   },
+  [ReportOption.SLSAProvenance]: {
+    icon: <MdVerified />,
+    name: 'SLSA provenance',
+    legend: <span>The project's release process generates provenance for the release artifacts</span>,
+    reference: '/docs/topics/checks/#slsa-provenance',
+  },
   [ReportOption.SlackPresence]: {
     icon: <FaSlack />,
     name: 'Slack presence',
     legend: <span>Projects should have presence in the CNCF Slack or Kubernetes Slack</span>,
     reference: '/docs/topics/checks/#slack-presence',
   },
+  [ReportOption.SupportedVersions]: {
+    icon: <BsCalendarCheck />,
+    name: 'Supported versions',
+    legend: <span>Documented supported versions and policy for backporting security fixes to them</span>,
+    reference: '/docs/topics/checks/#supported-versions',
+  },
   [ReportOption.SPDX]: {
     icon: <FaBalanceScale />,
     name: 'License found',
@@ -354,6 +490,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>Projects sites should have the Linux Foundation trademark disclaimer</span>,
     reference: '/docs/topics/checks/#trademark-disclaimer',
   },
+  [ReportOption.VulnerabilityDisclosure]: {
+    icon: <MdOutlineReportProblem />,
+    name: 'Vulnerability disclosure',
+    legend: <span>Private vulnerability reporting is enabled and vulnerabilities are published as security advisories</span>,
+    reference: '/docs/topics/checks/#vulnerability-disclosure',
+  },
   [ReportOption.Website]: {
     icon: <BiWorld />,
     name: 'Website',
@@ -373,38 +515,59 @@ export const FOUNDATIONS = {
 
 export const CHECKS_PER_CATEGORY: ChecksPerCategory = {
   [ScoreType.Documentation]: [
+    ReportOption.AccessibilityStatement,
     ReportOption.Adopters,
     ReportOption.Changelog,
     ReportOption.CodeOfConduct,
     ReportOption.Contributing,
     ReportOption.Governance,
     ReportOption.Maintainers,
+    ReportOption.MaintainersActivity,
     ReportOption.Readme,
     ReportOption.Roadmap,
     ReportOption.Website,
   ],
-  [ScoreType.License]: [ReportOption.SPDX, ReportOption.ApprovedLicense, ReportOption.LicenseScanning],
+  [ScoreType.License]: [
+    ReportOption.SPDX,
+    ReportOption.ApprovedLicense,
+    ReportOption.LicenseCompatibility,
+    ReportOption.LicenseScanning,
+  ],
   [ScoreType.BestPractices]: [
     ReportOption.Analytics,
     ReportOption.ArtifactHubBadge,
     ReportOption.CLA,
     ReportOption.CommunityMeeting,
     ReportOption.DCO,
+    ReportOption.DependencyUpdateAutomation,
     ReportOption.GithubDiscussions,
     ReportOption.OpenSSFBadge,
     ReportOption.RecentRelease,
+    ReportOption.ReleaseNotes,
     ReportOption.SlackPresence,
   ],
   [ScoreType.Security]: [
     ReportOption.BinaryArtifacts,
+    ReportOption.BranchProtection,
     ReportOption.CodeReview,
     ReportOption.DangerousWorkflow,
     ReportOption.DependencyUpdateTool,
+    ReportOption.Fuzzing,
     ReportOption.Maintained,
     ReportOption.SBOM,
+    ReportOption.SecurityInsights,
     ReportOption.SecurityPolicy,
     ReportOption.SignedReleases,
+    ReportOption.SLSAProvenance,
+    ReportOption.SupportedVersions,
     ReportOption.TokenPermissions,
+    ReportOption.VulnerabilityDisclosure,
+  ],
+  [ScoreType.Legal]: [ReportOption.DCOOrCLA, ReportOption.PrivacyPolicy, ReportOption.TrademarkDisclaimer],
+  [ScoreType.Artifacts]: [ReportOption.ImageRecentRebuild, ReportOption.ImageSBOM, ReportOption.ImageSignature],
+  [ScoreType.Organization]: [
+    ReportOption.OrgDefaultPermissions,
+    ReportOption.OrgSecretScanning,
+    ReportOption.OrgTwoFactorAuth,
   ],
-  [ScoreType.Legal]: [ReportOption.TrademarkDisclaimer],
 };
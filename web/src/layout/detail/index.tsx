@@ -253,7 +253,22 @@ const Detail = (props: Props) => {
                         </p>
                         <div className={`text-muted fst-italic mx-3 mx-md-0 mb-2 mb-md-3 ${styles.updated}`}>
                           {isUndefined(activeDate) ? (
-                            <>Updated {moment.unix(detail.updated_at).fromNow()}</>
+                            <>
+                              Updated {moment.unix(detail.updated_at).fromNow()}
+                              {detail.cohort &&
+                                detail.cohort.projects > 1 &&
+                                !isUndefined(detail.cohort.percentiles.global) && (
+                                  <>
+                                    {' '}
+                                    · Global score higher than or equal to {detail.cohort.percentiles.global}% of
+                                    the {detail.cohort.projects}{' '}
+                                    {detail.maturity
+                                      ? `${detail.maturity} projects`
+                                      : 'projects without maturity level'}{' '}
+                                    in the foundation
+                                  </>
+                                )}
+                            </>
                           ) : (
                             <>
                               This is a snapshot of the project taken on{' '}
@@ -113,6 +113,28 @@ describe('OptionCell', () => {
       expect(link).toHaveProperty('rel', 'noopener noreferrer');
     });
 
+    it('renders option with remediation', () => {
+      render(
+        <table>
+          <tbody>
+            <OptionCell
+              {...defaultProps}
+              check={{
+                passed: false,
+                remediation: {
+                  steps: ['Add an ADOPTERS.md file to the repository.'],
+                  template: { path: 'ADOPTERS.md', content: '# Adopters' },
+                  docs_url: 'https://clomonitor.io/docs/topics/checks/#adopters',
+                },
+              }}
+            />
+          </tbody>
+        </table>
+      );
+
+      expect(screen.getAllByTestId('remediation-link')[0]).toHaveTextContent('[how to fix]');
+    });
+
     it('renders option with details', async () => {
       jest.useFakeTimers();
 
@@ -28,6 +28,9 @@ const OptionCell = (props: Props) => {
   const successIcon = <FaRegCheckCircle data-testid="success-icon" className={`text-success ${styles.icon}`} />;
   const exemptIcon = <MdRemoveCircleOutline data-testid="exempt-icon" className={`text-muted ${styles.exemptIcon}`} />;
   const failedIcon = <RiErrorWarningLine data-testid="failed-icon" className={styles.failedIcon} />;
+  const notApplicableIcon = (
+    <MdRemoveCircleOutline data-testid="not-applicable-icon" className={`text-muted ${styles.exemptIcon}`} />
+  );
 
   const opt: ReportOptionData = getOptionInfo(props.label);
 
@@ -130,7 +133,32 @@ const OptionCell = (props: Props) => {
   };
 
   const getIconCheck = (): JSX.Element => {
-    if (!isUndefined(props.check.exempt) && props.check.exempt) {
+    if (!isUndefined(props.check.not_applicable) && props.check.not_applicable) {
+      return (
+        <>
+          <ElementWithTooltip
+            element={notApplicableIcon}
+            tooltipWidth={500}
+            tooltipClassName={styles.reasonTooltipMessage}
+            tooltipMessage={
+              <div className="text-start p-2">
+                <div className="border-bottom pb-2 mb-3 fw-bold">This check does not apply to this repository</div>
+                {!isUndefined(props.check.not_applicable_reason) && props.check.not_applicable_reason !== '' && (
+                  <div className={`text-break ${styles.reason}`}>
+                    <span className="fw-bold">Reason:</span> {props.check.not_applicable_reason}
+                  </div>
+                )}
+              </div>
+            }
+            alignmentTooltip="left"
+            forceAlignment
+            visibleTooltip
+            active
+          />
+          <span className="d-block d-md-none">{notApplicableIcon}</span>
+        </>
+      );
+    } else if (!isUndefined(props.check.exempt) && props.check.exempt) {
       return (
         <>
           {!isUndefined(props.check.exemption_reason) && props.check.exemption_reason !== '' ? (
@@ -251,6 +279,41 @@ const OptionCell = (props: Props) => {
                     </ExternalLink>
                   </div>
                 )}
+                {props.check.remediation && (
+                  <div className="d-none d-lg-flex text-nowrap ms-1">
+                    <DropdownOnHover
+                      width={600}
+                      dropdownClassName={styles.detailsDropdown}
+                      linkContent={<div data-testid="remediation-link">[how to fix]</div>}
+                      tooltipStyle
+                    >
+                      <div className={`overflow-scroll text-start ${styles.detailsWrapper} ${styles.visibleScroll}`}>
+                        <div className="fs-6 border-bottom pb-2 mb-2 fw-bold w-100">How to fix</div>
+                        <ol className={`ps-3 mb-2 ${styles.reason}`}>
+                          {props.check.remediation.steps.map((step: string) => (
+                            <li key={step}>{step}</li>
+                          ))}
+                        </ol>
+                        {props.check.remediation.template && (
+                          <>
+                            <div className={`fw-bold mb-1 ${styles.reason}`}>
+                              Example <code>{props.check.remediation.template.path}</code> file:
+                            </div>
+                            <pre className={`p-2 mb-2 ${styles.codeContent} ${styles.visibleScroll}`}>
+                              {props.check.remediation.template.content}
+                            </pre>
+                          </>
+                        )}
+                        <ExternalLink href={props.check.remediation.docs_url} className={`d-inline ${styles.reason}`}>
+                          <div className="d-flex flex-row align-items-center">
+                            <div>More details in the check documentation</div>
+                            <FiExternalLink className={`ms-1 ${styles.extraMiniIcon}`} />
+                          </div>
+                        </ExternalLink>
+                      </div>
+                    </DropdownOnHover>
+                  </div>
+                )}
               </div>
             </div>
           </div>
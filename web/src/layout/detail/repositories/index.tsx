@@ -240,6 +240,54 @@ const RepositoriesList = (props: Props) => {
                     referenceUrl="/docs/topics/checks/#legal"
                     getAnchorLink={getAnchorLink}
                   />
+                  {repo.report.data.artifacts && (
+                    <Row
+                      repoName={repo.name}
+                      reportId={repo.report.report_id}
+                      name={ScoreType.Artifacts}
+                      label="Artifacts"
+                      data={repo.report.data.artifacts}
+                      icon={CATEGORY_ICONS[ScoreType.Artifacts]}
+                      score={!isUndefined(repo.score) ? repo.score.artifacts : undefined}
+                      referenceUrl="/docs/topics/checks/#artifacts"
+                      getAnchorLink={getAnchorLink}
+                    />
+                  )}
+                  {repo.report.data.organization && (
+                    <Row
+                      repoName={repo.name}
+                      reportId={repo.report.report_id}
+                      name={ScoreType.Organization}
+                      label="Organization"
+                      data={repo.report.data.organization}
+                      icon={CATEGORY_ICONS[ScoreType.Organization]}
+                      score={!isUndefined(repo.score) ? repo.score.organization : undefined}
+                      referenceUrl="/docs/topics/checks/#organization"
+                      getAnchorLink={getAnchorLink}
+                    />
+                  )}
+                  {repo.report.data.provider_limited && (
+                    <div className="my-2">
+                      <div className={`alert alert-info mb-0 rounded-0 ${styles.alert}`} role="alert">
+                        <div className="fw-bold mb-2">Checks limited by provider support</div>
+                        <div className="mb-2">
+                          Some checks are limited (or not available at all) on the provider hosting this repository.
+                          Checks not supported are not run, so they are not taken into account when calculating the
+                          score.
+                        </div>
+                        <ul className="mb-0">
+                          {Object.keys(repo.report.data.provider_limited).map((checkId: string) => (
+                            <li key={`${repo.report.report_id}_${checkId}_limited`}>
+                              <code>{checkId}</code>:{' '}
+                              {repo.report.data.provider_limited[checkId] === 'partial'
+                                ? 'partially supported'
+                                : 'not supported'}
+                            </li>
+                          ))}
+                        </ul>
+                      </div>
+                    </div>
+                  )}
                 </>
               )}
             </div>
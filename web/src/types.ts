@@ -22,6 +22,21 @@ export interface Project extends BaseProject {
 export interface ProjectDetail extends BaseProject {
   repositories: Repository[];
   snapshots?: string[];
+  cohort?: ProjectCohort;
+}
+
+export interface ProjectCohort {
+  projects: number;
+  percentiles: { [key in ScoreType]?: number };
+}
+
+export interface ProjectSuggestion {
+  id: string;
+  name: string;
+  display_name?: string;
+  foundation: Foundation;
+  logo_url?: string;
+  rating?: string;
 }
 
 export interface BaseRepository {
@@ -59,9 +74,22 @@ export interface ReportCheck {
   exemption_reason?: string;
   failed?: boolean;
   fail_reason?: string;
+  not_applicable?: boolean;
+  not_applicable_reason?: string;
   value?: string | string[];
   url?: string;
   details?: string;
+  credit?: number;
+  remediation?: CheckRemediation;
+}
+
+export interface CheckRemediation {
+  steps: string[];
+  template?: {
+    path: string;
+    content: string;
+  };
+  docs_url: string;
 }
 
 export interface FiltersSection {
@@ -127,11 +155,13 @@ export enum FilterKind {
 }
 
 export enum ScoreType {
+  Artifacts = 'artifacts',
   BestPractices = 'best_practices',
   Documentation = 'documentation',
   Global = 'global',
   Legal = 'legal',
   License = 'license',
+  Organization = 'organization',
   Security = 'security',
 }
 
@@ -150,14 +180,17 @@ export enum CheckSet {
   CodeLite = 'code-lite',
   Community = 'community',
   Docs = 'docs',
+  DocsSite = 'docs-site',
 }
 
 export enum ReportOption {
+  AccessibilityStatement = 'accessibility_statement',
   Adopters = 'adopters',
   Analytics = 'analytics',
   ApprovedLicense = 'license_approved',
   ArtifactHubBadge = 'artifacthub_badge',
   BinaryArtifacts = 'binary_artifacts',
+  BranchProtection = 'branch_protection',
   Changelog = 'changelog',
   CLA = 'cla',
   CodeOfConduct = 'code_of_conduct',
@@ -167,22 +200,39 @@ export enum ReportOption {
   DangerousWorkflow = 'dangerous_workflow',
   DependencyUpdateTool = 'dependency_update_tool',
   DCO = 'dco',
+  DCOOrCLA = 'dco_or_cla',
+  DependencyUpdateAutomation = 'dependency_update_automation',
+  Fuzzing = 'fuzzing',
   GithubDiscussions = 'github_discussions',
   Governance = 'governance',
+  ImageRecentRebuild = 'image_recent_rebuild',
+  ImageSBOM = 'image_sbom',
+  ImageSignature = 'image_signature',
+  LicenseCompatibility = 'license_compatibility',
   LicenseScanning = 'license_scanning',
   Maintained = 'maintained',
   Maintainers = 'maintainers',
+  MaintainersActivity = 'maintainers_activity',
   OpenSSFBadge = 'openssf_badge',
+  OrgDefaultPermissions = 'org_default_permissions',
+  OrgSecretScanning = 'org_secret_scanning',
+  OrgTwoFactorAuth = 'org_two_factor_auth',
+  PrivacyPolicy = 'privacy_policy',
   Readme = 'readme',
   RecentRelease = 'recent_release',
+  ReleaseNotes = 'release_notes',
   Roadmap = 'roadmap',
   SBOM = 'sbom',
+  SecurityInsights = 'security_insights',
   SecurityPolicy = 'security_policy',
   SignedReleases = 'signed_releases',
+  SLSAProvenance = 'slsa_provenance',
   SlackPresence = 'slack_presence',
   SPDX = 'license_spdx_id',
+  SupportedVersions = 'supported_versions',
   TokenPermissions = 'token_permissions',
   TrademarkDisclaimer = 'trademark_disclaimer',
+  VulnerabilityDisclosure = 'vulnerability_disclosure',
   Website = 'website',
 }
 